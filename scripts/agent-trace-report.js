@@ -1,4 +1,6 @@
 #!/usr/bin/env node
+// Deprecated: use `converge trace report <trace.jsonl> [--out <report.md>]`, which validates the
+// versioned trace schema. Kept for existing automation and will be removed in a later release.
 const fs = require('fs');
 const path = require('path');
 
@@ -192,6 +194,7 @@ function formatReport(tracePath, analysis) {
 }
 
 (function main() {
+  console.error('warning: scripts/agent-trace-report.js is deprecated; use `converge trace report` instead');
   const { tracePath, outPath } = parseArgs(process.argv);
   if (!fs.existsSync(tracePath)) {
     console.error(`Trace file not found: ${tracePath}`);
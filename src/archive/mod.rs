@@ -0,0 +1,307 @@
+//! Deterministic tar / tar.zst / zip archives of manifest trees.
+//!
+//! Archives stream straight from an object source: chunked files are read chunk by chunk and
+//! nothing is materialized on disk. Entries are sorted by path, mtimes are fixed, ownership
+//! is cleared and file modes are normalized, so the same tree always produces byte-identical
+//! output.
+
+use std::collections::VecDeque;
+use std::io::{Read, Write};
+
+use anyhow::{Context, Result, anyhow};
+
+use crate::model::{FileRecipe, FileRecipeChunk, Manifest, ManifestEntryKind, ObjectId};
+use crate::store::LocalStore;
+
+mod zip_stream;
+
+use self::zip_stream::ZipStream;
+
+/// Fixed modification time for tar entries (2000-01-01T00:00:00Z).
+const TAR_MTIME: u64 = 946_684_800;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ArchiveFormat {
+    Tar,
+    TarZst,
+    Zip,
+}
+
+impl ArchiveFormat {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ArchiveFormat::Tar => "tar",
+            ArchiveFormat::TarZst => "tar.zst",
+            ArchiveFormat::Zip => "zip",
+        }
+    }
+
+    pub fn content_type(self) -> &'static str {
+        match self {
+            ArchiveFormat::Tar => "application/x-tar",
+            ArchiveFormat::TarZst => "application/zstd",
+            ArchiveFormat::Zip => "application/zip",
+        }
+    }
+}
+
+impl std::str::FromStr for ArchiveFormat {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "tar" => Ok(ArchiveFormat::Tar),
+            "tar.zst" | "tzst" => Ok(ArchiveFormat::TarZst),
+            "zip" => Ok(ArchiveFormat::Zip),
+            other => Err(anyhow!(
+                "unknown archive format {} (expected tar, tar.zst or zip)",
+                other
+            )),
+        }
+    }
+}
+
+/// Read access to manifests, blobs and recipes (the local store, or a server repo).
+pub trait ObjectSource {
+    fn manifest(&self, id: &ObjectId) -> Result<Manifest>;
+    fn blob(&self, id: &ObjectId) -> Result<Vec<u8>>;
+    fn recipe(&self, id: &ObjectId) -> Result<FileRecipe>;
+}
+
+impl ObjectSource for LocalStore {
+    fn manifest(&self, id: &ObjectId) -> Result<Manifest> {
+        self.get_manifest(id)
+    }
+
+    fn blob(&self, id: &ObjectId) -> Result<Vec<u8>> {
+        self.get_blob(id)
+    }
+
+    fn recipe(&self, id: &ObjectId) -> Result<FileRecipe> {
+        self.get_recipe(id)
+    }
+}
+
+/// Writes the tree at `root` to `out` and returns the writer once the archive is finished.
+pub fn write_archive<W: Write>(
+    source: &dyn ObjectSource,
+    root: &ObjectId,
+    format: ArchiveFormat,
+    out: W,
+) -> Result<W> {
+    match format {
+        ArchiveFormat::Tar => write_tar(source, root, out),
+        ArchiveFormat::TarZst => {
+            let mut encoder = zstd::Encoder::new(out, 0).context("start zstd stream")?;
+            // Keep the frame independent of the writer: no content size, no dictionary id.
+            encoder
+                .include_contentsize(false)
+                .context("configure zstd stream")?;
+            let encoder = write_tar(source, root, encoder)?;
+            encoder.finish().context("finish zstd stream")
+        }
+        ArchiveFormat::Zip => write_zip(source, root, out),
+    }
+}
+
+enum Node {
+    Dir,
+    File {
+        mode: u32,
+        size: u64,
+        content: FileContent,
+    },
+    Symlink {
+        target: String,
+    },
+}
+
+enum FileContent {
+    Blob(ObjectId),
+    Recipe(ObjectId),
+}
+
+/// Visits every entry under `manifest_id` depth-first in name order; directories come first.
+fn walk(
+    source: &dyn ObjectSource,
+    manifest_id: &ObjectId,
+    prefix: &str,
+    visit: &mut dyn FnMut(&str, Node) -> Result<()>,
+) -> Result<()> {
+    let manifest = source.manifest(manifest_id)?;
+    let mut entries = manifest.entries;
+    entries.sort_by(|a, b| a.name.cmp(&b.name));
+    for entry in entries {
+        let path = if prefix.is_empty() {
+            entry.name
+        } else {
+            format!("{}/{}", prefix, entry.name)
+        };
+        match entry.kind {
+            ManifestEntryKind::Dir { manifest } => {
+                visit(&path, Node::Dir)?;
+                walk(source, &manifest, &path, visit)?;
+            }
+            ManifestEntryKind::File { blob, mode, size } => visit(
+                &path,
+                Node::File {
+                    mode,
+                    size,
+                    content: FileContent::Blob(blob),
+                },
+            )?,
+            ManifestEntryKind::FileChunks { recipe, mode, size } => visit(
+                &path,
+                Node::File {
+                    mode,
+                    size,
+                    content: FileContent::Recipe(recipe),
+                },
+            )?,
+            ManifestEntryKind::Symlink { target } => visit(&path, Node::Symlink { target })?,
+            ManifestEntryKind::Superposition { .. } => {
+                anyhow::bail!("cannot archive unresolved superposition at {}", path)
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Streams a file's bytes, fetching one chunk at a time.
+struct ContentReader<'a> {
+    source: &'a dyn ObjectSource,
+    pending: VecDeque<FileRecipeChunk>,
+    current: std::io::Cursor<Vec<u8>>,
+}
+
+impl<'a> ContentReader<'a> {
+    fn new(source: &'a dyn ObjectSource, content: &FileContent, size: u64) -> Result<Self> {
+        let (current, pending) = match content {
+            FileContent::Blob(blob) => (source.blob(blob)?, VecDeque::new()),
+            FileContent::Recipe(recipe) => {
+                let recipe = source.recipe(recipe)?;
+                if recipe.size != size {
+                    anyhow::bail!(
+                        "recipe size mismatch (recipe {}, entry {})",
+                        recipe.size,
+                        size
+                    );
+                }
+                (Vec::new(), recipe.chunks.into())
+            }
+        };
+        Ok(Self {
+            source,
+            pending,
+            current: std::io::Cursor::new(current),
+        })
+    }
+}
+
+impl Read for ContentReader<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        loop {
+            let n = self.current.read(buf)?;
+            if n > 0 || buf.is_empty() {
+                return Ok(n);
+            }
+            let Some(chunk) = self.pending.pop_front() else {
+                return Ok(0);
+            };
+            let bytes = self
+                .source
+                .blob(&chunk.blob)
+                .map_err(std::io::Error::other)?;
+            if bytes.len() != chunk.size as usize {
+                return Err(std::io::Error::other(format!(
+                    "chunk size mismatch for {}",
+                    chunk.blob.as_str()
+                )));
+            }
+            self.current = std::io::Cursor::new(bytes);
+        }
+    }
+}
+
+fn write_tar<W: Write>(source: &dyn ObjectSource, root: &ObjectId, out: W) -> Result<W> {
+    let mut builder = tar::Builder::new(out);
+    builder.mode(tar::HeaderMode::Deterministic);
+
+    walk(source, root, "", &mut |path, node| {
+        let mut header = tar::Header::new_gnu();
+        header.set_mtime(TAR_MTIME);
+        header.set_uid(0);
+        header.set_gid(0);
+        match node {
+            Node::Dir => {
+                header.set_entry_type(tar::EntryType::Directory);
+                header.set_mode(0o755);
+                header.set_size(0);
+                builder
+                    .append_data(&mut header, format!("{}/", path), std::io::empty())
+                    .with_context(|| format!("archive dir {}", path))?;
+            }
+            Node::File {
+                mode,
+                size,
+                content,
+            } => {
+                header.set_entry_type(tar::EntryType::Regular);
+                header.set_mode(normalized_mode(mode));
+                header.set_size(size);
+                let reader = ContentReader::new(source, &content, size)?;
+                builder
+                    .append_data(&mut header, path, reader)
+                    .with_context(|| format!("archive file {}", path))?;
+            }
+            Node::Symlink { target } => {
+                header.set_entry_type(tar::EntryType::Symlink);
+                header.set_mode(0o777);
+                header.set_size(0);
+                builder
+                    .append_link(&mut header, path, &target)
+                    .with_context(|| format!("archive symlink {}", path))?;
+            }
+        }
+        Ok(())
+    })?;
+
+    builder.into_inner().context("finish tar stream")
+}
+
+fn write_zip<W: Write>(source: &dyn ObjectSource, root: &ObjectId, out: W) -> Result<W> {
+    let mut zip = ZipStream::new(out);
+    walk(source, root, "", &mut |path, node| {
+        match node {
+            Node::Dir => zip
+                .add_directory(path, 0o755)
+                .with_context(|| format!("archive dir {}", path))?,
+            Node::File {
+                mode,
+                size,
+                content,
+            } => {
+                let mut reader = ContentReader::new(source, &content, size)?;
+                zip.add_file(path, normalized_mode(mode), size, &mut reader)
+                    .with_context(|| format!("archive file {}", path))?;
+            }
+            Node::Symlink { target } => zip
+                .add_symlink(path, &target)
+                .with_context(|| format!("archive symlink {}", path))?,
+        }
+        Ok(())
+    })?;
+    zip.finish().context("finish zip stream")
+}
+
+/// Archived file mode: 0755 when any execute bit is set, else 0644.
+///
+/// Stored modes carry the snapshotting host's umask (0664, 0600, ...); like Git, archives keep
+/// only the executable bit so equal trees give equal bytes. This is deliberately lossy.
+fn normalized_mode(mode: u32) -> u32 {
+    if mode & 0o111 != 0 { 0o755 } else { 0o644 }
+}
+
+#[cfg(test)]
+#[path = "../tests/archive/archive_tests.rs"]
+mod tests;
@@ -0,0 +1,356 @@
+//! Forward-only zip writer.
+//!
+//! Every entry is followed by a data descriptor, so the output never needs to seek and can go
+//! straight to a pipe or socket. Timestamps are fixed at the DOS epoch; Zip64 records are added
+//! only for entries or offsets that need them.
+
+use std::io::{self, Read, Write};
+
+use flate2::Compression;
+use flate2::write::DeflateEncoder;
+
+const LOCAL_HEADER: u32 = 0x0403_4b50;
+const DATA_DESCRIPTOR: u32 = 0x0807_4b50;
+const CENTRAL_HEADER: u32 = 0x0201_4b50;
+const END_OF_CENTRAL_DIR: u32 = 0x0605_4b50;
+const ZIP64_END_OF_CENTRAL_DIR: u32 = 0x0606_4b50;
+const ZIP64_LOCATOR: u32 = 0x0706_4b50;
+
+/// Bit 3: sizes and CRC follow the data. Bit 11: names are UTF-8.
+const FLAGS: u16 = 0x0808;
+const METHOD_STORED: u16 = 0;
+const METHOD_DEFLATED: u16 = 8;
+/// Upper byte 3 = Unix, so readers honour the mode in the external attributes.
+const VERSION_MADE_BY: u16 = (3 << 8) | 45;
+const VERSION_NEEDED: u16 = 20;
+const VERSION_NEEDED_ZIP64: u16 = 45;
+/// 1980-01-01, the earliest DOS date.
+const DOS_DATE: u16 = (1 << 5) | 1;
+const DOS_TIME: u16 = 0;
+
+const S_IFREG: u32 = 0o100000;
+const S_IFDIR: u32 = 0o040000;
+const S_IFLNK: u32 = 0o120000;
+
+/// Entries at least this large use Zip64 sizes (leaves room for deflate overhead).
+const ZIP64_ENTRY_THRESHOLD: u64 = u32::MAX as u64 - (1 << 20);
+
+struct CentralEntry {
+    name: String,
+    method: u16,
+    zip64: bool,
+    crc: u32,
+    compressed: u64,
+    size: u64,
+    offset: u64,
+    unix_mode: u32,
+}
+
+pub(super) struct ZipStream<W: Write> {
+    out: Counting<W>,
+    entries: Vec<CentralEntry>,
+}
+
+impl<W: Write> ZipStream<W> {
+    pub(super) fn new(out: W) -> Self {
+        Self {
+            out: Counting {
+                inner: out,
+                count: 0,
+            },
+            entries: Vec::new(),
+        }
+    }
+
+    pub(super) fn add_directory(&mut self, path: &str, mode: u32) -> io::Result<()> {
+        self.add(
+            format!("{}/", path),
+            S_IFDIR | mode,
+            METHOD_STORED,
+            0,
+            &mut io::empty(),
+        )
+    }
+
+    pub(super) fn add_file(
+        &mut self,
+        path: &str,
+        mode: u32,
+        size: u64,
+        content: &mut dyn Read,
+    ) -> io::Result<()> {
+        self.add(
+            path.to_string(),
+            S_IFREG | mode,
+            METHOD_DEFLATED,
+            size,
+            content,
+        )
+    }
+
+    pub(super) fn add_symlink(&mut self, path: &str, target: &str) -> io::Result<()> {
+        self.add(
+            path.to_string(),
+            S_IFLNK | 0o777,
+            METHOD_STORED,
+            target.len() as u64,
+            &mut target.as_bytes(),
+        )
+    }
+
+    fn add(
+        &mut self,
+        name: String,
+        unix_mode: u32,
+        method: u16,
+        expected_size: u64,
+        content: &mut dyn Read,
+    ) -> io::Result<()> {
+        let offset = self.out.count;
+        let zip64 = expected_size >= ZIP64_ENTRY_THRESHOLD;
+
+        let out = &mut self.out;
+        put_u32(out, LOCAL_HEADER)?;
+        put_u16(
+            out,
+            if zip64 {
+                VERSION_NEEDED_ZIP64
+            } else {
+                VERSION_NEEDED
+            },
+        )?;
+        put_u16(out, FLAGS)?;
+        put_u16(out, method)?;
+        put_u16(out, DOS_TIME)?;
+        put_u16(out, DOS_DATE)?;
+        put_u32(out, 0)?; // crc, in the data descriptor
+        put_u32(out, if zip64 { u32::MAX } else { 0 })?;
+        put_u32(out, if zip64 { u32::MAX } else { 0 })?;
+        put_u16(out, name_len(&name)?)?;
+        put_u16(out, if zip64 { 20 } else { 0 })?;
+        out.write_all(name.as_bytes())?;
+        if zip64 {
+            put_u16(out, 0x0001)?;
+            put_u16(out, 16)?;
+            put_u64(out, 0)?;
+            put_u64(out, 0)?;
+        }
+
+        let data_start = out.count;
+        let (crc, size) = {
+            let mut sink = Checksum {
+                hasher: crc32fast::Hasher::new(),
+                size: 0,
+                inner: match method {
+                    METHOD_DEFLATED => {
+                        Sink::Deflate(DeflateEncoder::new(&mut *out, Compression::default()))
+                    }
+                    _ => Sink::Stored(&mut *out),
+                },
+            };
+            io::copy(content, &mut sink)?;
+            if let Sink::Deflate(encoder) = &mut sink.inner {
+                encoder.try_finish()?;
+            }
+            (sink.hasher.clone().finalize(), sink.size)
+        };
+        if size != expected_size {
+            return Err(io::Error::other(format!(
+                "{}: expected {} bytes, read {}",
+                name, expected_size, size
+            )));
+        }
+        let compressed = out.count - data_start;
+
+        put_u32(out, DATA_DESCRIPTOR)?;
+        put_u32(out, crc)?;
+        if zip64 {
+            put_u64(out, compressed)?;
+            put_u64(out, size)?;
+        } else {
+            put_u32(out, compressed as u32)?;
+            put_u32(out, size as u32)?;
+        }
+
+        self.entries.push(CentralEntry {
+            name,
+            method,
+            zip64,
+            crc,
+            compressed,
+            size,
+            offset,
+            unix_mode,
+        });
+        Ok(())
+    }
+
+    /// Writes the central directory and returns the underlying writer.
+    pub(super) fn finish(mut self) -> io::Result<W> {
+        let cd_start = self.out.count;
+        let out = &mut self.out;
+        for e in &self.entries {
+            let big_size = e.zip64 || e.size >= u32::MAX as u64 || e.compressed >= u32::MAX as u64;
+            let big_offset = e.offset >= u32::MAX as u64;
+            let mut extra = Vec::new();
+            if big_size {
+                extra.extend_from_slice(&e.size.to_le_bytes());
+                extra.extend_from_slice(&e.compressed.to_le_bytes());
+            }
+            if big_offset {
+                extra.extend_from_slice(&e.offset.to_le_bytes());
+            }
+
+            put_u32(out, CENTRAL_HEADER)?;
+            put_u16(out, VERSION_MADE_BY)?;
+            put_u16(
+                out,
+                if big_size || big_offset {
+                    VERSION_NEEDED_ZIP64
+                } else {
+                    VERSION_NEEDED
+                },
+            )?;
+            put_u16(out, FLAGS)?;
+            put_u16(out, e.method)?;
+            put_u16(out, DOS_TIME)?;
+            put_u16(out, DOS_DATE)?;
+            put_u32(out, e.crc)?;
+            put_u32(
+                out,
+                if big_size {
+                    u32::MAX
+                } else {
+                    e.compressed as u32
+                },
+            )?;
+            put_u32(out, if big_size { u32::MAX } else { e.size as u32 })?;
+            put_u16(out, name_len(&e.name)?)?;
+            put_u16(
+                out,
+                if extra.is_empty() {
+                    0
+                } else {
+                    extra.len() as u16 + 4
+                },
+            )?;
+            put_u16(out, 0)?; // comment length
+            put_u16(out, 0)?; // disk number
+            put_u16(out, 0)?; // internal attributes
+            put_u32(out, e.unix_mode << 16)?;
+            put_u32(
+                out,
+                if big_offset {
+                    u32::MAX
+                } else {
+                    e.offset as u32
+                },
+            )?;
+            out.write_all(e.name.as_bytes())?;
+            if !extra.is_empty() {
+                put_u16(out, 0x0001)?;
+                put_u16(out, extra.len() as u16)?;
+                out.write_all(&extra)?;
+            }
+        }
+        let cd_end = out.count;
+        let cd_size = cd_end - cd_start;
+        let count = self.entries.len() as u64;
+
+        let needs_zip64 =
+            count >= u16::MAX as u64 || cd_size >= u32::MAX as u64 || cd_start >= u32::MAX as u64;
+        if needs_zip64 {
+            put_u32(out, ZIP64_END_OF_CENTRAL_DIR)?;
+            put_u64(out, 44)?;
+            put_u16(out, VERSION_MADE_BY)?;
+            put_u16(out, VERSION_NEEDED_ZIP64)?;
+            put_u32(out, 0)?;
+            put_u32(out, 0)?;
+            put_u64(out, count)?;
+            put_u64(out, count)?;
+            put_u64(out, cd_size)?;
+            put_u64(out, cd_start)?;
+
+            put_u32(out, ZIP64_LOCATOR)?;
+            put_u32(out, 0)?;
+            put_u64(out, cd_end)?;
+            put_u32(out, 1)?;
+        }
+
+        put_u32(out, END_OF_CENTRAL_DIR)?;
+        put_u16(out, 0)?;
+        put_u16(out, 0)?;
+        put_u16(out, count.min(u16::MAX as u64) as u16)?;
+        put_u16(out, count.min(u16::MAX as u64) as u16)?;
+        put_u32(out, cd_size.min(u32::MAX as u64) as u32)?;
+        put_u32(out, cd_start.min(u32::MAX as u64) as u32)?;
+        put_u16(out, 0)?;
+        out.flush()?;
+        Ok(self.out.inner)
+    }
+}
+
+fn name_len(name: &str) -> io::Result<u16> {
+    u16::try_from(name.len())
+        .map_err(|_| io::Error::other(format!("path too long for zip: {}", name)))
+}
+
+fn put_u16(w: &mut impl Write, v: u16) -> io::Result<()> {
+    w.write_all(&v.to_le_bytes())
+}
+
+fn put_u32(w: &mut impl Write, v: u32) -> io::Result<()> {
+    w.write_all(&v.to_le_bytes())
+}
+
+fn put_u64(w: &mut impl Write, v: u64) -> io::Result<()> {
+    w.write_all(&v.to_le_bytes())
+}
+
+struct Counting<W> {
+    inner: W,
+    count: u64,
+}
+
+impl<W: Write> Write for Counting<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let n = self.inner.write(buf)?;
+        self.count += n as u64;
+        Ok(n)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+enum Sink<'a, W: Write> {
+    Stored(&'a mut Counting<W>),
+    Deflate(DeflateEncoder<&'a mut Counting<W>>),
+}
+
+/// Tracks CRC and uncompressed size of what passes through.
+struct Checksum<'a, W: Write> {
+    hasher: crc32fast::Hasher,
+    size: u64,
+    inner: Sink<'a, W>,
+}
+
+impl<W: Write> Write for Checksum<'_, W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let n = match &mut self.inner {
+            Sink::Stored(w) => w.write(buf)?,
+            Sink::Deflate(w) => w.write(buf)?,
+        };
+        self.hasher.update(&buf[..n]);
+        self.size += n as u64;
+        Ok(n)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        match &mut self.inner {
+            Sink::Stored(w) => w.flush(),
+            Sink::Deflate(w) => w.flush(),
+        }
+    }
+}
@@ -51,6 +51,9 @@ use self::handlers_publications::*;
 #[path = "converge_server/handlers_release/mod.rs"]
 mod handlers_release;
 use self::handlers_release::*;
+#[path = "converge_server/handlers_resolutions/mod.rs"]
+mod handlers_resolutions;
+use self::handlers_resolutions::*;
 #[path = "converge_server/handlers_gc/mod.rs"]
 mod handlers_gc;
 use self::handlers_gc::*;
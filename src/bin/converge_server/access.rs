@@ -1,21 +1,223 @@
 use super::*;
 
+/// What an access check is about; gate- and lane-narrowed grants only apply to their own target.
+/// `Repo` asks whether the subject holds the role anywhere in the repo.
+#[derive(Clone, Copy, Debug)]
+pub(super) enum Target<'a> {
+    Repo,
+    Gate(&'a str),
+    Lane(&'a str),
+}
+
+// The legacy `publish` role predates fine-grained roles and keeps everything it used to allow.
+const LEGACY_PUBLISH_ROLES: &[RepoRole] = &[
+    RepoRole::Publisher,
+    RepoRole::Converger,
+    RepoRole::Promoter,
+    RepoRole::Releaser,
+    RepoRole::Approver,
+];
+
 pub(super) fn can_read(repo: &Repo, subject: &Subject) -> bool {
+    subject.token_allows(&repo.id, TokenCapability::Read)
+        && (is_owner(repo, subject)
+            || repo.readers.contains(&subject.user)
+            || repo.reader_user_ids.contains(&subject.user_id)
+            || repo.role_grants.iter().any(|g| held_by(g, subject)))
+}
+
+/// Lane membership, directly or through a member group.
+pub(super) fn is_lane_member(lane: &Lane, subject: &Subject) -> bool {
+    lane.members.contains(&subject.user)
+        || lane.member_user_ids.contains(&subject.user_id)
+        || lane.member_group_ids.iter().any(|g| subject.in_group(g))
+}
+
+pub(super) fn can_publish(repo: &Repo, subject: &Subject, target: Target) -> bool {
+    subject.token_allows(&repo.id, TokenCapability::Publish)
+        && holds(repo, subject, &[RepoRole::Publisher], target)
+}
+
+/// Reporting CI results on a bundle: anyone who may publish at its gate, with a token that
+/// carries `report-checks`.
+pub(super) fn can_report_checks(repo: &Repo, subject: &Subject, target: Target) -> bool {
+    subject.token_allows(&repo.id, TokenCapability::ReportChecks)
+        && holds(repo, subject, &[RepoRole::Publisher], target)
+}
+
+pub(super) fn can_converge(repo: &Repo, subject: &Subject, target: Target) -> bool {
+    subject.token_allows(&repo.id, TokenCapability::Publish)
+        && in_owning_lane(repo, subject, target)
+        && holds(
+            repo,
+            subject,
+            gate_roles(repo, target, |r| &r.bundle, &[RepoRole::Converger]),
+            target,
+        )
+}
+
+pub(super) fn can_approve(repo: &Repo, subject: &Subject, target: Target) -> bool {
+    subject.token_allows(&repo.id, TokenCapability::Approve)
+        && in_owning_lane(repo, subject, target)
+        && holds(repo, subject, &[RepoRole::Approver], target)
+}
+
+pub(super) fn can_promote(repo: &Repo, subject: &Subject, target: Target) -> bool {
+    subject.token_allows(&repo.id, TokenCapability::Promote)
+        && in_owning_lane(repo, subject, target)
+        && holds(
+            repo,
+            subject,
+            gate_roles(repo, target, |r| &r.promote, &[RepoRole::Promoter]),
+            target,
+        )
+}
+
+pub(super) fn can_release(repo: &Repo, subject: &Subject, target: Target) -> bool {
+    subject.token_allows(&repo.id, TokenCapability::Release)
+        && holds(
+            repo,
+            subject,
+            gate_roles(repo, target, |r| &r.release, &[RepoRole::Releaser]),
+            target,
+        )
+}
+
+/// Repo administration (members, lanes, scopes, pins, GC) for owners and repo-wide maintainers;
+/// never granted to scoped tokens.
+pub(super) fn can_manage(repo: &Repo, subject: &Subject) -> bool {
+    !subject.is_scoped() && (subject.admin || is_maintainer(repo, subject))
+}
+
+/// Lane visibility: members and active subscribers see a lane; owners, maintainers and admins
+/// see every lane. A scope-limited subscription only covers `scope`, so it never covers heads.
+pub(super) fn can_see_lane(
+    repo: &Repo,
+    subject: &Subject,
+    lane: &Lane,
+    scope: Option<&str>,
+) -> bool {
+    if subject.admin || is_maintainer(repo, subject) || is_lane_member(lane, subject) {
+        return true;
+    }
+    let now = time::OffsetDateTime::now_utc();
+    repo.lane_subscriptions.iter().any(|s| {
+        s.lane == lane.id
+            && s.user_id == subject.user_id
+            && s.is_active(now)
+            && s.scope.as_deref().is_none_or(|s| Some(s) == scope)
+    })
+}
+
+/// Publications are visible to their publisher, to convergers at their gate, and to anyone who
+/// can see one of the lanes recorded on them. Publications that belong to no lane stay visible.
+pub(super) fn can_see_publication(
+    repo: &Repo,
+    subject: &Subject,
+    publication: &Publication,
+) -> bool {
+    let lanes = publication.lanes.as_deref().unwrap_or_default();
+    publication.publisher_user_id.as_ref() == Some(&subject.user_id)
+        || publication.publisher == subject.user
+        || lanes.is_empty()
+        || can_converge(repo, subject, Target::Gate(&publication.gate))
+        || lanes.iter().any(|l| {
+            repo.lanes
+                .get(l)
+                .is_some_and(|lane| can_see_lane(repo, subject, lane, Some(&publication.scope)))
+        })
+}
+
+fn is_maintainer(repo: &Repo, subject: &Subject) -> bool {
+    is_owner(repo, subject)
+        || repo.role_grants.iter().any(|g| {
+            held_by(g, subject)
+                && g.role == RepoRole::Maintainer
+                && g.gate.is_none()
+                && g.lane.is_none()
+        })
+}
+
+fn is_owner(repo: &Repo, subject: &Subject) -> bool {
     repo.owner == subject.user
-        || repo.readers.contains(&subject.user)
         || repo
             .owner_user_id
             .as_ref()
             .is_some_and(|u| u == &subject.user_id)
-        || repo.reader_user_ids.contains(&subject.user_id)
 }
 
-pub(super) fn can_publish(repo: &Repo, subject: &Subject) -> bool {
-    repo.owner == subject.user
-        || repo.publishers.contains(&subject.user)
+/// Whether the subject holds one of `roles` (or is a maintainer) for `target`.
+fn holds(repo: &Repo, subject: &Subject, roles: &[RepoRole], target: Target) -> bool {
+    if is_owner(repo, subject) {
+        return true;
+    }
+    let legacy_publisher = repo.publishers.contains(&subject.user)
+        || repo.publisher_user_ids.contains(&subject.user_id);
+    if legacy_publisher && roles.iter().any(|r| LEGACY_PUBLISH_ROLES.contains(r)) {
+        return true;
+    }
+    repo.role_grants.iter().any(|g| {
+        held_by(g, subject)
+            && (g.role == RepoRole::Maintainer || roles.contains(&g.role))
+            && grant_applies(g, target)
+    })
+}
+
+/// Whether the grant names the subject, or a group the subject is (transitively) in.
+fn held_by(grant: &RoleGrant, subject: &Subject) -> bool {
+    match &grant.group {
+        Some(group) => subject.in_group(group),
+        None => grant.user_id == subject.user_id,
+    }
+}
+
+fn grant_applies(grant: &RoleGrant, target: Target) -> bool {
+    match (grant.gate.as_deref(), grant.lane.as_deref(), target) {
+        (None, None, _) | (_, _, Target::Repo) => true,
+        (Some(g), _, Target::Gate(gate)) => g == gate,
+        (_, Some(l), Target::Lane(lane)) => l == lane,
+        _ => false,
+    }
+}
+
+/// A gate owned by a lane is operated by that lane's members (and admins) only, whatever roles
+/// others hold there.
+fn in_owning_lane(repo: &Repo, subject: &Subject, target: Target) -> bool {
+    let Target::Gate(gate) = target else {
+        return true;
+    };
+    let Some(lane) = repo
+        .gate_graph
+        .gates
+        .iter()
+        .find(|g| g.id == gate)
+        .and_then(|g| g.lane.as_ref())
+    else {
+        return true;
+    };
+    subject.admin
         || repo
-            .owner_user_id
-            .as_ref()
-            .is_some_and(|u| u == &subject.user_id)
-        || repo.publisher_user_ids.contains(&subject.user_id)
+            .lanes
+            .get(lane)
+            .is_some_and(|l| is_lane_member(l, subject))
 }
+
+/// Roles a gate accepts for an action, falling back to the action's default role.
+fn gate_roles<'a>(
+    repo: &'a Repo,
+    target: Target,
+    pick: impl Fn(&'a GateRoles) -> &'a Vec<RepoRole>,
+    default: &'a [RepoRole],
+) -> &'a [RepoRole] {
+    if let Target::Gate(gate) = target
+        && let Some(def) = repo.gate_graph.gates.iter().find(|g| g.id == gate)
+        && !pick(&def.roles).is_empty()
+    {
+        return pick(&def.roles);
+    }
+    default
+}
+
+#[cfg(test)]
+#[path = "../../tests/bin/converge_server/access_tests.rs"]
+mod tests;
@@ -0,0 +1,21 @@
+use super::*;
+
+/// Owning lanes must exist in the repo; this runs separately because it needs the repo's lanes.
+pub(crate) fn validate_gate_lanes(
+    graph: &GateGraph,
+    lanes: &HashMap<String, Lane>,
+) -> Vec<GateGraphIssue> {
+    graph
+        .gates
+        .iter()
+        .filter_map(|g| {
+            let lane = g.lane.as_ref()?;
+            (!lanes.contains_key(lane)).then(|| GateGraphIssue {
+                code: "unknown_lane".to_string(),
+                message: format!("gate {} is owned by unknown lane {}", g.id, lane),
+                gate: Some(g.id.clone()),
+                upstream: None,
+            })
+        })
+        .collect()
+}
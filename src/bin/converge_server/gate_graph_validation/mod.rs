@@ -1,11 +1,14 @@
-//! Gate graph structural validation (ID checks, cycles, and reachability).
+//! Gate graph structural validation (ID checks, cycles, and reachability) and lane ownership checks.
 
 use super::*;
 
 mod cycles;
+mod lanes;
 mod reachability;
 mod structural;
 
+pub(super) use self::lanes::validate_gate_lanes;
+
 #[derive(Clone, Debug, serde::Serialize)]
 pub(super) struct GateGraphIssue {
     code: String,
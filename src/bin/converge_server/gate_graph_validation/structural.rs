@@ -39,6 +39,14 @@ pub(super) fn run(graph: &GateGraph, issues: &mut Vec<GateGraphIssue>) -> Result
                 upstream: None,
             });
         }
+        if let Err(err) = converge::resolve::validate_resolution_rules(&g.resolution_rules) {
+            issues.push(GateGraphIssue {
+                code: "invalid_resolution_rule".to_string(),
+                message: format!("{:#}", err),
+                gate: Some(g.id.clone()),
+                upstream: None,
+            });
+        }
         if !ids.insert(g.id.clone()) {
             issues.push(GateGraphIssue {
                 code: "duplicate_gate_id".to_string(),
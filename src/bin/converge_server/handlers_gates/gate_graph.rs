@@ -44,11 +44,7 @@ pub(crate) async fn put_gate_graph(
 ) -> Result<Json<GateGraph>, Response> {
     let issues = validate_gate_graph_issues(&graph);
     if !issues.is_empty() {
-        return Err((
-            StatusCode::BAD_REQUEST,
-            Json(serde_json::json!({"error": "invalid gate graph", "issues": issues})),
-        )
-            .into_response());
+        return Err(invalid_gate_graph(issues));
     }
 
     let mut repos = state.repos.write().await;
@@ -56,8 +52,21 @@ pub(crate) async fn put_gate_graph(
     if !subject.admin {
         return Err(forbidden());
     }
+    let issues = validate_gate_lanes(&graph, &repo.lanes);
+    if !issues.is_empty() {
+        return Err(invalid_gate_graph(issues));
+    }
 
     repo.gate_graph = graph.clone();
+    sync_lane_gates(repo);
     persist_repo(state.as_ref(), repo).map_err(internal_error)?;
     Ok(Json(graph))
 }
+
+fn invalid_gate_graph(issues: Vec<GateGraphIssue>) -> Response {
+    (
+        StatusCode::BAD_REQUEST,
+        Json(serde_json::json!({"error": "invalid gate graph", "issues": issues})),
+    )
+        .into_response()
+}
@@ -10,7 +10,7 @@ pub(crate) async fn create_scope(
 
     let mut repos = state.repos.write().await;
     let repo = repos.get_mut(&repo_id).ok_or_else(not_found)?;
-    if !can_publish(repo, &subject) {
+    if !can_manage(repo, &subject) {
         return Err(forbidden());
     }
 
@@ -20,7 +20,7 @@ pub(super) async fn gc_repo(
 ) -> Result<Json<serde_json::Value>, Response> {
     let mut repos = state.repos.write().await;
     let repo = repos.get_mut(&repo_id).ok_or_else(not_found)?;
-    if !can_publish(repo, &subject) {
+    if !can_manage(repo, &subject) {
         return Err(forbidden());
     }
 
@@ -0,0 +1,25 @@
+use super::*;
+
+/// Release attachments are retained for as long as their release is.
+pub(super) fn collect_attachment_objects(
+    state: &AppState,
+    repo_id: &str,
+    repo: &Repo,
+    keep_blobs: &mut HashSet<String>,
+    keep_recipes: &mut HashSet<String>,
+) -> Result<(), Response> {
+    for attachment in repo.releases.iter().flat_map(|r| r.attachments.iter()) {
+        if let Some(blob) = &attachment.blob {
+            keep_blobs.insert(blob.clone());
+        }
+        if let Some(recipe_id) = &attachment.recipe
+            && keep_recipes.insert(recipe_id.clone())
+        {
+            let recipe = read_recipe(state, repo_id, recipe_id)?;
+            for chunk in recipe.chunks {
+                keep_blobs.insert(chunk.blob.0);
+            }
+        }
+    }
+    Ok(())
+}
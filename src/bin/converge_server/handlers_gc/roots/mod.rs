@@ -1,5 +1,6 @@
 use super::*;
 
+mod attachment_refs;
 mod bundle_refs;
 mod snap_refs;
 
@@ -22,8 +23,15 @@ pub(super) fn collect_retained_roots(
         bundle_refs::collect_bundle_roots_and_publications(state, repo_id, repo, &keep_bundles)?;
 
     let keep_snaps = snap_refs::collect_snap_ids(repo, &keep_publications);
-    let (keep_blobs, keep_manifests, keep_recipes) =
+    let (mut keep_blobs, keep_manifests, mut keep_recipes) =
         snap_refs::collect_tree_objects(state, repo_id, &bundle_roots, &keep_snaps)?;
+    attachment_refs::collect_attachment_objects(
+        state,
+        repo_id,
+        repo,
+        &mut keep_blobs,
+        &mut keep_recipes,
+    )?;
 
     Ok(RetainedRoots {
         keep_bundles,
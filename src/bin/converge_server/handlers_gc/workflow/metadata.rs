@@ -17,6 +17,16 @@ pub(super) fn prune_repo_metadata(
         .retain(|bundle_id| retained.keep_bundles.contains(bundle_id));
     repo.releases
         .retain(|r| retained.keep_bundles.contains(&r.bundle_id));
+    // Records stay alive while a kept publication still carries a resolution for the bundle.
+    let resolved_bundles: HashSet<String> = repo
+        .publications
+        .iter()
+        .filter(|p| retained.keep_publications.contains(&p.id))
+        .filter_map(|p| p.resolution.as_ref().map(|r| r.bundle_id.clone()))
+        .collect();
+    repo.resolutions.retain(|bundle_id, _| {
+        retained.keep_bundles.contains(bundle_id) || resolved_bundles.contains(bundle_id)
+    });
     repo.publications
         .retain(|p| retained.keep_publications.contains(&p.id));
     repo.snaps = retained.keep_snaps.clone();
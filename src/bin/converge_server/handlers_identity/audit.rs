@@ -0,0 +1,38 @@
+use super::*;
+
+#[derive(Debug, Default, serde::Deserialize)]
+pub(crate) struct AuditQuery {
+    #[serde(default)]
+    limit: Option<usize>,
+}
+
+pub(crate) async fn list_audit(
+    State(state): State<Arc<AppState>>,
+    Extension(subject): Extension<Subject>,
+    Query(query): Query<AuditQuery>,
+) -> Result<Json<Vec<AuditEntry>>, Response> {
+    if !subject.admin {
+        return Err(forbidden());
+    }
+    let limit = query.limit.unwrap_or(100);
+    let entries = read_audit(&state.data_dir, limit).map_err(internal_error)?;
+    Ok(Json(entries))
+}
+
+/// Records an identity or membership change made by `subject`.
+pub(crate) fn record_audit(
+    state: &AppState,
+    subject: &Subject,
+    action: &str,
+    target: String,
+    detail: Option<String>,
+) -> Result<(), Response> {
+    let entry = AuditEntry {
+        at: now_ts(),
+        actor: subject.user.clone(),
+        action: action.to_string(),
+        target,
+        detail,
+    };
+    append_audit(&state.data_dir, &entry).map_err(internal_error)
+}
@@ -0,0 +1,286 @@
+use super::*;
+
+/// A group as shown to clients: members by handle, nested groups by name.
+#[derive(Debug, serde::Serialize)]
+pub(crate) struct GroupView {
+    id: String,
+    name: String,
+    description: Option<String>,
+    members: Vec<String>,
+    groups: Vec<String>,
+    created_at: String,
+}
+
+#[derive(Debug, serde::Deserialize)]
+pub(crate) struct CreateGroupRequest {
+    name: String,
+
+    #[serde(default)]
+    description: Option<String>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+pub(crate) struct GroupMemberRequest {
+    /// A user handle, or `@<group>` to nest a group.
+    handle: String,
+}
+
+pub(crate) async fn list_groups(
+    State(state): State<Arc<AppState>>,
+    Extension(subject): Extension<Subject>,
+) -> Result<Json<Vec<GroupView>>, Response> {
+    if subject.is_scoped() {
+        return Err(forbidden());
+    }
+    let groups = state.groups.read().await;
+    let users = state.users.read().await;
+    let mut out: Vec<GroupView> = groups
+        .values()
+        .map(|g| group_view(g, &groups, &users))
+        .collect();
+    out.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(Json(out))
+}
+
+pub(crate) async fn get_group(
+    State(state): State<Arc<AppState>>,
+    Extension(subject): Extension<Subject>,
+    Path(name): Path<String>,
+) -> Result<Json<GroupView>, Response> {
+    if subject.is_scoped() {
+        return Err(forbidden());
+    }
+    let groups = state.groups.read().await;
+    let users = state.users.read().await;
+    let group = find_group(&groups, &name).ok_or_else(not_found)?;
+    Ok(Json(group_view(group, &groups, &users)))
+}
+
+pub(crate) async fn create_group(
+    State(state): State<Arc<AppState>>,
+    Extension(subject): Extension<Subject>,
+    Json(payload): Json<CreateGroupRequest>,
+) -> Result<Json<GroupView>, Response> {
+    if !subject.admin {
+        return Err(forbidden());
+    }
+    validate_group_name(&payload.name).map_err(bad_request)?;
+
+    let group = Group {
+        id: generate_token_secret().map_err(internal_error)?,
+        name: payload.name,
+        description: payload.description,
+        member_user_ids: Vec::new(),
+        member_group_ids: Vec::new(),
+        created_at: now_ts(),
+    };
+
+    let view = {
+        let mut groups = state.groups.write().await;
+        if find_group(&groups, &group.name).is_some() {
+            return Err(conflict("group name already exists"));
+        }
+        groups.insert(group.id.clone(), group.clone());
+        persist_groups_to_disk(&state.data_dir, &groups).map_err(internal_error)?;
+        let users = state.users.read().await;
+        group_view(&group, &groups, &users)
+    };
+
+    record_audit(
+        &state,
+        &subject,
+        "group.create",
+        format!("@{}", group.name),
+        None,
+    )?;
+    Ok(Json(view))
+}
+
+pub(crate) async fn delete_group(
+    State(state): State<Arc<AppState>>,
+    Extension(subject): Extension<Subject>,
+    Path(name): Path<String>,
+) -> Result<Json<serde_json::Value>, Response> {
+    if !subject.admin {
+        return Err(forbidden());
+    }
+
+    let group_id = {
+        let mut groups = state.groups.write().await;
+        let id = find_group(&groups, &name).ok_or_else(not_found)?.id.clone();
+        groups.remove(&id);
+        for g in groups.values_mut() {
+            g.member_group_ids.retain(|m| m != &id);
+        }
+        persist_groups_to_disk(&state.data_dir, &groups).map_err(internal_error)?;
+        id
+    };
+
+    // Drop the group's repo roles and lane memberships so a recreated group starts clean.
+    {
+        let mut repos = state.repos.write().await;
+        for repo in repos.values_mut() {
+            let before = repo.role_grants.len();
+            repo.role_grants
+                .retain(|g| g.group.as_deref() != Some(group_id.as_str()));
+            let mut changed = repo.role_grants.len() != before;
+            for lane in repo.lanes.values_mut() {
+                changed |= lane.member_group_ids.remove(&group_id);
+            }
+            if changed {
+                persist_repo(state.as_ref(), repo).map_err(internal_error)?;
+            }
+        }
+    }
+
+    record_audit(&state, &subject, "group.delete", format!("@{}", name), None)?;
+    Ok(Json(serde_json::json!({"ok": true})))
+}
+
+pub(crate) async fn add_group_member(
+    State(state): State<Arc<AppState>>,
+    Extension(subject): Extension<Subject>,
+    Path(name): Path<String>,
+    Json(payload): Json<GroupMemberRequest>,
+) -> Result<Json<GroupView>, Response> {
+    if !subject.admin {
+        return Err(forbidden());
+    }
+
+    let view = {
+        let mut groups = state.groups.write().await;
+        let users = state.users.read().await;
+        let id = find_group(&groups, &name).ok_or_else(not_found)?.id.clone();
+
+        if let Some(member) = payload.handle.strip_prefix('@') {
+            let member_id = find_group(&groups, member)
+                .ok_or_else(|| bad_request(anyhow::anyhow!("unknown group @{}", member)))?
+                .id
+                .clone();
+            if group_contains(&groups, &member_id, &id) {
+                return Err(bad_request(anyhow::anyhow!(
+                    "@{} already contains @{}; groups cannot form a cycle",
+                    member,
+                    name
+                )));
+            }
+            let group = groups.get_mut(&id).ok_or_else(not_found)?;
+            if !group.member_group_ids.contains(&member_id) {
+                group.member_group_ids.push(member_id);
+            }
+        } else {
+            validate_user_handle(&payload.handle).map_err(bad_request)?;
+            let user_id = users
+                .values()
+                .find(|u| u.handle == payload.handle)
+                .map(|u| u.id.clone())
+                .ok_or_else(|| bad_request(anyhow::anyhow!("unknown user handle")))?;
+            let group = groups.get_mut(&id).ok_or_else(not_found)?;
+            if !group.member_user_ids.contains(&user_id) {
+                group.member_user_ids.push(user_id);
+            }
+        }
+
+        persist_groups_to_disk(&state.data_dir, &groups).map_err(internal_error)?;
+        let group = groups.get(&id).ok_or_else(not_found)?;
+        group_view(group, &groups, &users)
+    };
+
+    record_audit(
+        &state,
+        &subject,
+        "group.member_add",
+        format!("@{}", name),
+        Some(payload.handle),
+    )?;
+    Ok(Json(view))
+}
+
+pub(crate) async fn remove_group_member(
+    State(state): State<Arc<AppState>>,
+    Extension(subject): Extension<Subject>,
+    Path((name, handle)): Path<(String, String)>,
+) -> Result<Json<GroupView>, Response> {
+    if !subject.admin {
+        return Err(forbidden());
+    }
+
+    let view = {
+        let mut groups = state.groups.write().await;
+        let users = state.users.read().await;
+        let id = find_group(&groups, &name).ok_or_else(not_found)?.id.clone();
+
+        let removed = if let Some(member) = handle.strip_prefix('@') {
+            let member_id = find_group(&groups, member).map(|g| g.id.clone());
+            let group = groups.get_mut(&id).ok_or_else(not_found)?;
+            let before = group.member_group_ids.len();
+            group
+                .member_group_ids
+                .retain(|m| Some(m) != member_id.as_ref());
+            group.member_group_ids.len() != before
+        } else {
+            let user_id = users
+                .values()
+                .find(|u| u.handle == handle)
+                .map(|u| u.id.clone());
+            let group = groups.get_mut(&id).ok_or_else(not_found)?;
+            let before = group.member_user_ids.len();
+            group
+                .member_user_ids
+                .retain(|m| Some(m) != user_id.as_ref());
+            group.member_user_ids.len() != before
+        };
+        if !removed {
+            return Err(not_found());
+        }
+
+        persist_groups_to_disk(&state.data_dir, &groups).map_err(internal_error)?;
+        let group = groups.get(&id).ok_or_else(not_found)?;
+        group_view(group, &groups, &users)
+    };
+
+    record_audit(
+        &state,
+        &subject,
+        "group.member_remove",
+        format!("@{}", name),
+        Some(handle),
+    )?;
+    Ok(Json(view))
+}
+
+pub(crate) fn find_group<'a>(groups: &'a HashMap<String, Group>, name: &str) -> Option<&'a Group> {
+    groups.values().find(|g| g.name == name)
+}
+
+fn validate_group_name(name: &str) -> Result<()> {
+    validate_user_handle(name)
+        .map_err(|_| anyhow::anyhow!("group name must be non-empty lowercase alnum or '-'"))
+}
+
+fn group_view(
+    group: &Group,
+    groups: &HashMap<String, Group>,
+    users: &HashMap<String, User>,
+) -> GroupView {
+    let mut members: Vec<String> = group
+        .member_user_ids
+        .iter()
+        .filter_map(|id| users.get(id).map(|u| u.handle.clone()))
+        .collect();
+    members.sort();
+    let mut nested: Vec<String> = group
+        .member_group_ids
+        .iter()
+        .filter_map(|id| groups.get(id).map(|g| g.name.clone()))
+        .collect();
+    nested.sort();
+    GroupView {
+        id: group.id.clone(),
+        name: group.name.clone(),
+        description: group.description.clone(),
+        members,
+        groups: nested,
+        created_at: group.created_at.clone(),
+    }
+}
@@ -0,0 +1,159 @@
+use super::*;
+
+mod verify;
+
+pub(crate) use self::verify::{
+    approval_count, check_signature, known_signing_keys, unsigned_inputs,
+};
+
+#[derive(Debug, serde::Serialize)]
+pub(crate) struct SigningKeyView {
+    id: String,
+    user: String,
+    public_key: String,
+    label: Option<String>,
+    created_at: String,
+    revoked_at: Option<String>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+pub(crate) struct ListKeysQuery {
+    #[serde(default)]
+    user: Option<String>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+pub(crate) struct RegisterKeyRequest {
+    public_key: String,
+
+    /// Hex signature over `key_registration_payload(user_id, public_key)` made with the key.
+    proof: String,
+
+    #[serde(default)]
+    label: Option<String>,
+}
+
+fn to_key_view(user: &User, key: &SigningKeyRecord) -> SigningKeyView {
+    SigningKeyView {
+        id: key.id.clone(),
+        user: user.handle.clone(),
+        public_key: key.public_key.clone(),
+        label: key.label.clone(),
+        created_at: key.created_at.clone(),
+        revoked_at: key.revoked_at.clone(),
+    }
+}
+
+/// Public keys are readable by any authenticated user so signatures can be checked client-side.
+pub(crate) async fn list_keys(
+    State(state): State<Arc<AppState>>,
+    Query(q): Query<ListKeysQuery>,
+) -> Result<Json<Vec<SigningKeyView>>, Response> {
+    let users = state.users.read().await;
+    let mut out = Vec::new();
+    for user in users.values() {
+        if q.user.as_deref().is_some_and(|h| h != user.handle) {
+            continue;
+        }
+        out.extend(user.signing_keys.iter().map(|k| to_key_view(user, k)));
+    }
+    out.sort_by(|a, b| (&a.user, &a.created_at).cmp(&(&b.user, &b.created_at)));
+    Ok(Json(out))
+}
+
+pub(crate) async fn register_key(
+    State(state): State<Arc<AppState>>,
+    Extension(subject): Extension<Subject>,
+    Json(payload): Json<RegisterKeyRequest>,
+) -> Result<Json<SigningKeyView>, Response> {
+    if subject.is_scoped() {
+        return Err(forbidden());
+    }
+    let key_id = converge::signing::public_key_id(&payload.public_key).map_err(bad_request)?;
+    // Without proof of possession a user could claim someone else's key and be credited as
+    // the signer of their work.
+    let proof = converge::signing::Signature {
+        key_id: key_id.clone(),
+        signer: String::new(),
+        signature: payload.proof,
+        signed_at: None,
+    };
+    converge::signing::verify(
+        &payload.public_key,
+        &proof,
+        &converge::signing::key_registration_payload(&subject.user_id, &payload.public_key),
+    )
+    .map_err(|e| bad_request(e.context("key registration proof")))?;
+
+    let view = {
+        let mut users = state.users.write().await;
+        if users
+            .values()
+            .any(|u| u.signing_keys.iter().any(|k| k.id == key_id))
+        {
+            return Err(conflict("signing key already registered"));
+        }
+        let user = users.get_mut(&subject.user_id).ok_or_else(not_found)?;
+        let record = SigningKeyRecord {
+            id: key_id,
+            public_key: payload.public_key.to_ascii_lowercase(),
+            label: payload.label,
+            created_at: now_ts(),
+            revoked_at: None,
+        };
+        user.signing_keys.push(record.clone());
+        to_key_view(user, &record)
+    };
+
+    {
+        let users = state.users.read().await;
+        let tokens = state.tokens.read().await;
+        if let Err(err) = persist_identity_to_disk(&state.data_dir, &users, &tokens) {
+            return Err(internal_error(err));
+        }
+    }
+
+    Ok(Json(view))
+}
+
+pub(crate) async fn revoke_key(
+    State(state): State<Arc<AppState>>,
+    Extension(subject): Extension<Subject>,
+    Path(key_id): Path<String>,
+) -> Result<Json<SigningKeyView>, Response> {
+    if subject.is_scoped() {
+        return Err(forbidden());
+    }
+    let view = {
+        let mut users = state.users.write().await;
+        let Some(user) = users
+            .values_mut()
+            .find(|u| u.signing_keys.iter().any(|k| k.id == key_id))
+        else {
+            return Err(not_found());
+        };
+        if user.id != subject.user_id && !subject.admin {
+            return Err(forbidden());
+        }
+        let user_snapshot = user.clone();
+        let key = user
+            .signing_keys
+            .iter_mut()
+            .find(|k| k.id == key_id)
+            .ok_or_else(not_found)?;
+        if key.revoked_at.is_none() {
+            key.revoked_at = Some(now_ts());
+        }
+        to_key_view(&user_snapshot, key)
+    };
+
+    {
+        let users = state.users.read().await;
+        let tokens = state.tokens.read().await;
+        if let Err(err) = persist_identity_to_disk(&state.data_dir, &users, &tokens) {
+            return Err(internal_error(err));
+        }
+    }
+
+    Ok(Json(view))
+}
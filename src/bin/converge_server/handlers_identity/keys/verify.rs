@@ -0,0 +1,86 @@
+use super::*;
+
+use converge::signing::Signature;
+
+/// Owner and state of a registered key, snapshotted so checks can run under the repo lock.
+#[derive(Clone, Debug)]
+pub(crate) struct KnownKey {
+    pub(crate) user_id: String,
+    pub(crate) handle: String,
+    pub(crate) public_key: String,
+    pub(crate) revoked: bool,
+}
+
+pub(crate) async fn known_signing_keys(state: &AppState) -> HashMap<String, KnownKey> {
+    let users = state.users.read().await;
+    let mut out = HashMap::new();
+    for user in users.values() {
+        for key in &user.signing_keys {
+            out.insert(
+                key.id.clone(),
+                KnownKey {
+                    user_id: user.id.clone(),
+                    handle: user.handle.clone(),
+                    public_key: key.public_key.clone(),
+                    revoked: key.revoked_at.is_some(),
+                },
+            );
+        }
+    }
+    out
+}
+
+/// Verifies a client-supplied signature over `payload` and stamps the key owner as signer.
+///
+/// With `owner`, the key must belong to that subject (publications, approvals, releases are
+/// signed by whoever makes the request); without it any registered key is accepted (snaps).
+pub(crate) fn check_signature(
+    keys: &HashMap<String, KnownKey>,
+    signature: Option<Signature>,
+    payload: &[u8],
+    owner: Option<&Subject>,
+) -> Result<Option<Signature>, Response> {
+    let Some(mut signature) = signature else {
+        return Ok(None);
+    };
+    let Some(key) = keys.get(&signature.key_id) else {
+        return Err(bad_request(anyhow::anyhow!(
+            "unknown signing key {} (register it with `converge key register`)",
+            signature.key_id
+        )));
+    };
+    if key.revoked {
+        return Err(bad_request(anyhow::anyhow!(
+            "signing key {} is revoked",
+            signature.key_id
+        )));
+    }
+    if owner.is_some_and(|s| s.user_id != key.user_id) {
+        return Err(forbidden());
+    }
+    converge::signing::verify(&key.public_key, &signature, payload).map_err(bad_request)?;
+    signature.signer = key.handle.clone();
+    signature.signed_at = Some(now_ts());
+    Ok(Some(signature))
+}
+
+/// Approvals that count toward `required_approvals`: all of them, or only signed ones when the
+/// gate requires signatures.
+pub(crate) fn approval_count(gate: &GateDef, bundle: &Bundle) -> usize {
+    if gate.require_signed {
+        bundle.approval_signatures.len()
+    } else {
+        bundle.approvals.len()
+    }
+}
+
+/// True when the gate requires signatures and some input publication (or its snap) is unsigned.
+pub(crate) fn unsigned_inputs(repo: &Repo, gate: &GateDef, input_publications: &[String]) -> bool {
+    gate.require_signed
+        && input_publications.iter().any(|pid| {
+            repo.publications
+                .iter()
+                .find(|p| &p.id == pid)
+                .is_none_or(|p| p.signature.is_none() || p.snap_signature.is_none())
+        })
+}
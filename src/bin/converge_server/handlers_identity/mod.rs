@@ -1,9 +1,21 @@
 use super::*;
 
+mod audit;
+mod groups;
+mod keys;
 mod profile;
 mod tokens;
 mod users;
 
+pub(crate) use self::audit::{list_audit, record_audit};
+pub(crate) use self::groups::{
+    add_group_member, create_group, delete_group, find_group, get_group, list_groups,
+    remove_group_member,
+};
+pub(crate) use self::keys::{
+    approval_count, check_signature, known_signing_keys, list_keys, register_key, revoke_key,
+    unsigned_inputs,
+};
 pub(crate) use self::profile::whoami;
 pub(crate) use self::tokens::{
     CreateTokenResponse, create_token, create_token_for_user, list_tokens, revoke_token,
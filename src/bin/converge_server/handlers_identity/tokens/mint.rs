@@ -1,12 +1,29 @@
-use super::types::CreateTokenResponse;
+use super::types::{CreateTokenRequest, CreateTokenResponse};
 use super::*;
 
 pub(super) async fn mint_token(
     state: &Arc<AppState>,
     user_id: &str,
-    label: Option<String>,
+    request: CreateTokenRequest,
 ) -> Result<CreateTokenResponse, Response> {
+    validate_token_scope(state, &request).await?;
+
+    let now = time::OffsetDateTime::now_utc();
     let created_at = now_ts();
+    let expires_at = match request.expires_in_secs {
+        Some(secs) => {
+            let secs = i64::try_from(secs)
+                .map_err(|_| bad_request(anyhow::anyhow!("expiry is too far away")))?;
+            let at = now
+                .checked_add(time::Duration::seconds(secs))
+                .ok_or_else(|| bad_request(anyhow::anyhow!("expiry is too far away")))?;
+            Some(
+                at.format(&time::format_description::well_known::Rfc3339)
+                    .map_err(|e| internal_error(anyhow::anyhow!(e)))?,
+            )
+        }
+        None => None,
+    };
 
     let token = generate_token_secret().map_err(internal_error)?;
     let token_hash = hash_token(&token);
@@ -28,11 +45,13 @@ pub(super) async fn mint_token(
                 id: token_id.clone(),
                 user_id: user_id.to_string(),
                 token_hash: token_hash.clone(),
-                label,
+                label: request.label,
                 created_at: created_at.clone(),
                 last_used_at: None,
                 revoked_at: None,
-                expires_at: None,
+                expires_at,
+                repos: request.repos,
+                capabilities: request.capabilities,
             },
         );
     }
@@ -50,6 +69,32 @@ pub(super) async fn mint_token(
     })
 }
 
+async fn validate_token_scope(
+    state: &Arc<AppState>,
+    request: &CreateTokenRequest,
+) -> Result<(), Response> {
+    if request.expires_in_secs == Some(0) {
+        return Err(bad_request(anyhow::anyhow!("expiry must be in the future")));
+    }
+    if request.capabilities.as_ref().is_some_and(|c| c.is_empty()) {
+        return Err(bad_request(anyhow::anyhow!(
+            "capability allowlist must not be empty"
+        )));
+    }
+    if let Some(repo_ids) = &request.repos {
+        if repo_ids.is_empty() {
+            return Err(bad_request(anyhow::anyhow!(
+                "repo allowlist must not be empty"
+            )));
+        }
+        let repos = state.repos.read().await;
+        if let Some(missing) = repo_ids.iter().find(|r| !repos.contains_key(*r)) {
+            return Err(bad_request(anyhow::anyhow!("unknown repo: {}", missing)));
+        }
+    }
+    Ok(())
+}
+
 async fn persist_identity(state: &Arc<AppState>) -> Result<(), Response> {
     let users = state.users.read().await;
     let tokens = state.tokens.read().await;
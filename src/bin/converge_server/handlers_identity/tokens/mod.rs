@@ -21,7 +21,11 @@ pub(crate) async fn create_token(
     Extension(subject): Extension<Subject>,
     Json(payload): Json<CreateTokenRequest>,
 ) -> Result<Json<CreateTokenResponse>, Response> {
-    let out = mint::mint_token(&state, &subject.user_id, payload.label).await?;
+    // A scoped token must not be able to mint itself a broader one.
+    if subject.is_scoped() {
+        return Err(forbidden());
+    }
+    let out = mint::mint_token(&state, &subject.user_id, payload).await?;
     Ok(Json(out))
 }
 
@@ -31,7 +35,7 @@ pub(crate) async fn create_token_for_user(
     Path(user_id): Path<String>,
     Json(payload): Json<CreateTokenRequest>,
 ) -> Result<Json<CreateTokenResponse>, Response> {
-    if !subject.admin && subject.user_id != user_id {
+    if subject.is_scoped() || (!subject.admin && subject.user_id != user_id) {
         return Err(forbidden());
     }
     {
@@ -40,7 +44,7 @@ pub(crate) async fn create_token_for_user(
             return Err(not_found());
         }
     }
-    let out = mint::mint_token(&state, &user_id, payload.label).await?;
+    let out = mint::mint_token(&state, &user_id, payload).await?;
     Ok(Json(out))
 }
 
@@ -49,5 +53,8 @@ pub(crate) async fn revoke_token(
     Extension(subject): Extension<Subject>,
     Path(token_id): Path<String>,
 ) -> Result<Json<serde_json::Value>, Response> {
+    if subject.is_scoped() {
+        return Err(forbidden());
+    }
     revoke::revoke_token(&state, &subject, &token_id).await
 }
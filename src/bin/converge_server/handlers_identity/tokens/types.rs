@@ -8,12 +8,29 @@ pub(crate) struct TokenView {
     pub(super) last_used_at: Option<String>,
     pub(super) revoked_at: Option<String>,
     pub(super) expires_at: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(super) repos: Option<Vec<String>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(super) capabilities: Option<Vec<TokenCapability>>,
 }
 
 #[derive(Debug, serde::Deserialize)]
 pub(crate) struct CreateTokenRequest {
     #[serde(default)]
     pub(super) label: Option<String>,
+
+    /// Limit the token to these repo ids.
+    #[serde(default)]
+    pub(super) repos: Option<Vec<String>>,
+
+    /// Limit the token to these capabilities.
+    #[serde(default)]
+    pub(super) capabilities: Option<Vec<TokenCapability>>,
+
+    #[serde(default)]
+    pub(super) expires_in_secs: Option<u64>,
 }
 
 #[derive(Debug, serde::Serialize)]
@@ -31,5 +48,7 @@ pub(super) fn to_token_view(token: &AccessToken) -> TokenView {
         last_used_at: token.last_used_at.clone(),
         revoked_at: token.revoked_at.clone(),
         expires_at: token.expires_at.clone(),
+        repos: token.repos.clone(),
+        capabilities: token.capabilities.clone(),
     }
 }
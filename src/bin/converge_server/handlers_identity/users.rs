@@ -42,6 +42,7 @@ pub(crate) async fn create_user(
         display_name: payload.display_name,
         admin: payload.admin,
         created_at,
+        signing_keys: Vec::new(),
     };
 
     {
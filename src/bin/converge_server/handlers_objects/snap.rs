@@ -11,7 +11,7 @@ pub(crate) async fn put_snap(
     {
         let repos = state.repos.read().await;
         let repo = repos.get(&repo_id).ok_or_else(not_found)?;
-        if !can_publish(repo, &subject) {
+        if !can_publish(repo, &subject, Target::Repo) {
             return Err(forbidden());
         }
     }
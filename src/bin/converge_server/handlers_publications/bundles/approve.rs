@@ -1,15 +1,24 @@
 use super::*;
 
+#[derive(Debug, Default, serde::Deserialize)]
+pub(crate) struct ApproveBundleRequest {
+    #[serde(default)]
+    signature: Option<converge::signing::Signature>,
+}
+
 pub(crate) async fn approve_bundle(
     State(state): State<Arc<AppState>>,
     Extension(subject): Extension<Subject>,
     Path((repo_id, bundle_id)): Path<(String, String)>,
+    payload: Option<Json<ApproveBundleRequest>>,
 ) -> Result<Json<Bundle>, Response> {
     validate_object_id(&bundle_id).map_err(bad_request)?;
+    let signature = payload.and_then(|Json(p)| p.signature);
+    let keys = known_signing_keys(state.as_ref()).await;
 
     let mut repos = state.repos.write().await;
     let repo = repos.get_mut(&repo_id).ok_or_else(not_found)?;
-    if !can_publish(repo, &subject) {
+    if !can_approve(repo, &subject, Target::Repo) {
         return Err(forbidden());
     }
 
@@ -19,6 +28,16 @@ pub(crate) async fn approve_bundle(
     } else {
         load_bundle_from_disk(state.as_ref(), &repo_id, &bundle_id)?
     };
+    if !can_approve(repo, &subject, Target::Gate(&bundle.gate)) {
+        return Err(forbidden());
+    }
+
+    let signature = check_signature(
+        &keys,
+        signature,
+        &converge::signing::approval_payload(&repo_id, &bundle.id, &bundle.root_manifest),
+        Some(&subject),
+    )?;
 
     if !bundle.approvals.contains(&subject.user) {
         bundle.approvals.push(subject.user.clone());
@@ -42,11 +61,35 @@ pub(crate) async fn approve_bundle(
         .iter()
         .find(|g| g.id == bundle.gate)
         .ok_or_else(|| internal_error(anyhow::anyhow!("bundle gate not found")))?;
+    if gate_def.require_signed && signature.is_none() {
+        return Err(bad_request(anyhow::anyhow!(
+            "gate {} requires signed approvals",
+            bundle.gate
+        )));
+    }
+    if let Some(signature) = signature {
+        // Re-approving replaces the approver's earlier signature.
+        bundle
+            .approval_signatures
+            .retain(|s| s.signer != signature.signer);
+        bundle.approval_signatures.push(signature);
+        bundle
+            .approval_signatures
+            .sort_by(|a, b| a.signer.cmp(&b.signer));
+    }
 
     let has_superpositions =
         manifest_has_superpositions(state.as_ref(), &repo_id, &bundle.root_manifest)?;
-    let (promotable, reasons) =
-        compute_promotability(gate_def, has_superpositions, bundle.approvals.len());
+    let resolutions_open =
+        resolution_inputs_open(state.as_ref(), repo, &bundle.input_publications)?;
+    let (promotable, reasons) = compute_promotability(
+        gate_def,
+        has_superpositions,
+        approval_count(gate_def, &bundle),
+        resolutions_open,
+        unsigned_inputs(repo, gate_def, &bundle.input_publications),
+        tree_rules_invalid(state.as_ref(), &repo_id, &bundle.root_manifest)?,
+    );
     bundle.promotable = promotable;
     bundle.reasons = reasons;
 
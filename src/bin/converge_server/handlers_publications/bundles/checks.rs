@@ -0,0 +1,67 @@
+use super::*;
+
+#[derive(Debug, serde::Deserialize)]
+pub(crate) struct ReportCheckRequest {
+    name: String,
+    status: CheckStatus,
+
+    #[serde(default)]
+    summary: Option<String>,
+
+    #[serde(default)]
+    url: Option<String>,
+}
+
+/// Records a check result on a bundle, replacing any earlier report under the same name.
+pub(crate) async fn report_check(
+    State(state): State<Arc<AppState>>,
+    Extension(subject): Extension<Subject>,
+    Path((repo_id, bundle_id)): Path<(String, String)>,
+    Json(payload): Json<ReportCheckRequest>,
+) -> Result<Json<Bundle>, Response> {
+    validate_object_id(&bundle_id).map_err(bad_request)?;
+    validate_check_name(&payload.name).map_err(bad_request)?;
+
+    let mut repos = state.repos.write().await;
+    let repo = repos.get_mut(&repo_id).ok_or_else(not_found)?;
+    if !can_read(repo, &subject) {
+        return Err(forbidden());
+    }
+
+    let mut bundle = if let Some(b) = repo.bundles.iter().find(|b| b.id == bundle_id) {
+        b.clone()
+    } else {
+        load_bundle_from_disk(state.as_ref(), &repo_id, &bundle_id)?
+    };
+    if !can_report_checks(repo, &subject, Target::Gate(&bundle.gate)) {
+        return Err(forbidden());
+    }
+
+    bundle.checks.retain(|c| c.name != payload.name);
+    bundle.checks.push(CheckReport {
+        name: payload.name,
+        status: payload.status,
+        summary: payload.summary,
+        url: payload.url,
+        reported_by: subject.user.clone(),
+        reported_by_user_id: subject.user_id.clone(),
+        reported_at: now_ts(),
+    });
+    bundle.checks.sort_by(|a, b| a.name.cmp(&b.name));
+
+    let bytes =
+        serde_json::to_vec_pretty(&bundle).map_err(|e| internal_error(anyhow::anyhow!(e)))?;
+    let path = repo_data_dir(state.as_ref(), &repo_id)
+        .join("bundles")
+        .join(format!("{}.json", bundle.id));
+    write_atomic_overwrite(&path, &bytes).map_err(internal_error)?;
+
+    if let Some(existing) = repo.bundles.iter_mut().find(|b| b.id == bundle.id) {
+        *existing = bundle.clone();
+    } else {
+        repo.bundles.push(bundle.clone());
+    }
+    persist_repo(state.as_ref(), repo).map_err(internal_error)?;
+
+    Ok(Json(bundle))
+}
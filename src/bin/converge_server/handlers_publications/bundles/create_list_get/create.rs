@@ -1,7 +1,8 @@
 use super::super::super::super::*;
 
 use super::create_helpers::{
-    build_bundle_id, normalize_input_publications, now_rfc3339, validate_bundle_create_input,
+    build_bundle_id, merge_base_root, normalize_input_publications, now_rfc3339,
+    validate_bundle_create_input, variant_provenance,
 };
 use super::types::CreateBundleRequest;
 
@@ -17,7 +18,7 @@ pub(super) async fn create_bundle(
 
     let mut repos = state.repos.write().await;
     let repo = repos.get_mut(&repo_id).ok_or_else(not_found)?;
-    if !can_publish(repo, &subject) {
+    if !can_converge(repo, &subject, Target::Gate(&payload.gate)) {
         return Err(forbidden());
     }
     if !repo.scopes.contains(&payload.scope) {
@@ -27,8 +28,10 @@ pub(super) async fn create_bundle(
         return Err(bad_request(anyhow::anyhow!("unknown gate")));
     }
 
-    // Resolve and validate publication ids; gather input snap roots.
+    // Resolve and validate publication ids; gather input snap roots and variant provenance.
+    let origins = rule_origins(repo, &input_publications);
     let mut input_roots: Vec<(String, String)> = Vec::new();
+    let mut provenance = VariantProvenance::new();
     for pid in &input_publications {
         let Some(p) = repo.publications.iter().find(|p| &p.id == pid) else {
             return Err(bad_request(anyhow::anyhow!("unknown publication {}", pid)));
@@ -48,10 +51,11 @@ pub(super) async fn create_bundle(
 
         let snap = read_snap(&state, &repo_id, &p.snap_id)?;
         input_roots.push((pid.clone(), snap.root_manifest.as_str().to_string()));
+        provenance.insert(pid.clone(), variant_provenance(p, &snap, origins.get(pid)));
     }
 
     // Derive a new root manifest by coalescing input snap trees.
-    let root_manifest = coalesce_root_manifest(&state, &repo_id, &input_roots)?;
+    let merged_root = coalesce_root_manifest(&state, &repo_id, &input_roots, &provenance)?;
 
     let gate_def = repo
         .gate_graph
@@ -60,8 +64,38 @@ pub(super) async fn create_bundle(
         .find(|g| g.id == payload.gate)
         .ok_or_else(|| bad_request(anyhow::anyhow!("unknown gate")))?;
 
+    // Structured merge drivers run first; gate rules then take precedence over tree rules.
+    // A malformed rules file is skipped and blocks promotion rather than failing the bundle.
+    let (tree_rules, rules_invalid) = match tree_rules_file(&state, &repo_id, &merged_root)? {
+        Ok(rules) => (rules, false),
+        Err(_) => (Default::default(), true),
+    };
+    let registry =
+        converge::merge::MergeDriverRegistry::new(&tree_rules.drivers).map_err(bad_request)?;
+    let base_root = merge_base_root(repo, &payload.scope, &payload.gate);
+    let merged_root = apply_merge_drivers(
+        &state,
+        &repo_id,
+        &merged_root,
+        base_root.as_deref(),
+        &registry,
+    )?;
+
+    let mut rules = gate_def.resolution_rules.clone();
+    rules.extend(tree_rules.rules);
+    let (root_manifest, rule_applications) =
+        apply_resolution_rules(&state, &repo_id, &merged_root, &rules, &origins)?;
+
     let has_superpositions = manifest_has_superpositions(&state, &repo_id, &root_manifest)?;
-    let (promotable, reasons) = compute_promotability(gate_def, has_superpositions, 0);
+    let resolutions_open = resolution_inputs_open(&state, repo, &input_publications)?;
+    let (promotable, reasons) = compute_promotability(
+        gate_def,
+        has_superpositions,
+        0,
+        resolutions_open,
+        unsigned_inputs(repo, gate_def, &input_publications),
+        rules_invalid,
+    );
 
     let id = build_bundle_id(
         &repo_id,
@@ -79,8 +113,8 @@ pub(super) async fn create_bundle(
         gate: payload.gate,
         root_manifest,
         input_publications,
-        created_by: subject.user,
-        created_by_user_id: Some(subject.user_id),
+        created_by: subject.user.clone(),
+        created_by_user_id: Some(subject.user_id.clone()),
         created_at,
 
         promotable,
@@ -88,6 +122,8 @@ pub(super) async fn create_bundle(
 
         approvals: Vec::new(),
         approval_user_ids: Vec::new(),
+        approval_signatures: Vec::new(),
+        checks: Vec::new(),
     };
 
     let bytes =
@@ -97,6 +133,13 @@ pub(super) async fn create_bundle(
         .join(format!("{}.json", id));
     write_if_absent(&path, &bytes).map_err(internal_error)?;
 
+    record_rule_applications(
+        repo,
+        &bundle,
+        rule_applications,
+        &subject,
+        &bundle.created_at,
+    );
     repo.bundles.push(bundle.clone());
     persist_repo(state.as_ref(), repo).map_err(internal_error)?;
     Ok(Json(bundle))
@@ -55,3 +55,41 @@ pub(super) fn build_bundle_id(
     hasher.update(created_at.as_bytes());
     hasher.finalize().to_hex().to_string()
 }
+
+/// Common ancestor for structured merges at a gate: the bundle last promoted into it, else the
+/// bundle most recently promoted out of it.
+pub(super) fn merge_base_root(repo: &Repo, scope: &str, gate: &str) -> Option<String> {
+    let promoted_into = repo
+        .promotion_state
+        .get(scope)
+        .and_then(|gates| gates.get(gate))
+        .cloned();
+    let promoted_from = || {
+        repo.promotions
+            .iter()
+            .filter(|p| p.scope == scope && p.from_gate == gate)
+            .max_by(|a, b| a.promoted_at.cmp(&b.promoted_at))
+            .map(|p| p.bundle_id.clone())
+    };
+    let bundle_id = promoted_into.or_else(promoted_from)?;
+    repo.bundles
+        .iter()
+        .find(|b| b.id == bundle_id)
+        .map(|b| b.root_manifest.clone())
+}
+
+/// Variant metadata for a bundle input; the lane is the first owning lane by id.
+pub(super) fn variant_provenance(
+    publication: &Publication,
+    snap: &converge::model::SnapRecord,
+    origin: Option<&converge::resolve::VariantOrigin>,
+) -> converge::model::VariantMetadata {
+    converge::model::VariantMetadata {
+        publisher: Some(publication.publisher.clone()),
+        publisher_user_id: publication.publisher_user_id.clone(),
+        published_at: Some(publication.created_at.clone()),
+        snap_message: snap.message.clone(),
+        lane: origin.and_then(|o| o.lanes.first().cloned()),
+        ..Default::default()
+    }
+}
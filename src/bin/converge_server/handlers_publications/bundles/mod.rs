@@ -1,9 +1,11 @@
 use super::super::*;
 
 mod approve;
+mod checks;
 mod create_list_get;
 
-pub(super) use self::approve::approve_bundle;
+pub(super) use self::approve::{ApproveBundleRequest, approve_bundle};
+pub(super) use self::checks::{ReportCheckRequest, report_check};
 pub(in super::super) use self::create_list_get::types::{CreateBundleRequest, ListBundlesQuery};
 
 pub(super) async fn create_bundle(
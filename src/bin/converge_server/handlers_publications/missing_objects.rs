@@ -25,7 +25,7 @@ pub(super) async fn find_missing_objects(
     {
         let repos = state.repos.read().await;
         let repo = repos.get(&repo_id).ok_or_else(not_found)?;
-        if !can_publish(repo, &subject) {
+        if !can_publish(repo, &subject, Target::Repo) {
             return Err(forbidden());
         }
     }
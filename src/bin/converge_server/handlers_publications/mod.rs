@@ -61,8 +61,18 @@ pub(super) async fn approve_bundle(
     state: State<Arc<AppState>>,
     subject: Extension<Subject>,
     ids: Path<(String, String)>,
+    payload: Option<Json<bundles::ApproveBundleRequest>>,
 ) -> Result<Json<Bundle>, Response> {
-    bundles::approve_bundle(state, subject, ids).await
+    bundles::approve_bundle(state, subject, ids, payload).await
+}
+
+pub(super) async fn report_bundle_check(
+    state: State<Arc<AppState>>,
+    subject: Extension<Subject>,
+    ids: Path<(String, String)>,
+    payload: Json<bundles::ReportCheckRequest>,
+) -> Result<Json<Bundle>, Response> {
+    bundles::report_check(state, subject, ids, payload).await
 }
 
 pub(super) async fn list_pins(
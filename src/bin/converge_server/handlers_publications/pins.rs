@@ -25,7 +25,7 @@ pub(super) async fn pin_bundle(
 
     let mut repos = state.repos.write().await;
     let repo = repos.get_mut(&repo_id).ok_or_else(not_found)?;
-    if !can_publish(repo, &subject) {
+    if !can_manage(repo, &subject) {
         return Err(forbidden());
     }
 
@@ -53,7 +53,7 @@ pub(super) async fn unpin_bundle(
 
     let mut repos = state.repos.write().await;
     let repo = repos.get_mut(&repo_id).ok_or_else(not_found)?;
-    if !can_publish(repo, &subject) {
+    if !can_manage(repo, &subject) {
         return Err(forbidden());
     }
 
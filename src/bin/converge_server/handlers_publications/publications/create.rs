@@ -9,12 +9,27 @@ pub(super) async fn create_publication(
     validate::validate_publication_request(&payload)?;
     let created_at = validate::created_at()?;
     let id = validate::publication_id(&repo_id, &payload, &subject.user, &created_at);
+    let keys = known_signing_keys(state.as_ref()).await;
+    let signature = check_signature(
+        &keys,
+        payload.signature.clone(),
+        &converge::signing::publication_payload(
+            &repo_id,
+            &payload.scope,
+            &payload.gate,
+            &payload.snap_id,
+        ),
+        Some(&subject),
+    )?;
 
     let mut repos = state.repos.write().await;
     let repo = repos.get_mut(&repo_id).ok_or_else(not_found)?;
     validate::enforce_publication_constraints(repo, &payload, &subject)?;
 
     let snap = read_snap(state.as_ref(), &repo_id, &payload.snap_id)?;
+    let snap_payload = converge::signing::snap_payload(&snap).map_err(internal_error)?;
+    let snap_signature = check_signature(&keys, payload.snap_signature, &snap_payload, None)?;
+    validate::enforce_signed_gate(repo, &payload.gate, &signature, &snap_signature)?;
     validate_manifest_tree_availability(
         state.as_ref(),
         &repo_id,
@@ -22,6 +37,14 @@ pub(super) async fn create_publication(
         !payload.metadata_only,
     )?;
 
+    let mut lanes: Vec<String> = repo
+        .lanes
+        .values()
+        .filter(|lane| is_lane_member(lane, &subject))
+        .map(|lane| lane.id.clone())
+        .collect();
+    lanes.sort();
+
     let pubrec = Publication {
         id,
         snap_id: payload.snap_id,
@@ -31,6 +54,9 @@ pub(super) async fn create_publication(
         publisher_user_id: Some(subject.user_id),
         created_at,
         resolution: payload.resolution,
+        signature,
+        snap_signature,
+        lanes: Some(lanes),
     };
     repo.publications.push(pubrec.clone());
 
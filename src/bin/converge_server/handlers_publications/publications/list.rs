@@ -10,5 +10,13 @@ pub(super) async fn list_publications(
     if !can_read(repo, &subject) {
         return Err(forbidden());
     }
-    Ok(Json(repo.publications.clone()))
+
+    // Lane visibility: only publications from lanes the caller can see (see `can_see_publication`).
+    let visible = repo
+        .publications
+        .iter()
+        .filter(|p| can_see_publication(repo, &subject, p))
+        .cloned()
+        .collect();
+    Ok(Json(visible))
 }
@@ -15,6 +15,12 @@ pub(in super::super) struct CreatePublicationRequest {
 
     #[serde(default)]
     resolution: Option<PublicationResolution>,
+
+    #[serde(default)]
+    signature: Option<converge::signing::Signature>,
+
+    #[serde(default)]
+    snap_signature: Option<converge::signing::Signature>,
 }
 
 pub(super) async fn create_publication(
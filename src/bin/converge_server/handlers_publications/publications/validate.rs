@@ -41,7 +41,7 @@ pub(super) fn enforce_publication_constraints(
     payload: &CreatePublicationRequest,
     subject: &Subject,
 ) -> Result<(), Response> {
-    if !can_publish(repo, subject) {
+    if !can_publish(repo, subject, Target::Gate(&payload.gate)) {
         return Err(forbidden());
     }
     if !repo.scopes.contains(&payload.scope) {
@@ -77,3 +77,23 @@ pub(super) fn enforce_publication_constraints(
     }
     Ok(())
 }
+
+pub(super) fn enforce_signed_gate(
+    repo: &Repo,
+    gate: &str,
+    signature: &Option<converge::signing::Signature>,
+    snap_signature: &Option<converge::signing::Signature>,
+) -> Result<(), Response> {
+    let required = repo
+        .gate_graph
+        .gates
+        .iter()
+        .any(|g| g.id == gate && g.require_signed);
+    if required && (signature.is_none() || snap_signature.is_none()) {
+        return Err(bad_request(anyhow::anyhow!(
+            "gate {} requires signed publications (configure a key with `converge key generate`)",
+            gate
+        )));
+    }
+    Ok(())
+}
@@ -6,4 +6,6 @@ mod release_endpoints;
 
 pub(crate) use self::promotion_endpoints::{create_promotion, list_promotions};
 pub(crate) use self::promotion_state::get_promotion_state;
-pub(crate) use self::release_endpoints::{create_release, get_release_channel, list_releases};
+pub(crate) use self::release_endpoints::{
+    attach_release_file, create_release, get_release_archive, get_release_channel, list_releases,
+};
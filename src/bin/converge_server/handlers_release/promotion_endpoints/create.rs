@@ -19,7 +19,7 @@ pub(crate) async fn create_promotion(
 
     let mut repos = state.repos.write().await;
     let repo = repos.get_mut(&repo_id).ok_or_else(not_found)?;
-    if !can_publish(repo, &subject) {
+    if !can_promote(repo, &subject, Target::Repo) {
         return Err(forbidden());
     }
 
@@ -28,6 +28,9 @@ pub(crate) async fn create_promotion(
     } else {
         load_bundle_from_disk(state.as_ref(), &repo_id, &payload.bundle_id)?
     };
+    if !can_promote(repo, &subject, Target::Gate(&bundle.gate)) {
+        return Err(forbidden());
+    }
 
     // Re-check promotability at promotion time.
     let gate_def = repo
@@ -38,8 +41,16 @@ pub(crate) async fn create_promotion(
         .ok_or_else(|| internal_error(anyhow::anyhow!("bundle gate not found")))?;
     let has_superpositions =
         manifest_has_superpositions(state.as_ref(), &repo_id, &bundle.root_manifest)?;
-    let (promotable, _reasons) =
-        compute_promotability(gate_def, has_superpositions, bundle.approvals.len());
+    let resolutions_open =
+        resolution_inputs_open(state.as_ref(), repo, &bundle.input_publications)?;
+    let (promotable, _reasons) = compute_promotability(
+        gate_def,
+        has_superpositions,
+        approval_count(gate_def, &bundle),
+        resolutions_open,
+        unsigned_inputs(repo, gate_def, &bundle.input_publications),
+        tree_rules_invalid(state.as_ref(), &repo_id, &bundle.root_manifest)?,
+    );
     if !promotable {
         return Err(conflict("bundle not promotable"));
     }
@@ -0,0 +1,123 @@
+use std::pin::Pin;
+use std::sync::Mutex;
+use std::task::{Context as TaskContext, Poll};
+
+use super::*;
+
+use converge::archive::{ArchiveFormat, write_archive};
+use converge::model::ObjectId;
+
+use super::read::latest_release;
+
+#[derive(Debug, Default, serde::Deserialize)]
+pub(crate) struct ReleaseArchiveQuery {
+    #[serde(default)]
+    format: Option<String>,
+}
+
+/// Streams the latest release on a channel as a deterministic archive (same bytes as
+/// `converge archive --release`).
+pub(crate) async fn get_release_archive(
+    State(state): State<Arc<AppState>>,
+    Extension(subject): Extension<Subject>,
+    Path((repo_id, channel)): Path<(String, String)>,
+    Query(q): Query<ReleaseArchiveQuery>,
+) -> Result<Response, Response> {
+    validate_release_channel(&channel).map_err(bad_request)?;
+    let format: ArchiveFormat = q
+        .format
+        .as_deref()
+        .unwrap_or("tar")
+        .parse()
+        .map_err(bad_request)?;
+
+    let (release, root) = {
+        let repos = state.repos.read().await;
+        let repo = repos.get(&repo_id).ok_or_else(not_found)?;
+        if !can_read(repo, &subject) {
+            return Err(forbidden());
+        }
+        let release = latest_release(repo, &channel).ok_or_else(not_found)?;
+        let root = match repo.bundles.iter().find(|b| b.id == release.bundle_id) {
+            Some(b) => b.root_manifest.clone(),
+            None => {
+                load_bundle_from_disk(state.as_ref(), &repo_id, &release.bundle_id)?.root_manifest
+            }
+        };
+        (release, root)
+    };
+
+    // Fail before any bytes are sent; errors mid-stream can only abort the body.
+    if manifest_has_superpositions(state.as_ref(), &repo_id, &root)? {
+        return Err(conflict("release tree has unresolved superpositions"));
+    }
+    validate_manifest_tree_availability(state.as_ref(), &repo_id, &root, true)?;
+
+    let filename = format!(
+        "{}-{}.{}",
+        repo_id,
+        release.channel.replace('/', "-"),
+        format.as_str()
+    );
+    let (reader, writer) = tokio::io::duplex(64 * 1024);
+    let failed = Arc::new(Mutex::new(None));
+    let body = ArchiveBody {
+        inner: reader,
+        failed: failed.clone(),
+    };
+    let source = RepoObjects {
+        state: state.clone(),
+        repo_id: repo_id.clone(),
+    };
+    tokio::task::spawn_blocking(move || {
+        let mut out = tokio_util::io::SyncIoBridge::new(writer);
+        if let Err(err) = write_archive(&source, &ObjectId(root), format, &mut out) {
+            // Headers are already sent, so the log is the only place the cause is reported.
+            eprintln!(
+                "error: archive {} {}: {:#}",
+                source.repo_id, release.channel, err
+            );
+            // Recorded before `out` closes, so the body ends in an error rather than EOF.
+            if let Ok(mut failed) = failed.lock() {
+                *failed = Some(format!("{:#}", err));
+            }
+        }
+    });
+
+    Ok((
+        [
+            (header::CONTENT_TYPE, format.content_type().to_string()),
+            (
+                header::CONTENT_DISPOSITION,
+                format!("attachment; filename=\"{}\"", filename),
+            ),
+        ],
+        axum::body::Body::from_stream(tokio_util::io::ReaderStream::new(body)),
+    )
+        .into_response())
+}
+
+/// The archive pipe's read side; once the writer failed, its end of stream becomes an error so
+/// the connection is aborted instead of the client getting a truncated archive.
+struct ArchiveBody {
+    inner: tokio::io::DuplexStream,
+    failed: Arc<Mutex<Option<String>>>,
+}
+
+impl tokio::io::AsyncRead for ArchiveBody {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut TaskContext<'_>,
+        buf: &mut tokio::io::ReadBuf<'_>,
+    ) -> Poll<std::io::Result<()>> {
+        let filled = buf.filled().len();
+        let poll = Pin::new(&mut self.inner).poll_read(cx, buf);
+        if matches!(poll, Poll::Ready(Ok(()))) && buf.filled().len() == filled {
+            let failed = self.failed.lock().ok().and_then(|mut f| f.take());
+            if let Some(err) = failed {
+                return Poll::Ready(Err(std::io::Error::other(err)));
+            }
+        }
+        poll
+    }
+}
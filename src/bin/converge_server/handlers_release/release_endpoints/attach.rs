@@ -0,0 +1,178 @@
+use super::*;
+
+use super::read::latest_release;
+
+const ATTACHMENT_KINDS: &[&str] = &["artifact", "sbom", "signature"];
+
+#[derive(Debug, serde::Deserialize)]
+pub(crate) struct AttachReleaseRequest {
+    name: String,
+    kind: String,
+    size: u64,
+
+    #[serde(default)]
+    blob: Option<String>,
+
+    #[serde(default)]
+    recipe: Option<String>,
+
+    /// Attach to this release instead of the channel's latest.
+    #[serde(default)]
+    release_id: Option<String>,
+}
+
+/// Records an uploaded blob (or chunk recipe) as a named attachment on a release.
+pub(crate) async fn attach_release_file(
+    State(state): State<Arc<AppState>>,
+    Extension(subject): Extension<Subject>,
+    Path((repo_id, channel)): Path<(String, String)>,
+    Json(payload): Json<AttachReleaseRequest>,
+) -> Result<Json<Release>, Response> {
+    validate_release_channel(&channel).map_err(bad_request)?;
+    validate_attachment_name(&payload.name).map_err(bad_request)?;
+    if !ATTACHMENT_KINDS.contains(&payload.kind.as_str()) {
+        return Err(bad_request(anyhow::anyhow!(
+            "attachment kind must be one of {}",
+            ATTACHMENT_KINDS.join(", ")
+        )));
+    }
+
+    let mut repos = state.repos.write().await;
+    let repo = repos.get_mut(&repo_id).ok_or_else(not_found)?;
+    if !can_release(repo, &subject, Target::Repo) {
+        return Err(forbidden());
+    }
+
+    let release_id = match payload.release_id.as_deref() {
+        Some(id) => {
+            let r = repo
+                .releases
+                .iter()
+                .find(|r| r.id == id)
+                .ok_or_else(not_found)?;
+            if r.channel != channel {
+                return Err(bad_request(anyhow::anyhow!(
+                    "release {} is not in channel {}",
+                    id,
+                    channel
+                )));
+            }
+            r.id.clone()
+        }
+        None => latest_release(repo, &channel).ok_or_else(not_found)?.id,
+    };
+    let release_gate = repo
+        .releases
+        .iter()
+        .find(|r| r.id == release_id)
+        .map(|r| r.gate.clone())
+        .unwrap_or_default();
+    if !can_release(repo, &subject, Target::Gate(&release_gate)) {
+        return Err(forbidden());
+    }
+
+    let stored_size = attachment_object_size(
+        state.as_ref(),
+        &repo_id,
+        payload.blob.as_deref(),
+        payload.recipe.as_deref(),
+    )?;
+    if stored_size != payload.size {
+        return Err(bad_request(anyhow::anyhow!(
+            "attachment size mismatch (declared {}, stored {})",
+            payload.size,
+            stored_size
+        )));
+    }
+
+    let attached_at = time::OffsetDateTime::now_utc()
+        .format(&time::format_description::well_known::Rfc3339)
+        .map_err(|e| internal_error(anyhow::anyhow!(e)))?;
+    let attachment = ReleaseAttachment {
+        name: payload.name,
+        kind: payload.kind,
+        size: payload.size,
+        blob: payload.blob,
+        recipe: payload.recipe,
+        attached_by: subject.user.clone(),
+        attached_at,
+    };
+
+    let release = repo
+        .releases
+        .iter_mut()
+        .find(|r| r.id == release_id)
+        .ok_or_else(not_found)?;
+    if let Some(existing) = release
+        .attachments
+        .iter()
+        .find(|a| a.name == attachment.name)
+    {
+        if existing.blob == attachment.blob
+            && existing.recipe == attachment.recipe
+            && existing.kind == attachment.kind
+        {
+            return Ok(Json(release.clone()));
+        }
+        return Err(conflict(
+            "release already has a different attachment with that name",
+        ));
+    }
+    release.attachments.push(attachment);
+    release.attachments.sort_by(|a, b| a.name.cmp(&b.name));
+    let release = release.clone();
+
+    let bytes =
+        serde_json::to_vec_pretty(&release).map_err(|e| internal_error(anyhow::anyhow!(e)))?;
+    let path = repo_data_dir(&state, &repo_id)
+        .join("releases")
+        .join(format!("{}.json", release.id));
+    write_atomic_overwrite(&path, &bytes).map_err(internal_error)?;
+    persist_repo(state.as_ref(), repo).map_err(internal_error)?;
+    Ok(Json(release))
+}
+
+fn validate_attachment_name(name: &str) -> anyhow::Result<()> {
+    if name.is_empty() || name.len() > 255 {
+        anyhow::bail!("attachment name must be 1-255 bytes");
+    }
+    if name == "." || name == ".." || name.contains(['/', '\\', '\0']) {
+        anyhow::bail!("attachment name must be a plain file name");
+    }
+    Ok(())
+}
+
+/// Size of the referenced content; every object must already be uploaded.
+fn attachment_object_size(
+    state: &AppState,
+    repo_id: &str,
+    blob: Option<&str>,
+    recipe: Option<&str>,
+) -> Result<u64, Response> {
+    let blobs_dir = repo_data_dir(state, repo_id).join("objects/blobs");
+    let blob_len = |id: &str| -> Result<u64, Response> {
+        validate_object_id(id).map_err(bad_request)?;
+        std::fs::metadata(blobs_dir.join(id))
+            .map(|m| m.len())
+            .map_err(|_| bad_request(anyhow::anyhow!("missing referenced blob {}", id)))
+    };
+
+    match (blob, recipe) {
+        (Some(blob), None) => blob_len(blob),
+        (None, Some(recipe_id)) => {
+            let recipe = read_recipe(state, repo_id, recipe_id)?;
+            for chunk in &recipe.chunks {
+                if blob_len(chunk.blob.as_str())? != chunk.size as u64 {
+                    return Err(bad_request(anyhow::anyhow!(
+                        "recipe chunk {} size mismatch",
+                        chunk.blob.as_str()
+                    )));
+                }
+            }
+            Ok(recipe.size)
+        }
+        _ => Err(bad_request(anyhow::anyhow!(
+            "attachment needs exactly one of blob or recipe"
+        ))),
+    }
+}
@@ -7,6 +7,9 @@ pub(crate) struct CreateReleaseRequest {
 
     #[serde(default)]
     notes: Option<String>,
+
+    #[serde(default)]
+    signature: Option<converge::signing::Signature>,
 }
 
 pub(crate) async fn create_release(
@@ -21,10 +24,17 @@ pub(crate) async fn create_release(
     let released_at = time::OffsetDateTime::now_utc()
         .format(&time::format_description::well_known::Rfc3339)
         .map_err(|e| internal_error(anyhow::anyhow!(e)))?;
+    let keys = known_signing_keys(state.as_ref()).await;
+    let signature = check_signature(
+        &keys,
+        payload.signature,
+        &converge::signing::release_payload(&repo_id, &payload.channel, &payload.bundle_id),
+        Some(&subject),
+    )?;
 
     let mut repos = state.repos.write().await;
     let repo = repos.get_mut(&repo_id).ok_or_else(not_found)?;
-    if !can_publish(repo, &subject) {
+    if !can_release(repo, &subject, Target::Repo) {
         return Err(forbidden());
     }
 
@@ -33,6 +43,9 @@ pub(crate) async fn create_release(
     } else {
         load_bundle_from_disk(state.as_ref(), &repo_id, &payload.bundle_id)?
     };
+    if !can_release(repo, &subject, Target::Gate(&bundle.gate)) {
+        return Err(forbidden());
+    }
 
     let gate_def = repo
         .gate_graph
@@ -47,12 +60,26 @@ pub(crate) async fn create_release(
             bundle.gate
         )));
     }
+    if gate_def.require_signed && signature.is_none() {
+        return Err(bad_request(anyhow::anyhow!(
+            "gate {} requires signed releases",
+            bundle.gate
+        )));
+    }
 
     // Re-check promotability at release time.
     let has_superpositions =
         manifest_has_superpositions(state.as_ref(), &repo_id, &bundle.root_manifest)?;
-    let (promotable, _reasons) =
-        compute_promotability(gate_def, has_superpositions, bundle.approvals.len());
+    let resolutions_open =
+        resolution_inputs_open(state.as_ref(), repo, &bundle.input_publications)?;
+    let (promotable, _reasons) = compute_promotability(
+        gate_def,
+        has_superpositions,
+        approval_count(gate_def, &bundle),
+        resolutions_open,
+        unsigned_inputs(repo, gate_def, &bundle.input_publications),
+        tree_rules_invalid(state.as_ref(), &repo_id, &bundle.root_manifest)?,
+    );
     if !promotable {
         return Err(conflict("bundle not promotable"));
     }
@@ -81,6 +108,8 @@ pub(crate) async fn create_release(
         released_by_user_id: Some(subject.user_id.clone()),
         released_at,
         notes: payload.notes,
+        attachments: Vec::new(),
+        signature,
     };
 
     let bytes =
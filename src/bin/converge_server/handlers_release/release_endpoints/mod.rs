@@ -1,7 +1,11 @@
 use super::*;
 
+mod archive;
+mod attach;
 mod create;
 mod read;
 
+pub(crate) use self::archive::get_release_archive;
+pub(crate) use self::attach::attach_release_file;
 pub(crate) use self::create::create_release;
 pub(crate) use self::read::{get_release_channel, list_releases};
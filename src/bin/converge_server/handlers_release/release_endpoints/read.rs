@@ -28,22 +28,21 @@ pub(crate) async fn get_release_channel(
         return Err(forbidden());
     }
 
-    let mut best: Option<Release> = None;
-    for r in &repo.releases {
-        if r.channel != channel {
-            continue;
-        }
-        match best.as_ref() {
-            None => best = Some(r.clone()),
-            Some(prev) => {
-                if r.released_at > prev.released_at {
-                    best = Some(r.clone());
-                }
-            }
-        }
-    }
-    let Some(best) = best else {
-        return Err(not_found());
-    };
+    let best = latest_release(repo, &channel).ok_or_else(not_found)?;
     Ok(Json(best))
 }
+
+/// Most recent release on `channel`.
+pub(super) fn latest_release(repo: &Repo, channel: &str) -> Option<Release> {
+    repo.releases
+        .iter()
+        .filter(|r| r.channel == channel)
+        .reduce(|best, r| {
+            if r.released_at > best.released_at {
+                r
+            } else {
+                best
+            }
+        })
+        .cloned()
+}
@@ -19,14 +19,17 @@ pub(crate) async fn update_lane_head_me(
 
     let mut repos = state.repos.write().await;
     let repo = repos.get_mut(&repo_id).ok_or_else(not_found)?;
-    if !can_publish(repo, &subject) {
+    if !can_publish(repo, &subject, Target::Lane(&lane_id)) {
         return Err(forbidden());
     }
 
     let lane = repo.lanes.get_mut(&lane_id).ok_or_else(not_found)?;
-    if !lane.members.contains(&subject.user) && !lane.member_user_ids.contains(&subject.user_id) {
+    if !is_lane_member(lane, &subject) {
         return Err(forbidden());
     }
+    if lane.archived_at.is_some() {
+        return Err(conflict("lane is archived"));
+    }
 
     if !repo.snaps.contains(&payload.snap_id) {
         return Err(bad_request(anyhow::anyhow!(
@@ -68,7 +71,7 @@ pub(crate) async fn get_lane_head(
         return Err(forbidden());
     }
     let lane = repo.lanes.get(&lane_id).ok_or_else(not_found)?;
-    if !lane.members.contains(&subject.user) && !lane.member_user_ids.contains(&subject.user_id) {
+    if !can_see_lane(repo, &subject, lane, None) {
         return Err(forbidden());
     }
 
@@ -0,0 +1,291 @@
+use super::super::*;
+
+#[derive(Debug, serde::Deserialize)]
+pub(crate) struct CreateLaneRequest {
+    id: String,
+
+    #[serde(default)]
+    description: Option<String>,
+
+    #[serde(default)]
+    gates: Vec<String>,
+
+    #[serde(default)]
+    default_scope: Option<String>,
+}
+
+/// Unset fields are left alone; an empty `description` or `default_scope` clears it.
+#[derive(Debug, serde::Deserialize)]
+pub(crate) struct UpdateLaneRequest {
+    /// Rename the lane.
+    #[serde(default)]
+    id: Option<String>,
+
+    #[serde(default)]
+    description: Option<String>,
+
+    #[serde(default)]
+    gates: Option<Vec<String>>,
+
+    #[serde(default)]
+    default_scope: Option<String>,
+
+    #[serde(default)]
+    archived: Option<bool>,
+}
+
+pub(crate) async fn create_lane(
+    State(state): State<Arc<AppState>>,
+    Extension(subject): Extension<Subject>,
+    Path(repo_id): Path<String>,
+    Json(payload): Json<CreateLaneRequest>,
+) -> Result<Json<Lane>, Response> {
+    validate_lane_id(&payload.id).map_err(bad_request)?;
+
+    let mut repos = state.repos.write().await;
+    let repo = repos.get_mut(&repo_id).ok_or_else(not_found)?;
+    if !can_manage(repo, &subject) {
+        return Err(forbidden());
+    }
+    if repo.lanes.contains_key(&payload.id) {
+        return Err(conflict("lane already exists"));
+    }
+    let default_scope = payload.default_scope.filter(|s| !s.is_empty());
+    validate_lane_metadata(repo, &payload.gates, default_scope.as_deref())?;
+    assign_gates(repo, &payload.id, &payload.gates)?;
+
+    let lane = Lane {
+        id: payload.id.clone(),
+        members: HashSet::new(),
+        description: payload.description.filter(|d| !d.is_empty()),
+        gates: payload.gates,
+        default_scope,
+        archived_at: None,
+        member_user_ids: HashSet::new(),
+        member_group_ids: HashSet::new(),
+        heads: HashMap::new(),
+        head_history: HashMap::new(),
+    };
+    repo.lanes.insert(lane.id.clone(), lane.clone());
+    persist_repo(state.as_ref(), repo).map_err(internal_error)?;
+    drop(repos);
+
+    record_audit(
+        &state,
+        &subject,
+        "lane.create",
+        format!("{}/{}", repo_id, lane.id),
+        None,
+    )?;
+    Ok(Json(lane))
+}
+
+pub(crate) async fn update_lane(
+    State(state): State<Arc<AppState>>,
+    Extension(subject): Extension<Subject>,
+    Path((repo_id, lane_id)): Path<(String, String)>,
+    Json(payload): Json<UpdateLaneRequest>,
+) -> Result<Json<Lane>, Response> {
+    validate_lane_id(&lane_id).map_err(bad_request)?;
+    if let Some(id) = &payload.id {
+        validate_lane_id(id).map_err(bad_request)?;
+    }
+
+    let mut repos = state.repos.write().await;
+    let repo = repos.get_mut(&repo_id).ok_or_else(not_found)?;
+    if !can_manage(repo, &subject) {
+        return Err(forbidden());
+    }
+    let mut lane = repo.lanes.get(&lane_id).cloned().ok_or_else(not_found)?;
+    let rename = payload.id.filter(|id| id != &lane_id);
+    if rename
+        .as_ref()
+        .is_some_and(|id| repo.lanes.contains_key(id))
+    {
+        return Err(conflict("lane already exists"));
+    }
+
+    let mut changes = Vec::new();
+    if let Some(description) = payload.description {
+        lane.description = Some(description).filter(|d| !d.is_empty());
+        changes.push("description".to_string());
+    }
+    if let Some(scope) = payload.default_scope {
+        lane.default_scope = Some(scope).filter(|s| !s.is_empty());
+        validate_lane_metadata(repo, &[], lane.default_scope.as_deref())?;
+        changes.push(format!(
+            "default_scope={}",
+            lane.default_scope.as_deref().unwrap_or("-")
+        ));
+    }
+    // Gate ownership is written straight into the gate graph, so it goes last.
+    if let Some(gates) = payload.gates {
+        validate_lane_metadata(repo, &gates, None)?;
+        assign_gates(repo, &lane_id, &gates)?;
+        lane.gates = gates;
+        changes.push(format!("gates={}", lane.gates.join(",")));
+    }
+    match payload.archived {
+        Some(true) if lane.archived_at.is_none() => {
+            lane.archived_at = Some(now_ts());
+            changes.push("archived".to_string());
+        }
+        Some(false) if lane.archived_at.is_some() => {
+            lane.archived_at = None;
+            changes.push("unarchived".to_string());
+        }
+        _ => {}
+    }
+
+    if let Some(new_id) = rename {
+        // Grants and subscriptions follow the lane to its new id.
+        for g in repo.role_grants.iter_mut() {
+            if g.lane.as_deref() == Some(lane_id.as_str()) {
+                g.lane = Some(new_id.clone());
+            }
+        }
+        for s in repo.lane_subscriptions.iter_mut() {
+            if s.lane == lane_id {
+                s.lane = new_id.clone();
+            }
+        }
+        for g in repo.gate_graph.gates.iter_mut() {
+            if g.lane.as_deref() == Some(lane_id.as_str()) {
+                g.lane = Some(new_id.clone());
+            }
+        }
+        for p in repo.publications.iter_mut() {
+            for l in p.lanes.iter_mut().flatten() {
+                if *l == lane_id {
+                    *l = new_id.clone();
+                }
+            }
+        }
+        repo.lanes.remove(&lane_id);
+        changes.push(format!("renamed to {}", new_id));
+        lane.id = new_id;
+    }
+
+    repo.lanes.insert(lane.id.clone(), lane.clone());
+    persist_repo(state.as_ref(), repo).map_err(internal_error)?;
+    drop(repos);
+
+    let action = match changes.last().map(String::as_str) {
+        Some("archived") => "lane.archive",
+        Some("unarchived") => "lane.unarchive",
+        _ => "lane.update",
+    };
+    record_audit(
+        &state,
+        &subject,
+        action,
+        format!("{}/{}", repo_id, lane_id),
+        (!changes.is_empty()).then(|| changes.join(" ")),
+    )?;
+    Ok(Json(lane))
+}
+
+pub(crate) async fn delete_lane(
+    State(state): State<Arc<AppState>>,
+    Extension(subject): Extension<Subject>,
+    Path((repo_id, lane_id)): Path<(String, String)>,
+) -> Result<Json<serde_json::Value>, Response> {
+    validate_lane_id(&lane_id).map_err(bad_request)?;
+
+    let mut repos = state.repos.write().await;
+    let repo = repos.get_mut(&repo_id).ok_or_else(not_found)?;
+    if !can_manage(repo, &subject) {
+        return Err(forbidden());
+    }
+    let lane = repo.lanes.get(&lane_id).ok_or_else(not_found)?;
+    // Heads are unpublished work; deleting them would drop their GC roots.
+    if !lane.heads.is_empty() {
+        return Err(conflict("lane has heads; archive it instead"));
+    }
+    if repo
+        .gate_graph
+        .gates
+        .iter()
+        .any(|g| g.lane.as_deref() == Some(lane_id.as_str()))
+    {
+        return Err(conflict("lane owns gates; reassign them first"));
+    }
+
+    repo.lanes.remove(&lane_id);
+    repo.role_grants
+        .retain(|g| g.lane.as_deref() != Some(lane_id.as_str()));
+    repo.lane_subscriptions.retain(|s| s.lane != lane_id);
+    for p in repo.publications.iter_mut() {
+        if let Some(lanes) = p.lanes.as_mut() {
+            lanes.retain(|l| l != &lane_id);
+        }
+    }
+    persist_repo(state.as_ref(), repo).map_err(internal_error)?;
+    drop(repos);
+
+    record_audit(
+        &state,
+        &subject,
+        "lane.delete",
+        format!("{}/{}", repo_id, lane_id),
+        None,
+    )?;
+    Ok(Json(serde_json::json!({"ok": true})))
+}
+
+fn validate_lane_metadata(
+    repo: &Repo,
+    gates: &[String],
+    default_scope: Option<&str>,
+) -> Result<(), Response> {
+    if let Some(gate) = gates
+        .iter()
+        .find(|g| !repo.gate_graph.gates.iter().any(|d| &d.id == *g))
+    {
+        return Err(bad_request(anyhow::anyhow!("unknown gate {}", gate)));
+    }
+    if let Some(scope) = default_scope
+        && !repo.scopes.contains(scope)
+    {
+        return Err(bad_request(anyhow::anyhow!("unknown scope {}", scope)));
+    }
+    Ok(())
+}
+
+/// Makes `lane_id` the owner of exactly `gates` in the gate graph. Gates owned by another lane
+/// are not taken over.
+fn assign_gates(repo: &mut Repo, lane_id: &str, gates: &[String]) -> Result<(), Response> {
+    if let Some(owned) = repo
+        .gate_graph
+        .gates
+        .iter()
+        .find(|g| gates.contains(&g.id) && g.lane.as_deref().is_some_and(|l| l != lane_id))
+    {
+        return Err(conflict(&format!(
+            "gate {} is owned by lane {}",
+            owned.id,
+            owned.lane.as_deref().unwrap_or_default()
+        )));
+    }
+    for g in repo.gate_graph.gates.iter_mut() {
+        if gates.contains(&g.id) {
+            g.lane = Some(lane_id.to_string());
+        } else if g.lane.as_deref() == Some(lane_id) {
+            g.lane = None;
+        }
+    }
+    Ok(())
+}
+
+/// Rebuilds each lane's `gates` from gate ownership after the gate graph changes.
+pub(crate) fn sync_lane_gates(repo: &mut Repo) {
+    for lane in repo.lanes.values_mut() {
+        lane.gates = repo
+            .gate_graph
+            .gates
+            .iter()
+            .filter(|g| g.lane.as_deref() == Some(lane.id.as_str()))
+            .map(|g| g.id.clone())
+            .collect();
+    }
+}
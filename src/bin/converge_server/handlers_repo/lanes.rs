@@ -1,6 +1,6 @@
 use super::super::*;
 
-use super::members::MemberHandleRequest;
+use super::members::{MemberHandleRequest, Principal, resolve_principal};
 
 pub(crate) async fn list_lane_members(
     State(state): State<Arc<AppState>>,
@@ -10,14 +10,22 @@ pub(crate) async fn list_lane_members(
     validate_lane_id(&lane_id).map_err(bad_request)?;
     let repos = state.repos.read().await;
     let repo = repos.get(&repo_id).ok_or_else(not_found)?;
-    if !subject.admin && repo.owner_user_id.as_ref() != Some(&subject.user_id) {
+    if !can_manage(repo, &subject) {
         return Err(forbidden());
     }
     let lane = repo.lanes.get(&lane_id).ok_or_else(not_found)?;
+    let groups = state.groups.read().await;
+    let mut member_groups: Vec<String> = lane
+        .member_group_ids
+        .iter()
+        .filter_map(|id| groups.get(id).map(|g| format!("@{}", g.name)))
+        .collect();
+    member_groups.sort();
     Ok(Json(serde_json::json!({
         "lane": lane.id,
         "members": lane.members,
         "member_user_ids": lane.member_user_ids,
+        "groups": member_groups,
     })))
 }
 
@@ -28,26 +36,33 @@ pub(crate) async fn add_lane_member(
     Json(payload): Json<MemberHandleRequest>,
 ) -> Result<Json<serde_json::Value>, Response> {
     validate_lane_id(&lane_id).map_err(bad_request)?;
-    validate_user_handle(&payload.handle).map_err(bad_request)?;
+    let principal = resolve_principal(state.as_ref(), &payload.handle).await?;
 
     let mut repos = state.repos.write().await;
     let repo = repos.get_mut(&repo_id).ok_or_else(not_found)?;
-    if !subject.admin && repo.owner_user_id.as_ref() != Some(&subject.user_id) {
+    if !can_manage(repo, &subject) {
         return Err(forbidden());
     }
 
-    let users = state.users.read().await;
-    let (user_id, handle) = users
-        .values()
-        .find(|u| u.handle == payload.handle)
-        .map(|u| (u.id.clone(), u.handle.clone()))
-        .ok_or_else(|| bad_request(anyhow::anyhow!("unknown user handle")))?;
-    drop(users);
-
     let lane = repo.lanes.get_mut(&lane_id).ok_or_else(not_found)?;
-    lane.members.insert(handle);
-    lane.member_user_ids.insert(user_id);
+    match principal {
+        Principal::User { user_id, handle } => {
+            lane.members.insert(handle);
+            lane.member_user_ids.insert(user_id);
+        }
+        Principal::Group { group_id, .. } => {
+            lane.member_group_ids.insert(group_id);
+        }
+    }
     persist_repo(state.as_ref(), repo).map_err(internal_error)?;
+    drop(repos);
+    record_audit(
+        &state,
+        &subject,
+        "lane.member_add",
+        format!("{}/{}", repo_id, lane_id),
+        Some(payload.handle),
+    )?;
     Ok(Json(serde_json::json!({"ok": true})))
 }
 
@@ -57,28 +72,40 @@ pub(crate) async fn remove_lane_member(
     Path((repo_id, lane_id, handle)): Path<(String, String, String)>,
 ) -> Result<Json<serde_json::Value>, Response> {
     validate_lane_id(&lane_id).map_err(bad_request)?;
-    validate_user_handle(&handle).map_err(bad_request)?;
+    let principal = resolve_principal(state.as_ref(), &handle).await;
 
     let mut repos = state.repos.write().await;
     let repo = repos.get_mut(&repo_id).ok_or_else(not_found)?;
-    if !subject.admin && repo.owner_user_id.as_ref() != Some(&subject.user_id) {
+    if !can_manage(repo, &subject) {
         return Err(forbidden());
     }
 
-    let users = state.users.read().await;
-    let uid = users
-        .values()
-        .find(|u| u.handle == handle)
-        .map(|u| u.id.clone());
-    drop(users);
-
     let lane = repo.lanes.get_mut(&lane_id).ok_or_else(not_found)?;
-    lane.members.remove(&handle);
-    if let Some(uid) = uid {
-        lane.member_user_ids.remove(&uid);
+    match principal {
+        Ok(Principal::Group { group_id, .. }) => {
+            lane.member_group_ids.remove(&group_id);
+        }
+        Ok(Principal::User { user_id, .. }) => {
+            lane.members.remove(&handle);
+            lane.member_user_ids.remove(&user_id);
+        }
+        // Unknown users may still linger by handle in older lanes.
+        Err(_) if !handle.starts_with('@') => {
+            validate_user_handle(&handle).map_err(bad_request)?;
+            lane.members.remove(&handle);
+        }
+        Err(err) => return Err(err),
     }
 
     persist_repo(state.as_ref(), repo).map_err(internal_error)?;
+    drop(repos);
+    record_audit(
+        &state,
+        &subject,
+        "lane.member_remove",
+        format!("{}/{}", repo_id, lane_id),
+        Some(handle),
+    )?;
     Ok(Json(serde_json::json!({"ok": true})))
 }
 
@@ -93,7 +120,12 @@ pub(crate) async fn list_lanes(
         return Err(forbidden());
     }
 
-    let mut out: Vec<Lane> = repo.lanes.values().cloned().collect();
+    let mut out: Vec<Lane> = repo
+        .lanes
+        .values()
+        .filter(|lane| can_see_lane(repo, &subject, lane, None))
+        .cloned()
+        .collect();
     out.sort_by(|a, b| a.id.cmp(&b.id));
     Ok(Json(out))
 }
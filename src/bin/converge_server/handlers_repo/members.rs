@@ -6,6 +6,26 @@ pub(crate) struct MemberHandleRequest {
 
     #[serde(default)]
     pub(crate) role: Option<String>,
+
+    /// Narrow a fine-grained role to one gate.
+    #[serde(default)]
+    pub(crate) gate: Option<String>,
+
+    /// Narrow a fine-grained role to one lane.
+    #[serde(default)]
+    pub(crate) lane: Option<String>,
+}
+
+#[derive(Debug, Default, serde::Deserialize)]
+pub(crate) struct RemoveMemberQuery {
+    #[serde(default)]
+    role: Option<String>,
+
+    #[serde(default)]
+    gate: Option<String>,
+
+    #[serde(default)]
+    lane: Option<String>,
 }
 
 pub(crate) async fn list_repo_members(
@@ -15,9 +35,18 @@ pub(crate) async fn list_repo_members(
 ) -> Result<Json<serde_json::Value>, Response> {
     let repos = state.repos.read().await;
     let repo = repos.get(&repo_id).ok_or_else(not_found)?;
-    if !subject.admin && repo.owner_user_id.as_ref() != Some(&subject.user_id) {
+    if !can_manage(repo, &subject) {
         return Err(forbidden());
     }
+    let mut grants = repo.role_grants.clone();
+    grants.sort_by(|a, b| {
+        (&a.handle, a.role.as_str(), &a.gate, &a.lane).cmp(&(
+            &b.handle,
+            b.role.as_str(),
+            &b.gate,
+            &b.lane,
+        ))
+    });
     Ok(Json(serde_json::json!({
         "owner": repo.owner,
         "readers": repo.readers,
@@ -25,6 +54,7 @@ pub(crate) async fn list_repo_members(
         "owner_user_id": repo.owner_user_id,
         "reader_user_ids": repo.reader_user_ids,
         "publisher_user_ids": repo.publisher_user_ids,
+        "grants": grants,
     })))
 }
 
@@ -34,38 +64,66 @@ pub(crate) async fn add_repo_member(
     Path(repo_id): Path<String>,
     Json(payload): Json<MemberHandleRequest>,
 ) -> Result<Json<serde_json::Value>, Response> {
-    validate_user_handle(&payload.handle).map_err(bad_request)?;
+    let principal = resolve_principal(state.as_ref(), &payload.handle).await?;
 
     let mut repos = state.repos.write().await;
     let repo = repos.get_mut(&repo_id).ok_or_else(not_found)?;
-    if !subject.admin && repo.owner_user_id.as_ref() != Some(&subject.user_id) {
+    if !can_manage(repo, &subject) {
         return Err(forbidden());
     }
 
-    let users = state.users.read().await;
-    let (user_id, handle) = users
-        .values()
-        .find(|u| u.handle == payload.handle)
-        .map(|u| (u.id.clone(), u.handle.clone()))
-        .ok_or_else(|| bad_request(anyhow::anyhow!("unknown user handle")))?;
-    drop(users);
-
     let role = payload.role.unwrap_or_else(|| "read".to_string());
-    match role.as_str() {
-        "read" => {
+    let narrowed = payload.gate.is_some() || payload.lane.is_some();
+    let detail = grant_detail(
+        &payload.handle,
+        &role,
+        payload.gate.as_deref(),
+        payload.lane.as_deref(),
+    );
+    match (role.as_str(), principal) {
+        ("read" | "publish", _) if narrowed => {
+            return Err(bad_request(anyhow::anyhow!(
+                "--gate/--lane require a fine-grained role ({})",
+                role_names()
+            )));
+        }
+        ("publish", Principal::Group { .. }) => {
+            return Err(bad_request(anyhow::anyhow!(
+                "groups take fine-grained roles ({})",
+                role_names()
+            )));
+        }
+        ("read", Principal::User { user_id, handle }) => {
             repo.readers.insert(handle);
             repo.reader_user_ids.insert(user_id);
         }
-        "publish" => {
+        ("publish", Principal::User { user_id, handle }) => {
             repo.readers.insert(handle.clone());
             repo.reader_user_ids.insert(user_id.clone());
             repo.publishers.insert(handle);
             repo.publisher_user_ids.insert(user_id);
         }
-        _ => return Err(bad_request(anyhow::anyhow!("unknown role"))),
+        (other, principal) => {
+            // Groups have no legacy reader set; `read` becomes a repo-wide reader grant.
+            let other = if other == "read" { "reader" } else { other };
+            let role = RepoRole::parse(other).ok_or_else(|| {
+                bad_request(anyhow::anyhow!(
+                    "unknown role {} (expected read, publish, {})",
+                    other,
+                    role_names()
+                ))
+            })?;
+            validate_grant_target(repo, payload.gate.as_deref(), payload.lane.as_deref())?;
+            let grant = principal.grant(role, payload.gate, payload.lane);
+            if !repo.role_grants.contains(&grant) {
+                repo.role_grants.push(grant);
+            }
+        }
     }
 
     persist_repo(state.as_ref(), repo).map_err(internal_error)?;
+    drop(repos);
+    record_audit(&state, &subject, "repo.member_add", repo_id, Some(detail))?;
     Ok(Json(serde_json::json!({"ok": true})))
 }
 
@@ -73,29 +131,188 @@ pub(crate) async fn remove_repo_member(
     State(state): State<Arc<AppState>>,
     Extension(subject): Extension<Subject>,
     Path((repo_id, handle)): Path<(String, String)>,
+    Query(query): Query<RemoveMemberQuery>,
 ) -> Result<Json<serde_json::Value>, Response> {
-    validate_user_handle(&handle).map_err(bad_request)?;
+    let (uid, group_id) = match handle.strip_prefix('@') {
+        Some(name) => {
+            let groups = state.groups.read().await;
+            let id = find_group(&groups, name).map(|g| g.id.clone());
+            (None, Some(id.ok_or_else(not_found)?))
+        }
+        None => {
+            validate_user_handle(&handle).map_err(bad_request)?;
+            let users = state.users.read().await;
+            let uid = users
+                .values()
+                .find(|u| u.handle == handle)
+                .map(|u| u.id.clone());
+            (uid, None)
+        }
+    };
 
     let mut repos = state.repos.write().await;
     let repo = repos.get_mut(&repo_id).ok_or_else(not_found)?;
-    if !subject.admin && repo.owner_user_id.as_ref() != Some(&subject.user_id) {
+    if !can_manage(repo, &subject) {
         return Err(forbidden());
     }
 
+    let is_member = |g: &RoleGrant| match &group_id {
+        Some(id) => g.group.as_ref() == Some(id),
+        None => g.group.is_none() && (g.handle == handle || uid.as_ref() == Some(&g.user_id)),
+    };
+    let role = match query.role.as_deref() {
+        Some("read") if group_id.is_some() => Some("reader"),
+        role => role,
+    };
+
+    match role {
+        // Without a role, remove the member entirely.
+        None => {
+            repo.readers.remove(&handle);
+            repo.publishers.remove(&handle);
+            if let Some(uid) = &uid {
+                repo.reader_user_ids.remove(uid);
+                repo.publisher_user_ids.remove(uid);
+            }
+            repo.role_grants.retain(|g| !is_member(g));
+        }
+        Some("read") => {
+            repo.readers.remove(&handle);
+            if let Some(uid) = &uid {
+                repo.reader_user_ids.remove(uid);
+            }
+        }
+        Some("publish") => {
+            repo.publishers.remove(&handle);
+            if let Some(uid) = &uid {
+                repo.publisher_user_ids.remove(uid);
+            }
+        }
+        Some(other) => {
+            let role = RepoRole::parse(other)
+                .ok_or_else(|| bad_request(anyhow::anyhow!("unknown role {}", other)))?;
+            let before = repo.role_grants.len();
+            repo.role_grants.retain(|g| {
+                !(is_member(g) && g.role == role && g.gate == query.gate && g.lane == query.lane)
+            });
+            if repo.role_grants.len() == before {
+                return Err(not_found());
+            }
+        }
+    }
+
+    persist_repo(state.as_ref(), repo).map_err(internal_error)?;
+    drop(repos);
+    let detail = grant_detail(
+        &handle,
+        role.unwrap_or("*"),
+        query.gate.as_deref(),
+        query.lane.as_deref(),
+    );
+    record_audit(
+        &state,
+        &subject,
+        "repo.member_remove",
+        repo_id,
+        Some(detail),
+    )?;
+    Ok(Json(serde_json::json!({"ok": true})))
+}
+
+/// Who a membership request names: a user handle, or `@<group>`.
+pub(super) enum Principal {
+    User { user_id: String, handle: String },
+    Group { group_id: String, name: String },
+}
+
+impl Principal {
+    fn grant(self, role: RepoRole, gate: Option<String>, lane: Option<String>) -> RoleGrant {
+        match self {
+            Principal::User { user_id, handle } => RoleGrant {
+                user_id,
+                handle,
+                role,
+                group: None,
+                gate,
+                lane,
+            },
+            Principal::Group { group_id, name } => RoleGrant {
+                user_id: String::new(),
+                handle: format!("@{}", name),
+                role,
+                group: Some(group_id),
+                gate,
+                lane,
+            },
+        }
+    }
+}
+
+pub(super) async fn resolve_principal(
+    state: &AppState,
+    handle: &str,
+) -> Result<Principal, Response> {
+    if let Some(name) = handle.strip_prefix('@') {
+        let groups = state.groups.read().await;
+        let group = find_group(&groups, name)
+            .ok_or_else(|| bad_request(anyhow::anyhow!("unknown group @{}", name)))?;
+        return Ok(Principal::Group {
+            group_id: group.id.clone(),
+            name: group.name.clone(),
+        });
+    }
+
+    validate_user_handle(handle).map_err(bad_request)?;
     let users = state.users.read().await;
-    let uid = users
+    users
         .values()
         .find(|u| u.handle == handle)
-        .map(|u| u.id.clone());
-    drop(users);
-
-    repo.readers.remove(&handle);
-    repo.publishers.remove(&handle);
-    if let Some(uid) = uid {
-        repo.reader_user_ids.remove(&uid);
-        repo.publisher_user_ids.remove(&uid);
+        .map(|u| Principal::User {
+            user_id: u.id.clone(),
+            handle: u.handle.clone(),
+        })
+        .ok_or_else(|| bad_request(anyhow::anyhow!("unknown user handle")))
+}
+
+pub(super) fn grant_detail(
+    handle: &str,
+    role: &str,
+    gate: Option<&str>,
+    lane: Option<&str>,
+) -> String {
+    let mut out = format!("{} {}", handle, role);
+    if let Some(gate) = gate {
+        out.push_str(&format!(" gate={}", gate));
     }
+    if let Some(lane) = lane {
+        out.push_str(&format!(" lane={}", lane));
+    }
+    out
+}
 
-    persist_repo(state.as_ref(), repo).map_err(internal_error)?;
-    Ok(Json(serde_json::json!({"ok": true})))
+fn validate_grant_target(
+    repo: &Repo,
+    gate: Option<&str>,
+    lane: Option<&str>,
+) -> Result<(), Response> {
+    match (gate, lane) {
+        (Some(_), Some(_)) => Err(bad_request(anyhow::anyhow!(
+            "a role can be narrowed to a gate or a lane, not both"
+        ))),
+        (Some(gate), None) if !repo.gate_graph.gates.iter().any(|g| g.id == gate) => {
+            Err(bad_request(anyhow::anyhow!("unknown gate {}", gate)))
+        }
+        (None, Some(lane)) if !repo.lanes.contains_key(lane) => {
+            Err(bad_request(anyhow::anyhow!("unknown lane {}", lane)))
+        }
+        _ => Ok(()),
+    }
+}
+
+fn role_names() -> String {
+    RepoRole::ALL
+        .iter()
+        .map(|r| r.as_str())
+        .collect::<Vec<_>>()
+        .join(", ")
 }
@@ -1,9 +1,13 @@
 mod lane_heads;
+mod lane_lifecycle;
 mod lanes;
 mod members;
 mod repo_crud;
+mod subscriptions;
 
 pub(super) use self::lane_heads::{get_lane_head, update_lane_head_me};
+pub(super) use self::lane_lifecycle::{create_lane, delete_lane, sync_lane_gates, update_lane};
 pub(super) use self::lanes::{add_lane_member, list_lane_members, list_lanes, remove_lane_member};
 pub(super) use self::members::{add_repo_member, list_repo_members, remove_repo_member};
 pub(super) use self::repo_crud::{create_repo, get_repo, get_repo_permissions, list_repos};
+pub(super) use self::subscriptions::{list_subscriptions, subscribe_lane, unsubscribe_lane};
@@ -10,6 +10,9 @@ pub(crate) async fn create_repo(
     Extension(subject): Extension<Subject>,
     Json(payload): Json<CreateRepoRequest>,
 ) -> Result<Json<Repo>, Response> {
+    if subject.is_scoped() {
+        return Err(forbidden());
+    }
     validate_repo_id(&payload.id).map_err(bad_request)?;
 
     let mut repos = state.repos.write().await;
@@ -34,7 +37,12 @@ pub(crate) async fn create_repo(
     let default_lane = Lane {
         id: "default".to_string(),
         members,
+        description: None,
+        gates: Vec::new(),
+        default_scope: None,
+        archived_at: None,
         member_user_ids,
+        member_group_ids: HashSet::new(),
         heads: HashMap::new(),
         head_history: HashMap::new(),
     };
@@ -47,10 +55,14 @@ pub(crate) async fn create_repo(
             id: "dev-intake".to_string(),
             name: "Dev Intake".to_string(),
             upstream: vec![],
+            lane: None,
             allow_releases: true,
             allow_superpositions: false,
             allow_metadata_only_publications: false,
             required_approvals: 0,
+            require_signed: false,
+            roles: GateRoles::default(),
+            resolution_rules: Vec::new(),
         }],
     };
 
@@ -64,6 +76,7 @@ pub(crate) async fn create_repo(
     let promotions = Vec::new();
     let promotion_state = HashMap::new();
     let releases = Vec::new();
+    let resolutions = HashMap::new();
 
     let repo = Repo {
         id: payload.id.clone(),
@@ -73,7 +86,9 @@ pub(crate) async fn create_repo(
         reader_user_ids,
         publishers,
         publisher_user_ids,
+        role_grants: Vec::new(),
         lanes,
+        lane_subscriptions: Vec::new(),
         gate_graph,
         scopes,
         snaps,
@@ -83,6 +98,7 @@ pub(crate) async fn create_repo(
         promotions,
         promotion_state,
         releases,
+        resolutions,
     };
     repos.insert(repo.id.clone(), repo.clone());
 
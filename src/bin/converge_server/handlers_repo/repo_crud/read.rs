@@ -35,8 +35,30 @@ pub(crate) async fn get_repo_permissions(
 ) -> Result<Json<serde_json::Value>, Response> {
     let repos = state.repos.read().await;
     let repo = repos.get(&repo_id).ok_or_else(not_found)?;
+    let mut lanes: Vec<&str> = repo
+        .lanes
+        .values()
+        .filter(|l| is_lane_member(l, &subject))
+        .map(|l| l.id.as_str())
+        .collect();
+    lanes.sort();
+    let owned_gates: Vec<&str> = repo
+        .gate_graph
+        .gates
+        .iter()
+        .filter(|g| g.lane.as_deref().is_some_and(|l| lanes.contains(&l)))
+        .map(|g| g.id.as_str())
+        .collect();
     Ok(Json(serde_json::json!({
         "read": can_read(repo, &subject),
-        "publish": can_publish(repo, &subject)
+        "publish": can_publish(repo, &subject, Target::Repo),
+        "converge": can_converge(repo, &subject, Target::Repo),
+        "report_checks": can_report_checks(repo, &subject, Target::Repo),
+        "approve": can_approve(repo, &subject, Target::Repo),
+        "promote": can_promote(repo, &subject, Target::Repo),
+        "release": can_release(repo, &subject, Target::Repo),
+        "manage": can_manage(repo, &subject),
+        "lanes": lanes,
+        "owned_gates": owned_gates
     })))
 }
@@ -0,0 +1,226 @@
+use super::super::*;
+
+/// Expiry of a self-subscription made without `until`.
+const SELF_SUBSCRIPTION_DEFAULT_DAYS: i64 = 30;
+
+/// Longest a user may subscribe themselves; managers granting others are not limited.
+const SELF_SUBSCRIPTION_MAX_DAYS: i64 = 90;
+
+#[derive(Debug, serde::Deserialize)]
+pub(crate) struct SubscribeRequest {
+    /// Limit the subscription to one scope.
+    #[serde(default)]
+    scope: Option<String>,
+
+    /// RFC3339 timestamp or `YYYY-MM-DD` (through the end of that day, UTC). Self-subscriptions
+    /// default to `SELF_SUBSCRIPTION_DEFAULT_DAYS` and are capped at `SELF_SUBSCRIPTION_MAX_DAYS`.
+    #[serde(default)]
+    until: Option<String>,
+
+    /// Subscribe another user (owners, maintainers and admins only).
+    #[serde(default)]
+    user: Option<String>,
+}
+
+#[derive(Debug, Default, serde::Deserialize)]
+pub(crate) struct UnsubscribeQuery {
+    #[serde(default)]
+    scope: Option<String>,
+
+    #[serde(default)]
+    user: Option<String>,
+}
+
+pub(crate) async fn list_subscriptions(
+    State(state): State<Arc<AppState>>,
+    Extension(subject): Extension<Subject>,
+    Path(repo_id): Path<String>,
+) -> Result<Json<Vec<LaneSubscription>>, Response> {
+    let repos = state.repos.read().await;
+    let repo = repos.get(&repo_id).ok_or_else(not_found)?;
+    if !can_read(repo, &subject) {
+        return Err(forbidden());
+    }
+
+    let all = can_manage(repo, &subject);
+    let now = time::OffsetDateTime::now_utc();
+    let mut out: Vec<LaneSubscription> = repo
+        .lane_subscriptions
+        .iter()
+        .filter(|s| s.is_active(now) && (all || s.user_id == subject.user_id))
+        .cloned()
+        .collect();
+    out.sort_by(|a, b| (&a.lane, &a.handle, &a.scope).cmp(&(&b.lane, &b.handle, &b.scope)));
+    Ok(Json(out))
+}
+
+pub(crate) async fn subscribe_lane(
+    State(state): State<Arc<AppState>>,
+    Extension(subject): Extension<Subject>,
+    Path((repo_id, lane_id)): Path<(String, String)>,
+    Json(payload): Json<SubscribeRequest>,
+) -> Result<Json<LaneSubscription>, Response> {
+    validate_lane_id(&lane_id).map_err(bad_request)?;
+    if let Some(scope) = &payload.scope {
+        validate_scope_id(scope).map_err(bad_request)?;
+    }
+    let until = payload
+        .until
+        .as_deref()
+        .map(parse_until)
+        .transpose()
+        .map_err(bad_request)?;
+    let (user_id, handle) = subscriber(state.as_ref(), &subject, payload.user.as_deref()).await?;
+    let until = if user_id == subject.user_id {
+        Some(self_subscription_until(until).map_err(bad_request)?)
+    } else {
+        until
+    };
+    let until = until
+        .map(|t| t.format(&time::format_description::well_known::Rfc3339))
+        .transpose()
+        .map_err(|e| internal_error(anyhow::anyhow!(e)))?;
+
+    let mut repos = state.repos.write().await;
+    let repo = repos.get_mut(&repo_id).ok_or_else(not_found)?;
+    if !can_read(repo, &subject) || (user_id != subject.user_id && !can_manage(repo, &subject)) {
+        return Err(forbidden());
+    }
+    let lane = repo.lanes.get(&lane_id).ok_or_else(not_found)?;
+    if lane.archived_at.is_some() {
+        return Err(conflict("lane is archived"));
+    }
+    if let Some(scope) = &payload.scope
+        && !repo.scopes.contains(scope)
+    {
+        return Err(bad_request(anyhow::anyhow!("unknown scope {}", scope)));
+    }
+
+    let subscription = LaneSubscription {
+        lane: lane_id.clone(),
+        user_id: user_id.clone(),
+        handle: handle.clone(),
+        scope: payload.scope.clone(),
+        until: until.clone(),
+        created_at: now_ts(),
+        created_by: subject.user.clone(),
+    };
+    // Re-subscribing replaces the previous expiry; expired subscriptions are dropped.
+    let now = time::OffsetDateTime::now_utc();
+    repo.lane_subscriptions.retain(|s| {
+        s.is_active(now) && !(s.lane == lane_id && s.user_id == user_id && s.scope == payload.scope)
+    });
+    repo.lane_subscriptions.push(subscription.clone());
+    persist_repo(state.as_ref(), repo).map_err(internal_error)?;
+    drop(repos);
+
+    let mut detail = handle;
+    if let Some(scope) = &payload.scope {
+        detail.push_str(&format!(" scope={}", scope));
+    }
+    if let Some(until) = &until {
+        detail.push_str(&format!(" until={}", until));
+    }
+    record_audit(
+        &state,
+        &subject,
+        "lane.subscribe",
+        format!("{}/{}", repo_id, lane_id),
+        Some(detail),
+    )?;
+    Ok(Json(subscription))
+}
+
+pub(crate) async fn unsubscribe_lane(
+    State(state): State<Arc<AppState>>,
+    Extension(subject): Extension<Subject>,
+    Path((repo_id, lane_id)): Path<(String, String)>,
+    Query(query): Query<UnsubscribeQuery>,
+) -> Result<Json<serde_json::Value>, Response> {
+    validate_lane_id(&lane_id).map_err(bad_request)?;
+    let (user_id, handle) = subscriber(state.as_ref(), &subject, query.user.as_deref()).await?;
+
+    let mut repos = state.repos.write().await;
+    let repo = repos.get_mut(&repo_id).ok_or_else(not_found)?;
+    if !can_read(repo, &subject) || (user_id != subject.user_id && !can_manage(repo, &subject)) {
+        return Err(forbidden());
+    }
+
+    let before = repo.lane_subscriptions.len();
+    repo.lane_subscriptions
+        .retain(|s| !(s.lane == lane_id && s.user_id == user_id && s.scope == query.scope));
+    if repo.lane_subscriptions.len() == before {
+        return Err(not_found());
+    }
+    persist_repo(state.as_ref(), repo).map_err(internal_error)?;
+    drop(repos);
+
+    let detail = match &query.scope {
+        Some(scope) => format!("{} scope={}", handle, scope),
+        None => handle,
+    };
+    record_audit(
+        &state,
+        &subject,
+        "lane.unsubscribe",
+        format!("{}/{}", repo_id, lane_id),
+        Some(detail),
+    )?;
+    Ok(Json(serde_json::json!({"ok": true})))
+}
+
+/// The user a subscription request is for: the caller, or `user` by handle.
+async fn subscriber(
+    state: &AppState,
+    subject: &Subject,
+    user: Option<&str>,
+) -> Result<(String, String), Response> {
+    let Some(handle) = user.filter(|h| *h != subject.user) else {
+        return Ok((subject.user_id.clone(), subject.user.clone()));
+    };
+    validate_user_handle(handle).map_err(bad_request)?;
+    let users = state.users.read().await;
+    users
+        .values()
+        .find(|u| u.handle == handle)
+        .map(|u| (u.id.clone(), u.handle.clone()))
+        .ok_or_else(|| bad_request(anyhow::anyhow!("unknown user handle")))
+}
+
+/// Self-subscriptions always expire, so cross-lane reach cannot quietly become permanent.
+fn self_subscription_until(until: Option<time::OffsetDateTime>) -> Result<time::OffsetDateTime> {
+    let now = time::OffsetDateTime::now_utc();
+    let Some(until) = until else {
+        return Ok(now + time::Duration::days(SELF_SUBSCRIPTION_DEFAULT_DAYS));
+    };
+    if until > now + time::Duration::days(SELF_SUBSCRIPTION_MAX_DAYS) {
+        anyhow::bail!(
+            "until must be within {} days for a self-subscription",
+            SELF_SUBSCRIPTION_MAX_DAYS
+        );
+    }
+    Ok(until)
+}
+
+/// Parses `--until`; a bare date means through the end of that day (UTC).
+fn parse_until(value: &str) -> Result<time::OffsetDateTime> {
+    use time::format_description::well_known::Rfc3339;
+
+    let until = match time::OffsetDateTime::parse(value, &Rfc3339) {
+        Ok(t) => t,
+        Err(_) => {
+            let format = time::format_description::parse("[year]-[month]-[day]")
+                .context("build date format")?;
+            let date = time::Date::parse(value, &format)
+                .map_err(|_| anyhow::anyhow!("until must be an RFC3339 timestamp or YYYY-MM-DD"))?;
+            date.next_day()
+                .context("until out of range")?
+                .midnight()
+                .assume_utc()
+        }
+    };
+    if until <= time::OffsetDateTime::now_utc() {
+        anyhow::bail!("until is in the past");
+    }
+    Ok(until)
+}
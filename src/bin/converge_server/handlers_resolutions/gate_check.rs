@@ -0,0 +1,44 @@
+use super::*;
+
+/// True when any input publication carries a resolution for a bundle whose superpositions are
+/// not all recorded as resolved on the server (missing, unresolved, or reopened).
+pub(crate) fn resolution_inputs_open(
+    state: &AppState,
+    repo: &Repo,
+    input_publications: &[String],
+) -> Result<bool, Response> {
+    let mut checked = HashSet::new();
+    for pid in input_publications {
+        let Some(resolution) = repo
+            .publications
+            .iter()
+            .find(|p| &p.id == pid)
+            .and_then(|p| p.resolution.as_ref())
+        else {
+            continue;
+        };
+        if !checked.insert(resolution.bundle_id.clone()) {
+            continue;
+        }
+
+        let records = repo.resolutions.get(&resolution.bundle_id);
+
+        // The source bundle's tree may have been collected; fall back to the recorded paths.
+        let paths: Vec<String> =
+            match manifest_superposition_variants(state, &repo.id, &resolution.root_manifest) {
+                Ok(variants) => variants.into_keys().collect(),
+                Err(_) => records
+                    .map(|r| r.records.keys().cloned().collect())
+                    .unwrap_or_default(),
+            };
+        let all_resolved = paths.iter().all(|path| {
+            records
+                .and_then(|r| r.records.get(path))
+                .is_some_and(|r| r.status == ResolutionStatus::Resolved)
+        });
+        if !all_resolved {
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
@@ -0,0 +1,75 @@
+//! Server-side superposition resolution records (per bundle, per path).
+
+use super::*;
+
+mod gate_check;
+mod read;
+mod record;
+mod reopen;
+mod rules;
+mod view;
+
+pub(super) use self::gate_check::resolution_inputs_open;
+pub(super) use self::rules::{record_rule_applications, rule_origins};
+
+#[derive(Debug, serde::Deserialize)]
+pub(crate) struct RecordResolutionDecision {
+    path: String,
+    decision: converge::model::VariantKey,
+
+    #[serde(default)]
+    method: Option<ResolutionMethod>,
+
+    #[serde(default)]
+    rule: Option<String>,
+
+    #[serde(default)]
+    rationale: Option<String>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+pub(crate) struct RecordResolutionsRequest {
+    decisions: Vec<RecordResolutionDecision>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+pub(crate) struct ReopenResolutionRequest {
+    path: String,
+
+    #[serde(default)]
+    rationale: Option<String>,
+}
+
+pub(super) async fn get_bundle_resolutions(
+    state: State<Arc<AppState>>,
+    subject: Extension<Subject>,
+    ids: Path<(String, String)>,
+) -> Result<Json<BundleResolutions>, Response> {
+    read::get_bundle_resolutions(state, subject, ids).await
+}
+
+pub(super) async fn get_bundle_origins(
+    state: State<Arc<AppState>>,
+    subject: Extension<Subject>,
+    ids: Path<(String, String)>,
+) -> Result<Json<std::collections::BTreeMap<String, converge::resolve::VariantOrigin>>, Response> {
+    read::get_bundle_origins(state, subject, ids).await
+}
+
+pub(super) async fn record_bundle_resolutions(
+    state: State<Arc<AppState>>,
+    subject: Extension<Subject>,
+    ids: Path<(String, String)>,
+    payload: Json<RecordResolutionsRequest>,
+) -> Result<Json<BundleResolutions>, Response> {
+    record::record_bundle_resolutions(state, subject, ids, payload).await
+}
+
+pub(super) async fn reopen_bundle_resolution(
+    state: State<Arc<AppState>>,
+    subject: Extension<Subject>,
+    ids: Path<(String, String)>,
+    payload: Json<ReopenResolutionRequest>,
+) -> Result<Json<BundleResolutions>, Response> {
+    reopen::reopen_bundle_resolution(state, subject, ids, payload).await
+}
@@ -0,0 +1,37 @@
+use super::view::{find_bundle, resolutions_view};
+use super::*;
+
+pub(super) async fn get_bundle_resolutions(
+    State(state): State<Arc<AppState>>,
+    Extension(subject): Extension<Subject>,
+    Path((repo_id, bundle_id)): Path<(String, String)>,
+) -> Result<Json<BundleResolutions>, Response> {
+    validate_object_id(&bundle_id).map_err(bad_request)?;
+
+    let repos = state.repos.read().await;
+    let repo = repos.get(&repo_id).ok_or_else(not_found)?;
+    if !can_read(repo, &subject) {
+        return Err(forbidden());
+    }
+
+    let bundle = find_bundle(state.as_ref(), repo, &bundle_id)?;
+    Ok(Json(resolutions_view(state.as_ref(), repo, &bundle)?))
+}
+
+/// The attribution rules rank variants by, so clients applying rules agree with bundling.
+pub(super) async fn get_bundle_origins(
+    State(state): State<Arc<AppState>>,
+    Extension(subject): Extension<Subject>,
+    Path((repo_id, bundle_id)): Path<(String, String)>,
+) -> Result<Json<std::collections::BTreeMap<String, converge::resolve::VariantOrigin>>, Response> {
+    validate_object_id(&bundle_id).map_err(bad_request)?;
+
+    let repos = state.repos.read().await;
+    let repo = repos.get(&repo_id).ok_or_else(not_found)?;
+    if !can_read(repo, &subject) {
+        return Err(forbidden());
+    }
+
+    let bundle = find_bundle(state.as_ref(), repo, &bundle_id)?;
+    Ok(Json(rule_origins(repo, &bundle.input_publications)))
+}
@@ -0,0 +1,100 @@
+use super::view::{find_bundle, normalize_rationale, now_rfc3339, resolutions_view};
+use super::*;
+
+pub(super) async fn record_bundle_resolutions(
+    State(state): State<Arc<AppState>>,
+    Extension(subject): Extension<Subject>,
+    Path((repo_id, bundle_id)): Path<(String, String)>,
+    Json(payload): Json<RecordResolutionsRequest>,
+) -> Result<Json<BundleResolutions>, Response> {
+    validate_object_id(&bundle_id).map_err(bad_request)?;
+    if payload.decisions.is_empty() {
+        return Err(bad_request(anyhow::anyhow!(
+            "at least one decision is required"
+        )));
+    }
+    let now = now_rfc3339()?;
+
+    let mut repos = state.repos.write().await;
+    let repo = repos.get_mut(&repo_id).ok_or_else(not_found)?;
+    if !can_converge(repo, &subject, Target::Repo) {
+        return Err(forbidden());
+    }
+
+    let bundle = find_bundle(state.as_ref(), repo, &bundle_id)?;
+    if !can_converge(repo, &subject, Target::Gate(&bundle.gate)) {
+        return Err(forbidden());
+    }
+    let variants =
+        manifest_superposition_variants(state.as_ref(), &repo_id, &bundle.root_manifest)?;
+
+    // Validate every decision before mutating anything.
+    for d in &payload.decisions {
+        let Some(vs) = variants.get(&d.path) else {
+            return Err(bad_request(anyhow::anyhow!(
+                "no superposition at path {}",
+                d.path
+            )));
+        };
+        // Manual merges carry new content the resolved publication uploads later.
+        let manual = d.method == Some(ResolutionMethod::Manual) && d.decision.is_manual();
+        if !manual && !vs.iter().any(|v| v.key() == d.decision) {
+            return Err(bad_request(anyhow::anyhow!(
+                "decision key not present at path {}",
+                d.path
+            )));
+        }
+    }
+
+    let entry = repo
+        .resolutions
+        .entry(bundle.id.clone())
+        .or_insert_with(|| BundleResolutions {
+            bundle_id: bundle.id.clone(),
+            root_manifest: bundle.root_manifest.clone(),
+            records: Default::default(),
+        });
+
+    for d in payload.decisions {
+        let rationale = normalize_rationale(d.rationale);
+        let record = entry
+            .records
+            .entry(d.path.clone())
+            .or_insert_with(|| ResolutionRecord::unresolved(&d.path));
+
+        // Re-recording the same decision is a no-op unless it adds a rationale.
+        let unchanged = record.status == ResolutionStatus::Resolved
+            && record.decision.as_ref() == Some(&d.decision)
+            && record.rule == d.rule
+            && (rationale.is_none() || record.rationale == rationale);
+        if unchanged {
+            continue;
+        }
+
+        if rationale.is_some() || record.decision.as_ref() != Some(&d.decision) {
+            record.rationale = rationale.clone();
+        }
+        record.status = ResolutionStatus::Resolved;
+        record.resolver = Some(subject.user.clone());
+        record.resolver_user_id = Some(subject.user_id.clone());
+        let default_method = if d.rule.is_some() {
+            ResolutionMethod::Rule
+        } else {
+            ResolutionMethod::Pick
+        };
+        record.method = Some(d.method.unwrap_or(default_method));
+        record.rule = d.rule;
+        record.decision = Some(d.decision);
+        record.updated_at = Some(now.clone());
+        record.history.push(ResolutionEvent {
+            status: ResolutionStatus::Resolved,
+            actor: subject.user.clone(),
+            actor_user_id: Some(subject.user_id.clone()),
+            rationale,
+            at: now.clone(),
+        });
+    }
+
+    persist_repo(state.as_ref(), repo).map_err(internal_error)?;
+    Ok(Json(resolutions_view(state.as_ref(), repo, &bundle)?))
+}
@@ -0,0 +1,53 @@
+use super::view::{find_bundle, normalize_rationale, now_rfc3339, resolutions_view};
+use super::*;
+
+pub(super) async fn reopen_bundle_resolution(
+    State(state): State<Arc<AppState>>,
+    Extension(subject): Extension<Subject>,
+    Path((repo_id, bundle_id)): Path<(String, String)>,
+    Json(payload): Json<ReopenResolutionRequest>,
+) -> Result<Json<BundleResolutions>, Response> {
+    validate_object_id(&bundle_id).map_err(bad_request)?;
+    let now = now_rfc3339()?;
+
+    let mut repos = state.repos.write().await;
+    let repo = repos.get_mut(&repo_id).ok_or_else(not_found)?;
+    if !can_converge(repo, &subject, Target::Repo) {
+        return Err(forbidden());
+    }
+
+    let bundle = find_bundle(state.as_ref(), repo, &bundle_id)?;
+    if !can_converge(repo, &subject, Target::Gate(&bundle.gate)) {
+        return Err(forbidden());
+    }
+    let Some(record) = repo
+        .resolutions
+        .get_mut(&bundle.id)
+        .and_then(|r| r.records.get_mut(&payload.path))
+    else {
+        return Err(bad_request(anyhow::anyhow!(
+            "no resolution recorded at path {}",
+            payload.path
+        )));
+    };
+    if record.status != ResolutionStatus::Resolved {
+        return Err(conflict("resolution is not resolved"));
+    }
+
+    let rationale = normalize_rationale(payload.rationale);
+    record.status = ResolutionStatus::Reopened;
+    record.decision = None;
+    record.rule = None;
+    record.rationale = rationale.clone();
+    record.updated_at = Some(now.clone());
+    record.history.push(ResolutionEvent {
+        status: ResolutionStatus::Reopened,
+        actor: subject.user.clone(),
+        actor_user_id: Some(subject.user_id.clone()),
+        rationale,
+        at: now,
+    });
+
+    persist_repo(state.as_ref(), repo).map_err(internal_error)?;
+    Ok(Json(resolutions_view(state.as_ref(), repo, &bundle)?))
+}
@@ -0,0 +1,87 @@
+use std::collections::BTreeMap;
+
+use super::*;
+
+/// Creation time and owning lanes for each input publication, keyed by publication id.
+///
+/// A publication belongs to a lane when one of the lane's heads (current or retained history)
+/// points at its snap; otherwise to the lanes recorded on it when it was published.
+pub(crate) fn rule_origins(
+    repo: &Repo,
+    input_publications: &[String],
+) -> BTreeMap<String, converge::resolve::VariantOrigin> {
+    let mut out = BTreeMap::new();
+    for pid in input_publications {
+        let Some(p) = repo.publications.iter().find(|p| &p.id == pid) else {
+            continue;
+        };
+
+        let mut lanes: Vec<String> = repo
+            .lanes
+            .values()
+            .filter(|lane| {
+                lane.heads.values().any(|h| h.snap_id == p.snap_id)
+                    || lane
+                        .head_history
+                        .values()
+                        .any(|hs| hs.iter().any(|h| h.snap_id == p.snap_id))
+            })
+            .map(|lane| lane.id.clone())
+            .collect();
+        if lanes.is_empty() {
+            lanes = p.lanes.clone().unwrap_or_default();
+        }
+        lanes.sort();
+
+        out.insert(
+            pid.clone(),
+            converge::resolve::VariantOrigin {
+                created_at: p.created_at.clone(),
+                lanes,
+            },
+        );
+    }
+    out
+}
+
+/// Stores a resolved record (method `rule`) for every superposition a rule decided.
+pub(crate) fn record_rule_applications(
+    repo: &mut Repo,
+    bundle: &Bundle,
+    applications: Vec<RuleApplication>,
+    subject: &Subject,
+    at: &str,
+) {
+    if applications.is_empty() {
+        return;
+    }
+
+    let entry = repo
+        .resolutions
+        .entry(bundle.id.clone())
+        .or_insert_with(|| BundleResolutions {
+            bundle_id: bundle.id.clone(),
+            root_manifest: bundle.root_manifest.clone(),
+            records: Default::default(),
+        });
+    for app in applications {
+        let rationale = Some(format!("rule {} ({})", app.rule, app.strategy));
+        let mut record = ResolutionRecord::unresolved(&app.path);
+        record.status = ResolutionStatus::Resolved;
+        record.resolver = Some(subject.user.clone());
+        record.resolver_user_id = Some(subject.user_id.clone());
+        record.method = Some(ResolutionMethod::Rule);
+        record.rationale = rationale.clone();
+        record.rule = Some(app.rule);
+        record.decision = app.decision;
+        record.updated_at = Some(at.to_string());
+        record.history.push(ResolutionEvent {
+            status: ResolutionStatus::Resolved,
+            actor: subject.user.clone(),
+            actor_user_id: Some(subject.user_id.clone()),
+            rationale,
+            at: at.to_string(),
+        });
+        entry.records.insert(app.path.clone(), record);
+    }
+}
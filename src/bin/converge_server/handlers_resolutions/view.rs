@@ -0,0 +1,50 @@
+use super::*;
+
+pub(super) fn find_bundle(
+    state: &AppState,
+    repo: &Repo,
+    bundle_id: &str,
+) -> Result<Bundle, Response> {
+    if let Some(b) = repo.bundles.iter().find(|b| b.id == bundle_id) {
+        return Ok(b.clone());
+    }
+    load_bundle_from_disk(state, &repo.id, bundle_id)
+}
+
+/// Returns the stored records for a bundle, with an `unresolved` record filled in for every
+/// superposition path that has not been decided yet.
+pub(super) fn resolutions_view(
+    state: &AppState,
+    repo: &Repo,
+    bundle: &Bundle,
+) -> Result<BundleResolutions, Response> {
+    let paths = manifest_superposition_variants(state, &repo.id, &bundle.root_manifest)?;
+
+    let mut out = repo
+        .resolutions
+        .get(&bundle.id)
+        .cloned()
+        .unwrap_or_else(|| BundleResolutions {
+            bundle_id: bundle.id.clone(),
+            root_manifest: bundle.root_manifest.clone(),
+            records: Default::default(),
+        });
+    for path in paths.keys() {
+        out.records
+            .entry(path.clone())
+            .or_insert_with(|| ResolutionRecord::unresolved(path));
+    }
+    Ok(out)
+}
+
+pub(super) fn now_rfc3339() -> Result<String, Response> {
+    time::OffsetDateTime::now_utc()
+        .format(&time::format_description::well_known::Rfc3339)
+        .map_err(|e| internal_error(anyhow::anyhow!(e)))
+}
+
+pub(super) fn normalize_rationale(rationale: Option<String>) -> Option<String> {
+    rationale
+        .map(|r| r.trim().to_string())
+        .filter(|r| !r.is_empty())
+}
@@ -27,7 +27,7 @@ pub(super) async fn require_bearer(
         return unauthorized();
     };
 
-    let (user_id, handle, admin) = {
+    let (user_id, handle, admin, token_repos, token_capabilities) = {
         let tokens = state.tokens.read().await;
         let Some(t) = tokens.get(&token_id) else {
             return unauthorized();
@@ -47,7 +47,19 @@ pub(super) async fn require_bearer(
         let Some(u) = users.get(&t.user_id) else {
             return unauthorized();
         };
-        (u.id.clone(), u.handle.clone(), u.admin)
+        let scoped = t.repos.is_some() || t.capabilities.is_some();
+        (
+            u.id.clone(),
+            u.handle.clone(),
+            u.admin && !scoped,
+            t.repos.clone(),
+            t.capabilities.clone(),
+        )
+    };
+
+    let groups = {
+        let groups = state.groups.read().await;
+        groups_of_user(&groups, &user_id)
     };
 
     // Best-effort last_used tracking (in-memory only).
@@ -63,6 +75,9 @@ pub(super) async fn require_bearer(
         user_id,
         user: handle,
         admin,
+        token_repos,
+        token_capabilities,
+        groups,
     });
     next.run(req).await
 }
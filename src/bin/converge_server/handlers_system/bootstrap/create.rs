@@ -11,6 +11,7 @@ pub(super) fn create_admin_user(
         display_name: payload.display_name.clone(),
         admin: true,
         created_at: created_at.to_string(),
+        signing_keys: Vec::new(),
     })
 }
 
@@ -69,6 +70,8 @@ pub(super) async fn create_bootstrap_token(
                 last_used_at: None,
                 revoked_at: None,
                 expires_at: None,
+                repos: None,
+                capabilities: None,
             },
         );
     }
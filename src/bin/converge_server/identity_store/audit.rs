@@ -0,0 +1,31 @@
+use std::io::Write as _;
+
+use super::*;
+
+/// Appends one entry to `audit.jsonl`.
+pub(crate) fn append_audit(data_dir: &std::path::Path, entry: &AuditEntry) -> Result<()> {
+    let mut line = serde_json::to_vec(entry).context("serialize audit entry")?;
+    line.push(b'\n');
+    let mut file = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(identity_audit_path(data_dir))
+        .context("open audit.jsonl")?;
+    file.write_all(&line).context("append audit.jsonl")?;
+    Ok(())
+}
+
+/// The newest `limit` audit entries, oldest first.
+pub(crate) fn read_audit(data_dir: &std::path::Path, limit: usize) -> Result<Vec<AuditEntry>> {
+    let path = identity_audit_path(data_dir);
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+    let text = std::fs::read_to_string(&path).context("read audit.jsonl")?;
+    let mut out = Vec::new();
+    for line in text.lines().filter(|l| !l.trim().is_empty()) {
+        out.push(serde_json::from_str::<AuditEntry>(line).context("parse audit entry")?);
+    }
+    let skip = out.len().saturating_sub(limit);
+    Ok(out.split_off(skip))
+}
@@ -15,6 +15,7 @@ pub(crate) fn bootstrap_identity(handle: &str, token_secret: &str) -> (User, Acc
         display_name: None,
         admin: true,
         created_at: created_at.clone(),
+        signing_keys: Vec::new(),
     };
 
     let token_hash = hash_token(token_secret);
@@ -34,6 +35,8 @@ pub(crate) fn bootstrap_identity(handle: &str, token_secret: &str) -> (User, Acc
         last_used_at: None,
         revoked_at: None,
         expires_at: None,
+        repos: None,
+        capabilities: None,
     };
 
     (user, token)
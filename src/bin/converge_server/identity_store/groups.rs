@@ -0,0 +1,65 @@
+use super::*;
+
+pub(crate) fn load_groups_from_disk(data_dir: &std::path::Path) -> Result<HashMap<String, Group>> {
+    let path = identity_groups_path(data_dir);
+    if !path.exists() {
+        return Ok(HashMap::new());
+    }
+    let bytes = std::fs::read(&path).context("read groups.json")?;
+    let list: Vec<Group> = serde_json::from_slice(&bytes).context("parse groups.json")?;
+    Ok(list.into_iter().map(|g| (g.id.clone(), g)).collect())
+}
+
+pub(crate) fn persist_groups_to_disk(
+    data_dir: &std::path::Path,
+    groups: &HashMap<String, Group>,
+) -> Result<()> {
+    let mut list: Vec<Group> = groups.values().cloned().collect();
+    list.sort_by(|a, b| a.name.cmp(&b.name));
+    let bytes = serde_json::to_vec_pretty(&list).context("serialize groups")?;
+    write_atomic_overwrite(&identity_groups_path(data_dir), &bytes).context("write groups.json")?;
+    Ok(())
+}
+
+/// Ids of every group `user_id` belongs to, directly or through nested groups.
+pub(crate) fn groups_of_user(groups: &HashMap<String, Group>, user_id: &str) -> Vec<String> {
+    let mut out: Vec<String> = groups
+        .values()
+        .filter(|g| g.member_user_ids.iter().any(|u| u == user_id))
+        .map(|g| g.id.clone())
+        .collect();
+    // A group containing a group we're in contains us too; repeat until nothing new is found.
+    loop {
+        let more: Vec<String> = groups
+            .values()
+            .filter(|g| !out.contains(&g.id))
+            .filter(|g| g.member_group_ids.iter().any(|m| out.contains(m)))
+            .map(|g| g.id.clone())
+            .collect();
+        if more.is_empty() {
+            break;
+        }
+        out.extend(more);
+    }
+    out.sort();
+    out
+}
+
+/// Whether `target` is `root` or nested (at any depth) inside it.
+pub(crate) fn group_contains(groups: &HashMap<String, Group>, root: &str, target: &str) -> bool {
+    let mut seen: Vec<&str> = Vec::new();
+    let mut stack = vec![root];
+    while let Some(id) = stack.pop() {
+        if id == target {
+            return true;
+        }
+        if seen.contains(&id) {
+            continue;
+        }
+        seen.push(id);
+        if let Some(g) = groups.get(id) {
+            stack.extend(g.member_group_ids.iter().map(String::as_str));
+        }
+    }
+    false
+}
@@ -1,9 +1,18 @@
 use super::*;
 
+mod audit;
 mod bootstrap;
 mod disk;
+mod groups;
 mod util;
 
+pub(super) use self::audit::{append_audit, read_audit};
 pub(super) use self::bootstrap::{bootstrap_identity, generate_token_secret};
 pub(super) use self::disk::{load_identity_from_disk, persist_identity_to_disk};
-pub(super) use self::util::{hash_token, identity_tokens_path, identity_users_path, now_ts};
+pub(super) use self::groups::{
+    group_contains, groups_of_user, load_groups_from_disk, persist_groups_to_disk,
+};
+pub(super) use self::util::{
+    hash_token, identity_audit_path, identity_groups_path, identity_tokens_path,
+    identity_users_path, now_ts,
+};
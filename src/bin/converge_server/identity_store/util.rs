@@ -15,3 +15,11 @@ pub(crate) fn identity_users_path(data_dir: &std::path::Path) -> std::path::Path
 pub(crate) fn identity_tokens_path(data_dir: &std::path::Path) -> std::path::PathBuf {
     data_dir.join("tokens.json")
 }
+
+pub(crate) fn identity_groups_path(data_dir: &std::path::Path) -> std::path::PathBuf {
+    data_dir.join("groups.json")
+}
+
+pub(crate) fn identity_audit_path(data_dir: &std::path::Path) -> std::path::PathBuf {
+    data_dir.join("audit.jsonl")
+}
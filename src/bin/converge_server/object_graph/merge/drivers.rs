@@ -0,0 +1,101 @@
+use super::super::store::{read_blob, store_blob};
+use super::rewrite::{SuperpositionEdit, rewrite_superpositions};
+use super::*;
+
+/// Runs structured merge drivers over file superpositions.
+///
+/// Clean merges replace the superposition with the merged file; conflicting merges keep it and
+/// annotate each variant with the key paths it is involved in.
+pub(crate) fn apply_merge_drivers(
+    state: &AppState,
+    repo_id: &str,
+    root_manifest_id: &str,
+    base_root: Option<&str>,
+    registry: &converge::merge::MergeDriverRegistry,
+) -> Result<String, Response> {
+    rewrite_superpositions(state, repo_id, root_manifest_id, &mut |path, variants| {
+        let Some(driver) = registry.driver_for(path) else {
+            return Ok(SuperpositionEdit::Keep);
+        };
+
+        let mut inputs = Vec::with_capacity(variants.len());
+        let mut mode = None;
+        for v in variants {
+            let converge::model::SuperpositionVariantKind::File { blob, mode: m, .. } = &v.kind
+            else {
+                return Ok(SuperpositionEdit::Keep);
+            };
+            let Some(bytes) = read_blob(state, repo_id, blob.as_str())? else {
+                return Ok(SuperpositionEdit::Keep);
+            };
+            mode.get_or_insert(*m);
+            inputs.push(bytes);
+        }
+        let Some(mode) = mode else {
+            return Ok(SuperpositionEdit::Keep);
+        };
+
+        let base = match base_root {
+            Some(root) => base_file_bytes(state, repo_id, root, path),
+            None => None,
+        };
+        let refs = inputs.iter().map(|b| b.as_slice()).collect::<Vec<_>>();
+        let Ok(outcome) = driver.merge(base.as_deref(), &refs) else {
+            return Ok(SuperpositionEdit::Keep);
+        };
+
+        if let Some(merged) = outcome.merged {
+            let size = merged.len() as u64;
+            let blob = store_blob(state, repo_id, &merged)?;
+            return Ok(SuperpositionEdit::Replace(
+                converge::model::ManifestEntryKind::File {
+                    blob: converge::model::ObjectId(blob),
+                    mode,
+                    size,
+                },
+            ));
+        }
+
+        let annotated = variants
+            .iter()
+            .enumerate()
+            .map(|(idx, v)| {
+                let mut v = v.clone();
+                let metadata = v.metadata.get_or_insert_with(Default::default);
+                metadata.merge_driver = Some(driver.name().to_string());
+                metadata.conflicting_keys = outcome
+                    .conflicts
+                    .iter()
+                    .filter(|c| c.inputs.contains(&idx))
+                    .map(|c| c.path.clone())
+                    .collect();
+                v
+            })
+            .collect();
+        Ok(SuperpositionEdit::Replace(
+            converge::model::ManifestEntryKind::Superposition {
+                variants: annotated,
+            },
+        ))
+    })
+}
+
+// Best effort: a base that was garbage collected just means a two-way merge.
+fn base_file_bytes(state: &AppState, repo_id: &str, root: &str, path: &str) -> Option<Vec<u8>> {
+    let mut manifest_id = root.to_string();
+    let mut parts = path.split('/').peekable();
+    while let Some(part) = parts.next() {
+        let manifest = read_manifest(state, repo_id, &manifest_id).ok()?;
+        let entry = manifest.entries.into_iter().find(|e| e.name == part)?;
+        match (entry.kind, parts.peek().is_some()) {
+            (converge::model::ManifestEntryKind::Dir { manifest }, true) => {
+                manifest_id = manifest.as_str().to_string();
+            }
+            (converge::model::ManifestEntryKind::File { blob, .. }, false) => {
+                return read_blob(state, repo_id, blob.as_str()).ok().flatten();
+            }
+            _ => return None,
+        }
+    }
+    None
+}
@@ -6,6 +6,7 @@ pub(super) fn merge_dir_manifests(
     state: &AppState,
     repo_id: &str,
     inputs: &[(String, String)],
+    provenance: &VariantProvenance,
 ) -> Result<String, Response> {
     use std::collections::{BTreeMap, BTreeSet};
 
@@ -34,7 +35,7 @@ pub(super) fn merge_dir_manifests(
             kinds.push((pub_id.clone(), map.get(&name).cloned()));
         }
 
-        if let Some(entry) = try_merge_present_dir(state, repo_id, &name, &kinds)? {
+        if let Some(entry) = try_merge_present_dir(state, repo_id, &name, &kinds, provenance)? {
             out_entries.push(entry);
             continue;
         }
@@ -43,7 +44,7 @@ pub(super) fn merge_dir_manifests(
             continue;
         }
 
-        out_entries.push(variants::superposition_entry(name, kinds));
+        out_entries.push(variants::superposition_entry(name, kinds, provenance));
     }
 
     let merged = converge::model::Manifest {
@@ -63,6 +64,7 @@ fn try_merge_present_dir(
     repo_id: &str,
     name: &str,
     kinds: &[(String, Option<converge::model::ManifestEntryKind>)],
+    provenance: &VariantProvenance,
 ) -> Result<Option<converge::model::ManifestEntry>, Response> {
     let all_present = kinds.iter().all(|(_, k)| k.is_some());
     if !all_present {
@@ -87,7 +89,7 @@ fn try_merge_present_dir(
             (pub_id.clone(), manifest.as_str().to_string())
         })
         .collect::<Vec<_>>();
-    let merged_child = merge_dir_manifests(state, repo_id, &child_inputs)?;
+    let merged_child = merge_dir_manifests(state, repo_id, &child_inputs, provenance)?;
     Ok(Some(converge::model::ManifestEntry {
         name: name.to_string(),
         kind: converge::model::ManifestEntryKind::Dir {
@@ -1,6 +1,7 @@
 pub(super) fn superposition_entry(
     name: String,
     kinds: Vec<(String, Option<converge::model::ManifestEntryKind>)>,
+    provenance: &super::VariantProvenance,
 ) -> converge::model::ManifestEntry {
     let mut variants = Vec::new();
     for (pub_id, kind) in kinds {
@@ -23,9 +24,11 @@ pub(super) fn superposition_entry(
             }
             None => converge::model::SuperpositionVariantKind::Tombstone,
         };
+        let metadata = provenance.get(&pub_id).cloned();
         variants.push(converge::model::SuperpositionVariant {
             source: pub_id,
             kind: vkind,
+            metadata,
         });
     }
 
@@ -1,17 +1,28 @@
 use super::super::*;
 use super::store::{read_manifest, store_manifest, validate_manifest_entry_refs};
 
+mod drivers;
 mod manifest_merge;
 mod promotability;
+mod rewrite;
+mod rules;
 
+pub(super) use self::drivers::apply_merge_drivers;
 pub(super) use self::promotability::compute_promotability;
+pub(crate) use self::rules::RuleApplication;
+pub(super) use self::rules::{apply_resolution_rules, tree_rules_file};
+
+/// Per-publication context copied onto every superposition variant it sources.
+pub(crate) type VariantProvenance =
+    std::collections::BTreeMap<String, converge::model::VariantMetadata>;
 
 pub(super) fn coalesce_root_manifest(
     state: &AppState,
     repo_id: &str,
     inputs: &[(String, String)],
+    provenance: &VariantProvenance,
 ) -> Result<String, Response> {
     let mut sorted_inputs = inputs.to_vec();
     sorted_inputs.sort_by(|a, b| a.0.cmp(&b.0));
-    manifest_merge::merge_dir_manifests(state, repo_id, &sorted_inputs)
+    manifest_merge::merge_dir_manifests(state, repo_id, &sorted_inputs, provenance)
 }
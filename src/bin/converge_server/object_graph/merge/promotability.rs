@@ -4,14 +4,26 @@ pub(in crate::object_graph) fn compute_promotability(
     gate: &GateDef,
     has_superpositions: bool,
     approval_count: usize,
+    resolutions_open: bool,
+    unsigned_inputs: bool,
+    rules_invalid: bool,
 ) -> (bool, Vec<String>) {
     let mut reasons = Vec::new();
     if has_superpositions && !gate.allow_superpositions {
         reasons.push("superpositions_present".to_string());
     }
+    if resolutions_open {
+        reasons.push("resolutions_open".to_string());
+    }
     if approval_count < gate.required_approvals as usize {
         reasons.push("approvals_missing".to_string());
     }
+    if unsigned_inputs {
+        reasons.push("signatures_missing".to_string());
+    }
+    if rules_invalid {
+        reasons.push("rules_invalid".to_string());
+    }
     (reasons.is_empty(), reasons)
 }
 
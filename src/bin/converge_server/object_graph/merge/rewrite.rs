@@ -0,0 +1,91 @@
+use super::*;
+
+/// What a rewrite pass does with one superposition entry.
+pub(super) enum SuperpositionEdit {
+    Keep,
+    Replace(converge::model::ManifestEntryKind),
+    Remove,
+}
+
+/// Decides one superposition given its path and variants.
+pub(super) type EditFn<'a> = dyn FnMut(&str, &[converge::model::SuperpositionVariant]) -> Result<SuperpositionEdit, Response>
+    + 'a;
+
+/// Walks the tree under `root_manifest_id`, letting `edit` decide each superposition; returns
+/// the (possibly unchanged) root manifest id.
+pub(super) fn rewrite_superpositions(
+    state: &AppState,
+    repo_id: &str,
+    root_manifest_id: &str,
+    edit: &mut EditFn<'_>,
+) -> Result<String, Response> {
+    Ok(
+        rewrite_manifest(state, repo_id, "", root_manifest_id, edit)?
+            .unwrap_or_else(|| root_manifest_id.to_string()),
+    )
+}
+
+fn rewrite_manifest(
+    state: &AppState,
+    repo_id: &str,
+    prefix: &str,
+    manifest_id: &str,
+    edit: &mut EditFn<'_>,
+) -> Result<Option<String>, Response> {
+    let manifest = read_manifest(state, repo_id, manifest_id)?;
+    let mut changed = false;
+    let mut entries = Vec::with_capacity(manifest.entries.len());
+
+    for entry in manifest.entries {
+        let path = if prefix.is_empty() {
+            entry.name.clone()
+        } else {
+            format!("{}/{}", prefix, entry.name)
+        };
+
+        match &entry.kind {
+            converge::model::ManifestEntryKind::Dir { manifest } => {
+                if let Some(child) =
+                    rewrite_manifest(state, repo_id, &path, manifest.as_str(), edit)?
+                {
+                    changed = true;
+                    entries.push(converge::model::ManifestEntry {
+                        name: entry.name,
+                        kind: converge::model::ManifestEntryKind::Dir {
+                            manifest: converge::model::ObjectId(child),
+                        },
+                    });
+                    continue;
+                }
+            }
+            converge::model::ManifestEntryKind::Superposition { variants } => {
+                match edit(&path, variants)? {
+                    SuperpositionEdit::Keep => {}
+                    SuperpositionEdit::Replace(kind) => {
+                        changed = true;
+                        entries.push(converge::model::ManifestEntry {
+                            name: entry.name,
+                            kind,
+                        });
+                        continue;
+                    }
+                    SuperpositionEdit::Remove => {
+                        changed = true;
+                        continue;
+                    }
+                }
+            }
+            _ => {}
+        }
+        entries.push(entry);
+    }
+
+    if !changed {
+        return Ok(None);
+    }
+    let rewritten = converge::model::Manifest {
+        version: manifest.version,
+        entries,
+    };
+    store_manifest(state, repo_id, &rewritten).map(Some)
+}
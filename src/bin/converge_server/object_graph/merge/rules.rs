@@ -0,0 +1,145 @@
+use std::collections::BTreeMap;
+
+use super::super::store::{read_blob, store_blob};
+use super::rewrite::{SuperpositionEdit, rewrite_superpositions};
+use super::*;
+
+/// A superposition that a resolution rule resolved while coalescing a bundle.
+#[derive(Clone, Debug)]
+pub(crate) struct RuleApplication {
+    pub(crate) path: String,
+    pub(crate) rule: String,
+    pub(crate) strategy: &'static str,
+
+    /// Variant the rule selected (absent when the rule synthesized new content).
+    pub(crate) decision: Option<converge::model::VariantKey>,
+}
+
+/// The tree's root `converge-rules.json` (empty when absent); the inner error means the file
+/// is malformed.
+pub(crate) fn tree_rules_file(
+    state: &AppState,
+    repo_id: &str,
+    root_manifest_id: &str,
+) -> Result<anyhow::Result<converge::model::ResolutionRules>, Response> {
+    let root = read_manifest(state, repo_id, root_manifest_id)?;
+    let Some(entry) = root
+        .entries
+        .iter()
+        .find(|e| e.name == converge::model::RESOLUTION_RULES_FILE)
+    else {
+        return Ok(Ok(Default::default()));
+    };
+    let converge::model::ManifestEntryKind::File { blob, .. } = &entry.kind else {
+        return Ok(Ok(Default::default()));
+    };
+    let Some(bytes) = read_blob(state, repo_id, blob.as_str())? else {
+        return Ok(Ok(Default::default()));
+    };
+    Ok(converge::resolve::parse_resolution_rules(&bytes))
+}
+
+/// Rewrites every superposition a rule can decide; returns the new root and what was applied.
+pub(crate) fn apply_resolution_rules(
+    state: &AppState,
+    repo_id: &str,
+    root_manifest_id: &str,
+    rules: &[converge::model::ResolutionRule],
+    origins: &BTreeMap<String, converge::resolve::VariantOrigin>,
+) -> Result<(String, Vec<RuleApplication>), Response> {
+    let mut applied = Vec::new();
+    if rules.is_empty() {
+        return Ok((root_manifest_id.to_string(), applied));
+    }
+
+    let root = rewrite_superpositions(state, repo_id, root_manifest_id, &mut |path, variants| {
+        let rule = converge::resolve::matching_rule(rules, path).map_err(bad_request)?;
+        let Some(rule) = rule else {
+            return Ok(SuperpositionEdit::Keep);
+        };
+
+        let (edit, decision) = match &rule.strategy {
+            converge::model::ResolutionStrategy::UnionLines => {
+                match union_file_variants(state, repo_id, variants)? {
+                    Some(kind) => (SuperpositionEdit::Replace(kind), None),
+                    None => return Ok(SuperpositionEdit::Keep),
+                }
+            }
+            strategy => {
+                let Some(idx) = converge::resolve::choose_variant(strategy, variants, origins)
+                else {
+                    return Ok(SuperpositionEdit::Keep);
+                };
+                let variant = &variants[idx];
+                let edit = match variant_entry_kind(&variant.kind) {
+                    Some(kind) => SuperpositionEdit::Replace(kind),
+                    None => SuperpositionEdit::Remove,
+                };
+                (edit, Some(variant.key()))
+            }
+        };
+
+        applied.push(RuleApplication {
+            path: path.to_string(),
+            rule: rule.name.clone(),
+            strategy: rule.strategy.as_str(),
+            decision,
+        });
+        Ok(edit)
+    })?;
+    Ok((root, applied))
+}
+
+fn union_file_variants(
+    state: &AppState,
+    repo_id: &str,
+    variants: &[converge::model::SuperpositionVariant],
+) -> Result<Option<converge::model::ManifestEntryKind>, Response> {
+    let mut contents = Vec::new();
+    let mut mode = None;
+    for v in variants {
+        match &v.kind {
+            converge::model::SuperpositionVariantKind::File { blob, mode: m, .. } => {
+                let Some(bytes) = read_blob(state, repo_id, blob.as_str())? else {
+                    return Ok(None);
+                };
+                mode.get_or_insert(*m);
+                contents.push(bytes);
+            }
+            converge::model::SuperpositionVariantKind::Tombstone => {}
+            _ => return Ok(None),
+        }
+    }
+    let Some(mode) = mode else {
+        return Ok(None);
+    };
+
+    let merged = converge::resolve::union_lines(&contents);
+    let size = merged.len() as u64;
+    let blob = store_blob(state, repo_id, &merged)?;
+    Ok(Some(converge::model::ManifestEntryKind::File {
+        blob: converge::model::ObjectId(blob),
+        mode,
+        size,
+    }))
+}
+
+fn variant_entry_kind(
+    kind: &converge::model::SuperpositionVariantKind,
+) -> Option<converge::model::ManifestEntryKind> {
+    match kind.clone() {
+        converge::model::SuperpositionVariantKind::File { blob, mode, size } => {
+            Some(converge::model::ManifestEntryKind::File { blob, mode, size })
+        }
+        converge::model::SuperpositionVariantKind::FileChunks { recipe, mode, size } => {
+            Some(converge::model::ManifestEntryKind::FileChunks { recipe, mode, size })
+        }
+        converge::model::SuperpositionVariantKind::Dir { manifest } => {
+            Some(converge::model::ManifestEntryKind::Dir { manifest })
+        }
+        converge::model::SuperpositionVariantKind::Symlink { target } => {
+            Some(converge::model::ManifestEntryKind::Symlink { target })
+        }
+        converge::model::SuperpositionVariantKind::Tombstone => None,
+    }
+}
@@ -6,6 +6,9 @@ mod merge;
 mod store;
 mod traversal;
 
+pub(crate) use self::merge::{RuleApplication, VariantProvenance};
+pub(crate) use self::store::RepoObjects;
+
 pub(super) fn validate_manifest_entry_refs(
     state: &AppState,
     repo_id: &str,
@@ -23,6 +26,14 @@ pub(super) fn read_snap(
     store::read_snap(state, repo_id, snap_id)
 }
 
+pub(super) fn read_recipe(
+    state: &AppState,
+    repo_id: &str,
+    recipe_id: &str,
+) -> Result<converge::model::FileRecipe, Response> {
+    store::read_recipe(state, repo_id, recipe_id)
+}
+
 pub(super) fn collect_objects_from_manifest_tree(
     state: &AppState,
     repo_id: &str,
@@ -54,8 +65,46 @@ pub(super) fn coalesce_root_manifest(
     state: &AppState,
     repo_id: &str,
     inputs: &[(String, String)],
+    provenance: &VariantProvenance,
 ) -> Result<String, Response> {
-    merge::coalesce_root_manifest(state, repo_id, inputs)
+    merge::coalesce_root_manifest(state, repo_id, inputs, provenance)
+}
+
+pub(super) fn tree_rules_file(
+    state: &AppState,
+    repo_id: &str,
+    root_manifest_id: &str,
+) -> Result<anyhow::Result<converge::model::ResolutionRules>, Response> {
+    merge::tree_rules_file(state, repo_id, root_manifest_id)
+}
+
+/// Whether the tree's `converge-rules.json` is malformed (and so was skipped while bundling).
+pub(super) fn tree_rules_invalid(
+    state: &AppState,
+    repo_id: &str,
+    root_manifest_id: &str,
+) -> Result<bool, Response> {
+    Ok(merge::tree_rules_file(state, repo_id, root_manifest_id)?.is_err())
+}
+
+pub(super) fn apply_merge_drivers(
+    state: &AppState,
+    repo_id: &str,
+    root_manifest_id: &str,
+    base_root: Option<&str>,
+    registry: &converge::merge::MergeDriverRegistry,
+) -> Result<String, Response> {
+    merge::apply_merge_drivers(state, repo_id, root_manifest_id, base_root, registry)
+}
+
+pub(super) fn apply_resolution_rules(
+    state: &AppState,
+    repo_id: &str,
+    root_manifest_id: &str,
+    rules: &[converge::model::ResolutionRule],
+    origins: &std::collections::BTreeMap<String, converge::resolve::VariantOrigin>,
+) -> Result<(String, Vec<RuleApplication>), Response> {
+    merge::apply_resolution_rules(state, repo_id, root_manifest_id, rules, origins)
 }
 
 pub(super) fn manifest_has_superpositions(
@@ -66,10 +115,29 @@ pub(super) fn manifest_has_superpositions(
     traversal::manifest_has_superpositions(state, repo_id, root_manifest_id)
 }
 
+pub(super) fn manifest_superposition_variants(
+    state: &AppState,
+    repo_id: &str,
+    root_manifest_id: &str,
+) -> Result<std::collections::BTreeMap<String, Vec<converge::model::SuperpositionVariant>>, Response>
+{
+    traversal::manifest_superposition_variants(state, repo_id, root_manifest_id)
+}
+
 pub(super) fn compute_promotability(
     gate: &GateDef,
     has_superpositions: bool,
     approval_count: usize,
+    resolutions_open: bool,
+    unsigned_inputs: bool,
+    rules_invalid: bool,
 ) -> (bool, Vec<String>) {
-    merge::compute_promotability(gate, has_superpositions, approval_count)
+    merge::compute_promotability(
+        gate,
+        has_superpositions,
+        approval_count,
+        resolutions_open,
+        unsigned_inputs,
+        rules_invalid,
+    )
 }
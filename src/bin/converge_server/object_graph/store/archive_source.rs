@@ -0,0 +1,31 @@
+use super::super::super::*;
+
+use converge::model::{FileRecipe, Manifest, ObjectId};
+
+/// A repo's object store as an archive source; owned so it can move into a blocking task.
+pub(crate) struct RepoObjects {
+    pub(crate) state: Arc<AppState>,
+    pub(crate) repo_id: String,
+}
+
+impl converge::archive::ObjectSource for RepoObjects {
+    fn manifest(&self, id: &ObjectId) -> Result<Manifest> {
+        super::readers::read_manifest(&self.state, &self.repo_id, id.as_str())
+            .map_err(|r| object_error("manifest", id, r))
+    }
+
+    fn blob(&self, id: &ObjectId) -> Result<Vec<u8>> {
+        super::readers::read_blob(&self.state, &self.repo_id, id.as_str())
+            .map_err(|r| object_error("blob", id, r))?
+            .with_context(|| format!("missing blob {}", id.as_str()))
+    }
+
+    fn recipe(&self, id: &ObjectId) -> Result<FileRecipe> {
+        super::readers::read_recipe(&self.state, &self.repo_id, id.as_str())
+            .map_err(|r| object_error("recipe", id, r))
+    }
+}
+
+fn object_error(kind: &str, id: &ObjectId, response: Response) -> anyhow::Error {
+    anyhow::anyhow!("read {} {}: {}", kind, id.as_str(), response.status())
+}
@@ -1,9 +1,12 @@
 use super::super::*;
 
+mod archive_source;
 mod entry_validation;
 mod readers;
 mod writers;
 
+pub(crate) use self::archive_source::RepoObjects;
+
 pub(super) fn validate_manifest_entry_refs(
     state: &AppState,
     repo_id: &str,
@@ -44,3 +47,19 @@ pub(super) fn store_manifest(
 ) -> Result<String, Response> {
     writers::store_manifest(state, repo_id, manifest)
 }
+
+pub(super) fn read_blob(
+    state: &AppState,
+    repo_id: &str,
+    blob_id: &str,
+) -> Result<Option<Vec<u8>>, Response> {
+    readers::read_blob(state, repo_id, blob_id)
+}
+
+pub(super) fn store_blob(
+    state: &AppState,
+    repo_id: &str,
+    bytes: &[u8],
+) -> Result<String, Response> {
+    writers::store_blob(state, repo_id, bytes)
+}
@@ -66,6 +66,31 @@ pub(super) fn read_manifest(
     Ok(manifest)
 }
 
+/// Blob bytes, or `None` when the blob was never uploaded (metadata-only publications).
+pub(super) fn read_blob(
+    state: &AppState,
+    repo_id: &str,
+    blob_id: &str,
+) -> Result<Option<Vec<u8>>, Response> {
+    validate_object_id(blob_id).map_err(bad_request)?;
+    let path = repo_data_dir(state, repo_id)
+        .join("objects/blobs")
+        .join(blob_id);
+    if !path.exists() {
+        return Ok(None);
+    }
+    let bytes = std::fs::read(&path)
+        .with_context(|| format!("read {}", path.display()))
+        .map_err(|e| internal_error(anyhow::anyhow!(e)))?;
+    let actual = blake3::hash(&bytes).to_hex().to_string();
+    if actual != blob_id {
+        return Err(internal_error(anyhow::anyhow!(
+            "blob integrity check failed"
+        )));
+    }
+    Ok(Some(bytes))
+}
+
 fn recipe_path(state: &AppState, repo_id: &str, recipe_id: &str) -> std::path::PathBuf {
     repo_data_dir(state, repo_id)
         .join("objects/recipes")
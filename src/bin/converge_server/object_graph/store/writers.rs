@@ -13,3 +13,16 @@ pub(super) fn store_manifest(
     write_if_absent(&path, &bytes).map_err(internal_error)?;
     Ok(id)
 }
+
+pub(super) fn store_blob(
+    state: &AppState,
+    repo_id: &str,
+    bytes: &[u8],
+) -> Result<String, Response> {
+    let id = blake3::hash(bytes).to_hex().to_string();
+    let path = repo_data_dir(state, repo_id)
+        .join("objects/blobs")
+        .join(&id);
+    write_if_absent(&path, bytes).map_err(internal_error)?;
+    Ok(id)
+}
@@ -6,5 +6,7 @@ mod superpositions;
 mod validate;
 
 pub(super) use self::collect::collect_objects_from_manifest_tree;
-pub(super) use self::superpositions::manifest_has_superpositions;
+pub(super) use self::superpositions::{
+    manifest_has_superpositions, manifest_superposition_variants,
+};
 pub(super) use self::validate::validate_manifest_tree_availability;
@@ -34,3 +34,41 @@ pub(crate) fn manifest_has_superpositions(
 
     inner(state, repo_id, root_manifest_id, &mut HashSet::new())
 }
+
+pub(crate) fn manifest_superposition_variants(
+    state: &AppState,
+    repo_id: &str,
+    root_manifest_id: &str,
+) -> Result<std::collections::BTreeMap<String, Vec<converge::model::SuperpositionVariant>>, Response>
+{
+    let mut out = std::collections::BTreeMap::new();
+    let mut stack = vec![(String::new(), root_manifest_id.to_string())];
+    let mut visited = HashSet::new();
+
+    while let Some((prefix, manifest_id)) = stack.pop() {
+        if !visited.insert((prefix.clone(), manifest_id.clone())) {
+            continue;
+        }
+        let manifest = read_manifest(state, repo_id, &manifest_id)?;
+        for e in manifest.entries {
+            let path = if prefix.is_empty() {
+                e.name.clone()
+            } else {
+                format!("{}/{}", prefix, e.name)
+            };
+            match e.kind {
+                converge::model::ManifestEntryKind::Superposition { variants } => {
+                    out.insert(path, variants);
+                }
+                converge::model::ManifestEntryKind::Dir { manifest } => {
+                    stack.push((path, manifest.as_str().to_string()));
+                }
+                converge::model::ManifestEntryKind::File { .. } => {}
+                converge::model::ManifestEntryKind::FileChunks { .. } => {}
+                converge::model::ManifestEntryKind::Symlink { .. } => {}
+            }
+        }
+    }
+
+    Ok(out)
+}
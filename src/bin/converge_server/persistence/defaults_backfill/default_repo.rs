@@ -14,7 +14,12 @@ pub(crate) fn default_repo_state(state: &AppState, repo_id: &str) -> Repo {
     let default_lane = Lane {
         id: "default".to_string(),
         members,
+        description: None,
+        gates: Vec::new(),
+        default_scope: None,
+        archived_at: None,
         member_user_ids,
+        member_group_ids: HashSet::new(),
         heads: HashMap::new(),
         head_history: HashMap::new(),
     };
@@ -27,10 +32,14 @@ pub(crate) fn default_repo_state(state: &AppState, repo_id: &str) -> Repo {
             id: "dev-intake".to_string(),
             name: "Dev Intake".to_string(),
             upstream: vec![],
+            lane: None,
             allow_releases: true,
             allow_superpositions: false,
             allow_metadata_only_publications: false,
             required_approvals: 0,
+            require_signed: false,
+            roles: GateRoles::default(),
+            resolution_rules: Vec::new(),
         }],
     };
 
@@ -45,7 +54,9 @@ pub(crate) fn default_repo_state(state: &AppState, repo_id: &str) -> Repo {
         reader_user_ids,
         publishers,
         publisher_user_ids,
+        role_grants: Vec::new(),
         lanes,
+        lane_subscriptions: Vec::new(),
         gate_graph,
         scopes,
         snaps: HashSet::new(),
@@ -55,5 +66,6 @@ pub(crate) fn default_repo_state(state: &AppState, repo_id: &str) -> Repo {
         promotions: Vec::new(),
         promotion_state: HashMap::new(),
         releases: Vec::new(),
+        resolutions: HashMap::new(),
     }
 }
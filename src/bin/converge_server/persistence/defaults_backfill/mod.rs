@@ -3,7 +3,9 @@ use super::super::*;
 mod acl;
 mod default_repo;
 mod provenance;
+mod publication_lanes;
 
 pub(crate) use self::acl::backfill_acl_user_ids;
 pub(crate) use self::default_repo::default_repo_state;
 pub(crate) use self::provenance::backfill_provenance_user_ids;
+pub(crate) use self::publication_lanes::backfill_publication_lanes;
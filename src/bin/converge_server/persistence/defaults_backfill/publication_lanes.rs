@@ -0,0 +1,33 @@
+use super::*;
+
+/// Records lanes on publications stored before they carried them, from current lane membership
+/// (direct or through groups), so older publications do not become visible to every reader.
+pub(crate) fn backfill_publication_lanes(repo: &mut Repo, groups: &HashMap<String, Group>) {
+    for p in &mut repo.publications {
+        if p.lanes.is_some() {
+            continue;
+        }
+        let user_groups = p
+            .publisher_user_id
+            .as_deref()
+            .map(|uid| groups_of_user(groups, uid))
+            .unwrap_or_default();
+        let mut lanes: Vec<String> = repo
+            .lanes
+            .values()
+            .filter(|lane| {
+                lane.members.contains(&p.publisher)
+                    || p.publisher_user_id
+                        .as_ref()
+                        .is_some_and(|uid| lane.member_user_ids.contains(uid))
+                    || lane
+                        .member_group_ids
+                        .iter()
+                        .any(|g| user_groups.contains(g))
+            })
+            .map(|lane| lane.id.clone())
+            .collect();
+        lanes.sort();
+        p.lanes = Some(lanes);
+    }
+}
@@ -3,7 +3,8 @@ mod io_paths;
 mod repo_load;
 
 pub(super) use self::defaults_backfill::{
-    backfill_acl_user_ids, backfill_provenance_user_ids, default_repo_state,
+    backfill_acl_user_ids, backfill_provenance_user_ids, backfill_publication_lanes,
+    default_repo_state,
 };
 pub(super) use self::io_paths::{
     load_bundle_from_disk, persist_repo, repo_data_dir, repo_state_path, write_atomic_overwrite,
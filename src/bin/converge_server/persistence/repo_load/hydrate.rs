@@ -9,6 +9,7 @@ use super::promotion_state::rebuild_promotion_state;
 pub(super) fn load_repos_from_disk(
     state: &AppState,
     handle_to_id: &HashMap<String, String>,
+    groups: &HashMap<String, Group>,
 ) -> Result<HashMap<String, Repo>> {
     let mut out = HashMap::new();
     if !state.data_dir.is_dir() {
@@ -27,7 +28,7 @@ pub(super) fn load_repos_from_disk(
             .into_string()
             .map_err(|_| anyhow::anyhow!("non-utf8 repo dir name"))?;
 
-        let repo = load_repo_from_disk(state, &repo_id, handle_to_id)
+        let repo = load_repo_from_disk(state, &repo_id, handle_to_id, groups)
             .with_context(|| format!("load repo {}", repo_id))?;
         out.insert(repo_id, repo);
     }
@@ -39,6 +40,7 @@ fn load_repo_from_disk(
     state: &AppState,
     repo_id: &str,
     handle_to_id: &HashMap<String, String>,
+    groups: &HashMap<String, Group>,
 ) -> Result<Repo> {
     let mut repo = if repo_state_path(state, repo_id).exists() {
         let bytes = std::fs::read(repo_state_path(state, repo_id)).context("read repo.json")?;
@@ -75,6 +77,7 @@ fn load_repo_from_disk(
     // Backfill user_id fields for older on-disk records (best-effort).
     backfill_provenance_user_ids(&mut repo, handle_to_id);
     backfill_acl_user_ids(&mut repo, handle_to_id);
+    backfill_publication_lanes(&mut repo, groups);
 
     Ok(repo)
 }
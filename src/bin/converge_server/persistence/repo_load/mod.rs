@@ -7,6 +7,7 @@ mod promotion_state;
 pub(crate) fn load_repos_from_disk(
     state: &AppState,
     handle_to_id: &HashMap<String, String>,
+    groups: &HashMap<String, Group>,
 ) -> Result<HashMap<String, Repo>> {
-    hydrate::load_repos_from_disk(state, handle_to_id)
+    hydrate::load_repos_from_disk(state, handle_to_id, groups)
 }
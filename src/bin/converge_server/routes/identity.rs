@@ -13,4 +13,17 @@ pub(super) fn register_identity_routes(router: Router<Arc<AppState>>) -> Router<
             "/tokens/:token_id/revoke",
             axum::routing::post(revoke_token),
         )
+        .route("/keys", get(list_keys).post(register_key))
+        .route("/keys/:key_id/revoke", axum::routing::post(revoke_key))
+        .route("/groups", get(list_groups).post(create_group))
+        .route("/groups/:name", get(get_group).delete(delete_group))
+        .route(
+            "/groups/:name/members",
+            axum::routing::post(add_group_member),
+        )
+        .route(
+            "/groups/:name/members/:handle",
+            axum::routing::delete(remove_group_member),
+        )
+        .route("/audit", get(list_audit))
 }
@@ -18,4 +18,9 @@ pub(super) fn register_membership_routes(router: Router<Arc<AppState>>) -> Route
             "/repos/:repo_id/lanes/:lane_id/members/:handle",
             axum::routing::delete(remove_lane_member),
         )
+        .route("/repos/:repo_id/subscriptions", get(list_subscriptions))
+        .route(
+            "/repos/:repo_id/lanes/:lane_id/subscriptions",
+            axum::routing::post(subscribe_lane).delete(unsubscribe_lane),
+        )
 }
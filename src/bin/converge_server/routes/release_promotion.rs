@@ -12,6 +12,14 @@ pub(super) fn register_release_promotion_routes(
             "/repos/:repo_id/releases/:channel",
             get(get_release_channel),
         )
+        .route(
+            "/repos/:repo_id/releases/:channel/archive",
+            get(get_release_archive),
+        )
+        .route(
+            "/repos/:repo_id/releases/:channel/attachments",
+            axum::routing::post(attach_release_file),
+        )
         .route(
             "/repos/:repo_id/promotions",
             get(list_promotions).post(create_promotion),
@@ -5,7 +5,11 @@ pub(super) fn register_repo_core_routes(router: Router<Arc<AppState>>) -> Router
         .route("/repos", get(list_repos).post(create_repo))
         .route("/repos/:repo_id", get(get_repo))
         .route("/repos/:repo_id/permissions", get(get_repo_permissions))
-        .route("/repos/:repo_id/lanes", get(list_lanes))
+        .route("/repos/:repo_id/lanes", get(list_lanes).post(create_lane))
+        .route(
+            "/repos/:repo_id/lanes/:lane_id",
+            axum::routing::patch(update_lane).delete(delete_lane),
+        )
         .route(
             "/repos/:repo_id/lanes/:lane_id/heads/me",
             axum::routing::post(update_lane_head_me),
@@ -45,4 +49,20 @@ pub(super) fn register_repo_core_routes(router: Router<Arc<AppState>>) -> Router
             "/repos/:repo_id/bundles/:bundle_id/approve",
             axum::routing::post(approve_bundle),
         )
+        .route(
+            "/repos/:repo_id/bundles/:bundle_id/checks",
+            axum::routing::post(report_bundle_check),
+        )
+        .route(
+            "/repos/:repo_id/bundles/:bundle_id/resolutions",
+            get(get_bundle_resolutions).post(record_bundle_resolutions),
+        )
+        .route(
+            "/repos/:repo_id/bundles/:bundle_id/origins",
+            get(get_bundle_origins),
+        )
+        .route(
+            "/repos/:repo_id/bundles/:bundle_id/resolutions/reopen",
+            axum::routing::post(reopen_bundle_resolution),
+        )
 }
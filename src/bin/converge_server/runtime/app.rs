@@ -10,13 +10,14 @@ use super::super::handlers_system::{bootstrap, healthz};
 use super::super::identity_store::hash_token;
 use super::super::persistence::load_repos_from_disk;
 use super::super::routes::authed_router;
-use super::super::types::{AccessToken, AppState, User};
+use super::super::types::{AccessToken, AppState, Group, User};
 use super::Args;
 
 pub(super) fn build_state(
     args: &Args,
     users: HashMap<String, User>,
     tokens: HashMap<String, AccessToken>,
+    groups: HashMap<String, Group>,
 ) -> Arc<AppState> {
     let default_user = users
         .values()
@@ -37,6 +38,7 @@ pub(super) fn build_state(
         users: Arc::new(RwLock::new(users)),
         tokens: Arc::new(RwLock::new(tokens)),
         token_hash_index: Arc::new(RwLock::new(token_hash_index)),
+        groups: Arc::new(RwLock::new(groups)),
         bootstrap_token_hash: args.bootstrap_token.as_deref().map(hash_token),
     })
 }
@@ -48,10 +50,11 @@ pub(super) async fn load_repos_into_state(state: &Arc<AppState>) -> Result<()> {
         .map(|u| (u.handle.clone(), u.id.clone()))
         .collect();
     drop(users);
+    let groups = state.groups.read().await.clone();
 
     // Best-effort load repos from disk so the dev server survives restarts.
-    let loaded =
-        load_repos_from_disk(state.as_ref(), &handle_to_id).context("load repos from disk")?;
+    let loaded = load_repos_from_disk(state.as_ref(), &handle_to_id, &groups)
+        .context("load repos from disk")?;
     {
         let mut repos = state.repos.write().await;
         *repos = loaded;
@@ -13,6 +13,7 @@ use self::app::{build_app_router, build_state, load_repos_into_state};
 use self::identity::load_or_bootstrap_identity;
 use self::listener::{bind_listener, maybe_write_addr_file};
 use self::shutdown::shutdown_signal;
+use super::identity_store::load_groups_from_disk;
 
 #[derive(Parser)]
 #[command(name = "converge-server")]
@@ -57,7 +58,8 @@ pub(super) async fn run() -> Result<()> {
         .with_context(|| format!("create data dir {}", args.data_dir.display()))?;
 
     let (users, tokens) = load_or_bootstrap_identity(&args)?;
-    let state = build_state(&args, users, tokens);
+    let groups = load_groups_from_disk(&args.data_dir).context("load groups")?;
+    let state = build_state(&args, users, tokens, groups);
     load_repos_into_state(&state).await?;
 
     let app = build_app_router(state);
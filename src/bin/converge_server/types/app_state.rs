@@ -12,6 +12,7 @@ pub(crate) struct AppState {
     pub(crate) users: Arc<RwLock<HashMap<String, User>>>,
     pub(crate) tokens: Arc<RwLock<HashMap<String, AccessToken>>>,
     pub(crate) token_hash_index: Arc<RwLock<HashMap<String, String>>>,
+    pub(crate) groups: Arc<RwLock<HashMap<String, Group>>>,
 
     // Optional one-time bootstrap token (hash) used to create the first admin.
     // Enabled only when the server is started with `--bootstrap-token`.
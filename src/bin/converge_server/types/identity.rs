@@ -5,6 +5,53 @@ pub(crate) struct Subject {
 
     #[allow(dead_code)]
     pub(crate) admin: bool,
+
+    /// Allowlists of the token used for this request; `None` means unrestricted.
+    pub(crate) token_repos: Option<Vec<String>>,
+    pub(crate) token_capabilities: Option<Vec<TokenCapability>>,
+
+    /// Ids of every group the user belongs to, directly or through nested groups.
+    pub(crate) groups: Vec<String>,
+}
+
+impl Subject {
+    pub(crate) fn in_group(&self, group_id: &str) -> bool {
+        self.groups.iter().any(|g| g == group_id)
+    }
+
+    /// Whether the request was made with a repo- or capability-limited token.
+    pub(crate) fn is_scoped(&self) -> bool {
+        self.token_repos.is_some() || self.token_capabilities.is_some()
+    }
+
+    /// Whether the token's allowlists permit `capability` on `repo_id`.
+    /// Any granted capability implies `read` on the repos the token covers.
+    pub(crate) fn token_allows(&self, repo_id: &str, capability: TokenCapability) -> bool {
+        if let Some(repos) = &self.token_repos
+            && !repos.iter().any(|r| r == repo_id)
+        {
+            return false;
+        }
+        match &self.token_capabilities {
+            None => true,
+            Some(caps) => {
+                caps.contains(&capability)
+                    || (capability == TokenCapability::Read && !caps.is_empty())
+            }
+        }
+    }
+}
+
+/// A capability an access token can be limited to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub(crate) enum TokenCapability {
+    Read,
+    Publish,
+    ReportChecks,
+    Approve,
+    Promote,
+    Release,
 }
 
 #[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
@@ -19,6 +66,24 @@ pub(crate) struct User {
     pub(crate) admin: bool,
 
     pub(crate) created_at: String,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub(crate) signing_keys: Vec<SigningKeyRecord>,
+}
+
+/// A registered ed25519 public key; the id is `converge::signing::key_id` of the key bytes.
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub(crate) struct SigningKeyRecord {
+    pub(crate) id: String,
+    pub(crate) public_key: String,
+
+    #[serde(default)]
+    pub(crate) label: Option<String>,
+
+    pub(crate) created_at: String,
+
+    #[serde(default)]
+    pub(crate) revoked_at: Option<String>,
 }
 
 #[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
@@ -42,4 +107,45 @@ pub(crate) struct AccessToken {
 
     #[serde(default)]
     pub(crate) expires_at: Option<String>,
+
+    /// Repo ids this token may access; `None` means every repo the user can access.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) repos: Option<Vec<String>>,
+
+    /// Capabilities this token may exercise; `None` means everything the user can do.
+    /// Scoped tokens never carry the user's admin rights.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) capabilities: Option<Vec<TokenCapability>>,
+}
+
+/// A named set of users and nested groups, managed by admins.
+/// Anywhere a user can be granted access, `@<name>` grants it to every member.
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub(crate) struct Group {
+    pub(crate) id: String,
+    pub(crate) name: String,
+
+    #[serde(default)]
+    pub(crate) description: Option<String>,
+
+    #[serde(default)]
+    pub(crate) member_user_ids: Vec<String>,
+
+    // Ids of nested groups; their members are members of this group too.
+    #[serde(default)]
+    pub(crate) member_group_ids: Vec<String>,
+
+    pub(crate) created_at: String,
+}
+
+/// One line of the append-only identity and membership audit log.
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub(crate) struct AuditEntry {
+    pub(crate) at: String,
+    pub(crate) actor: String,
+    pub(crate) action: String,
+    pub(crate) target: String,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) detail: Option<String>,
 }
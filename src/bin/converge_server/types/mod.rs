@@ -5,8 +5,12 @@ mod identity;
 mod repo;
 
 pub(crate) use self::app_state::AppState;
-pub(crate) use self::identity::{AccessToken, Subject, User};
+pub(crate) use self::identity::{
+    AccessToken, AuditEntry, Group, SigningKeyRecord, Subject, TokenCapability, User,
+};
 pub(crate) use self::repo::{
-    Bundle, Gate, GateDef, GateGraph, LANE_HEAD_HISTORY_KEEP_LAST, Lane, LaneHead, Promotion,
-    Publication, PublicationResolution, Release, Repo,
+    Bundle, BundleResolutions, CheckReport, CheckStatus, Gate, GateDef, GateGraph, GateRoles,
+    LANE_HEAD_HISTORY_KEEP_LAST, Lane, LaneHead, LaneSubscription, Promotion, Publication,
+    PublicationResolution, Release, ReleaseAttachment, Repo, RepoRole, ResolutionEvent,
+    ResolutionMethod, ResolutionRecord, ResolutionStatus, RoleGrant,
 };
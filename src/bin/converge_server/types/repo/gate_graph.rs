@@ -1,3 +1,5 @@
+use super::GateRoles;
+
 #[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
 pub(crate) struct Gate {
     pub(crate) id: String,
@@ -16,6 +18,10 @@ pub(crate) struct GateDef {
     pub(crate) name: String,
     pub(crate) upstream: Vec<String>,
 
+    // Lane that owns this gate; only its members (and admins) bundle, approve and promote here.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) lane: Option<String>,
+
     #[serde(default = "default_true")]
     pub(crate) allow_releases: bool,
 
@@ -27,6 +33,18 @@ pub(crate) struct GateDef {
 
     #[serde(default)]
     pub(crate) required_approvals: u32,
+
+    // Publications, approvals and releases at this gate must carry valid signatures.
+    #[serde(default)]
+    pub(crate) require_signed: bool,
+
+    // Roles allowed to bundle/promote/release at this gate, overriding the defaults.
+    #[serde(default, skip_serializing_if = "GateRoles::is_empty")]
+    pub(crate) roles: GateRoles,
+
+    // Ordered path-glob rules applied to superpositions when bundling at this gate.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub(crate) resolution_rules: Vec<converge::model::ResolutionRule>,
 }
 
 fn default_true() -> bool {
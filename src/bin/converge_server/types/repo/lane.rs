@@ -5,9 +5,28 @@ pub(crate) struct Lane {
     pub(crate) id: String,
     pub(crate) members: HashSet<String>,
 
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) description: Option<String>,
+
+    // Gate ids this lane owns; mirrors `GateDef.lane`, which is what access checks read.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub(crate) gates: Vec<String>,
+
+    // Scope members publish and sync into by default.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) default_scope: Option<String>,
+
+    // Archived lanes keep their history but accept no head updates or subscriptions.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) archived_at: Option<String>,
+
     #[serde(default)]
     pub(crate) member_user_ids: HashSet<String>,
 
+    // Group ids whose members (transitively) are lane members.
+    #[serde(default, skip_serializing_if = "HashSet::is_empty")]
+    pub(crate) member_group_ids: HashSet<String>,
+
     #[serde(default)]
     pub(crate) heads: HashMap<String, LaneHead>,
 
@@ -27,3 +46,34 @@ pub(crate) struct LaneHead {
 }
 
 pub(crate) const LANE_HEAD_HISTORY_KEEP_LAST: usize = 5;
+
+/// Explicit cross-lane read access: lets a non-member see a lane's publications (optionally only
+/// in one scope) and, when not scope-limited, its heads, until `until`.
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub(crate) struct LaneSubscription {
+    pub(crate) lane: String,
+    pub(crate) user_id: String,
+    pub(crate) handle: String,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) scope: Option<String>,
+
+    // RFC3339; `None` means until unsubscribed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) until: Option<String>,
+
+    pub(crate) created_at: String,
+    pub(crate) created_by: String,
+}
+
+impl LaneSubscription {
+    pub(crate) fn is_active(&self, now: time::OffsetDateTime) -> bool {
+        match &self.until {
+            None => true,
+            Some(until) => {
+                time::OffsetDateTime::parse(until, &time::format_description::well_known::Rfc3339)
+                    .is_ok_and(|until| now < until)
+            }
+        }
+    }
+}
@@ -3,12 +3,19 @@ use super::*;
 mod gate_graph;
 mod lane;
 mod publication_flow;
+mod resolution;
+mod roles;
 
 pub(crate) use self::gate_graph::{Gate, GateDef, GateGraph};
-pub(crate) use self::lane::{LANE_HEAD_HISTORY_KEEP_LAST, Lane, LaneHead};
+pub(crate) use self::lane::{LANE_HEAD_HISTORY_KEEP_LAST, Lane, LaneHead, LaneSubscription};
 pub(crate) use self::publication_flow::{
-    Bundle, Promotion, Publication, PublicationResolution, Release,
+    Bundle, CheckReport, CheckStatus, Promotion, Publication, PublicationResolution, Release,
+    ReleaseAttachment,
 };
+pub(crate) use self::resolution::{
+    BundleResolutions, ResolutionEvent, ResolutionMethod, ResolutionRecord, ResolutionStatus,
+};
+pub(crate) use self::roles::{GateRoles, RepoRole, RoleGrant};
 
 #[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
 pub(crate) struct Repo {
@@ -28,8 +35,15 @@ pub(crate) struct Repo {
     #[serde(default)]
     pub(crate) publisher_user_ids: HashSet<String>,
 
+    // Fine-grained role grants; `readers`/`publishers` remain the legacy read/publish roles.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub(crate) role_grants: Vec<RoleGrant>,
+
     pub(crate) lanes: HashMap<String, Lane>,
 
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub(crate) lane_subscriptions: Vec<LaneSubscription>,
+
     pub(crate) gate_graph: GateGraph,
     pub(crate) scopes: HashSet<String>,
 
@@ -46,4 +60,8 @@ pub(crate) struct Repo {
 
     #[serde(default)]
     pub(crate) releases: Vec<Release>,
+
+    // Bundle id -> per-path superposition resolution records.
+    #[serde(default)]
+    pub(crate) resolutions: HashMap<String, BundleResolutions>,
 }
@@ -1,3 +1,5 @@
+use converge::signing::Signature;
+
 #[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
 pub(crate) struct Publication {
     pub(crate) id: String,
@@ -12,6 +14,18 @@ pub(crate) struct Publication {
 
     #[serde(default)]
     pub(crate) resolution: Option<PublicationResolution>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) signature: Option<Signature>,
+
+    // Signature over the published snap record.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) snap_signature: Option<Signature>,
+
+    // Lanes the publisher belonged to (directly or through a group) when publishing; decides
+    // who can see the publication. `None` only on older records until the load-time backfill.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) lanes: Option<Vec<String>>,
 }
 
 #[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
@@ -43,6 +57,38 @@ pub(crate) struct Bundle {
 
     #[serde(default)]
     pub(crate) approval_user_ids: Vec<String>,
+
+    // At most one per approver; only these count when the gate requires signatures.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub(crate) approval_signatures: Vec<Signature>,
+
+    // Latest report per check name (CI results and the like); informational, not a gate input.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub(crate) checks: Vec<CheckReport>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub(crate) enum CheckStatus {
+    Pending,
+    Success,
+    Failure,
+}
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub(crate) struct CheckReport {
+    pub(crate) name: String,
+    pub(crate) status: CheckStatus,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) summary: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) url: Option<String>,
+
+    pub(crate) reported_by: String,
+    pub(crate) reported_by_user_id: String,
+    pub(crate) reported_at: String,
 }
 
 #[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
@@ -76,4 +122,29 @@ pub(crate) struct Release {
 
     #[serde(default)]
     pub(crate) notes: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub(crate) attachments: Vec<ReleaseAttachment>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) signature: Option<Signature>,
+}
+
+/// A file attached to a release (build artifact, SBOM or signature), stored content-addressed as
+/// a blob, or as a chunk recipe when large.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub(crate) struct ReleaseAttachment {
+    pub(crate) name: String,
+    /// `artifact`, `sbom` or `signature`.
+    pub(crate) kind: String,
+    pub(crate) size: u64,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) blob: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) recipe: Option<String>,
+
+    pub(crate) attached_by: String,
+    pub(crate) attached_at: String,
 }
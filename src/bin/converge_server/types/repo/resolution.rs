@@ -0,0 +1,90 @@
+use std::collections::BTreeMap;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum ResolutionStatus {
+    Unresolved,
+    Resolved,
+    Reopened,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum ResolutionMethod {
+    Pick,
+    Manual,
+    Rule,
+}
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub(crate) struct ResolutionEvent {
+    pub(crate) status: ResolutionStatus,
+    pub(crate) actor: String,
+
+    #[serde(default)]
+    pub(crate) actor_user_id: Option<String>,
+
+    #[serde(default)]
+    pub(crate) rationale: Option<String>,
+
+    pub(crate) at: String,
+}
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub(crate) struct ResolutionRecord {
+    pub(crate) path: String,
+    pub(crate) status: ResolutionStatus,
+
+    #[serde(default)]
+    pub(crate) resolver: Option<String>,
+
+    #[serde(default)]
+    pub(crate) resolver_user_id: Option<String>,
+
+    #[serde(default)]
+    pub(crate) method: Option<ResolutionMethod>,
+
+    #[serde(default)]
+    pub(crate) rationale: Option<String>,
+
+    /// Name of the resolution rule that chose the decision (method `rule`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) rule: Option<String>,
+
+    /// Variant chosen by the resolver (absent while unresolved or reopened).
+    #[serde(default)]
+    pub(crate) decision: Option<converge::model::VariantKey>,
+
+    #[serde(default)]
+    pub(crate) updated_at: Option<String>,
+
+    #[serde(default)]
+    pub(crate) history: Vec<ResolutionEvent>,
+}
+
+impl ResolutionRecord {
+    pub(crate) fn unresolved(path: &str) -> Self {
+        Self {
+            path: path.to_string(),
+            status: ResolutionStatus::Unresolved,
+            resolver: None,
+            resolver_user_id: None,
+            method: None,
+            rationale: None,
+            rule: None,
+            decision: None,
+            updated_at: None,
+            history: Vec::new(),
+        }
+    }
+}
+
+// Server-side resolution state for one bundle, keyed by superposition path.
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub(crate) struct BundleResolutions {
+    pub(crate) bundle_id: String,
+    pub(crate) root_manifest: String,
+
+    #[serde(default)]
+    pub(crate) records: BTreeMap<String, ResolutionRecord>,
+}
@@ -0,0 +1,83 @@
+/// A repo role. Grants are per repo and may be narrowed to one gate or lane.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum RepoRole {
+    Reader,
+    Publisher,
+    Converger,
+    Promoter,
+    Releaser,
+    Approver,
+    Maintainer,
+}
+
+impl RepoRole {
+    pub(crate) const ALL: [RepoRole; 7] = [
+        RepoRole::Reader,
+        RepoRole::Publisher,
+        RepoRole::Converger,
+        RepoRole::Promoter,
+        RepoRole::Releaser,
+        RepoRole::Approver,
+        RepoRole::Maintainer,
+    ];
+
+    pub(crate) fn as_str(self) -> &'static str {
+        match self {
+            RepoRole::Reader => "reader",
+            RepoRole::Publisher => "publisher",
+            RepoRole::Converger => "converger",
+            RepoRole::Promoter => "promoter",
+            RepoRole::Releaser => "releaser",
+            RepoRole::Approver => "approver",
+            RepoRole::Maintainer => "maintainer",
+        }
+    }
+
+    pub(crate) fn parse(s: &str) -> Option<RepoRole> {
+        RepoRole::ALL.into_iter().find(|r| r.as_str() == s)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub(crate) struct RoleGrant {
+    // Empty for group grants.
+    #[serde(default)]
+    pub(crate) user_id: String,
+
+    /// User handle, or `@<group>` for group grants.
+    pub(crate) handle: String,
+
+    pub(crate) role: RepoRole,
+
+    /// Set when the role is granted to a group (by id) rather than a user.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) group: Option<String>,
+
+    // At most one of `gate`/`lane` is set; neither means repo-wide.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) gate: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) lane: Option<String>,
+}
+
+/// Per-gate override of which roles may bundle, promote or release there.
+/// An empty list falls back to the matching default role.
+#[derive(Clone, Debug, Default, serde::Serialize, serde::Deserialize)]
+pub(crate) struct GateRoles {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub(crate) bundle: Vec<RepoRole>,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub(crate) promote: Vec<RepoRole>,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub(crate) release: Vec<RepoRole>,
+}
+
+impl GateRoles {
+    pub(crate) fn is_empty(&self) -> bool {
+        self.bundle.is_empty() && self.promote.is_empty() && self.release.is_empty()
+    }
+}
@@ -38,20 +38,7 @@ pub(super) fn validate_scope_id(id: &str) -> Result<()> {
     Ok(())
 }
 
-pub(super) fn validate_release_channel(id: &str) -> Result<()> {
-    if id.is_empty() {
-        return Err(anyhow::anyhow!("release channel cannot be empty"));
-    }
-    if !id
-        .chars()
-        .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
-    {
-        return Err(anyhow::anyhow!(
-            "release channel must be lowercase alnum or '-'"
-        ));
-    }
-    Ok(())
-}
+pub(super) use converge::model::validate_release_channel;
 
 pub(super) fn validate_user_handle(handle: &str) -> Result<()> {
     if handle.is_empty() {
@@ -81,6 +68,21 @@ pub(super) fn validate_gate_id(id: &str) -> Result<()> {
     Ok(())
 }
 
+pub(super) fn validate_check_name(name: &str) -> Result<()> {
+    if name.is_empty() || name.len() > 64 {
+        return Err(anyhow::anyhow!("check name must be 1-64 chars"));
+    }
+    if !name
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.' | '/'))
+    {
+        return Err(anyhow::anyhow!(
+            "check name must be alnum or one of '-', '_', '.', '/'"
+        ));
+    }
+    Ok(())
+}
+
 pub(super) fn validate_lane_id(id: &str) -> Result<()> {
     if id.is_empty() {
         return Err(anyhow::anyhow!("lane id cannot be empty"));
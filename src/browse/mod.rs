@@ -0,0 +1,14 @@
+//! Read-only browsing of snap, bundle and release trees.
+//!
+//! Manifests and file objects missing from the local store are fetched from the remote
+//! only when a listing or read actually needs them.
+
+mod refs;
+mod tree;
+
+pub use refs::{TreeRef, TreeRefKind, resolve_tree_ref};
+pub use tree::{Tree, TreeEntry, normalize_path};
+
+#[cfg(test)]
+#[path = "../tests/browse/tree_tests.rs"]
+mod tests;
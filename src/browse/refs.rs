@@ -0,0 +1,87 @@
+use anyhow::Result;
+
+use crate::model::{ObjectId, validate_release_channel};
+use crate::remote::{NotFound, RemoteClient};
+use crate::store::LocalStore;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TreeRefKind {
+    Snap,
+    Bundle,
+    Release,
+}
+
+impl TreeRefKind {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            TreeRefKind::Snap => "snap",
+            TreeRefKind::Bundle => "bundle",
+            TreeRefKind::Release => "release",
+        }
+    }
+}
+
+/// A named tree root: a snap, a bundle, or the bundle behind a release channel.
+#[derive(Clone, Debug, serde::Serialize)]
+pub struct TreeRef {
+    pub kind: TreeRefKind,
+    /// Snap id, bundle id, or release channel as given.
+    pub id: String,
+    pub root_manifest: ObjectId,
+}
+
+impl TreeRef {
+    pub fn label(&self) -> String {
+        format!("{} {}", self.kind.as_str(), self.id)
+    }
+}
+
+/// Resolves `name` as a local snap, then (with a remote) a bundle id, a release channel,
+/// or a published snap not fetched yet. Only "not found" moves on to the next kind; other remote
+/// errors (auth, network, server) are returned as-is.
+pub fn resolve_tree_ref(
+    store: &LocalStore,
+    remote: Option<&RemoteClient>,
+    name: &str,
+) -> Result<TreeRef> {
+    let tree = |kind, root: String| TreeRef {
+        kind,
+        id: name.to_string(),
+        root_manifest: ObjectId(root),
+    };
+
+    if store.has_snap(name) {
+        let snap = store.get_snap(name)?;
+        return Ok(tree(TreeRefKind::Snap, snap.root_manifest.0));
+    }
+    let Some(remote) = remote else {
+        anyhow::bail!(
+            "no local snap {} (configure a remote to browse bundles and releases)",
+            name
+        );
+    };
+    // The server rejects malformed ids and channels, so only names of the right shape are looked up.
+    let is_id = name.len() == 64 && name.chars().all(|c| matches!(c, '0'..='9' | 'a'..='f'));
+    if is_id && let Some(bundle) = found(remote.get_bundle(name))? {
+        return Ok(tree(TreeRefKind::Bundle, bundle.root_manifest));
+    }
+    let is_channel = validate_release_channel(name).is_ok();
+    if is_channel && let Some(release) = found(remote.get_release(name))? {
+        let bundle = remote.get_bundle(&release.bundle_id)?;
+        return Ok(tree(TreeRefKind::Release, bundle.root_manifest));
+    }
+    if is_id && let Some(snap) = found(remote.get_snap(name))? {
+        return Ok(tree(TreeRefKind::Snap, snap.root_manifest.0));
+    }
+    anyhow::bail!("no snap, bundle or release channel named {}", name)
+}
+
+/// `None` when `result` failed because the remote has no such resource.
+fn found<T>(result: Result<T>) -> Result<Option<T>> {
+    match result {
+        Ok(v) => Ok(Some(v)),
+        Err(err) if err.downcast_ref::<NotFound>().is_some() => Ok(None),
+        Err(err) => Err(err),
+    }
+}
@@ -0,0 +1,174 @@
+use anyhow::{Context, Result};
+
+use crate::model::{
+    Manifest, ManifestEntry, ManifestEntryKind, ObjectId, SuperpositionVariantKind,
+};
+use crate::remote::RemoteClient;
+use crate::store::{LocalStore, find_entry_with};
+
+/// An entry with its full path from the tree root.
+#[derive(Clone, Debug, serde::Serialize)]
+pub struct TreeEntry {
+    pub path: String,
+
+    #[serde(flatten)]
+    pub entry: ManifestEntry,
+}
+
+/// A manifest tree rooted at `root`, read from the local store and filled in from `remote`.
+pub struct Tree<'a> {
+    store: &'a LocalStore,
+    remote: Option<&'a RemoteClient>,
+    root: ObjectId,
+}
+
+impl<'a> Tree<'a> {
+    pub fn new(store: &'a LocalStore, remote: Option<&'a RemoteClient>, root: ObjectId) -> Self {
+        Self {
+            store,
+            remote,
+            root,
+        }
+    }
+
+    /// Entries of the directory at `path` ("" for the root), or the single entry at a non-directory path.
+    pub fn list(&self, path: &str) -> Result<Vec<TreeEntry>> {
+        let path = normalize_path(path)?;
+        let dir = if path.is_empty() {
+            self.root.clone()
+        } else {
+            let entry = self.entry(&path)?;
+            match entry.kind {
+                ManifestEntryKind::Dir { manifest } => manifest,
+                _ => {
+                    return Ok(vec![TreeEntry { path, entry }]);
+                }
+            }
+        };
+        Ok(self
+            .manifest(&dir)?
+            .entries
+            .into_iter()
+            .map(|entry| TreeEntry {
+                path: join(&path, &entry.name),
+                entry,
+            })
+            .collect())
+    }
+
+    /// Every entry below `path`, depth first in name order.
+    pub fn walk(&self, path: &str) -> Result<Vec<TreeEntry>> {
+        let mut out = Vec::new();
+        for e in self.list(path)? {
+            let is_dir = matches!(e.entry.kind, ManifestEntryKind::Dir { .. });
+            let p = e.path.clone();
+            out.push(e);
+            if is_dir {
+                out.extend(self.walk(&p)?);
+            }
+        }
+        Ok(out)
+    }
+
+    /// The entry at `path`; fails if any component is missing or not a directory.
+    pub fn entry(&self, path: &str) -> Result<ManifestEntry> {
+        let path = normalize_path(path)?;
+        self.find(&path)?
+            .with_context(|| format!("no such path: {}", path))
+    }
+
+    /// The entry at `path`, or `None` when it does not exist in this tree.
+    pub fn find(&self, path: &str) -> Result<Option<ManifestEntry>> {
+        let path = normalize_path(path)?;
+        find_entry_with(&self.root, &path, |id| self.manifest(id))
+    }
+
+    /// Contents of the file at `path`.
+    pub fn read(&self, path: &str) -> Result<Vec<u8>> {
+        let entry = self.entry(path)?;
+        match entry.kind {
+            ManifestEntryKind::File { blob, .. } => self.read_blob(&blob),
+            ManifestEntryKind::FileChunks { recipe, .. } => self.read_recipe(&recipe),
+            ManifestEntryKind::Symlink { target } => {
+                anyhow::bail!("{} is a symlink to {}", path, target)
+            }
+            ManifestEntryKind::Dir { .. } => anyhow::bail!("{} is a directory", path),
+            ManifestEntryKind::Superposition { variants } => anyhow::bail!(
+                "{} is a superposition with {} variants; pick one with --variant",
+                path,
+                variants.len()
+            ),
+        }
+    }
+
+    /// Contents of variant `n` (1-based) of the superposition at `path`.
+    pub fn read_variant(&self, path: &str, n: usize) -> Result<Vec<u8>> {
+        let ManifestEntryKind::Superposition { variants } = self.entry(path)?.kind else {
+            anyhow::bail!("{} is not a superposition", path);
+        };
+        let v = n
+            .checked_sub(1)
+            .and_then(|i| variants.get(i))
+            .with_context(|| format!("variant out of range (variants: {})", variants.len()))?;
+        match &v.kind {
+            SuperpositionVariantKind::File { blob, .. } => self.read_blob(blob),
+            SuperpositionVariantKind::FileChunks { recipe, .. } => self.read_recipe(recipe),
+            SuperpositionVariantKind::Symlink { target } => {
+                anyhow::bail!("variant #{} is a symlink to {}", n, target)
+            }
+            SuperpositionVariantKind::Dir { .. } => anyhow::bail!("variant #{} is a directory", n),
+            SuperpositionVariantKind::Tombstone => anyhow::bail!("variant #{} deletes the path", n),
+        }
+    }
+
+    fn manifest(&self, id: &ObjectId) -> Result<Manifest> {
+        if !self.store.has_manifest(id)
+            && let Some(remote) = self.remote
+        {
+            remote.fetch_manifest(self.store, id)?;
+        }
+        self.store.get_manifest(id)
+    }
+
+    fn read_blob(&self, blob: &ObjectId) -> Result<Vec<u8>> {
+        if !self.store.has_blob(blob)
+            && let Some(remote) = self.remote
+        {
+            remote.fetch_file_objects(self.store, Some(blob), None)?;
+        }
+        self.store.get_blob(blob)
+    }
+
+    fn read_recipe(&self, recipe: &ObjectId) -> Result<Vec<u8>> {
+        if let Some(remote) = self.remote {
+            remote.fetch_file_objects(self.store, None, Some(recipe))?;
+        }
+        let recipe = self.store.get_recipe(recipe)?;
+        let mut out = Vec::with_capacity(recipe.size as usize);
+        for c in recipe.chunks {
+            out.extend_from_slice(&self.store.get_blob(&c.blob)?);
+        }
+        Ok(out)
+    }
+}
+
+/// Strips surrounding slashes and `.` components; rejects `..`.
+pub fn normalize_path(path: &str) -> Result<String> {
+    let mut parts = Vec::new();
+    for p in path.split('/') {
+        match p {
+            "" | "." => {}
+            ".." => anyhow::bail!("paths may not contain ..: {}", path),
+            p => parts.push(p),
+        }
+    }
+    Ok(parts.join("/"))
+}
+
+fn join(prefix: &str, name: &str) -> String {
+    if prefix.is_empty() {
+        name.to_string()
+    } else {
+        format!("{}/{}", prefix, name)
+    }
+}
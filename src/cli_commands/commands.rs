@@ -1,8 +1,9 @@
 use clap::Subcommand;
 
 use crate::{
-    GateGraphCommands, LaneCommands, MembersCommands, ReleaseCommands, RemoteCommands,
-    ResolveCommands, TokenCommands, UserCommands,
+    ExportCommands, GateGraphCommands, GroupCommands, ImportCommands, KeyCommands, LaneCommands,
+    MembersCommands, ReleaseCommands, RemoteCommands, ResolveCommands, TokenCommands,
+    TraceCommands, UserCommands,
 };
 
 use super::{delivery, identity, local};
@@ -27,10 +28,40 @@ pub(crate) enum Commands {
     /// Compute a basic diff (workspace vs HEAD, or snap vs snap)
     Diff(local::DiffArgs),
 
+    /// List a directory of a snap, bundle or release tree
+    Ls(local::LsArgs),
+
+    /// Print a file from a snap, bundle or release tree
+    Cat(local::CatArgs),
+
+    /// List the snaps, bundles and releases in which a path changed
+    History(local::HistoryArgs),
+
+    /// Attribute each line of a file to the snap that introduced it
+    Blame(local::BlameArgs),
+
     /// Move/rename a file or directory within the workspace
     #[command(name = "mv")]
     Mv(local::MvArgs),
 
+    /// Import history from another version control system
+    Import {
+        #[command(subcommand)]
+        command: ImportCommands,
+    },
+
+    /// Export releases or bundles to another version control system
+    Export {
+        #[command(subcommand)]
+        command: ExportCommands,
+    },
+
+    /// Write a snap, bundle or release tree as a deterministic tar, tar.zst or zip archive
+    ///
+    /// File modes are normalized to 0644, or 0755 when any execute bit is set, so the same tree
+    /// archives identically whatever umask it was snapped under.
+    Archive(local::ArchiveArgs),
+
     /// Configure or show the remote
     Remote {
         #[command(subcommand)]
@@ -65,6 +96,21 @@ pub(crate) enum Commands {
         command: UserCommands,
     },
 
+    /// Manage groups of users that can be granted repo roles and lane membership
+    Group {
+        #[command(subcommand)]
+        command: GroupCommands,
+    },
+
+    /// Show the identity and membership audit log (admin)
+    Audit(identity::AuditArgs),
+
+    /// Manage ed25519 signing keys for snaps, publications, approvals and releases
+    Key {
+        #[command(subcommand)]
+        command: KeyCommands,
+    },
+
     /// Publish a snap to the configured remote
     Publish(delivery::PublishArgs),
 
@@ -89,6 +135,9 @@ pub(crate) enum Commands {
     /// Fetch objects and publications from the configured remote
     Fetch(delivery::FetchArgs),
 
+    /// Merge an upstream bundle into the workspace (structured merge for JSON/TOML/YAML)
+    Update(delivery::UpdateArgs),
+
     /// Create a bundle on the remote from publications
     Bundle(delivery::BundleArgs),
 
@@ -104,6 +153,12 @@ pub(crate) enum Commands {
     /// Approve a bundle (manual policy step)
     Approve(delivery::ApproveArgs),
 
+    /// Report a CI check result on a bundle (needs a token with `report-checks`)
+    ReportCheck(delivery::ReportCheckArgs),
+
+    /// Check the signature chain of a release or bundle down to its snaps
+    Verify(delivery::VerifyArgs),
+
     /// List pinned bundles on the remote
     Pins(delivery::PinsArgs),
 
@@ -118,4 +173,13 @@ pub(crate) enum Commands {
         #[command(subcommand)]
         command: ResolveCommands,
     },
+
+    /// Open the TUI, or run a scripted TUI session headlessly (--script)
+    Tui(local::TuiArgs),
+
+    /// Analyze agent traces recorded with --agent-trace
+    Trace {
+        #[command(subcommand)]
+        command: TraceCommands,
+    },
 }
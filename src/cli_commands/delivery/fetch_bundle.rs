@@ -14,6 +14,10 @@ pub(crate) struct FetchArgs {
     #[arg(long, conflicts_with_all = ["snap_id", "lane", "user", "bundle_id"])]
     pub(crate) release: Option<String>,
 
+    /// Download a release attachment by name into --into (default: current dir); repeatable
+    #[arg(long = "attachment", requires = "release")]
+    pub(crate) attachments: Vec<String>,
+
     /// Fetch unpublished lane heads (defaults to publications if omitted)
     #[arg(long)]
     pub(crate) lane: Option<String>,
@@ -39,6 +43,21 @@ pub(crate) struct FetchArgs {
     pub(crate) json: bool,
 }
 
+#[derive(Args)]
+pub(crate) struct UpdateArgs {
+    /// Merge this bundle (defaults to the newest promotable bundle at the configured scope/gate)
+    #[arg(long, conflicts_with = "release")]
+    pub(crate) bundle_id: Option<String>,
+
+    /// Merge the bundle behind a release channel
+    #[arg(long, conflicts_with = "bundle_id")]
+    pub(crate) release: Option<String>,
+
+    /// Emit JSON
+    #[arg(long)]
+    pub(crate) json: bool,
+}
+
 #[derive(Args)]
 pub(crate) struct BundleArgs {
     /// Scope (defaults to remote config)
@@ -68,6 +87,28 @@ pub(crate) struct PromoteArgs {
     pub(crate) json: bool,
 }
 
+#[derive(Args)]
+pub(crate) struct ReportCheckArgs {
+    /// Bundle id the check ran against
+    #[arg(long)]
+    pub(crate) bundle_id: String,
+    /// Check name (replaces an earlier report with the same name)
+    #[arg(long)]
+    pub(crate) name: String,
+    /// Result of the check
+    #[arg(long, value_parser = ["pending", "success", "failure"])]
+    pub(crate) status: String,
+    /// One-line summary
+    #[arg(long)]
+    pub(crate) summary: Option<String>,
+    /// Link to the check's logs or details
+    #[arg(long)]
+    pub(crate) url: Option<String>,
+    /// Emit JSON
+    #[arg(long)]
+    pub(crate) json: bool,
+}
+
 #[derive(Args)]
 pub(crate) struct ApproveArgs {
     /// Bundle id to approve
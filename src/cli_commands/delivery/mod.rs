@@ -1,7 +1,11 @@
 mod fetch_bundle;
 mod pins_status;
 mod publish_sync;
+mod verify;
 
-pub(crate) use fetch_bundle::{ApproveArgs, BundleArgs, FetchArgs, PromoteArgs};
+pub(crate) use fetch_bundle::{
+    ApproveArgs, BundleArgs, FetchArgs, PromoteArgs, ReportCheckArgs, UpdateArgs,
+};
 pub(crate) use pins_status::{PinArgs, PinsArgs, StatusArgs};
 pub(crate) use publish_sync::{LanesArgs, PublishArgs, SyncArgs};
+pub(crate) use verify::VerifyArgs;
@@ -0,0 +1,20 @@
+use clap::Args;
+
+#[derive(Args)]
+pub(crate) struct VerifyArgs {
+    /// Release channel to verify (latest release)
+    #[arg(
+        long,
+        conflicts_with = "bundle_id",
+        required_unless_present = "bundle_id"
+    )]
+    pub(crate) release: Option<String>,
+
+    /// Bundle id to verify
+    #[arg(long = "bundle")]
+    pub(crate) bundle_id: Option<String>,
+
+    /// Emit JSON
+    #[arg(long)]
+    pub(crate) json: bool,
+}
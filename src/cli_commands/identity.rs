@@ -20,3 +20,13 @@ pub(crate) struct WhoamiArgs {
     #[arg(long)]
     pub(crate) json: bool,
 }
+
+#[derive(Args)]
+pub(crate) struct AuditArgs {
+    /// Show at most this many of the newest entries
+    #[arg(long, default_value_t = 50)]
+    pub(crate) limit: usize,
+    /// Emit JSON
+    #[arg(long)]
+    pub(crate) json: bool,
+}
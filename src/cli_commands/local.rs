@@ -53,6 +53,56 @@ pub(crate) struct DiffArgs {
     /// Target snap id
     #[arg(long)]
     pub(crate) to: Option<String>,
+    /// Show line-level hunks for each changed file
+    #[arg(long, short = 'p')]
+    pub(crate) patch: bool,
+    /// Emit JSON
+    #[arg(long)]
+    pub(crate) json: bool,
+}
+
+#[derive(Args)]
+pub(crate) struct LsArgs {
+    /// Snap id, bundle id or release channel
+    pub(crate) tree: String,
+    /// Directory (or file) within the tree
+    pub(crate) path: Option<String>,
+    /// List everything below the path
+    #[arg(short = 'r', long)]
+    pub(crate) recursive: bool,
+    /// Emit JSON
+    #[arg(long)]
+    pub(crate) json: bool,
+}
+
+#[derive(Args)]
+pub(crate) struct CatArgs {
+    /// `<snap|bundle|release>:<path>`
+    pub(crate) spec: String,
+    /// Variant number (1-based) when the path is a superposition
+    #[arg(long)]
+    pub(crate) variant: Option<usize>,
+}
+
+#[derive(Args)]
+pub(crate) struct HistoryArgs {
+    /// Path within the workspace tree
+    pub(crate) path: String,
+    /// Show at most this many entries (newest first)
+    #[arg(long)]
+    pub(crate) limit: Option<usize>,
+    /// Emit JSON
+    #[arg(long)]
+    pub(crate) json: bool,
+}
+
+#[derive(Args)]
+pub(crate) struct BlameArgs {
+    /// File path within the workspace tree
+    pub(crate) path: String,
+    /// Snap to blame (defaults to HEAD)
+    #[arg(long)]
+    pub(crate) snap: Option<String>,
     /// Emit JSON
     #[arg(long)]
     pub(crate) json: bool,
@@ -63,3 +113,49 @@ pub(crate) struct MvArgs {
     pub(crate) from: String,
     pub(crate) to: String,
 }
+
+#[derive(Args)]
+pub(crate) struct ArchiveArgs {
+    /// Archive a local snap
+    #[arg(long, conflicts_with_all = ["bundle_id", "release"], required_unless_present_any = ["bundle_id", "release"])]
+    pub(crate) snap: Option<String>,
+
+    /// Archive a bundle from the remote
+    #[arg(long = "bundle", conflicts_with = "release")]
+    pub(crate) bundle_id: Option<String>,
+
+    /// Archive the bundle behind a release channel
+    #[arg(long)]
+    pub(crate) release: Option<String>,
+
+    /// Archive format: tar, tar.zst or zip
+    #[arg(long, default_value = "tar")]
+    pub(crate) format: String,
+
+    /// Output file (`-` for stdout)
+    #[arg(short = 'o', long)]
+    pub(crate) output: String,
+
+    /// Emit JSON
+    #[arg(long)]
+    pub(crate) json: bool,
+}
+
+#[derive(Args)]
+pub(crate) struct TuiArgs {
+    /// Drive the TUI headlessly from a JSONL script instead of the terminal
+    #[arg(long, value_name = "PATH")]
+    pub(crate) script: Option<PathBuf>,
+
+    /// Screen width for --script
+    #[arg(long, default_value_t = 100, requires = "script")]
+    pub(crate) width: u16,
+
+    /// Screen height for --script
+    #[arg(long, default_value_t = 30, requires = "script")]
+    pub(crate) height: u16,
+
+    /// Emit the rendered screen after every step
+    #[arg(long, requires = "script")]
+    pub(crate) frames: bool,
+}
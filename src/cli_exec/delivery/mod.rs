@@ -3,13 +3,16 @@ use super::*;
 mod moderation_status;
 mod publish_sync;
 mod transfer;
+mod verify;
 
 pub(super) use self::moderation_status::{
-    handle_approve_command, handle_pin_command, handle_pins_command, handle_status_command,
+    handle_approve_command, handle_pin_command, handle_pins_command, handle_report_check_command,
+    handle_status_command,
 };
 pub(super) use self::publish_sync::{
     handle_lanes_command, handle_publish_command, handle_sync_command,
 };
 pub(super) use self::transfer::{
-    handle_bundle_command, handle_fetch_command, handle_promote_command,
+    handle_bundle_command, handle_fetch_command, handle_promote_command, handle_update_command,
 };
+pub(super) use self::verify::handle_verify_command;
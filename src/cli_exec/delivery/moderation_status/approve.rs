@@ -2,7 +2,7 @@ use super::super::*;
 
 pub(super) fn handle_approve_command(ws: &Workspace, bundle_id: String, json: bool) -> Result<()> {
     let (remote, token) = require_remote_and_token(&ws.store)?;
-    let client = RemoteClient::new(remote, token)?;
+    let client = RemoteClient::new(remote, token)?.with_signer(ws.store.signing_key()?);
     let bundle = client.approve_bundle(&bundle_id)?;
     if json {
         println!(
@@ -0,0 +1,25 @@
+use super::*;
+
+pub(super) fn handle_report_check_command(
+    ws: &Workspace,
+    args: crate::cli_commands::delivery::ReportCheckArgs,
+) -> Result<()> {
+    let (remote, token) = require_remote_and_token(&ws.store)?;
+    let client = RemoteClient::new(remote, token)?;
+    let bundle = client.report_check(
+        &args.bundle_id,
+        &args.name,
+        &args.status,
+        args.summary,
+        args.url,
+    )?;
+    if args.json {
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&bundle).context("serialize report-check json")?
+        );
+    } else {
+        println!("Reported {} {} on {}", args.name, args.status, bundle.id);
+    }
+    Ok(())
+}
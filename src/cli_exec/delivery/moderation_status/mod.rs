@@ -1,6 +1,7 @@
 use super::*;
 
 mod approve;
+mod checks;
 mod pinning;
 mod status;
 
@@ -12,6 +13,13 @@ pub(in crate::cli_exec) fn handle_approve_command(
     approve::handle_approve_command(ws, bundle_id, json)
 }
 
+pub(in crate::cli_exec) fn handle_report_check_command(
+    ws: &Workspace,
+    args: crate::cli_commands::delivery::ReportCheckArgs,
+) -> Result<()> {
+    checks::handle_report_check_command(ws, args)
+}
+
 pub(in crate::cli_exec) fn handle_pins_command(ws: &Workspace, json: bool) -> Result<()> {
     pinning::handle_pins_command(ws, json)
 }
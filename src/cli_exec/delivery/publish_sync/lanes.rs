@@ -13,7 +13,19 @@ pub(in crate::cli_exec) fn handle_lanes_command(ws: &Workspace, json: bool) -> R
         );
     } else {
         for l in lanes {
-            println!("lane: {}", l.id);
+            match &l.archived_at {
+                Some(at) => println!("lane: {} (archived {})", l.id, at),
+                None => println!("lane: {}", l.id),
+            }
+            if let Some(d) = &l.description {
+                println!("  description: {}", d);
+            }
+            if !l.gates.is_empty() {
+                println!("  gates: {}", l.gates.join(", "));
+            }
+            if let Some(s) = &l.default_scope {
+                println!("  default scope: {}", s);
+            }
             let mut members = l.members.into_iter().collect::<Vec<_>>();
             members.sort();
             for m in members {
@@ -9,7 +9,7 @@ pub(in crate::cli_exec) fn handle_publish_command(
     json: bool,
 ) -> Result<()> {
     let (remote, token) = require_remote_and_token(&ws.store)?;
-    let client = RemoteClient::new(remote.clone(), token)?;
+    let client = RemoteClient::new(remote.clone(), token)?.with_signer(ws.store.signing_key()?);
 
     let snap = match snap_id {
         Some(id) => ws.show_snap(&id)?,
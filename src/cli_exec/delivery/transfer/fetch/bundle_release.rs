@@ -99,3 +99,62 @@ pub(super) fn handle_release_fetch(
     }
     Ok(())
 }
+
+pub(super) fn handle_release_attachment_fetch(
+    ws: &Workspace,
+    client: &RemoteClient,
+    channel: &str,
+    names: &[String],
+    into: Option<&str>,
+    force: bool,
+    json: bool,
+) -> Result<()> {
+    let rel = client.get_release(channel)?;
+    let dest_dir = std::path::PathBuf::from(into.unwrap_or("."));
+    std::fs::create_dir_all(&dest_dir).with_context(|| format!("create {}", dest_dir.display()))?;
+
+    let mut written = Vec::new();
+    for name in names {
+        let attachment = rel
+            .attachments
+            .iter()
+            .find(|a| &a.name == name)
+            .with_context(|| format!("release {} has no attachment {}", rel.channel, name))?;
+        let dest = dest_dir.join(&attachment.name);
+        if dest.exists() && !force {
+            anyhow::bail!("{} already exists (use --force)", dest.display());
+        }
+
+        let file = client.fetch_release_attachment(&ws.store, attachment)?;
+        ws.write_stored_file(&file, &dest)?;
+        if !json {
+            println!(
+                "Fetched {} ({}, {} bytes) into {}",
+                attachment.name,
+                attachment.kind,
+                attachment.size,
+                dest.display()
+            );
+        }
+        written.push(serde_json::json!({
+            "name": attachment.name,
+            "kind": attachment.kind,
+            "size": attachment.size,
+            "path": dest.display().to_string(),
+        }));
+    }
+
+    if json {
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&serde_json::json!({
+                "kind": "release_attachments",
+                "channel": rel.channel,
+                "release_id": rel.id,
+                "attachments": written,
+            }))
+            .context("serialize fetch attachments json")?
+        );
+    }
+    Ok(())
+}
@@ -10,6 +10,7 @@ pub(super) fn handle_fetch_command(
     snap_id: Option<String>,
     bundle_id: Option<String>,
     release: Option<String>,
+    attachments: Vec<String>,
     lane: Option<String>,
     user: Option<String>,
     restore: bool,
@@ -33,6 +34,17 @@ pub(super) fn handle_fetch_command(
     }
 
     if let Some(channel) = release.as_deref() {
+        if !attachments.is_empty() {
+            return bundle_release::handle_release_attachment_fetch(
+                ws,
+                &client,
+                channel,
+                &attachments,
+                into.as_deref(),
+                force,
+                json,
+            );
+        }
         return bundle_release::handle_release_fetch(
             ws,
             &client,
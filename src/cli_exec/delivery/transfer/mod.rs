@@ -3,6 +3,7 @@ use super::*;
 mod bundle;
 mod fetch;
 mod promote;
+mod update;
 
 #[allow(clippy::too_many_arguments)]
 pub(in crate::cli_exec) fn handle_fetch_command(
@@ -10,6 +11,7 @@ pub(in crate::cli_exec) fn handle_fetch_command(
     snap_id: Option<String>,
     bundle_id: Option<String>,
     release: Option<String>,
+    attachments: Vec<String>,
     lane: Option<String>,
     user: Option<String>,
     restore: bool,
@@ -18,7 +20,17 @@ pub(in crate::cli_exec) fn handle_fetch_command(
     json: bool,
 ) -> Result<()> {
     fetch::handle_fetch_command(
-        ws, snap_id, bundle_id, release, lane, user, restore, into, force, json,
+        ws,
+        snap_id,
+        bundle_id,
+        release,
+        attachments,
+        lane,
+        user,
+        restore,
+        into,
+        force,
+        json,
     )
 }
 
@@ -32,6 +44,15 @@ pub(in crate::cli_exec) fn handle_bundle_command(
     bundle::handle_bundle_command(ws, scope, gate, publications, json)
 }
 
+pub(in crate::cli_exec) fn handle_update_command(
+    ws: &Workspace,
+    bundle_id: Option<String>,
+    release: Option<String>,
+    json: bool,
+) -> Result<()> {
+    update::handle_update_command(ws, bundle_id, release, json)
+}
+
 pub(in crate::cli_exec) fn handle_promote_command(
     ws: &Workspace,
     bundle_id: String,
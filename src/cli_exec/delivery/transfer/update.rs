@@ -0,0 +1,174 @@
+use super::super::*;
+
+use converge::merge::{MergeDriverRegistry, merge_trees};
+use converge::model::{ObjectId, RESOLUTION_RULES_FILE};
+
+pub(super) fn handle_update_command(
+    ws: &Workspace,
+    bundle_id: Option<String>,
+    release: Option<String>,
+    json: bool,
+) -> Result<()> {
+    let (remote, token) = require_remote_and_token(&ws.store)?;
+    let client = RemoteClient::new(remote.clone(), token)?;
+
+    let bundle = if let Some(id) = bundle_id.as_deref() {
+        client.get_bundle(id)?
+    } else if let Some(channel) = release.as_deref() {
+        let rel = client.get_release(channel)?;
+        client.get_bundle(&rel.bundle_id)?
+    } else {
+        let mut bundles = client
+            .list_bundles()?
+            .into_iter()
+            .filter(|b| b.scope == remote.scope && b.gate == remote.gate && b.promotable)
+            .collect::<Vec<_>>();
+        bundles.sort_by(|a, b| a.created_at.cmp(&b.created_at));
+        match bundles.pop() {
+            Some(b) => b,
+            None => anyhow::bail!(
+                "no promotable bundle at scope {} gate {}",
+                remote.scope,
+                remote.gate
+            ),
+        }
+    };
+    if bundle.reasons.iter().any(|r| r == "superpositions_present") {
+        anyhow::bail!(
+            "bundle {} has unresolved superpositions; resolve them before updating",
+            bundle.id
+        );
+    }
+
+    let theirs = ObjectId(bundle.root_manifest.clone());
+    client.fetch_manifest_tree(&ws.store, &theirs)?;
+
+    let (current, manifests, _) = ws.current_manifest_tree()?;
+    let head_root = match ws.store.get_head()? {
+        Some(snap_id) => Some(ws.store.get_snap(&snap_id)?.root_manifest),
+        None => None,
+    };
+    let message = Some(format!("update from bundle {}", bundle.id));
+
+    let Some(ours) = head_root else {
+        if manifests
+            .get(&current)
+            .is_some_and(|m| !m.entries.is_empty())
+        {
+            anyhow::bail!("workspace has no snaps yet; create one before updating");
+        }
+        let snap = ws.checkout_manifest(&theirs, message)?;
+        ws.store
+            .set_last_updated(&remote, &remote.scope, &remote.gate, &theirs.0)?;
+        return report(&bundle.id, Some(&snap.id), &[], &[], json);
+    };
+    if current != ours {
+        anyhow::bail!("workspace has uncommitted changes; snap them before updating");
+    }
+
+    let base = ws
+        .store
+        .get_last_updated(&remote, &remote.scope, &remote.gate)?
+        .map(ObjectId)
+        .filter(|id| {
+            ws.store.has_manifest(id) || client.fetch_manifest_tree(&ws.store, id).is_ok()
+        });
+
+    let registry = match std::fs::read(ws.root.join(RESOLUTION_RULES_FILE)) {
+        Ok(bytes) => {
+            let rules = converge::resolve::parse_resolution_rules(&bytes)
+                .with_context(|| format!("read {}", RESOLUTION_RULES_FILE))?;
+            MergeDriverRegistry::new(&rules.drivers)?
+        }
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => MergeDriverRegistry::new(&[])?,
+        Err(err) => {
+            return Err(err).with_context(|| format!("read {}", RESOLUTION_RULES_FILE));
+        }
+    };
+
+    let merged = merge_trees(&ws.store, base.as_ref(), &ours, &theirs, &registry)?;
+    // With conflicts the merge is left unsnapped for the user to finish.
+    let snap_id = if merged.conflicts.is_empty() {
+        let snap = ws.checkout_manifest(&merged.root, message)?;
+        ws.store
+            .set_last_updated(&remote, &remote.scope, &remote.gate, &theirs.0)?;
+        Some(snap.id)
+    } else {
+        ws.write_manifest(&merged.root)?;
+        None
+    };
+
+    let conflicts = merged
+        .conflicts
+        .iter()
+        .map(|c| {
+            serde_json::json!({
+                "path": c.path,
+                "driver": c.driver,
+                "key_paths": c.key_paths,
+            })
+        })
+        .collect::<Vec<_>>();
+    report(
+        &bundle.id,
+        snap_id.as_deref(),
+        &merged.merged_paths,
+        &conflicts,
+        json,
+    )?;
+    if !merged.conflicts.is_empty() && !json {
+        println!("Conflicting paths kept the workspace version; edit them, then snap");
+    }
+    Ok(())
+}
+
+fn report(
+    bundle_id: &str,
+    snap_id: Option<&str>,
+    merged: &[String],
+    conflicts: &[serde_json::Value],
+    json: bool,
+) -> Result<()> {
+    if json {
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&serde_json::json!({
+                "bundle_id": bundle_id,
+                "snap_id": snap_id,
+                "merged": merged,
+                "conflicts": conflicts,
+            }))
+            .context("serialize update json")?
+        );
+        return Ok(());
+    }
+
+    match snap_id {
+        Some(snap_id) => println!("Updated from bundle {} (snap {})", bundle_id, snap_id),
+        None => println!(
+            "Merged bundle {} into the workspace (not snapped)",
+            bundle_id
+        ),
+    }
+    for path in merged {
+        println!("merged {}", path);
+    }
+    for c in conflicts {
+        let path = c["path"].as_str().unwrap_or_default();
+        let keys = c["key_paths"]
+            .as_array()
+            .map(|k| {
+                k.iter()
+                    .filter_map(|v| v.as_str())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            })
+            .unwrap_or_default();
+        if keys.is_empty() {
+            println!("conflict {}", path);
+        } else {
+            println!("conflict {} (keys: {})", path, keys);
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,217 @@
+use std::collections::HashMap;
+
+use super::*;
+
+use converge::remote::SigningKeyView;
+use converge::signing::{self, Signature};
+
+#[derive(serde::Serialize)]
+struct Check {
+    object: String,
+    id: String,
+    status: &'static str,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    signer: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    key_id: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    detail: Option<String>,
+}
+
+/// Checks signatures client-side against the registered public keys; nothing is taken from the
+/// server's own verification.
+pub(in crate::cli_exec) fn handle_verify_command(
+    ws: &Workspace,
+    release: Option<String>,
+    bundle_id: Option<String>,
+    json: bool,
+) -> Result<()> {
+    let (remote, token) = require_remote_and_token(&ws.store)?;
+    let client = RemoteClient::new(remote, token)?;
+    let repo = client.remote().repo_id.clone();
+    let keys: HashMap<String, SigningKeyView> = client
+        .list_signing_keys(None)?
+        .into_iter()
+        .map(|k| (k.id.clone(), k))
+        .collect();
+
+    let mut checks = Vec::new();
+    let (target, bundle_id) = match (release, bundle_id) {
+        (Some(channel), _) => {
+            let rel = client.get_release(&channel)?;
+            checks.push(check(
+                &keys,
+                "release",
+                &rel.id,
+                rel.signature.as_ref(),
+                &signing::release_payload(&repo, &rel.channel, &rel.bundle_id),
+                Some(&rel.released_by),
+            ));
+            (format!("release {}", rel.channel), rel.bundle_id)
+        }
+        (None, Some(id)) => (format!("bundle {}", id), id),
+        (None, None) => anyhow::bail!("pass --release or --bundle"),
+    };
+
+    let bundle = client.get_bundle(&bundle_id)?;
+    let approval_payload = signing::approval_payload(&repo, &bundle.id, &bundle.root_manifest);
+    for approver in &bundle.approvals {
+        let sig = bundle
+            .approval_signatures
+            .iter()
+            .find(|s| &s.signer == approver);
+        checks.push(check(
+            &keys,
+            "approval",
+            &bundle.id,
+            sig,
+            &approval_payload,
+            Some(approver),
+        ));
+    }
+
+    let publications = client.list_publications()?;
+    for pid in &bundle.input_publications {
+        let Some(p) = publications.iter().find(|p| &p.id == pid) else {
+            checks.push(failed("publication", pid, "publication not found"));
+            continue;
+        };
+        checks.push(check(
+            &keys,
+            "publication",
+            &p.id,
+            p.signature.as_ref(),
+            &signing::publication_payload(&repo, &p.scope, &p.gate, &p.snap_id),
+            Some(&p.publisher),
+        ));
+
+        let snap = client.get_snap(&p.snap_id)?;
+        if snap.id != p.snap_id || !snap.id_matches_content() {
+            checks.push(failed(
+                "snap",
+                &p.snap_id,
+                "snap id does not match its content",
+            ));
+            continue;
+        }
+        checks.push(check(
+            &keys,
+            "snap",
+            &snap.id,
+            p.snap_signature.as_ref(),
+            &signing::snap_payload(&snap)?,
+            None,
+        ));
+    }
+
+    let problems = checks.iter().filter(|c| c.status != "ok").count();
+    if json {
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&serde_json::json!({
+                "target": target,
+                "bundle_id": bundle.id,
+                "verified": problems == 0,
+                "checks": checks,
+            }))
+            .context("serialize verify json")?
+        );
+    } else {
+        for c in &checks {
+            let mut line = format!("{:<9} {} {}", c.status, c.object, short(&c.id));
+            if let Some(signer) = &c.signer {
+                line.push_str(&format!(" signed by {}", signer));
+            }
+            if let Some(key_id) = &c.key_id {
+                line.push_str(&format!(" (key {})", key_id));
+            }
+            if let Some(detail) = &c.detail {
+                line.push_str(&format!(": {}", detail));
+            }
+            println!("{}", line);
+        }
+    }
+
+    if problems > 0 {
+        anyhow::bail!("{}: {} of {} checks failed", target, problems, checks.len());
+    }
+    if !json {
+        println!("Verified {} ({} signatures)", target, checks.len());
+    }
+    Ok(())
+}
+
+fn check(
+    keys: &HashMap<String, SigningKeyView>,
+    object: &str,
+    id: &str,
+    signature: Option<&Signature>,
+    payload: &[u8],
+    expected_signer: Option<&str>,
+) -> Check {
+    let Some(sig) = signature else {
+        return Check {
+            object: object.to_string(),
+            id: id.to_string(),
+            status: "unsigned",
+            signer: None,
+            key_id: None,
+            detail: None,
+        };
+    };
+    let problem = match keys.get(&sig.key_id) {
+        None => Some("unknown key".to_string()),
+        Some(key) if !signed_before_revocation(key, sig) => Some("key revoked".to_string()),
+        Some(key) if key.user != sig.signer => {
+            Some(format!("key belongs to {}, not {}", key.user, sig.signer))
+        }
+        Some(_) if expected_signer.is_some_and(|e| e != sig.signer) => Some(format!(
+            "signed by {}, expected {}",
+            sig.signer,
+            expected_signer.unwrap_or_default()
+        )),
+        Some(key) => signing::verify(&key.public_key, sig, payload)
+            .err()
+            .map(|e| e.to_string()),
+    };
+    Check {
+        object: object.to_string(),
+        id: id.to_string(),
+        status: if problem.is_some() { "invalid" } else { "ok" },
+        signer: Some(sig.signer.clone()),
+        key_id: Some(sig.key_id.clone()),
+        detail: problem,
+    }
+}
+
+/// Signatures the server accepted before the key was revoked stay valid.
+fn signed_before_revocation(key: &SigningKeyView, sig: &Signature) -> bool {
+    let Some(revoked_at) = &key.revoked_at else {
+        return true;
+    };
+    let parse = |ts: &str| {
+        time::OffsetDateTime::parse(ts, &time::format_description::well_known::Rfc3339).ok()
+    };
+    match (sig.signed_at.as_deref().and_then(parse), parse(revoked_at)) {
+        (Some(signed), Some(revoked)) => signed < revoked,
+        _ => false,
+    }
+}
+
+fn failed(object: &str, id: &str, detail: &str) -> Check {
+    Check {
+        object: object.to_string(),
+        id: id.to_string(),
+        status: "invalid",
+        signer: None,
+        key_id: None,
+        detail: Some(detail.to_string()),
+    }
+}
+
+fn short(id: &str) -> &str {
+    &id[..id.len().min(12)]
+}
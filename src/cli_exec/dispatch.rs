@@ -1,18 +1,23 @@
 use super::delivery::{
     handle_approve_command, handle_bundle_command, handle_fetch_command, handle_lanes_command,
     handle_pin_command, handle_pins_command, handle_promote_command, handle_publish_command,
-    handle_status_command, handle_sync_command,
+    handle_report_check_command, handle_status_command, handle_sync_command, handle_update_command,
+    handle_verify_command,
 };
 use super::identity::{
-    handle_lane_command, handle_login_command, handle_logout_command, handle_members_command,
-    handle_token_command, handle_user_command, handle_whoami_command,
+    handle_audit_command, handle_group_command, handle_key_command, handle_lane_command,
+    handle_login_command, handle_logout_command, handle_members_command, handle_token_command,
+    handle_user_command, handle_whoami_command,
 };
+use super::interop::{handle_archive_command, handle_export_command, handle_import_command};
 use super::local::{
-    handle_diff_command, handle_init_command, handle_mv_command, handle_restore_command,
-    handle_show_command, handle_snap_command, handle_snaps_command,
+    handle_blame_command, handle_cat_command, handle_diff_command, handle_history_command,
+    handle_init_command, handle_ls_command, handle_mv_command, handle_restore_command,
+    handle_show_command, handle_snap_command, handle_snaps_command, handle_tui_command,
 };
 use super::release_resolve::{handle_release_command, handle_resolve_command};
 use super::remote_admin::{handle_gates_command, handle_remote_command};
+use super::trace::handle_trace_command;
 use super::workspace::with_workspace;
 use super::*;
 
@@ -23,8 +28,33 @@ pub(super) fn handle_command(command: Commands) -> Result<()> {
         Commands::Snaps(args) => handle_snaps_command(args.json)?,
         Commands::Show(args) => handle_show_command(args.snap_id, args.json)?,
         Commands::Restore(args) => handle_restore_command(args.snap_id, args.force)?,
-        Commands::Diff(args) => handle_diff_command(args.from, args.to, args.json)?,
+        Commands::Diff(args) => handle_diff_command(args.from, args.to, args.patch, args.json)?,
+        Commands::Ls(args) => with_workspace(|ws| {
+            handle_ls_command(ws, args.tree, args.path, args.recursive, args.json)
+        })?,
+        Commands::Cat(args) => {
+            with_workspace(|ws| handle_cat_command(ws, args.spec, args.variant))?
+        }
+        Commands::History(args) => {
+            with_workspace(|ws| handle_history_command(ws, args.path, args.limit, args.json))?
+        }
+        Commands::Blame(args) => {
+            with_workspace(|ws| handle_blame_command(ws, args.path, args.snap, args.json))?
+        }
         Commands::Mv(args) => handle_mv_command(args.from, args.to)?,
+        Commands::Import { command } => with_workspace(|ws| handle_import_command(ws, command))?,
+        Commands::Export { command } => with_workspace(|ws| handle_export_command(ws, command))?,
+        Commands::Archive(args) => with_workspace(|ws| {
+            handle_archive_command(
+                ws,
+                args.snap,
+                args.bundle_id,
+                args.release,
+                args.format,
+                args.output,
+                args.json,
+            )
+        })?,
         Commands::Remote { command } => with_workspace(|ws| handle_remote_command(ws, command))?,
         Commands::Gates { command } => with_workspace(|ws| handle_gates_command(ws, command))?,
         Commands::Login(args) => with_workspace(|ws| {
@@ -34,6 +64,11 @@ pub(super) fn handle_command(command: Commands) -> Result<()> {
         Commands::Whoami(args) => with_workspace(|ws| handle_whoami_command(ws, args.json))?,
         Commands::Token { command } => with_workspace(|ws| handle_token_command(ws, command))?,
         Commands::User { command } => with_workspace(|ws| handle_user_command(ws, command))?,
+        Commands::Group { command } => with_workspace(|ws| handle_group_command(ws, command))?,
+        Commands::Audit(args) => {
+            with_workspace(|ws| handle_audit_command(ws, args.limit, args.json))?
+        }
+        Commands::Key { command } => with_workspace(|ws| handle_key_command(ws, command))?,
         Commands::Publish(args) => with_workspace(|ws| {
             handle_publish_command(
                 ws,
@@ -56,6 +91,7 @@ pub(super) fn handle_command(command: Commands) -> Result<()> {
                 args.snap_id,
                 args.bundle_id,
                 args.release,
+                args.attachments,
                 args.lane,
                 args.user,
                 args.restore,
@@ -64,6 +100,9 @@ pub(super) fn handle_command(command: Commands) -> Result<()> {
                 args.json,
             )
         })?,
+        Commands::Update(args) => {
+            with_workspace(|ws| handle_update_command(ws, args.bundle_id, args.release, args.json))?
+        }
         Commands::Bundle(args) => with_workspace(|ws| {
             handle_bundle_command(ws, args.scope, args.gate, args.publications, args.json)
         })?,
@@ -74,6 +113,10 @@ pub(super) fn handle_command(command: Commands) -> Result<()> {
         Commands::Approve(args) => {
             with_workspace(|ws| handle_approve_command(ws, args.bundle_id, args.json))?
         }
+        Commands::ReportCheck(args) => with_workspace(|ws| handle_report_check_command(ws, args))?,
+        Commands::Verify(args) => {
+            with_workspace(|ws| handle_verify_command(ws, args.release, args.bundle_id, args.json))?
+        }
         Commands::Pins(args) => with_workspace(|ws| handle_pins_command(ws, args.json))?,
         Commands::Pin(args) => {
             with_workspace(|ws| handle_pin_command(ws, args.bundle_id, args.unpin, args.json))?
@@ -82,6 +125,10 @@ pub(super) fn handle_command(command: Commands) -> Result<()> {
             with_workspace(|ws| handle_status_command(ws, args.json, args.limit))?
         }
         Commands::Resolve { command } => with_workspace(|ws| handle_resolve_command(ws, command))?,
+        Commands::Tui(args) => {
+            handle_tui_command(args.script, args.width, args.height, args.frames, None)?
+        }
+        Commands::Trace { command } => handle_trace_command(command)?,
     }
 
     Ok(())
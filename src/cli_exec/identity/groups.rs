@@ -0,0 +1,108 @@
+use converge::remote::RemoteGroup;
+
+use super::*;
+
+pub(crate) fn handle_group_command(ws: &Workspace, command: GroupCommands) -> Result<()> {
+    let (remote, token) = require_remote_and_token(&ws.store)?;
+    let client = RemoteClient::new(remote, token)?;
+
+    match command {
+        GroupCommands::List { json } => {
+            let groups = client.list_groups()?;
+            if json {
+                println!(
+                    "{}",
+                    serde_json::to_string_pretty(&groups).context("serialize groups json")?
+                );
+            } else if groups.is_empty() {
+                println!("(no groups)");
+            } else {
+                for g in groups {
+                    println!("@{} {}", g.name, group_summary(&g));
+                }
+            }
+        }
+        GroupCommands::Show { name, json } => {
+            let group = client.get_group(strip_at(&name))?;
+            print_group(&group, json)?;
+        }
+        GroupCommands::Create {
+            name,
+            description,
+            json,
+        } => {
+            let group = client.create_group(strip_at(&name), description)?;
+            print_group(&group, json)?;
+        }
+        GroupCommands::Delete { name, json } => {
+            client.delete_group(strip_at(&name))?;
+            if json {
+                println!("{}", serde_json::json!({"deleted": true, "group": name}));
+            } else {
+                println!("Deleted @{}", strip_at(&name));
+            }
+        }
+        GroupCommands::Add { name, member, json } => {
+            let group = client.add_group_member(strip_at(&name), &member)?;
+            print_group(&group, json)?;
+        }
+        GroupCommands::Remove { name, member, json } => {
+            let group = client.remove_group_member(strip_at(&name), &member)?;
+            print_group(&group, json)?;
+        }
+    }
+
+    Ok(())
+}
+
+pub(crate) fn handle_audit_command(ws: &Workspace, limit: usize, json: bool) -> Result<()> {
+    let (remote, token) = require_remote_and_token(&ws.store)?;
+    let client = RemoteClient::new(remote, token)?;
+    let entries = client.list_audit(limit)?;
+    if json {
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&entries).context("serialize audit json")?
+        );
+        return Ok(());
+    }
+    for e in entries {
+        let detail = e.detail.map(|d| format!(" {}", d)).unwrap_or_default();
+        println!("{} {} {} {}{}", e.at, e.actor, e.action, e.target, detail);
+    }
+    Ok(())
+}
+
+// Accept both `platform` and `@platform` where a group name is expected.
+fn strip_at(name: &str) -> &str {
+    name.strip_prefix('@').unwrap_or(name)
+}
+
+fn group_summary(group: &RemoteGroup) -> String {
+    let mut parts = vec![format!("members={}", group.members.join(","))];
+    if !group.groups.is_empty() {
+        let nested: Vec<String> = group.groups.iter().map(|g| format!("@{}", g)).collect();
+        parts.push(format!("groups={}", nested.join(",")));
+    }
+    parts.join(" ")
+}
+
+fn print_group(group: &RemoteGroup, json: bool) -> Result<()> {
+    if json {
+        println!(
+            "{}",
+            serde_json::to_string_pretty(group).context("serialize group json")?
+        );
+        return Ok(());
+    }
+    println!("group: @{}", group.name);
+    if let Some(d) = &group.description {
+        println!("description: {}", d);
+    }
+    println!("members: {}", group.members.join(", "));
+    if !group.groups.is_empty() {
+        let nested: Vec<String> = group.groups.iter().map(|g| format!("@{}", g)).collect();
+        println!("groups: {}", nested.join(", "));
+    }
+    Ok(())
+}
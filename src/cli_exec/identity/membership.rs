@@ -1,5 +1,7 @@
 use super::*;
 
+use converge::remote::{Lane, LaneUpdate};
+
 pub(crate) fn handle_members_command(ws: &Workspace, command: MembersCommands) -> Result<()> {
     let (remote, token) = require_remote_and_token(&ws.store)?;
     let client = RemoteClient::new(remote, token)?;
@@ -26,23 +28,65 @@ pub(crate) fn handle_members_command(ws: &Workspace, command: MembersCommands) -
                     };
                     println!("{} {}", r, role);
                 }
+                for g in m.grants {
+                    println!("{} {}{}", g.handle, g.role, grant_target(&g.gate, &g.lane));
+                }
             }
         }
-        MembersCommands::Add { handle, role, json } => {
-            client.add_repo_member(&handle, &role)?;
+        MembersCommands::Add {
+            handle,
+            role,
+            gate,
+            lane,
+            json,
+        } => {
+            client.add_repo_member(&handle, &role, gate.as_deref(), lane.as_deref())?;
             if json {
                 println!(
                     "{}",
-                    serde_json::json!({"ok": true, "handle": handle, "role": role})
+                    serde_json::json!({
+                        "ok": true,
+                        "handle": handle,
+                        "role": role,
+                        "gate": gate,
+                        "lane": lane
+                    })
                 );
             } else {
-                println!("Added {} ({})", handle, role);
+                println!("Added {} ({}{})", handle, role, grant_target(&gate, &lane));
             }
         }
-        MembersCommands::Remove { handle, json } => {
-            client.remove_repo_member(&handle)?;
+        MembersCommands::Remove {
+            handle,
+            role,
+            gate,
+            lane,
+            json,
+        } => {
+            match role.as_deref() {
+                Some(role) => {
+                    client.remove_repo_role(&handle, role, gate.as_deref(), lane.as_deref())?
+                }
+                None => client.remove_repo_member(&handle)?,
+            }
             if json {
-                println!("{}", serde_json::json!({"ok": true, "handle": handle}));
+                println!(
+                    "{}",
+                    serde_json::json!({
+                        "ok": true,
+                        "handle": handle,
+                        "role": role,
+                        "gate": gate,
+                        "lane": lane
+                    })
+                );
+            } else if let Some(role) = role {
+                println!(
+                    "Removed {} from {}{}",
+                    role,
+                    handle,
+                    grant_target(&gate, &lane)
+                );
             } else {
                 println!("Removed {}", handle);
             }
@@ -52,11 +96,77 @@ pub(crate) fn handle_members_command(ws: &Workspace, command: MembersCommands) -
     Ok(())
 }
 
+fn grant_target(gate: &Option<String>, lane: &Option<String>) -> String {
+    match (gate, lane) {
+        (Some(g), _) => format!(" gate={}", g),
+        (None, Some(l)) => format!(" lane={}", l),
+        (None, None) => String::new(),
+    }
+}
+
 pub(crate) fn handle_lane_command(ws: &Workspace, command: LaneCommands) -> Result<()> {
     let (remote, token) = require_remote_and_token(&ws.store)?;
     let client = RemoteClient::new(remote, token)?;
 
     match command {
+        LaneCommands::Create {
+            lane_id,
+            description,
+            gates,
+            default_scope,
+            json,
+        } => {
+            let lane = client.create_lane(
+                &lane_id,
+                description.as_deref(),
+                &gates,
+                default_scope.as_deref(),
+            )?;
+            print_lane_change(&lane, "Created", json)?;
+        }
+        LaneCommands::Edit {
+            lane_id,
+            rename,
+            description,
+            gates,
+            clear_gates,
+            default_scope,
+            json,
+        } => {
+            let update = LaneUpdate {
+                id: rename,
+                description,
+                gates: (clear_gates || !gates.is_empty()).then_some(gates),
+                default_scope,
+                archived: None,
+            };
+            let lane = client.update_lane(&lane_id, &update)?;
+            print_lane_change(&lane, "Updated", json)?;
+        }
+        LaneCommands::Archive { lane_id, json } => {
+            let update = LaneUpdate {
+                archived: Some(true),
+                ..Default::default()
+            };
+            let lane = client.update_lane(&lane_id, &update)?;
+            print_lane_change(&lane, "Archived", json)?;
+        }
+        LaneCommands::Unarchive { lane_id, json } => {
+            let update = LaneUpdate {
+                archived: Some(false),
+                ..Default::default()
+            };
+            let lane = client.update_lane(&lane_id, &update)?;
+            print_lane_change(&lane, "Unarchived", json)?;
+        }
+        LaneCommands::Delete { lane_id, json } => {
+            client.delete_lane(&lane_id)?;
+            if json {
+                println!("{}", serde_json::json!({"ok": true, "lane": lane_id}));
+            } else {
+                println!("Deleted lane {}", lane_id);
+            }
+        }
         LaneCommands::Members { lane_id, command } => match command {
             LaneMembersCommands::List { json } => {
                 let m = client.list_lane_members(&lane_id)?;
@@ -72,6 +182,9 @@ pub(crate) fn handle_lane_command(ws: &Workspace, command: LaneCommands) -> Resu
                     for h in members {
                         println!("{}", h);
                     }
+                    for g in m.groups {
+                        println!("{}", g);
+                    }
                 }
             }
             LaneMembersCommands::Add { handle, json } => {
@@ -97,7 +210,93 @@ pub(crate) fn handle_lane_command(ws: &Workspace, command: LaneCommands) -> Resu
                 }
             }
         },
+        LaneCommands::Subscribe {
+            lane_id,
+            scope,
+            until,
+            user,
+            json,
+        } => {
+            let s = client.subscribe_lane(
+                &lane_id,
+                scope.as_deref(),
+                until.as_deref(),
+                user.as_deref(),
+            )?;
+            if json {
+                println!(
+                    "{}",
+                    serde_json::to_string_pretty(&s).context("serialize subscription json")?
+                );
+            } else {
+                println!(
+                    "Subscribed {} to lane {}{}",
+                    s.handle,
+                    s.lane,
+                    subscription_limits(&s.scope, &s.until)
+                );
+            }
+        }
+        LaneCommands::Unsubscribe {
+            lane_id,
+            scope,
+            user,
+            json,
+        } => {
+            client.unsubscribe_lane(&lane_id, scope.as_deref(), user.as_deref())?;
+            if json {
+                println!(
+                    "{}",
+                    serde_json::json!({"ok": true, "lane": lane_id, "scope": scope, "user": user})
+                );
+            } else {
+                println!("Unsubscribed from lane {}", lane_id);
+            }
+        }
+        LaneCommands::Subscriptions { json } => {
+            let list = client.list_subscriptions()?;
+            if json {
+                println!(
+                    "{}",
+                    serde_json::to_string_pretty(&list).context("serialize subscriptions json")?
+                );
+            } else if list.is_empty() {
+                println!("(no subscriptions)");
+            } else {
+                for s in list {
+                    println!(
+                        "{} {}{}",
+                        s.lane,
+                        s.handle,
+                        subscription_limits(&s.scope, &s.until)
+                    );
+                }
+            }
+        }
     }
 
     Ok(())
 }
+
+fn subscription_limits(scope: &Option<String>, until: &Option<String>) -> String {
+    let mut out = String::new();
+    if let Some(scope) = scope {
+        out.push_str(&format!(" scope={}", scope));
+    }
+    if let Some(until) = until {
+        out.push_str(&format!(" until={}", until));
+    }
+    out
+}
+
+fn print_lane_change(lane: &Lane, verb: &str, json: bool) -> Result<()> {
+    if json {
+        println!(
+            "{}",
+            serde_json::to_string_pretty(lane).context("serialize lane json")?
+        );
+    } else {
+        println!("{} lane {}", verb, lane.id);
+    }
+    Ok(())
+}
@@ -1,11 +1,15 @@
 use super::*;
 
+mod groups;
 mod membership;
 mod session;
+mod signing_keys;
 mod token_user;
 
+pub(super) use self::groups::{handle_audit_command, handle_group_command};
 pub(super) use self::membership::{handle_lane_command, handle_members_command};
 pub(super) use self::session::{
     handle_login_command, handle_logout_command, handle_whoami_command,
 };
+pub(super) use self::signing_keys::handle_key_command;
 pub(super) use self::token_user::{handle_token_command, handle_user_command};
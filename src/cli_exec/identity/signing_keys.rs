@@ -0,0 +1,139 @@
+use super::*;
+
+use converge::signing::SigningKey;
+
+pub(crate) fn handle_key_command(ws: &Workspace, command: KeyCommands) -> Result<()> {
+    match command {
+        KeyCommands::Generate {
+            path,
+            register,
+            label,
+            json,
+        } => {
+            let key = SigningKey::generate()?;
+            let path =
+                path.unwrap_or_else(|| ws.store.keys_dir().join(format!("{}.json", key.key_id())));
+            key.save(&path)?;
+            ws.store.set_signing_key_path(Some(&path))?;
+            let registered = if register {
+                Some(remote_client(ws)?.register_signing_key(&key, label)?)
+            } else {
+                None
+            };
+            if json {
+                println!(
+                    "{}",
+                    serde_json::to_string_pretty(&serde_json::json!({
+                        "key_id": key.key_id(),
+                        "public_key": key.public_key_hex(),
+                        "path": path.display().to_string(),
+                        "registered": registered,
+                    }))
+                    .context("serialize key generate json")?
+                );
+            } else {
+                println!(
+                    "Generated signing key {} at {}",
+                    key.key_id(),
+                    path.display()
+                );
+                if registered.is_some() {
+                    println!("Registered {} with the remote", key.key_id());
+                } else {
+                    println!("note: run `converge key register` so the remote accepts signatures");
+                }
+            }
+        }
+        KeyCommands::Use { path, json } => {
+            let path = std::path::absolute(&path)
+                .with_context(|| format!("resolve {}", path.display()))?;
+            let key = SigningKey::load(&path)?;
+            ws.store.set_signing_key_path(Some(&path))?;
+            if json {
+                println!(
+                    "{}",
+                    serde_json::json!({
+                        "key_id": key.key_id(),
+                        "public_key": key.public_key_hex(),
+                        "path": path.display().to_string(),
+                    })
+                );
+            } else {
+                println!("Using signing key {} at {}", key.key_id(), path.display());
+            }
+        }
+        KeyCommands::Show { json } => {
+            let path = ws.store.signing_key_path()?;
+            let key = ws.store.signing_key()?;
+            if json {
+                println!(
+                    "{}",
+                    serde_json::to_string_pretty(&serde_json::json!({
+                        "key_id": key.as_ref().map(|k| k.key_id()),
+                        "public_key": key.as_ref().map(|k| k.public_key_hex()),
+                        "path": path.map(|p| p.display().to_string()),
+                    }))
+                    .context("serialize key show json")?
+                );
+            } else if let (Some(key), Some(path)) = (key, path) {
+                println!("key_id: {}", key.key_id());
+                println!("public_key: {}", key.public_key_hex());
+                println!("path: {}", path.display());
+            } else {
+                println!("No signing key configured (run `converge key generate`)");
+            }
+        }
+        KeyCommands::Register { label, json } => {
+            let key = ws
+                .store
+                .signing_key()?
+                .context("no signing key configured (run `converge key generate`)")?;
+            let view = remote_client(ws)?.register_signing_key(&key, label)?;
+            if json {
+                println!(
+                    "{}",
+                    serde_json::to_string_pretty(&view).context("serialize key register json")?
+                );
+            } else {
+                println!("Registered {} for {}", view.id, view.user);
+            }
+        }
+        KeyCommands::List { user, json } => {
+            let keys = remote_client(ws)?.list_signing_keys(user.as_deref())?;
+            if json {
+                println!(
+                    "{}",
+                    serde_json::to_string_pretty(&keys).context("serialize key list json")?
+                );
+            } else {
+                for k in keys {
+                    let label = k.label.unwrap_or_default();
+                    let revoked = if k.revoked_at.is_some() {
+                        " revoked"
+                    } else {
+                        ""
+                    };
+                    println!("{} {} {}{}", k.id, k.user, label, revoked);
+                }
+            }
+        }
+        KeyCommands::Revoke { id, json } => {
+            let view = remote_client(ws)?.revoke_signing_key(&id)?;
+            if json {
+                println!(
+                    "{}",
+                    serde_json::to_string_pretty(&view).context("serialize key revoke json")?
+                );
+            } else {
+                println!("Revoked {}", view.id);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn remote_client(ws: &Workspace) -> Result<RemoteClient> {
+    let (remote, token) = require_remote_and_token(&ws.store)?;
+    RemoteClient::new(remote, token)
+}
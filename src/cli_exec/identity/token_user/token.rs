@@ -1,3 +1,5 @@
+use converge::remote::CreateTokenRequest;
+
 use super::*;
 
 pub(crate) fn handle_token_command(ws: &Workspace, command: TokenCommands) -> Result<()> {
@@ -5,7 +7,29 @@ pub(crate) fn handle_token_command(ws: &Workspace, command: TokenCommands) -> Re
     let client = RemoteClient::new(remote, token)?;
 
     match command {
-        TokenCommands::Create { label, user, json } => {
+        TokenCommands::Create {
+            label,
+            user,
+            repos,
+            allow,
+            expires,
+            json,
+        } => {
+            for cap in &allow {
+                if !TOKEN_CAPABILITIES.contains(&cap.as_str()) {
+                    anyhow::bail!(
+                        "unknown capability: {} (expected one of: {})",
+                        cap,
+                        TOKEN_CAPABILITIES.join(", ")
+                    );
+                }
+            }
+            let request = CreateTokenRequest {
+                label,
+                repos: (!repos.is_empty()).then_some(repos),
+                capabilities: (!allow.is_empty()).then_some(allow),
+                expires_in_secs: expires.as_deref().map(parse_duration_secs).transpose()?,
+            };
             let created = if let Some(handle) = user.as_deref() {
                 let users = client.list_users()?;
                 let uid = users
@@ -13,9 +37,9 @@ pub(crate) fn handle_token_command(ws: &Workspace, command: TokenCommands) -> Re
                     .find(|u| u.handle == handle)
                     .map(|u| u.id.clone())
                     .with_context(|| format!("unknown user handle: {}", handle))?;
-                client.create_token_for_user(&uid, label)?
+                client.create_token_for_user(&uid, &request)?
             } else {
-                client.create_token(label)?
+                client.create_token(&request)?
             };
             if json {
                 println!(
@@ -45,7 +69,22 @@ pub(crate) fn handle_token_command(ws: &Workspace, command: TokenCommands) -> Re
                     } else {
                         ""
                     };
-                    println!("{} {}{}", t.id, label, revoked);
+                    let repos = t
+                        .repos
+                        .map(|r| r.join(","))
+                        .unwrap_or_else(|| "*".to_string());
+                    let allow = t
+                        .capabilities
+                        .map(|c| c.join(","))
+                        .unwrap_or_else(|| "*".to_string());
+                    let expires = t
+                        .expires_at
+                        .map(|e| format!(" expires={}", e))
+                        .unwrap_or_default();
+                    println!(
+                        "{} {} repos={} allow={}{}{}",
+                        t.id, label, repos, allow, expires, revoked
+                    );
                 }
             }
         }
@@ -61,3 +100,39 @@ pub(crate) fn handle_token_command(ws: &Workspace, command: TokenCommands) -> Re
 
     Ok(())
 }
+
+const TOKEN_CAPABILITIES: &[&str] = &[
+    "read",
+    "publish",
+    "report-checks",
+    "approve",
+    "promote",
+    "release",
+];
+
+/// Parses `<n><unit>` durations with units `s`, `m`, `h`, `d` or `w`.
+fn parse_duration_secs(s: &str) -> Result<u64> {
+    let s = s.trim();
+    let split = s.len() - s.trim_start_matches(|c: char| c.is_ascii_digit()).len();
+    let (n, unit) = s.split_at(split);
+    let n: u64 = n
+        .parse()
+        .with_context(|| format!("invalid duration: {} (expected e.g. 30d)", s))?;
+    let mult = match unit {
+        "s" => 1,
+        "m" => 60,
+        "h" => 60 * 60,
+        "d" => 24 * 60 * 60,
+        "w" => 7 * 24 * 60 * 60,
+        _ => anyhow::bail!("invalid duration unit in {} (expected s, m, h, d or w)", s),
+    };
+    if n == 0 {
+        anyhow::bail!("duration must be positive: {}", s);
+    }
+    n.checked_mul(mult)
+        .with_context(|| format!("duration too large: {}", s))
+}
+
+#[cfg(test)]
+#[path = "../../../tests/cli_exec/identity/token_tests.rs"]
+mod tests;
@@ -0,0 +1,121 @@
+use super::*;
+
+use std::io::Write;
+
+use converge::archive::{ArchiveFormat, write_archive};
+use converge::model::ObjectId;
+
+pub(in crate::cli_exec) fn handle_archive_command(
+    ws: &Workspace,
+    snap: Option<String>,
+    bundle_id: Option<String>,
+    release: Option<String>,
+    format: String,
+    output: String,
+    json: bool,
+) -> Result<()> {
+    let format: ArchiveFormat = format.parse()?;
+
+    let (source, root) = match (snap, bundle_id, release) {
+        (Some(snap_id), _, _) => {
+            let snap = ws.show_snap(&snap_id)?;
+            (format!("snap {}", snap.id), snap.root_manifest)
+        }
+        (None, bundle_id, release) => {
+            let (remote, token) = require_remote_and_token(&ws.store)?;
+            let client = RemoteClient::new(remote, token)?;
+            let bundle = match (bundle_id, release) {
+                (Some(id), _) => client.get_bundle(&id)?,
+                (None, Some(channel)) => {
+                    let rel = client.get_release(&channel)?;
+                    client.get_bundle(&rel.bundle_id)?
+                }
+                (None, None) => anyhow::bail!("pass --snap, --bundle or --release"),
+            };
+            let root = ObjectId(bundle.root_manifest.clone());
+            client.fetch_manifest_tree(&ws.store, &root)?;
+            (format!("bundle {}", bundle.id), root)
+        }
+    };
+
+    // Hash while writing so the digest can be compared across machines without re-reading.
+    let mut hasher = blake3::Hasher::new();
+    let bytes = if output == "-" {
+        let stdout = std::io::stdout().lock();
+        let out = write_archive(&ws.store, &root, format, Tee::new(stdout, &mut hasher))?;
+        out.finish()?
+    } else {
+        let file = std::fs::File::create(&output).with_context(|| format!("create {}", output))?;
+        let out = write_archive(
+            &ws.store,
+            &root,
+            format,
+            Tee::new(std::io::BufWriter::new(file), &mut hasher),
+        )
+        .with_context(|| format!("write {}", output))?;
+        out.finish()?
+    };
+    let digest = hasher.finalize().to_hex().to_string();
+
+    if json {
+        let report = serde_json::json!({
+            "source": source,
+            "root_manifest": root.0,
+            "format": format.as_str(),
+            "output": output,
+            "bytes": bytes,
+            "blake3": digest,
+        });
+        // Keep stdout clean when the archive itself went there.
+        if output == "-" {
+            eprintln!("{}", serde_json::to_string_pretty(&report)?);
+        } else {
+            println!("{}", serde_json::to_string_pretty(&report)?);
+        }
+    } else if output != "-" {
+        println!(
+            "Archived {} to {} ({}, {} bytes, blake3 {})",
+            source,
+            output,
+            format.as_str(),
+            bytes,
+            digest
+        );
+    }
+    Ok(())
+}
+
+/// Writer that also feeds a hasher and counts bytes.
+struct Tee<'a, W: Write> {
+    inner: W,
+    hasher: &'a mut blake3::Hasher,
+    bytes: u64,
+}
+
+impl<'a, W: Write> Tee<'a, W> {
+    fn new(inner: W, hasher: &'a mut blake3::Hasher) -> Self {
+        Self {
+            inner,
+            hasher,
+            bytes: 0,
+        }
+    }
+
+    fn finish(mut self) -> Result<u64> {
+        self.inner.flush().context("flush archive")?;
+        Ok(self.bytes)
+    }
+}
+
+impl<W: Write> Write for Tee<'_, W> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        let n = self.inner.write(buf)?;
+        self.hasher.update(&buf[..n]);
+        self.bytes += n as u64;
+        Ok(n)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.inner.flush()
+    }
+}
@@ -0,0 +1,122 @@
+use super::*;
+
+use converge::model::ObjectId;
+use converge::remote::{Bundle, Release};
+use converge::workspace::GitExportCommit;
+
+pub(super) fn handle_export_git(
+    ws: &Workspace,
+    release: Option<String>,
+    bundle_id: Option<String>,
+    into: String,
+    branch: Option<String>,
+    json: bool,
+) -> Result<()> {
+    let (remote, token) = require_remote_and_token(&ws.store)?;
+    let client = RemoteClient::new(remote, token)?;
+
+    let (release, bundle) = match (release, bundle_id) {
+        (Some(channel), _) => {
+            let rel = client.get_release(&channel)?;
+            let bundle = client.get_bundle(&rel.bundle_id)?;
+            (Some(rel), bundle)
+        }
+        (None, Some(id)) => (None, client.get_bundle(&id)?),
+        (None, None) => anyhow::bail!("pass --release or --bundle-id"),
+    };
+    if bundle.reasons.iter().any(|r| r == "superpositions_present") {
+        anyhow::bail!(
+            "bundle {} has unresolved superpositions; resolve them before exporting",
+            bundle.id
+        );
+    }
+
+    let root = ObjectId(bundle.root_manifest.clone());
+    client.fetch_manifest_tree(&ws.store, &root)?;
+
+    let commit = export_commit(release.as_ref(), &bundle, branch)?;
+    let exported = ws
+        .export_git(&root, std::path::Path::new(&into), &commit)
+        .with_context(|| format!("export to {}", into))?;
+
+    if json {
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&exported).context("serialize export json")?
+        );
+    } else if exported.created {
+        println!(
+            "Exported bundle {} to {} ({} {})",
+            bundle.id, into, exported.branch, exported.commit
+        );
+    } else {
+        println!(
+            "Already exported: {} is at {}",
+            exported.branch, exported.commit
+        );
+    }
+    Ok(())
+}
+
+/// Commit metadata: notes in the body, release and bundle provenance as trailers.
+fn export_commit(
+    release: Option<&Release>,
+    bundle: &Bundle,
+    branch: Option<String>,
+) -> Result<GitExportCommit> {
+    let mut message = String::new();
+    let mut trailers = Vec::new();
+    let (branch, committer, at) = match release {
+        Some(rel) => {
+            message.push_str(&format!("Release {}\n", rel.channel));
+            if let Some(notes) = rel.notes.as_deref().filter(|n| !n.trim().is_empty()) {
+                message.push_str(&format!("\n{}\n", notes.trim_end()));
+            }
+            trailers.push(("Converge-Release", rel.id.clone()));
+            trailers.push(("Converge-Channel", rel.channel.clone()));
+            trailers.push(("Converge-Released-By", rel.released_by.clone()));
+            (
+                branch.unwrap_or_else(|| format!("converge/{}", rel.channel)),
+                rel.released_by.clone(),
+                rel.released_at.clone(),
+            )
+        }
+        None => {
+            message.push_str(&format!("Bundle {}\n", bundle.id));
+            (
+                branch.unwrap_or_else(|| "converge/bundles".to_string()),
+                bundle.created_by.clone(),
+                bundle.created_at.clone(),
+            )
+        }
+    };
+
+    trailers.push(("Converge-Bundle", bundle.id.clone()));
+    trailers.push(("Converge-Scope", bundle.scope.clone()));
+    trailers.push(("Converge-Gate", bundle.gate.clone()));
+    trailers.push(("Converge-Root-Manifest", bundle.root_manifest.clone()));
+    trailers.push(("Converge-Bundled-By", bundle.created_by.clone()));
+    for p in &bundle.input_publications {
+        trailers.push(("Converge-Publication", p.clone()));
+    }
+    for a in &bundle.approvals {
+        trailers.push(("Converge-Approved-By", a.clone()));
+    }
+
+    message.push('\n');
+    for (key, value) in trailers {
+        message.push_str(&format!("{}: {}\n", key, value));
+    }
+
+    let unix_time =
+        time::OffsetDateTime::parse(&at, &time::format_description::well_known::Rfc3339)
+            .with_context(|| format!("parse timestamp {}", at))?
+            .unix_timestamp();
+
+    Ok(GitExportCommit {
+        branch,
+        committer,
+        unix_time,
+        message,
+    })
+}
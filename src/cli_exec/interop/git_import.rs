@@ -0,0 +1,140 @@
+use super::*;
+
+use converge::workspace::{GitImport, GitImportOptions};
+
+pub(super) fn handle_import_git(
+    ws: &Workspace,
+    path: String,
+    branch: Option<String>,
+    scope: Option<String>,
+    release_tags: bool,
+    json: bool,
+) -> Result<()> {
+    let imported = ws
+        .import_git(std::path::Path::new(&path), &GitImportOptions { branch })
+        .with_context(|| format!("import git repository {}", path))?;
+
+    let releases = if release_tags {
+        release_tags_on_remote(ws, &imported, scope)?
+    } else {
+        Vec::new()
+    };
+
+    if json {
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&serde_json::json!({
+                "import": imported,
+                "releases": releases,
+            }))
+            .context("serialize import json")?
+        );
+        return Ok(());
+    }
+
+    println!(
+        "Imported {} commits from {} ({})",
+        imported.commits.len(),
+        path,
+        imported.branch
+    );
+    if let Some(tip) = &imported.tip {
+        println!("tip: {}", tip);
+    }
+    for (tag, snap) in &imported.tags {
+        println!("tag {} -> {}", tag, snap);
+    }
+    for r in &releases {
+        println!("Released {} (bundle {})", r.channel, r.bundle_id);
+    }
+    Ok(())
+}
+
+/// Publishes each tagged snap, bundles it alone, and releases it on a channel named after the tag.
+///
+/// Tags are mapped to channel names up front (see `tag_channel`); tags with no usable name are
+/// skipped with a warning. A snap is published and bundled once per run, and a publication left
+/// by an earlier import of the same snap is reused.
+fn release_tags_on_remote(
+    ws: &Workspace,
+    imported: &GitImport,
+    scope: Option<String>,
+) -> Result<Vec<converge::remote::Release>> {
+    let (remote, token) = require_remote_and_token(&ws.store)?;
+    let client = RemoteClient::new(remote.clone(), token)?;
+    let scope = scope.unwrap_or_else(|| remote.scope.clone());
+
+    // Release in history order so the newest tag ends up latest in shared channels.
+    let order = imported
+        .commits
+        .iter()
+        .enumerate()
+        .map(|(i, c)| (c.snap_id.as_str(), i))
+        .collect::<std::collections::HashMap<_, _>>();
+    let mut tags = Vec::new();
+    for (tag, snap_id) in &imported.tags {
+        match tag_channel(tag) {
+            Some(channel) => tags.push((tag, channel, snap_id)),
+            None => eprintln!(
+                "warning: skipping tag {}: no valid release channel name",
+                tag
+            ),
+        }
+    }
+    tags.sort_by_key(|(tag, _, snap)| (order.get(snap.as_str()).copied(), tag.as_str()));
+    if tags.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let mut existing = client
+        .list_publications()?
+        .into_iter()
+        .filter(|p| p.scope == scope && p.gate == remote.gate)
+        .map(|p| (p.snap_id.clone(), p))
+        .collect::<std::collections::HashMap<_, _>>();
+    let mut bundles = std::collections::HashMap::new();
+    let mut releases = Vec::new();
+    for (tag, channel, snap_id) in tags {
+        if !bundles.contains_key(snap_id) {
+            let publication = match existing.remove(snap_id) {
+                Some(p) => p,
+                None => {
+                    let snap = ws.store.get_snap(snap_id)?;
+                    client
+                        .publish_snap(&ws.store, &snap, &scope, &remote.gate)
+                        .with_context(|| format!("publish tag {}", tag))?
+                }
+            };
+            let bundle = client
+                .create_bundle(&scope, &remote.gate, std::slice::from_ref(&publication.id))
+                .with_context(|| format!("bundle tag {}", tag))?;
+            bundles.insert(snap_id.clone(), bundle.id);
+        }
+        let release = client
+            .create_release(
+                &channel,
+                &bundles[snap_id],
+                Some(format!("imported from git tag {}", tag)),
+            )
+            .with_context(|| format!("release tag {}", tag))?;
+        releases.push(release);
+    }
+    Ok(releases)
+}
+
+/// Release channel for a git tag: lowercased, with runs of other characters (`.`, `/`, `_`, ...)
+/// turned into single dashes, so `v1.2.0` becomes `v1-2-0` and `release/1.0` becomes
+/// `release-1-0`. `None` when nothing usable is left.
+fn tag_channel(tag: &str) -> Option<String> {
+    let mut channel = String::new();
+    for c in tag.chars() {
+        let c = c.to_ascii_lowercase();
+        if c.is_ascii_lowercase() || c.is_ascii_digit() {
+            channel.push(c);
+        } else if !channel.is_empty() && !channel.ends_with('-') {
+            channel.push('-');
+        }
+    }
+    let channel = channel.trim_end_matches('-');
+    (!channel.is_empty()).then(|| channel.to_string())
+}
@@ -0,0 +1,31 @@
+use super::*;
+
+mod archive;
+mod git_export;
+mod git_import;
+
+pub(super) use self::archive::handle_archive_command;
+
+pub(super) fn handle_import_command(ws: &Workspace, command: ImportCommands) -> Result<()> {
+    match command {
+        ImportCommands::Git {
+            path,
+            branch,
+            scope,
+            release_tags,
+            json,
+        } => git_import::handle_import_git(ws, path, branch, scope, release_tags, json),
+    }
+}
+
+pub(super) fn handle_export_command(ws: &Workspace, command: ExportCommands) -> Result<()> {
+    match command {
+        ExportCommands::Git {
+            release,
+            bundle_id,
+            into,
+            branch,
+            json,
+        } => git_export::handle_export_git(ws, release, bundle_id, into, branch, json),
+    }
+}
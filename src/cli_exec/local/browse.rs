@@ -0,0 +1,106 @@
+use std::io::Write;
+
+use super::*;
+
+use converge::browse::{Tree, TreeEntry, resolve_tree_ref};
+use converge::model::ManifestEntryKind;
+
+pub(in crate::cli_exec) fn handle_ls_command(
+    ws: &Workspace,
+    tree: String,
+    path: Option<String>,
+    recursive: bool,
+    json: bool,
+) -> Result<()> {
+    let remote = optional_remote_client(ws)?;
+    let tree_ref = resolve_tree_ref(&ws.store, remote.as_ref(), &tree)?;
+    let browser = Tree::new(&ws.store, remote.as_ref(), tree_ref.root_manifest.clone());
+    let path = path.unwrap_or_default();
+    let entries = if recursive {
+        browser.walk(&path)?
+    } else {
+        browser.list(&path)?
+    };
+
+    if json {
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&serde_json::json!({
+                "tree": tree_ref,
+                "entries": entries,
+            }))
+            .context("serialize ls json")?
+        );
+        return Ok(());
+    }
+    for e in &entries {
+        println!("{}", ls_line(e, recursive));
+    }
+    Ok(())
+}
+
+pub(in crate::cli_exec) fn handle_cat_command(
+    ws: &Workspace,
+    spec: String,
+    variant: Option<usize>,
+) -> Result<()> {
+    let Some((tree, path)) = spec.split_once(':').filter(|(_, p)| !p.is_empty()) else {
+        anyhow::bail!("expected <snap|bundle|release>:<path>");
+    };
+    let remote = optional_remote_client(ws)?;
+    let tree_ref = resolve_tree_ref(&ws.store, remote.as_ref(), tree)?;
+    let browser = Tree::new(&ws.store, remote.as_ref(), tree_ref.root_manifest);
+    let bytes = match variant {
+        Some(n) => browser.read_variant(path, n)?,
+        None => browser.read(path)?,
+    };
+    std::io::stdout()
+        .lock()
+        .write_all(&bytes)
+        .context("write stdout")?;
+    Ok(())
+}
+
+/// `<mode> <size> <kind> <name>`, with `/` after directories and markers for links and superpositions.
+fn ls_line(e: &TreeEntry, full_path: bool) -> String {
+    let name = if full_path { &e.path } else { &e.entry.name };
+    let (mode, size, kind, name) = match &e.entry.kind {
+        ManifestEntryKind::File { mode, size, .. } => (
+            format!("{:o}", mode),
+            size.to_string(),
+            "file",
+            name.clone(),
+        ),
+        ManifestEntryKind::FileChunks { mode, size, .. } => (
+            format!("{:o}", mode),
+            size.to_string(),
+            "chunked",
+            name.clone(),
+        ),
+        ManifestEntryKind::Dir { .. } => ("-".into(), "-".into(), "dir", format!("{}/", name)),
+        ManifestEntryKind::Symlink { target } => (
+            "-".into(),
+            "-".into(),
+            "symlink",
+            format!("{} -> {}", name, target),
+        ),
+        ManifestEntryKind::Superposition { variants } => (
+            "-".into(),
+            "-".into(),
+            "super",
+            format!("{} ({} variants)", name, variants.len()),
+        ),
+    };
+    format!("{:>6} {:>10} {:<7} {}", mode, size, kind, name)
+}
+
+/// A client when a remote and token are configured; browsing local snaps needs neither.
+pub(super) fn optional_remote_client(ws: &Workspace) -> Result<Option<RemoteClient>> {
+    let Some(remote) = ws.store.read_config()?.remote else {
+        return Ok(None);
+    };
+    let Some(token) = ws.store.get_remote_token(&remote)? else {
+        return Ok(None);
+    };
+    Ok(Some(RemoteClient::new(remote, token)?))
+}
@@ -1,13 +1,16 @@
 use super::*;
 
+use converge::diff::{ContentSource, DiffLine, FileDiff, FileDiffBody, HunkLine};
+
 pub(in crate::cli_exec) fn handle_diff_command(
     from: Option<String>,
     to: Option<String>,
+    patch: bool,
     json: bool,
 ) -> Result<()> {
     let ws = Workspace::discover(&std::env::current_dir().context("get current dir")?)?;
 
-    let diffs = match (from.as_deref(), to.as_deref()) {
+    let (diffs, to_source) = match (from.as_deref(), to.as_deref()) {
         (None, None) => {
             let head = ws.store.get_head()?.context("no HEAD snap")?;
             let head_snap = ws.store.get_snap(&head)?;
@@ -16,7 +19,10 @@ pub(in crate::cli_exec) fn handle_diff_command(
             let (cur_root, cur_manifests, _stats) = ws.current_manifest_tree()?;
             let to_tree = converge::diff::tree_from_memory(&cur_manifests, &cur_root)?;
 
-            converge::diff::diff_trees(&from_tree, &to_tree)
+            (
+                converge::diff::diff_trees(&from_tree, &to_tree),
+                ContentSource::Dir(&ws.root),
+            )
         }
         (Some(_), None) | (None, Some(_)) => {
             anyhow::bail!(
@@ -28,10 +34,29 @@ pub(in crate::cli_exec) fn handle_diff_command(
             let to_snap = ws.store.get_snap(to)?;
             let from_tree = converge::diff::tree_from_store(&ws.store, &from_snap.root_manifest)?;
             let to_tree = converge::diff::tree_from_store(&ws.store, &to_snap.root_manifest)?;
-            converge::diff::diff_trees(&from_tree, &to_tree)
+            (
+                converge::diff::diff_trees(&from_tree, &to_tree),
+                ContentSource::Store(&ws.store),
+            )
         }
     };
 
+    if patch {
+        let files = converge::diff::file_diffs(&diffs, ContentSource::Store(&ws.store), to_source)?;
+        if json {
+            println!(
+                "{}",
+                serde_json::to_string_pretty(&files).context("serialize diff json")?
+            );
+        } else {
+            for f in &files {
+                print_file_patch(f);
+            }
+            println!("{} changes", files.len());
+        }
+        return Ok(());
+    }
+
     if json {
         println!(
             "{}",
@@ -40,12 +65,42 @@ pub(in crate::cli_exec) fn handle_diff_command(
     } else {
         for d in &diffs {
             match d {
-                converge::diff::DiffLine::Added { path, .. } => println!("A {}", path),
-                converge::diff::DiffLine::Deleted { path, .. } => println!("D {}", path),
-                converge::diff::DiffLine::Modified { path, .. } => println!("M {}", path),
+                DiffLine::Added { path, .. } => println!("A {}", path),
+                DiffLine::Deleted { path, .. } => println!("D {}", path),
+                DiffLine::Modified { path, .. } => println!("M {}", path),
             }
         }
         println!("{} changes", diffs.len());
     }
     Ok(())
 }
+
+fn print_file_patch(f: &FileDiff) {
+    let path = f.path();
+    let (old, new) = match f.change {
+        DiffLine::Added { .. } => ("/dev/null".to_string(), format!("b/{}", path)),
+        DiffLine::Deleted { .. } => (format!("a/{}", path), "/dev/null".to_string()),
+        DiffLine::Modified { .. } => (format!("a/{}", path), format!("b/{}", path)),
+    };
+    println!("--- {}", old);
+    println!("+++ {}", new);
+    match &f.body {
+        FileDiffBody::Text { hunks } => {
+            for h in hunks {
+                println!("{}", h.header());
+                for l in &h.lines {
+                    match l {
+                        HunkLine::Context(t) => println!(" {}", t),
+                        HunkLine::Removed(t) => println!("-{}", t),
+                        HunkLine::Added(t) => println!("+{}", t),
+                    }
+                }
+            }
+        }
+        other => {
+            if let Some(summary) = other.summary() {
+                println!("{}", summary);
+            }
+        }
+    }
+}
@@ -0,0 +1,107 @@
+use super::*;
+
+use super::browse::optional_remote_client;
+
+use converge::history::{blame_path, path_history};
+
+pub(in crate::cli_exec) fn handle_history_command(
+    ws: &Workspace,
+    path: String,
+    limit: Option<usize>,
+    json: bool,
+) -> Result<()> {
+    let remote = optional_remote_client(ws)?;
+    let history = path_history(&ws.store, remote.as_ref(), &path)?;
+    if let Some(warning) = &history.warning {
+        eprintln!("warning: {}", warning);
+    }
+    let mut entries = history.entries;
+    if let Some(n) = limit {
+        entries.truncate(n);
+    }
+
+    if json {
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&entries).context("serialize history json")?
+        );
+        return Ok(());
+    }
+    if entries.is_empty() {
+        println!("no history for {}", path);
+        return Ok(());
+    }
+    for e in &entries {
+        println!(
+            "{} {} {:<7} {} {} {}",
+            e.at,
+            e.change.status_char(),
+            e.kind.as_str(),
+            short(&e.id),
+            e.by.as_deref().unwrap_or("-"),
+            e.detail.as_deref().unwrap_or("")
+        );
+    }
+    Ok(())
+}
+
+pub(in crate::cli_exec) fn handle_blame_command(
+    ws: &Workspace,
+    path: String,
+    snap: Option<String>,
+    json: bool,
+) -> Result<()> {
+    let tip = match snap {
+        Some(s) => s,
+        None => ws
+            .store
+            .get_head()?
+            .context("no snaps yet (pass --snap or create one with `converge snap`)")?,
+    };
+    let remote = optional_remote_client(ws)?;
+    let blame = blame_path(&ws.store, remote.as_ref(), &tip, &path)?;
+    if let Some(warning) = &blame.warning {
+        eprintln!("warning: {}", warning);
+    }
+
+    if json {
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&blame).context("serialize blame json")?
+        );
+        return Ok(());
+    }
+    let who = |o: &converge::history::LineOrigin| {
+        o.publisher
+            .clone()
+            .or_else(|| o.author.clone())
+            .unwrap_or_else(|| "-".to_string())
+    };
+    let width = blame
+        .origins
+        .iter()
+        .map(|o| who(o).chars().count())
+        .max()
+        .unwrap_or(1);
+    let digits = blame.lines.len().to_string().len();
+    for (i, l) in blame.lines.iter().enumerate() {
+        let o = &blame.origins[l.origin];
+        println!(
+            "{} {:<width$} {:>digits$}) {}",
+            short(&o.snap_id),
+            who(o),
+            i + 1,
+            l.text,
+        );
+    }
+    Ok(())
+}
+
+/// Snap and bundle ids are shortened; release channels are printed as given.
+fn short(id: &str) -> String {
+    if id.len() == 64 {
+        id.chars().take(8).collect()
+    } else {
+        id.to_string()
+    }
+}
@@ -1,9 +1,15 @@
 use super::*;
 
+mod browse;
 mod diff;
+mod history;
+mod tui;
 mod workspace_ops;
 
+pub(super) use self::browse::{handle_cat_command, handle_ls_command};
 pub(super) use self::diff::handle_diff_command;
+pub(super) use self::history::{handle_blame_command, handle_history_command};
+pub(in crate::cli_exec) use self::tui::handle_tui_command;
 pub(super) use self::workspace_ops::{
     handle_init_command, handle_mv_command, handle_restore_command, handle_show_command,
     handle_snap_command, handle_snaps_command,
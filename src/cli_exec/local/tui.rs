@@ -0,0 +1,35 @@
+use std::path::PathBuf;
+
+use super::*;
+
+pub(in crate::cli_exec) fn handle_tui_command(
+    script: Option<PathBuf>,
+    width: u16,
+    height: u16,
+    frames: bool,
+    agent_trace: Option<PathBuf>,
+) -> Result<()> {
+    let Some(script) = script else {
+        return converge::tui::run_with_options(converge::tui::TuiRunOptions { agent_trace });
+    };
+
+    let mut stdout = std::io::stdout().lock();
+    let summary = converge::tui::run_script(
+        converge::tui::TuiScriptOptions {
+            script,
+            width,
+            height,
+            frames,
+            agent_trace,
+        },
+        &mut stdout,
+    )?;
+    if summary.failed > 0 {
+        anyhow::bail!(
+            "{} of the script's expectations failed ({} steps run)",
+            summary.failed,
+            summary.steps
+        );
+    }
+    Ok(())
+}
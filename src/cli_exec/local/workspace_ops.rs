@@ -62,6 +62,12 @@ pub(in crate::cli_exec) fn handle_show_command(snap_id: String, json: bool) -> R
         {
             println!("message: {}", msg);
         }
+        if let Some(author) = &snap.author {
+            println!("author: {}", author);
+        }
+        for parent in &snap.parents {
+            println!("parent: {}", parent);
+        }
         println!("root_manifest: {}", snap.root_manifest.as_str());
         println!(
             "stats: files={} dirs={} symlinks={} bytes={}",
@@ -4,19 +4,31 @@ use converge::remote::RemoteClient;
 use converge::workspace::Workspace;
 
 use crate::{
-    Commands, GateGraphCommands, LaneCommands, LaneMembersCommands, MembersCommands,
-    ReleaseCommands, RemoteCommands, ResolveCommands, TokenCommands, UserCommands,
-    require_remote_and_token,
+    Commands, ExportCommands, GateGraphCommands, GroupCommands, ImportCommands, KeyCommands,
+    LaneCommands, LaneMembersCommands, MembersCommands, ReleaseCommands, RemoteCommands,
+    ResolveCommands, TokenCommands, TraceCommands, UserCommands, require_remote_and_token,
 };
 
 mod delivery;
 mod dispatch;
 mod identity;
+mod interop;
 mod local;
 mod release_resolve;
 mod remote_admin;
+mod trace;
 mod workspace;
 
 pub(super) fn handle_command(command: Commands) -> Result<()> {
     dispatch::handle_command(command)
 }
+
+pub(super) fn handle_tui_command(
+    script: Option<std::path::PathBuf>,
+    width: u16,
+    height: u16,
+    frames: bool,
+    agent_trace: Option<std::path::PathBuf>,
+) -> Result<()> {
+    local::handle_tui_command(script, width, height, frames, agent_trace)
+}
@@ -2,8 +2,11 @@ use super::*;
 
 mod release_cmd;
 mod resolve_apply_validate;
+mod resolve_auto;
 mod resolve_init;
 mod resolve_pick_clear_show;
+mod resolve_records;
+mod resolve_reopen;
 
 pub(super) fn handle_release_command(ws: &Workspace, command: ReleaseCommands) -> Result<()> {
     let (remote, token) = require_remote_and_token(&ws.store)?;
@@ -15,11 +18,21 @@ pub(super) fn handle_release_command(ws: &Workspace, command: ReleaseCommands) -
             bundle_id,
             notes,
             json,
-        } => release_cmd::handle_release_create(&client, channel, bundle_id, notes, json)?,
+        } => {
+            let client = client.with_signer(ws.store.signing_key()?);
+            release_cmd::handle_release_create(&client, channel, bundle_id, notes, json)?
+        }
         ReleaseCommands::List { json } => release_cmd::handle_release_list(&client, json)?,
         ReleaseCommands::Show { channel, json } => {
             release_cmd::handle_release_show(&client, channel, json)?
         }
+        ReleaseCommands::Attach {
+            channel,
+            file,
+            kind,
+            name,
+            json,
+        } => release_cmd::handle_release_attach(ws, &client, channel, file, kind, name, json)?,
     }
 
     Ok(())
@@ -33,17 +46,34 @@ pub(super) fn handle_resolve_command(ws: &Workspace, command: ResolveCommands) -
         ResolveCommands::Init {
             bundle_id,
             force,
+            auto,
             json,
-        } => resolve_init::handle_resolve_init(ws, &client, bundle_id, force, json)?,
+        } => resolve_init::handle_resolve_init(ws, &client, bundle_id, force, auto, json)?,
         ResolveCommands::Pick {
             bundle_id,
             path,
             variant,
             key,
+            rationale,
             json,
         } => resolve_pick_clear_show::handle_resolve_pick(
-            ws, &client, bundle_id, path, variant, key, json,
+            ws,
+            &client,
+            resolve_pick_clear_show::ResolvePickInput {
+                bundle_id,
+                path,
+                variant,
+                key,
+                rationale,
+                json,
+            },
         )?,
+        ResolveCommands::Reopen {
+            bundle_id,
+            path,
+            rationale,
+            json,
+        } => resolve_reopen::handle_resolve_reopen(ws, &client, bundle_id, path, rationale, json)?,
         ResolveCommands::Clear {
             bundle_id,
             path,
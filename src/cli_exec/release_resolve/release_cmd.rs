@@ -60,6 +60,48 @@ pub(super) fn handle_release_show(
         if let Some(n) = r.notes {
             println!("notes: {}", n);
         }
+        if !r.attachments.is_empty() {
+            println!("attachments:");
+            for a in &r.attachments {
+                println!("  {} ({}, {} bytes)", a.name, a.kind, a.size);
+            }
+        }
+    }
+    Ok(())
+}
+
+pub(super) fn handle_release_attach(
+    ws: &Workspace,
+    client: &RemoteClient,
+    channel: String,
+    file: String,
+    kind: String,
+    name: Option<String>,
+    json: bool,
+) -> Result<()> {
+    let path = std::path::Path::new(&file);
+    let name = match name {
+        Some(n) => n,
+        None => path
+            .file_name()
+            .and_then(|n| n.to_str())
+            .with_context(|| format!("cannot derive attachment name from {}", file))?
+            .to_string(),
+    };
+
+    let stored = ws.store_file(path)?;
+    client.upload_stored_file(&ws.store, &stored)?;
+    let r = client.attach_release_file(&channel, &name, &kind, &stored)?;
+    if json {
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&r).context("serialize release attach json")?
+        );
+    } else {
+        println!(
+            "Attached {} ({}, {} bytes) to release {} ({})",
+            name, kind, stored.size, r.channel, r.id
+        );
     }
     Ok(())
 }
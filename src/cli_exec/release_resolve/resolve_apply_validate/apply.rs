@@ -1,3 +1,4 @@
+use super::super::resolve_records::record_local_decisions;
 use super::*;
 
 pub(super) fn handle_resolve_apply(
@@ -35,12 +36,16 @@ pub(super) fn handle_resolve_apply(
         root_manifest: resolved_root,
         message: input.message,
         stats: converge::model::SnapStats::default(),
+        parents: Vec::new(),
+        author: None,
     };
 
     ws.store.put_snap(&snap)?;
 
     let mut pub_id = None;
     if input.publish {
+        record_local_decisions(ws, client, &resolution)?;
+
         let pubrec = client.publish_snap_with_resolution(
             &ws.store,
             &snap,
@@ -0,0 +1,96 @@
+use super::*;
+
+/// A decision pre-filled by a resolution rule.
+#[derive(Debug, serde::Serialize)]
+pub(super) struct AutoDecision {
+    pub(super) path: String,
+    pub(super) rule: String,
+    pub(super) strategy: &'static str,
+}
+
+/// Applies gate rules, then the tree's `converge-rules.json`, to every superposition in the
+/// bundle and records the chosen variants on the server.
+///
+/// `union-lines` rules synthesize new content, so they only take effect during bundling.
+pub(super) fn auto_resolve(
+    ws: &Workspace,
+    client: &RemoteClient,
+    bundle: &converge::remote::Bundle,
+    resolution: &mut converge::model::Resolution,
+) -> Result<Vec<AutoDecision>> {
+    let mut rules = client
+        .get_gate_graph()?
+        .gates
+        .into_iter()
+        .find(|g| g.id == bundle.gate)
+        .map(|g| g.resolution_rules)
+        .unwrap_or_default();
+    rules.extend(tree_rules(ws, &resolution.root_manifest)?);
+    if rules.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let origins = client.get_bundle_origins(&bundle.id)?;
+    let variants = converge::resolve::superposition_variants(&ws.store, &resolution.root_manifest)?;
+
+    let mut applied = Vec::new();
+    let mut records = Vec::new();
+    for (path, vs) in variants {
+        let Some(rule) = converge::resolve::matching_rule(&rules, &path)? else {
+            continue;
+        };
+        let Some(idx) = converge::resolve::choose_variant(&rule.strategy, &vs, &origins) else {
+            continue;
+        };
+
+        let key = vs[idx].key();
+        resolution.decisions.insert(
+            path.clone(),
+            converge::model::ResolutionDecision::Key(key.clone()),
+        );
+        resolution.rules.insert(path.clone(), rule.name.clone());
+        records.push(converge::remote::RecordResolutionDecision {
+            path: path.clone(),
+            decision: key,
+            method: Some(converge::remote::ResolutionMethod::Rule),
+            rule: Some(rule.name.clone()),
+            rationale: Some(format!("rule {} ({})", rule.name, rule.strategy.as_str())),
+        });
+        applied.push(AutoDecision {
+            path,
+            rule: rule.name.clone(),
+            strategy: rule.strategy.as_str(),
+        });
+    }
+
+    if !records.is_empty() {
+        client
+            .record_bundle_resolutions(&bundle.id, &records)
+            .context("record rule decisions")?;
+    }
+    Ok(applied)
+}
+
+fn tree_rules(
+    ws: &Workspace,
+    root: &converge::model::ObjectId,
+) -> Result<Vec<converge::model::ResolutionRule>> {
+    let manifest = ws.store.get_manifest(root)?;
+    let Some(entry) = manifest
+        .entries
+        .iter()
+        .find(|e| e.name == converge::model::RESOLUTION_RULES_FILE)
+    else {
+        return Ok(Vec::new());
+    };
+    let converge::model::ManifestEntryKind::File { blob, .. } = &entry.kind else {
+        return Ok(Vec::new());
+    };
+    if !ws.store.has_blob(blob) {
+        return Ok(Vec::new());
+    }
+    let bytes = ws.store.get_blob(blob)?;
+    let rules = converge::resolve::parse_resolution_rules(&bytes)
+        .with_context(|| format!("read {}", converge::model::RESOLUTION_RULES_FILE))?;
+    Ok(rules.rules)
+}
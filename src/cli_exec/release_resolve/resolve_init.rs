@@ -5,6 +5,7 @@ pub(super) fn handle_resolve_init(
     client: &RemoteClient,
     bundle_id: String,
     force: bool,
+    auto: bool,
     json: bool,
 ) -> Result<()> {
     if ws.store.has_resolution(&bundle_id) && !force {
@@ -20,12 +21,19 @@ pub(super) fn handle_resolve_init(
     let created_at = time::OffsetDateTime::now_utc()
         .format(&time::format_description::well_known::Rfc3339)
         .context("format time")?;
-    let resolution = converge::model::Resolution {
+    let mut resolution = converge::model::Resolution {
         version: 2,
         bundle_id: bundle_id.clone(),
         root_manifest: root,
         created_at,
         decisions: std::collections::BTreeMap::new(),
+        rules: std::collections::BTreeMap::new(),
+        rationales: std::collections::BTreeMap::new(),
+    };
+    let auto_decisions = if auto {
+        super::resolve_auto::auto_resolve(ws, client, &bundle, &mut resolution)?
+    } else {
+        Vec::new()
     };
     ws.store.put_resolution(&resolution)?;
 
@@ -34,7 +42,8 @@ pub(super) fn handle_resolve_init(
             "{}",
             serde_json::to_string_pretty(&serde_json::json!({
                 "resolution": resolution,
-                "conflicts": counts
+                "conflicts": counts,
+                "auto": auto_decisions
             }))
             .context("serialize resolve init json")?
         );
@@ -48,6 +57,9 @@ pub(super) fn handle_resolve_init(
                 println!("{} (variants: {})", p, n);
             }
         }
+        for d in &auto_decisions {
+            println!("Auto-resolved {} (rule {}: {})", d.path, d.rule, d.strategy);
+        }
     }
 
     Ok(())
@@ -4,16 +4,21 @@ mod pick;
 mod pick_spec;
 mod show_clear;
 
+pub(in super::super) struct ResolvePickInput {
+    pub(in super::super) bundle_id: String,
+    pub(in super::super) path: String,
+    pub(in super::super) variant: Option<u32>,
+    pub(in super::super) key: Option<String>,
+    pub(in super::super) rationale: Option<String>,
+    pub(in super::super) json: bool,
+}
+
 pub(super) fn handle_resolve_pick(
     ws: &Workspace,
     client: &RemoteClient,
-    bundle_id: String,
-    path: String,
-    variant: Option<u32>,
-    key: Option<String>,
-    json: bool,
+    input: ResolvePickInput,
 ) -> Result<()> {
-    pick::handle_resolve_pick(ws, client, bundle_id, path, variant, key, json)
+    pick::handle_resolve_pick(ws, client, input)
 }
 
 pub(super) fn handle_resolve_clear(
@@ -1,16 +1,21 @@
 use super::super::*;
 
+use super::ResolvePickInput;
 use super::pick_spec::{PickSpecifier, parse_pick_specifier};
 
 pub(super) fn handle_resolve_pick(
     ws: &Workspace,
     client: &RemoteClient,
-    bundle_id: String,
-    path: String,
-    variant: Option<u32>,
-    key: Option<String>,
-    json: bool,
+    input: ResolvePickInput,
 ) -> Result<()> {
+    let ResolvePickInput {
+        bundle_id,
+        path,
+        variant,
+        key,
+        rationale,
+        json,
+    } = input;
     let bundle = client.get_bundle(&bundle_id)?;
     let root = converge::model::ObjectId(bundle.root_manifest.clone());
     client.fetch_manifest_tree(&ws.store, &root)?;
@@ -21,15 +26,15 @@ pub(super) fn handle_resolve_pick(
     };
     let vlen = vs.len();
 
-    let decision = match parse_pick_specifier(variant, key, vlen)? {
-        PickSpecifier::VariantIndex(idx) => converge::model::ResolutionDecision::Key(vs[idx].key()),
+    let picked = match parse_pick_specifier(variant, key, vlen)? {
+        PickSpecifier::VariantIndex(idx) => vs[idx].key(),
         PickSpecifier::KeyJson(key_json) => {
             let key: converge::model::VariantKey =
                 serde_json::from_str(&key_json).context("parse --key")?;
             if !vs.iter().any(|v| v.key() == key) {
                 anyhow::bail!("key not present at path {}", path);
             }
-            converge::model::ResolutionDecision::Key(key)
+            key
         }
     };
 
@@ -59,7 +64,24 @@ pub(super) fn handle_resolve_pick(
         }
     }
 
-    r.decisions.insert(path.clone(), decision);
+    // Record the decision on the server first so the team sees who resolved what.
+    client.record_bundle_resolutions(
+        &bundle_id,
+        &[converge::remote::RecordResolutionDecision {
+            path: path.clone(),
+            decision: picked.clone(),
+            method: Some(converge::remote::ResolutionMethod::Pick),
+            rule: None,
+            rationale,
+        }],
+    )?;
+
+    r.decisions.insert(
+        path.clone(),
+        converge::model::ResolutionDecision::Key(picked),
+    );
+    r.rules.remove(&path);
+    r.rationales.remove(&path);
     ws.store.put_resolution(&r)?;
 
     if json {
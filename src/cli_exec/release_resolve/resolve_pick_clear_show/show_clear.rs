@@ -8,6 +8,8 @@ pub(super) fn handle_resolve_clear(
 ) -> Result<()> {
     let mut r = ws.store.get_resolution(&bundle_id)?;
     r.decisions.remove(&path);
+    r.rules.remove(&path);
+    r.rationales.remove(&path);
     if r.version == 1 {
         r.version = 2;
     }
@@ -42,6 +44,10 @@ pub(super) fn handle_resolve_show(
         .keys()
         .filter(|p| r.decisions.contains_key(*p))
         .count();
+    let records = client
+        .get_bundle_resolutions(&bundle_id)
+        .with_context(|| format!("fetch resolutions for bundle {}", bundle_id))?
+        .records;
 
     if json {
         println!(
@@ -49,7 +55,8 @@ pub(super) fn handle_resolve_show(
             serde_json::to_string_pretty(&serde_json::json!({
                 "resolution": r,
                 "conflicts": variants,
-                "decided": decided
+                "decided": decided,
+                "records": records
             }))
             .context("serialize resolve show json")?
         );
@@ -59,17 +66,45 @@ pub(super) fn handle_resolve_show(
         println!("created_at: {}", r.created_at);
         println!("decisions: {}", r.decisions.len());
 
+        // Paths a rule already resolved while the bundle was coalesced.
+        let bundled = records
+            .values()
+            .filter(|rec| rec.rule.is_some() && !variants.contains_key(&rec.path))
+            .collect::<Vec<_>>();
+        if !bundled.is_empty() {
+            println!("resolved by rules during bundling:");
+            for rec in bundled {
+                println!("{}", rec.path);
+                print_record(rec);
+            }
+        }
+
         if !variants.is_empty() {
             println!("decided: {}/{}", decided, variants.len());
             println!("conflicts:");
             for (p, vs) in variants {
                 println!("{} (variants: {})", p, vs.len());
+                if let Some(rec) = records.get(&p) {
+                    print_record(rec);
+                }
                 for (idx, v) in vs.iter().enumerate() {
                     let n = idx + 1;
                     let key_json =
                         serde_json::to_string(&v.key()).context("serialize variant key")?;
                     println!("  #{} source={}", n, v.source);
                     println!("    key={}", key_json);
+                    if let Some(meta) = &v.metadata {
+                        print_provenance(meta);
+                    }
+                    if let Some(driver) = v.metadata.as_ref().and_then(|m| m.merge_driver.as_ref())
+                    {
+                        let keys = v
+                            .metadata
+                            .as_ref()
+                            .map(|m| m.conflicting_keys.join(", "))
+                            .unwrap_or_default();
+                        println!("    driver={} conflicting_keys: {}", driver, keys);
+                    }
                 }
             }
         }
@@ -77,3 +112,42 @@ pub(super) fn handle_resolve_show(
 
     Ok(())
 }
+
+fn print_record(rec: &converge::remote::ResolutionRecord) {
+    let mut line = format!("  status={}", rec.status.as_str());
+    if let Some(resolver) = &rec.resolver {
+        line.push_str(&format!(" resolver={}", resolver));
+    }
+    if let Some(method) = rec.method {
+        line.push_str(&format!(" method={}", method.as_str()));
+    }
+    if let Some(rule) = &rec.rule {
+        line.push_str(&format!(" rule={}", rule));
+    }
+    if let Some(at) = &rec.updated_at {
+        line.push_str(&format!(" at={}", at));
+    }
+    println!("{}", line);
+    if let Some(rationale) = &rec.rationale {
+        println!("  rationale: {}", rationale);
+    }
+}
+
+fn print_provenance(meta: &converge::model::VariantMetadata) {
+    let mut line = String::new();
+    if let Some(publisher) = &meta.publisher {
+        line.push_str(&format!(" publisher={}", publisher));
+    }
+    if let Some(at) = &meta.published_at {
+        line.push_str(&format!(" published_at={}", at));
+    }
+    if let Some(lane) = &meta.lane {
+        line.push_str(&format!(" lane={}", lane));
+    }
+    if !line.is_empty() {
+        println!("   {}", line);
+    }
+    if let Some(message) = &meta.snap_message {
+        println!("    message: {}", message);
+    }
+}
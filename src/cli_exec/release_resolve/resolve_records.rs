@@ -0,0 +1,42 @@
+use super::*;
+
+/// Records every local decision on the server so gates can see the bundle as resolved.
+pub(super) fn record_local_decisions(
+    ws: &Workspace,
+    client: &RemoteClient,
+    resolution: &converge::model::Resolution,
+) -> Result<()> {
+    let keys = converge::resolve::decision_keys(
+        &ws.store,
+        &resolution.root_manifest,
+        &resolution.decisions,
+    )?;
+    let decisions = keys
+        .into_iter()
+        .map(|(path, key)| {
+            let rule = resolution.rules.get(&path).cloned();
+            let method = if rule.is_some() {
+                converge::remote::ResolutionMethod::Rule
+            } else if key.is_manual() {
+                converge::remote::ResolutionMethod::Manual
+            } else {
+                converge::remote::ResolutionMethod::Pick
+            };
+            let rationale = resolution.rationales.get(&path).cloned();
+            converge::remote::RecordResolutionDecision {
+                path,
+                decision: key,
+                method: Some(method),
+                rule,
+                rationale,
+            }
+        })
+        .collect::<Vec<_>>();
+
+    if !decisions.is_empty() {
+        client
+            .record_bundle_resolutions(&resolution.bundle_id, &decisions)
+            .context("record resolution decisions")?;
+    }
+    Ok(())
+}
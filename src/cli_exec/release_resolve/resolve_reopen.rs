@@ -0,0 +1,36 @@
+use super::*;
+
+pub(super) fn handle_resolve_reopen(
+    ws: &Workspace,
+    client: &RemoteClient,
+    bundle_id: String,
+    path: String,
+    rationale: Option<String>,
+    json: bool,
+) -> Result<()> {
+    let records = client.reopen_bundle_resolution(&bundle_id, &path, rationale)?;
+
+    // Drop the local decision so the path shows up as missing again.
+    if ws.store.has_resolution(&bundle_id) {
+        let mut r = ws.store.get_resolution(&bundle_id)?;
+        r.rules.remove(&path);
+        r.rationales.remove(&path);
+        if r.decisions.remove(&path).is_some() {
+            if r.version == 1 {
+                r.version = 2;
+            }
+            ws.store.put_resolution(&r)?;
+        }
+    }
+
+    if json {
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&records).context("serialize resolution records")?
+        );
+    } else {
+        println!("Reopened {}", path);
+    }
+
+    Ok(())
+}
@@ -38,28 +38,40 @@ fn starter_gate_graph() -> converge::remote::GateGraph {
                 id: "dev-intake".to_string(),
                 name: "Dev Intake".to_string(),
                 upstream: Vec::new(),
+                lane: None,
                 allow_releases: true,
                 allow_superpositions: false,
                 allow_metadata_only_publications: false,
                 required_approvals: 0,
+                require_signed: false,
+                roles: Default::default(),
+                resolution_rules: Vec::new(),
             },
             converge::remote::GateDef {
                 id: "integrate".to_string(),
                 name: "Integrate".to_string(),
                 upstream: vec!["dev-intake".to_string()],
+                lane: None,
                 allow_releases: true,
                 allow_superpositions: false,
                 allow_metadata_only_publications: false,
                 required_approvals: 0,
+                require_signed: false,
+                roles: Default::default(),
+                resolution_rules: Vec::new(),
             },
             converge::remote::GateDef {
                 id: "ship".to_string(),
                 name: "Ship".to_string(),
                 upstream: vec!["integrate".to_string()],
+                lane: None,
                 allow_releases: true,
                 allow_superpositions: false,
                 allow_metadata_only_publications: false,
                 required_approvals: 0,
+                require_signed: false,
+                roles: Default::default(),
+                resolution_rules: Vec::new(),
             },
         ],
     }
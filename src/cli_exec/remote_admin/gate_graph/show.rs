@@ -17,7 +17,13 @@ pub(super) fn show_gate_graph(client: &RemoteClient, json: bool) -> Result<()> {
                 format!("<- {}", g.upstream.join(", "))
             };
             let release = if g.allow_releases { "" } else { " no-releases" };
-            println!("{} {}{}", g.id, ups, release);
+            let signed = if g.require_signed { " signed" } else { "" };
+            let roles = if g.roles.is_empty() {
+                String::new()
+            } else {
+                format!(" roles[{}]", g.roles.summary())
+            };
+            println!("{} {}{}{}{}", g.id, ups, release, signed, roles);
         }
     }
 
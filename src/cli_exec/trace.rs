@@ -0,0 +1,127 @@
+use super::*;
+
+use converge::trace::{FrictionReport, SCHEMA_VERSION, friction_report, parse_trace};
+
+pub(super) fn handle_trace_command(command: TraceCommands) -> Result<()> {
+    match command {
+        TraceCommands::Report { file, out, json } => {
+            let text = std::fs::read_to_string(&file)
+                .with_context(|| format!("read trace {}", file.display()))?;
+            let report = friction_report(&parse_trace(&text));
+            let rendered = if json {
+                let mut s =
+                    serde_json::to_string_pretty(&report).context("serialize trace report json")?;
+                s.push('\n');
+                s
+            } else {
+                render_markdown(&file.display().to_string(), &report)
+            };
+            match out {
+                Some(path) => {
+                    std::fs::write(&path, rendered)
+                        .with_context(|| format!("write report {}", path.display()))?;
+                    println!("wrote {}", path.display());
+                }
+                None => print!("{}", rendered),
+            }
+        }
+    }
+    Ok(())
+}
+
+fn render_markdown(path: &str, r: &FrictionReport) -> String {
+    let mut out = String::new();
+    let versions = r
+        .schema_versions
+        .iter()
+        .map(|v| v.to_string())
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    out.push_str("# Agent Trace Friction Report\n\n");
+    out.push_str(&format!("- Trace: `{}`\n", path));
+    out.push_str(&format!(
+        "- Schema versions: {}\n",
+        if versions.is_empty() { "-" } else { &versions }
+    ));
+    out.push_str(&format!("- Sessions: {}\n", r.sessions));
+    out.push_str(&format!("- Events: {}\n", r.events));
+    out.push_str(&format!("- Duration: {}\n", fmt_duration(r.duration_ms)));
+    if r.skipped_lines > 0 {
+        out.push_str(&format!("- Skipped lines: {}\n", r.skipped_lines));
+    }
+    if r.unknown_events > 0 {
+        out.push_str(&format!("- Unknown events: {}\n", r.unknown_events));
+    }
+    if r.schema_versions.iter().any(|v| *v > SCHEMA_VERSION) {
+        out.push_str(&format!(
+            "\nNote: this trace was written by a newer build (schema > {}); events this build does not know are counted but not analyzed.\n",
+            SCHEMA_VERSION
+        ));
+    }
+
+    out.push_str("\n## Modes\n\n");
+    if r.modes.is_empty() {
+        out.push_str("None.\n");
+    } else {
+        out.push_str("| Mode | Dwell | Views | Keys | Commands | Errors | Error rate |\n");
+        out.push_str("| --- | ---: | ---: | ---: | ---: | ---: | ---: |\n");
+        for m in &r.modes {
+            out.push_str(&format!(
+                "| {} | {} | {} | {} | {} | {} | {} |\n",
+                m.mode,
+                fmt_duration(m.dwell_ms),
+                m.views,
+                m.key_presses,
+                m.commands,
+                m.validation_errors + m.system_errors,
+                m.error_rate
+                    .map(|rate| format!("{:.0}%", rate * 100.0))
+                    .unwrap_or_else(|| "-".to_string())
+            ));
+        }
+    }
+
+    out.push_str("\n## Abandoned wizards\n\n");
+    if r.abandoned_wizards.is_empty() {
+        out.push_str("None.\n");
+    }
+    for w in &r.abandoned_wizards {
+        match &w.prompt {
+            Some(prompt) => out.push_str(&format!(
+                "- {}x `{}` at \"{}\" (in {})\n",
+                w.count, w.title, prompt, w.mode
+            )),
+            None => out.push_str(&format!("- {}x `{}` (in {})\n", w.count, w.title, w.mode)),
+        }
+    }
+
+    out.push_str("\n## Repeated command loops\n\n");
+    if r.command_loops.is_empty() {
+        out.push_str("None.\n");
+    }
+    for l in &r.command_loops {
+        out.push_str(&format!(
+            "- {}x `{}` in {} ({} errors)\n",
+            l.count, l.command, l.mode, l.errors
+        ));
+    }
+
+    out.push_str("\n## Repeated errors\n\n");
+    if r.error_loops.is_empty() {
+        out.push_str("None.\n");
+    }
+    for e in &r.error_loops {
+        out.push_str(&format!("- {}x {}\n", e.count, e.message));
+    }
+    out
+}
+
+fn fmt_duration(ms: u64) -> String {
+    let secs = ms / 1000;
+    if secs >= 60 {
+        format!("{}m {:02}s", secs / 60, secs % 60)
+    } else {
+        format!("{}.{}s", secs, (ms % 1000) / 100)
+    }
+}
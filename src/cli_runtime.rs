@@ -26,10 +26,17 @@ pub(crate) fn run() -> Result<()> {
                 agent_trace: cli.agent_trace,
             })?;
         }
+        Some(Commands::Tui(args)) => crate::cli_exec::handle_tui_command(
+            args.script,
+            args.width,
+            args.height,
+            args.frames,
+            cli.agent_trace,
+        )?,
         Some(command) => {
             if cli.agent_trace.is_some() {
                 anyhow::bail!(
-                    "`--agent-trace` is only supported when running the TUI (no subcommand)"
+                    "`--agent-trace` is only supported when running the TUI (no subcommand or `tui`)"
                 );
             }
             crate::cli_exec::handle_command(command)?
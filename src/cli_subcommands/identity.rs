@@ -9,20 +9,36 @@ pub(crate) enum MembersCommands {
         json: bool,
     },
 
-    /// Add a repo member
+    /// Add a repo member or grant a role
     Add {
+        /// User handle, or @<group>
         handle: String,
-        /// Role: read|publish
+        /// Role: read|publish (legacy), or reader|publisher|converger|promoter|releaser|approver|maintainer
         #[arg(long, default_value = "read")]
         role: String,
+        /// Narrow the role to one gate
+        #[arg(long, conflicts_with = "lane")]
+        gate: Option<String>,
+        /// Narrow the role to one lane
+        #[arg(long)]
+        lane: Option<String>,
         /// Emit JSON
         #[arg(long)]
         json: bool,
     },
 
-    /// Remove a repo member
+    /// Remove a repo member, or only one of their roles
     Remove {
         handle: String,
+        /// Remove only this role
+        #[arg(long)]
+        role: Option<String>,
+        /// Gate the role was narrowed to
+        #[arg(long, requires = "role", conflicts_with = "lane")]
+        gate: Option<String>,
+        /// Lane the role was narrowed to
+        #[arg(long, requires = "role")]
+        lane: Option<String>,
         /// Emit JSON
         #[arg(long)]
         json: bool,
@@ -31,12 +47,114 @@ pub(crate) enum MembersCommands {
 
 #[derive(Subcommand)]
 pub(crate) enum LaneCommands {
+    /// Create a lane (repo owner/maintainer)
+    Create {
+        lane_id: String,
+        /// What the lane is for
+        #[arg(long)]
+        description: Option<String>,
+        /// Gate the lane feeds (repeatable)
+        #[arg(long = "gate")]
+        gates: Vec<String>,
+        /// Scope new work in this lane defaults to
+        #[arg(long)]
+        default_scope: Option<String>,
+        /// Emit JSON
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Edit lane metadata (repo owner/maintainer)
+    Edit {
+        lane_id: String,
+        /// Rename the lane
+        #[arg(long)]
+        rename: Option<String>,
+        /// New description ("" clears)
+        #[arg(long)]
+        description: Option<String>,
+        /// Replace the owning gates (repeatable)
+        #[arg(long = "gate")]
+        gates: Vec<String>,
+        /// Drop all owning gates
+        #[arg(long, conflicts_with = "gates")]
+        clear_gates: bool,
+        /// New default scope ("" clears)
+        #[arg(long)]
+        default_scope: Option<String>,
+        /// Emit JSON
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Archive a lane; its heads are kept but can no longer move
+    Archive {
+        lane_id: String,
+        /// Emit JSON
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Reopen an archived lane
+    Unarchive {
+        lane_id: String,
+        /// Emit JSON
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Delete a lane that has no heads
+    Delete {
+        lane_id: String,
+        /// Emit JSON
+        #[arg(long)]
+        json: bool,
+    },
+
     /// Manage lane members
     Members {
         lane_id: String,
         #[command(subcommand)]
         command: LaneMembersCommands,
     },
+
+    /// See a lane you are not a member of (audited)
+    Subscribe {
+        lane_id: String,
+        /// Only see publications in this scope
+        #[arg(long)]
+        scope: Option<String>,
+        /// Expire the subscription (YYYY-MM-DD or RFC3339); your own default to 30 days, at most 90
+        #[arg(long)]
+        until: Option<String>,
+        /// Subscribe another user (repo owner/maintainer)
+        #[arg(long)]
+        user: Option<String>,
+        /// Emit JSON
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Drop a lane subscription
+    Unsubscribe {
+        lane_id: String,
+        /// Scope the subscription was limited to
+        #[arg(long)]
+        scope: Option<String>,
+        /// Unsubscribe another user (repo owner/maintainer)
+        #[arg(long)]
+        user: Option<String>,
+        /// Emit JSON
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// List active lane subscriptions
+    Subscriptions {
+        /// Emit JSON
+        #[arg(long)]
+        json: bool,
+    },
 }
 
 #[derive(Subcommand)]
@@ -50,6 +168,7 @@ pub(crate) enum LaneMembersCommands {
 
     /// Add a lane member
     Add {
+        /// User handle, or @<group>
         handle: String,
         /// Emit JSON
         #[arg(long)]
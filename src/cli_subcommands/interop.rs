@@ -0,0 +1,56 @@
+use clap::Subcommand;
+
+#[derive(Subcommand)]
+pub(crate) enum ImportCommands {
+    /// Import a local Git repository's branch history as snaps (via `git fast-export`)
+    Git {
+        /// Path to the Git repository
+        path: String,
+
+        /// Branch to import (defaults to the repository's current branch)
+        #[arg(long)]
+        branch: Option<String>,
+
+        /// Scope to publish tags into (defaults to remote config)
+        #[arg(long)]
+        scope: Option<String>,
+
+        /// Publish and bundle each tag, then release it on a channel named after it (v1.2.0 -> v1-2-0)
+        #[arg(long)]
+        release_tags: bool,
+
+        /// Emit JSON
+        #[arg(long)]
+        json: bool,
+    },
+}
+
+#[derive(Subcommand)]
+pub(crate) enum ExportCommands {
+    /// Write a release (or bundle) tree as a commit in a Git repository (via `git fast-import`)
+    Git {
+        /// Release channel to export
+        #[arg(
+            long,
+            conflicts_with = "bundle_id",
+            required_unless_present = "bundle_id"
+        )]
+        release: Option<String>,
+
+        /// Bundle to export
+        #[arg(long)]
+        bundle_id: Option<String>,
+
+        /// Git repository to commit into (created if missing)
+        #[arg(long)]
+        into: String,
+
+        /// Branch to commit to (defaults to converge/<channel>, or converge/bundles)
+        #[arg(long)]
+        branch: Option<String>,
+
+        /// Emit JSON
+        #[arg(long)]
+        json: bool,
+    },
+}
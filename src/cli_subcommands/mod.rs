@@ -1,13 +1,19 @@
 mod gate_graph;
 mod identity;
+mod interop;
 mod release;
 mod remote;
 mod resolve;
+mod signing;
+mod trace;
 mod user_token;
 
 pub(crate) use self::gate_graph::GateGraphCommands;
 pub(crate) use self::identity::{LaneCommands, LaneMembersCommands, MembersCommands};
+pub(crate) use self::interop::{ExportCommands, ImportCommands};
 pub(crate) use self::release::ReleaseCommands;
 pub(crate) use self::remote::RemoteCommands;
 pub(crate) use self::resolve::ResolveCommands;
-pub(crate) use self::user_token::{TokenCommands, UserCommands};
+pub(crate) use self::signing::KeyCommands;
+pub(crate) use self::trace::TraceCommands;
+pub(crate) use self::user_token::{GroupCommands, TokenCommands, UserCommands};
@@ -30,4 +30,21 @@ pub(crate) enum ReleaseCommands {
         #[arg(long)]
         json: bool,
     },
+
+    /// Attach a file (build artifact, SBOM or signature) to the latest release in a channel
+    Attach {
+        #[arg(long)]
+        channel: String,
+        /// File to upload
+        #[arg(long)]
+        file: String,
+        #[arg(long, value_parser = ["artifact", "sbom", "signature"], default_value = "artifact")]
+        kind: String,
+        /// Attachment name (defaults to the file name)
+        #[arg(long)]
+        name: Option<String>,
+        /// Emit JSON
+        #[arg(long)]
+        json: bool,
+    },
 }
@@ -2,7 +2,7 @@ use clap::Subcommand;
 
 #[derive(Subcommand)]
 pub(crate) enum ResolveCommands {
-    /// Initialize a resolution file for a bundle (does not choose variants)
+    /// Initialize a resolution file for a bundle (chooses variants only with --auto)
     Init {
         /// Bundle id to resolve
         #[arg(long)]
@@ -10,6 +10,9 @@ pub(crate) enum ResolveCommands {
         /// Overwrite existing resolution
         #[arg(long)]
         force: bool,
+        /// Pre-fill decisions from resolution rules (gate rules, then converge-rules.json)
+        #[arg(long)]
+        auto: bool,
         /// Emit JSON
         #[arg(long)]
         json: bool,
@@ -30,6 +33,25 @@ pub(crate) enum ResolveCommands {
         /// Variant key JSON (stable)
         #[arg(long, conflicts_with = "variant")]
         key: Option<String>,
+        /// Why this variant was chosen (recorded on the server)
+        #[arg(long)]
+        rationale: Option<String>,
+        /// Emit JSON
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Reopen a resolved path on the server and drop the local decision
+    Reopen {
+        /// Bundle id
+        #[arg(long)]
+        bundle_id: String,
+        /// Path to reopen
+        #[arg(long)]
+        path: String,
+        /// Why the resolution is being reopened
+        #[arg(long)]
+        rationale: Option<String>,
         /// Emit JSON
         #[arg(long)]
         json: bool,
@@ -0,0 +1,73 @@
+use std::path::PathBuf;
+
+use clap::Subcommand;
+
+#[derive(Subcommand)]
+pub(crate) enum KeyCommands {
+    /// Generate an ed25519 signing key and use it for this workspace
+    Generate {
+        /// Where to write the private key (default: .converge/keys/<key-id>.json)
+        #[arg(long)]
+        path: Option<PathBuf>,
+
+        /// Also register the public key with the remote
+        #[arg(long)]
+        register: bool,
+
+        /// Label shown in `key list` when registering
+        #[arg(long)]
+        label: Option<String>,
+
+        /// Emit JSON
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Use an existing private key file for this workspace
+    Use {
+        #[arg(long)]
+        path: PathBuf,
+
+        /// Emit JSON
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Show the workspace signing key
+    Show {
+        /// Emit JSON
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Register the workspace signing key's public key with the remote
+    Register {
+        #[arg(long)]
+        label: Option<String>,
+
+        /// Emit JSON
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// List public keys registered on the remote
+    List {
+        /// Only keys of this user handle
+        #[arg(long)]
+        user: Option<String>,
+
+        /// Emit JSON
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Revoke a registered key (yours, or any as admin)
+    Revoke {
+        #[arg(long)]
+        id: String,
+
+        /// Emit JSON
+        #[arg(long)]
+        json: bool,
+    },
+}
@@ -0,0 +1,20 @@
+use std::path::PathBuf;
+
+use clap::Subcommand;
+
+#[derive(Subcommand)]
+pub(crate) enum TraceCommands {
+    /// Summarize friction in an agent trace (dwell per mode, errors, abandoned wizards, loops)
+    Report {
+        /// Trace file written by `--agent-trace`
+        file: PathBuf,
+
+        /// Write the report here instead of stdout
+        #[arg(long)]
+        out: Option<PathBuf>,
+
+        /// Emit JSON instead of markdown
+        #[arg(long)]
+        json: bool,
+    },
+}
@@ -10,6 +10,19 @@ pub(crate) enum TokenCommands {
         /// Create token for another user handle (admin)
         #[arg(long)]
         user: Option<String>,
+
+        /// Limit the token to a repo (repeatable)
+        #[arg(long = "repo")]
+        repos: Vec<String>,
+
+        /// Limit the token to capabilities: read, publish, report-checks, approve, promote, release
+        #[arg(long, value_delimiter = ',')]
+        allow: Vec<String>,
+
+        /// Expire the token after a duration (e.g. 3600s, 12h, 30d, 2w)
+        #[arg(long)]
+        expires: Option<String>,
+
         /// Emit JSON
         #[arg(long)]
         json: bool,
@@ -53,3 +66,57 @@ pub(crate) enum UserCommands {
         json: bool,
     },
 }
+
+#[derive(Subcommand)]
+pub(crate) enum GroupCommands {
+    /// List groups
+    List {
+        /// Emit JSON
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Show a group's members and nested groups
+    Show {
+        name: String,
+        /// Emit JSON
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Create a group (admin)
+    Create {
+        name: String,
+        #[arg(long)]
+        description: Option<String>,
+        /// Emit JSON
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Delete a group and every repo role and lane membership granted to it (admin)
+    Delete {
+        name: String,
+        /// Emit JSON
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Add a user handle, or a nested group as @<name> (admin)
+    Add {
+        name: String,
+        member: String,
+        /// Emit JSON
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Remove a user handle or nested @<group> (admin)
+    Remove {
+        name: String,
+        member: String,
+        /// Emit JSON
+        #[arg(long)]
+        json: bool,
+    },
+}
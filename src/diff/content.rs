@@ -0,0 +1,240 @@
+use std::path::Path;
+
+use anyhow::{Context, Result};
+
+use crate::model::ObjectId;
+use crate::store::LocalStore;
+
+use super::text::{Hunk, HunkLine, diff_lines};
+use super::{DiffLine, EntrySig};
+
+/// Files larger than this are summarized instead of diffed line by line.
+pub const MAX_TEXT_DIFF_BYTES: u64 = 1024 * 1024;
+
+/// Lines of unchanged context kept around each change.
+pub const DIFF_CONTEXT_LINES: usize = 3;
+
+/// Where the bytes behind a tree's entries live.
+#[derive(Clone, Copy)]
+pub enum ContentSource<'a> {
+    /// Blobs and recipes in the local object store (snaps, fetched bundles).
+    Store(&'a LocalStore),
+    /// Files under a directory, for trees scanned from the working copy.
+    Dir(&'a Path),
+}
+
+impl ContentSource<'_> {
+    /// File contents for `sig`; `None` for entries that are not regular files.
+    pub fn read(&self, path: &str, sig: &EntrySig) -> Result<Option<Vec<u8>>> {
+        match (self, sig) {
+            (ContentSource::Store(store), EntrySig::File { blob, .. }) => {
+                Ok(Some(store.get_blob(&ObjectId(blob.clone()))?))
+            }
+            (ContentSource::Store(store), EntrySig::FileChunks { recipe, .. }) => {
+                let recipe = store.get_recipe(&ObjectId(recipe.clone()))?;
+                let mut out = Vec::with_capacity(recipe.size as usize);
+                for c in recipe.chunks {
+                    out.extend_from_slice(&store.get_blob(&c.blob)?);
+                }
+                Ok(Some(out))
+            }
+            (ContentSource::Dir(root), EntrySig::File { .. } | EntrySig::FileChunks { .. }) => {
+                let p = root.join(path);
+                Ok(Some(
+                    std::fs::read(&p).with_context(|| format!("read {}", p.display()))?,
+                ))
+            }
+            _ => Ok(None),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum FileDiffBody {
+    Text {
+        hunks: Vec<Hunk>,
+    },
+    /// Non-UTF-8 or NUL-containing content; only sizes are compared.
+    Binary {
+        old_size: Option<u64>,
+        new_size: Option<u64>,
+    },
+    TooLarge {
+        old_size: Option<u64>,
+        new_size: Option<u64>,
+    },
+    Symlink {
+        old_target: Option<String>,
+        new_target: Option<String>,
+    },
+    Superposition {
+        variants: usize,
+    },
+    /// The entry changed kind (e.g. file to symlink).
+    KindChange,
+}
+
+impl FileDiffBody {
+    /// One-line description for bodies without hunks; `None` for text.
+    pub fn summary(&self) -> Option<String> {
+        let size = |s: &Option<u64>| s.map(|n| format!("{} bytes", n)).unwrap_or("absent".into());
+        let target = |t: &Option<String>| t.clone().unwrap_or("absent".into());
+        match self {
+            FileDiffBody::Text { .. } => None,
+            FileDiffBody::Binary { old_size, new_size } => Some(format!(
+                "Binary files differ ({} -> {})",
+                size(old_size),
+                size(new_size)
+            )),
+            FileDiffBody::TooLarge { old_size, new_size } => Some(format!(
+                "File too large to diff ({} -> {})",
+                size(old_size),
+                size(new_size)
+            )),
+            FileDiffBody::Symlink {
+                old_target,
+                new_target,
+            } => Some(format!(
+                "Symlink {} -> {}",
+                target(old_target),
+                target(new_target)
+            )),
+            FileDiffBody::Superposition { variants } => {
+                Some(format!("Superposition with {} variants", variants))
+            }
+            FileDiffBody::KindChange => Some("Entry kind changed".to_string()),
+        }
+    }
+}
+
+/// A path-level change together with its content diff.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize)]
+pub struct FileDiff {
+    #[serde(flatten)]
+    pub change: DiffLine,
+    pub body: FileDiffBody,
+}
+
+impl FileDiff {
+    pub fn path(&self) -> &str {
+        match &self.change {
+            DiffLine::Added { path, .. }
+            | DiffLine::Deleted { path, .. }
+            | DiffLine::Modified { path, .. } => path,
+        }
+    }
+
+    /// `A`, `D` or `M`, as printed by `converge diff`.
+    pub fn status_char(&self) -> char {
+        match self.change {
+            DiffLine::Added { .. } => 'A',
+            DiffLine::Deleted { .. } => 'D',
+            DiffLine::Modified { .. } => 'M',
+        }
+    }
+
+    pub fn hunks(&self) -> &[Hunk] {
+        match &self.body {
+            FileDiffBody::Text { hunks } => hunks,
+            _ => &[],
+        }
+    }
+
+    /// Added and removed line counts.
+    pub fn line_counts(&self) -> (usize, usize) {
+        self.hunks()
+            .iter()
+            .flat_map(|h| &h.lines)
+            .fold((0, 0), |(a, r), l| match l {
+                HunkLine::Added(_) => (a + 1, r),
+                HunkLine::Removed(_) => (a, r + 1),
+                HunkLine::Context(_) => (a, r),
+            })
+    }
+}
+
+/// Content diffs for `changes`, reading the old side from `from` and the new side from `to`.
+pub fn file_diffs(
+    changes: &[DiffLine],
+    from: ContentSource,
+    to: ContentSource,
+) -> Result<Vec<FileDiff>> {
+    changes.iter().map(|c| file_diff(c, from, to)).collect()
+}
+
+pub fn file_diff(change: &DiffLine, from: ContentSource, to: ContentSource) -> Result<FileDiff> {
+    let (path, old, new) = match change {
+        DiffLine::Added { path, to } => (path, None, Some(to)),
+        DiffLine::Deleted { path, from } => (path, Some(from), None),
+        DiffLine::Modified { path, from, to } => (path, Some(from), Some(to)),
+    };
+
+    let body = match (old, new) {
+        (_, Some(EntrySig::Superposition { variants }))
+        | (Some(EntrySig::Superposition { variants }), None) => FileDiffBody::Superposition {
+            variants: *variants,
+        },
+        (Some(EntrySig::Symlink { target: a }), Some(EntrySig::Symlink { target: b })) => {
+            FileDiffBody::Symlink {
+                old_target: Some(a.clone()),
+                new_target: Some(b.clone()),
+            }
+        }
+        (Some(EntrySig::Symlink { target }), None) => FileDiffBody::Symlink {
+            old_target: Some(target.clone()),
+            new_target: None,
+        },
+        (None, Some(EntrySig::Symlink { target })) => FileDiffBody::Symlink {
+            old_target: None,
+            new_target: Some(target.clone()),
+        },
+        (Some(a), Some(b)) if !is_file(a) || !is_file(b) => FileDiffBody::KindChange,
+        _ => {
+            let old_size = old.and_then(file_size);
+            let new_size = new.and_then(file_size);
+            if old_size.max(new_size).unwrap_or(0) > MAX_TEXT_DIFF_BYTES {
+                FileDiffBody::TooLarge { old_size, new_size }
+            } else {
+                let old_bytes = read_side(from, path, old)?;
+                let new_bytes = read_side(to, path, new)?;
+                match (text_of(&old_bytes), text_of(&new_bytes)) {
+                    (Some(a), Some(b)) => FileDiffBody::Text {
+                        hunks: diff_lines(a, b, DIFF_CONTEXT_LINES),
+                    },
+                    _ => FileDiffBody::Binary { old_size, new_size },
+                }
+            }
+        }
+    };
+
+    Ok(FileDiff {
+        change: change.clone(),
+        body,
+    })
+}
+
+fn is_file(sig: &EntrySig) -> bool {
+    matches!(sig, EntrySig::File { .. } | EntrySig::FileChunks { .. })
+}
+
+fn file_size(sig: &EntrySig) -> Option<u64> {
+    match sig {
+        EntrySig::File { size, .. } | EntrySig::FileChunks { size, .. } => Some(*size),
+        _ => None,
+    }
+}
+
+fn read_side(source: ContentSource, path: &str, sig: Option<&EntrySig>) -> Result<Vec<u8>> {
+    match sig {
+        Some(sig) => Ok(source.read(path, sig)?.unwrap_or_default()),
+        None => Ok(Vec::new()),
+    }
+}
+
+fn text_of(bytes: &[u8]) -> Option<&str> {
+    if bytes.iter().take(8000).any(|b| *b == 0) {
+        return None;
+    }
+    std::str::from_utf8(bytes).ok()
+}
@@ -1,8 +1,15 @@
+mod content;
 mod diff_ops;
 mod signatures;
+mod text;
 mod tree_build;
 mod walk;
 
+pub use content::{
+    ContentSource, DIFF_CONTEXT_LINES, FileDiff, FileDiffBody, MAX_TEXT_DIFF_BYTES, file_diff,
+    file_diffs,
+};
 pub use diff_ops::{DiffLine, diff_trees};
 pub use signatures::EntrySig;
+pub use text::{Hunk, HunkLine, diff_lines};
 pub use tree_build::{tree_from_memory, tree_from_store};
@@ -0,0 +1,211 @@
+/// One line of a hunk, without its trailing newline.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize)]
+#[serde(tag = "op", content = "text", rename_all = "lowercase")]
+pub enum HunkLine {
+    Context(String),
+    Removed(String),
+    Added(String),
+}
+
+/// A run of changes with surrounding context; line numbers are 1-based, as in unified diffs.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize)]
+pub struct Hunk {
+    pub old_start: usize,
+    pub old_lines: usize,
+    pub new_start: usize,
+    pub new_lines: usize,
+    pub lines: Vec<HunkLine>,
+}
+
+impl Hunk {
+    pub fn header(&self) -> String {
+        format!(
+            "@@ -{},{} +{},{} @@",
+            self.old_start, self.old_lines, self.new_start, self.new_lines
+        )
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Edit {
+    Keep(usize, usize),
+    Delete(usize),
+    Insert(usize),
+}
+
+/// Line diff of two texts, grouped into hunks with `context` unchanged lines around each change.
+pub fn diff_lines(old: &str, new: &str, context: usize) -> Vec<Hunk> {
+    let a: Vec<&str> = old.lines().collect();
+    let b: Vec<&str> = new.lines().collect();
+    let edits = myers(&a, &b);
+
+    let changed: Vec<usize> = edits
+        .iter()
+        .enumerate()
+        .filter(|(_, e)| !matches!(e, Edit::Keep(..)))
+        .map(|(i, _)| i)
+        .collect();
+    let Some(&first) = changed.first() else {
+        return Vec::new();
+    };
+
+    // Group changes whose context windows touch.
+    let mut groups = vec![(first, first)];
+    for &i in &changed[1..] {
+        let last = groups.last_mut().expect("non-empty groups");
+        if i - last.1 <= 2 * context + 1 {
+            last.1 = i;
+        } else {
+            groups.push((i, i));
+        }
+    }
+
+    groups
+        .into_iter()
+        .map(|(start, end)| {
+            let lo = start.saturating_sub(context);
+            let hi = (end + context + 1).min(edits.len());
+            build_hunk(&edits[lo..hi], &a, &b, position(&edits[..lo]))
+        })
+        .collect()
+}
+
+/// Old/new line counts consumed by `edits`.
+fn position(edits: &[Edit]) -> (usize, usize) {
+    edits.iter().fold((0, 0), |(o, n), e| match e {
+        Edit::Keep(..) => (o + 1, n + 1),
+        Edit::Delete(_) => (o + 1, n),
+        Edit::Insert(_) => (o, n + 1),
+    })
+}
+
+fn build_hunk(edits: &[Edit], a: &[&str], b: &[&str], (old_pos, new_pos): (usize, usize)) -> Hunk {
+    let (old_lines, new_lines) = position(edits);
+    let lines = edits
+        .iter()
+        .map(|e| match *e {
+            Edit::Keep(i, _) => HunkLine::Context(a[i].to_string()),
+            Edit::Delete(i) => HunkLine::Removed(a[i].to_string()),
+            Edit::Insert(j) => HunkLine::Added(b[j].to_string()),
+        })
+        .collect();
+    // Unified diffs number an empty side from the line before it.
+    let start = |pos: usize, len: usize| if len == 0 { pos } else { pos + 1 };
+    Hunk {
+        old_start: start(old_pos, old_lines),
+        old_lines,
+        new_start: start(new_pos, new_lines),
+        new_lines,
+        lines,
+    }
+}
+
+/// Cap on trace cells kept for backtracking (8 bytes each); past it, the changed middle of the
+/// file is reported as one replacement instead of a minimal script.
+const MAX_TRACE_CELLS: usize = 1 << 22;
+
+/// Shortest edit script, with deletions ordered before insertions. The unchanged prefix and
+/// suffix are matched directly and only the middle goes through Myers.
+fn myers(a: &[&str], b: &[&str]) -> Vec<Edit> {
+    let prefix = a.iter().zip(b).take_while(|(x, y)| x == y).count();
+    let suffix = a[prefix..]
+        .iter()
+        .rev()
+        .zip(b[prefix..].iter().rev())
+        .take_while(|(x, y)| x == y)
+        .count();
+    let (a_end, b_end) = (a.len() - suffix, b.len() - suffix);
+
+    let mut edits: Vec<Edit> = (0..prefix).map(|i| Edit::Keep(i, i)).collect();
+    match shortest_edit(&a[prefix..a_end], &b[prefix..b_end]) {
+        Some(middle) => edits.extend(middle.into_iter().map(|e| match e {
+            Edit::Keep(i, j) => Edit::Keep(i + prefix, j + prefix),
+            Edit::Delete(i) => Edit::Delete(i + prefix),
+            Edit::Insert(j) => Edit::Insert(j + prefix),
+        })),
+        None => {
+            edits.extend((prefix..a_end).map(Edit::Delete));
+            edits.extend((prefix..b_end).map(Edit::Insert));
+        }
+    }
+    edits.extend((0..suffix).map(|i| Edit::Keep(a_end + i, b_end + i)));
+    edits
+}
+
+/// Myers, O((N+M)D) time. Each step keeps only its `2d+1` live diagonals for backtracking, so the
+/// trace is O(D^2); `None` when that would exceed `MAX_TRACE_CELLS`.
+fn shortest_edit(a: &[&str], b: &[&str]) -> Option<Vec<Edit>> {
+    let n = a.len() as isize;
+    let m = b.len() as isize;
+    let max = (n + m) as usize;
+    let offset = max as isize;
+    let mut v = vec![0isize; 2 * max + 2];
+    // trace[d] holds diagonals -d..=d as they were before step d.
+    let mut trace: Vec<Vec<isize>> = Vec::new();
+    let mut cells = 0usize;
+
+    'outer: for d in 0..=max as isize {
+        cells += 2 * d as usize + 1;
+        if cells > MAX_TRACE_CELLS {
+            return None;
+        }
+        trace.push(v[(offset - d) as usize..=(offset + d) as usize].to_vec());
+        let mut k = -d;
+        while k <= d {
+            let idx = (k + offset) as usize;
+            let mut x = if k == -d || (k != d && v[idx - 1] < v[idx + 1]) {
+                v[idx + 1]
+            } else {
+                v[idx - 1] + 1
+            };
+            let mut y = x - k;
+            while x < n && y < m && a[x as usize] == b[y as usize] {
+                x += 1;
+                y += 1;
+            }
+            v[idx] = x;
+            if x >= n && y >= m {
+                break 'outer;
+            }
+            k += 2;
+        }
+    }
+
+    let mut edits = Vec::new();
+    let (mut x, mut y) = (n, m);
+    for (d, w) in trace.iter().enumerate().rev() {
+        let d = d as isize;
+        let (prev_x, prev_y) = if d == 0 {
+            (0, 0)
+        } else {
+            let k = x - y;
+            let at = |k: isize| w[(k + d) as usize];
+            let prev_k = if k == -d || (k != d && at(k - 1) < at(k + 1)) {
+                k + 1
+            } else {
+                k - 1
+            };
+            (at(prev_k), at(prev_k) - prev_k)
+        };
+        while x > prev_x && y > prev_y {
+            x -= 1;
+            y -= 1;
+            edits.push(Edit::Keep(x as usize, y as usize));
+        }
+        if d > 0 {
+            if x == prev_x {
+                edits.push(Edit::Insert(prev_y as usize));
+            } else {
+                edits.push(Edit::Delete(prev_x as usize));
+            }
+        }
+        x = prev_x;
+        y = prev_y;
+    }
+    edits.reverse();
+    Some(edits)
+}
+
+#[cfg(test)]
+#[path = "../tests/diff/text_tests.rs"]
+mod tests;
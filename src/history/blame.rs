@@ -0,0 +1,142 @@
+use std::collections::HashMap;
+
+use anyhow::{Context, Result};
+
+use crate::browse::{Tree, normalize_path};
+use crate::merge::{LineSegment, line_segments};
+use crate::model::{ManifestEntryKind, ObjectId};
+use crate::remote::RemoteClient;
+use crate::store::LocalStore;
+
+use super::remote_publications;
+use super::timeline::snap_chain;
+
+/// The snap (and its publication, when known) that introduced a line.
+#[derive(Clone, Debug, serde::Serialize)]
+pub struct LineOrigin {
+    pub snap_id: String,
+    pub created_at: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub author: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub publication_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub publisher: Option<String>,
+}
+
+#[derive(Clone, Debug, serde::Serialize)]
+pub struct BlameLine {
+    /// Index into [`Blame::origins`].
+    pub origin: usize,
+    pub text: String,
+}
+
+#[derive(Clone, Debug, serde::Serialize)]
+pub struct Blame {
+    pub path: String,
+    pub snap_id: String,
+    pub origins: Vec<LineOrigin>,
+    pub lines: Vec<BlameLine>,
+    /// Set when the remote could not be reached and publications are not attributed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub warning: Option<String>,
+}
+
+/// Attributes each line of `path` at snap `tip` to the snap in its ancestry that introduced it.
+///
+/// Attributions are memoized by blob (or recipe) id, so content that reappears keeps the
+/// origins it had before and each distinct version is read and diffed once.
+pub fn blame_path(
+    store: &LocalStore,
+    remote: Option<&RemoteClient>,
+    tip: &str,
+    path: &str,
+) -> Result<Blame> {
+    let path = normalize_path(path)?;
+    let (remote, mut pubs, warning) = remote_publications(remote);
+    // Keep the earliest publication of each snap.
+    pubs.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+    let publications: HashMap<String, (String, String)> = pubs
+        .into_iter()
+        .map(|p| (p.snap_id, (p.id, p.publisher)))
+        .collect();
+
+    let mut origins: Vec<LineOrigin> = Vec::new();
+    let mut memo: HashMap<ObjectId, (String, Vec<usize>)> = HashMap::new();
+    let mut current: Option<ObjectId> = None;
+    let (mut text, mut attribution) = (String::new(), Vec::new());
+
+    for snap in snap_chain(store, tip)? {
+        let tree = Tree::new(store, remote, snap.root_manifest.clone());
+        let content = match tree.find(&path)?.map(|e| e.kind) {
+            Some(ManifestEntryKind::File { blob, .. }) => Some(blob),
+            Some(ManifestEntryKind::FileChunks { recipe, .. }) => Some(recipe),
+            _ => None,
+        };
+        if content == current {
+            continue;
+        }
+        current = content.clone();
+        let Some(id) = content else {
+            (text, attribution) = (String::new(), Vec::new());
+            continue;
+        };
+        if let Some((t, a)) = memo.get(&id) {
+            (text, attribution) = (t.clone(), a.clone());
+            continue;
+        }
+
+        let bytes = tree.read(&path)?;
+        if bytes.contains(&0) {
+            anyhow::bail!("{} is binary at snap {}", path, snap.id);
+        }
+        let new_text = String::from_utf8(bytes)
+            .with_context(|| format!("{} is not UTF-8 at snap {}", path, snap.id))?;
+
+        let origin = origins.len();
+        let mut next = Vec::new();
+        let mut old = attribution.iter();
+        for seg in line_segments(&text, &new_text) {
+            match seg {
+                LineSegment::Common(lines) => next.extend(old.by_ref().take(lines.len())),
+                LineSegment::Conflict { left, right } => {
+                    old.by_ref().take(left.len()).for_each(drop);
+                    next.extend(std::iter::repeat_n(origin, right.len()));
+                }
+            }
+        }
+        if next.contains(&origin) {
+            let publication = publications.get(&snap.id);
+            origins.push(LineOrigin {
+                snap_id: snap.id.clone(),
+                created_at: snap.created_at.clone(),
+                message: snap.message.clone(),
+                author: snap.author.clone(),
+                publication_id: publication.map(|p| p.0.clone()),
+                publisher: publication.map(|p| p.1.clone()),
+            });
+        }
+        memo.insert(id, (new_text.clone(), next.clone()));
+        (text, attribution) = (new_text, next);
+    }
+
+    if current.is_none() {
+        anyhow::bail!("{} is not a file at snap {}", path, tip);
+    }
+    Ok(Blame {
+        path,
+        snap_id: tip.to_string(),
+        origins,
+        lines: text
+            .lines()
+            .zip(attribution)
+            .map(|(l, origin)| BlameLine {
+                origin,
+                text: l.to_string(),
+            })
+            .collect(),
+        warning,
+    })
+}
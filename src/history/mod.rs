@@ -0,0 +1,35 @@
+//! Per-path history and line blame.
+//!
+//! Both walk manifests with [`crate::store::find_entry_with`], reading only the manifests on
+//! the path itself; remote bundle and release trees are fetched as the walk needs them.
+
+use crate::remote::{Publication, RemoteClient};
+
+mod blame;
+mod timeline;
+
+pub use blame::{Blame, BlameLine, LineOrigin, blame_path};
+pub use timeline::{HistoryEntry, PathChange, PathHistory, path_history, snap_chain};
+
+/// Publications on `remote`. When they cannot be listed (e.g. the remote is unreachable) the
+/// walk drops the remote and stays local; the returned warning says so.
+fn remote_publications(
+    remote: Option<&RemoteClient>,
+) -> (Option<&RemoteClient>, Vec<Publication>, Option<String>) {
+    match remote.map(|r| r.list_publications()) {
+        Some(Ok(pubs)) => (remote, pubs, None),
+        Some(Err(err)) => (
+            None,
+            Vec::new(),
+            Some(format!(
+                "remote unavailable, using local snaps only: {:#}",
+                err
+            )),
+        ),
+        None => (None, Vec::new(), None),
+    }
+}
+
+#[cfg(test)]
+#[path = "../tests/history/history_tests.rs"]
+mod tests;
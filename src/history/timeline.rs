@@ -0,0 +1,232 @@
+use std::collections::{BTreeMap, HashMap};
+
+use anyhow::Result;
+
+use crate::browse::{Tree, TreeRefKind, normalize_path};
+use crate::model::{ManifestEntryKind, ObjectId, SnapRecord};
+use crate::remote::RemoteClient;
+use crate::store::LocalStore;
+
+use super::remote_publications;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PathChange {
+    Added,
+    Modified,
+    Deleted,
+}
+
+impl PathChange {
+    pub fn status_char(self) -> char {
+        match self {
+            PathChange::Added => 'A',
+            PathChange::Modified => 'M',
+            PathChange::Deleted => 'D',
+        }
+    }
+}
+
+/// A snap, bundle or release whose content at the path differs from its predecessor's.
+#[derive(Clone, Debug, serde::Serialize)]
+pub struct HistoryEntry {
+    pub kind: TreeRefKind,
+    /// Snap id, bundle id, or release channel.
+    pub id: String,
+    pub at: String,
+    pub change: PathChange,
+    /// Snap author or publisher, bundle creator, or releaser.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub by: Option<String>,
+    /// Snap message, bundle scope/gate, or the bundle behind a release.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub detail: Option<String>,
+}
+
+/// Result of [`path_history`].
+#[derive(Clone, Debug, serde::Serialize)]
+pub struct PathHistory {
+    pub entries: Vec<HistoryEntry>,
+    /// Set when the remote could not be reached and only local snaps were walked.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub warning: Option<String>,
+}
+
+/// Every point at which `path` changed, newest first.
+///
+/// Snaps are compared with their parent (or the next older snap), bundles with the previous
+/// bundle for their scope/gate, and releases with the previous release in their channel.
+/// Bundles and releases are only included with a reachable remote.
+pub fn path_history(
+    store: &LocalStore,
+    remote: Option<&RemoteClient>,
+    path: &str,
+) -> Result<PathHistory> {
+    let path = normalize_path(path)?;
+    let (remote, pubs, warning) = remote_publications(remote);
+    let mut lookup = PathLookup::new(store, remote, &path);
+    let mut out = Vec::new();
+
+    let publishers: HashMap<String, String> =
+        pubs.into_iter().map(|p| (p.snap_id, p.publisher)).collect();
+    let snaps = sorted_snaps(store)?;
+    for (i, snap) in snaps.iter().enumerate() {
+        let before = match previous_snap(store, &snaps, i) {
+            Some(prev) => lookup.at(&prev.root_manifest)?,
+            None => None,
+        };
+        let after = lookup.at(&snap.root_manifest)?;
+        if let Some(change) = change(before.as_ref(), after.as_ref()) {
+            out.push(HistoryEntry {
+                kind: TreeRefKind::Snap,
+                id: snap.id.clone(),
+                at: snap.created_at.clone(),
+                change,
+                by: publishers
+                    .get(&snap.id)
+                    .cloned()
+                    .or_else(|| snap.author.clone()),
+                detail: snap.message.clone(),
+            });
+        }
+    }
+
+    if let Some(remote) = remote {
+        let mut bundles: BTreeMap<(String, String), Vec<_>> = BTreeMap::new();
+        for b in remote.list_bundles()? {
+            bundles
+                .entry((b.scope.clone(), b.gate.clone()))
+                .or_default()
+                .push(b);
+        }
+        for ((scope, gate), mut group) in bundles {
+            group.sort_by(|a, b| a.created_at.cmp(&b.created_at));
+            let mut before = None;
+            for b in group {
+                let after = lookup.at(&ObjectId(b.root_manifest.clone()))?;
+                if let Some(change) = change(before.as_ref(), after.as_ref()) {
+                    out.push(HistoryEntry {
+                        kind: TreeRefKind::Bundle,
+                        id: b.id.clone(),
+                        at: b.created_at.clone(),
+                        change,
+                        by: Some(b.created_by.clone()),
+                        detail: Some(format!("{}/{}", scope, gate)),
+                    });
+                }
+                before = after;
+            }
+        }
+
+        let mut channels: BTreeMap<String, Vec<_>> = BTreeMap::new();
+        for r in remote.list_releases()? {
+            channels.entry(r.channel.clone()).or_default().push(r);
+        }
+        for (channel, mut group) in channels {
+            group.sort_by(|a, b| a.released_at.cmp(&b.released_at));
+            let mut before = None;
+            for r in group {
+                let root = ObjectId(remote.get_bundle(&r.bundle_id)?.root_manifest);
+                let after = lookup.at(&root)?;
+                if let Some(change) = change(before.as_ref(), after.as_ref()) {
+                    out.push(HistoryEntry {
+                        kind: TreeRefKind::Release,
+                        id: channel.clone(),
+                        at: r.released_at.clone(),
+                        change,
+                        by: Some(r.released_by.clone()),
+                        detail: Some(format!("bundle {}", r.bundle_id)),
+                    });
+                }
+                before = after;
+            }
+        }
+    }
+
+    out.sort_by(|a, b| b.at.cmp(&a.at));
+    Ok(PathHistory {
+        entries: out,
+        warning,
+    })
+}
+
+/// Ancestry of `tip`, oldest first: each snap's first recorded parent, or else the next older snap.
+pub fn snap_chain(store: &LocalStore, tip: &str) -> Result<Vec<SnapRecord>> {
+    let snaps = sorted_snaps(store)?;
+    let Some(mut i) = snaps.iter().position(|s| s.id == tip) else {
+        anyhow::bail!("no such snap: {}", tip);
+    };
+    let mut chain = vec![snaps[i].clone()];
+    while let Some(prev) = previous_snap(store, &snaps, i) {
+        let Some(j) = snaps.iter().position(|s| s.id == prev.id) else {
+            break;
+        };
+        if chain.iter().any(|s| s.id == prev.id) {
+            break;
+        }
+        chain.push(prev.clone());
+        i = j;
+    }
+    chain.reverse();
+    Ok(chain)
+}
+
+/// Local snaps, oldest first.
+fn sorted_snaps(store: &LocalStore) -> Result<Vec<SnapRecord>> {
+    let mut snaps = store.list_snaps()?;
+    snaps.sort_by(|a, b| a.created_at.cmp(&b.created_at).then(a.id.cmp(&b.id)));
+    Ok(snaps)
+}
+
+fn previous_snap<'a>(
+    store: &LocalStore,
+    snaps: &'a [SnapRecord],
+    i: usize,
+) -> Option<&'a SnapRecord> {
+    match snaps[i].parents.first().filter(|p| store.has_snap(p)) {
+        Some(p) => snaps.iter().find(|s| &s.id == p),
+        None => i.checked_sub(1).map(|j| &snaps[j]),
+    }
+}
+
+fn change(
+    before: Option<&ManifestEntryKind>,
+    after: Option<&ManifestEntryKind>,
+) -> Option<PathChange> {
+    match (before, after) {
+        (None, Some(_)) => Some(PathChange::Added),
+        (Some(_), None) => Some(PathChange::Deleted),
+        (Some(a), Some(b)) if a != b => Some(PathChange::Modified),
+        _ => None,
+    }
+}
+
+/// Looks the path up in tree roots, memoized by root manifest (bundles and releases share them).
+struct PathLookup<'a> {
+    store: &'a LocalStore,
+    remote: Option<&'a RemoteClient>,
+    path: &'a str,
+    seen: HashMap<ObjectId, Option<ManifestEntryKind>>,
+}
+
+impl<'a> PathLookup<'a> {
+    fn new(store: &'a LocalStore, remote: Option<&'a RemoteClient>, path: &'a str) -> Self {
+        Self {
+            store,
+            remote,
+            path,
+            seen: HashMap::new(),
+        }
+    }
+
+    fn at(&mut self, root: &ObjectId) -> Result<Option<ManifestEntryKind>> {
+        if let Some(kind) = self.seen.get(root) {
+            return Ok(kind.clone());
+        }
+        let kind = Tree::new(self.store, self.remote, root.clone())
+            .find(self.path)?
+            .map(|e| e.kind);
+        self.seen.insert(root.clone(), kind.clone());
+        Ok(kind)
+    }
+}
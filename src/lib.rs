@@ -1,8 +1,14 @@
+pub mod archive;
+pub mod browse;
 pub mod diff;
+pub mod history;
+pub mod merge;
 pub mod model;
 pub mod remote;
 pub mod resolve;
+pub mod signing;
 pub mod store;
+pub mod trace;
 pub mod tui;
 mod tui_shell;
 pub mod workspace;
@@ -5,8 +5,9 @@ mod cli_subcommands;
 pub(crate) use crate::cli_commands::Commands;
 pub(crate) use crate::cli_runtime::require_remote_and_token;
 pub(crate) use crate::cli_subcommands::{
-    GateGraphCommands, LaneCommands, LaneMembersCommands, MembersCommands, ReleaseCommands,
-    RemoteCommands, ResolveCommands, TokenCommands, UserCommands,
+    ExportCommands, GateGraphCommands, GroupCommands, ImportCommands, KeyCommands, LaneCommands,
+    LaneMembersCommands, MembersCommands, ReleaseCommands, RemoteCommands, ResolveCommands,
+    TokenCommands, TraceCommands, UserCommands,
 };
 
 fn main() {
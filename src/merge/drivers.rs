@@ -0,0 +1,107 @@
+use anyhow::{Context, Result};
+use serde_json::Value;
+
+/// Built-in structural merge drivers.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MergeDriver {
+    Json,
+    Toml,
+    Yaml,
+}
+
+impl MergeDriver {
+    pub fn name(&self) -> &'static str {
+        match self {
+            MergeDriver::Json => "json",
+            MergeDriver::Toml => "toml",
+            MergeDriver::Yaml => "yaml",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "json" => Some(MergeDriver::Json),
+            "toml" => Some(MergeDriver::Toml),
+            "yaml" | "yml" => Some(MergeDriver::Yaml),
+            _ => None,
+        }
+    }
+
+    /// Default driver for a path, by file extension.
+    pub fn for_path(path: &str) -> Option<Self> {
+        let name = path.rsplit('/').next().unwrap_or(path);
+        let (_, ext) = name.rsplit_once('.')?;
+        Self::from_name(&ext.to_ascii_lowercase())
+    }
+
+    pub(super) fn parse(&self, bytes: &[u8]) -> Result<Value> {
+        match self {
+            MergeDriver::Json => serde_json::from_slice(bytes).context("parse json"),
+            MergeDriver::Toml => {
+                let text = std::str::from_utf8(bytes).context("toml is not utf-8")?;
+                if has_comments(text) {
+                    anyhow::bail!("toml has comments that re-rendering would drop");
+                }
+                let value: toml::Value = toml::from_str(text).context("parse toml")?;
+                serde_json::to_value(value).context("convert toml")
+            }
+            MergeDriver::Yaml => {
+                let text = std::str::from_utf8(bytes).context("yaml is not utf-8")?;
+                if has_comments(text) {
+                    anyhow::bail!("yaml has comments that re-rendering would drop");
+                }
+                let value: serde_yaml::Value = serde_yaml::from_str(text).context("parse yaml")?;
+                serde_json::to_value(value).context("convert yaml")
+            }
+        }
+    }
+
+    pub(super) fn render(&self, value: Value) -> Result<Vec<u8>> {
+        match self {
+            MergeDriver::Json => {
+                let mut out = serde_json::to_vec_pretty(&value).context("render json")?;
+                out.push(b'\n');
+                Ok(out)
+            }
+            MergeDriver::Toml => {
+                let value: toml::Value = serde_json::from_value(value).context("convert toml")?;
+                Ok(toml::to_string_pretty(&value)
+                    .context("render toml")?
+                    .into_bytes())
+            }
+            MergeDriver::Yaml => {
+                let value: serde_yaml::Value =
+                    serde_json::from_value(value).context("convert yaml")?;
+                Ok(serde_yaml::to_string(&value)
+                    .context("render yaml")?
+                    .into_bytes())
+            }
+        }
+    }
+}
+
+/// Whether any line has a `#` outside a quoted string.
+///
+/// Errs towards `true` (e.g. for `#` inside multi-line strings); a false positive only means the
+/// file is merged as a whole instead of key by key.
+fn has_comments(text: &str) -> bool {
+    text.lines().any(|line| {
+        let mut quote = None;
+        let mut escaped = false;
+        // Quotes only open a string where a value or key starts, not mid-word (`it's`).
+        let mut at_start = true;
+        for c in line.chars() {
+            match quote {
+                Some('"') if escaped => escaped = false,
+                Some('"') if c == '\\' => escaped = true,
+                Some(q) if c == q => quote = None,
+                Some(_) => {}
+                None if c == '#' => return true,
+                None if (c == '"' || c == '\'') && at_start => quote = Some(c),
+                None if c.is_whitespace() => {}
+                None => at_start = matches!(c, '=' | ':' | '[' | '{' | ',' | '-' | '?'),
+            }
+        }
+        false
+    })
+}
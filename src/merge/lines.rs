@@ -0,0 +1,113 @@
+use crate::diff::diff_lines;
+
+/// A stretch of two texts that either agree or conflict line-wise.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum LineSegment {
+    Common(Vec<String>),
+    Conflict {
+        left: Vec<String>,
+        right: Vec<String>,
+    },
+}
+
+/// How one conflict is settled.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ConflictChoice {
+    Left,
+    Right,
+    /// Left lines followed by right lines.
+    Both,
+}
+
+pub const CONFLICT_START_MARKER: &str = "<<<<<<<";
+pub const CONFLICT_SEPARATOR: &str = "=======";
+pub const CONFLICT_END_MARKER: &str = ">>>>>>>";
+
+/// Splits two texts into common runs and conflicting regions.
+pub fn line_segments(left: &str, right: &str) -> Vec<LineSegment> {
+    let a: Vec<&str> = left.lines().collect();
+    let b: Vec<&str> = right.lines().collect();
+    let owned = |lines: &[&str]| lines.iter().map(|l| l.to_string()).collect::<Vec<_>>();
+
+    let mut out = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    for hunk in diff_lines(left, right, 0) {
+        // Zero-length sides number from the line before the change.
+        let old_at = if hunk.old_lines == 0 {
+            hunk.old_start
+        } else {
+            hunk.old_start - 1
+        };
+        let new_at = if hunk.new_lines == 0 {
+            hunk.new_start
+        } else {
+            hunk.new_start - 1
+        };
+        if old_at > i {
+            out.push(LineSegment::Common(owned(&a[i..old_at])));
+        }
+        out.push(LineSegment::Conflict {
+            left: owned(&a[old_at..old_at + hunk.old_lines]),
+            right: owned(&b[new_at..new_at + hunk.new_lines]),
+        });
+        i = old_at + hunk.old_lines;
+        j = new_at + hunk.new_lines;
+    }
+    if i < a.len() {
+        out.push(LineSegment::Common(owned(&a[i..])));
+    }
+    debug_assert_eq!(a.len() - i, b.len() - j);
+    out
+}
+
+/// Renders `segments` with `choices` (one per conflict, in order); unsettled conflicts keep
+/// conflict markers labelled with `labels`.
+pub fn merged_text(
+    segments: &[LineSegment],
+    choices: &[Option<ConflictChoice>],
+    labels: (&str, &str),
+    trailing_newline: bool,
+) -> String {
+    let mut lines: Vec<&str> = Vec::new();
+    let start = format!("{} {}", CONFLICT_START_MARKER, labels.0);
+    let end = format!("{} {}", CONFLICT_END_MARKER, labels.1);
+    let mut conflict = 0;
+    for segment in segments {
+        match segment {
+            LineSegment::Common(common) => lines.extend(common.iter().map(String::as_str)),
+            LineSegment::Conflict { left, right } => {
+                let choice = choices.get(conflict).copied().flatten();
+                conflict += 1;
+                match choice {
+                    Some(ConflictChoice::Left) => lines.extend(left.iter().map(String::as_str)),
+                    Some(ConflictChoice::Right) => lines.extend(right.iter().map(String::as_str)),
+                    Some(ConflictChoice::Both) => {
+                        lines.extend(left.iter().map(String::as_str));
+                        lines.extend(right.iter().map(String::as_str));
+                    }
+                    None => {
+                        lines.push(&start);
+                        lines.extend(left.iter().map(String::as_str));
+                        lines.push(CONFLICT_SEPARATOR);
+                        lines.extend(right.iter().map(String::as_str));
+                        lines.push(&end);
+                    }
+                }
+            }
+        }
+    }
+    let mut out = lines.join("\n");
+    if trailing_newline && !out.is_empty() {
+        out.push('\n');
+    }
+    out
+}
+
+/// True when `text` still contains a conflict start marker line.
+pub fn has_conflict_markers(text: &str) -> bool {
+    text.lines().any(|l| l.starts_with(CONFLICT_START_MARKER))
+}
+
+#[cfg(test)]
+#[path = "../tests/merge/lines_tests.rs"]
+mod tests;
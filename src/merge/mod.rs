@@ -0,0 +1,97 @@
+//! Structured (key-aware) merge drivers for configuration files, plus line-level conflict
+//! splitting for interactive merges.
+
+use anyhow::{Context, Result};
+
+use crate::model::MergeDriverRule;
+
+mod drivers;
+mod lines;
+mod structural;
+mod tree;
+
+pub use self::drivers::MergeDriver;
+pub use self::lines::{
+    CONFLICT_END_MARKER, CONFLICT_SEPARATOR, CONFLICT_START_MARKER, ConflictChoice, LineSegment,
+    has_conflict_markers, line_segments, merged_text,
+};
+pub use self::tree::{FileMergeConflict, TreeMerge, merge_trees};
+
+/// A key path that several inputs changed in different ways.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct KeyConflict {
+    /// Dotted key path (`$` for the document root).
+    pub path: String,
+    /// Indices of the inputs that changed the value at `path`.
+    pub inputs: Vec<usize>,
+}
+
+#[derive(Clone, Debug)]
+pub struct StructuredMerge {
+    /// Merged document, present only when there were no conflicts.
+    pub merged: Option<Vec<u8>>,
+    pub conflicts: Vec<KeyConflict>,
+}
+
+impl MergeDriver {
+    /// Merges `inputs` against an optional common ancestor.
+    ///
+    /// Fails when any document does not parse; callers fall back to whole-file handling.
+    pub fn merge(&self, base: Option<&[u8]>, inputs: &[&[u8]]) -> Result<StructuredMerge> {
+        let base = base.map(|b| self.parse(b)).transpose()?;
+        let values = inputs
+            .iter()
+            .map(|b| self.parse(b))
+            .collect::<Result<Vec<_>>>()?;
+        let refs = values.iter().collect::<Vec<_>>();
+
+        let (merged, conflicts) = structural::merge_values(base.as_ref(), &refs);
+        let merged = if conflicts.is_empty() {
+            Some(self.render(merged)?)
+        } else {
+            None
+        };
+        Ok(StructuredMerge { merged, conflicts })
+    }
+}
+
+/// Chooses a merge driver per path: explicit driver rules first, then the file extension.
+#[derive(Clone, Debug, Default)]
+pub struct MergeDriverRegistry {
+    rules: Vec<(globset::GlobMatcher, Option<MergeDriver>)>,
+}
+
+impl MergeDriverRegistry {
+    /// `driver = "none"` in a rule opts matching paths out of structured merging.
+    pub fn new(rules: &[MergeDriverRule]) -> Result<Self> {
+        let mut compiled = Vec::new();
+        for rule in rules {
+            let glob = globset::GlobBuilder::new(&rule.path)
+                .literal_separator(true)
+                .build()
+                .with_context(|| format!("invalid path glob {}", rule.path))?;
+            let driver = match rule.driver.as_str() {
+                "none" => None,
+                name => Some(
+                    MergeDriver::from_name(name)
+                        .with_context(|| format!("unknown merge driver {}", name))?,
+                ),
+            };
+            compiled.push((glob.compile_matcher(), driver));
+        }
+        Ok(Self { rules: compiled })
+    }
+
+    pub fn driver_for(&self, path: &str) -> Option<MergeDriver> {
+        for (matcher, driver) in &self.rules {
+            if matcher.is_match(path) {
+                return *driver;
+            }
+        }
+        MergeDriver::for_path(path)
+    }
+}
+
+#[cfg(test)]
+#[path = "../tests/merge/drivers_tests.rs"]
+mod tests;
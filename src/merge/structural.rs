@@ -0,0 +1,110 @@
+use serde_json::{Map, Value};
+
+use super::KeyConflict;
+
+/// N-way structural merge of `inputs` against an optional common `base`.
+///
+/// Object keys changed by a single input (or changed identically by several) merge cleanly;
+/// anything else is reported as a conflict at its key path. Arrays and scalars are atomic.
+pub(super) fn merge_values(base: Option<&Value>, inputs: &[&Value]) -> (Value, Vec<KeyConflict>) {
+    let sides = inputs.iter().map(|v| Some(*v)).collect::<Vec<_>>();
+    let mut conflicts = Vec::new();
+    let merged = merge_node("", base, &sides, &mut conflicts);
+    let merged = merged
+        .or_else(|| inputs.first().map(|v| (*v).clone()))
+        .unwrap_or(Value::Null);
+    (merged, conflicts)
+}
+
+fn merge_node(
+    path: &str,
+    base: Option<&Value>,
+    sides: &[Option<&Value>],
+    conflicts: &mut Vec<KeyConflict>,
+) -> Option<Value> {
+    let changed = sides
+        .iter()
+        .enumerate()
+        .filter(|(_, v)| **v != base)
+        .collect::<Vec<_>>();
+    let Some((_, first)) = changed.first() else {
+        return base.cloned();
+    };
+    if changed.iter().all(|(_, v)| *v == *first) {
+        return first.cloned();
+    }
+
+    let base_is_object = base.is_none_or(|b| b.is_object());
+    let sides_are_objects = sides.iter().all(|v| v.is_none_or(|v| v.is_object()));
+    let all_present = sides.iter().all(|v| v.is_some());
+    if base_is_object && sides_are_objects && all_present {
+        return Some(Value::Object(merge_objects(path, base, sides, conflicts)));
+    }
+
+    conflicts.push(KeyConflict {
+        path: if path.is_empty() {
+            "$".to_string()
+        } else {
+            path.to_string()
+        },
+        inputs: changed.iter().map(|(idx, _)| *idx).collect(),
+    });
+    base.cloned().or_else(|| first.cloned())
+}
+
+fn merge_objects(
+    path: &str,
+    base: Option<&Value>,
+    sides: &[Option<&Value>],
+    conflicts: &mut Vec<KeyConflict>,
+) -> Map<String, Value> {
+    let base_map = base.and_then(|b| b.as_object());
+    let side_maps = sides
+        .iter()
+        .map(|v| v.and_then(|v| v.as_object()))
+        .collect::<Vec<_>>();
+
+    // Base key order first, then keys introduced by each input in order.
+    let mut keys: Vec<&String> = Vec::new();
+    for map in base_map
+        .into_iter()
+        .chain(side_maps.iter().flatten().copied())
+    {
+        for key in map.keys() {
+            if !keys.contains(&key) {
+                keys.push(key);
+            }
+        }
+    }
+
+    let mut out = Map::new();
+    for key in keys {
+        let child_path = join_key_path(path, key);
+        let child_base = base_map.and_then(|m| m.get(key));
+        let child_sides = side_maps
+            .iter()
+            .map(|m| m.and_then(|m| m.get(key)))
+            .collect::<Vec<_>>();
+        if let Some(v) = merge_node(&child_path, child_base, &child_sides, conflicts) {
+            out.insert(key.clone(), v);
+        }
+    }
+    out
+}
+
+fn join_key_path(parent: &str, key: &str) -> String {
+    let plain = !key.is_empty()
+        && key
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-');
+    let key = if plain {
+        key.to_string()
+    } else {
+        serde_json::to_string(key).unwrap_or_else(|_| key.to_string())
+    };
+    if parent.is_empty() {
+        key
+    } else {
+        format!("{}.{}", parent, key)
+    }
+}
@@ -0,0 +1,223 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use anyhow::Result;
+
+use crate::model::{Manifest, ManifestEntry, ManifestEntryKind, ObjectId};
+use crate::store::LocalStore;
+
+use super::MergeDriverRegistry;
+
+/// A path both sides changed that could not be merged; the local version is kept.
+#[derive(Clone, Debug, serde::Serialize)]
+pub struct FileMergeConflict {
+    pub path: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub driver: Option<&'static str>,
+
+    /// Key paths the structured driver could not reconcile.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub key_paths: Vec<String>,
+}
+
+#[derive(Clone, Debug)]
+pub struct TreeMerge {
+    pub root: ObjectId,
+    /// Paths merged key-by-key by a structured driver.
+    pub merged_paths: Vec<String>,
+    pub conflicts: Vec<FileMergeConflict>,
+}
+
+/// Three-way merge of two manifest trees in the local store.
+///
+/// Without a `base`, every path both sides hold with different content is a conflict unless a
+/// structured driver can combine the documents.
+pub fn merge_trees(
+    store: &LocalStore,
+    base: Option<&ObjectId>,
+    ours: &ObjectId,
+    theirs: &ObjectId,
+    registry: &MergeDriverRegistry,
+) -> Result<TreeMerge> {
+    let mut ctx = TreeMergeContext {
+        store,
+        registry,
+        merged_paths: Vec::new(),
+        conflicts: Vec::new(),
+    };
+    let root = ctx.merge_dir("", base, ours, theirs)?;
+    Ok(TreeMerge {
+        root,
+        merged_paths: ctx.merged_paths,
+        conflicts: ctx.conflicts,
+    })
+}
+
+struct TreeMergeContext<'a> {
+    store: &'a LocalStore,
+    registry: &'a MergeDriverRegistry,
+    merged_paths: Vec<String>,
+    conflicts: Vec<FileMergeConflict>,
+}
+
+impl TreeMergeContext<'_> {
+    fn merge_dir(
+        &mut self,
+        prefix: &str,
+        base: Option<&ObjectId>,
+        ours: &ObjectId,
+        theirs: &ObjectId,
+    ) -> Result<ObjectId> {
+        let base = match base {
+            Some(id) => entries_by_name(self.store.get_manifest(id)?),
+            None => BTreeMap::new(),
+        };
+        let ours_entries = entries_by_name(self.store.get_manifest(ours)?);
+        let theirs_entries = entries_by_name(self.store.get_manifest(theirs)?);
+
+        let names = base
+            .keys()
+            .chain(ours_entries.keys())
+            .chain(theirs_entries.keys())
+            .cloned()
+            .collect::<BTreeSet<_>>();
+
+        let mut entries = Vec::new();
+        for name in names {
+            let path = if prefix.is_empty() {
+                name.clone()
+            } else {
+                format!("{}/{}", prefix, name)
+            };
+            let merged = self.merge_entry(
+                &path,
+                base.get(&name),
+                ours_entries.get(&name),
+                theirs_entries.get(&name),
+            )?;
+            if let Some(kind) = merged {
+                entries.push(ManifestEntry { name, kind });
+            }
+        }
+
+        self.store.put_manifest(&Manifest {
+            version: 1,
+            entries,
+        })
+    }
+
+    fn merge_entry(
+        &mut self,
+        path: &str,
+        base: Option<&ManifestEntryKind>,
+        ours: Option<&ManifestEntryKind>,
+        theirs: Option<&ManifestEntryKind>,
+    ) -> Result<Option<ManifestEntryKind>> {
+        if ours == theirs || theirs == base {
+            return Ok(ours.cloned());
+        }
+        if ours == base {
+            return Ok(theirs.cloned());
+        }
+
+        match (ours, theirs) {
+            (
+                Some(ManifestEntryKind::Dir { manifest: o }),
+                Some(ManifestEntryKind::Dir { manifest: t }),
+            ) => {
+                let b = match base {
+                    Some(ManifestEntryKind::Dir { manifest }) => Some(manifest),
+                    _ => None,
+                };
+                let merged = self.merge_dir(path, b, o, t)?;
+                Ok(Some(ManifestEntryKind::Dir { manifest: merged }))
+            }
+            (
+                Some(ManifestEntryKind::File {
+                    blob: o, mode: m, ..
+                }),
+                Some(ManifestEntryKind::File { blob: t, .. }),
+            ) => {
+                let b = match base {
+                    Some(ManifestEntryKind::File { blob, .. }) => Some(blob),
+                    _ => None,
+                };
+                self.merge_file(path, b, o, t, *m, ours)
+            }
+            _ => {
+                self.conflicts.push(FileMergeConflict {
+                    path: path.to_string(),
+                    driver: None,
+                    key_paths: Vec::new(),
+                });
+                Ok(ours.cloned())
+            }
+        }
+    }
+
+    fn merge_file(
+        &mut self,
+        path: &str,
+        base: Option<&ObjectId>,
+        ours: &ObjectId,
+        theirs: &ObjectId,
+        mode: u32,
+        ours_kind: Option<&ManifestEntryKind>,
+    ) -> Result<Option<ManifestEntryKind>> {
+        let Some(driver) = self.registry.driver_for(path) else {
+            self.conflicts.push(FileMergeConflict {
+                path: path.to_string(),
+                driver: None,
+                key_paths: Vec::new(),
+            });
+            return Ok(ours_kind.cloned());
+        };
+
+        let base_bytes = base.map(|b| self.store.get_blob(b)).transpose()?;
+        let ours_bytes = self.store.get_blob(ours)?;
+        let theirs_bytes = self.store.get_blob(theirs)?;
+        let outcome = driver.merge(
+            base_bytes.as_deref(),
+            &[ours_bytes.as_slice(), theirs_bytes.as_slice()],
+        );
+
+        match outcome {
+            Ok(merge) => match merge.merged {
+                Some(bytes) => {
+                    let blob = self.store.put_blob(&bytes)?;
+                    self.merged_paths.push(path.to_string());
+                    Ok(Some(ManifestEntryKind::File {
+                        blob,
+                        mode,
+                        size: bytes.len() as u64,
+                    }))
+                }
+                None => {
+                    self.conflicts.push(FileMergeConflict {
+                        path: path.to_string(),
+                        driver: Some(driver.name()),
+                        key_paths: merge.conflicts.into_iter().map(|c| c.path).collect(),
+                    });
+                    Ok(ours_kind.cloned())
+                }
+            },
+            // Unparseable documents fall back to a whole-file conflict.
+            Err(_) => {
+                self.conflicts.push(FileMergeConflict {
+                    path: path.to_string(),
+                    driver: Some(driver.name()),
+                    key_paths: Vec::new(),
+                });
+                Ok(ours_kind.cloned())
+            }
+        }
+    }
+}
+
+fn entries_by_name(manifest: Manifest) -> BTreeMap<String, ManifestEntryKind> {
+    manifest
+        .entries
+        .into_iter()
+        .map(|e| (e.name, e.kind))
+        .collect()
+}
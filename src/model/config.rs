@@ -114,6 +114,14 @@ pub struct WorkspaceState {
     /// Tracks the last snap published for a given remote+scope+gate.
     #[serde(default)]
     pub last_published: std::collections::HashMap<String, String>,
+
+    /// Tracks the upstream root manifest last merged by `update` for a remote+scope+gate.
+    #[serde(default)]
+    pub last_updated: std::collections::HashMap<String, String>,
+
+    /// Path to the ed25519 key used to sign snaps, publications, approvals and releases.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signing_key: Option<String>,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
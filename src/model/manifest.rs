@@ -47,6 +47,40 @@ pub struct SuperpositionVariant {
 
     #[serde(flatten)]
     pub kind: SuperpositionVariantKind,
+
+    /// Context about the variant; never part of its key.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<VariantMetadata>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VariantMetadata {
+    /// Handle of the user who published the variant's source.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub publisher: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub publisher_user_id: Option<String>,
+
+    /// When the source publication was created (RFC3339).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub published_at: Option<String>,
+
+    /// Message of the snap that was published.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub snap_message: Option<String>,
+
+    /// Lane the source publication came from.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lane: Option<String>,
+
+    /// Structured merge driver that attempted to merge the path.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub merge_driver: Option<String>,
+
+    /// Key paths this variant changed that conflict with other variants.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub conflicting_keys: Vec<String>,
 }
 
 impl SuperpositionVariant {
@@ -1,7 +1,9 @@
 mod config;
 mod ids;
 mod manifest;
+mod release;
 mod resolution;
+mod rules;
 mod snap;
 
 pub use self::config::{
@@ -11,6 +13,15 @@ pub use self::config::{
 pub use self::ids::ObjectId;
 pub use self::manifest::{
     Manifest, ManifestEntry, ManifestEntryKind, SuperpositionVariant, SuperpositionVariantKind,
+    VariantMetadata,
+};
+pub use self::release::validate_release_channel;
+pub use self::resolution::{
+    MANUAL_MERGE_SOURCE, Resolution, ResolutionDecision, VariantKey, VariantKeyKind,
+};
+pub use self::rules::{
+    MergeDriverRule, RESOLUTION_RULES_FILE, ResolutionRule, ResolutionRules, ResolutionStrategy,
+};
+pub use self::snap::{
+    FileRecipe, FileRecipeChunk, SnapRecord, SnapStats, compute_lineage_snap_id, compute_snap_id,
 };
-pub use self::resolution::{Resolution, ResolutionDecision, VariantKey, VariantKeyKind};
-pub use self::snap::{FileRecipe, FileRecipeChunk, SnapRecord, SnapStats, compute_snap_id};
@@ -0,0 +1,15 @@
+use anyhow::Result;
+
+/// Release channels are non-empty lowercase alnum or '-'; the server and client share this check.
+pub fn validate_release_channel(channel: &str) -> Result<()> {
+    if channel.is_empty() {
+        anyhow::bail!("release channel cannot be empty");
+    }
+    if !channel
+        .chars()
+        .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+    {
+        anyhow::bail!("release channel must be lowercase alnum or '-'");
+    }
+    Ok(())
+}
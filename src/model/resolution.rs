@@ -10,6 +10,23 @@ pub struct VariantKey {
     pub kind: VariantKeyKind,
 }
 
+/// `source` of keys that carry hand-merged content instead of naming a variant.
+pub const MANUAL_MERGE_SOURCE: &str = "manual";
+
+impl VariantKey {
+    /// Key for merged file content written to the store by a manual merge.
+    pub fn manual(blob: ObjectId, mode: u32, size: u64) -> Self {
+        VariantKey {
+            source: MANUAL_MERGE_SOURCE.to_string(),
+            kind: VariantKeyKind::File { blob, mode, size },
+        }
+    }
+
+    pub fn is_manual(&self) -> bool {
+        self.source == MANUAL_MERGE_SOURCE && matches!(self.kind, VariantKeyKind::File { .. })
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(tag = "type")]
 pub enum VariantKeyKind {
@@ -37,7 +54,8 @@ pub enum VariantKeyKind {
 pub enum ResolutionDecision {
     /// Legacy decision: 0-based variant index.
     Index(u32),
-    /// Stable decision: a key derived from variant content.
+    /// Stable decision: a key derived from variant content, or a manual merge
+    /// (see [`VariantKey::manual`]).
     Key(VariantKey),
 }
 
@@ -50,4 +68,12 @@ pub struct Resolution {
 
     /// Path -> selected decision (v1 index or v2 key)
     pub decisions: std::collections::BTreeMap<String, ResolutionDecision>,
+
+    /// Path -> name of the resolution rule that chose the decision
+    #[serde(default, skip_serializing_if = "std::collections::BTreeMap::is_empty")]
+    pub rules: std::collections::BTreeMap<String, String>,
+
+    /// Path -> rationale given for a manual merge
+    #[serde(default, skip_serializing_if = "std::collections::BTreeMap::is_empty")]
+    pub rationales: std::collections::BTreeMap<String, String>,
 }
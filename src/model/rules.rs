@@ -0,0 +1,60 @@
+use serde::{Deserialize, Serialize};
+
+/// In-tree rules file (at the repository root) consulted when resolving superpositions.
+pub const RESOLUTION_RULES_FILE: &str = "converge-rules.json";
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct ResolutionRules {
+    pub version: u32,
+
+    #[serde(default)]
+    pub rules: Vec<ResolutionRule>,
+
+    /// Structured merge driver overrides, consulted before file extensions.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub drivers: Vec<MergeDriverRule>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MergeDriverRule {
+    /// Glob matched against the full path (e.g. `config/*.conf`).
+    pub path: String,
+
+    /// `json`, `toml`, `yaml`, or `none` to disable structured merging.
+    pub driver: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ResolutionRule {
+    pub name: String,
+
+    /// Glob matched against the full superposition path (e.g. `assets/**`).
+    pub path: String,
+
+    #[serde(flatten)]
+    pub strategy: ResolutionStrategy,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "strategy", rename_all = "kebab-case")]
+pub enum ResolutionStrategy {
+    /// Take the variant published from the named lane.
+    PreferSourceLane { lane: String },
+    /// Take the variant from the most recently created publication.
+    PreferNewest,
+    /// Concatenate the distinct lines of every file variant (applied during bundling).
+    UnionLines,
+    /// Take the deletion when any input removed the path.
+    TakeTombstone,
+}
+
+impl ResolutionStrategy {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ResolutionStrategy::PreferSourceLane { .. } => "prefer-source-lane",
+            ResolutionStrategy::PreferNewest => "prefer-newest",
+            ResolutionStrategy::UnionLines => "union-lines",
+            ResolutionStrategy::TakeTombstone => "take-tombstone",
+        }
+    }
+}
@@ -18,6 +18,14 @@ pub struct SnapRecord {
     pub root_manifest: ObjectId,
     pub message: Option<String>,
     pub stats: SnapStats,
+
+    /// Parent snaps, recorded for history imported from other systems.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub parents: Vec<String>,
+
+    /// Original author (`Name <email>`) when it differs from the local user.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub author: Option<String>,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
@@ -40,3 +48,46 @@ pub fn compute_snap_id(created_at: &str, root_manifest: &ObjectId) -> String {
     hasher.update(root_manifest.as_str().as_bytes());
     hasher.finalize().to_hex().to_string()
 }
+
+/// Id for a snap whose time and tree alone need not be unique, such as imported history where
+/// several commits can share a tree within the same second: also covers parents, message and
+/// author.
+pub fn compute_lineage_snap_id(
+    created_at: &str,
+    root_manifest: &ObjectId,
+    parents: &[String],
+    message: Option<&str>,
+    author: Option<&str>,
+) -> String {
+    let mut hasher = blake3::Hasher::new();
+    hasher.update(b"lineage\n");
+    hasher.update(created_at.as_bytes());
+    hasher.update(b"\n");
+    hasher.update(root_manifest.as_str().as_bytes());
+    for parent in parents {
+        hasher.update(b"\nparent ");
+        hasher.update(parent.as_bytes());
+    }
+    for (tag, value) in [("author", author), ("message", message)] {
+        if let Some(value) = value {
+            hasher.update(format!("\n{} {}\n", tag, value.len()).as_bytes());
+            hasher.update(value.as_bytes());
+        }
+    }
+    hasher.finalize().to_hex().to_string()
+}
+
+impl SnapRecord {
+    /// Whether `id` matches the snap's content under either id scheme.
+    pub fn id_matches_content(&self) -> bool {
+        self.id == compute_snap_id(&self.created_at, &self.root_manifest)
+            || self.id
+                == compute_lineage_snap_id(
+                    &self.created_at,
+                    &self.root_manifest,
+                    &self.parents,
+                    self.message.as_deref(),
+                    self.author.as_deref(),
+                )
+    }
+}
@@ -1,7 +1,10 @@
 use anyhow::{Context, Result};
 
 use crate::model::RemoteConfig;
+use crate::signing::SigningKey;
 
+mod error;
+pub use self::error::NotFound;
 mod http_client;
 use self::http_client::with_retries;
 
@@ -16,6 +19,7 @@ pub struct RemoteClient {
     remote: RemoteConfig,
     token: String,
     client: reqwest::blocking::Client,
+    signer: Option<SigningKey>,
 }
 
 impl RemoteClient {
@@ -28,9 +32,16 @@ impl RemoteClient {
             remote,
             token,
             client,
+            signer: None,
         })
     }
 
+    /// Signs publications, approvals and releases created through this client.
+    pub fn with_signer(mut self, signer: Option<SigningKey>) -> Self {
+        self.signer = signer;
+        self
+    }
+
     pub fn remote(&self) -> &RemoteConfig {
         &self.remote
     }
@@ -0,0 +1,15 @@
+//! Typed errors callers can match on instead of message text.
+
+/// The server answered 404 for the requested resource (`.0` names it, e.g. "bundle").
+///
+/// Returned inside `anyhow::Error`; check with `err.downcast_ref::<NotFound>()`.
+#[derive(Debug)]
+pub struct NotFound(pub &'static str);
+
+impl std::fmt::Display for NotFound {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} not found", self.0)
+    }
+}
+
+impl std::error::Error for NotFound {}
@@ -19,3 +19,29 @@ pub(super) fn fetch_manifest_tree(
     let mut visited = HashSet::new();
     traversal::fetch_manifest_tree_inner(store, remote, repo, root, &mut visited)
 }
+
+/// Fetches one manifest (not its children) if it is not already stored.
+pub(super) fn fetch_manifest(
+    store: &LocalStore,
+    remote: &RemoteClient,
+    repo: &str,
+    manifest_id: &ObjectId,
+) -> Result<()> {
+    object_fetch::fetch_manifest_if_missing(store, remote, repo, manifest_id)
+}
+
+pub(super) fn fetch_file_objects(
+    store: &LocalStore,
+    remote: &RemoteClient,
+    repo: &str,
+    blob: Option<&ObjectId>,
+    recipe: Option<&ObjectId>,
+) -> Result<()> {
+    if let Some(blob) = blob {
+        object_fetch::fetch_blob_if_missing(store, remote, repo, blob)?;
+    }
+    if let Some(recipe) = recipe {
+        object_fetch::fetch_recipe_and_chunks(store, remote, repo, recipe)?;
+    }
+    Ok(())
+}
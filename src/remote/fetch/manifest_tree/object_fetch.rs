@@ -1,5 +1,33 @@
 use super::*;
 
+pub(super) fn fetch_manifest_if_missing(
+    store: &LocalStore,
+    remote: &RemoteClient,
+    repo: &str,
+    manifest_id: &ObjectId,
+) -> Result<()> {
+    if store.has_manifest(manifest_id) {
+        return Ok(());
+    }
+    let resp = remote
+        .client
+        .get(remote.url(&format!(
+            "/repos/{}/objects/manifests/{}",
+            repo,
+            manifest_id.as_str()
+        )))
+        .header(reqwest::header::AUTHORIZATION, remote.auth())
+        .send()
+        .context("fetch manifest")?;
+    let bytes = remote
+        .ensure_ok(resp, "fetch manifest")?
+        .bytes()
+        .context("read manifest bytes")?;
+
+    store.put_manifest_bytes(manifest_id, &bytes)?;
+    Ok(())
+}
+
 pub(super) fn fetch_blob_if_missing(
     store: &LocalStore,
     remote: &RemoteClient,
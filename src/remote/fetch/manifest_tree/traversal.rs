@@ -1,4 +1,6 @@
-use super::object_fetch::{fetch_blob_if_missing, fetch_recipe_and_chunks};
+use super::object_fetch::{
+    fetch_blob_if_missing, fetch_manifest_if_missing, fetch_recipe_and_chunks,
+};
 use super::*;
 
 pub(super) fn fetch_manifest_tree_inner(
@@ -12,24 +14,7 @@ pub(super) fn fetch_manifest_tree_inner(
         return Ok(());
     }
 
-    if !store.has_manifest(manifest_id) {
-        let resp = remote
-            .client
-            .get(remote.url(&format!(
-                "/repos/{}/objects/manifests/{}",
-                repo,
-                manifest_id.as_str()
-            )))
-            .header(reqwest::header::AUTHORIZATION, remote.auth())
-            .send()
-            .context("fetch manifest")?;
-        let bytes = remote
-            .ensure_ok(resp, "fetch manifest")?
-            .bytes()
-            .context("read manifest bytes")?;
-
-        store.put_manifest_bytes(manifest_id, &bytes)?;
-    }
+    fetch_manifest_if_missing(store, remote, repo, manifest_id)?;
 
     let manifest = store.get_manifest(manifest_id)?;
     for e in manifest.entries {
@@ -5,7 +5,9 @@ use anyhow::{Context, Result};
 use crate::model::{ObjectId, SnapRecord};
 use crate::store::LocalStore;
 
-use super::{RemoteClient, with_retries};
+use crate::workspace::StoredFile;
+
+use super::{NotFound, ReleaseAttachment, RemoteClient, with_retries};
 
 mod manifest_tree;
 mod object_graph;
@@ -40,6 +42,45 @@ impl RemoteClient {
         manifest_tree::fetch_manifest_tree(store, self, repo, root_manifest)
     }
 
+    /// Fetches a single manifest (without descending) so trees can be browsed lazily.
+    pub fn fetch_manifest(&self, store: &LocalStore, manifest_id: &ObjectId) -> Result<()> {
+        let repo = &self.remote.repo_id;
+        manifest_tree::fetch_manifest(store, self, repo, manifest_id)
+    }
+
+    /// Fetches a file's blob, or its recipe and chunks, if missing locally.
+    pub fn fetch_file_objects(
+        &self,
+        store: &LocalStore,
+        blob: Option<&ObjectId>,
+        recipe: Option<&ObjectId>,
+    ) -> Result<()> {
+        let repo = &self.remote.repo_id;
+        manifest_tree::fetch_file_objects(store, self, repo, blob, recipe)
+    }
+
+    /// Downloads a release attachment's blob or recipe + chunks into the local store.
+    pub fn fetch_release_attachment(
+        &self,
+        store: &LocalStore,
+        attachment: &ReleaseAttachment,
+    ) -> Result<StoredFile> {
+        let file = StoredFile {
+            size: attachment.size,
+            blob: attachment.blob.clone().map(ObjectId),
+            recipe: attachment.recipe.clone().map(ObjectId),
+        };
+        let repo = &self.remote.repo_id;
+        manifest_tree::fetch_file_objects(
+            store,
+            self,
+            repo,
+            file.blob.as_ref(),
+            file.recipe.as_ref(),
+        )?;
+        Ok(file)
+    }
+
     pub fn fetch_lane_heads(
         &self,
         store: &LocalStore,
@@ -68,6 +109,23 @@ impl RemoteClient {
         Ok(fetched)
     }
 
+    /// Reads a snap record from the remote without storing it.
+    pub fn get_snap(&self, snap_id: &str) -> Result<SnapRecord> {
+        let repo = &self.remote.repo_id;
+        let resp = self
+            .client
+            .get(self.url(&format!("/repos/{}/objects/snaps/{}", repo, snap_id)))
+            .header(reqwest::header::AUTHORIZATION, self.auth())
+            .send()
+            .context("get snap")?;
+        if resp.status() == reqwest::StatusCode::NOT_FOUND {
+            anyhow::bail!(NotFound("snap"));
+        }
+        self.ensure_ok(resp, "get snap")?
+            .json()
+            .context("parse snap")
+    }
+
     fn fetch_snap_by_id(
         &self,
         store: &LocalStore,
@@ -68,7 +68,7 @@ impl RemoteClient {
             .context("get repo")?;
 
         if resp.status() == reqwest::StatusCode::NOT_FOUND {
-            anyhow::bail!("remote repo not found");
+            anyhow::bail!(NotFound("remote repo"));
         }
 
         let repo: Repo = self
@@ -0,0 +1,109 @@
+use super::*;
+
+impl RemoteClient {
+    pub fn list_groups(&self) -> Result<Vec<RemoteGroup>> {
+        let resp = self
+            .client
+            .get(self.url("/groups"))
+            .header(reqwest::header::AUTHORIZATION, self.auth())
+            .send()
+            .context("list groups")?;
+        let out: Vec<RemoteGroup> = self
+            .ensure_ok(resp, "list groups")?
+            .json()
+            .context("parse groups")?;
+        Ok(out)
+    }
+
+    pub fn get_group(&self, name: &str) -> Result<RemoteGroup> {
+        let resp = self
+            .client
+            .get(self.url(&format!("/groups/{}", name)))
+            .header(reqwest::header::AUTHORIZATION, self.auth())
+            .send()
+            .context("get group")?;
+        if resp.status() == reqwest::StatusCode::NOT_FOUND {
+            anyhow::bail!("unknown group: {}", name);
+        }
+        let out: RemoteGroup = self
+            .ensure_ok(resp, "get group")?
+            .json()
+            .context("parse group")?;
+        Ok(out)
+    }
+
+    pub fn create_group(&self, name: &str, description: Option<String>) -> Result<RemoteGroup> {
+        let resp = self
+            .client
+            .post(self.url("/groups"))
+            .header(reqwest::header::AUTHORIZATION, self.auth())
+            .json(&serde_json::json!({
+                "name": name,
+                "description": description,
+            }))
+            .send()
+            .context("create group")?;
+        let out: RemoteGroup = self
+            .ensure_ok(resp, "create group")?
+            .json()
+            .context("parse create group")?;
+        Ok(out)
+    }
+
+    pub fn delete_group(&self, name: &str) -> Result<()> {
+        let resp = self
+            .client
+            .delete(self.url(&format!("/groups/{}", name)))
+            .header(reqwest::header::AUTHORIZATION, self.auth())
+            .send()
+            .context("delete group")?;
+        let _ = self.ensure_ok(resp, "delete group")?;
+        Ok(())
+    }
+
+    /// Adds a user handle, or a nested group as `@<name>`.
+    pub fn add_group_member(&self, name: &str, handle: &str) -> Result<RemoteGroup> {
+        let resp = self
+            .client
+            .post(self.url(&format!("/groups/{}/members", name)))
+            .header(reqwest::header::AUTHORIZATION, self.auth())
+            .json(&serde_json::json!({"handle": handle}))
+            .send()
+            .context("add group member")?;
+        let out: RemoteGroup = self
+            .ensure_ok(resp, "add group member")?
+            .json()
+            .context("parse group")?;
+        Ok(out)
+    }
+
+    pub fn remove_group_member(&self, name: &str, handle: &str) -> Result<RemoteGroup> {
+        let resp = self
+            .client
+            .delete(self.url(&format!("/groups/{}/members/{}", name, handle)))
+            .header(reqwest::header::AUTHORIZATION, self.auth())
+            .send()
+            .context("remove group member")?;
+        let out: RemoteGroup = self
+            .ensure_ok(resp, "remove group member")?
+            .json()
+            .context("parse group")?;
+        Ok(out)
+    }
+
+    /// The newest `limit` identity and membership audit entries (admin).
+    pub fn list_audit(&self, limit: usize) -> Result<Vec<AuditEntry>> {
+        let resp = self
+            .client
+            .get(self.url("/audit"))
+            .query(&[("limit", limit)])
+            .header(reqwest::header::AUTHORIZATION, self.auth())
+            .send()
+            .context("list audit")?;
+        let out: Vec<AuditEntry> = self
+            .ensure_ok(resp, "list audit")?
+            .json()
+            .context("parse audit")?;
+        Ok(out)
+    }
+}
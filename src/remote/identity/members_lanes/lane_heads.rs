@@ -40,7 +40,7 @@ impl RemoteClient {
             .context("get lane head")?;
 
         if resp.status() == reqwest::StatusCode::NOT_FOUND {
-            anyhow::bail!("lane head not found");
+            anyhow::bail!(NotFound("lane head"));
         }
 
         let head: LaneHead = self
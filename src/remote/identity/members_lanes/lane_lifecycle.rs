@@ -0,0 +1,83 @@
+use super::*;
+
+impl RemoteClient {
+    pub fn create_lane(
+        &self,
+        lane_id: &str,
+        description: Option<&str>,
+        gates: &[String],
+        default_scope: Option<&str>,
+    ) -> Result<Lane> {
+        let repo = &self.remote.repo_id;
+        let resp = self
+            .client
+            .post(self.url(&format!("/repos/{}/lanes", repo)))
+            .header(reqwest::header::AUTHORIZATION, self.auth())
+            .json(&serde_json::json!({
+                "id": lane_id,
+                "description": description,
+                "gates": gates,
+                "default_scope": default_scope,
+            }))
+            .send()
+            .context("create lane")?;
+        if resp.status() == reqwest::StatusCode::CONFLICT {
+            anyhow::bail!("lane {} already exists", lane_id);
+        }
+        let lane: Lane = self
+            .ensure_ok(resp, "create lane")?
+            .json()
+            .context("parse lane")?;
+        Ok(lane)
+    }
+
+    pub fn update_lane(&self, lane_id: &str, update: &LaneUpdate) -> Result<Lane> {
+        let repo = &self.remote.repo_id;
+        let resp = self
+            .client
+            .patch(self.url(&format!("/repos/{}/lanes/{}", repo, lane_id)))
+            .header(reqwest::header::AUTHORIZATION, self.auth())
+            .json(update)
+            .send()
+            .context("update lane")?;
+        if resp.status() == reqwest::StatusCode::NOT_FOUND {
+            anyhow::bail!(NotFound("remote lane"));
+        }
+        if resp.status() == reqwest::StatusCode::CONFLICT {
+            anyhow::bail!(
+                "lane {} already exists",
+                update.id.as_deref().unwrap_or(lane_id)
+            );
+        }
+        let lane: Lane = self
+            .ensure_ok(resp, "update lane")?
+            .json()
+            .context("parse lane")?;
+        Ok(lane)
+    }
+
+    /// Deletes a lane. The server refuses while the lane still has heads.
+    pub fn delete_lane(&self, lane_id: &str) -> Result<()> {
+        let repo = &self.remote.repo_id;
+        let resp = self
+            .client
+            .delete(self.url(&format!("/repos/{}/lanes/{}", repo, lane_id)))
+            .header(reqwest::header::AUTHORIZATION, self.auth())
+            .send()
+            .context("delete lane")?;
+        if resp.status() == reqwest::StatusCode::NOT_FOUND {
+            anyhow::bail!(NotFound("remote lane"));
+        }
+        if resp.status() == reqwest::StatusCode::CONFLICT {
+            // Heads or gate ownership; the server says which.
+            let body: serde_json::Value = resp.json().unwrap_or_default();
+            anyhow::bail!(
+                "cannot delete lane {}: {}",
+                lane_id,
+                body["error"].as_str().unwrap_or("conflict")
+            );
+        }
+        let _ = self.ensure_ok(resp, "delete lane")?;
+        Ok(())
+    }
+}
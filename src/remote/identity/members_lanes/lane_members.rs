@@ -11,7 +11,7 @@ impl RemoteClient {
             .context("list lane members")?;
 
         if resp.status() == reqwest::StatusCode::NOT_FOUND {
-            anyhow::bail!("remote lane not found");
+            anyhow::bail!(NotFound("remote lane"));
         }
 
         let out: LaneMembers = self
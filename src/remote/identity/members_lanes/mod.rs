@@ -1,5 +1,7 @@
 use super::*;
 
 mod lane_heads;
+mod lane_lifecycle;
 mod lane_members;
 mod repo_members;
+mod subscriptions;
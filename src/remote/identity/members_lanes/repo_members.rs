@@ -11,7 +11,7 @@ impl RemoteClient {
             .context("list repo members")?;
 
         if resp.status() == reqwest::StatusCode::NOT_FOUND {
-            anyhow::bail!("remote repo not found");
+            anyhow::bail!(NotFound("remote repo"));
         }
 
         let out: RepoMembers = self
@@ -21,13 +21,25 @@ impl RemoteClient {
         Ok(out)
     }
 
-    pub fn add_repo_member(&self, handle: &str, role: &str) -> Result<()> {
+    /// Grants `role` to `handle`, optionally narrowed to one gate or lane.
+    pub fn add_repo_member(
+        &self,
+        handle: &str,
+        role: &str,
+        gate: Option<&str>,
+        lane: Option<&str>,
+    ) -> Result<()> {
         let repo = &self.remote.repo_id;
         let resp = self
             .client
             .post(self.url(&format!("/repos/{}/members", repo)))
             .header(reqwest::header::AUTHORIZATION, self.auth())
-            .json(&serde_json::json!({"handle": handle, "role": role}))
+            .json(&serde_json::json!({
+                "handle": handle,
+                "role": role,
+                "gate": gate,
+                "lane": lane
+            }))
             .send()
             .context("add repo member")?;
 
@@ -46,4 +58,27 @@ impl RemoteClient {
         let _ = self.ensure_ok(resp, "remove repo member")?;
         Ok(())
     }
+
+    /// Removes a single role grant, leaving the member's other roles in place.
+    pub fn remove_repo_role(
+        &self,
+        handle: &str,
+        role: &str,
+        gate: Option<&str>,
+        lane: Option<&str>,
+    ) -> Result<()> {
+        let repo = &self.remote.repo_id;
+        let mut query = vec![("role", role)];
+        query.extend(gate.map(|g| ("gate", g)));
+        query.extend(lane.map(|l| ("lane", l)));
+        let resp = self
+            .client
+            .delete(self.url(&format!("/repos/{}/members/{}", repo, handle)))
+            .query(&query)
+            .header(reqwest::header::AUTHORIZATION, self.auth())
+            .send()
+            .context("remove repo role")?;
+        let _ = self.ensure_ok(resp, "remove repo role")?;
+        Ok(())
+    }
 }
@@ -0,0 +1,74 @@
+use super::*;
+
+impl RemoteClient {
+    /// Active lane subscriptions: your own, or everyone's for repo owners and maintainers.
+    pub fn list_subscriptions(&self) -> Result<Vec<LaneSubscription>> {
+        let repo = &self.remote.repo_id;
+        let resp = self
+            .client
+            .get(self.url(&format!("/repos/{}/subscriptions", repo)))
+            .header(reqwest::header::AUTHORIZATION, self.auth())
+            .send()
+            .context("list subscriptions")?;
+        let out: Vec<LaneSubscription> = self
+            .ensure_ok(resp, "list subscriptions")?
+            .json()
+            .context("parse subscriptions")?;
+        Ok(out)
+    }
+
+    /// Subscribes to a lane (or subscribes `user`, for owners and maintainers).
+    /// `until` is an RFC3339 timestamp or `YYYY-MM-DD`.
+    pub fn subscribe_lane(
+        &self,
+        lane_id: &str,
+        scope: Option<&str>,
+        until: Option<&str>,
+        user: Option<&str>,
+    ) -> Result<LaneSubscription> {
+        let repo = &self.remote.repo_id;
+        let resp = self
+            .client
+            .post(self.url(&format!("/repos/{}/lanes/{}/subscriptions", repo, lane_id)))
+            .header(reqwest::header::AUTHORIZATION, self.auth())
+            .json(&serde_json::json!({"scope": scope, "until": until, "user": user}))
+            .send()
+            .context("subscribe lane")?;
+        if resp.status() == reqwest::StatusCode::NOT_FOUND {
+            anyhow::bail!(NotFound("remote lane"));
+        }
+        let out: LaneSubscription = self
+            .ensure_ok(resp, "subscribe lane")?
+            .json()
+            .context("parse subscription")?;
+        Ok(out)
+    }
+
+    pub fn unsubscribe_lane(
+        &self,
+        lane_id: &str,
+        scope: Option<&str>,
+        user: Option<&str>,
+    ) -> Result<()> {
+        let repo = &self.remote.repo_id;
+        let mut query = Vec::new();
+        if let Some(scope) = scope {
+            query.push(("scope", scope));
+        }
+        if let Some(user) = user {
+            query.push(("user", user));
+        }
+        let resp = self
+            .client
+            .delete(self.url(&format!("/repos/{}/lanes/{}/subscriptions", repo, lane_id)))
+            .query(&query)
+            .header(reqwest::header::AUTHORIZATION, self.auth())
+            .send()
+            .context("unsubscribe lane")?;
+        if resp.status() == reqwest::StatusCode::NOT_FOUND {
+            anyhow::bail!("no matching subscription");
+        }
+        let _ = self.ensure_ok(resp, "unsubscribe lane")?;
+        Ok(())
+    }
+}
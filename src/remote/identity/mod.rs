@@ -3,10 +3,13 @@
 use anyhow::{Context, Result};
 
 use super::{
-    BootstrapResponse, CreateTokenResponse, Lane, LaneHead, LaneMembers, RemoteClient, RemoteUser,
-    Repo, RepoMembers, TokenView, UpdateLaneHeadRequest, WhoAmI,
+    AuditEntry, BootstrapResponse, CreateTokenRequest, CreateTokenResponse, Lane, LaneHead,
+    LaneMembers, LaneSubscription, LaneUpdate, NotFound, RemoteClient, RemoteGroup, RemoteUser,
+    Repo, RepoMembers, SigningKeyView, TokenView, UpdateLaneHeadRequest, WhoAmI,
 };
 
 mod auth_session;
+mod groups;
 mod members_lanes;
+mod signing_keys;
 mod users_tokens;
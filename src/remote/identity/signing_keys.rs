@@ -0,0 +1,64 @@
+use super::*;
+
+impl RemoteClient {
+    /// Lists registered public keys, for every user or only `user`.
+    pub fn list_signing_keys(&self, user: Option<&str>) -> Result<Vec<SigningKeyView>> {
+        let mut req = self
+            .client
+            .get(self.url("/keys"))
+            .header(reqwest::header::AUTHORIZATION, self.auth());
+        if let Some(user) = user {
+            req = req.query(&[("user", user)]);
+        }
+        let resp = req.send().context("list signing keys")?;
+        let out: Vec<SigningKeyView> = self
+            .ensure_ok(resp, "list signing keys")?
+            .json()
+            .context("parse signing keys")?;
+        Ok(out)
+    }
+
+    /// Registers `key` for the calling user, proving possession by signing the registration.
+    pub fn register_signing_key(
+        &self,
+        key: &crate::signing::SigningKey,
+        label: Option<String>,
+    ) -> Result<SigningKeyView> {
+        let user_id = self.whoami()?.user_id;
+        let public_key = key.public_key_hex();
+        let proof = key.sign(&crate::signing::key_registration_payload(
+            &user_id,
+            &public_key,
+        ));
+        let resp = self
+            .client
+            .post(self.url("/keys"))
+            .header(reqwest::header::AUTHORIZATION, self.auth())
+            .json(&serde_json::json!({
+                "public_key": public_key,
+                "proof": proof.signature,
+                "label": label,
+            }))
+            .send()
+            .context("register signing key")?;
+        let out: SigningKeyView = self
+            .ensure_ok(resp, "register signing key")?
+            .json()
+            .context("parse signing key")?;
+        Ok(out)
+    }
+
+    pub fn revoke_signing_key(&self, key_id: &str) -> Result<SigningKeyView> {
+        let resp = self
+            .client
+            .post(self.url(&format!("/keys/{}/revoke", key_id)))
+            .header(reqwest::header::AUTHORIZATION, self.auth())
+            .send()
+            .context("revoke signing key")?;
+        let out: SigningKeyView = self
+            .ensure_ok(resp, "revoke signing key")?
+            .json()
+            .context("parse signing key")?;
+        Ok(out)
+    }
+}
@@ -42,13 +42,13 @@ impl RemoteClient {
     pub fn create_token_for_user(
         &self,
         user_id: &str,
-        label: Option<String>,
+        request: &CreateTokenRequest,
     ) -> Result<CreateTokenResponse> {
         let resp = self
             .client
             .post(self.url(&format!("/users/{}/tokens", user_id)))
             .header(reqwest::header::AUTHORIZATION, self.auth())
-            .json(&serde_json::json!({"label": label}))
+            .json(request)
             .send()
             .context("create token for user")?;
         let out: CreateTokenResponse = self
@@ -72,12 +72,12 @@ impl RemoteClient {
         Ok(out)
     }
 
-    pub fn create_token(&self, label: Option<String>) -> Result<CreateTokenResponse> {
+    pub fn create_token(&self, request: &CreateTokenRequest) -> Result<CreateTokenResponse> {
         let resp = self
             .client
             .post(self.url("/tokens"))
             .header(reqwest::header::AUTHORIZATION, self.auth())
-            .json(&serde_json::json!({"label": label}))
+            .json(request)
             .send()
             .context("create token")?;
         let out: CreateTokenResponse = self
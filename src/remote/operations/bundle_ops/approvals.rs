@@ -3,16 +3,51 @@ use super::*;
 impl RemoteClient {
     pub fn approve_bundle(&self, bundle_id: &str) -> Result<Bundle> {
         let repo = &self.remote.repo_id;
-        let resp = self
+        let mut req = self
             .client
             .post(self.url(&format!("/repos/{}/bundles/{}/approve", repo, bundle_id)))
-            .header(reqwest::header::AUTHORIZATION, self.auth())
-            .send()
-            .context("approve request")?;
+            .header(reqwest::header::AUTHORIZATION, self.auth());
+        if let Some(key) = self.signer.as_ref() {
+            // The approval covers the bundle's content, not just its id.
+            let bundle = self.get_bundle(bundle_id)?;
+            let payload = crate::signing::approval_payload(repo, &bundle.id, &bundle.root_manifest);
+            req = req.json(&serde_json::json!({ "signature": key.sign(&payload) }));
+        }
+        let resp = req.send().context("approve request")?;
 
         let resp = self.ensure_ok(resp, "approve")?;
 
         let bundle: Bundle = resp.json().context("parse approved bundle")?;
         Ok(bundle)
     }
+
+    /// Reports a check result (`pending`, `success` or `failure`) on a bundle; a later report with
+    /// the same name replaces it.
+    pub fn report_check(
+        &self,
+        bundle_id: &str,
+        name: &str,
+        status: &str,
+        summary: Option<String>,
+        url: Option<String>,
+    ) -> Result<Bundle> {
+        let repo = &self.remote.repo_id;
+        let resp = self
+            .client
+            .post(self.url(&format!("/repos/{}/bundles/{}/checks", repo, bundle_id)))
+            .header(reqwest::header::AUTHORIZATION, self.auth())
+            .json(&serde_json::json!({
+                "name": name,
+                "status": status,
+                "summary": summary,
+                "url": url,
+            }))
+            .send()
+            .context("report check")?;
+        let bundle: Bundle = self
+            .ensure_ok(resp, "report check")?
+            .json()
+            .context("parse bundle")?;
+        Ok(bundle)
+    }
 }
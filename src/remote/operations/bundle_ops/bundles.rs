@@ -56,7 +56,7 @@ impl RemoteClient {
             .context("get bundle")?;
 
         if resp.status() == reqwest::StatusCode::NOT_FOUND {
-            anyhow::bail!("bundle not found");
+            anyhow::bail!(NotFound("bundle"));
         }
 
         let bundle: Bundle = self
@@ -3,3 +3,4 @@ use super::*;
 mod approvals;
 mod bundles;
 mod pins;
+mod resolutions;
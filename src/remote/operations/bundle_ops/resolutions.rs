@@ -0,0 +1,101 @@
+use super::*;
+
+impl RemoteClient {
+    pub fn get_bundle_resolutions(&self, bundle_id: &str) -> Result<BundleResolutions> {
+        let repo = &self.remote.repo_id;
+        let resp = self
+            .client
+            .get(self.url(&format!(
+                "/repos/{}/bundles/{}/resolutions",
+                repo, bundle_id
+            )))
+            .header(reqwest::header::AUTHORIZATION, self.auth())
+            .send()
+            .context("get bundle resolutions")?;
+
+        if resp.status() == reqwest::StatusCode::NOT_FOUND {
+            anyhow::bail!(NotFound("bundle"));
+        }
+
+        let out: BundleResolutions = self
+            .ensure_ok(resp, "get bundle resolutions")?
+            .json()
+            .context("parse bundle resolutions")?;
+        Ok(out)
+    }
+
+    /// Creation time and owning lanes of each input publication, as the server attributes
+    /// them when applying resolution rules.
+    pub fn get_bundle_origins(
+        &self,
+        bundle_id: &str,
+    ) -> Result<std::collections::BTreeMap<String, crate::resolve::VariantOrigin>> {
+        let repo = &self.remote.repo_id;
+        let resp = self
+            .client
+            .get(self.url(&format!("/repos/{}/bundles/{}/origins", repo, bundle_id)))
+            .header(reqwest::header::AUTHORIZATION, self.auth())
+            .send()
+            .context("get bundle origins")?;
+
+        if resp.status() == reqwest::StatusCode::NOT_FOUND {
+            anyhow::bail!(NotFound("bundle"));
+        }
+
+        self.ensure_ok(resp, "get bundle origins")?
+            .json()
+            .context("parse bundle origins")
+    }
+
+    pub fn record_bundle_resolutions(
+        &self,
+        bundle_id: &str,
+        decisions: &[RecordResolutionDecision],
+    ) -> Result<BundleResolutions> {
+        let repo = &self.remote.repo_id;
+        let resp = self
+            .client
+            .post(self.url(&format!(
+                "/repos/{}/bundles/{}/resolutions",
+                repo, bundle_id
+            )))
+            .header(reqwest::header::AUTHORIZATION, self.auth())
+            .json(&serde_json::json!({ "decisions": decisions }))
+            .send()
+            .context("record bundle resolutions")?;
+
+        let out: BundleResolutions = self
+            .ensure_ok(resp, "record bundle resolutions")?
+            .json()
+            .context("parse bundle resolutions")?;
+        Ok(out)
+    }
+
+    pub fn reopen_bundle_resolution(
+        &self,
+        bundle_id: &str,
+        path: &str,
+        rationale: Option<String>,
+    ) -> Result<BundleResolutions> {
+        let repo = &self.remote.repo_id;
+        let resp = self
+            .client
+            .post(self.url(&format!(
+                "/repos/{}/bundles/{}/resolutions/reopen",
+                repo, bundle_id
+            )))
+            .header(reqwest::header::AUTHORIZATION, self.auth())
+            .json(&serde_json::json!({
+                "path": path,
+                "rationale": rationale,
+            }))
+            .send()
+            .context("reopen bundle resolution")?;
+
+        let out: BundleResolutions = self
+            .ensure_ok(resp, "reopen bundle resolution")?
+            .json()
+            .context("parse bundle resolutions")?;
+        Ok(out)
+    }
+}
@@ -5,8 +5,9 @@ use std::collections::HashMap;
 use anyhow::{Context, Result};
 
 use super::{
-    Bundle, CreateRepoRequest, GateGraph, GateGraphValidationError, Pins, Promotion, Publication,
-    Release, RemoteClient, Repo,
+    Bundle, BundleResolutions, CreateRepoRequest, GateGraph, GateGraphValidationError, NotFound,
+    Pins, Promotion, Publication, RecordResolutionDecision, Release, RemoteClient, Repo,
+    RepoPermissions,
 };
 
 mod bundle_ops;
@@ -1,5 +1,7 @@
 use super::*;
 
+use crate::workspace::StoredFile;
+
 impl RemoteClient {
     pub fn list_releases(&self) -> Result<Vec<Release>> {
         let repo = &self.remote.repo_id;
@@ -33,7 +35,7 @@ impl RemoteClient {
             .context("get release")?;
 
         if resp.status() == reqwest::StatusCode::NOT_FOUND {
-            anyhow::bail!("release not found");
+            anyhow::bail!(NotFound("release"));
         }
 
         let r: Release = self
@@ -50,6 +52,10 @@ impl RemoteClient {
         notes: Option<String>,
     ) -> Result<Release> {
         let repo = &self.remote.repo_id;
+        let signature = self
+            .signer
+            .as_ref()
+            .map(|key| key.sign(&crate::signing::release_payload(repo, channel, bundle_id)));
         let resp = self
             .client
             .post(self.url(&format!("/repos/{}/releases", repo)))
@@ -58,6 +64,7 @@ impl RemoteClient {
                 "channel": channel,
                 "bundle_id": bundle_id,
                 "notes": notes,
+                "signature": signature,
             }))
             .send()
             .context("create release")?;
@@ -66,4 +73,36 @@ impl RemoteClient {
         let r: Release = resp.json().context("parse release")?;
         Ok(r)
     }
+
+    /// Attaches uploaded content to the latest release in `channel`.
+    pub fn attach_release_file(
+        &self,
+        channel: &str,
+        name: &str,
+        kind: &str,
+        file: &StoredFile,
+    ) -> Result<Release> {
+        let repo = &self.remote.repo_id;
+        let resp = self
+            .client
+            .post(self.url(&format!("/repos/{}/releases/{}/attachments", repo, channel)))
+            .header(reqwest::header::AUTHORIZATION, self.auth())
+            .json(&serde_json::json!({
+                "name": name,
+                "kind": kind,
+                "size": file.size,
+                "blob": file.blob.as_ref().map(|id| id.as_str()),
+                "recipe": file.recipe.as_ref().map(|id| id.as_str()),
+            }))
+            .send()
+            .context("attach release file")?;
+
+        if resp.status() == reqwest::StatusCode::NOT_FOUND {
+            anyhow::bail!(NotFound("release"));
+        }
+
+        let resp = self.ensure_ok(resp, "attach release file")?;
+        let r: Release = resp.json().context("parse release")?;
+        Ok(r)
+    }
 }
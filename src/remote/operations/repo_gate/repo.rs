@@ -20,4 +20,19 @@ impl RemoteClient {
         let repo: Repo = resp.json().context("parse create repo response")?;
         Ok(repo)
     }
+
+    pub fn get_repo_permissions(&self) -> Result<RepoPermissions> {
+        let repo = &self.remote.repo_id;
+        let resp = self
+            .client
+            .get(self.url(&format!("/repos/{}/permissions", repo)))
+            .header(reqwest::header::AUTHORIZATION, self.auth())
+            .send()
+            .context("get repo permissions")?;
+        let perms: RepoPermissions = self
+            .ensure_ok(resp, "get repo permissions")?
+            .json()
+            .context("parse repo permissions")?;
+        Ok(perms)
+    }
 }
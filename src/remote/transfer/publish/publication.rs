@@ -2,6 +2,7 @@ use anyhow::{Context, Result};
 
 use crate::model::SnapRecord;
 use crate::remote::CreatePublicationRequest;
+use crate::signing;
 
 use super::{Publication, PublicationResolution, RemoteClient, with_retries};
 
@@ -14,6 +15,14 @@ pub(super) fn create_publication(
     metadata_only: bool,
     resolution: Option<PublicationResolution>,
 ) -> Result<Publication> {
+    let (signature, snap_signature) = match client.signer.as_ref() {
+        Some(key) => (
+            Some(key.sign(&signing::publication_payload(repo, scope, gate, &snap.id))),
+            Some(key.sign(&signing::snap_payload(snap)?)),
+        ),
+        None => (None, None),
+    };
+
     let resp = with_retries("create publication", || {
         let resp = client
             .client
@@ -25,6 +34,8 @@ pub(super) fn create_publication(
                 gate: gate.to_string(),
                 metadata_only,
                 resolution: resolution.clone(),
+                signature: signature.clone(),
+                snap_signature: snap_signature.clone(),
             })
             .send()
             .context("send")?;
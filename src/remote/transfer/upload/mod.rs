@@ -2,6 +2,7 @@ use anyhow::Result;
 
 use crate::model::{ObjectId, SnapRecord};
 use crate::store::LocalStore;
+use crate::workspace::StoredFile;
 
 use super::super::fetch::{collect_objects, manifest_postorder};
 use super::super::{
@@ -26,6 +27,28 @@ impl RemoteClient {
         Ok(())
     }
 
+    /// Uploads the blob, or recipe and chunks, behind a stored file.
+    pub fn upload_stored_file(&self, store: &LocalStore, file: &StoredFile) -> Result<()> {
+        let mut blobs = Vec::new();
+        if let Some(blob) = &file.blob {
+            blobs.push(blob.0.clone());
+        }
+        if let Some(recipe) = &file.recipe {
+            blobs.extend(
+                store
+                    .get_recipe(recipe)?
+                    .chunks
+                    .into_iter()
+                    .map(|c| c.blob.0),
+            );
+        }
+        upload_objects::upload_blobs(self, store, &blobs)?;
+        if let Some(recipe) = &file.recipe {
+            upload_objects::upload_recipes(self, store, std::slice::from_ref(&recipe.0))?;
+        }
+        Ok(())
+    }
+
     pub fn sync_snap(
         &self,
         store: &LocalStore,
@@ -19,6 +19,28 @@ pub struct TokenView {
     pub last_used_at: Option<String>,
     pub revoked_at: Option<String>,
     pub expires_at: Option<String>,
+
+    /// Repo allowlist; `None` means unrestricted.
+    #[serde(default)]
+    pub repos: Option<Vec<String>>,
+
+    /// Capability allowlist (`read`, `publish`, ...); `None` means unrestricted.
+    #[serde(default)]
+    pub capabilities: Option<Vec<String>>,
+}
+
+#[derive(Clone, Debug, Default, serde::Serialize)]
+pub struct CreateTokenRequest {
+    pub label: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub repos: Option<Vec<String>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub capabilities: Option<Vec<String>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expires_in_secs: Option<u64>,
 }
 
 #[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
@@ -38,3 +60,39 @@ pub struct RemoteUser {
     pub admin: bool,
     pub created_at: String,
 }
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct SigningKeyView {
+    pub id: String,
+    pub user: String,
+    pub public_key: String,
+    #[serde(default)]
+    pub label: Option<String>,
+    pub created_at: String,
+    #[serde(default)]
+    pub revoked_at: Option<String>,
+}
+
+/// A server-side group; `members` are user handles, `groups` nested group names.
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct RemoteGroup {
+    pub id: String,
+    pub name: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub members: Vec<String>,
+    #[serde(default)]
+    pub groups: Vec<String>,
+    pub created_at: String,
+}
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct AuditEntry {
+    pub at: String,
+    pub actor: String,
+    pub action: String,
+    pub target: String,
+    #[serde(default)]
+    pub detail: Option<String>,
+}
@@ -10,6 +10,10 @@ pub struct GateDef {
     pub name: String,
     pub upstream: Vec<String>,
 
+    /// Lane that owns this gate; only its members (and admins) bundle, approve and promote here.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lane: Option<String>,
+
     #[serde(default = "default_true")]
     pub allow_releases: bool,
 
@@ -21,6 +25,51 @@ pub struct GateDef {
 
     #[serde(default)]
     pub required_approvals: u32,
+
+    /// Publications, approvals and releases at this gate must carry valid signatures.
+    #[serde(default)]
+    pub require_signed: bool,
+
+    /// Roles allowed to bundle/promote/release at this gate, overriding the defaults.
+    #[serde(default, skip_serializing_if = "GateRoles::is_empty")]
+    pub roles: GateRoles,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub resolution_rules: Vec<crate::model::ResolutionRule>,
+}
+
+/// Per-gate role overrides; an empty list keeps the default role
+/// (`converger`, `promoter`, `releaser`).
+#[derive(Clone, Debug, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct GateRoles {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub bundle: Vec<String>,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub promote: Vec<String>,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub release: Vec<String>,
+}
+
+impl GateRoles {
+    pub fn is_empty(&self) -> bool {
+        self.bundle.is_empty() && self.promote.is_empty() && self.release.is_empty()
+    }
+
+    /// `bundle=a,b release=c` for the overridden actions only.
+    pub fn summary(&self) -> String {
+        [
+            ("bundle", &self.bundle),
+            ("promote", &self.promote),
+            ("release", &self.release),
+        ]
+        .into_iter()
+        .filter(|(_, roles)| !roles.is_empty())
+        .map(|(action, roles)| format!("{}={}", action, roles.join(",")))
+        .collect::<Vec<_>>()
+        .join(" ")
+    }
 }
 
 #[derive(Debug, serde::Deserialize)]
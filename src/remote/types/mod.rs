@@ -5,14 +5,27 @@ mod gate_graph;
 mod publication_flow;
 mod repo_lanes;
 mod requests;
+mod resolutions;
 
-pub use self::auth::{BootstrapResponse, CreateTokenResponse, RemoteUser, TokenView, WhoAmI};
+pub use self::auth::{
+    AuditEntry, BootstrapResponse, CreateTokenRequest, CreateTokenResponse, RemoteGroup,
+    RemoteUser, SigningKeyView, TokenView, WhoAmI,
+};
 pub(crate) use self::gate_graph::GateGraphValidationError;
-pub use self::gate_graph::{GateDef, GateGraph};
+pub use self::gate_graph::{GateDef, GateGraph, GateRoles};
 pub use self::publication_flow::{
-    Bundle, MissingObjectsResponse, Pins, Promotion, Publication, PublicationResolution, Release,
+    Bundle, CheckReport, MissingObjectsResponse, Pins, Promotion, Publication,
+    PublicationResolution, Release, ReleaseAttachment,
+};
+pub use self::repo_lanes::{
+    LEGACY_REPO_ROLES, Lane, LaneHead, LaneMembers, LaneSubscription, REPO_ROLES, Repo,
+    RepoMembers, RepoPermissions, RoleGrant,
 };
-pub use self::repo_lanes::{Lane, LaneHead, LaneMembers, Repo, RepoMembers};
+pub use self::requests::LaneUpdate;
 pub(crate) use self::requests::{
     CreatePublicationRequest, CreateRepoRequest, MissingObjectsRequest, UpdateLaneHeadRequest,
 };
+pub use self::resolutions::{
+    BundleResolutions, RecordResolutionDecision, ResolutionEvent, ResolutionMethod,
+    ResolutionRecord, ResolutionStatus,
+};
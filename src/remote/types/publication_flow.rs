@@ -1,3 +1,5 @@
+use crate::signing::Signature;
+
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
 pub struct MissingObjectsResponse {
     pub missing_blobs: Vec<String>,
@@ -22,6 +24,13 @@ pub struct Publication {
 
     #[serde(default)]
     pub resolution: Option<PublicationResolution>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signature: Option<Signature>,
+
+    /// Signature over the published snap record.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub snap_signature: Option<Signature>,
 }
 
 #[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
@@ -46,6 +55,29 @@ pub struct Bundle {
 
     #[serde(default)]
     pub approvals: Vec<String>,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub approval_signatures: Vec<Signature>,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub checks: Vec<CheckReport>,
+}
+
+/// Latest result reported for one named check on a bundle.
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct CheckReport {
+    pub name: String,
+    /// `pending`, `success` or `failure`.
+    pub status: String,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+
+    pub reported_by: String,
+    pub reported_at: String,
 }
 
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
@@ -75,4 +107,29 @@ pub struct Release {
 
     #[serde(default)]
     pub notes: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub attachments: Vec<ReleaseAttachment>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signature: Option<Signature>,
+}
+
+/// A file attached to a release (build artifact, SBOM or signature), stored content-addressed as
+/// a blob, or as a chunk recipe when large.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ReleaseAttachment {
+    pub name: String,
+    /// `artifact`, `sbom` or `signature`.
+    pub kind: String,
+    pub size: u64,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub blob: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub recipe: Option<String>,
+
+    pub attached_by: String,
+    pub attached_at: String,
 }
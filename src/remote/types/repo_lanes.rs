@@ -6,6 +6,26 @@ pub struct Repo {
     pub owner: String,
 }
 
+/// What the caller may do in a repo.
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub struct RepoPermissions {
+    pub read: bool,
+    pub publish: bool,
+    pub converge: bool,
+    pub approve: bool,
+    pub promote: bool,
+    pub release: bool,
+    pub manage: bool,
+
+    /// Lanes the caller is a member of.
+    #[serde(default)]
+    pub lanes: Vec<String>,
+
+    /// Gates owned by those lanes, in gate graph order.
+    #[serde(default)]
+    pub owned_gates: Vec<String>,
+}
+
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
 pub struct RepoMembers {
     pub owner: String,
@@ -18,6 +38,44 @@ pub struct RepoMembers {
     pub reader_user_ids: Vec<String>,
     #[serde(default)]
     pub publisher_user_ids: Vec<String>,
+
+    /// Fine-grained role grants (`approver`, `releaser`, ...).
+    #[serde(default)]
+    pub grants: Vec<RoleGrant>,
+}
+
+/// Legacy repo roles: `read`, and `publish` (which also allows bundling, approving, promoting
+/// and releasing). Neither can be narrowed to a gate or lane.
+pub const LEGACY_REPO_ROLES: &[&str] = &["read", "publish"];
+
+/// Fine-grained repo roles; grants may be narrowed to one gate or lane.
+pub const REPO_ROLES: &[&str] = &[
+    "reader",
+    "publisher",
+    "converger",
+    "promoter",
+    "releaser",
+    "approver",
+    "maintainer",
+];
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct RoleGrant {
+    #[serde(default)]
+    pub user_id: String,
+    /// User handle, or `@<group>` for group grants.
+    pub handle: String,
+    pub role: String,
+
+    /// Group id when the role is granted to a group.
+    #[serde(default)]
+    pub group: Option<String>,
+
+    #[serde(default)]
+    pub gate: Option<String>,
+
+    #[serde(default)]
+    pub lane: Option<String>,
 }
 
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
@@ -27,6 +85,27 @@ pub struct LaneMembers {
 
     #[serde(default)]
     pub member_user_ids: Vec<String>,
+
+    /// Member groups, as `@<group>`.
+    #[serde(default)]
+    pub groups: Vec<String>,
+}
+
+/// Temporary read visibility into a lane the user is not a member of.
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct LaneSubscription {
+    pub lane: String,
+    pub user_id: String,
+    pub handle: String,
+
+    #[serde(default)]
+    pub scope: Option<String>,
+
+    #[serde(default)]
+    pub until: Option<String>,
+
+    pub created_at: String,
+    pub created_by: String,
 }
 
 #[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
@@ -43,6 +122,18 @@ pub struct Lane {
     pub id: String,
     pub members: HashSet<String>,
 
+    #[serde(default)]
+    pub description: Option<String>,
+
+    #[serde(default)]
+    pub gates: Vec<String>,
+
+    #[serde(default)]
+    pub default_scope: Option<String>,
+
+    #[serde(default)]
+    pub archived_at: Option<String>,
+
     #[serde(default)]
     pub heads: HashMap<String, LaneHead>,
 }
@@ -1,4 +1,5 @@
 use super::publication_flow::PublicationResolution;
+use crate::signing::Signature;
 
 fn is_false(v: &bool) -> bool {
     !*v
@@ -23,6 +24,12 @@ pub(crate) struct CreatePublicationRequest {
 
     #[serde(skip_serializing_if = "Option::is_none")]
     pub(crate) resolution: Option<PublicationResolution>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) signature: Option<Signature>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) snap_signature: Option<Signature>,
 }
 
 #[derive(Debug, serde::Serialize)]
@@ -37,3 +44,22 @@ pub(crate) struct UpdateLaneHeadRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub(crate) client_id: Option<String>,
 }
+
+/// Lane metadata edits; `None` leaves a field unchanged and an empty string clears it.
+#[derive(Debug, Default, serde::Serialize)]
+pub struct LaneUpdate {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gates: Option<Vec<String>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_scope: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub archived: Option<bool>,
+}
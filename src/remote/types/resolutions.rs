@@ -0,0 +1,107 @@
+use std::collections::BTreeMap;
+
+use crate::model::VariantKey;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ResolutionStatus {
+    Unresolved,
+    Resolved,
+    Reopened,
+}
+
+impl ResolutionStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ResolutionStatus::Unresolved => "unresolved",
+            ResolutionStatus::Resolved => "resolved",
+            ResolutionStatus::Reopened => "reopened",
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ResolutionMethod {
+    Pick,
+    Manual,
+    Rule,
+}
+
+impl ResolutionMethod {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ResolutionMethod::Pick => "pick",
+            ResolutionMethod::Manual => "manual",
+            ResolutionMethod::Rule => "rule",
+        }
+    }
+}
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct ResolutionEvent {
+    pub status: ResolutionStatus,
+    pub actor: String,
+
+    #[serde(default)]
+    pub actor_user_id: Option<String>,
+
+    #[serde(default)]
+    pub rationale: Option<String>,
+
+    pub at: String,
+}
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct ResolutionRecord {
+    pub path: String,
+    pub status: ResolutionStatus,
+
+    #[serde(default)]
+    pub resolver: Option<String>,
+
+    #[serde(default)]
+    pub resolver_user_id: Option<String>,
+
+    #[serde(default)]
+    pub method: Option<ResolutionMethod>,
+
+    #[serde(default)]
+    pub rationale: Option<String>,
+
+    #[serde(default)]
+    pub rule: Option<String>,
+
+    #[serde(default)]
+    pub decision: Option<VariantKey>,
+
+    #[serde(default)]
+    pub updated_at: Option<String>,
+
+    #[serde(default)]
+    pub history: Vec<ResolutionEvent>,
+}
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct BundleResolutions {
+    pub bundle_id: String,
+    pub root_manifest: String,
+
+    #[serde(default)]
+    pub records: BTreeMap<String, ResolutionRecord>,
+}
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct RecordResolutionDecision {
+    pub path: String,
+    pub decision: VariantKey,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub method: Option<ResolutionMethod>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rule: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rationale: Option<String>,
+}
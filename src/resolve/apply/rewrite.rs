@@ -4,7 +4,7 @@ use anyhow::{Context, Result};
 
 use crate::model::{
     Manifest, ManifestEntry, ManifestEntryKind, ObjectId, ResolutionDecision,
-    SuperpositionVariantKind,
+    SuperpositionVariantKind, VariantKeyKind,
 };
 use crate::store::LocalStore;
 
@@ -45,6 +45,20 @@ pub(super) fn rewrite_manifest(
                 let decision = decisions
                     .get(&path)
                     .with_context(|| format!("no resolution decision for {}", path))?;
+                if let ResolutionDecision::Key(key) = decision
+                    && key.is_manual()
+                    && let VariantKeyKind::File { blob, mode, size } = &key.kind
+                {
+                    out_entries.push(ManifestEntry {
+                        name: e.name,
+                        kind: ManifestEntryKind::File {
+                            blob: blob.clone(),
+                            mode: *mode,
+                            size: *size,
+                        },
+                    });
+                    continue;
+                }
                 let idx = decision_to_index(&path, decision, &variants)?;
 
                 let v = &variants[idx];
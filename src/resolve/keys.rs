@@ -0,0 +1,38 @@
+use anyhow::Result;
+
+use crate::model::{ObjectId, ResolutionDecision, VariantKey};
+use crate::store::LocalStore;
+
+use super::variants::superposition_variants;
+
+/// Converts decisions to stable variant keys, mapping legacy index decisions through the
+/// current superpositions. Decisions that no longer match a variant are skipped; manual merge
+/// keys pass through unchanged.
+pub fn decision_keys(
+    store: &LocalStore,
+    root: &ObjectId,
+    decisions: &std::collections::BTreeMap<String, ResolutionDecision>,
+) -> Result<std::collections::BTreeMap<String, VariantKey>> {
+    let variants = superposition_variants(store, root)?;
+
+    let mut out = std::collections::BTreeMap::new();
+    for (path, decision) in decisions {
+        let Some(vs) = variants.get(path) else {
+            continue;
+        };
+        let key = match decision {
+            ResolutionDecision::Key(key) => {
+                if !key.is_manual() && !vs.iter().any(|v| v.key() == *key) {
+                    continue;
+                }
+                key.clone()
+            }
+            ResolutionDecision::Index(i) => match vs.get(*i as usize) {
+                Some(v) => v.key(),
+                None => continue,
+            },
+        };
+        out.insert(path.clone(), key);
+    }
+    Ok(out)
+}
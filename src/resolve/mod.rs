@@ -1,9 +1,16 @@
 mod apply;
+mod keys;
+mod rules;
 mod types;
 mod validate;
 mod variants;
 
 pub use self::apply::apply_resolution;
+pub use self::keys::decision_keys;
+pub use self::rules::{
+    VariantOrigin, choose_variant, matching_rule, parse_resolution_rules, union_lines,
+    validate_resolution_rules,
+};
 pub use self::types::{InvalidKeyDecision, OutOfRangeDecision, ResolutionValidation};
 pub use self::validate::validate_resolution;
 pub use self::variants::{superposition_variant_counts, superposition_variants};
@@ -0,0 +1,137 @@
+use std::collections::{BTreeMap, HashSet};
+
+use anyhow::{Context, Result};
+
+use crate::model::{
+    ResolutionRule, ResolutionRules, ResolutionStrategy, SuperpositionVariant,
+    SuperpositionVariantKind,
+};
+
+/// Publication metadata used to rank the variants of a superposition.
+#[derive(Clone, Debug, Default, serde::Serialize, serde::Deserialize)]
+pub struct VariantOrigin {
+    pub created_at: String,
+    pub lanes: Vec<String>,
+}
+
+pub fn parse_resolution_rules(bytes: &[u8]) -> Result<ResolutionRules> {
+    let rules: ResolutionRules = serde_json::from_slice(bytes).context("parse resolution rules")?;
+    if rules.version != 1 {
+        anyhow::bail!("unsupported resolution rules version {}", rules.version);
+    }
+    validate_resolution_rules(&rules.rules)?;
+    crate::merge::MergeDriverRegistry::new(&rules.drivers)?;
+    Ok(rules)
+}
+
+pub fn validate_resolution_rules(rules: &[ResolutionRule]) -> Result<()> {
+    for rule in rules {
+        if rule.name.trim().is_empty() {
+            anyhow::bail!("resolution rule name cannot be empty");
+        }
+        path_matcher(&rule.path).with_context(|| format!("rule {}", rule.name))?;
+        if let ResolutionStrategy::PreferSourceLane { lane } = &rule.strategy
+            && lane.trim().is_empty()
+        {
+            anyhow::bail!("rule {}: prefer-source-lane requires a lane", rule.name);
+        }
+    }
+    Ok(())
+}
+
+/// First rule whose glob matches `path` (rules are ordered by precedence).
+pub fn matching_rule<'a>(
+    rules: &'a [ResolutionRule],
+    path: &str,
+) -> Result<Option<&'a ResolutionRule>> {
+    for rule in rules {
+        if path_matcher(&rule.path)?.is_match(path) {
+            return Ok(Some(rule));
+        }
+    }
+    Ok(None)
+}
+
+/// Index of the variant a strategy selects, if it selects an existing one.
+///
+/// `union-lines` synthesizes new content and never selects a variant here.
+pub fn choose_variant(
+    strategy: &ResolutionStrategy,
+    variants: &[SuperpositionVariant],
+    origins: &BTreeMap<String, VariantOrigin>,
+) -> Option<usize> {
+    match strategy {
+        ResolutionStrategy::PreferSourceLane { lane } => {
+            newest_variant(variants, origins, |o| o.lanes.iter().any(|l| l == lane))
+        }
+        ResolutionStrategy::PreferNewest => newest_variant(variants, origins, |_| true),
+        ResolutionStrategy::UnionLines => None,
+        ResolutionStrategy::TakeTombstone => variants
+            .iter()
+            .position(|v| matches!(v.kind, SuperpositionVariantKind::Tombstone)),
+    }
+}
+
+/// Distinct lines of every input, in order of first appearance.
+pub fn union_lines(contents: &[Vec<u8>]) -> Vec<u8> {
+    let mut seen = HashSet::new();
+    let mut out = Vec::new();
+    let mut trailing_newline = false;
+    for content in contents {
+        if content.is_empty() {
+            continue;
+        }
+        trailing_newline |= content.ends_with(b"\n");
+        let body = content.strip_suffix(b"\n").unwrap_or(content);
+        for line in body.split(|b| *b == b'\n') {
+            if seen.insert(line.to_vec()) {
+                if !out.is_empty() {
+                    out.push(b'\n');
+                }
+                out.extend_from_slice(line);
+            }
+        }
+    }
+    if trailing_newline && !out.is_empty() {
+        out.push(b'\n');
+    }
+    out
+}
+
+fn newest_variant(
+    variants: &[SuperpositionVariant],
+    origins: &BTreeMap<String, VariantOrigin>,
+    eligible: impl Fn(&VariantOrigin) -> bool,
+) -> Option<usize> {
+    let mut best: Option<(usize, time::OffsetDateTime)> = None;
+    for (idx, v) in variants.iter().enumerate() {
+        let Some(origin) = origins.get(&v.source) else {
+            continue;
+        };
+        if !eligible(origin) {
+            continue;
+        }
+        let Ok(at) = time::OffsetDateTime::parse(
+            &origin.created_at,
+            &time::format_description::well_known::Rfc3339,
+        ) else {
+            continue;
+        };
+        if best.is_none_or(|(_, b)| at > b) {
+            best = Some((idx, at));
+        }
+    }
+    best.map(|(idx, _)| idx)
+}
+
+fn path_matcher(pattern: &str) -> Result<globset::GlobMatcher> {
+    let glob = globset::GlobBuilder::new(pattern)
+        .literal_separator(true)
+        .build()
+        .with_context(|| format!("invalid path glob {}", pattern))?;
+    Ok(glob.compile_matcher())
+}
+
+#[cfg(test)]
+#[path = "../tests/resolve/rules_tests.rs"]
+mod tests;
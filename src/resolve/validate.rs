@@ -1,6 +1,6 @@
 use anyhow::Result;
 
-use crate::model::{ObjectId, ResolutionDecision};
+use crate::model::{ObjectId, ResolutionDecision, VariantKeyKind};
 use crate::store::LocalStore;
 
 use super::types::{InvalidKeyDecision, OutOfRangeDecision, ResolutionValidation};
@@ -46,7 +46,12 @@ pub fn validate_resolution(
                 }
             }
             ResolutionDecision::Key(k) => {
-                if !vs.iter().any(|v| &v.key() == k) {
+                let valid = match &k.kind {
+                    // Manual merges are valid as long as the merged content is stored locally.
+                    VariantKeyKind::File { blob, .. } if k.is_manual() => store.has_blob(blob),
+                    _ => vs.iter().any(|v| &v.key() == k),
+                };
+                if !valid {
                     invalid_keys.push(InvalidKeyDecision {
                         path: path.clone(),
                         wanted: k.clone(),
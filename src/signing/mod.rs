@@ -0,0 +1,235 @@
+//! Ed25519 signing keys and detached signatures.
+//!
+//! Snaps, publications, approvals and releases are signed over a canonical JSON payload: object
+//! keys sorted, no whitespace. Payloads only contain fields the signer controls, so a client can
+//! sign before the server assigns ids and timestamps, and anyone holding the record can rebuild
+//! the payload and check the signature against the signer's registered public key.
+
+use std::path::Path;
+
+use anyhow::{Context, Result, anyhow};
+use ed25519_dalek::{Signer as _, Verifier as _};
+use serde::{Deserialize, Serialize};
+
+use crate::model::SnapRecord;
+
+pub const ALGORITHM: &str = "ed25519";
+
+/// A detached signature. `signer` is the handle owning `key_id`, filled in by the server.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Signature {
+    pub key_id: String,
+
+    #[serde(default)]
+    pub signer: String,
+
+    /// Hex-encoded 64-byte ed25519 signature.
+    pub signature: String,
+
+    /// When the server accepted the signature; signatures from before a key's revocation stay
+    /// valid.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signed_at: Option<String>,
+}
+
+/// On-disk form of a private signing key.
+#[derive(Serialize, Deserialize)]
+struct KeyFile {
+    version: u32,
+    algorithm: String,
+    key_id: String,
+    public_key: String,
+    secret_key: String,
+}
+
+pub struct SigningKey {
+    key: ed25519_dalek::SigningKey,
+}
+
+impl SigningKey {
+    pub fn generate() -> Result<Self> {
+        let mut secret = [0u8; 32];
+        getrandom::getrandom(&mut secret).map_err(|e| anyhow!("generate signing key: {}", e))?;
+        Ok(Self {
+            key: ed25519_dalek::SigningKey::from_bytes(&secret),
+        })
+    }
+
+    pub fn load(path: &Path) -> Result<Self> {
+        let bytes = std::fs::read(path).with_context(|| format!("read {}", path.display()))?;
+        let file: KeyFile = serde_json::from_slice(&bytes)
+            .with_context(|| format!("parse signing key {}", path.display()))?;
+        if file.version != 1 || file.algorithm != ALGORITHM {
+            anyhow::bail!(
+                "unsupported signing key {} (version {}, algorithm {})",
+                path.display(),
+                file.version,
+                file.algorithm
+            );
+        }
+        let secret: [u8; 32] = hex::decode(&file.secret_key)
+            .ok()
+            .and_then(|b| b.try_into().ok())
+            .with_context(|| format!("invalid secret key in {}", path.display()))?;
+        let key = Self {
+            key: ed25519_dalek::SigningKey::from_bytes(&secret),
+        };
+        if key.public_key_hex() != file.public_key {
+            anyhow::bail!("public key does not match secret key in {}", path.display());
+        }
+        Ok(key)
+    }
+
+    /// Writes the key with owner-only permissions; refuses to replace an existing file.
+    pub fn save(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)
+                .with_context(|| format!("create {}", parent.display()))?;
+        }
+        let file = KeyFile {
+            version: 1,
+            algorithm: ALGORITHM.to_string(),
+            key_id: self.key_id(),
+            public_key: self.public_key_hex(),
+            secret_key: hex::encode(self.key.to_bytes()),
+        };
+        let bytes = serde_json::to_vec_pretty(&file).context("serialize signing key")?;
+
+        let mut opts = std::fs::OpenOptions::new();
+        opts.write(true).create_new(true);
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::OpenOptionsExt;
+            opts.mode(0o600);
+        }
+        let mut f = opts
+            .open(path)
+            .with_context(|| format!("create {}", path.display()))?;
+        std::io::Write::write_all(&mut f, &bytes)
+            .with_context(|| format!("write {}", path.display()))?;
+        Ok(())
+    }
+
+    pub fn public_key_hex(&self) -> String {
+        hex::encode(self.key.verifying_key().to_bytes())
+    }
+
+    pub fn key_id(&self) -> String {
+        key_id(&self.key.verifying_key().to_bytes())
+    }
+
+    pub fn sign(&self, payload: &[u8]) -> Signature {
+        Signature {
+            key_id: self.key_id(),
+            signer: String::new(),
+            signature: hex::encode(self.key.sign(payload).to_bytes()),
+            signed_at: None,
+        }
+    }
+}
+
+/// Short fingerprint of a public key: the first 16 hex digits of its blake3 hash.
+pub fn key_id(public_key: &[u8]) -> String {
+    blake3::hash(public_key).to_hex()[..16].to_string()
+}
+
+/// Parses a hex public key and returns its key id.
+pub fn public_key_id(public_key_hex: &str) -> Result<String> {
+    Ok(key_id(&parse_public_key(public_key_hex)?.to_bytes()))
+}
+
+fn parse_public_key(public_key_hex: &str) -> Result<ed25519_dalek::VerifyingKey> {
+    let bytes: [u8; 32] = hex::decode(public_key_hex)
+        .ok()
+        .and_then(|b| b.try_into().ok())
+        .context("public key must be 32 bytes of hex")?;
+    ed25519_dalek::VerifyingKey::from_bytes(&bytes).context("invalid ed25519 public key")
+}
+
+/// Checks `signature` over `payload` with the given hex public key.
+pub fn verify(public_key_hex: &str, signature: &Signature, payload: &[u8]) -> Result<()> {
+    let key = parse_public_key(public_key_hex)?;
+    if key_id(&key.to_bytes()) != signature.key_id {
+        anyhow::bail!(
+            "signature key {} does not match public key",
+            signature.key_id
+        );
+    }
+    let bytes: [u8; 64] = hex::decode(&signature.signature)
+        .ok()
+        .and_then(|b| b.try_into().ok())
+        .context("signature must be 64 bytes of hex")?;
+    key.verify(payload, &ed25519_dalek::Signature::from_bytes(&bytes))
+        .map_err(|_| anyhow!("bad signature from key {}", signature.key_id))
+}
+
+/// Serializes `value` with object keys sorted and no insignificant whitespace.
+pub fn canonical_json(value: &serde_json::Value) -> Vec<u8> {
+    fn sorted(value: &serde_json::Value) -> serde_json::Value {
+        match value {
+            serde_json::Value::Object(map) => {
+                let mut keys = map.keys().collect::<Vec<_>>();
+                keys.sort();
+                serde_json::Value::Object(
+                    keys.into_iter()
+                        .map(|k| (k.clone(), sorted(&map[k])))
+                        .collect(),
+                )
+            }
+            serde_json::Value::Array(items) => {
+                serde_json::Value::Array(items.iter().map(sorted).collect())
+            }
+            other => other.clone(),
+        }
+    }
+    serde_json::to_vec(&sorted(value)).expect("serialize json value")
+}
+
+pub fn snap_payload(snap: &SnapRecord) -> Result<Vec<u8>> {
+    let value = serde_json::to_value(snap).context("serialize snap")?;
+    Ok(canonical_json(&serde_json::json!({
+        "type": "snap",
+        "snap": value,
+    })))
+}
+
+pub fn publication_payload(repo: &str, scope: &str, gate: &str, snap_id: &str) -> Vec<u8> {
+    canonical_json(&serde_json::json!({
+        "type": "publication",
+        "repo": repo,
+        "scope": scope,
+        "gate": gate,
+        "snap_id": snap_id,
+    }))
+}
+
+pub fn approval_payload(repo: &str, bundle_id: &str, root_manifest: &str) -> Vec<u8> {
+    canonical_json(&serde_json::json!({
+        "type": "approval",
+        "repo": repo,
+        "bundle_id": bundle_id,
+        "root_manifest": root_manifest,
+    }))
+}
+
+/// Proof that whoever registers a public key for `user_id` holds its private half.
+pub fn key_registration_payload(user_id: &str, public_key: &str) -> Vec<u8> {
+    canonical_json(&serde_json::json!({
+        "type": "key-registration",
+        "user_id": user_id,
+        "public_key": public_key.to_ascii_lowercase(),
+    }))
+}
+
+pub fn release_payload(repo: &str, channel: &str, bundle_id: &str) -> Vec<u8> {
+    canonical_json(&serde_json::json!({
+        "type": "release",
+        "repo": repo,
+        "channel": channel,
+        "bundle_id": bundle_id,
+    }))
+}
+
+#[cfg(test)]
+#[path = "../tests/signing/signing_tests.rs"]
+mod tests;
@@ -12,6 +12,8 @@ mod snap_resolution;
 mod state_meta;
 mod traversal;
 
+pub use traversal::find_entry_with;
+
 #[derive(Clone)]
 pub struct LocalStore {
     root: PathBuf,
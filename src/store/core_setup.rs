@@ -55,6 +55,8 @@ impl LocalStore {
             lane_sync: std::collections::HashMap::new(),
             remote_tokens: std::collections::HashMap::new(),
             last_published: std::collections::HashMap::new(),
+            last_updated: std::collections::HashMap::new(),
+            signing_key: None,
         };
         let state_bytes = serde_json::to_vec_pretty(&state).context("serialize workspace state")?;
         write_atomic(&root.join("state.json"), &state_bytes).context("write state.json")?;
@@ -9,6 +9,7 @@ use super::{LocalStore, write_atomic};
 mod lane_sync;
 mod publishing;
 mod remote_tokens;
+mod signing_key;
 
 impl LocalStore {
     pub fn read_state(&self) -> Result<WorkspaceState> {
@@ -19,6 +20,8 @@ impl LocalStore {
                 lane_sync: std::collections::HashMap::new(),
                 remote_tokens: std::collections::HashMap::new(),
                 last_published: std::collections::HashMap::new(),
+                last_updated: std::collections::HashMap::new(),
+                signing_key: None,
             });
         }
         let bytes = fs::read(&path).context("read state.json")?;
@@ -40,4 +40,38 @@ impl LocalStore {
             .insert(self.publish_key(remote, scope, gate), snap_id.to_string());
         self.write_state(&st)
     }
+
+    pub fn get_last_updated(
+        &self,
+        remote: &RemoteConfig,
+        scope: &str,
+        gate: &str,
+    ) -> Result<Option<String>> {
+        let st = self.read_state()?;
+        if st.version != 1 {
+            anyhow::bail!("unsupported workspace state version {}", st.version);
+        }
+        Ok(st
+            .last_updated
+            .get(&self.publish_key(remote, scope, gate))
+            .cloned())
+    }
+
+    pub fn set_last_updated(
+        &self,
+        remote: &RemoteConfig,
+        scope: &str,
+        gate: &str,
+        root_manifest: &str,
+    ) -> Result<()> {
+        let mut st = self.read_state()?;
+        if st.version != 1 {
+            anyhow::bail!("unsupported workspace state version {}", st.version);
+        }
+        st.last_updated.insert(
+            self.publish_key(remote, scope, gate),
+            root_manifest.to_string(),
+        );
+        self.write_state(&st)
+    }
 }
@@ -0,0 +1,46 @@
+use std::path::PathBuf;
+
+use anyhow::{Context, Result};
+
+use crate::signing::SigningKey;
+
+use super::LocalStore;
+
+impl LocalStore {
+    /// Default location for keys created by `converge key generate`.
+    pub fn keys_dir(&self) -> PathBuf {
+        self.root.join("keys")
+    }
+
+    pub fn signing_key_path(&self) -> Result<Option<PathBuf>> {
+        let st = self.read_state()?;
+        if st.version != 1 {
+            anyhow::bail!("unsupported workspace state version {}", st.version);
+        }
+        Ok(st.signing_key.map(PathBuf::from))
+    }
+
+    pub fn set_signing_key_path(&self, path: Option<&std::path::Path>) -> Result<()> {
+        let mut st = self.read_state()?;
+        if st.version != 1 {
+            anyhow::bail!("unsupported workspace state version {}", st.version);
+        }
+        st.signing_key = path.map(|p| p.display().to_string());
+        self.write_state(&st)
+    }
+
+    /// Loads the configured signing key, if any.
+    pub fn signing_key(&self) -> Result<Option<SigningKey>> {
+        let Some(path) = self.signing_key_path()? else {
+            return Ok(None);
+        };
+        SigningKey::load(&path)
+            .with_context(|| {
+                format!(
+                    "load signing key (configured in state.json: {})",
+                    path.display()
+                )
+            })
+            .map(Some)
+    }
+}
@@ -2,11 +2,17 @@ use std::fs;
 
 use anyhow::{Context, Result};
 
-use crate::model::ObjectId;
+use crate::model::{Manifest, ManifestEntry, ManifestEntryKind, ObjectId};
 
 use super::LocalStore;
 
 impl LocalStore {
+    /// The entry at `path` below the manifest `root`; `None` when a component is missing or
+    /// not a directory.
+    pub fn find_entry(&self, root: &ObjectId, path: &str) -> Result<Option<ManifestEntry>> {
+        find_entry_with(root, path, |id| self.get_manifest(id))
+    }
+
     pub fn list_blob_ids(&self) -> Result<Vec<ObjectId>> {
         let dir = self.root.join("objects/blobs");
         let mut out = Vec::new();
@@ -101,3 +107,29 @@ impl LocalStore {
         Ok(())
     }
 }
+
+/// Like [`LocalStore::find_entry`], reading each manifest on the way through `load`.
+pub fn find_entry_with(
+    root: &ObjectId,
+    path: &str,
+    mut load: impl FnMut(&ObjectId) -> Result<Manifest>,
+) -> Result<Option<ManifestEntry>> {
+    let mut dir = root.clone();
+    let mut parts = path.split('/').filter(|p| !p.is_empty()).peekable();
+    if parts.peek().is_none() {
+        anyhow::bail!("the root is not an entry");
+    }
+    while let Some(name) = parts.next() {
+        let Some(entry) = load(&dir)?.entries.into_iter().find(|e| e.name == name) else {
+            return Ok(None);
+        };
+        if parts.peek().is_none() {
+            return Ok(Some(entry));
+        }
+        match entry.kind {
+            ManifestEntryKind::Dir { manifest } => dir = manifest,
+            _ => return Ok(None),
+        }
+    }
+    unreachable!("the loop returns on the last component")
+}
@@ -0,0 +1,224 @@
+use std::collections::HashMap;
+use std::io::Read;
+
+use super::*;
+use crate::model::ManifestEntry;
+
+/// In-memory objects keyed by id; ids are just labels here.
+#[derive(Default)]
+struct MemSource {
+    manifests: HashMap<String, Manifest>,
+    blobs: HashMap<String, Vec<u8>>,
+    recipes: HashMap<String, FileRecipe>,
+}
+
+impl ObjectSource for MemSource {
+    fn manifest(&self, id: &ObjectId) -> Result<Manifest> {
+        self.manifests.get(&id.0).cloned().context("no manifest")
+    }
+
+    fn blob(&self, id: &ObjectId) -> Result<Vec<u8>> {
+        self.blobs.get(&id.0).cloned().context("no blob")
+    }
+
+    fn recipe(&self, id: &ObjectId) -> Result<FileRecipe> {
+        self.recipes.get(&id.0).cloned().context("no recipe")
+    }
+}
+
+fn entry(name: &str, kind: ManifestEntryKind) -> ManifestEntry {
+    ManifestEntry {
+        name: name.to_string(),
+        kind,
+    }
+}
+
+fn id(s: &str) -> ObjectId {
+    ObjectId(s.to_string())
+}
+
+/// root: zeta.txt, bin/run.sh (exec), big.bin (chunked), link -> zeta.txt
+fn sample() -> MemSource {
+    let mut src = MemSource::default();
+    src.blobs.insert("b-zeta".into(), b"zeta\n".to_vec());
+    src.blobs.insert("b-run".into(), b"#!/bin/sh\n".to_vec());
+    src.blobs.insert("c1".into(), b"hello ".to_vec());
+    src.blobs.insert("c2".into(), b"world".to_vec());
+    src.recipes.insert(
+        "r-big".into(),
+        FileRecipe {
+            version: 1,
+            size: 11,
+            chunks: vec![
+                FileRecipeChunk {
+                    blob: id("c1"),
+                    size: 6,
+                },
+                FileRecipeChunk {
+                    blob: id("c2"),
+                    size: 5,
+                },
+            ],
+        },
+    );
+    src.manifests.insert(
+        "m-bin".into(),
+        Manifest {
+            version: 1,
+            entries: vec![entry(
+                "run.sh",
+                ManifestEntryKind::File {
+                    blob: id("b-run"),
+                    mode: 0o100755,
+                    size: 10,
+                },
+            )],
+        },
+    );
+    // Deliberately unsorted: the archive must not depend on manifest order.
+    src.manifests.insert(
+        "m-root".into(),
+        Manifest {
+            version: 1,
+            entries: vec![
+                entry(
+                    "zeta.txt",
+                    ManifestEntryKind::File {
+                        blob: id("b-zeta"),
+                        mode: 0o100644,
+                        size: 5,
+                    },
+                ),
+                entry(
+                    "link",
+                    ManifestEntryKind::Symlink {
+                        target: "zeta.txt".into(),
+                    },
+                ),
+                entry(
+                    "big.bin",
+                    ManifestEntryKind::FileChunks {
+                        recipe: id("r-big"),
+                        mode: 0o100644,
+                        size: 11,
+                    },
+                ),
+                entry(
+                    "bin",
+                    ManifestEntryKind::Dir {
+                        manifest: id("m-bin"),
+                    },
+                ),
+            ],
+        },
+    );
+    src
+}
+
+fn archive(src: &MemSource, format: ArchiveFormat) -> Vec<u8> {
+    write_archive(src, &id("m-root"), format, Vec::new()).expect("write archive")
+}
+
+#[test]
+fn tar_entries_are_sorted_with_modes_links_and_chunked_content() {
+    let bytes = archive(&sample(), ArchiveFormat::Tar);
+    let mut tar = tar::Archive::new(bytes.as_slice());
+    let mut seen = Vec::new();
+    for e in tar.entries().expect("entries") {
+        let mut e = e.expect("entry");
+        let path = e.path().expect("path").to_string_lossy().into_owned();
+        let header = e.header().clone();
+        assert_eq!(header.mtime().expect("mtime"), TAR_MTIME);
+        let mut content = Vec::new();
+        e.read_to_end(&mut content).expect("read entry");
+        seen.push((
+            path,
+            header.mode().expect("mode"),
+            header.entry_type(),
+            content,
+        ));
+    }
+
+    let names = seen.iter().map(|s| s.0.as_str()).collect::<Vec<_>>();
+    assert_eq!(
+        names,
+        vec!["big.bin", "bin/", "bin/run.sh", "link", "zeta.txt"]
+    );
+    assert_eq!(seen[0].3, b"hello world");
+    assert_eq!(seen[2].1, 0o755);
+    assert_eq!(seen[3].2, tar::EntryType::Symlink);
+    assert_eq!(seen[4].1, 0o644);
+}
+
+#[test]
+fn archives_are_byte_identical_across_runs() {
+    let src = sample();
+    for format in [
+        ArchiveFormat::Tar,
+        ArchiveFormat::TarZst,
+        ArchiveFormat::Zip,
+    ] {
+        assert_eq!(archive(&src, format), archive(&src, format), "{:?}", format);
+    }
+}
+
+#[test]
+fn tar_zst_decompresses_to_the_tar_stream() {
+    let src = sample();
+    let zst = archive(&src, ArchiveFormat::TarZst);
+    let tar = zstd::decode_all(zst.as_slice()).expect("decode zstd");
+    assert_eq!(tar, archive(&src, ArchiveFormat::Tar));
+}
+
+#[test]
+fn zip_keeps_unix_modes_and_symlinks() {
+    let bytes = archive(&sample(), ArchiveFormat::Zip);
+    let mut zip = zip::ZipArchive::new(std::io::Cursor::new(bytes)).expect("open zip");
+    let names = zip.file_names().map(str::to_string).collect::<Vec<_>>();
+    assert_eq!(names.len(), 5);
+
+    let mut run = zip.by_name("bin/run.sh").expect("run.sh");
+    assert_eq!(run.unix_mode().map(|m| m & 0o777), Some(0o755));
+    let mut body = String::new();
+    run.read_to_string(&mut body).expect("read run.sh");
+    assert_eq!(body, "#!/bin/sh\n");
+    drop(run);
+
+    let link = zip.by_name("link").expect("link");
+    assert!(link.is_symlink());
+    drop(link);
+
+    let mut big = zip.by_name("big.bin").expect("big.bin");
+    let mut body = Vec::new();
+    big.read_to_end(&mut body).expect("read big.bin");
+    assert_eq!(body, b"hello world");
+}
+
+#[test]
+fn superpositions_are_refused() {
+    let mut src = sample();
+    src.manifests.insert(
+        "m-root".into(),
+        Manifest {
+            version: 1,
+            entries: vec![entry(
+                "a.txt",
+                ManifestEntryKind::Superposition { variants: vec![] },
+            )],
+        },
+    );
+    let err = write_archive(&src, &id("m-root"), ArchiveFormat::Tar, Vec::new())
+        .expect_err("superposition");
+    assert!(err.to_string().contains("superposition"));
+}
+
+#[test]
+fn format_parses_cli_names() {
+    assert_eq!("tar".parse::<ArchiveFormat>().unwrap(), ArchiveFormat::Tar);
+    assert_eq!(
+        "tar.zst".parse::<ArchiveFormat>().unwrap(),
+        ArchiveFormat::TarZst
+    );
+    assert_eq!("zip".parse::<ArchiveFormat>().unwrap(), ArchiveFormat::Zip);
+    assert!("rar".parse::<ArchiveFormat>().is_err());
+}
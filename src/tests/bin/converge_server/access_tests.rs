@@ -0,0 +1,194 @@
+use super::*;
+
+fn subject(handle: &str) -> Subject {
+    Subject {
+        user_id: format!("{}-id", handle),
+        user: handle.to_string(),
+        admin: false,
+        token_repos: None,
+        token_capabilities: None,
+        groups: Vec::new(),
+    }
+}
+
+fn grant(handle: &str, role: RepoRole, gate: Option<&str>, lane: Option<&str>) -> RoleGrant {
+    RoleGrant {
+        user_id: format!("{}-id", handle),
+        handle: handle.to_string(),
+        role,
+        group: None,
+        gate: gate.map(str::to_string),
+        lane: lane.map(str::to_string),
+    }
+}
+
+fn repo() -> Repo {
+    serde_json::from_value(serde_json::json!({
+        "id": "test",
+        "owner": "owner",
+        "owner_user_id": "owner-id",
+        "readers": ["legacy"],
+        "publishers": ["legacy"],
+        "lanes": {},
+        "gate_graph": {
+            "version": 1,
+            "gates": [
+                {"id": "dev", "name": "Dev", "upstream": []},
+                {"id": "prod", "name": "Prod", "upstream": ["dev"], "roles": {"release": ["maintainer"]}}
+            ]
+        },
+        "scopes": ["main"],
+        "snaps": [],
+        "publications": [],
+        "bundles": [],
+        "promotions": [],
+        "promotion_state": {}
+    }))
+    .expect("parse repo")
+}
+
+#[test]
+fn owner_and_legacy_publishers_keep_their_rights() {
+    let repo = repo();
+    let owner = subject("owner");
+    assert!(can_release(&repo, &owner, Target::Gate("prod")));
+    assert!(can_manage(&repo, &owner));
+
+    let legacy = subject("legacy");
+    assert!(can_publish(&repo, &legacy, Target::Gate("dev")));
+    assert!(can_converge(&repo, &legacy, Target::Gate("dev")));
+    assert!(can_approve(&repo, &legacy, Target::Gate("dev")));
+    assert!(can_release(&repo, &legacy, Target::Gate("dev")));
+    assert!(!can_release(&repo, &legacy, Target::Gate("prod")));
+    assert!(!can_manage(&repo, &legacy));
+}
+
+#[test]
+fn grants_apply_only_to_their_role_and_target() {
+    let mut repo = repo();
+    repo.role_grants
+        .push(grant("alice", RepoRole::Approver, Some("dev"), None));
+    repo.role_grants
+        .push(grant("bob", RepoRole::Publisher, None, Some("default")));
+
+    let alice = subject("alice");
+    assert!(can_read(&repo, &alice));
+    assert!(can_approve(&repo, &alice, Target::Repo));
+    assert!(can_approve(&repo, &alice, Target::Gate("dev")));
+    assert!(!can_approve(&repo, &alice, Target::Gate("prod")));
+    assert!(!can_converge(&repo, &alice, Target::Gate("dev")));
+
+    let bob = subject("bob");
+    assert!(can_publish(&repo, &bob, Target::Lane("default")));
+    assert!(!can_publish(&repo, &bob, Target::Lane("other")));
+    assert!(!can_publish(&repo, &bob, Target::Gate("dev")));
+
+    assert!(!can_read(&repo, &subject("mallory")));
+}
+
+#[test]
+fn gate_role_overrides_replace_the_default_role() {
+    let mut repo = repo();
+    repo.role_grants
+        .push(grant("rel", RepoRole::Releaser, None, None));
+    repo.role_grants
+        .push(grant("maint", RepoRole::Maintainer, Some("prod"), None));
+
+    let rel = subject("rel");
+    assert!(can_release(&repo, &rel, Target::Gate("dev")));
+    assert!(!can_release(&repo, &rel, Target::Gate("prod")));
+
+    let maint = subject("maint");
+    assert!(can_release(&repo, &maint, Target::Gate("prod")));
+    assert!(!can_release(&repo, &maint, Target::Gate("dev")));
+    // Gate-narrowed maintainers do not administer the repo.
+    assert!(!can_manage(&repo, &maint));
+}
+
+#[test]
+fn group_grants_reach_members_of_nested_groups() {
+    let mut repo = repo();
+    repo.role_grants.push(RoleGrant {
+        user_id: String::new(),
+        handle: "@platform".to_string(),
+        role: RepoRole::Approver,
+        group: Some("platform-id".to_string()),
+        gate: Some("dev".to_string()),
+        lane: None,
+    });
+
+    let mut carol = subject("carol");
+    carol.groups = vec!["platform-id".to_string(), "sre-id".to_string()];
+    assert!(can_read(&repo, &carol));
+    assert!(can_approve(&repo, &carol, Target::Gate("dev")));
+    assert!(!can_approve(&repo, &carol, Target::Gate("prod")));
+
+    // A user whose id happens to be empty must not match group grants.
+    let mut nobody = subject("nobody");
+    nobody.user_id = String::new();
+    assert!(!can_read(&repo, &nobody));
+}
+
+#[test]
+fn lanes_are_visible_to_members_subscribers_and_maintainers() {
+    let mut repo = repo();
+    let lane: Lane = serde_json::from_value(serde_json::json!({
+        "id": "team",
+        "members": ["alice"],
+    }))
+    .expect("parse lane");
+    repo.lanes.insert(lane.id.clone(), lane.clone());
+    repo.lane_subscriptions.push(LaneSubscription {
+        lane: "team".to_string(),
+        user_id: "bob-id".to_string(),
+        handle: "bob".to_string(),
+        scope: Some("main".to_string()),
+        until: None,
+        created_at: "2026-01-01T00:00:00Z".to_string(),
+        created_by: "bob".to_string(),
+    });
+    repo.lane_subscriptions.push(LaneSubscription {
+        lane: "team".to_string(),
+        user_id: "carol-id".to_string(),
+        handle: "carol".to_string(),
+        scope: None,
+        until: Some("2000-01-01T00:00:00Z".to_string()),
+        created_at: "1999-01-01T00:00:00Z".to_string(),
+        created_by: "carol".to_string(),
+    });
+
+    assert!(can_see_lane(&repo, &subject("alice"), &lane, None));
+    assert!(can_see_lane(&repo, &subject("owner"), &lane, None));
+    // Scope-limited subscriptions cover that scope only, and never heads.
+    assert!(can_see_lane(&repo, &subject("bob"), &lane, Some("main")));
+    assert!(!can_see_lane(&repo, &subject("bob"), &lane, Some("other")));
+    assert!(!can_see_lane(&repo, &subject("bob"), &lane, None));
+    // Expired subscriptions do not count.
+    assert!(!can_see_lane(&repo, &subject("carol"), &lane, Some("main")));
+}
+
+#[test]
+fn owning_lanes_restrict_who_operates_a_gate() {
+    let mut repo = repo();
+    let lane: Lane = serde_json::from_value(serde_json::json!({
+        "id": "team",
+        "members": ["alice"],
+    }))
+    .expect("parse lane");
+    repo.lanes.insert(lane.id.clone(), lane);
+    repo.gate_graph.gates[0].lane = Some("team".to_string());
+    repo.role_grants
+        .push(grant("alice", RepoRole::Converger, None, None));
+    repo.role_grants
+        .push(grant("bob", RepoRole::Converger, None, None));
+
+    assert!(can_converge(&repo, &subject("alice"), Target::Gate("dev")));
+    assert!(!can_converge(&repo, &subject("bob"), Target::Gate("dev")));
+    assert!(can_converge(&repo, &subject("bob"), Target::Gate("prod")));
+    // Even the owner needs lane membership, unless they are an admin.
+    let mut owner = subject("owner");
+    assert!(!can_promote(&repo, &owner, Target::Gate("dev")));
+    owner.admin = true;
+    assert!(can_promote(&repo, &owner, Target::Gate("dev")));
+    assert!(!can_approve(&repo, &subject("nobody"), Target::Gate("dev")));
+}
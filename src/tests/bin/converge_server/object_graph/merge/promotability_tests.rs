@@ -5,17 +5,21 @@ fn gate(allow_superpositions: bool, required_approvals: u32) -> GateDef {
         id: "gate".to_string(),
         name: "Gate".to_string(),
         upstream: Vec::new(),
+        lane: None,
         allow_releases: true,
         allow_superpositions,
         allow_metadata_only_publications: false,
         required_approvals,
+        require_signed: false,
+        roles: Default::default(),
+        resolution_rules: Vec::new(),
     }
 }
 
 #[test]
 fn promotability_accepts_when_requirements_are_met() {
     let gate = gate(true, 2);
-    let (promotable, reasons) = compute_promotability(&gate, false, 2);
+    let (promotable, reasons) = compute_promotability(&gate, false, 2, false, false, false);
     assert!(promotable);
     assert!(reasons.is_empty());
 }
@@ -23,7 +27,7 @@ fn promotability_accepts_when_requirements_are_met() {
 #[test]
 fn promotability_rejects_superpositions_when_gate_disallows_them() {
     let gate = gate(false, 0);
-    let (promotable, reasons) = compute_promotability(&gate, true, 0);
+    let (promotable, reasons) = compute_promotability(&gate, true, 0, false, false, false);
     assert!(!promotable);
     assert_eq!(reasons, vec!["superpositions_present".to_string()]);
 }
@@ -31,7 +35,7 @@ fn promotability_rejects_superpositions_when_gate_disallows_them() {
 #[test]
 fn promotability_accumulates_multiple_rejection_reasons() {
     let gate = gate(false, 3);
-    let (promotable, reasons) = compute_promotability(&gate, true, 1);
+    let (promotable, reasons) = compute_promotability(&gate, true, 1, false, false, false);
     assert!(!promotable);
     assert_eq!(
         reasons,
@@ -41,3 +45,27 @@ fn promotability_accumulates_multiple_rejection_reasons() {
         ]
     );
 }
+
+#[test]
+fn promotability_rejects_open_resolution_inputs() {
+    let gate = gate(false, 0);
+    let (promotable, reasons) = compute_promotability(&gate, false, 0, true, false, false);
+    assert!(!promotable);
+    assert_eq!(reasons, vec!["resolutions_open".to_string()]);
+}
+
+#[test]
+fn promotability_rejects_unsigned_inputs() {
+    let gate = gate(false, 0);
+    let (promotable, reasons) = compute_promotability(&gate, false, 0, false, true, false);
+    assert!(!promotable);
+    assert_eq!(reasons, vec!["signatures_missing".to_string()]);
+}
+
+#[test]
+fn promotability_rejects_invalid_rules_file() {
+    let gate = gate(false, 0);
+    let (promotable, reasons) = compute_promotability(&gate, false, 0, false, false, true);
+    assert!(!promotable);
+    assert_eq!(reasons, vec!["rules_invalid".to_string()]);
+}
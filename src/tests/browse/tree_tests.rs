@@ -0,0 +1,145 @@
+use super::*;
+use crate::model::{
+    FileRecipe, FileRecipeChunk, Manifest, ManifestEntry, ManifestEntryKind, ObjectId,
+    SuperpositionVariant, SuperpositionVariantKind,
+};
+use crate::store::LocalStore;
+
+fn file(store: &LocalStore, name: &str, bytes: &[u8]) -> ManifestEntry {
+    ManifestEntry {
+        name: name.to_string(),
+        kind: ManifestEntryKind::File {
+            blob: store.put_blob(bytes).unwrap(),
+            mode: 0o100644,
+            size: bytes.len() as u64,
+        },
+    }
+}
+
+fn dir(store: &LocalStore, name: &str, entries: Vec<ManifestEntry>) -> ManifestEntry {
+    ManifestEntry {
+        name: name.to_string(),
+        kind: ManifestEntryKind::Dir {
+            manifest: store
+                .put_manifest(&Manifest {
+                    version: 1,
+                    entries,
+                })
+                .unwrap(),
+        },
+    }
+}
+
+/// `a.txt`, `src/{lib.rs, big.bin (chunked)}` and a two-variant superposition `conf`.
+fn sample(store: &LocalStore) -> ObjectId {
+    let chunks = [b"hello ".as_slice(), b"chunks".as_slice()];
+    let recipe = store
+        .put_recipe(&FileRecipe {
+            version: 1,
+            size: 12,
+            chunks: chunks
+                .iter()
+                .map(|c| FileRecipeChunk {
+                    blob: store.put_blob(c).unwrap(),
+                    size: c.len() as u32,
+                })
+                .collect(),
+        })
+        .unwrap();
+    let variant = |source: &str, bytes: &[u8]| SuperpositionVariant {
+        source: source.to_string(),
+        kind: SuperpositionVariantKind::File {
+            blob: store.put_blob(bytes).unwrap(),
+            mode: 0o100644,
+            size: bytes.len() as u64,
+        },
+        metadata: None,
+    };
+
+    let src = dir(
+        store,
+        "src",
+        vec![
+            ManifestEntry {
+                name: "big.bin".to_string(),
+                kind: ManifestEntryKind::FileChunks {
+                    recipe,
+                    mode: 0o100644,
+                    size: 12,
+                },
+            },
+            file(store, "lib.rs", b"fn main() {}\n"),
+        ],
+    );
+    let conf = ManifestEntry {
+        name: "conf".to_string(),
+        kind: ManifestEntryKind::Superposition {
+            variants: vec![variant("p1", b"left\n"), variant("p2", b"right\n")],
+        },
+    };
+    store
+        .put_manifest(&Manifest {
+            version: 1,
+            entries: vec![file(store, "a.txt", b"a\n"), conf, src],
+        })
+        .unwrap()
+}
+
+#[test]
+fn list_and_walk_report_full_paths() {
+    let tmp = tempfile::tempdir().unwrap();
+    let store = LocalStore::init(tmp.path(), false).unwrap();
+    let tree = Tree::new(&store, None, sample(&store));
+
+    let root: Vec<String> = tree.list("").unwrap().into_iter().map(|e| e.path).collect();
+    assert_eq!(root, vec!["a.txt", "conf", "src"]);
+
+    let src: Vec<String> = tree
+        .list("/src/")
+        .unwrap()
+        .into_iter()
+        .map(|e| e.path)
+        .collect();
+    assert_eq!(src, vec!["src/big.bin", "src/lib.rs"]);
+
+    // A file path lists just that entry.
+    let one = tree.list("src/lib.rs").unwrap();
+    assert_eq!(one.len(), 1);
+    assert_eq!(one[0].path, "src/lib.rs");
+
+    let all: Vec<String> = tree.walk("").unwrap().into_iter().map(|e| e.path).collect();
+    assert_eq!(
+        all,
+        vec!["a.txt", "conf", "src", "src/big.bin", "src/lib.rs"]
+    );
+
+    assert!(tree.list("missing").is_err());
+    assert!(tree.list("a.txt/x").is_err());
+}
+
+#[test]
+fn read_joins_chunks_and_selects_variants() {
+    let tmp = tempfile::tempdir().unwrap();
+    let store = LocalStore::init(tmp.path(), false).unwrap();
+    let tree = Tree::new(&store, None, sample(&store));
+
+    assert_eq!(tree.read("a.txt").unwrap(), b"a\n");
+    assert_eq!(tree.read("src/big.bin").unwrap(), b"hello chunks");
+
+    let err = tree.read("conf").unwrap_err().to_string();
+    assert!(err.contains("2 variants"), "{}", err);
+    assert_eq!(tree.read_variant("conf", 1).unwrap(), b"left\n");
+    assert_eq!(tree.read_variant("conf", 2).unwrap(), b"right\n");
+    assert!(tree.read_variant("conf", 0).is_err());
+    assert!(tree.read_variant("conf", 3).is_err());
+    assert!(tree.read_variant("a.txt", 1).is_err());
+
+    assert!(tree.read("src").is_err());
+}
+
+#[test]
+fn normalize_path_strips_separators_and_rejects_parent() {
+    assert_eq!(normalize_path("/a//b/./c/").unwrap(), "a/b/c");
+    assert_eq!(normalize_path("").unwrap(), "");
+    assert!(normalize_path("a/../b").is_err());
+}
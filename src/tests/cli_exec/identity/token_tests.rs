@@ -0,0 +1,17 @@
+use super::*;
+
+#[test]
+fn parse_duration_secs_accepts_units() {
+    assert_eq!(parse_duration_secs("90s").unwrap(), 90);
+    assert_eq!(parse_duration_secs("15m").unwrap(), 900);
+    assert_eq!(parse_duration_secs("12h").unwrap(), 43_200);
+    assert_eq!(parse_duration_secs("30d").unwrap(), 2_592_000);
+    assert_eq!(parse_duration_secs("2w").unwrap(), 1_209_600);
+}
+
+#[test]
+fn parse_duration_secs_rejects_bad_input() {
+    for bad in ["", "30", "d", "0d", "3x", "-1d", "1.5h"] {
+        assert!(parse_duration_secs(bad).is_err(), "{}", bad);
+    }
+}
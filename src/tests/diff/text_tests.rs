@@ -0,0 +1,82 @@
+use super::*;
+
+fn render(hunks: &[Hunk]) -> String {
+    let mut out = String::new();
+    for h in hunks {
+        out.push_str(&h.header());
+        out.push('\n');
+        for l in &h.lines {
+            let (sign, text) = match l {
+                HunkLine::Context(t) => (' ', t),
+                HunkLine::Removed(t) => ('-', t),
+                HunkLine::Added(t) => ('+', t),
+            };
+            out.push(sign);
+            out.push_str(text);
+            out.push('\n');
+        }
+    }
+    out
+}
+
+#[test]
+fn identical_texts_have_no_hunks() {
+    assert!(diff_lines("a\nb\n", "a\nb\n", 3).is_empty());
+    assert!(diff_lines("", "", 3).is_empty());
+}
+
+#[test]
+fn single_change_is_wrapped_in_context() {
+    let old = "1\n2\n3\n4\n5\n6\n7\n";
+    let new = "1\n2\n3\nfour\n5\n6\n7\n";
+    let hunks = diff_lines(old, new, 1);
+    assert_eq!(render(&hunks), "@@ -3,3 +3,3 @@\n 3\n-4\n+four\n 5\n");
+}
+
+#[test]
+fn distant_changes_split_into_hunks_and_near_ones_merge() {
+    let text = |changed: &[usize]| -> String {
+        (1..=20)
+            .map(|i| {
+                if changed.contains(&i) {
+                    format!("line {} changed\n", i)
+                } else {
+                    format!("line {}\n", i)
+                }
+            })
+            .collect()
+    };
+    let old = text(&[]);
+    let hunks = diff_lines(&old, &text(&[2, 18]), 2);
+    assert_eq!(hunks.len(), 2);
+    assert_eq!(hunks[0].header(), "@@ -1,4 +1,4 @@");
+    assert_eq!(hunks[1].header(), "@@ -16,5 +16,5 @@");
+
+    assert_eq!(diff_lines(&old, &text(&[5, 9]), 2).len(), 1);
+}
+
+#[test]
+fn added_and_deleted_files_use_zero_start() {
+    assert_eq!(
+        render(&diff_lines("", "a\nb\n", 3)),
+        "@@ -0,0 +1,2 @@\n+a\n+b\n"
+    );
+    assert_eq!(render(&diff_lines("a\n", "", 3)), "@@ -1,1 +0,0 @@\n-a\n");
+}
+
+#[test]
+fn large_fully_changed_file_falls_back_to_one_replacement() {
+    let old: String = (0..50_000).map(|i| format!("old {}\n", i)).collect();
+    let new: String = (0..50_000).map(|i| format!("new {}\n", i)).collect();
+    let hunks = diff_lines(&old, &new, 3);
+    assert_eq!(hunks.len(), 1);
+    assert_eq!(hunks[0].header(), "@@ -1,50000 +1,50000 @@");
+    assert!(matches!(hunks[0].lines[0], HunkLine::Removed(_)));
+    assert!(matches!(hunks[0].lines[50_000], HunkLine::Added(_)));
+
+    // The unchanged ends still frame the replacement as context.
+    let framed = |body: &str| format!("head\n{}tail\n", body);
+    let hunks = diff_lines(&framed(&old), &framed(&new), 1);
+    assert_eq!(hunks[0].header(), "@@ -1,50002 +1,50002 @@");
+    assert_eq!(hunks[0].lines[0], HunkLine::Context("head".to_string()));
+}
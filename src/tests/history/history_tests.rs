@@ -0,0 +1,117 @@
+use super::*;
+use crate::model::{Manifest, ManifestEntry, ManifestEntryKind, SnapRecord, SnapStats};
+use crate::store::LocalStore;
+
+/// Stores a snap whose root holds `files`, created at minute `n`.
+fn snap(store: &LocalStore, n: u32, files: &[(&str, &str)], parents: &[&str]) -> String {
+    let entries = files
+        .iter()
+        .map(|(name, text)| ManifestEntry {
+            name: name.to_string(),
+            kind: ManifestEntryKind::File {
+                blob: store.put_blob(text.as_bytes()).unwrap(),
+                mode: 0o100644,
+                size: text.len() as u64,
+            },
+        })
+        .collect();
+    let root = store
+        .put_manifest(&Manifest {
+            version: 1,
+            entries,
+        })
+        .unwrap();
+    let record = SnapRecord {
+        version: 1,
+        id: format!("snap{}", n),
+        created_at: format!("2026-01-01T00:{:02}:00Z", n),
+        root_manifest: root,
+        message: Some(format!("change {}", n)),
+        stats: SnapStats::default(),
+        parents: parents.iter().map(|p| p.to_string()).collect(),
+        author: None,
+    };
+    store.put_snap(&record).unwrap();
+    record.id
+}
+
+#[test]
+fn history_lists_only_snaps_that_change_the_path() {
+    let tmp = tempfile::tempdir().unwrap();
+    let store = LocalStore::init(tmp.path(), false).unwrap();
+    snap(&store, 1, &[("a.txt", "a\n")], &[]);
+    snap(&store, 2, &[("a.txt", "a\n"), ("b.txt", "b\n")], &[]);
+    snap(&store, 3, &[("a.txt", "a2\n"), ("b.txt", "b\n")], &[]);
+    snap(&store, 4, &[("b.txt", "b\n")], &[]);
+
+    let history = path_history(&store, None, "a.txt").unwrap().entries;
+    let got: Vec<(&str, PathChange)> = history.iter().map(|e| (e.id.as_str(), e.change)).collect();
+    assert_eq!(
+        got,
+        vec![
+            ("snap4", PathChange::Deleted),
+            ("snap3", PathChange::Modified),
+            ("snap1", PathChange::Added),
+        ]
+    );
+    assert_eq!(history[1].detail.as_deref(), Some("change 3"));
+    assert!(
+        path_history(&store, None, "nope")
+            .unwrap()
+            .entries
+            .is_empty()
+    );
+}
+
+#[test]
+fn snap_chain_prefers_recorded_parents() {
+    let tmp = tempfile::tempdir().unwrap();
+    let store = LocalStore::init(tmp.path(), false).unwrap();
+    snap(&store, 1, &[("a", "1")], &[]);
+    snap(&store, 2, &[("a", "2")], &["snap1"]);
+    snap(&store, 3, &[("a", "3")], &["snap1"]);
+
+    let ids: Vec<String> = snap_chain(&store, "snap3")
+        .unwrap()
+        .into_iter()
+        .map(|s| s.id)
+        .collect();
+    assert_eq!(ids, vec!["snap1", "snap3"]);
+    assert!(snap_chain(&store, "missing").is_err());
+}
+
+#[test]
+fn blame_attributes_lines_and_reuses_reverted_content() {
+    let tmp = tempfile::tempdir().unwrap();
+    let store = LocalStore::init(tmp.path(), false).unwrap();
+    snap(&store, 1, &[("f", "one\ntwo\n")], &[]);
+    snap(&store, 2, &[("f", "one\n2\nthree\n")], &[]);
+    snap(&store, 3, &[("f", "one\ntwo\n")], &[]);
+
+    let origin_of = |b: &Blame| {
+        b.lines
+            .iter()
+            .map(|l| (b.origins[l.origin].snap_id.clone(), l.text.clone()))
+            .collect::<Vec<_>>()
+    };
+    let pair = |s: &str, t: &str| (s.to_string(), t.to_string());
+
+    let b2 = blame_path(&store, None, "snap2", "f").unwrap();
+    assert_eq!(
+        origin_of(&b2),
+        vec![
+            pair("snap1", "one"),
+            pair("snap2", "2"),
+            pair("snap2", "three")
+        ]
+    );
+
+    // Reverting to snap1's blob restores snap1's attribution.
+    let b3 = blame_path(&store, None, "snap3", "f").unwrap();
+    assert_eq!(
+        origin_of(&b3),
+        vec![pair("snap1", "one"), pair("snap1", "two")]
+    );
+
+    assert!(blame_path(&store, None, "snap3", "missing").is_err());
+}
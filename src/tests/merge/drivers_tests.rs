@@ -0,0 +1,141 @@
+use super::*;
+
+use crate::model::MergeDriverRule;
+
+fn merge(driver: MergeDriver, base: &str, inputs: &[&str]) -> StructuredMerge {
+    let inputs = inputs.iter().map(|s| s.as_bytes()).collect::<Vec<_>>();
+    driver
+        .merge(Some(base.as_bytes()), &inputs)
+        .expect("structured merge")
+}
+
+#[test]
+fn json_disjoint_key_edits_merge_cleanly() {
+    let out = merge(
+        MergeDriver::Json,
+        r#"{"a": 1, "b": {"c": 2, "d": 3}}"#,
+        &[
+            r#"{"a": 10, "b": {"c": 2, "d": 3}}"#,
+            r#"{"a": 1, "b": {"c": 2, "d": 30}, "e": true}"#,
+        ],
+    );
+    assert!(out.conflicts.is_empty());
+    let merged: serde_json::Value =
+        serde_json::from_slice(&out.merged.expect("merged")).expect("parse merged");
+    assert_eq!(
+        merged,
+        serde_json::json!({"a": 10, "b": {"c": 2, "d": 30}, "e": true})
+    );
+}
+
+#[test]
+fn json_same_key_edits_conflict_at_key_path() {
+    let out = merge(
+        MergeDriver::Json,
+        r#"{"server": {"port": 80}, "name": "x"}"#,
+        &[
+            r#"{"server": {"port": 8080}, "name": "x"}"#,
+            r#"{"server": {"port": 9090}, "name": "y"}"#,
+        ],
+    );
+    assert!(out.merged.is_none());
+    assert_eq!(
+        out.conflicts,
+        vec![KeyConflict {
+            path: "server.port".to_string(),
+            inputs: vec![0, 1],
+        }]
+    );
+}
+
+#[test]
+fn identical_changes_do_not_conflict() {
+    let out = merge(
+        MergeDriver::Json,
+        r#"{"v": 1}"#,
+        &[r#"{"v": 2}"#, r#"{"v": 2}"#],
+    );
+    assert!(out.conflicts.is_empty());
+}
+
+#[test]
+fn toml_tables_merge_key_by_key() {
+    let out = merge(
+        MergeDriver::Toml,
+        "[package]\nname = \"x\"\nversion = \"1.0.0\"\n",
+        &[
+            "[package]\nname = \"x\"\nversion = \"1.1.0\"\n",
+            "[package]\nname = \"x\"\nversion = \"1.0.0\"\nedition = \"2021\"\n",
+        ],
+    );
+    assert!(out.conflicts.is_empty());
+    let text = String::from_utf8(out.merged.expect("merged")).expect("utf8");
+    assert!(text.contains("version = \"1.1.0\""));
+    assert!(text.contains("edition = \"2021\""));
+}
+
+#[test]
+fn yaml_reports_conflicting_keys() {
+    let out = merge(
+        MergeDriver::Yaml,
+        "replicas: 1\nimage: app:1\n",
+        &["replicas: 2\nimage: app:1\n", "replicas: 3\nimage: app:2\n"],
+    );
+    assert_eq!(out.conflicts.len(), 1);
+    assert_eq!(out.conflicts[0].path, "replicas");
+}
+
+#[test]
+fn unparseable_input_is_an_error() {
+    let inputs: [&[u8]; 2] = [b"{", b"{}"];
+    assert!(MergeDriver::Json.merge(None, &inputs).is_err());
+}
+
+#[test]
+fn commented_toml_and_yaml_are_not_merged_structurally() {
+    let toml: [&[u8]; 2] = [b"# owner: ops\nport = 1\n", b"port = 2\n"];
+    assert!(MergeDriver::Toml.merge(None, &toml).is_err());
+    let yaml: [&[u8]; 2] = [b"replicas: 2 # keep low\n", b"replicas: 3\n"];
+    assert!(MergeDriver::Yaml.merge(None, &yaml).is_err());
+    let yaml: [&[u8]; 2] = [b"name: it's on # pinned\n", b"name: off\n"];
+    assert!(MergeDriver::Yaml.merge(None, &yaml).is_err());
+
+    // `#` inside a quoted string is not a comment.
+    let out = merge(
+        MergeDriver::Toml,
+        "color = \"#fff\"\nport = 1\n",
+        &[
+            "color = \"#fff\"\nport = 2\n",
+            "color = \"#000\"\nport = 1\n",
+        ],
+    );
+    assert!(out.conflicts.is_empty());
+}
+
+#[test]
+fn registry_prefers_rules_over_extensions() {
+    let registry = MergeDriverRegistry::new(&[
+        MergeDriverRule {
+            path: "vendor/**".to_string(),
+            driver: "none".to_string(),
+        },
+        MergeDriverRule {
+            path: "*.conf".to_string(),
+            driver: "toml".to_string(),
+        },
+    ])
+    .expect("registry");
+    assert_eq!(registry.driver_for("vendor/pkg.json"), None);
+    assert_eq!(registry.driver_for("app.conf"), Some(MergeDriver::Toml));
+    assert_eq!(registry.driver_for("cfg/app.yml"), Some(MergeDriver::Yaml));
+    assert_eq!(registry.driver_for("README.md"), None);
+}
+
+#[test]
+fn registry_rejects_unknown_driver() {
+    let err = MergeDriverRegistry::new(&[MergeDriverRule {
+        path: "*.ini".to_string(),
+        driver: "ini".to_string(),
+    }]);
+    assert!(err.is_err());
+}
@@ -0,0 +1,43 @@
+use super::*;
+
+#[test]
+fn segments_split_common_and_conflicting_runs() {
+    let segments = line_segments("a\nb\nc\nd\n", "a\nB\nc\nd\ne\n");
+    assert_eq!(
+        segments,
+        vec![
+            LineSegment::Common(vec!["a".to_string()]),
+            LineSegment::Conflict {
+                left: vec!["b".to_string()],
+                right: vec!["B".to_string()],
+            },
+            LineSegment::Common(vec!["c".to_string(), "d".to_string()]),
+            LineSegment::Conflict {
+                left: vec![],
+                right: vec!["e".to_string()],
+            },
+        ]
+    );
+}
+
+#[test]
+fn merged_text_applies_choices_and_marks_open_conflicts() {
+    let segments = line_segments("a\nb\nc\nd\n", "a\nB\nc\nd\ne\n");
+    let labels = ("ours", "theirs");
+
+    let both = merged_text(
+        &segments,
+        &[Some(ConflictChoice::Both), Some(ConflictChoice::Right)],
+        labels,
+        true,
+    );
+    assert_eq!(both, "a\nb\nB\nc\nd\ne\n");
+    assert!(!has_conflict_markers(&both));
+
+    let open = merged_text(&segments, &[Some(ConflictChoice::Left), None], labels, true);
+    assert_eq!(
+        open,
+        "a\nb\nc\nd\n<<<<<<< ours\n=======\ne\n>>>>>>> theirs\n"
+    );
+    assert!(has_conflict_markers(&open));
+}
@@ -0,0 +1,119 @@
+use super::*;
+use crate::model::ObjectId;
+
+fn file_variant(source: &str, blob: &str) -> SuperpositionVariant {
+    SuperpositionVariant {
+        source: source.to_string(),
+        kind: SuperpositionVariantKind::File {
+            blob: ObjectId(blob.to_string()),
+            mode: 0o100644,
+            size: 1,
+        },
+        metadata: None,
+    }
+}
+
+fn origin(created_at: &str, lanes: &[&str]) -> VariantOrigin {
+    VariantOrigin {
+        created_at: created_at.to_string(),
+        lanes: lanes.iter().map(|l| l.to_string()).collect(),
+    }
+}
+
+fn origins() -> BTreeMap<String, VariantOrigin> {
+    BTreeMap::from([
+        ("p1".to_string(), origin("2026-01-01T00:00:02Z", &["art"])),
+        ("p2".to_string(), origin("2026-01-01T00:00:05Z", &["code"])),
+    ])
+}
+
+#[test]
+fn matching_rule_uses_first_match_and_respects_separators() {
+    let rules: ResolutionRules = serde_json::from_value(serde_json::json!({
+        "version": 1,
+        "rules": [
+            { "name": "root-locks", "path": "*.lock", "strategy": "prefer-newest" },
+            { "name": "assets", "path": "assets/**", "strategy": "prefer-source-lane", "lane": "art" },
+            { "name": "all", "path": "**", "strategy": "take-tombstone" }
+        ]
+    }))
+    .unwrap();
+
+    let name = |p: &str| {
+        matching_rule(&rules.rules, p)
+            .unwrap()
+            .map(|r| r.name.clone())
+    };
+    assert_eq!(name("Cargo.lock").as_deref(), Some("root-locks"));
+    assert_eq!(name("sub/Cargo.lock").as_deref(), Some("all"));
+    assert_eq!(name("assets/a/b.png").as_deref(), Some("assets"));
+}
+
+#[test]
+fn choose_variant_applies_each_strategy() {
+    let variants = vec![
+        file_variant("p1", "b1"),
+        file_variant("p2", "b2"),
+        SuperpositionVariant {
+            source: "p3".to_string(),
+            kind: SuperpositionVariantKind::Tombstone,
+            metadata: None,
+        },
+    ];
+    let origins = origins();
+
+    assert_eq!(
+        choose_variant(&ResolutionStrategy::PreferNewest, &variants, &origins),
+        Some(1)
+    );
+    assert_eq!(
+        choose_variant(
+            &ResolutionStrategy::PreferSourceLane {
+                lane: "art".to_string()
+            },
+            &variants,
+            &origins
+        ),
+        Some(0)
+    );
+    assert_eq!(
+        choose_variant(
+            &ResolutionStrategy::PreferSourceLane {
+                lane: "docs".to_string()
+            },
+            &variants,
+            &origins
+        ),
+        None
+    );
+    assert_eq!(
+        choose_variant(&ResolutionStrategy::TakeTombstone, &variants, &origins),
+        Some(2)
+    );
+    assert_eq!(
+        choose_variant(&ResolutionStrategy::UnionLines, &variants, &origins),
+        None
+    );
+}
+
+#[test]
+fn union_lines_keeps_first_appearance_order() {
+    let out = union_lines(&[b"a\nshared\n".to_vec(), b"shared\nb\n".to_vec()]);
+    assert_eq!(out, b"a\nshared\nb\n");
+}
+
+#[test]
+fn parse_resolution_rules_rejects_bad_globs_and_versions() {
+    let err = parse_resolution_rules(br#"{"version":2,"rules":[]}"#).unwrap_err();
+    assert!(err.to_string().contains("unsupported"), "{}", err);
+
+    let err = parse_resolution_rules(
+        br#"{"version":1,"rules":[{"name":"x","path":"[","strategy":"prefer-newest"}]}"#,
+    )
+    .unwrap_err();
+    assert!(
+        format!("{:#}", err).contains("invalid path glob"),
+        "{:#}",
+        err
+    );
+}
@@ -0,0 +1,68 @@
+use super::*;
+
+#[test]
+fn canonical_json_sorts_keys_recursively() {
+    let value = serde_json::json!({"b": 1, "a": {"d": [{"z": 0, "y": 1}], "c": null}});
+    assert_eq!(
+        String::from_utf8(canonical_json(&value)).unwrap(),
+        r#"{"a":{"c":null,"d":[{"y":1,"z":0}]},"b":1}"#
+    );
+}
+
+#[test]
+fn signatures_verify_only_for_the_signed_payload() {
+    let key = SigningKey::generate().unwrap();
+    let payload = publication_payload("repo", "main", "dev", "snap1");
+    let sig = key.sign(&payload);
+    assert_eq!(sig.key_id, key.key_id());
+    verify(&key.public_key_hex(), &sig, &payload).unwrap();
+
+    let other = publication_payload("repo", "main", "dev", "snap2");
+    assert!(verify(&key.public_key_hex(), &sig, &other).is_err());
+
+    let stranger = SigningKey::generate().unwrap();
+    assert!(verify(&stranger.public_key_hex(), &sig, &payload).is_err());
+}
+
+#[test]
+fn key_registration_proof_is_bound_to_the_user() {
+    let key = SigningKey::generate().unwrap();
+    let proof = key.sign(&key_registration_payload("u1", &key.public_key_hex()));
+    let public_key = key.public_key_hex().to_ascii_uppercase();
+    verify(
+        &key.public_key_hex(),
+        &proof,
+        &key_registration_payload("u1", &public_key),
+    )
+    .unwrap();
+    assert!(
+        verify(
+            &key.public_key_hex(),
+            &proof,
+            &key_registration_payload("u2", &public_key)
+        )
+        .is_err()
+    );
+}
+
+#[test]
+fn keys_round_trip_through_disk() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("keys/signing.json");
+    let key = SigningKey::generate().unwrap();
+    key.save(&path).unwrap();
+    assert!(
+        key.save(&path).is_err(),
+        "existing key files are not replaced"
+    );
+
+    let loaded = SigningKey::load(&path).unwrap();
+    assert_eq!(loaded.public_key_hex(), key.public_key_hex());
+    assert_eq!(public_key_id(&key.public_key_hex()).unwrap(), key.key_id());
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let mode = std::fs::metadata(&path).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o600);
+    }
+}
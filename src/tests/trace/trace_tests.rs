@@ -0,0 +1,193 @@
+use super::*;
+
+fn line(v: Option<u32>, secs: u32, event: &str, payload: Value) -> String {
+    let mut record = serde_json::json!({
+        "seq": secs,
+        "ts": format!("2026-01-01T00:{:02}:{:02}Z", secs / 60, secs % 60),
+        "event": event,
+        "payload": payload,
+    });
+    if let Some(v) = v {
+        record["v"] = v.into();
+    }
+    record.to_string()
+}
+
+fn screen(v: Option<u32>, secs: u32, mode: &str, modal: Option<&str>) -> String {
+    let mut payload = serde_json::json!({
+        "screen_id": format!("local:{}", mode),
+        "title": mode,
+        "mode": mode,
+        "has_modal": modal.is_some(),
+    });
+    if v.is_some()
+        && let Some(title) = modal
+    {
+        payload["modal_title"] = title.into();
+    }
+    line(v, secs, "screen_view", payload)
+}
+
+fn key(v: Option<u32>, secs: u32, mode: &str, key: &str) -> String {
+    line(
+        v,
+        secs,
+        "user_action",
+        serde_json::json!({"source": "keyboard", "action": "key_press", "key": key, "mode": mode}),
+    )
+}
+
+fn command(v: Option<u32>, secs: u32, mode: &str, raw: &str) -> String {
+    line(
+        v,
+        secs,
+        "user_action",
+        serde_json::json!({
+            "source": "command_input",
+            "action": "command_submitted",
+            "raw_input": raw,
+            "command": raw.split_whitespace().next().unwrap_or(""),
+            "mode": mode,
+        }),
+    )
+}
+
+fn error(v: Option<u32>, secs: u32, mode: &str, message: &str) -> String {
+    line(
+        v,
+        secs,
+        "validation_error",
+        serde_json::json!({"message": message, "mode": mode}),
+    )
+}
+
+#[test]
+fn records_round_trip_and_tolerate_missing_and_unknown_fields() {
+    let event = TraceEvent::UserAction(UserAction {
+        source: "keyboard".to_string(),
+        action: "key_press".to_string(),
+        key: Some("alt+n".to_string()),
+        mode: "diff".to_string(),
+        ..UserAction::default()
+    });
+    let record = TraceRecord::new(7, "2026-01-01T00:00:00Z".to_string(), &event);
+    let text = serde_json::to_string(&record).unwrap();
+    assert!(text.contains("\"v\":1"), "{}", text);
+    assert!(!text.contains("raw_input"), "{}", text);
+
+    let back: TraceRecord = serde_json::from_str(&text).unwrap();
+    let TraceEvent::UserAction(u) = back.event() else {
+        panic!("expected user_action");
+    };
+    assert_eq!(u.key.as_deref(), Some("alt+n"));
+    assert_eq!(u.mode, "diff");
+
+    let trace = parse_trace(&format!(
+        "{}\nnot json\n\n{}\n{}\n",
+        r#"{"seq":1,"ts":"x","event":"screen_view","payload":{"mode":"root","extra":[1]}}"#,
+        r#"{"v":9,"seq":2,"event":"hover","payload":{"x":1}}"#,
+        r#"{"seq":3,"event":"system_error","payload":"oops"}"#,
+    ));
+    assert_eq!(trace.records.len(), 3);
+    assert_eq!(trace.skipped_lines, 1);
+    assert_eq!(trace.records[0].v, 0);
+    assert!(matches!(trace.records[0].event(), TraceEvent::ScreenView(s) if s.mode == "root"));
+    assert!(matches!(trace.records[1].event(), TraceEvent::Unknown));
+    assert!(matches!(trace.records[2].event(), TraceEvent::SystemError(e) if e.message.is_empty()));
+    assert!(trace.records[0].at().is_none());
+}
+
+#[test]
+fn report_measures_dwell_errors_abandoned_wizards_and_loops() {
+    let v = Some(SCHEMA_VERSION);
+    let lines = [
+        line(v, 0, "session_start", serde_json::json!({"mode": "root"})),
+        screen(v, 0, "root", None),
+        command(v, 5, "root", "publish"),
+        screen(v, 5, "root", Some("Publish")),
+        line(
+            v,
+            8,
+            "modal_cancelled",
+            serde_json::json!({"title": "Publish", "prompt": "publish>", "mode": "root"}),
+        ),
+        screen(v, 8, "root", None),
+        command(v, 10, "root", "history"),
+        screen(v, 10, "snaps", None),
+        command(v, 20, "snaps", "filter x y"),
+        error(v, 20, "snaps", "invalid filter"),
+        command(v, 21, "snaps", "filter x y"),
+        error(v, 21, "snaps", "invalid filter"),
+        command(v, 22, "snaps", "filter x y"),
+        command(v, 30, "snaps", "restore"),
+        screen(v, 30, "snaps", Some("Restore")),
+        line(v, 70, "session_end", serde_json::json!({"reason": "quit"})),
+    ];
+    let report = friction_report(&parse_trace(&lines.join("\n")));
+
+    assert_eq!(report.schema_versions, vec![SCHEMA_VERSION]);
+    assert_eq!(report.sessions, 1);
+    assert_eq!(report.duration_ms, 70_000);
+
+    let modes: Vec<(&str, u64)> = report
+        .modes
+        .iter()
+        .map(|m| (m.mode.as_str(), m.dwell_ms))
+        .collect();
+    assert_eq!(modes, vec![("snaps", 60_000), ("root", 10_000)]);
+    let snaps = &report.modes[0];
+    assert_eq!(snaps.commands, 4);
+    assert_eq!(snaps.validation_errors, 2);
+    assert_eq!(snaps.error_rate, Some(0.5));
+    assert_eq!(report.modes[1].error_rate, Some(0.0));
+
+    let abandoned: Vec<(&str, Option<&str>, usize)> = report
+        .abandoned_wizards
+        .iter()
+        .map(|a| (a.title.as_str(), a.prompt.as_deref(), a.count))
+        .collect();
+    assert!(abandoned.contains(&("Publish", Some("publish>"), 1)));
+    assert!(abandoned.contains(&("Restore", None, 1)));
+
+    assert_eq!(report.command_loops.len(), 1);
+    assert_eq!(report.command_loops[0].command, "filter x y");
+    assert_eq!(report.command_loops[0].count, 3);
+    assert_eq!(report.command_loops[0].errors, 2);
+    assert_eq!(report.error_loops.len(), 1);
+    assert_eq!(report.error_loops[0].count, 2);
+}
+
+#[test]
+fn report_reads_unversioned_traces_and_appended_sessions() {
+    let lines = [
+        line(
+            None,
+            0,
+            "session_start",
+            serde_json::json!({"mode": "root"}),
+        ),
+        screen(None, 0, "root", Some("ignored")),
+        key(None, 4, "root", "esc"),
+        screen(None, 4, "root", None),
+        key(None, 6, "root", "esc"),
+        // No session_end: the next session starts much later.
+        line(
+            None,
+            600,
+            "session_start",
+            serde_json::json!({"mode": "root"}),
+        ),
+        screen(None, 600, "root", None),
+        line(None, 602, "session_end", serde_json::json!({})),
+    ];
+    let report = friction_report(&parse_trace(&lines.join("\n")));
+
+    assert_eq!(report.schema_versions, vec![0]);
+    assert_eq!(report.sessions, 2);
+    assert_eq!(report.modes.len(), 1);
+    assert_eq!(report.modes[0].dwell_ms, 8_000);
+    assert_eq!(report.modes[0].key_presses, 2);
+    assert_eq!(report.modes[0].error_rate, None);
+    assert_eq!(report.abandoned_wizards.len(), 1);
+    assert_eq!(report.abandoned_wizards[0].title, "modal on root");
+}
@@ -0,0 +1,78 @@
+use crossterm::event::{KeyCode, KeyModifiers, MouseButton, MouseEventKind};
+
+use super::*;
+
+#[test]
+fn parses_steps_and_skips_comments() {
+    let steps = parse_script(
+        "# setup\n\n{\"key\":\"alt+n\"}\n{\"keys\":[\"esc\",\"q\"]}\n{\"type\":\"snap\"}\n\
+         {\"command\":\"history\"}\n{\"expect\":{\"mode\":\"snaps\"}}\n{\"frame\":true}\n",
+    )
+    .unwrap();
+    let lines: Vec<usize> = steps.iter().map(|(l, _)| *l).collect();
+    assert_eq!(lines, vec![3, 4, 5, 6, 7, 8]);
+
+    let Step::Keys(keys) = &steps[0].1 else {
+        panic!("expected keys, got {:?}", steps[0].1);
+    };
+    assert_eq!(keys[0].code, KeyCode::Char('n'));
+    assert_eq!(keys[0].modifiers, KeyModifiers::ALT);
+    assert!(matches!(&steps[1].1, Step::Keys(k) if k.len() == 2));
+    assert!(matches!(&steps[2].1, Step::Type(t) if t == "snap"));
+    assert!(matches!(&steps[3].1, Step::Command(c) if c == "history"));
+    assert!(matches!(steps[4].1, Step::Expect(_)));
+    assert!(matches!(steps[5].1, Step::Frame));
+}
+
+#[test]
+fn rejects_ambiguous_unknown_and_malformed_steps() {
+    let err = parse_script("{\"key\":\"esc\",\"command\":\"quit\"}").unwrap_err();
+    assert!(format!("{:#}", err).contains("exactly one"), "{:#}", err);
+    let err = parse_script("{}\n{\"key\":\"hyper+x\"}").unwrap_err();
+    assert!(format!("{:#}", err).contains("line 1"), "{:#}", err);
+    let err = parse_script("{\"key\":\"hyper+x\"}").unwrap_err();
+    assert!(format!("{:#}", err).contains("unknown key"), "{:#}", err);
+    let err = parse_script("{\"expect\":{\"moed\":\"root\"}}").unwrap_err();
+    assert!(format!("{:#}", err).contains("moed"), "{:#}", err);
+}
+
+#[test]
+fn replays_only_key_presses_from_agent_traces() {
+    let steps = parse_script(concat!(
+        r#"{"seq":1,"event":"session_start","payload":{}}"#,
+        "\n",
+        r#"{"seq":2,"event":"user_action","payload":{"action":"key_press","key":" "}}"#,
+        "\n",
+        r#"{"seq":3,"event":"user_action","payload":{"action":"key_press","key":"ctrl+u"}}"#,
+        "\n",
+        r#"{"seq":4,"event":"command_submitted","payload":{"raw_input":"snap"}}"#,
+    ))
+    .unwrap();
+    assert_eq!(steps.len(), 2);
+    assert!(matches!(&steps[0].1, Step::Keys(k) if k[0].code == KeyCode::Char(' ')));
+    assert!(matches!(&steps[1].1, Step::Keys(k) if k[0].modifiers == KeyModifiers::CONTROL));
+}
+
+#[test]
+fn parses_mouse_steps() {
+    let steps = parse_script(
+        "{\"mouse\":\"click 3 4\"}\n{\"mouse\":\"scroll-down 0 9\"}\n{\"mouse\":\"drag 1 2\"}",
+    )
+    .unwrap();
+    let Step::Mouse(click) = &steps[0].1 else {
+        panic!("expected mouse, got {:?}", steps[0].1);
+    };
+    assert_eq!(click.len(), 2);
+    assert_eq!((click[0].column, click[0].row), (3, 4));
+    assert_eq!(click[0].kind, MouseEventKind::Down(MouseButton::Left));
+    assert_eq!(click[1].kind, MouseEventKind::Up(MouseButton::Left));
+    assert!(matches!(&steps[1].1, Step::Mouse(m) if m[0].kind == MouseEventKind::ScrollDown));
+    assert!(
+        matches!(&steps[2].1, Step::Mouse(m) if m[0].kind == MouseEventKind::Drag(MouseButton::Left))
+    );
+
+    let err = parse_script("{\"mouse\":\"hover 1 2\"}").unwrap_err();
+    assert!(format!("{:#}", err).contains("unknown kind"), "{:#}", err);
+    let err = parse_script("{\"mouse\":\"click 1\"}").unwrap_err();
+    assert!(format!("{:#}", err).contains("<kind>"), "{:#}", err);
+}
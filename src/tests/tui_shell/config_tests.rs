@@ -0,0 +1,159 @@
+use super::*;
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use ratatui::style::Color;
+
+fn layer(name: &str, toml: &str) -> (PathBuf, String) {
+    (PathBuf::from(name), toml.to_string())
+}
+
+fn key(code: KeyCode, mods: KeyModifiers) -> KeyEvent {
+    KeyEvent::new(code, mods)
+}
+
+#[test]
+fn defaults_match_the_built_in_bindings() {
+    let cfg = TuiConfig::from_layers(&[]).unwrap();
+    assert_eq!(cfg.theme, Theme::default());
+
+    let km = &cfg.keymap;
+    let alt_n = key(KeyCode::Char('n'), KeyModifiers::ALT);
+    assert_eq!(
+        km.lookup(KeyScope::Diff, alt_n, false),
+        Some(KeyAction::NextHunk)
+    );
+    assert_eq!(
+        km.lookup(KeyScope::Merge, alt_n, false),
+        Some(KeyAction::NextConflict)
+    );
+    // Mode scopes fall back to global bindings.
+    assert_eq!(
+        km.lookup(KeyScope::Diff, key(KeyCode::Esc, KeyModifiers::NONE), false),
+        Some(KeyAction::Back)
+    );
+    assert_eq!(
+        km.lookup(
+            KeyScope::Merge,
+            key(KeyCode::Down, KeyModifiers::ALT),
+            false
+        ),
+        Some(KeyAction::GrowPane)
+    );
+    // Typed characters are left to the input while it has text.
+    let q = key(KeyCode::Char('q'), KeyModifiers::NONE);
+    assert_eq!(km.lookup(KeyScope::Global, q, false), Some(KeyAction::Quit));
+    assert_eq!(km.lookup(KeyScope::Global, q, true), None);
+}
+
+#[test]
+fn workspace_layer_overrides_user_layer() {
+    let cfg = TuiConfig::from_layers(&[
+        layer(
+            "user.toml",
+            r##"
+[theme]
+preset = "light"
+warning = "light-red"
+
+[keys.diff]
+next-hunk = "alt+j"
+
+[keys.global]
+down = ["down", "j"]
+"##,
+        ),
+        layer(
+            "ws.toml",
+            r##"
+[theme]
+added = "#00ff00"
+
+[keys.diff]
+next-hunk = ["ctrl+n", "J"]
+"##,
+        ),
+    ])
+    .unwrap();
+
+    let light = Theme::preset("light").unwrap();
+    assert_eq!(cfg.theme.accent, light.accent);
+    assert_eq!(cfg.theme.warning, Color::LightRed);
+    assert_eq!(cfg.theme.added, Color::Rgb(0, 0xff, 0));
+
+    let km = &cfg.keymap;
+    assert_eq!(
+        km.lookup(
+            KeyScope::Diff,
+            key(KeyCode::Char('n'), KeyModifiers::CONTROL),
+            true
+        ),
+        Some(KeyAction::NextHunk)
+    );
+    assert_eq!(
+        km.lookup(
+            KeyScope::Diff,
+            key(KeyCode::Char('J'), KeyModifiers::SHIFT),
+            false
+        ),
+        Some(KeyAction::NextHunk)
+    );
+    assert_eq!(
+        km.lookup(
+            KeyScope::Diff,
+            key(KeyCode::Char('j'), KeyModifiers::ALT),
+            false
+        ),
+        None
+    );
+    assert_eq!(
+        km.lookup(
+            KeyScope::Superpositions,
+            key(KeyCode::Char('j'), KeyModifiers::NONE),
+            false
+        ),
+        Some(KeyAction::Down)
+    );
+}
+
+#[test]
+fn every_problem_is_reported_with_its_file() {
+    let err = TuiConfig::from_layers(&[
+        layer(
+            "user.toml",
+            r#"
+[theme]
+preset = "solarized"
+shiny = "red"
+added = "not-a-color"
+
+[keys.nope]
+x = "a"
+
+[keys.diff]
+jump = "alt+j"
+layout = "hyper+x"
+prev-hunk = "alt+n"
+"#,
+        ),
+        layer("ws.toml", "colours = 1\n"),
+    ])
+    .unwrap_err()
+    .to_string();
+
+    for expected in [
+        "user.toml: theme.preset: unknown preset 'solarized'",
+        "user.toml: theme.shiny: unknown theme role",
+        "user.toml: theme.added: invalid color 'not-a-color'",
+        "user.toml: keys.nope: unknown mode",
+        "user.toml: keys.diff: unknown action 'jump'",
+        "user.toml: keys.diff.layout: unknown key 'hyper+x'",
+        "user.toml: keys.diff.prev-hunk: 'alt+n' is already bound to next-hunk",
+        "ws.toml: unknown field `colours`",
+    ] {
+        assert!(
+            err.contains(expected),
+            "missing {:?} in:\n{}",
+            expected,
+            err
+        );
+    }
+}
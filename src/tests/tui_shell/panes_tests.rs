@@ -0,0 +1,65 @@
+use super::*;
+
+fn split(direction: Direction) -> SplitHit {
+    SplitHit {
+        area: Rect::new(1, 3, 40, 20),
+        direction,
+        pct: 65,
+        divider: 16,
+    }
+}
+
+#[test]
+fn divider_is_grabbed_on_either_side_and_dragged_within_bounds() {
+    let s = split(Direction::Vertical);
+    assert!(s.on_divider(10, 15));
+    assert!(s.on_divider(10, 16));
+    assert!(!s.on_divider(10, 17));
+    assert!(!s.on_divider(50, 16));
+
+    // Rows 3..=12 are the first half of the area.
+    assert_eq!(s.pct_at(10, 12), 50);
+    assert_eq!(s.pct_at(10, 3), MIN_PCT);
+    assert_eq!(s.pct_at(10, 40), MAX_PCT);
+
+    let h = SplitHit {
+        divider: 27,
+        ..split(Direction::Horizontal)
+    };
+    assert!(h.on_divider(26, 5));
+    assert_eq!(h.pct_at(20, 5), 50);
+}
+
+#[test]
+fn list_rows_map_to_items_past_the_scroll_offset() {
+    let list = ListHit {
+        area: Rect::new(1, 4, 30, 5),
+        offset: 10,
+        selected: Some(12),
+        len: 13,
+    };
+    assert_eq!(list.item_at(2, 4), Some(10));
+    assert_eq!(list.item_at(2, 6), Some(12));
+    // Below the last item, and outside the list.
+    assert_eq!(list.item_at(2, 7), None);
+    assert_eq!(list.item_at(0, 5), None);
+}
+
+#[test]
+fn sizes_round_trip_through_the_state_file() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("state").join("tui-panes.json");
+
+    let mut panes = Panes::load(Some(path.clone()));
+    assert!(panes.sizes.is_empty());
+    panes.sizes.insert("snaps".to_string(), 40);
+    panes.save().unwrap();
+
+    let loaded = Panes::load(Some(path.clone()));
+    assert_eq!(loaded.sizes.get("snaps"), Some(&40));
+
+    std::fs::write(&path, "not json").unwrap();
+    assert!(Panes::load(Some(path)).sizes.is_empty());
+    // Scripted sessions keep sizes in memory only.
+    Panes::default().save().unwrap();
+}
@@ -39,3 +39,19 @@ fn non_hinted_suggestions_keep_score_order() {
     sort_scored_suggestions(&mut scored, &[]);
     assert_eq!(scored[0].1.name, "beta");
 }
+
+#[test]
+fn fuzzy_match_finds_subsequences_and_prefers_word_starts() {
+    let (_, pos) = fuzzy_match("bdl", "bundles").unwrap();
+    assert_eq!(pos, vec![0, 3, 4]);
+    assert!(fuzzy_match("xyz", "bundles").is_none());
+    assert!(fuzzy_match("bundlesx", "bundles").is_none());
+
+    // The word start of "pick" beats the nearer "p" inside "snap".
+    let (_, pos) = fuzzy_match("sp", "snap-pick").unwrap();
+    assert_eq!(pos, vec![0, 5]);
+
+    assert!(score_match("sup", "superpositions") > score_match("sup", "setup"));
+    assert!(score_match("help", "help") > score_match("help", "helpers"));
+    assert_eq!(score_match("q", "bundles"), 0);
+}
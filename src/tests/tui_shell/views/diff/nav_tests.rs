@@ -0,0 +1,74 @@
+use super::*;
+use crate::diff::{DiffLine, EntrySig, FileDiffBody, Hunk, HunkLine};
+use crate::tui_shell::View;
+
+fn file(path: &str, hunks: usize) -> FileDiff {
+    let sig = EntrySig::File {
+        blob: "b".repeat(64),
+        mode: 0o644,
+        size: 1,
+    };
+    let body = if hunks == 0 {
+        FileDiffBody::Binary {
+            old_size: Some(1),
+            new_size: Some(1),
+        }
+    } else {
+        FileDiffBody::Text {
+            hunks: (0..hunks)
+                .map(|i| Hunk {
+                    old_start: i * 10 + 1,
+                    old_lines: 1,
+                    new_start: i * 10 + 1,
+                    new_lines: 1,
+                    lines: vec![
+                        HunkLine::Removed("a".to_string()),
+                        HunkLine::Added("b".to_string()),
+                    ],
+                })
+                .collect(),
+        }
+    };
+    FileDiff {
+        change: DiffLine::Modified {
+            path: path.to_string(),
+            from: sig.clone(),
+            to: sig,
+        },
+        body,
+    }
+}
+
+#[test]
+fn hunk_navigation_crosses_files_and_skips_binaries() {
+    let mut v = DiffView::new(
+        "Diff".to_string(),
+        vec![file("a", 2), file("bin", 0), file("c", 1)],
+    );
+
+    assert!(v.next_hunk());
+    assert_eq!((v.selected, v.hunk), (0, 1));
+    assert!(v.next_hunk());
+    assert_eq!((v.selected, v.hunk), (2, 0));
+    assert!(v.next_hunk());
+    assert_eq!((v.selected, v.hunk), (0, 0));
+
+    assert!(v.prev_hunk());
+    assert_eq!((v.selected, v.hunk), (2, 0));
+    assert!(v.prev_hunk());
+    assert_eq!((v.selected, v.hunk), (0, 1));
+}
+
+#[test]
+fn file_selection_resets_hunk_and_binary_only_diffs_have_no_hunks() {
+    let mut v = DiffView::new("Diff".to_string(), vec![file("a", 2), file("bin", 0)]);
+    v.next_hunk();
+    v.move_down();
+    assert_eq!((v.selected, v.hunk), (1, 0));
+    v.move_down();
+    assert_eq!(v.selected, 1);
+
+    let mut bin = DiffView::new("Diff".to_string(), vec![file("bin", 0)]);
+    assert!(!bin.next_hunk());
+    assert!(!bin.prev_hunk());
+}
@@ -0,0 +1,44 @@
+use super::*;
+use crate::browse::TreeRefKind;
+use crate::model::{ManifestEntry, ManifestEntryKind, ObjectId};
+
+fn entries(paths: &[&str]) -> Vec<TreeEntry> {
+    paths
+        .iter()
+        .map(|p| TreeEntry {
+            path: p.to_string(),
+            entry: ManifestEntry {
+                name: p.rsplit('/').next().unwrap().to_string(),
+                kind: ManifestEntryKind::Dir {
+                    manifest: ObjectId("m".to_string()),
+                },
+            },
+        })
+        .collect()
+}
+
+#[test]
+fn leaving_a_directory_restores_the_parent_selection() {
+    let tree = TreeRef {
+        kind: TreeRefKind::Snap,
+        id: "s1".to_string(),
+        root_manifest: ObjectId("root".to_string()),
+    };
+    let mut v = FilesView::new(tree, false, entries(&["a", "b", "c"]));
+    v.move_down();
+    v.move_down();
+    assert_eq!(v.selected_entry().unwrap().path, "c");
+
+    v.enter("c".to_string(), entries(&["c/d"]));
+    v.enter("c/d".to_string(), entries(&["c/d/e", "c/d/f"]));
+    v.move_down();
+    assert_eq!(v.dir, "c/d");
+
+    v.leave(entries(&["c/d"]));
+    assert_eq!(v.dir, "c");
+    assert_eq!(v.selected_entry().unwrap().path, "c/d");
+
+    v.leave(entries(&["a", "b", "c"]));
+    assert_eq!(v.dir, "");
+    assert_eq!(v.selected_entry().unwrap().path, "c");
+}
@@ -0,0 +1,107 @@
+use super::layout::{Slot, gate_layers};
+use super::*;
+
+fn gate(id: &str, upstream: &[&str]) -> crate::remote::GateDef {
+    serde_json::from_value(serde_json::json!({
+        "id": id,
+        "name": id,
+        "upstream": upstream,
+    }))
+    .unwrap()
+}
+
+fn graph(gates: Vec<crate::remote::GateDef>) -> crate::remote::GateGraph {
+    crate::remote::GateGraph { version: 1, gates }
+}
+
+fn via(from: &str, to: &str) -> Slot {
+    Slot::Via {
+        from: from.to_string(),
+        to: to.to_string(),
+    }
+}
+
+fn pipeline() -> Vec<crate::remote::GateDef> {
+    vec![
+        gate("prod", &["staging", "dev"]),
+        gate("staging", &["qa", "sec"]),
+        gate("sec", &["dev"]),
+        gate("qa", &["dev"]),
+        gate("dev", &[]),
+    ]
+}
+
+#[test]
+fn layers_follow_longest_upstream_path_with_pass_throughs() {
+    let layers = gate_layers(&pipeline());
+    assert_eq!(layers.len(), 4);
+    assert_eq!(layers[0], vec![Slot::Gate("dev".into())]);
+    assert!(layers[1].contains(&Slot::Gate("qa".into())));
+    assert!(layers[1].contains(&Slot::Gate("sec".into())));
+    assert!(layers[1].contains(&via("dev", "prod")));
+    assert!(layers[2].contains(&Slot::Gate("staging".into())));
+    assert!(layers[2].contains(&via("dev", "prod")));
+    assert_eq!(layers[3], vec![Slot::Gate("prod".into())]);
+
+    // Selection order follows the layout.
+    let view = GateGraphView::new(graph(pipeline()), BTreeMap::new());
+    let ids = view
+        .graph
+        .gates
+        .iter()
+        .map(|g| g.id.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(ids, vec!["dev", "qa", "sec", "staging", "prod"]);
+}
+
+#[test]
+fn unknown_upstreams_and_cycles_do_not_break_layout() {
+    let layers = gate_layers(&[
+        gate("a", &["b", "missing"]),
+        gate("b", &["a"]),
+        gate("c", &["c"]),
+    ]);
+    let gates = layers
+        .iter()
+        .flatten()
+        .filter(|s| matches!(s, Slot::Gate(_)))
+        .count();
+    assert_eq!(gates, 3);
+}
+
+#[test]
+fn dag_draws_boxes_edges_and_annotations() {
+    let mut status = BTreeMap::new();
+    status.insert(
+        "dev".to_string(),
+        GateStatus {
+            heads: vec![GateHead {
+                scope: "main".to_string(),
+                bundle_id: "1234abcd5678".to_string(),
+                promotable: Some(true),
+            }],
+            pending: 2,
+        },
+    );
+    let mut view = GateGraphView::new(graph(pipeline()), status);
+    view.selected = 3;
+
+    let dag = super::dag::dag_render(&view, &crate::tui_shell::Theme::default());
+    let text = dag
+        .lines
+        .iter()
+        .map(|l| l.to_string())
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    for id in ["dev", "qa", "sec", "staging", "prod"] {
+        assert!(text.contains(id), "missing {id} in\n{text}");
+    }
+    assert!(text.contains("main 1234abcd ok"));
+    assert!(text.contains("inbox: 2 pending"));
+    assert!(text.contains('┬') && text.contains('▼') && text.contains('┌'));
+
+    // The selected box (staging) sits below the first two layers.
+    let (_, y) = dag.selected_at.unwrap();
+    assert!(y >= 2 * dag.box_size.1);
+}
@@ -0,0 +1,48 @@
+use super::*;
+
+fn view() -> MergeView {
+    MergeView::new(
+        "b".repeat(64),
+        ObjectId("m".repeat(64)),
+        "notes.txt".to_string(),
+        ("A".to_string(), "B".to_string()),
+        0o644,
+        "one\ntwo\nthree\nfour\n",
+        "one\nTWO\nthree\nFOUR\n",
+    )
+}
+
+#[test]
+fn choices_settle_conflicts_and_undo_redo_restores_them() {
+    let mut v = view();
+    assert_eq!((v.conflicts(), v.unresolved()), (2, 2));
+
+    v.choose(ConflictChoice::Right).unwrap();
+    assert_eq!(v.conflict, 1);
+    v.choose(ConflictChoice::Both).unwrap();
+    assert_eq!(v.unresolved(), 0);
+    assert_eq!(v.result(), "one\nTWO\nthree\nfour\nFOUR\n");
+
+    assert!(v.undo());
+    assert_eq!(v.unresolved(), 1);
+    assert!(
+        v.result()
+            .contains("<<<<<<< A\nfour\n=======\nFOUR\n>>>>>>> B\n")
+    );
+    assert!(v.redo());
+    assert_eq!(v.unresolved(), 0);
+    assert!(!v.redo());
+}
+
+#[test]
+fn edited_result_overrides_choices_until_undone() {
+    let mut v = view();
+    assert!(!v.set_edited(v.result()));
+    assert!(v.set_edited("one\n2\nthree\n4\n".to_string()));
+    assert_eq!(v.unresolved(), 0);
+    assert!(v.choose(ConflictChoice::Left).is_err());
+
+    assert!(v.undo());
+    assert_eq!(v.state.edited, None);
+    assert!(v.choose(ConflictChoice::Left).is_ok());
+}
@@ -0,0 +1,67 @@
+use super::super::{SearchKind, SearchTarget};
+use super::*;
+
+fn snap(id: &str, message: &str) -> SearchCandidate {
+    SearchCandidate {
+        kind: SearchKind::Snap,
+        id: id.to_string(),
+        text: format!("{} {}", &id[..8], message),
+        detail: String::new(),
+        target: SearchTarget::Snap { id: id.to_string() },
+    }
+}
+
+fn lane(id: &str) -> SearchCandidate {
+    SearchCandidate {
+        kind: SearchKind::Lane,
+        id: id.to_string(),
+        text: id.to_string(),
+        detail: String::new(),
+        target: SearchTarget::Lane { id: id.to_string() },
+    }
+}
+
+#[test]
+fn every_term_must_match_and_tighter_matches_rank_first() {
+    let hits = rank_hits(
+        "fix parser",
+        vec![
+            snap("aaaaaaaa11", "fix the parser crash"),
+            snap("bbbbbbbb22", "fix tests"),
+            snap("cccccccc33", "first pass at a rewrite of the serializer"),
+        ],
+    );
+    let ids = hits
+        .iter()
+        .map(|h| h.candidate.id.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(ids[0], "aaaaaaaa11");
+    assert!(!ids.contains(&"bbbbbbbb22"));
+
+    // "fix" on the word start, "parser" as a run.
+    let text = hits[0].candidate.text.chars().collect::<Vec<_>>();
+    let matched = hits[0]
+        .positions
+        .iter()
+        .map(|&i| text[i])
+        .collect::<String>();
+    assert_eq!(matched, "fixparser");
+}
+
+#[test]
+fn id_prefix_beats_fuzzy_text_and_needs_four_chars() {
+    let candidates = vec![snap("deadbeef0123", "unrelated"), lane("dead-letter-queue")];
+    let hits = rank_hits("deadbe", candidates.clone());
+    assert_eq!(hits[0].candidate.id, "deadbeef0123");
+    assert_eq!(hits[0].positions, vec![0, 1, 2, 3, 4, 5]);
+
+    // Too short for an id prefix: both match on their text alone.
+    let hits = rank_hits("dea", candidates);
+    assert_eq!(hits.len(), 2);
+    assert!(hits.iter().all(|h| h.score < ID_PREFIX));
+}
+
+#[test]
+fn empty_query_has_no_hits() {
+    assert!(rank_hits("  ", vec![lane("core")]).is_empty());
+}
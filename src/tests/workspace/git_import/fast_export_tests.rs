@@ -0,0 +1,116 @@
+use super::*;
+
+fn read_all(stream: &[u8]) -> Vec<Command> {
+    let mut reader = Reader::new(stream);
+    let mut out = Vec::new();
+    while let Some(cmd) = reader.next_command().expect("parse stream") {
+        out.push(cmd);
+    }
+    out
+}
+
+#[test]
+fn parses_blobs_commits_resets_and_tags() {
+    let stream =
+        b"blob\nmark :1\noriginal-oid 78981922613b2afb6025042ff6bd878ac1994e85\ndata 2\na\n\n\
+blob\nmark :2\ndata 5\na.txt\n\
+reset refs/tags/v1\n\
+commit refs/heads/main\nmark :3\noriginal-oid 5f6d\n\
+author A <a@x> 1792388055 +0200\ncommitter C <c@x> 1792388056 +0000\n\
+data 11\nfirst\nbody\n\
+M 100644 :1 a.txt\nM 120000 :2 link\nM 100755 :1 \"sp ace/b\\\"q\\303\\251.txt\"\n\n\
+commit refs/heads/main\nmark :4\n\
+author A <a@x> 1792388057 +0000\ncommitter A <a@x> 1792388057 +0000\n\
+data 6\nsecondfrom :3\nmerge :9\nD a.txt\ndeleteall\n\n\
+tag v1\nfrom :3\noriginal-oid 2952\ntagger A <a@x> 1792388057 +0000\ndata 8\ntag msg\n\n\
+reset refs/tags/light\nfrom :4\n\ndone\n";
+
+    let cmds = read_all(stream);
+    assert_eq!(cmds.len(), 7);
+    assert_eq!(
+        cmds[0],
+        Command::Blob {
+            mark: Some(":1".to_string()),
+            data: b"a\n".to_vec(),
+        }
+    );
+    assert_eq!(
+        cmds[2],
+        Command::Reset {
+            refname: "refs/tags/v1".to_string(),
+            from: None,
+        }
+    );
+
+    let Command::Commit(first) = &cmds[3] else {
+        panic!("expected commit, got {:?}", cmds[3]);
+    };
+    assert_eq!(first.mark.as_deref(), Some(":3"));
+    assert_eq!(first.original_oid.as_deref(), Some("5f6d"));
+    assert_eq!(
+        first.author,
+        Some(Ident {
+            who: "A <a@x>".to_string(),
+            unix_time: 1792388055,
+        })
+    );
+    assert_eq!(first.message, "first\nbody\n");
+    assert_eq!(first.from, None);
+    assert_eq!(
+        first.ops,
+        vec![
+            FileOp::Modify {
+                mode: 0o100644,
+                dataref: ":1".to_string(),
+                path: "a.txt".to_string(),
+            },
+            FileOp::Modify {
+                mode: 0o120000,
+                dataref: ":2".to_string(),
+                path: "link".to_string(),
+            },
+            FileOp::Modify {
+                mode: 0o100755,
+                dataref: ":1".to_string(),
+                path: "sp ace/b\"qé.txt".to_string(),
+            },
+        ]
+    );
+
+    let Command::Commit(second) = &cmds[4] else {
+        panic!("expected commit, got {:?}", cmds[4]);
+    };
+    assert_eq!(second.message, "second");
+    assert_eq!(second.from.as_deref(), Some(":3"));
+    assert_eq!(second.merges, vec![":9".to_string()]);
+    assert_eq!(
+        second.ops,
+        vec![
+            FileOp::Delete {
+                path: "a.txt".to_string(),
+            },
+            FileOp::DeleteAll,
+        ]
+    );
+
+    assert_eq!(
+        cmds[5],
+        Command::Tag {
+            name: "v1".to_string(),
+            from: ":3".to_string(),
+        }
+    );
+    assert_eq!(
+        cmds[6],
+        Command::Reset {
+            refname: "refs/tags/light".to_string(),
+            from: Some(":4".to_string()),
+        }
+    );
+}
+
+#[test]
+fn rejects_unknown_commands() {
+    let mut reader = Reader::new(&b"checkpoint\n"[..]);
+    assert!(reader.next_command().is_err());
+}
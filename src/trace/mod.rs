@@ -0,0 +1,243 @@
+//! Agent trace event schema (`converge --agent-trace`) and friction reports.
+//!
+//! A trace is JSONL: one [`TraceRecord`] per line, carrying an event name and its payload.
+//! [`SCHEMA_VERSION`] is written as `v` on every record. Traces from builds before versioning
+//! have no `v` and read as version 0, which has the same shape minus the additions listed on
+//! [`SCHEMA_VERSION`]. Payload fields are all optional when reading, so a record missing
+//! fields (older builds) or carrying extra ones (newer builds) still parses; unknown event
+//! names read as [`TraceEvent::Unknown`].
+
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+mod report;
+
+pub use report::{
+    AbandonedWizard, CommandLoop, ErrorLoop, FrictionReport, ModeStats, friction_report,
+};
+
+/// Version written by this build.
+///
+/// - 0: records without `v` (before versioning).
+/// - 1: adds `v`, `modal_cancelled`, and `modal_title` on `screen_view`.
+pub const SCHEMA_VERSION: u32 = 1;
+
+/// One line of a trace file.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct TraceRecord {
+    #[serde(default)]
+    pub v: u32,
+    /// 1-based position within the session.
+    #[serde(default)]
+    pub seq: u64,
+    /// RFC 3339 UTC.
+    #[serde(default)]
+    pub ts: String,
+    pub event: String,
+    #[serde(default)]
+    pub payload: Value,
+}
+
+/// First event of a session.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SessionStart {
+    pub cwd: Option<String>,
+    pub root_context: String,
+    pub mode: String,
+    pub view_title: String,
+}
+
+/// The visible screen changed: view, focus, modal, or the offered commands.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ScreenView {
+    /// `<root_context>:<mode>`.
+    pub screen_id: String,
+    pub title: String,
+    pub mode: String,
+    pub root_context: String,
+    pub selectable_items: Vec<String>,
+    /// `modal`, `command-input` or `default-action`.
+    pub focused_element: String,
+    pub primary_cta: Option<String>,
+    pub has_modal: bool,
+    /// Since v1.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub modal_title: Option<String>,
+    pub has_command_input: bool,
+}
+
+/// A key press (`action: key_press`, with `key`), a submitted command line
+/// (`action: command_submitted`, with `raw_input` and the resolved `command`), or the
+/// suggested default action run with Enter (`action: run_primary_hint`, with `command`).
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct UserAction {
+    /// `keyboard`, `command_input` or `default_action`.
+    pub source: String,
+    pub action: String,
+    /// `[ctrl+][alt+][shift+]<key>`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub key: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raw_input: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub command: Option<String>,
+    pub mode: String,
+    pub root_context: String,
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct StateChange {
+    /// What changed, e.g. `mode`.
+    pub state: String,
+    pub from: String,
+    pub to: String,
+    pub mode: String,
+    pub root_context: String,
+}
+
+/// A prompt or wizard step was dismissed without submitting (since v1).
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ModalCancelled {
+    pub title: String,
+    /// The text prompt shown, for input modals.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prompt: Option<String>,
+    pub mode: String,
+    pub root_context: String,
+}
+
+/// Payload of `validation_error` and `system_error`.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TraceError {
+    pub message: String,
+    pub mode: String,
+    pub root_context: String,
+}
+
+/// Last event of a session.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SessionEnd {
+    pub reason: String,
+    pub stats: SessionStats,
+    pub trace_path: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SessionStats {
+    pub screen_views: u64,
+    pub user_actions: u64,
+    pub command_submissions: u64,
+    pub validation_errors: u64,
+    pub system_errors: u64,
+}
+
+#[derive(Clone, Debug)]
+pub enum TraceEvent {
+    SessionStart(SessionStart),
+    ScreenView(ScreenView),
+    UserAction(UserAction),
+    StateChange(StateChange),
+    ModalCancelled(ModalCancelled),
+    ValidationError(TraceError),
+    SystemError(TraceError),
+    SessionEnd(SessionEnd),
+    Unknown,
+}
+
+impl TraceEvent {
+    /// The record's `event` name.
+    pub fn name(&self) -> &'static str {
+        match self {
+            TraceEvent::SessionStart(_) => "session_start",
+            TraceEvent::ScreenView(_) => "screen_view",
+            TraceEvent::UserAction(_) => "user_action",
+            TraceEvent::StateChange(_) => "state_change",
+            TraceEvent::ModalCancelled(_) => "modal_cancelled",
+            TraceEvent::ValidationError(_) => "validation_error",
+            TraceEvent::SystemError(_) => "system_error",
+            TraceEvent::SessionEnd(_) => "session_end",
+            TraceEvent::Unknown => "unknown",
+        }
+    }
+
+    pub fn payload(&self) -> Value {
+        let value = match self {
+            TraceEvent::SessionStart(p) => serde_json::to_value(p),
+            TraceEvent::ScreenView(p) => serde_json::to_value(p),
+            TraceEvent::UserAction(p) => serde_json::to_value(p),
+            TraceEvent::StateChange(p) => serde_json::to_value(p),
+            TraceEvent::ModalCancelled(p) => serde_json::to_value(p),
+            TraceEvent::ValidationError(p) | TraceEvent::SystemError(p) => serde_json::to_value(p),
+            TraceEvent::SessionEnd(p) => serde_json::to_value(p),
+            TraceEvent::Unknown => Ok(Value::Null),
+        };
+        value.unwrap_or(Value::Null)
+    }
+}
+
+impl TraceRecord {
+    pub fn new(seq: u64, ts: String, event: &TraceEvent) -> Self {
+        Self {
+            v: SCHEMA_VERSION,
+            seq,
+            ts,
+            event: event.name().to_string(),
+            payload: event.payload(),
+        }
+    }
+
+    /// The typed event. Payloads that do not fit the schema read as defaults.
+    pub fn event(&self) -> TraceEvent {
+        fn payload<T: DeserializeOwned + Default>(v: &Value) -> T {
+            serde_json::from_value(v.clone()).unwrap_or_default()
+        }
+        match self.event.as_str() {
+            "session_start" => TraceEvent::SessionStart(payload(&self.payload)),
+            "screen_view" => TraceEvent::ScreenView(payload(&self.payload)),
+            "user_action" => TraceEvent::UserAction(payload(&self.payload)),
+            "state_change" => TraceEvent::StateChange(payload(&self.payload)),
+            "modal_cancelled" => TraceEvent::ModalCancelled(payload(&self.payload)),
+            "validation_error" => TraceEvent::ValidationError(payload(&self.payload)),
+            "system_error" => TraceEvent::SystemError(payload(&self.payload)),
+            "session_end" => TraceEvent::SessionEnd(payload(&self.payload)),
+            _ => TraceEvent::Unknown,
+        }
+    }
+
+    pub fn at(&self) -> Option<time::OffsetDateTime> {
+        time::OffsetDateTime::parse(&self.ts, &time::format_description::well_known::Rfc3339).ok()
+    }
+}
+
+/// A parsed trace file.
+#[derive(Clone, Debug, Default)]
+pub struct Trace {
+    pub records: Vec<TraceRecord>,
+    /// Non-empty lines that were not a JSON record.
+    pub skipped_lines: usize,
+}
+
+/// Parses a trace, skipping lines that are not records so a truncated file still reads.
+pub fn parse_trace(text: &str) -> Trace {
+    let mut trace = Trace::default();
+    for line in text.lines().map(str::trim).filter(|l| !l.is_empty()) {
+        match serde_json::from_str::<TraceRecord>(line) {
+            Ok(r) => trace.records.push(r),
+            Err(_) => trace.skipped_lines += 1,
+        }
+    }
+    trace
+}
+
+#[cfg(test)]
+#[path = "../tests/trace/trace_tests.rs"]
+mod tests;
@@ -0,0 +1,316 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use serde::Serialize;
+use time::OffsetDateTime;
+
+use super::{Trace, TraceEvent};
+
+/// Identical command lines submitted this many times in a row count as a loop.
+const LOOP_MIN: usize = 3;
+/// Identical error messages seen this many times count as a repeated error.
+const ERROR_REPEAT_MIN: usize = 2;
+
+/// Where a trace shows the user slowing down, failing or giving up.
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct FrictionReport {
+    /// Distinct record versions, ascending.
+    pub schema_versions: Vec<u32>,
+    pub sessions: usize,
+    pub events: usize,
+    pub skipped_lines: usize,
+    /// Events this build does not know (newer builds).
+    pub unknown_events: usize,
+    /// First to last timestamp, across sessions.
+    pub duration_ms: u64,
+    /// Longest dwell first.
+    pub modes: Vec<ModeStats>,
+    pub abandoned_wizards: Vec<AbandonedWizard>,
+    pub command_loops: Vec<CommandLoop>,
+    pub error_loops: Vec<ErrorLoop>,
+}
+
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct ModeStats {
+    pub mode: String,
+    /// Time from a screen in this mode being shown until the next screen.
+    pub dwell_ms: u64,
+    pub views: usize,
+    pub key_presses: usize,
+    pub commands: usize,
+    pub validation_errors: usize,
+    pub system_errors: usize,
+    /// Errors per submitted command; absent when no command was submitted.
+    pub error_rate: Option<f64>,
+}
+
+/// A prompt or wizard step left without submitting, grouped by where it was left.
+#[derive(Clone, Debug, Serialize)]
+pub struct AbandonedWizard {
+    pub title: String,
+    pub prompt: Option<String>,
+    pub mode: String,
+    pub count: usize,
+}
+
+/// The same command line submitted several times in a row.
+#[derive(Clone, Debug, Serialize)]
+pub struct CommandLoop {
+    pub command: String,
+    pub mode: String,
+    pub count: usize,
+    /// Errors reported while the loop ran.
+    pub errors: usize,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct ErrorLoop {
+    pub message: String,
+    pub count: usize,
+}
+
+#[derive(Default)]
+struct Screen {
+    mode: String,
+    at: Option<OffsetDateTime>,
+    modal: Option<String>,
+}
+
+#[derive(Default)]
+struct Analysis {
+    modes: BTreeMap<String, ModeStats>,
+    screen: Option<Screen>,
+    abandoned: BTreeMap<(String, Option<String>, String), usize>,
+    run: Option<CommandLoop>,
+    loops: Vec<CommandLoop>,
+    errors: BTreeMap<String, usize>,
+}
+
+impl Analysis {
+    fn mode(&mut self, mode: &str) -> &mut ModeStats {
+        self.modes
+            .entry(mode.to_string())
+            .or_insert_with(|| ModeStats {
+                mode: mode.to_string(),
+                ..ModeStats::default()
+            })
+    }
+
+    /// The payload's mode, or the current screen's for payloads without one.
+    fn mode_or_current(&self, mode: &str) -> String {
+        if !mode.is_empty() {
+            return mode.to_string();
+        }
+        self.screen
+            .as_ref()
+            .map(|s| s.mode.clone())
+            .unwrap_or_else(|| "unknown".to_string())
+    }
+
+    fn enter(&mut self, screen: Screen) {
+        self.leave(screen.at);
+        self.screen = Some(screen);
+    }
+
+    /// Ends the current screen's dwell at `at`.
+    fn leave(&mut self, at: Option<OffsetDateTime>) {
+        let Some(screen) = self.screen.take() else {
+            return;
+        };
+        if let (Some(from), Some(to)) = (screen.at, at)
+            && to > from
+        {
+            let ms = (to - from).whole_milliseconds().max(0) as u64;
+            self.mode(&screen.mode).dwell_ms += ms;
+        }
+    }
+
+    /// The session ended: a modal still open was abandoned.
+    fn end_session(&mut self, at: Option<OffsetDateTime>) {
+        if let Some(screen) = &self.screen
+            && let Some(title) = screen.modal.clone()
+        {
+            let mode = screen.mode.clone();
+            self.abandon(title, None, mode);
+        }
+        self.leave(at);
+        self.flush_run();
+    }
+
+    fn abandon(&mut self, title: String, prompt: Option<String>, mode: String) {
+        *self.abandoned.entry((title, prompt, mode)).or_default() += 1;
+    }
+
+    fn command(&mut self, line: &str, mode: String) {
+        self.mode(&mode).commands += 1;
+        match &mut self.run {
+            Some(run) if run.command == line => run.count += 1,
+            _ => {
+                self.flush_run();
+                self.run = Some(CommandLoop {
+                    command: line.to_string(),
+                    mode,
+                    count: 1,
+                    errors: 0,
+                });
+            }
+        }
+    }
+
+    fn flush_run(&mut self) {
+        if let Some(run) = self.run.take()
+            && run.count >= LOOP_MIN
+        {
+            self.loops.push(run);
+        }
+    }
+
+    fn error(&mut self, message: &str, mode: String, validation: bool) {
+        let stats = self.mode(&mode);
+        if validation {
+            stats.validation_errors += 1;
+        } else {
+            stats.system_errors += 1;
+        }
+        *self.errors.entry(message.to_string()).or_default() += 1;
+        if let Some(run) = &mut self.run {
+            run.errors += 1;
+        }
+    }
+}
+
+/// Analyzes every session in `trace`. Records of all schema versions are used; version 0
+/// traces have no `modal_cancelled`, so an `esc` pressed over a modal counts instead.
+pub fn friction_report(trace: &Trace) -> FrictionReport {
+    let mut a = Analysis::default();
+    let mut versions = BTreeSet::new();
+    let mut sessions = 0;
+    let mut unknown_events = 0;
+    let mut first_at = None;
+    let mut last_at = None;
+
+    for record in &trace.records {
+        versions.insert(record.v);
+        let at = record.at();
+        let prev_at = last_at;
+        if at.is_some() {
+            first_at = first_at.or(at);
+            last_at = at;
+        }
+
+        match record.event() {
+            TraceEvent::SessionStart(s) => {
+                // Appended sessions may follow one that never wrote session_end.
+                a.end_session(prev_at);
+                sessions += 1;
+                a.screen = Some(Screen {
+                    mode: if s.mode.is_empty() {
+                        "unknown".to_string()
+                    } else {
+                        s.mode
+                    },
+                    at,
+                    modal: None,
+                });
+            }
+            TraceEvent::ScreenView(s) => {
+                let mode = a.mode_or_current(&s.mode);
+                a.mode(&mode).views += 1;
+                let modal = s.has_modal.then(|| {
+                    s.modal_title
+                        .clone()
+                        .unwrap_or_else(|| format!("modal on {}", s.title))
+                });
+                a.enter(Screen { mode, at, modal });
+            }
+            TraceEvent::UserAction(u) => {
+                let mode = a.mode_or_current(&u.mode);
+                match u.action.as_str() {
+                    "key_press" => {
+                        a.mode(&mode).key_presses += 1;
+                        let over_modal = a.screen.as_ref().and_then(|s| s.modal.clone());
+                        if record.v == 0
+                            && u.key.as_deref() == Some("esc")
+                            && let Some(title) = over_modal
+                        {
+                            a.abandon(title, None, mode);
+                        }
+                    }
+                    "command_submitted" | "run_primary_hint" => {
+                        let line = u.raw_input.or(u.command).unwrap_or_default();
+                        a.command(line.trim(), mode);
+                    }
+                    _ => {}
+                }
+            }
+            TraceEvent::ModalCancelled(m) => {
+                let mode = a.mode_or_current(&m.mode);
+                a.abandon(m.title, m.prompt, mode);
+            }
+            TraceEvent::ValidationError(e) => {
+                let mode = a.mode_or_current(&e.mode);
+                a.error(&e.message, mode, true);
+            }
+            TraceEvent::SystemError(e) => {
+                let mode = a.mode_or_current(&e.mode);
+                a.error(&e.message, mode, false);
+            }
+            TraceEvent::SessionEnd(_) => a.end_session(at),
+            TraceEvent::StateChange(_) => {}
+            TraceEvent::Unknown => unknown_events += 1,
+        }
+    }
+    // A trace cut short (crash, still running) ends at its last event.
+    a.end_session(last_at);
+
+    let mut modes: Vec<ModeStats> = a
+        .modes
+        .into_values()
+        .map(|mut m| {
+            m.error_rate = (m.commands > 0)
+                .then(|| (m.validation_errors + m.system_errors) as f64 / m.commands as f64);
+            m
+        })
+        .collect();
+    modes.sort_by(|x, y| y.dwell_ms.cmp(&x.dwell_ms).then(x.mode.cmp(&y.mode)));
+
+    let mut abandoned_wizards: Vec<AbandonedWizard> = a
+        .abandoned
+        .into_iter()
+        .map(|((title, prompt, mode), count)| AbandonedWizard {
+            title,
+            prompt,
+            mode,
+            count,
+        })
+        .collect();
+    abandoned_wizards.sort_by_key(|w| std::cmp::Reverse(w.count));
+
+    let mut command_loops = a.loops;
+    command_loops.sort_by_key(|l| std::cmp::Reverse(l.count));
+
+    let mut error_loops: Vec<ErrorLoop> = a
+        .errors
+        .into_iter()
+        .filter(|(_, count)| *count >= ERROR_REPEAT_MIN)
+        .map(|(message, count)| ErrorLoop { message, count })
+        .collect();
+    error_loops.sort_by_key(|e| std::cmp::Reverse(e.count));
+
+    let duration_ms = match (first_at, last_at) {
+        (Some(from), Some(to)) if to > from => (to - from).whole_milliseconds() as u64,
+        _ => 0,
+    };
+
+    FrictionReport {
+        schema_versions: versions.into_iter().collect(),
+        sessions,
+        events: trace.records.len(),
+        skipped_lines: trace.skipped_lines,
+        unknown_events,
+        duration_ms,
+        modes,
+        abandoned_wizards,
+        command_loops,
+        error_loops,
+    }
+}
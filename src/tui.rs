@@ -7,6 +7,26 @@ pub struct TuiRunOptions {
     pub agent_trace: Option<PathBuf>,
 }
 
+/// Drives the TUI headlessly from a JSONL script (`converge tui --script`).
+#[derive(Clone, Debug)]
+pub struct TuiScriptOptions {
+    pub script: PathBuf,
+    pub width: u16,
+    pub height: u16,
+    /// Emit the rendered screen after every step, not only on `frame` steps.
+    pub frames: bool,
+    pub agent_trace: Option<PathBuf>,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct TuiScriptSummary {
+    pub steps: usize,
+    /// Expectations that did not hold.
+    pub failed: usize,
+    /// Whether the script ended by quitting the TUI.
+    pub quit: bool,
+}
+
 pub fn run() -> Result<()> {
     crate::tui_shell::run()
 }
@@ -14,3 +34,11 @@ pub fn run() -> Result<()> {
 pub fn run_with_options(opts: TuiRunOptions) -> Result<()> {
     crate::tui_shell::run_with_options(opts)
 }
+
+/// Runs a script against a fixed-size test terminal, writing one JSON event per line to `out`.
+pub fn run_script(
+    opts: TuiScriptOptions,
+    out: &mut dyn std::io::Write,
+) -> Result<TuiScriptSummary> {
+    crate::tui_shell::run_script(opts, out)
+}
@@ -4,10 +4,11 @@ use std::time::Duration;
 use anyhow::Result;
 use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
 use ratatui::layout::{Constraint, Direction, Layout};
-use ratatui::style::{Color, Modifier, Style};
+use ratatui::style::{Modifier, Style};
 use ratatui::text::{Line, Span};
 use ratatui::widgets::{Block, Borders, Paragraph, Wrap};
 
+use crate::merge::ConflictChoice;
 use crate::model::{ChunkingConfig, RemoteConfig, Resolution, ResolutionDecision};
 use crate::remote::RemoteClient;
 use crate::resolve::{superposition_variants, validate_resolution};
@@ -17,19 +18,22 @@ use time::OffsetDateTime;
 use time::format_description::FormatItem;
 use time::format_description::well_known::Rfc3339;
 
+use super::config::{KeyAction, KeyScope, Keymap, Theme, TuiConfig};
 use super::input::Input;
 use super::modal;
+use super::panes::{PaneCtx, Panes};
 use super::status::{extract_change_summary, local_status_lines, remote_status_lines};
-use super::suggest::{score_match, sort_scored_suggestions};
+use super::suggest::{highlight_matches, score_match, sort_scored_suggestions};
 use super::view::{RenderCtx, View};
 use super::views::{
-    BundlesView, GateGraphView, InboxView, LaneHeadItem, LanesView, ReleasesView, RootView,
-    SettingsItemKind, SettingsSnapshot, SettingsView, SnapsView, SuperpositionsView,
+    BundlesView, DiffLayout, DiffView, FilesView, GateGraphView, InboxView, LaneHeadItem,
+    LanesView, MergeView, ReleasesView, RootView, SettingsItemKind, SettingsSnapshot, SettingsView,
+    SnapsView, SuperpositionsView,
 };
 use super::wizard::{
-    BootstrapWizard, BrowseTarget, BrowseWizard, FetchWizard, LaneMemberWizard, LoginWizard,
-    MemberAction, MemberWizard, MoveWizard, PinWizard, PromoteWizard, PublishWizard, ReleaseWizard,
-    SyncWizard,
+    BootstrapWizard, BrowseTarget, BrowseWizard, FetchWizard, LaneMemberWizard, LaneWizard,
+    LoginWizard, MemberAction, MemberWizard, MoveWizard, PinWizard, PromoteWizard, PublishWizard,
+    ReleaseWizard, SyncWizard,
 };
 
 mod agent_trace;
@@ -42,13 +46,16 @@ mod cmd_text_input;
 mod cmd_transfer;
 mod command_availability;
 mod default_actions;
+mod diff_views;
 mod event_loop;
+mod files_views;
 mod input_hints;
 mod lifecycle;
 mod local_bootstrap;
 mod local_info;
 mod local_maintenance;
 mod local_snaps_filter;
+mod local_snaps_history;
 mod local_snaps_message;
 mod local_snaps_open;
 mod local_snaps_restore;
@@ -58,6 +65,7 @@ mod log_types;
 mod modal_output;
 mod modal_types;
 mod mode_commands;
+mod pane_layout;
 mod parse_utils;
 mod release_summary;
 mod remote_access;
@@ -76,11 +84,14 @@ mod root_context;
 mod root_refresh;
 mod root_style;
 mod runtime;
+mod script;
+mod search;
 mod settings_chunking;
 mod settings_do_mode;
 mod settings_overview;
 mod settings_retention;
 mod state;
+mod superpositions_merge;
 mod superpositions_nav;
 mod time_utils;
 mod types;
@@ -94,11 +105,12 @@ use self::parse_utils::{parse_id_list, tokenize, validate_gate_id_local};
 pub(super) use self::release_summary::latest_releases_by_channel;
 pub(in crate::tui_shell) use self::root_style::root_ctx_color;
 pub(super) use self::runtime::run;
+pub(super) use self::script::run_script;
 pub(super) use self::state::App;
 pub(in crate::tui_shell::app) use self::state::ViewFrame;
 pub(in crate::tui_shell) use self::time_utils::now_ts;
 pub(super) use self::time_utils::{fmt_ts_list, fmt_ts_ui};
-pub(super) use self::types::{RootContext, TimestampMode, UiMode};
+pub(super) use self::types::{ExternalProgram, RootContext, TimestampMode, UiMode};
 
 #[cfg(test)]
 mod releases_tests;
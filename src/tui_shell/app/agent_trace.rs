@@ -2,10 +2,12 @@ use std::fs::{File, OpenOptions};
 use std::io::{BufWriter, Write};
 use std::path::{Path, PathBuf};
 
+use crate::trace::{
+    ModalCancelled, ScreenView, SessionEnd, SessionStart, SessionStats, StateChange, TraceError,
+    TraceEvent, TraceRecord, UserAction,
+};
 use anyhow::{Context, Result};
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
-use serde::Serialize;
-use serde_json::json;
 
 use super::*;
 
@@ -48,14 +50,9 @@ impl AgentTraceWriter {
         })
     }
 
-    fn write_event<T: Serialize>(&mut self, event: &str, payload: T) -> Result<()> {
+    fn write_event(&mut self, event: &TraceEvent) -> Result<()> {
         self.seq += 1;
-        let line = json!({
-            "seq": self.seq,
-            "ts": now_ts(),
-            "event": event,
-            "payload": payload
-        });
+        let line = TraceRecord::new(self.seq, now_ts(), event);
         serde_json::to_writer(&mut self.out, &line).context("serialize trace event")?;
         self.out.write_all(b"\n").context("write trace newline")?;
         self.out.flush().context("flush trace event")?;
@@ -70,15 +67,14 @@ impl App {
         };
         match AgentTraceWriter::open(&path) {
             Ok(mut writer) => {
-                let _ = writer.write_event(
-                    "session_start",
-                    json!({
-                        "cwd": std::env::current_dir().ok().map(|p| p.display().to_string()),
-                        "root_context": self.root_ctx.label(),
-                        "mode": format!("{:?}", self.mode()).to_lowercase(),
-                        "view_title": self.view().title(),
-                    }),
-                );
+                let _ = writer.write_event(&TraceEvent::SessionStart(SessionStart {
+                    cwd: std::env::current_dir()
+                        .ok()
+                        .map(|p| p.display().to_string()),
+                    root_context: self.root_ctx.label().to_string(),
+                    mode: self.trace_mode(),
+                    view_title: self.view().title().to_string(),
+                }));
                 self.agent_trace = Some(writer);
                 self.push_output(vec![format!("agent trace enabled: {}", path.display())]);
             }
@@ -119,34 +115,31 @@ impl App {
         self.last_screen_signature = Some(signature);
         self.agent_trace_stats.screen_views += 1;
 
-        self.write_trace_event(
-            "screen_view",
-            json!({
-                "screen_id": format!("{}:{:?}", self.root_ctx.label(), self.mode()).to_lowercase(),
-                "title": self.view().title(),
-                "mode": format!("{:?}", self.mode()).to_lowercase(),
-                "root_context": self.root_ctx.label(),
-                "selectable_items": selectable,
-                "focused_element": focused_element,
-                "primary_cta": primary_cta,
-                "has_modal": self.modal.is_some(),
-                "has_command_input": !self.input.buf.is_empty(),
-            }),
-        );
+        self.write_trace_event(TraceEvent::ScreenView(ScreenView {
+            screen_id: format!("{}:{:?}", self.root_ctx.label(), self.mode()).to_lowercase(),
+            title: self.view().title().to_string(),
+            mode: self.trace_mode(),
+            root_context: self.root_ctx.label().to_string(),
+            selectable_items: selectable,
+            focused_element: focused_element.to_string(),
+            primary_cta,
+            has_modal: self.modal.is_some(),
+            modal_title: self.modal.as_ref().map(|m| m.title.clone()),
+            has_command_input: !self.input.buf.is_empty(),
+        }));
     }
 
     pub(in crate::tui_shell) fn trace_key_action(&mut self, key: KeyEvent) {
         self.agent_trace_stats.user_actions += 1;
-        self.write_trace_event(
-            "user_action",
-            json!({
-                "source": "keyboard",
-                "action": "key_press",
-                "key": key_to_string(&key),
-                "mode": format!("{:?}", self.mode()).to_lowercase(),
-                "root_context": self.root_ctx.label(),
-            }),
-        );
+        self.write_trace_event(TraceEvent::UserAction(UserAction {
+            source: "keyboard".to_string(),
+            action: "key_press".to_string(),
+            key: Some(key_to_string(&key)),
+            raw_input: None,
+            command: None,
+            mode: self.trace_mode(),
+            root_context: self.root_ctx.label().to_string(),
+        }));
     }
 
     pub(in crate::tui_shell::app) fn trace_command_submitted(
@@ -155,85 +148,98 @@ impl App {
         canonical_command: &str,
     ) {
         self.agent_trace_stats.command_submissions += 1;
-        self.write_trace_event(
-            "user_action",
-            json!({
-                "source": "command_input",
-                "action": "command_submitted",
-                "raw_input": raw_input,
-                "command": canonical_command,
-                "mode": format!("{:?}", self.mode()).to_lowercase(),
-                "root_context": self.root_ctx.label(),
-            }),
-        );
+        self.write_trace_event(TraceEvent::UserAction(UserAction {
+            source: "command_input".to_string(),
+            action: "command_submitted".to_string(),
+            key: None,
+            raw_input: Some(raw_input.to_string()),
+            command: Some(canonical_command.to_string()),
+            mode: self.trace_mode(),
+            root_context: self.root_ctx.label().to_string(),
+        }));
     }
 
     pub(in crate::tui_shell) fn trace_state_change(&mut self, state: &str, from: &str, to: &str) {
-        self.write_trace_event(
-            "state_change",
-            json!({
-                "state": state,
-                "from": from,
-                "to": to,
-                "mode": format!("{:?}", self.mode()).to_lowercase(),
-                "root_context": self.root_ctx.label(),
-            }),
-        );
+        self.write_trace_event(TraceEvent::StateChange(StateChange {
+            state: state.to_string(),
+            from: from.to_string(),
+            to: to.to_string(),
+            mode: self.trace_mode(),
+            root_context: self.root_ctx.label().to_string(),
+        }));
+    }
+
+    /// Call before closing a modal the user dismissed.
+    pub(in crate::tui_shell) fn trace_modal_cancelled(&mut self) {
+        let Some(modal) = &self.modal else {
+            return;
+        };
+        let prompt = match &modal.kind {
+            ModalKind::TextInput { prompt, .. } => Some(prompt.trim().to_string()),
+            ModalKind::Viewer => return,
+            _ => None,
+        };
+        let title = modal.title.clone();
+        self.write_trace_event(TraceEvent::ModalCancelled(ModalCancelled {
+            title,
+            prompt,
+            mode: self.trace_mode(),
+            root_context: self.root_ctx.label().to_string(),
+        }));
     }
 
     pub(in crate::tui_shell) fn trace_error(&mut self, msg: &str) {
         let lower = msg.to_lowercase();
-        let event = if lower.contains("parse error")
+        let validation = lower.contains("parse error")
             || lower.contains("invalid")
             || lower.contains("must ")
-            || lower.contains("expected")
-        {
+            || lower.contains("expected");
+        let err = TraceError {
+            message: msg.to_string(),
+            mode: self.trace_mode(),
+            root_context: self.root_ctx.label().to_string(),
+        };
+        let event = if validation {
             self.agent_trace_stats.validation_errors += 1;
-            "validation_error"
+            TraceEvent::ValidationError(err)
         } else {
             self.agent_trace_stats.system_errors += 1;
-            "system_error"
+            TraceEvent::SystemError(err)
         };
-
-        self.write_trace_event(
-            event,
-            json!({
-                "message": msg,
-                "mode": format!("{:?}", self.mode()).to_lowercase(),
-                "root_context": self.root_ctx.label(),
-            }),
-        );
+        self.write_trace_event(event);
     }
 
     pub(in crate::tui_shell) fn trace_session_end(&mut self, reason: &str) {
-        self.write_trace_event(
-            "session_end",
-            json!({
-                "reason": reason,
-                "stats": {
-                    "screen_views": self.agent_trace_stats.screen_views,
-                    "user_actions": self.agent_trace_stats.user_actions,
-                    "command_submissions": self.agent_trace_stats.command_submissions,
-                    "validation_errors": self.agent_trace_stats.validation_errors,
-                    "system_errors": self.agent_trace_stats.system_errors
-                },
-                "trace_path": self.agent_trace.as_ref().map(|w| w.path.display().to_string()),
-            }),
-        );
+        let stats = &self.agent_trace_stats;
+        let stats = SessionStats {
+            screen_views: stats.screen_views,
+            user_actions: stats.user_actions,
+            command_submissions: stats.command_submissions,
+            validation_errors: stats.validation_errors,
+            system_errors: stats.system_errors,
+        };
+        self.write_trace_event(TraceEvent::SessionEnd(SessionEnd {
+            reason: reason.to_string(),
+            stats,
+            trace_path: self
+                .agent_trace
+                .as_ref()
+                .map(|w| w.path.display().to_string()),
+        }));
     }
 
-    pub(in crate::tui_shell::app) fn write_trace_event<T: Serialize>(
-        &mut self,
-        event: &str,
-        payload: T,
-    ) {
+    pub(in crate::tui_shell::app) fn write_trace_event(&mut self, event: TraceEvent) {
         let Some(writer) = self.agent_trace.as_mut() else {
             return;
         };
-        if writer.write_event(event, payload).is_err() {
+        if writer.write_event(&event).is_err() {
             self.agent_trace = None;
         }
     }
+
+    pub(in crate::tui_shell::app) fn trace_mode(&self) -> String {
+        format!("{:?}", self.mode()).to_lowercase()
+    }
 }
 
 fn key_to_string(key: &KeyEvent) -> String {
@@ -11,6 +11,10 @@ impl App {
                 self.quit = true;
                 true
             }
+            "search" => {
+                self.cmd_search(args);
+                true
+            }
             "settings" => {
                 self.cmd_settings(args);
                 true
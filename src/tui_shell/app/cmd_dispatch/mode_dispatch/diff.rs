@@ -0,0 +1,11 @@
+use super::*;
+
+pub(super) fn dispatch_diff_mode(app: &mut App, mode: UiMode, cmd: &str, args: &[String]) {
+    match cmd {
+        "back" => app.dispatch_mode_back(),
+        "next" => app.cmd_diff_next(args),
+        "prev" => app.cmd_diff_prev(args),
+        "layout" => app.cmd_diff_layout(args),
+        _ => app.push_unknown_mode_command(mode, cmd, args),
+    }
+}
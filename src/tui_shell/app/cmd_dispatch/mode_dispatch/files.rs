@@ -0,0 +1,10 @@
+use super::*;
+
+pub(super) fn dispatch_files_mode(app: &mut App, mode: UiMode, cmd: &str, args: &[String]) {
+    match cmd {
+        "back" => app.dispatch_mode_back(),
+        "open" => app.cmd_files_open(args),
+        "up" => app.cmd_files_up(args),
+        _ => app.push_unknown_mode_command(mode, cmd, args),
+    }
+}
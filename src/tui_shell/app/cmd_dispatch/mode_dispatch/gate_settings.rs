@@ -7,6 +7,8 @@ pub(super) fn dispatch_gate_graph_mode(app: &mut App, mode: UiMode, cmd: &str, a
             let _ = args;
             app.open_gate_graph_view();
         }
+        "bundles" => app.cmd_gate_graph_bundles(args),
+        "inbox" => app.cmd_gate_graph_inbox(args),
         "add-gate" => {
             let _ = args;
             app.cmd_gate_graph_add_gate();
@@ -35,6 +37,10 @@ pub(super) fn dispatch_gate_graph_mode(app: &mut App, mode: UiMode, cmd: &str, a
             let _ = args;
             app.cmd_gate_graph_toggle_metadata_only();
         }
+        "toggle-signed" => {
+            let _ = args;
+            app.cmd_gate_graph_toggle_signed();
+        }
         _ => app.push_unknown_mode_command(mode, cmd, args),
     }
 }
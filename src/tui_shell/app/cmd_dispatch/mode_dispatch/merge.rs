@@ -0,0 +1,17 @@
+use super::*;
+
+pub(super) fn dispatch_merge_mode(app: &mut App, mode: UiMode, cmd: &str, args: &[String]) {
+    match cmd {
+        "back" => app.dispatch_mode_back(),
+        "left" => app.cmd_merge_choose(ConflictChoice::Left, args),
+        "right" => app.cmd_merge_choose(ConflictChoice::Right, args),
+        "both" => app.cmd_merge_choose(ConflictChoice::Both, args),
+        "next" => app.cmd_merge_jump(true, args),
+        "prev" => app.cmd_merge_jump(false, args),
+        "undo" => app.cmd_merge_undo(args),
+        "redo" => app.cmd_merge_redo(args),
+        "edit" => app.cmd_merge_edit(args),
+        "save" => app.cmd_merge_save(args),
+        _ => app.push_unknown_mode_command(mode, cmd, args),
+    }
+}
@@ -1,7 +1,11 @@
 use super::super::*;
 
+mod diff;
+mod files;
 mod gate_settings;
+mod merge;
 mod remote_modes;
+mod search;
 mod snaps;
 
 impl App {
@@ -22,6 +26,10 @@ impl App {
             }
             UiMode::GateGraph => gate_settings::dispatch_gate_graph_mode(self, mode, cmd, args),
             UiMode::Settings => gate_settings::dispatch_settings_mode(self, mode, cmd, args),
+            UiMode::Diff => diff::dispatch_diff_mode(self, mode, cmd, args),
+            UiMode::Merge => merge::dispatch_merge_mode(self, mode, cmd, args),
+            UiMode::Files => files::dispatch_files_mode(self, mode, cmd, args),
+            UiMode::Search => search::dispatch_search_mode(self, mode, cmd, args),
             UiMode::Root => {
                 self.dispatch_root(cmd, args);
             }
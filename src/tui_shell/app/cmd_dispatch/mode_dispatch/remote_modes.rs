@@ -12,6 +12,8 @@ pub(super) fn dispatch_inbox_mode(app: &mut App, mode: UiMode, cmd: &str, args:
         }
         "bundle" => app.cmd_inbox_bundle_mode(args),
         "fetch" => app.cmd_inbox_fetch_mode(args),
+        "diff" => app.cmd_inbox_diff_mode(args),
+        "files" => app.cmd_inbox_files_mode(args),
         _ => app.push_unknown_mode_command(mode, cmd, args),
     }
 }
@@ -31,6 +33,8 @@ pub(super) fn dispatch_bundles_mode(app: &mut App, mode: UiMode, cmd: &str, args
         "promote" => app.cmd_bundles_promote_mode(args),
         "release" => app.cmd_bundles_release_mode(args),
         "superpositions" | "supers" => app.cmd_bundles_superpositions_mode(args),
+        "diff" => app.cmd_bundles_diff_mode(args),
+        "files" => app.cmd_bundles_files_mode(args),
         _ => app.push_unknown_mode_command(mode, cmd, args),
     }
 }
@@ -39,6 +43,8 @@ pub(super) fn dispatch_releases_mode(app: &mut App, mode: UiMode, cmd: &str, arg
     match cmd {
         "back" => app.dispatch_mode_back(),
         "fetch" => app.cmd_releases_fetch_mode(args),
+        "diff" => app.cmd_releases_diff_mode(args),
+        "files" => app.cmd_releases_files_mode(args),
         _ => app.push_unknown_mode_command(mode, cmd, args),
     }
 }
@@ -61,10 +67,12 @@ pub(super) fn dispatch_superpositions_mode(
         "back" => app.dispatch_mode_back(),
         "pick" => app.cmd_superpositions_pick_mode(args),
         "clear" => app.cmd_superpositions_clear_mode(args),
+        "reopen" => app.cmd_superpositions_reopen_mode(args),
         "next-missing" => app.cmd_superpositions_next_missing_mode(args),
         "next-invalid" => app.cmd_superpositions_next_invalid_mode(args),
         "validate" => app.cmd_superpositions_validate_mode(args),
         "apply" => app.cmd_superpositions_apply_mode(args),
+        "merge" => app.cmd_superpositions_merge_mode(args),
         _ => app.push_unknown_mode_command(mode, cmd, args),
     }
 }
@@ -0,0 +1,9 @@
+use super::*;
+
+pub(super) fn dispatch_search_mode(app: &mut App, mode: UiMode, cmd: &str, args: &[String]) {
+    match cmd {
+        "back" => app.dispatch_mode_back(),
+        "open" => app.cmd_search_open(args),
+        _ => app.push_unknown_mode_command(mode, cmd, args),
+    }
+}
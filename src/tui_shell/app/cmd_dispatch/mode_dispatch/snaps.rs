@@ -10,6 +10,10 @@ pub(super) fn dispatch_snaps_mode(app: &mut App, mode: UiMode, cmd: &str, args:
         "revert" => app.cmd_snaps_revert(args),
         "unsnap" => app.cmd_snaps_unsnap(args),
         "restore" => app.cmd_snaps_restore(args),
+        "diff" => app.cmd_snaps_diff(args),
+        "files" => app.cmd_snaps_files(args),
+        "history" => app.cmd_snaps_history(args),
+        "blame" => app.cmd_snaps_blame(args),
         _ => app.push_unknown_mode_command(mode, cmd, args),
     }
 }
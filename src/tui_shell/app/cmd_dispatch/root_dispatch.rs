@@ -30,9 +30,9 @@ impl App {
                 }
 
                 "bootstrap" | "create-repo" | "gates" | "remote" | "ping" | "fetch" | "lanes"
-                | "releases" | "members" | "member" | "lane-member" | "inbox" | "bundles"
-                | "bundle" | "pins" | "pin" | "approve" | "promote" | "release"
-                | "superpositions" | "supers" => {
+                | "releases" | "members" | "member" | "lane" | "lane-member" | "groups"
+                | "audit" | "inbox" | "bundles" | "bundle" | "pins" | "pin" | "approve"
+                | "promote" | "release" | "superpositions" | "supers" => {
                     self.switch_to_remote_root();
                     self.push_output(vec![format!("switched to remote context for `{}`", cmd)]);
                     self.dispatch_root(cmd, args);
@@ -61,7 +61,10 @@ impl App {
                 "releases" => self.cmd_releases(args),
                 "members" => self.cmd_members(args),
                 "member" => self.cmd_member(args),
+                "lane" => self.cmd_lane(args),
                 "lane-member" => self.cmd_lane_member(args),
+                "groups" => self.cmd_groups(args),
+                "audit" => self.cmd_audit(args),
                 "inbox" => self.cmd_inbox(args),
                 "bundles" => self.cmd_bundles(args),
                 "bundle" => self.cmd_bundle(args),
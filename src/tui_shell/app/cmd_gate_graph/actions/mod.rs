@@ -62,6 +62,10 @@ impl App {
         toggles::cmd_gate_graph_toggle_metadata_only(self);
     }
 
+    pub(in crate::tui_shell) fn cmd_gate_graph_toggle_signed(&mut self) {
+        toggles::cmd_gate_graph_toggle_signed(self);
+    }
+
     pub(in crate::tui_shell) fn gate_graph_selected_gate_id(
         &self,
         v: &GateGraphView,
@@ -34,9 +34,7 @@ impl App {
         };
 
         if let Some(v) = self.current_view_mut::<GateGraphView>() {
-            let mut updated = updated;
-            updated.gates.sort_by(|a, b| a.id.cmp(&b.id));
-            v.graph = updated;
+            v.set_graph(updated);
             v.updated_at = now_ts();
             if let Some(id) = keep_selected
                 && let Some(i) = v.graph.gates.iter().position(|g| g.id == id)
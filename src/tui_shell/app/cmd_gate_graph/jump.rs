@@ -0,0 +1,49 @@
+use super::super::remote_scope_query_parse::parse_scope_query_args;
+use super::*;
+
+impl App {
+    /// Opens bundles for the selected gate (`--scope`/`--filter`/`--limit` as in root mode).
+    pub(in crate::tui_shell) fn cmd_gate_graph_bundles(&mut self, args: &[String]) {
+        if let Some((scope, gate, filter, limit)) = self.gate_graph_jump_target(args) {
+            self.open_bundles_view(scope, gate, filter, limit);
+        }
+    }
+
+    /// Opens the inbox for the selected gate.
+    pub(in crate::tui_shell) fn cmd_gate_graph_inbox(&mut self, args: &[String]) {
+        if let Some((scope, gate, filter, limit)) = self.gate_graph_jump_target(args) {
+            self.open_inbox_view(scope, gate, filter, limit);
+        }
+    }
+
+    fn gate_graph_jump_target(
+        &mut self,
+        args: &[String],
+    ) -> Option<(String, String, Option<String>, Option<usize>)> {
+        let Some(v) = self.current_view::<GateGraphView>() else {
+            self.push_error("not in gates mode".to_string());
+            return None;
+        };
+        let Some(gate) = self.gate_graph_selected_gate_id(v) else {
+            self.push_error("(no selection)".to_string());
+            return None;
+        };
+        let parsed = match parse_scope_query_args(args) {
+            Ok(p) => p,
+            Err(msg) => {
+                self.push_error(msg);
+                return None;
+            }
+        };
+        let Some(cfg) = self.remote_config() else {
+            self.start_login_wizard();
+            return None;
+        };
+        Some((
+            parsed.scope.unwrap_or(cfg.scope),
+            parsed.gate.unwrap_or(gate),
+            parsed.filter,
+            parsed.limit,
+        ))
+    }
+}
@@ -2,6 +2,8 @@ use super::*;
 
 mod actions;
 mod apply;
+mod jump;
+mod status;
 mod text_input;
 
 impl App {
@@ -42,13 +44,23 @@ impl App {
             }
         };
 
+        // Annotations are best-effort; the graph is still worth showing without them.
+        let scope = self.remote_config().map(|c| c.scope).unwrap_or_default();
+        let status = match status::gate_status(&client, &graph, &scope) {
+            Ok(s) => s,
+            Err(err) => {
+                self.push_error(format!("gate status: {:#}", err));
+                Default::default()
+            }
+        };
+
         if self.mode() == UiMode::GateGraph {
             if let Some(frame) = self.frames.last_mut() {
-                frame.view = Box::new(GateGraphView::new(graph));
+                frame.view = Box::new(GateGraphView::new(graph, status));
             }
             self.push_output(vec!["refreshed gates".to_string()]);
         } else {
-            self.push_view(GateGraphView::new(graph));
+            self.push_view(GateGraphView::new(graph, status));
             self.push_output(vec!["opened gates".to_string()]);
         }
     }
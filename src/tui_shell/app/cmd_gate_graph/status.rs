@@ -0,0 +1,47 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::remote::{GateGraph, RemoteClient};
+use crate::tui_shell::views::{GateHead, GateStatus};
+
+/// Per-gate annotations for the DAG: current bundle per scope and pending publications.
+pub(super) fn gate_status(
+    client: &RemoteClient,
+    graph: &GateGraph,
+    configured_scope: &str,
+) -> anyhow::Result<BTreeMap<String, GateStatus>> {
+    let bundles = client.list_bundles()?;
+    let publications = client.list_publications()?;
+
+    let mut scopes: BTreeSet<String> = bundles.iter().map(|b| b.scope.clone()).collect();
+    scopes.extend(publications.iter().map(|p| p.scope.clone()));
+    scopes.insert(configured_scope.to_string());
+
+    let promotable: BTreeMap<&str, bool> = bundles
+        .iter()
+        .map(|b| (b.id.as_str(), b.promotable))
+        .collect();
+
+    let mut out: BTreeMap<String, GateStatus> = graph
+        .gates
+        .iter()
+        .map(|g| (g.id.clone(), GateStatus::default()))
+        .collect();
+    for scope in &scopes {
+        let state = client.promotion_state(scope)?;
+        for (gate, bundle_id) in state {
+            if let Some(status) = out.get_mut(&gate) {
+                status.heads.push(GateHead {
+                    scope: scope.clone(),
+                    promotable: promotable.get(bundle_id.as_str()).copied(),
+                    bundle_id,
+                });
+            }
+        }
+    }
+    for p in publications.iter().filter(|p| p.resolution.is_none()) {
+        if let Some(status) = out.get_mut(&p.gate) {
+            status.pending += 1;
+        }
+    }
+    Ok(out)
+}
@@ -60,10 +60,14 @@ pub(super) fn handle_add_gate_text_input(app: &mut App, action: TextInputAction,
                     id: id.clone(),
                     name: name.clone(),
                     upstream,
+                    lane: None,
                     allow_releases: true,
                     allow_superpositions: false,
                     allow_metadata_only_publications: false,
                     required_approvals: 0,
+                    require_signed: false,
+                    roles: Default::default(),
+                    resolution_rules: Vec::new(),
                 });
                 Ok(())
             });
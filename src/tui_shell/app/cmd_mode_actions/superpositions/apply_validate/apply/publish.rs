@@ -30,14 +30,26 @@ pub(super) fn publish_resolved_snap(
         }
     };
 
+    let signer = match ws.store.signing_key() {
+        Ok(k) => k,
+        Err(err) => {
+            app.push_error(format!("{:#}", err));
+            return Err(());
+        }
+    };
     let client = match RemoteClient::new(remote.clone(), token) {
-        Ok(c) => c,
+        Ok(c) => c.with_signer(signer),
         Err(err) => {
             app.push_error(format!("init remote client: {:#}", err));
             return Err(());
         }
     };
 
+    if let Err(err) = record_resolution_decisions(ws, &client, bundle_id, root_manifest) {
+        app.push_error(format!("record resolution: {:#}", err));
+        return Err(());
+    }
+
     let res_meta = crate::remote::PublicationResolution {
         bundle_id: bundle_id.to_string(),
         root_manifest: root_manifest.as_str().to_string(),
@@ -59,3 +71,38 @@ pub(super) fn publish_resolved_snap(
         }
     }
 }
+
+fn record_resolution_decisions(
+    ws: &Workspace,
+    client: &RemoteClient,
+    bundle_id: &str,
+    root_manifest: &ObjectId,
+) -> anyhow::Result<()> {
+    let resolution = ws.store.get_resolution(bundle_id)?;
+    let keys = crate::resolve::decision_keys(&ws.store, root_manifest, &resolution.decisions)?;
+    let decisions = keys
+        .into_iter()
+        .map(|(path, key)| {
+            let rule = resolution.rules.get(&path).cloned();
+            let method = if rule.is_some() {
+                crate::remote::ResolutionMethod::Rule
+            } else if key.is_manual() {
+                crate::remote::ResolutionMethod::Manual
+            } else {
+                crate::remote::ResolutionMethod::Pick
+            };
+            let rationale = resolution.rationales.get(&path).cloned();
+            crate::remote::RecordResolutionDecision {
+                path,
+                decision: key,
+                method: Some(method),
+                rule,
+                rationale,
+            }
+        })
+        .collect::<Vec<_>>();
+    if !decisions.is_empty() {
+        client.record_bundle_resolutions(bundle_id, &decisions)?;
+    }
+    Ok(())
+}
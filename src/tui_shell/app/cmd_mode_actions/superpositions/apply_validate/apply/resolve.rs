@@ -37,5 +37,7 @@ pub(super) fn build_resolved_snap(
         root_manifest: resolved_root,
         message: None,
         stats: crate::model::SnapStats::default(),
+        parents: Vec::new(),
+        author: None,
     })
 }
@@ -28,6 +28,15 @@ impl App {
         super::superpositions_nav::superpositions_clear_decision(self);
     }
 
+    pub(in crate::tui_shell) fn cmd_superpositions_reopen_mode(&mut self, args: &[String]) {
+        let rationale = if args.is_empty() {
+            None
+        } else {
+            Some(args.join(" "))
+        };
+        super::superpositions_nav::superpositions_reopen_decision(self, rationale);
+    }
+
     pub(in crate::tui_shell) fn cmd_superpositions_next_missing_mode(&mut self, args: &[String]) {
         if !args.is_empty() {
             self.push_error("usage: next-missing".to_string());
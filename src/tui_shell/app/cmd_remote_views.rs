@@ -33,10 +33,26 @@ impl App {
         };
 
         let scope = parsed.scope.unwrap_or(cfg.scope);
-        let gate = parsed.gate.unwrap_or(cfg.gate);
+        let gate = match parsed.gate {
+            Some(g) => g,
+            None => self.default_inbox_gate(cfg.gate),
+        };
         self.open_inbox_view(scope, gate, parsed.filter, parsed.limit);
     }
 
+    /// Prefers a gate owned by one of your lanes, unless the configured gate already is one.
+    fn default_inbox_gate(&mut self, configured: String) -> String {
+        let owned = self
+            .remote_client()
+            .and_then(|c| c.get_repo_permissions().ok())
+            .map(|p| p.owned_gates)
+            .unwrap_or_default();
+        match owned.first() {
+            Some(first) if !owned.contains(&configured) => first.clone(),
+            _ => configured,
+        }
+    }
+
     pub(super) fn cmd_bundles(&mut self, args: &[String]) {
         if args.len() == 1 && args[0] == "edit" {
             self.start_browse_wizard(BrowseTarget::Bundles);
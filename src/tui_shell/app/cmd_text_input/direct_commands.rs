@@ -10,6 +10,7 @@ pub(super) fn is_direct_command_action(action: &TextInputAction) -> bool {
             | TextInputAction::PinAction
             | TextInputAction::ApproveBundleId
             | TextInputAction::SuperpositionsBundleId
+            | TextInputAction::MergeRationale
     )
 }
 
@@ -72,6 +73,7 @@ pub(super) fn apply_direct_command_text_input(
             }
             app.cmd_superpositions(&["--bundle-id".to_string(), id]);
         }
+        TextInputAction::MergeRationale => app.finish_merge_save(value),
         _ => app.push_error("unexpected direct command text input action".to_string()),
     }
 }
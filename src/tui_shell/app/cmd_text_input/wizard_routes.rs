@@ -38,7 +38,8 @@ pub(super) fn submit_wizard_text_input(app: &mut App, action: TextInputAction, v
 
         TextInputAction::MemberAction
         | TextInputAction::MemberHandle
-        | TextInputAction::MemberRole => {
+        | TextInputAction::MemberRole
+        | TextInputAction::MemberTarget => {
             app.continue_member_wizard(action, value);
         }
 
@@ -48,6 +49,13 @@ pub(super) fn submit_wizard_text_input(app: &mut App, action: TextInputAction, v
             app.continue_lane_member_wizard(action, value);
         }
 
+        TextInputAction::LaneCreateId
+        | TextInputAction::LaneCreateDescription
+        | TextInputAction::LaneCreateGates
+        | TextInputAction::LaneCreateScope => {
+            app.continue_lane_wizard(action, value);
+        }
+
         TextInputAction::BrowseQuery => {
             app.continue_browse_wizard(action, value);
         }
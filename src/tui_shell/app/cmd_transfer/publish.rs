@@ -72,8 +72,15 @@ impl App {
             }
         };
 
+        let signer = match ws.store.signing_key() {
+            Ok(k) => k,
+            Err(err) => {
+                self.push_error(format!("{:#}", err));
+                return;
+            }
+        };
         let client = match RemoteClient::new(cfg.clone(), token) {
-            Ok(c) => c,
+            Ok(c) => c.with_signer(signer),
             Err(err) => {
                 self.push_error(format!("init remote client: {:#}", err));
                 return;
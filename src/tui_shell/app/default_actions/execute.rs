@@ -15,16 +15,17 @@ impl App {
         }
 
         let cmd = cmds[0].clone();
-        self.write_trace_event(
-            "user_action",
-            serde_json::json!({
-                "source": "default_action",
-                "action": "run_primary_hint",
-                "command": cmd.clone(),
-                "mode": format!("{:?}", self.mode()).to_lowercase(),
-                "root_context": self.root_ctx.label(),
-            }),
-        );
+        self.write_trace_event(crate::trace::TraceEvent::UserAction(
+            crate::trace::UserAction {
+                source: "default_action".to_string(),
+                action: "run_primary_hint".to_string(),
+                key: None,
+                raw_input: None,
+                command: Some(cmd.clone()),
+                mode: self.trace_mode(),
+                root_context: self.root_ctx.label().to_string(),
+            },
+        ));
         let action = if self.mode() == UiMode::Root {
             PendingAction::Root {
                 root_ctx: self.root_ctx,
@@ -0,0 +1,16 @@
+use super::*;
+
+pub(super) fn merge_mode_hints(app: &App) -> Vec<String> {
+    let Some(v) = app.current_view::<MergeView>() else {
+        return Vec::new();
+    };
+    if v.unresolved() > 0 {
+        return vec![
+            "left".to_string(),
+            "right".to_string(),
+            "both".to_string(),
+            "edit".to_string(),
+        ];
+    }
+    vec!["save".to_string(), "undo".to_string(), "edit".to_string()]
+}
@@ -1,6 +1,7 @@
 use super::super::super::*;
 
 mod bundles;
+mod merge;
 mod root;
 mod settings;
 mod snaps;
@@ -15,7 +16,11 @@ pub(super) fn hint_commands_raw(app: &App) -> Vec<String> {
         UiMode::Lanes => vec!["fetch".to_string(), "back".to_string()],
         UiMode::Bundles => bundles::bundles_mode_hints(app),
         UiMode::Superpositions => superpositions::superpositions_mode_hints(app),
-        UiMode::GateGraph => Vec::new(),
+        UiMode::GateGraph => vec!["bundles".to_string(), "inbox".to_string()],
         UiMode::Settings => settings::settings_mode_hints(app),
+        UiMode::Diff => vec!["next".to_string(), "prev".to_string(), "layout".to_string()],
+        UiMode::Merge => merge::merge_mode_hints(app),
+        UiMode::Files => vec!["open".to_string(), "up".to_string()],
+        UiMode::Search => vec!["open".to_string(), "back".to_string()],
     }
 }
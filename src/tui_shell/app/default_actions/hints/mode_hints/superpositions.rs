@@ -10,7 +10,11 @@ pub(super) fn superpositions_mode_hints(app: &App) -> Vec<String> {
         .map(|x| !x.missing.is_empty())
         .unwrap_or(false);
     if missing {
-        vec!["next-missing".to_string(), "pick".to_string()]
+        vec![
+            "next-missing".to_string(),
+            "pick".to_string(),
+            "merge".to_string(),
+        ]
     } else {
         vec!["apply".to_string(), "back".to_string()]
     }
@@ -12,6 +12,10 @@ pub(super) fn hint_key(app: &App) -> usize {
         (UiMode::Superpositions, _) => 7,
         (UiMode::GateGraph, _) => 8,
         (UiMode::Settings, _) => 9,
+        (UiMode::Diff, _) => 10,
+        (UiMode::Merge, _) => 11,
+        (UiMode::Files, _) => 12,
+        (UiMode::Search, _) => 13,
     }
 }
 
@@ -0,0 +1,73 @@
+use super::*;
+
+impl App {
+    pub(in crate::tui_shell) fn cmd_snaps_diff(&mut self, args: &[String]) {
+        if !args.is_empty() {
+            self.push_error("usage: diff".to_string());
+            return;
+        }
+        let Some(ws) = self.require_workspace() else {
+            return;
+        };
+        let Some(v) = self.current_view::<SnapsView>() else {
+            self.push_error("not in snaps mode".to_string());
+            return;
+        };
+
+        if v.selected_is_pending() {
+            let head = v.head_id.clone();
+            let from = match head.as_deref().map(|id| ws.store.get_snap(id)) {
+                Some(Ok(snap)) => DiffSide::Manifest(snap.root_manifest),
+                Some(Err(err)) => {
+                    self.push_error(format!("diff: {:#}", err));
+                    return;
+                }
+                None => DiffSide::Empty,
+            };
+            self.open_diff(
+                &ws,
+                "Diff HEAD..workspace".to_string(),
+                from,
+                DiffSide::Workspace,
+            );
+            return;
+        }
+        if v.selected_is_clean() {
+            self.push_output(vec!["no pending changes".to_string()]);
+            return;
+        }
+        let Some(idx) = v.selected_snap_index() else {
+            self.push_error("(no selection)".to_string());
+            return;
+        };
+
+        let snap = v.items[idx].clone();
+        // Imported history records parents; otherwise the next older snap is the base.
+        let parent = snap
+            .parents
+            .first()
+            .filter(|p| ws.store.has_snap(p))
+            .cloned()
+            .or_else(|| {
+                let pos = v.all_items.iter().position(|s| s.id == snap.id)?;
+                v.all_items.get(pos + 1).map(|s| s.id.clone())
+            });
+
+        let (from, from_label) = match parent {
+            Some(id) => match ws.store.get_snap(&id) {
+                Ok(p) => (DiffSide::Manifest(p.root_manifest), short_id(&id)),
+                Err(err) => {
+                    self.push_error(format!("diff: {:#}", err));
+                    return;
+                }
+            },
+            None => (DiffSide::Empty, "(empty)".to_string()),
+        };
+        self.open_diff(
+            &ws,
+            format!("Diff {}..{}", from_label, short_id(&snap.id)),
+            from,
+            DiffSide::Manifest(snap.root_manifest),
+        );
+    }
+}
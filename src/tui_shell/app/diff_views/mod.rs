@@ -0,0 +1,64 @@
+use std::collections::BTreeMap;
+
+use crate::diff::{ContentSource, EntrySig, FileDiff};
+use crate::model::ObjectId;
+
+use super::*;
+
+mod local;
+mod nav;
+mod remote;
+
+/// One side of a diff opened in the TUI.
+pub(super) enum DiffSide {
+    Empty,
+    /// A manifest tree already present in the local store.
+    Manifest(ObjectId),
+    Workspace,
+}
+
+impl App {
+    pub(super) fn open_diff(
+        &mut self,
+        ws: &Workspace,
+        title: String,
+        from: DiffSide,
+        to: DiffSide,
+    ) {
+        match build_file_diffs(ws, &from, &to) {
+            Ok(files) => {
+                let count = files.len();
+                self.push_view(DiffView::new(title, files));
+                self.push_output(vec![format!("opened diff ({} files)", count)]);
+            }
+            Err(err) => self.push_error(format!("diff: {:#}", err)),
+        }
+    }
+}
+
+fn build_file_diffs(ws: &Workspace, from: &DiffSide, to: &DiffSide) -> Result<Vec<FileDiff>> {
+    let changes = crate::diff::diff_trees(&side_tree(ws, from)?, &side_tree(ws, to)?);
+    crate::diff::file_diffs(&changes, side_source(ws, from), side_source(ws, to))
+}
+
+fn side_tree(ws: &Workspace, side: &DiffSide) -> Result<BTreeMap<String, EntrySig>> {
+    match side {
+        DiffSide::Empty => Ok(BTreeMap::new()),
+        DiffSide::Manifest(root) => crate::diff::tree_from_store(&ws.store, root),
+        DiffSide::Workspace => {
+            let (root, manifests, _stats) = ws.current_manifest_tree()?;
+            crate::diff::tree_from_memory(&manifests, &root)
+        }
+    }
+}
+
+fn side_source<'a>(ws: &'a Workspace, side: &DiffSide) -> ContentSource<'a> {
+    match side {
+        DiffSide::Workspace => ContentSource::Dir(&ws.root),
+        _ => ContentSource::Store(&ws.store),
+    }
+}
+
+fn short_id(id: &str) -> String {
+    id.chars().take(8).collect()
+}
@@ -0,0 +1,54 @@
+use super::*;
+
+impl App {
+    pub(in crate::tui_shell) fn cmd_diff_next(&mut self, args: &[String]) {
+        if !args.is_empty() {
+            self.push_error("usage: next".to_string());
+            return;
+        }
+        self.diff_jump_hunk(true);
+    }
+
+    pub(in crate::tui_shell) fn cmd_diff_prev(&mut self, args: &[String]) {
+        if !args.is_empty() {
+            self.push_error("usage: prev".to_string());
+            return;
+        }
+        self.diff_jump_hunk(false);
+    }
+
+    pub(in crate::tui_shell) fn cmd_diff_layout(&mut self, args: &[String]) {
+        let Some(v) = self.current_view_mut::<DiffView>() else {
+            self.push_error("not in diff mode".to_string());
+            return;
+        };
+        let layout = match args {
+            [] => v.layout.toggle(),
+            [a] if a == "unified" => DiffLayout::Unified,
+            [a] if a == "side" || a == "side-by-side" => DiffLayout::SideBySide,
+            _ => {
+                self.push_error("usage: layout [unified|side]".to_string());
+                return;
+            }
+        };
+        v.layout = layout;
+        self.push_output(vec![format!("layout: {}", layout.label())]);
+    }
+
+    pub(in crate::tui_shell) fn diff_jump_hunk(&mut self, forward: bool) {
+        let Some(v) = self.current_view_mut::<DiffView>() else {
+            self.push_error("not in diff mode".to_string());
+            return;
+        };
+        let moved = if forward {
+            v.next_hunk()
+        } else {
+            v.prev_hunk()
+        };
+        if moved {
+            self.panes.detail_scroll = 0;
+        } else {
+            self.push_output(vec!["no hunks".to_string()]);
+        }
+    }
+}
@@ -0,0 +1,163 @@
+use super::*;
+
+/// The id and root manifest of a bundle one side of a diff is taken from.
+type BundleRef = (String, String);
+
+impl App {
+    pub(in crate::tui_shell) fn cmd_inbox_diff_mode(&mut self, args: &[String]) {
+        if !args.is_empty() {
+            self.push_error("usage: diff".to_string());
+            return;
+        }
+        let Some((snap_id, scope, gate)) = self.current_view::<InboxView>().and_then(|v| {
+            let p = v
+                .items
+                .get(v.selected.min(v.items.len().saturating_sub(1)))?;
+            Some((p.snap_id.clone(), p.scope.clone(), p.gate.clone()))
+        }) else {
+            self.push_error("(no selection)".to_string());
+            return;
+        };
+        let Some(ws) = self.require_workspace() else {
+            return;
+        };
+        let Some(client) = self.remote_client() else {
+            return;
+        };
+
+        let opened = (|| -> Result<(String, DiffSide, DiffSide)> {
+            client.fetch_publications(&ws.store, Some(&snap_id))?;
+            let snap = ws.store.get_snap(&snap_id)?;
+            let current = client
+                .list_bundles()?
+                .into_iter()
+                .filter(|b| b.scope == scope && b.gate == gate)
+                .max_by(|a, b| a.created_at.cmp(&b.created_at))
+                .map(|b| (b.id, b.root_manifest));
+            let (from, from_label) = bundle_side(&client, &ws, current)?;
+            Ok((
+                format!("Diff {}..{}", from_label, short_id(&snap.id)),
+                from,
+                DiffSide::Manifest(snap.root_manifest),
+            ))
+        })();
+        self.open_remote_diff(&ws, opened);
+    }
+
+    pub(in crate::tui_shell) fn cmd_bundles_diff_mode(&mut self, args: &[String]) {
+        if !args.is_empty() {
+            self.push_error("usage: diff".to_string());
+            return;
+        }
+        let Some((bundle, scope, gate, created_at)) =
+            self.current_view::<BundlesView>().and_then(|v| {
+                let b = v
+                    .items
+                    .get(v.selected.min(v.items.len().saturating_sub(1)))?;
+                Some((
+                    (b.id.clone(), b.root_manifest.clone()),
+                    b.scope.clone(),
+                    b.gate.clone(),
+                    b.created_at.clone(),
+                ))
+            })
+        else {
+            self.push_error("(no selection)".to_string());
+            return;
+        };
+        let Some(ws) = self.require_workspace() else {
+            return;
+        };
+        let Some(client) = self.remote_client() else {
+            return;
+        };
+
+        let opened = (|| -> Result<(String, DiffSide, DiffSide)> {
+            // The view may be filtered or limited, so look the predecessor up remotely.
+            let prev = client
+                .list_bundles()?
+                .into_iter()
+                .filter(|b| b.scope == scope && b.gate == gate && b.created_at < created_at)
+                .max_by(|a, b| a.created_at.cmp(&b.created_at))
+                .map(|b| (b.id, b.root_manifest));
+            let (from, from_label) = bundle_side(&client, &ws, prev)?;
+            let (to, to_label) = bundle_side(&client, &ws, Some(bundle))?;
+            Ok((format!("Diff {}..{}", from_label, to_label), from, to))
+        })();
+        self.open_remote_diff(&ws, opened);
+    }
+
+    pub(in crate::tui_shell) fn cmd_releases_diff_mode(&mut self, args: &[String]) {
+        if !args.is_empty() {
+            self.push_error("usage: diff".to_string());
+            return;
+        }
+        let Some((channel, bundle_id, released_at)) =
+            self.current_view::<ReleasesView>().and_then(|v| {
+                let r = v
+                    .items
+                    .get(v.selected.min(v.items.len().saturating_sub(1)))?;
+                Some((
+                    r.channel.clone(),
+                    r.bundle_id.clone(),
+                    r.released_at.clone(),
+                ))
+            })
+        else {
+            self.push_error("(no selection)".to_string());
+            return;
+        };
+        let Some(ws) = self.require_workspace() else {
+            return;
+        };
+        let Some(client) = self.remote_client() else {
+            return;
+        };
+
+        let opened = (|| -> Result<(String, DiffSide, DiffSide)> {
+            let prev = client
+                .list_releases()?
+                .into_iter()
+                .filter(|r| r.channel == channel && r.released_at < released_at)
+                .max_by(|a, b| a.released_at.cmp(&b.released_at));
+            let prev = match prev {
+                Some(r) => {
+                    let b = client.get_bundle(&r.bundle_id)?;
+                    Some((b.id, b.root_manifest))
+                }
+                None => None,
+            };
+            let bundle = client.get_bundle(&bundle_id)?;
+            let (from, from_label) = bundle_side(&client, &ws, prev)?;
+            let (to, to_label) =
+                bundle_side(&client, &ws, Some((bundle.id, bundle.root_manifest)))?;
+            Ok((
+                format!("Diff {} {}..{}", channel, from_label, to_label),
+                from,
+                to,
+            ))
+        })();
+        self.open_remote_diff(&ws, opened);
+    }
+
+    fn open_remote_diff(&mut self, ws: &Workspace, opened: Result<(String, DiffSide, DiffSide)>) {
+        match opened {
+            Ok((title, from, to)) => self.open_diff(ws, title, from, to),
+            Err(err) => self.push_error(format!("diff: {:#}", err)),
+        }
+    }
+}
+
+/// Fetches the bundle's tree into the local store; no bundle means an empty tree.
+fn bundle_side(
+    client: &RemoteClient,
+    ws: &Workspace,
+    bundle: Option<BundleRef>,
+) -> Result<(DiffSide, String)> {
+    let Some((id, root)) = bundle else {
+        return Ok((DiffSide::Empty, "(empty)".to_string()));
+    };
+    let root = ObjectId(root);
+    client.fetch_manifest_tree(&ws.store, &root)?;
+    Ok((DiffSide::Manifest(root), short_id(&id)))
+}
@@ -0,0 +1,14 @@
+use super::super::super::*;
+
+pub(super) fn handle_shortcut(app: &mut App, action: KeyAction) {
+    if app.mode() != UiMode::Diff {
+        return;
+    }
+
+    match action {
+        KeyAction::NextHunk => app.diff_jump_hunk(true),
+        KeyAction::PrevHunk => app.diff_jump_hunk(false),
+        KeyAction::ToggleLayout => app.cmd_diff_layout(&[]),
+        _ => {}
+    }
+}
@@ -0,0 +1,19 @@
+use super::super::super::*;
+
+pub(super) fn handle_shortcut(app: &mut App, action: KeyAction) {
+    if app.mode() != UiMode::Merge {
+        return;
+    }
+
+    match action {
+        KeyAction::TakeLeft => app.cmd_merge_choose(ConflictChoice::Left, &[]),
+        KeyAction::TakeRight => app.cmd_merge_choose(ConflictChoice::Right, &[]),
+        KeyAction::TakeBoth => app.cmd_merge_choose(ConflictChoice::Both, &[]),
+        KeyAction::NextConflict => app.cmd_merge_jump(true, &[]),
+        KeyAction::PrevConflict => app.cmd_merge_jump(false, &[]),
+        KeyAction::Undo => app.cmd_merge_undo(&[]),
+        KeyAction::Redo => app.cmd_merge_redo(&[]),
+        KeyAction::EditResult => app.cmd_merge_edit(&[]),
+        _ => {}
+    }
+}
@@ -1,6 +1,8 @@
 use super::super::*;
 
+mod diff_shortcuts;
 mod input_edit;
+mod merge_shortcuts;
 mod movement;
 mod root_mode;
 mod superpositions_shortcuts;
@@ -17,24 +19,44 @@ pub(super) fn handle_key(app: &mut App, key: KeyEvent) {
         return;
     }
 
-    match key.code {
-        KeyCode::Char('q') => app.quit = true,
-        KeyCode::Esc => root_mode::handle_escape(app),
-        KeyCode::Tab => root_mode::handle_tab(app),
-        KeyCode::Enter => root_mode::handle_enter(app),
-        KeyCode::Up => movement::handle_up(app),
-        KeyCode::Down => movement::handle_down(app),
-        KeyCode::Left => movement::handle_left(app),
-        KeyCode::Right => movement::handle_right(app),
-        KeyCode::Char(c)
-            if key.modifiers.contains(KeyModifiers::ALT) && app.input.buf.is_empty() =>
-        {
-            superpositions_shortcuts::handle_alt_shortcut(app, c);
-        }
-        KeyCode::Char(c) if !key.modifiers.contains(KeyModifiers::CONTROL) => {
-            app.input.insert_char(c);
-            app.recompute_suggestions();
+    let scope = match app.mode() {
+        UiMode::Superpositions => KeyScope::Superpositions,
+        UiMode::Diff => KeyScope::Diff,
+        UiMode::Merge => KeyScope::Merge,
+        _ => KeyScope::Global,
+    };
+    if let Some(action) = app.keymap.lookup(scope, key, !app.input.buf.is_empty()) {
+        run_key_action(app, action);
+        return;
+    }
+
+    if let KeyCode::Char(c) = key.code
+        && !key
+            .modifiers
+            .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT)
+    {
+        app.input.insert_char(c);
+        app.recompute_suggestions();
+    }
+}
+
+fn run_key_action(app: &mut App, action: KeyAction) {
+    match action {
+        KeyAction::Quit => app.quit = true,
+        KeyAction::Back => root_mode::handle_escape(app),
+        KeyAction::Complete => root_mode::handle_tab(app),
+        KeyAction::Submit => root_mode::handle_enter(app),
+        KeyAction::Up => movement::handle_up(app),
+        KeyAction::Down => movement::handle_down(app),
+        KeyAction::Left => movement::handle_left(app),
+        KeyAction::Right => movement::handle_right(app),
+        KeyAction::ShrinkPane => app.resize_pane(-1),
+        KeyAction::GrowPane => app.resize_pane(1),
+        KeyAction::ResetPane => app.reset_pane(),
+        _ => {
+            superpositions_shortcuts::handle_shortcut(app, action);
+            diff_shortcuts::handle_shortcut(app, action);
+            merge_shortcuts::handle_shortcut(app, action);
         }
-        _ => {}
     }
 }
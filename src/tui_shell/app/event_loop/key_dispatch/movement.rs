@@ -3,6 +3,7 @@ use super::super::super::*;
 pub(super) fn handle_up(app: &mut App) {
     if app.input.buf.is_empty() {
         app.view_mut().move_up();
+        app.panes.detail_scroll = 0;
         return;
     }
     if !app.suggestions.is_empty() {
@@ -19,6 +20,7 @@ pub(super) fn handle_up(app: &mut App) {
 pub(super) fn handle_down(app: &mut App) {
     if app.input.buf.is_empty() {
         app.view_mut().move_down();
+        app.panes.detail_scroll = 0;
         return;
     }
     if !app.suggestions.is_empty() {
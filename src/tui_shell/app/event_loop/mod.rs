@@ -6,6 +6,7 @@ use std::io;
 use super::*;
 
 mod key_dispatch;
+mod mouse;
 
 pub(super) fn run_loop(
     terminal: &mut Terminal<CrosstermBackend<io::Stdout>>,
@@ -36,8 +37,94 @@ pub(super) fn run_loop(
         if event::poll(Duration::from_millis(50)).context("poll")? {
             match event::read().context("read event")? {
                 Event::Key(k) if k.kind == KeyEventKind::Press => key_dispatch::handle_key(app, k),
+                Event::Mouse(m) => mouse::handle_mouse(app, m),
                 _ => {}
             }
         }
+
+        match app.pending_external.take() {
+            Some(ExternalProgram::Editor(path)) => {
+                let res = run_external(terminal, &editor_command(), &path);
+                app.finish_merge_edit(&path, res);
+            }
+            Some(ExternalProgram::Pager(path)) => {
+                let res = run_external(terminal, &pager_command(), &path);
+                let _ = std::fs::remove_file(&path);
+                if let Err(err) = res {
+                    app.push_error(format!("pager: {:#}", err));
+                }
+            }
+            None => {}
+        }
+    }
+}
+
+/// Applies one key press exactly as the interactive loop does.
+pub(super) fn press_key(app: &mut App, key: KeyEvent) {
+    key_dispatch::handle_key(app, key);
+}
+
+/// Applies one mouse event exactly as the interactive loop does.
+pub(super) fn press_mouse(app: &mut App, mouse: crossterm::event::MouseEvent) {
+    mouse::handle_mouse(app, mouse);
+}
+
+/// `$VISUAL`, then `$EDITOR`, then `vi`.
+fn editor_command() -> String {
+    env_command(&["VISUAL", "EDITOR"]).unwrap_or_else(|| "vi".to_string())
+}
+
+/// `$PAGER`, then `less`.
+fn pager_command() -> String {
+    env_command(&["PAGER"]).unwrap_or_else(|| "less".to_string())
+}
+
+fn env_command(vars: &[&str]) -> Option<String> {
+    vars.iter()
+        .filter_map(|v| std::env::var(v).ok())
+        .find(|c| !c.trim().is_empty())
+}
+
+/// Suspends the TUI, runs `command` (program plus arguments) on `path`, then restores the screen.
+fn run_external(
+    terminal: &mut Terminal<CrosstermBackend<io::Stdout>>,
+    command: &str,
+    path: &std::path::Path,
+) -> Result<()> {
+    use crossterm::event::{DisableMouseCapture, EnableMouseCapture};
+    use crossterm::execute;
+    use crossterm::terminal::{
+        EnterAlternateScreen, LeaveAlternateScreen, disable_raw_mode, enable_raw_mode,
+    };
+
+    let mut parts = command.split_whitespace();
+    let Some(program) = parts.next() else {
+        anyhow::bail!("empty command");
+    };
+
+    disable_raw_mode().context("disable raw mode")?;
+    execute!(
+        terminal.backend_mut(),
+        LeaveAlternateScreen,
+        DisableMouseCapture
+    )
+    .context("leave alternate screen")?;
+    let status = std::process::Command::new(program)
+        .args(parts)
+        .arg(path)
+        .status();
+    execute!(
+        terminal.backend_mut(),
+        EnterAlternateScreen,
+        EnableMouseCapture
+    )
+    .context("enter alternate screen")?;
+    enable_raw_mode().context("enable raw mode")?;
+    terminal.clear().context("clear terminal")?;
+
+    let status = status.with_context(|| format!("run {}", program))?;
+    if !status.success() {
+        anyhow::bail!("{} exited with {}", program, status);
     }
+    Ok(())
 }
@@ -0,0 +1,56 @@
+use crossterm::event::{MouseButton, MouseEvent, MouseEventKind};
+use ratatui::layout::Position;
+
+use super::super::*;
+
+/// Lines scrolled per wheel notch in details panes and modals.
+const WHEEL_LINES: i32 = 3;
+
+/// Clicks select list rows, dragging a divider resizes the panes, and the wheel moves the
+/// list selection or scrolls the details pane (or an open modal) under the pointer.
+pub(super) fn handle_mouse(app: &mut App, mouse: MouseEvent) {
+    let (column, row) = (mouse.column, mouse.row);
+    if let Some(m) = app.modal.as_mut() {
+        let max = m.lines.len().saturating_sub(1);
+        match mouse.kind {
+            MouseEventKind::ScrollUp => m.scroll = m.scroll.saturating_sub(WHEEL_LINES as usize),
+            MouseEventKind::ScrollDown => m.scroll = (m.scroll + WHEEL_LINES as usize).min(max),
+            _ => {}
+        }
+        return;
+    }
+
+    let hits = app.panes.hits.get();
+    let at = Position::new(column, row);
+    let on_list = hits.list.is_some_and(|l| l.area.contains(at));
+    let on_details = hits.details.is_some_and(|d| d.area.contains(at));
+    match mouse.kind {
+        MouseEventKind::Down(MouseButton::Left) => {
+            if hits.split.is_some_and(|s| s.on_divider(column, row)) {
+                app.panes.dragging = true;
+            } else if let Some(index) = hits.list.and_then(|l| l.item_at(column, row)) {
+                app.select_row(index);
+            }
+        }
+        MouseEventKind::Drag(MouseButton::Left) if app.panes.dragging => {
+            if let Some(split) = hits.split {
+                app.set_pane_pct(split.pct_at(column, row));
+            }
+        }
+        MouseEventKind::Up(MouseButton::Left) if app.panes.dragging => {
+            app.panes.dragging = false;
+            app.save_panes();
+        }
+        MouseEventKind::ScrollUp if on_list => {
+            app.view_mut().move_up();
+            app.panes.detail_scroll = 0;
+        }
+        MouseEventKind::ScrollDown if on_list => {
+            app.view_mut().move_down();
+            app.panes.detail_scroll = 0;
+        }
+        MouseEventKind::ScrollUp if on_details => app.scroll_details(-WHEEL_LINES),
+        MouseEventKind::ScrollDown if on_details => app.scroll_details(WHEEL_LINES),
+        _ => {}
+    }
+}
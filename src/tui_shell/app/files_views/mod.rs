@@ -0,0 +1,147 @@
+use crate::browse::{Tree, TreeRef};
+use crate::model::{ManifestEntryKind, ObjectId};
+
+use super::*;
+
+mod open;
+
+impl App {
+    /// Opens the files view at the root of `tree`; `remote` lets it fetch missing objects.
+    pub(super) fn open_files(&mut self, tree: TreeRef, remote: bool) {
+        let Some(entries) = self.files_tree(&tree.root_manifest, remote, |t| t.list("")) else {
+            return;
+        };
+        match entries {
+            Ok(entries) => {
+                let count = entries.len();
+                let label = tree.label();
+                self.push_view(FilesView::new(tree, remote, entries));
+                self.push_output(vec![format!("opened {} ({} entries)", label, count)]);
+            }
+            Err(err) => self.push_error(format!("files: {:#}", err)),
+        }
+    }
+
+    pub(in crate::tui_shell) fn cmd_files_open(&mut self, args: &[String]) {
+        let Some((root, remote, entry)) = self.current_view::<FilesView>().and_then(|v| {
+            let e = v.selected_entry()?;
+            Some((v.tree.root_manifest.clone(), v.remote, e.clone()))
+        }) else {
+            self.push_error("(no selection)".to_string());
+            return;
+        };
+
+        let path = entry.path.clone();
+        let contents = match (&entry.entry.kind, args) {
+            (ManifestEntryKind::Dir { .. }, []) => {
+                let Some(listed) = self.files_tree(&root, remote, |t| t.list(&path)) else {
+                    return;
+                };
+                match listed {
+                    Ok(entries) => {
+                        if let Some(v) = self.current_view_mut::<FilesView>() {
+                            v.enter(path, entries);
+                        }
+                    }
+                    Err(err) => self.push_error(format!("open: {:#}", err)),
+                }
+                return;
+            }
+            (ManifestEntryKind::Symlink { target }, []) => {
+                self.push_output(vec![format!("{} -> {}", path, target)]);
+                return;
+            }
+            (ManifestEntryKind::File { .. } | ManifestEntryKind::FileChunks { .. }, []) => {
+                self.files_tree(&root, remote, |t| t.read(&path))
+            }
+            (ManifestEntryKind::Superposition { variants }, [n]) => {
+                let Ok(n) = n.parse::<usize>() else {
+                    self.push_error(format!(
+                        "usage: open <variant#> (variants: {})",
+                        variants.len()
+                    ));
+                    return;
+                };
+                self.files_tree(&root, remote, |t| t.read_variant(&path, n))
+            }
+            (ManifestEntryKind::Superposition { variants }, _) => {
+                self.push_error(format!(
+                    "usage: open <variant#> (variants: {})",
+                    variants.len()
+                ));
+                return;
+            }
+            _ => {
+                self.push_error("usage: open".to_string());
+                return;
+            }
+        };
+        let Some(contents) = contents else {
+            return;
+        };
+        match contents {
+            Ok(bytes) => self.page_file(&path, &bytes),
+            Err(err) => self.push_error(format!("open: {:#}", err)),
+        }
+    }
+
+    pub(in crate::tui_shell) fn cmd_files_up(&mut self, args: &[String]) {
+        if !args.is_empty() {
+            self.push_error("usage: up".to_string());
+            return;
+        }
+        let Some((root, remote, dir)) = self
+            .current_view::<FilesView>()
+            .map(|v| (v.tree.root_manifest.clone(), v.remote, v.dir.clone()))
+        else {
+            self.push_error("not in files mode".to_string());
+            return;
+        };
+        if dir.is_empty() {
+            self.push_output(vec!["already at the root".to_string()]);
+            return;
+        }
+
+        let parent = dir.rsplit_once('/').map(|(p, _)| p).unwrap_or("");
+        let Some(listed) = self.files_tree(&root, remote, |t| t.list(parent)) else {
+            return;
+        };
+        match listed {
+            Ok(entries) => {
+                if let Some(v) = self.current_view_mut::<FilesView>() {
+                    v.leave(entries);
+                }
+            }
+            Err(err) => self.push_error(format!("up: {:#}", err)),
+        }
+    }
+
+    /// Runs `f` against the tree at `root`; `None` once a missing workspace or remote was reported.
+    fn files_tree<T>(
+        &mut self,
+        root: &ObjectId,
+        remote: bool,
+        f: impl FnOnce(&Tree) -> Result<T>,
+    ) -> Option<Result<T>> {
+        let ws = self.require_workspace()?;
+        let client = if remote {
+            Some(self.remote_client()?)
+        } else {
+            None
+        };
+        let tree = Tree::new(&ws.store, client.as_ref(), root.clone());
+        Some(f(&tree))
+    }
+
+    /// Writes `bytes` to a temp file; the event loop shows it in `$PAGER` and removes it.
+    pub(super) fn page_file(&mut self, path: &str, bytes: &[u8]) {
+        let name = path.rsplit('/').next().unwrap_or("file");
+        let tmp =
+            std::env::temp_dir().join(format!("converge-view-{}-{}", std::process::id(), name));
+        if let Err(err) = std::fs::write(&tmp, bytes) {
+            self.push_error(format!("write {}: {}", tmp.display(), err));
+            return;
+        }
+        self.pending_external = Some(ExternalProgram::Pager(tmp));
+    }
+}
@@ -0,0 +1,104 @@
+use crate::browse::TreeRefKind;
+
+use super::*;
+
+impl App {
+    pub(in crate::tui_shell) fn cmd_snaps_files(&mut self, args: &[String]) {
+        if !args.is_empty() {
+            self.push_error("usage: files".to_string());
+            return;
+        }
+        let Some(v) = self.current_view::<SnapsView>() else {
+            self.push_error("not in snaps mode".to_string());
+            return;
+        };
+        let Some(idx) = v.selected_snap_index() else {
+            self.push_error("select a snap to browse".to_string());
+            return;
+        };
+        let snap = &v.items[idx];
+        let tree = TreeRef {
+            kind: TreeRefKind::Snap,
+            id: snap.id.clone(),
+            root_manifest: snap.root_manifest.clone(),
+        };
+        self.open_files(tree, false);
+    }
+
+    pub(in crate::tui_shell) fn cmd_inbox_files_mode(&mut self, args: &[String]) {
+        if !args.is_empty() {
+            self.push_error("usage: files".to_string());
+            return;
+        }
+        let Some(snap_id) = self.current_view::<InboxView>().and_then(|v| {
+            let p = v
+                .items
+                .get(v.selected.min(v.items.len().saturating_sub(1)))?;
+            Some(p.snap_id.clone())
+        }) else {
+            self.push_error("(no selection)".to_string());
+            return;
+        };
+        let Some(ws) = self.require_workspace() else {
+            return;
+        };
+        let Some(client) = self.remote_client() else {
+            return;
+        };
+        match crate::browse::resolve_tree_ref(&ws.store, Some(&client), &snap_id) {
+            Ok(tree) => self.open_files(tree, true),
+            Err(err) => self.push_error(format!("files: {:#}", err)),
+        }
+    }
+
+    pub(in crate::tui_shell) fn cmd_bundles_files_mode(&mut self, args: &[String]) {
+        if !args.is_empty() {
+            self.push_error("usage: files".to_string());
+            return;
+        }
+        let Some(tree) = self.current_view::<BundlesView>().and_then(|v| {
+            let b = v
+                .items
+                .get(v.selected.min(v.items.len().saturating_sub(1)))?;
+            Some(TreeRef {
+                kind: TreeRefKind::Bundle,
+                id: b.id.clone(),
+                root_manifest: ObjectId(b.root_manifest.clone()),
+            })
+        }) else {
+            self.push_error("(no selection)".to_string());
+            return;
+        };
+        self.open_files(tree, true);
+    }
+
+    pub(in crate::tui_shell) fn cmd_releases_files_mode(&mut self, args: &[String]) {
+        if !args.is_empty() {
+            self.push_error("usage: files".to_string());
+            return;
+        }
+        let Some((channel, bundle_id)) = self.current_view::<ReleasesView>().and_then(|v| {
+            let r = v
+                .items
+                .get(v.selected.min(v.items.len().saturating_sub(1)))?;
+            Some((r.channel.clone(), r.bundle_id.clone()))
+        }) else {
+            self.push_error("(no selection)".to_string());
+            return;
+        };
+        let Some(client) = self.remote_client() else {
+            return;
+        };
+        match client.get_bundle(&bundle_id) {
+            Ok(bundle) => self.open_files(
+                TreeRef {
+                    kind: TreeRefKind::Release,
+                    id: channel,
+                    root_manifest: ObjectId(bundle.root_manifest),
+                },
+                true,
+            ),
+            Err(err) => self.push_error(format!("files: {:#}", err)),
+        }
+    }
+}
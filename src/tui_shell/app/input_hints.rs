@@ -28,35 +28,45 @@ pub(super) fn input_hint_right(app: &App) -> Option<(Line<'static>, usize)> {
     }
 
     let tab_target = match app.root_ctx {
-        RootContext::Local => ("remote", Color::Blue),
-        RootContext::Remote => ("local", Color::Yellow),
+        RootContext::Local => ("remote", app.theme.remote),
+        RootContext::Remote => ("local", app.theme.local),
     };
 
     Some((
         Line::from(vec![
             Span::styled(
                 "/".to_string(),
-                Style::default().fg(Color::Gray).add_modifier(Modifier::DIM),
+                Style::default()
+                    .fg(app.theme.muted)
+                    .add_modifier(Modifier::DIM),
             ),
             Span::raw(" cmds  "),
             Span::styled(
                 "Enter".to_string(),
-                Style::default().fg(Color::Gray).add_modifier(Modifier::DIM),
+                Style::default()
+                    .fg(app.theme.muted)
+                    .add_modifier(Modifier::DIM),
             ),
             Span::raw(" default  "),
             Span::styled(
                 "Esc".to_string(),
-                Style::default().fg(Color::Gray).add_modifier(Modifier::DIM),
+                Style::default()
+                    .fg(app.theme.muted)
+                    .add_modifier(Modifier::DIM),
             ),
             Span::raw(" back  "),
             Span::styled(
                 "q".to_string(),
-                Style::default().fg(Color::Gray).add_modifier(Modifier::DIM),
+                Style::default()
+                    .fg(app.theme.muted)
+                    .add_modifier(Modifier::DIM),
             ),
             Span::raw(" quit  "),
             Span::styled(
                 "Tab".to_string(),
-                Style::default().fg(Color::Gray).add_modifier(Modifier::DIM),
+                Style::default()
+                    .fg(app.theme.muted)
+                    .add_modifier(Modifier::DIM),
             ),
             Span::raw(":"),
             Span::styled(tab_target.0.to_string(), Style::default().fg(tab_target.1)),
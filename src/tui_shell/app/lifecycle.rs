@@ -1,8 +1,12 @@
 use super::*;
 
 impl App {
-    pub(super) fn load(opts: crate::tui::TuiRunOptions) -> Self {
-        let mut app = App::default();
+    pub(super) fn load(opts: crate::tui::TuiRunOptions, config: TuiConfig) -> Self {
+        let mut app = App {
+            theme: config.theme,
+            keymap: config.keymap,
+            ..App::default()
+        };
         let cwd = match std::env::current_dir() {
             Ok(p) => p,
             Err(err) => {
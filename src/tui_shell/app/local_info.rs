@@ -63,8 +63,15 @@ impl App {
         self.refresh_root_view();
 
         let ts_mode = self.ts_mode;
+
+        let theme = self.theme;
         let now = OffsetDateTime::now_utc();
-        let rctx = RenderCtx { now, ts_mode };
+        let rctx = RenderCtx {
+            now,
+            ts_mode,
+            theme,
+            panes: PaneCtx::default(),
+        };
 
         let mut lines = Vec::new();
         lines.push("Local".to_string());
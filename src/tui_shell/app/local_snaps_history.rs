@@ -0,0 +1,114 @@
+use super::*;
+
+impl App {
+    /// Lists the snaps (and, with a remote, bundles and releases) in which `path` changed.
+    pub(super) fn cmd_snaps_history(&mut self, args: &[String]) {
+        let [path] = args else {
+            self.push_error("usage: history <path>".to_string());
+            return;
+        };
+        let Some(ws) = self.require_workspace() else {
+            return;
+        };
+        let client = match self.remote_config() {
+            Some(_) => self.remote_client(),
+            None => None,
+        };
+
+        let history = match crate::history::path_history(&ws.store, client.as_ref(), path) {
+            Ok(h) => h,
+            Err(err) => {
+                self.push_error(format!("history: {:#}", err));
+                return;
+            }
+        };
+        if let Some(warning) = history.warning {
+            self.push_output(vec![format!("warning: {}", warning)]);
+        }
+        let entries = history.entries;
+        if entries.is_empty() {
+            self.push_output(vec![format!("no history for {}", path)]);
+            return;
+        }
+        let mut lines = vec![format!("history of {} ({} changes)", path, entries.len())];
+        for e in &entries {
+            lines.push(format!(
+                "{} {} {} {} {}{}",
+                fmt_ts_ui(&e.at),
+                e.change.status_char(),
+                e.kind.as_str(),
+                e.id.chars().take(8).collect::<String>(),
+                e.by.as_deref().unwrap_or("-"),
+                e.detail
+                    .as_deref()
+                    .map(|d| format!("  {}", d))
+                    .unwrap_or_default()
+            ));
+        }
+        self.push_output(lines);
+    }
+
+    /// Shows `path` at the selected snap (HEAD on the pending row) with per-line origins in `$PAGER`.
+    pub(super) fn cmd_snaps_blame(&mut self, args: &[String]) {
+        let [path] = args else {
+            self.push_error("usage: blame <path>".to_string());
+            return;
+        };
+        let Some(v) = self.current_view::<SnapsView>() else {
+            self.push_error("not in snaps mode".to_string());
+            return;
+        };
+        let tip = match v.selected_snap_index() {
+            Some(idx) => v.items[idx].id.clone(),
+            None => match v.head_id.clone() {
+                Some(h) => h,
+                None => {
+                    self.push_error("no snaps yet".to_string());
+                    return;
+                }
+            },
+        };
+        let Some(ws) = self.require_workspace() else {
+            return;
+        };
+        let client = match self.remote_config() {
+            Some(_) => self.remote_client(),
+            None => None,
+        };
+
+        let blame = match crate::history::blame_path(&ws.store, client.as_ref(), &tip, path) {
+            Ok(b) => b,
+            Err(err) => {
+                self.push_error(format!("blame: {:#}", err));
+                return;
+            }
+        };
+        if let Some(warning) = &blame.warning {
+            self.push_output(vec![format!("warning: {}", warning)]);
+        }
+        let mut out = String::new();
+        for (i, l) in blame.lines.iter().enumerate() {
+            let o = &blame.origins[l.origin];
+            let who = o
+                .publisher
+                .as_deref()
+                .or(o.author.as_deref())
+                .unwrap_or("-");
+            out.push_str(&format!(
+                "{} {:<12} {:>5}) {}\n",
+                o.snap_id.chars().take(8).collect::<String>(),
+                who,
+                i + 1,
+                l.text
+            ));
+        }
+        self.push_output(vec![format!(
+            "blame {} at {} ({} lines, {} origins)",
+            path,
+            tip.chars().take(8).collect::<String>(),
+            blame.lines.len(),
+            blame.origins.len()
+        )]);
+        self.page_file(&format!("{}.blame", path), out.as_bytes());
+    }
+}
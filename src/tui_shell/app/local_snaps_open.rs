@@ -9,6 +9,8 @@ impl App {
         let rctx = RenderCtx {
             now: OffsetDateTime::now_utc(),
             ts_mode: self.ts_mode,
+            theme: self.theme,
+            panes: PaneCtx::default(),
         };
 
         let mut limit: Option<usize> = None;
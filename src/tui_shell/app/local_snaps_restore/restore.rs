@@ -38,6 +38,8 @@ impl App {
                 self.push_output(vec![format!("restored {}", snap_id)]);
 
                 let ts_mode = self.ts_mode;
+
+                let theme = self.theme;
                 if let Some(v) = self.current_view_mut::<SnapsView>() {
                     v.head_id = Some(snap_id.clone());
                     v.updated_at = now_ts();
@@ -45,6 +47,8 @@ impl App {
                     let rctx = RenderCtx {
                         now: OffsetDateTime::now_utc(),
                         ts_mode,
+                        theme,
+                        panes: PaneCtx::default(),
                     };
                     v.pending_changes = local_status_lines(&ws, &rctx)
                         .ok()
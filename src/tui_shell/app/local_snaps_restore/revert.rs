@@ -47,12 +47,16 @@ impl App {
                 self.push_output(vec![format!("reverted to {}", head_id)]);
 
                 let ts_mode = self.ts_mode;
+
+                let theme = self.theme;
                 if let Some(v) = self.current_view_mut::<SnapsView>() {
                     v.head_id = Some(head_id.clone());
 
                     let rctx = RenderCtx {
                         now: OffsetDateTime::now_utc(),
                         ts_mode,
+                        theme,
+                        panes: PaneCtx::default(),
                     };
                     v.pending_changes = local_status_lines(&ws, &rctx)
                         .ok()
@@ -21,6 +21,7 @@ impl App {
             return;
         };
         let ts_mode = self.ts_mode;
+        let theme = self.theme;
         if let Some(v) = self.current_view_mut::<SnapsView>() {
             match ws.list_snaps() {
                 Ok(snaps) => {
@@ -31,6 +32,8 @@ impl App {
                     let rctx = RenderCtx {
                         now: OffsetDateTime::now_utc(),
                         ts_mode,
+                        theme,
+                        panes: PaneCtx::default(),
                     };
                     v.pending_changes = local_status_lines(&ws, &rctx)
                         .ok()
@@ -57,6 +57,8 @@ impl App {
         self.push_output(vec![format!("unsnapped {}", head_id)]);
 
         let ts_mode = self.ts_mode;
+
+        let theme = self.theme;
         if let Some(v) = self.current_view_mut::<SnapsView>() {
             let items = match ws.list_snaps() {
                 Ok(s) => s,
@@ -73,6 +75,8 @@ impl App {
             let rctx = RenderCtx {
                 now: OffsetDateTime::now_utc(),
                 ts_mode,
+                theme,
+                panes: PaneCtx::default(),
             };
             v.pending_changes = local_status_lines(&ws, &rctx)
                 .ok()
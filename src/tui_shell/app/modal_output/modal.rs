@@ -68,10 +68,6 @@ impl App {
         });
     }
 
-    pub(in crate::tui_shell) fn modal_mut(&mut self) -> Option<&mut Modal> {
-        self.modal.as_mut()
-    }
-
     pub(in crate::tui_shell) fn close_modal(&mut self) {
         self.modal = None;
     }
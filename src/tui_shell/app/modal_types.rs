@@ -48,15 +48,22 @@ pub(in crate::tui_shell) enum TextInputAction {
 
     ApproveBundleId,
     SuperpositionsBundleId,
+    MergeRationale,
 
     MemberAction,
     MemberHandle,
     MemberRole,
+    MemberTarget,
 
     LaneMemberAction,
     LaneMemberLane,
     LaneMemberHandle,
 
+    LaneCreateId,
+    LaneCreateDescription,
+    LaneCreateGates,
+    LaneCreateScope,
+
     MoveFrom,
     MoveTo,
 
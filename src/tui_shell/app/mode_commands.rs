@@ -1,6 +1,7 @@
 use super::super::commands::{
-    bundles_command_defs, gate_graph_command_defs, global_command_defs, inbox_command_defs,
-    lanes_command_defs, releases_command_defs, root_command_defs, snaps_command_defs,
+    bundles_command_defs, diff_command_defs, files_command_defs, gate_graph_command_defs,
+    global_command_defs, inbox_command_defs, lanes_command_defs, merge_command_defs,
+    releases_command_defs, root_command_defs, search_command_defs, snaps_command_defs,
     superpositions_command_defs,
 };
 use super::{CommandDef, RootContext, UiMode};
@@ -43,6 +44,26 @@ pub(super) fn mode_command_defs(mode: UiMode, root_ctx: RootContext) -> Vec<Comm
             out.extend(global_command_defs());
             out
         }
+        UiMode::Diff => {
+            let mut out = diff_command_defs();
+            out.extend(global_command_defs());
+            out
+        }
+        UiMode::Merge => {
+            let mut out = merge_command_defs();
+            out.extend(global_command_defs());
+            out
+        }
+        UiMode::Files => {
+            let mut out = files_command_defs();
+            out.extend(global_command_defs());
+            out
+        }
+        UiMode::Search => {
+            let mut out = search_command_defs();
+            out.extend(global_command_defs());
+            out
+        }
         UiMode::Settings => {
             let mut out = vec![CommandDef {
                 name: "back",
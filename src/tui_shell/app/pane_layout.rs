@@ -0,0 +1,66 @@
+use super::super::panes::{MAX_PCT, MIN_PCT, STEP_PCT};
+use super::*;
+
+impl App {
+    /// Pane sizes are kept per view, by mode name.
+    fn pane_key(&self) -> String {
+        format!("{:?}", self.mode()).to_lowercase()
+    }
+
+    /// The user's split for the current view, if they resized it.
+    pub(super) fn pane_split(&self) -> Option<u16> {
+        self.panes.sizes.get(&self.pane_key()).copied()
+    }
+
+    /// Moves the current view's divider by `steps` resize steps (negative shrinks the first
+    /// pane). Views without a split ignore it.
+    pub(super) fn resize_pane(&mut self, steps: i16) {
+        let Some(split) = self.panes.hits.get().split else {
+            return;
+        };
+        let pct = (split.pct as i16 + steps * STEP_PCT as i16).clamp(MIN_PCT as i16, MAX_PCT as i16)
+            as u16;
+        self.set_pane_pct(pct);
+        self.save_panes();
+    }
+
+    /// Returns the current view to its default split.
+    pub(super) fn reset_pane(&mut self) {
+        if self.panes.sizes.remove(&self.pane_key()).is_some() {
+            self.save_panes();
+        }
+    }
+
+    pub(super) fn set_pane_pct(&mut self, pct: u16) {
+        let key = self.pane_key();
+        self.panes.sizes.insert(key, pct.clamp(MIN_PCT, MAX_PCT));
+    }
+
+    pub(super) fn save_panes(&mut self) {
+        if let Err(err) = self.panes.save() {
+            self.push_error(format!("save pane sizes: {:#}", err));
+        }
+    }
+
+    /// Selects the list item at `index`, as if moved to with Up/Down.
+    pub(super) fn select_row(&mut self, index: usize) {
+        let Some(list) = self.panes.hits.get().list else {
+            return;
+        };
+        let current = list.selected.unwrap_or(0);
+        let view = self.view_mut();
+        for _ in index..current {
+            view.move_up();
+        }
+        for _ in current..index {
+            view.move_down();
+        }
+        self.panes.detail_scroll = 0;
+    }
+
+    pub(super) fn scroll_details(&mut self, lines: i32) {
+        let max = self.panes.hits.get().details.map(|d| d.max).unwrap_or(0);
+        let scroll = (self.panes.detail_scroll as i32 + lines).clamp(0, max as i32);
+        self.panes.detail_scroll = scroll as u16;
+    }
+}
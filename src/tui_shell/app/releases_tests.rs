@@ -16,6 +16,8 @@ fn mk_release(
         released_by_user_id: None,
         released_at: released_at.to_string(),
         notes: None,
+        attachments: Vec::new(),
+        signature: None,
     }
 }
 
@@ -42,8 +42,17 @@ impl App {
             }
         };
 
+        // Approvals and releases made from the TUI are signed with the workspace key.
+        let signer = match ws.store.signing_key() {
+            Ok(k) => k,
+            Err(err) => {
+                self.push_error(format!("{:#}", err));
+                return None;
+            }
+        };
+
         match RemoteClient::new(remote, token) {
-            Ok(c) => Some(c),
+            Ok(c) => Some(c.with_signer(signer)),
             Err(err) => {
                 self.push_error(format!("init remote client: {:#}", err));
                 None
@@ -0,0 +1,112 @@
+use super::*;
+
+impl App {
+    // Forms:
+    // - `groups` -> list groups
+    // - `groups show <name>`
+    // - `groups create <name> [description...]`
+    // - `groups delete <name>`
+    // - `groups add <name> <handle|@group>`
+    // - `groups remove <name> <handle|@group>`
+    pub(in crate::tui_shell::app) fn cmd_groups(&mut self, args: &[String]) {
+        let client = match self.remote_client() {
+            Some(c) => c,
+            None => return,
+        };
+        let name = args.get(1).map(|n| n.trim_start_matches('@').to_string());
+
+        let res = match (args.first().map(String::as_str), name, args.get(2)) {
+            (None, _, _) => {
+                match client.list_groups() {
+                    Ok(groups) => {
+                        let mut lines = Vec::new();
+                        if groups.is_empty() {
+                            lines.push("(no groups)".to_string());
+                        }
+                        for g in groups {
+                            lines.push(format!("@{}", g.name));
+                            lines.push(format!("  members: {}", g.members.join(", ")));
+                            if !g.groups.is_empty() {
+                                let nested: Vec<String> =
+                                    g.groups.iter().map(|n| format!("@{}", n)).collect();
+                                lines.push(format!("  groups: {}", nested.join(", ")));
+                            }
+                        }
+                        lines.push("".to_string());
+                        lines.push(
+                            "hint: grant a group with `member add @<group> <role>`".to_string(),
+                        );
+                        self.open_modal("Groups", lines);
+                    }
+                    Err(err) => self.push_error(format!("groups: {:#}", err)),
+                }
+                return;
+            }
+            (Some("show"), Some(name), None) => client.get_group(&name),
+            (Some("create"), Some(name), _) => {
+                let description = (args.len() > 2).then(|| args[2..].join(" "));
+                client.create_group(&name, description)
+            }
+            (Some("delete"), Some(name), None) => match client.delete_group(&name) {
+                Ok(()) => {
+                    self.push_output(vec![format!("deleted @{}", name)]);
+                    return;
+                }
+                Err(err) => Err(err),
+            },
+            (Some("add"), Some(name), Some(member)) => client.add_group_member(&name, member),
+            (Some("remove"), Some(name), Some(member)) => client.remove_group_member(&name, member),
+            _ => {
+                self.push_error(
+                    "usage: groups [show|create|delete <name>] | groups add|remove <name> <handle|@group>"
+                        .to_string(),
+                );
+                return;
+            }
+        };
+
+        match res {
+            Ok(g) => {
+                let mut lines = vec![format!("@{} members: {}", g.name, g.members.join(", "))];
+                if !g.groups.is_empty() {
+                    let nested: Vec<String> = g.groups.iter().map(|n| format!("@{}", n)).collect();
+                    lines.push(format!("@{} groups: {}", g.name, nested.join(", ")));
+                }
+                self.push_output(lines);
+            }
+            Err(err) => self.push_error(format!("groups: {:#}", err)),
+        }
+    }
+
+    pub(in crate::tui_shell::app) fn cmd_audit(&mut self, args: &[String]) {
+        let limit = match args.first().map(|s| s.parse::<usize>()) {
+            None => 50,
+            Some(Ok(n)) => n,
+            Some(Err(_)) => {
+                self.push_error("usage: audit [limit]".to_string());
+                return;
+            }
+        };
+        let client = match self.remote_client() {
+            Some(c) => c,
+            None => return,
+        };
+        match client.list_audit(limit) {
+            Ok(entries) => {
+                let mut lines: Vec<String> = entries
+                    .into_iter()
+                    .rev()
+                    .map(|e| {
+                        let detail = e.detail.map(|d| format!(" {}", d)).unwrap_or_default();
+                        format!("{} {} {} {}{}", e.at, e.actor, e.action, e.target, detail)
+                    })
+                    .collect();
+                if lines.is_empty() {
+                    lines.push("(no audit entries)".to_string());
+                }
+                self.open_modal("Audit", lines);
+            }
+            Err(err) => self.push_error(format!("audit: {:#}", err)),
+        }
+    }
+}
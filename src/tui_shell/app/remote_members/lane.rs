@@ -0,0 +1,52 @@
+use super::*;
+
+use crate::remote::LaneUpdate;
+
+impl App {
+    // Forms:
+    // - `lane` / `lane create` -> wizard
+    // - `lane create <id>` -> wizard for the optional fields
+    // - `lane archive <id>` / `lane unarchive <id>`
+    // - `lane delete <id>`
+    pub(in crate::tui_shell::app) fn cmd_lane(&mut self, args: &[String]) {
+        let (sub, id) = (args.first().map(String::as_str), args.get(1).cloned());
+        match (sub, id) {
+            (None | Some("create"), id) => self.start_lane_wizard(id),
+            (Some("archive" | "unarchive"), Some(id)) => {
+                let client = match self.remote_client() {
+                    Some(c) => c,
+                    None => return,
+                };
+                let archive = sub == Some("archive");
+                let update = LaneUpdate {
+                    archived: Some(archive),
+                    ..Default::default()
+                };
+                match client.update_lane(&id, &update) {
+                    Ok(_) => {
+                        let verb = if archive { "archived" } else { "unarchived" };
+                        self.push_output(vec![format!("{} lane {}", verb, id)]);
+                        self.refresh_root_view();
+                    }
+                    Err(err) => self.push_error(format!("lane {}: {:#}", sub.unwrap_or(""), err)),
+                }
+            }
+            (Some("delete"), Some(id)) => {
+                let client = match self.remote_client() {
+                    Some(c) => c,
+                    None => return,
+                };
+                match client.delete_lane(&id) {
+                    Ok(()) => {
+                        self.push_output(vec![format!("deleted lane {}", id)]);
+                        self.refresh_root_view();
+                    }
+                    Err(err) => self.push_error(format!("lane delete: {:#}", err)),
+                }
+            }
+            _ => self.push_error(
+                "usage: lane [create [id]|archive <id>|unarchive <id>|delete <id>]".to_string(),
+            ),
+        }
+    }
+}
@@ -1,4 +1,5 @@
 use super::*;
+use crate::tui_shell::wizard::MemberTarget;
 
 impl App {
     pub(in crate::tui_shell::app) fn cmd_members(&mut self, args: &[String]) {
@@ -36,6 +37,13 @@ impl App {
             };
             lines.push(format!("- {} {}", h, role));
         }
+        for g in members.grants {
+            let target = MemberTarget {
+                gate: g.gate,
+                lane: g.lane,
+            };
+            lines.push(format!("- {} {}{}", g.handle, g.role, target.describe()));
+        }
 
         if let Some(mut lanes) = lanes {
             lanes.sort_by(|a, b| a.id.cmp(&b.id));
@@ -44,7 +52,15 @@ impl App {
             for l in lanes {
                 let mut m = l.members.into_iter().collect::<Vec<_>>();
                 m.sort();
-                lines.push(format!("lane {} ({})", l.id, m.len()));
+                let archived = if l.archived_at.is_some() {
+                    " archived"
+                } else {
+                    ""
+                };
+                lines.push(format!("lane {} ({}){}", l.id, m.len(), archived));
+                if let Some(d) = &l.description {
+                    lines.push(format!("  {}", d));
+                }
                 if !m.is_empty() {
                     let preview = m.into_iter().take(10).collect::<Vec<_>>().join(", ");
                     lines.push(format!("  {}", preview));
@@ -53,7 +69,7 @@ impl App {
         }
 
         lines.push("".to_string());
-        lines.push("hint: type `member` or `lane-member`".to_string());
+        lines.push("hint: type `member`, `lane`, `lane-member` or `groups`".to_string());
         self.open_modal("Members", lines);
     }
 }
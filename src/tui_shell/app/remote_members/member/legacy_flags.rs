@@ -13,6 +13,8 @@ pub(super) fn run_legacy_member(app: &mut App, args: &[String]) {
     let sub = &args[0];
     let mut handle: Option<String> = None;
     let mut role: String = "read".to_string();
+    let mut gate: Option<String> = None;
+    let mut lane: Option<String> = None;
 
     let mut i = 1;
     while i < args.len() {
@@ -33,6 +35,22 @@ pub(super) fn run_legacy_member(app: &mut App, args: &[String]) {
                 }
                 role = args[i].clone();
             }
+            "--gate" => {
+                i += 1;
+                if i >= args.len() {
+                    app.push_error("missing value for --gate".to_string());
+                    return;
+                }
+                gate = Some(args[i].clone());
+            }
+            "--lane" => {
+                i += 1;
+                if i >= args.len() {
+                    app.push_error("missing value for --lane".to_string());
+                    return;
+                }
+                lane = Some(args[i].clone());
+            }
             a => {
                 app.push_error(format!("unknown arg: {}", a));
                 return;
@@ -47,7 +65,7 @@ pub(super) fn run_legacy_member(app: &mut App, args: &[String]) {
     };
 
     match sub.as_str() {
-        "add" => match client.add_repo_member(&handle, &role) {
+        "add" => match client.add_repo_member(&handle, &role, gate.as_deref(), lane.as_deref()) {
             Ok(()) => {
                 app.push_output(vec![format!("added {} ({})", handle, role)]);
                 app.refresh_root_view();
@@ -1,10 +1,11 @@
 use super::*;
+use crate::tui_shell::wizard::MemberTarget;
 
 pub(super) fn try_prompt_first_member(app: &mut App, args: &[String]) -> bool {
     // Prompt-first UX:
     // - `member` -> wizard
     // - `member add` / `member remove` -> wizard
-    // - `member add <handle> [read|publish]`
+    // - `member add <handle> [role] [gate:<id>|lane:<id>]`
     // - `member remove <handle>`
     let sub = args[0].as_str();
     if !matches!(sub, "add" | "remove" | "rm") {
@@ -38,13 +39,34 @@ pub(super) fn try_prompt_first_member(app: &mut App, args: &[String]) -> bool {
         Some(MemberAction::Add) => {
             let role = args.get(2).cloned().unwrap_or_else(|| "read".to_string());
             let role_lc = role.to_lowercase();
-            if role_lc != "read" && role_lc != "publish" {
-                app.push_error("role must be read or publish".to_string());
+            let legacy = crate::remote::LEGACY_REPO_ROLES.contains(&role_lc.as_str());
+            if !legacy && !crate::remote::REPO_ROLES.contains(&role_lc.as_str()) {
+                app.push_error(format!(
+                    "unknown role {} (expected {}, {})",
+                    role_lc,
+                    crate::remote::LEGACY_REPO_ROLES.join(", "),
+                    crate::remote::REPO_ROLES.join(", ")
+                ));
                 return true;
             }
-            match client.add_repo_member(&handle, &role_lc) {
+            let Some(target) = MemberTarget::parse(args.get(3).map(|s| s.as_str()).unwrap_or(""))
+            else {
+                app.push_error("target must be gate:<id> or lane:<id>".to_string());
+                return true;
+            };
+            match client.add_repo_member(
+                &handle,
+                &role_lc,
+                target.gate.as_deref(),
+                target.lane.as_deref(),
+            ) {
                 Ok(()) => {
-                    app.push_output(vec![format!("added {} ({})", handle, role_lc)]);
+                    app.push_output(vec![format!(
+                        "added {} ({}{})",
+                        handle,
+                        role_lc,
+                        target.describe()
+                    )]);
                     app.refresh_root_view();
                 }
                 Err(err) => app.push_error(format!("member add: {:#}", err)),
@@ -1,5 +1,7 @@
 use super::*;
 
+mod groups;
+mod lane;
 mod lane_member;
 mod list;
 mod member;
@@ -69,6 +69,15 @@ impl App {
 
         let validation = validate_resolution(&ws.store, &root, &decisions).ok();
 
+        // Server records are informational here; a reader can still browse variants locally.
+        let records = match client.get_bundle_resolutions(&bundle_id) {
+            Ok(r) => r.records,
+            Err(err) => {
+                self.push_error(format!("fetch resolution records: {:#}", err));
+                Default::default()
+            }
+        };
+
         let filter_lc = parsed.filter.as_ref().map(|s| s.to_lowercase());
         let mut items = variants
             .iter()
@@ -87,6 +96,7 @@ impl App {
             root_manifest: root,
             variants,
             decisions,
+            records,
             validation,
             items,
             selected: 0,
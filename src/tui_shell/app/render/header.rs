@@ -19,22 +19,22 @@ pub(super) fn render_header(frame: &mut ratatui::Frame, app: &App, area: ratatui
     let mut spans = vec![
         Span::styled(
             "Converge",
-            Style::default().fg(Color::Black).bg(Color::White),
+            Style::default().add_modifier(Modifier::REVERSED),
         ),
         Span::raw("  "),
         Span::styled(
             app.prompt(),
-            Style::default().fg(root_ctx_color(app.root_ctx)),
+            Style::default().fg(root_ctx_color(&app.theme, app.root_ctx)),
         ),
         Span::raw("  "),
         Span::raw(header_mid),
     ];
     if let Some(id) = app.remote_identity.as_deref() {
         spans.push(Span::raw("  "));
-        spans.push(Span::styled(id, Style::default().fg(Color::Green)));
+        spans.push(Span::styled(id, Style::default().fg(app.theme.ok)));
     } else if let Some(note) = app.remote_identity_note.as_deref() {
         spans.push(Span::raw("  "));
-        spans.push(Span::styled(note, Style::default().fg(Color::Red)));
+        spans.push(Span::styled(note, Style::default().fg(app.theme.error)));
     }
 
     let header = Paragraph::new(Line::from(spans)).block(Block::default().borders(Borders::BOTTOM));
@@ -3,7 +3,7 @@ use super::*;
 pub(super) fn render_input(frame: &mut ratatui::Frame, app: &App, area: ratatui::layout::Rect) {
     let prompt = app.prompt();
     let buf = &app.input.buf;
-    let prompt_color = root_ctx_color(app.root_ctx);
+    let prompt_color = root_ctx_color(&app.theme, app.root_ctx);
 
     let mut input_spans = Vec::new();
     input_spans.push(Span::styled(prompt, Style::default().fg(prompt_color)));
@@ -15,7 +15,9 @@ pub(super) fn render_input(frame: &mut ratatui::Frame, app: &App, area: ratatui:
         input_spans.push(Span::raw(sep));
         input_spans.push(Span::styled(
             hint,
-            Style::default().fg(Color::Gray).add_modifier(Modifier::DIM),
+            Style::default()
+                .fg(app.theme.muted)
+                .add_modifier(Modifier::DIM),
         ));
     }
 
@@ -25,9 +25,16 @@ pub(super) fn draw(frame: &mut ratatui::Frame, app: &App) {
 
     render_header(frame, app, chunks[0]);
 
+    app.panes.hits.take();
     let ctx = RenderCtx {
         now: OffsetDateTime::now_utc(),
         ts_mode: app.ts_mode,
+        theme: app.theme,
+        panes: PaneCtx {
+            split: app.pane_split(),
+            detail_scroll: app.panes.detail_scroll,
+            hits: Some(&app.panes.hits),
+        },
     };
     app.view().render(frame, chunks[1], &ctx);
 
@@ -42,7 +49,7 @@ pub(super) fn draw(frame: &mut ratatui::Frame, app: &App) {
 
     if let Some(m) = &app.modal {
         dim_frame(frame);
-        modal::draw_modal(frame, m);
+        modal::draw_modal(frame, m, &app.theme);
         return;
     }
 
@@ -4,22 +4,22 @@ pub(super) fn render_status(frame: &mut ratatui::Frame, app: &App, area: ratatui
     let mut lines = Vec::new();
     if let Some(cmd) = &app.last_command {
         lines.push(Line::from(vec![
-            Span::styled("> ", Style::default().fg(Color::Cyan)),
+            Span::styled("> ", Style::default().fg(app.theme.accent)),
             Span::raw(cmd.as_str()),
         ]));
     }
     if let Some(r) = &app.last_result {
         let style = match r.kind {
-            EntryKind::Output => Style::default().fg(Color::White),
-            EntryKind::Error => Style::default().fg(Color::Red),
-            EntryKind::Command => Style::default().fg(Color::Cyan),
+            EntryKind::Output => Style::default().fg(app.theme.text),
+            EntryKind::Error => Style::default().fg(app.theme.error),
+            EntryKind::Command => Style::default().fg(app.theme.accent),
         };
         for (i, l) in r.lines.iter().enumerate() {
             if i == 0 {
                 lines.push(Line::from(vec![
                     Span::styled(
                         format!("{} ", fmt_ts_ui(&r.ts)),
-                        Style::default().fg(Color::Gray),
+                        Style::default().fg(app.theme.muted),
                     ),
                     Span::styled(l.as_str(), style),
                 ]));
@@ -5,6 +5,13 @@ pub(super) fn render_suggestions(
     app: &App,
     area: ratatui::layout::Rect,
 ) {
+    let query = app
+        .input
+        .buf
+        .trim_start_matches('/')
+        .split_whitespace()
+        .next()
+        .unwrap_or("");
     let mut s_lines = Vec::new();
     let total = app.suggestions.len();
     let sel_idx = app
@@ -12,7 +19,7 @@ pub(super) fn render_suggestions(
         .min(app.suggestions.len().saturating_sub(1));
     s_lines.push(Line::from(Span::styled(
         format!("Suggestions {}/{}", sel_idx + 1, total),
-        Style::default().fg(Color::Gray),
+        Style::default().fg(app.theme.muted),
     )));
 
     let inner_h = area.height.saturating_sub(2) as usize;
@@ -30,14 +37,19 @@ pub(super) fn render_suggestions(
         let s = &app.suggestions[i];
         let sel = i == sel_idx;
         let style = if sel {
-            Style::default().bg(Color::DarkGray)
+            Style::default().bg(app.theme.selected)
         } else {
             Style::default()
         };
-        s_lines.push(Line::from(vec![
-            Span::styled(format!("{: <10}", s.name), style.fg(Color::Yellow)),
-            Span::styled(s.help, style.fg(Color::White)),
-        ]));
+        let name = format!("{: <10}", s.name);
+        let mut spans = highlight_matches(
+            &name,
+            query,
+            style.fg(app.theme.heading),
+            style.fg(app.theme.accent).add_modifier(Modifier::BOLD),
+        );
+        spans.push(Span::styled(s.help, style.fg(app.theme.text)));
+        s_lines.push(Line::from(spans));
     }
     let sugg =
         Paragraph::new(s_lines).block(Block::default().borders(Borders::TOP | Borders::BOTTOM));
@@ -35,8 +35,14 @@ impl App {
         let ws = self.workspace.clone();
         let ctx = self.root_ctx;
         let ts_mode = self.ts_mode;
+        let theme = self.theme;
         let now = OffsetDateTime::now_utc();
-        let rctx = RenderCtx { now, ts_mode };
+        let rctx = RenderCtx {
+            now,
+            ts_mode,
+            theme,
+            panes: PaneCtx::default(),
+        };
 
         let remote_cfg = ws
             .as_ref()
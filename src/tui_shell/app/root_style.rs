@@ -1,10 +1,11 @@
 use ratatui::style::Color;
 
+use super::Theme;
 use super::types::RootContext;
 
-pub(in crate::tui_shell) fn root_ctx_color(ctx: RootContext) -> Color {
+pub(in crate::tui_shell) fn root_ctx_color(theme: &Theme, ctx: RootContext) -> Color {
     match ctx {
-        RootContext::Local => Color::Yellow,
-        RootContext::Remote => Color::Blue,
+        RootContext::Local => theme.local,
+        RootContext::Remote => theme.remote,
     }
 }
@@ -1,6 +1,7 @@
 use std::io::{self, IsTerminal};
 
 use anyhow::{Context, Result};
+use crossterm::event::{DisableMouseCapture, EnableMouseCapture};
 use crossterm::execute;
 use crossterm::terminal::{
     EnterAlternateScreen, LeaveAlternateScreen, disable_raw_mode, enable_raw_mode,
@@ -8,26 +9,39 @@ use crossterm::terminal::{
 use ratatui::Terminal;
 use ratatui::backend::CrosstermBackend;
 
-use super::{App, event_loop};
+use super::super::panes::state_path;
+use super::{App, Panes, TuiConfig, event_loop};
 
 pub(in crate::tui_shell) fn run(opts: crate::tui::TuiRunOptions) -> Result<()> {
     if !io::stdin().is_terminal() || !io::stdout().is_terminal() {
         anyhow::bail!("TUI requires an interactive terminal (TTY)");
     }
 
+    // Config problems are reported before the terminal is taken over.
+    let workspace = std::env::current_dir()
+        .ok()
+        .and_then(|cwd| crate::workspace::Workspace::discover(&cwd).ok());
+    let config = TuiConfig::load(workspace.as_ref().map(|ws| ws.root.as_path()))?;
+
     let mut stdout = io::stdout();
     enable_raw_mode().context("enable raw mode")?;
-    execute!(stdout, EnterAlternateScreen).context("enter alternate screen")?;
+    execute!(stdout, EnterAlternateScreen, EnableMouseCapture).context("enter alternate screen")?;
 
     let backend = CrosstermBackend::new(stdout);
     let mut terminal = Terminal::new(backend).context("create terminal")?;
     terminal.clear().ok();
 
-    let mut app = App::load(opts);
+    let mut app = App::load(opts, config);
+    app.panes = Panes::load(state_path());
     let res = event_loop::run_loop(&mut terminal, &mut app);
 
     disable_raw_mode().ok();
-    execute!(terminal.backend_mut(), LeaveAlternateScreen).ok();
+    execute!(
+        terminal.backend_mut(),
+        LeaveAlternateScreen,
+        DisableMouseCapture
+    )
+    .ok();
     terminal.show_cursor().ok();
 
     res
@@ -0,0 +1,119 @@
+use serde::Deserialize;
+
+use super::super::{App, EntryKind};
+
+/// Checks a script makes against the app after its previous steps. Every field is optional;
+/// strings other than `mode`, `root_context` and `input` are substring matches.
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(super) struct Expect {
+    /// Current view mode, lowercase (`root`, `snaps`, `bundles`, `gategraph`, ...).
+    mode: Option<String>,
+    /// `local` or `remote`.
+    root_context: Option<String>,
+    title: Option<String>,
+    input: Option<String>,
+    /// Title of the open modal.
+    modal: Option<String>,
+    #[serde(default)]
+    no_modal: bool,
+    /// Text of the last command's output or error.
+    output: Option<String>,
+    /// The last command failed with this text.
+    error: Option<String>,
+    #[serde(default)]
+    no_error: bool,
+    /// Text anywhere on the rendered screen.
+    screen: Option<String>,
+    quit: Option<bool>,
+}
+
+impl Expect {
+    /// Failure messages; empty when every check holds.
+    pub(super) fn check(&self, app: &App, screen: &[String]) -> Vec<String> {
+        let mut failures = Vec::new();
+        let mode = mode_name(app);
+        if let Some(want) = &self.mode
+            && !want.eq_ignore_ascii_case(&mode)
+        {
+            failures.push(format!("mode: expected {}, got {}", want, mode));
+        }
+        if let Some(want) = &self.root_context
+            && !want.eq_ignore_ascii_case(app.root_ctx.label())
+        {
+            failures.push(format!(
+                "root_context: expected {}, got {}",
+                want,
+                app.root_ctx.label()
+            ));
+        }
+        if let Some(want) = &self.title
+            && !app.view().title().contains(want.as_str())
+        {
+            failures.push(format!(
+                "title: expected '{}' in '{}'",
+                want,
+                app.view().title()
+            ));
+        }
+        if let Some(want) = &self.input
+            && *want != app.input.buf
+        {
+            failures.push(format!(
+                "input: expected '{}', got '{}'",
+                want, app.input.buf
+            ));
+        }
+        match (&self.modal, &app.modal) {
+            (Some(want), Some(m)) if !m.title.contains(want.as_str()) => {
+                failures.push(format!("modal: expected '{}' in '{}'", want, m.title));
+            }
+            (Some(want), None) => failures.push(format!("modal: expected '{}', none open", want)),
+            _ => {}
+        }
+        if self.no_modal
+            && let Some(m) = &app.modal
+        {
+            failures.push(format!("no_modal: '{}' is open", m.title));
+        }
+        let result = app.last_result.as_ref();
+        let result_text = result.map(|r| r.lines.join("\n")).unwrap_or_default();
+        let is_error = result.is_some_and(|r| r.kind == EntryKind::Error);
+        if let Some(want) = &self.output
+            && !result_text.contains(want.as_str())
+        {
+            failures.push(format!("output: expected '{}' in '{}'", want, result_text));
+        }
+        if let Some(want) = &self.error
+            && !(is_error && result_text.contains(want.as_str()))
+        {
+            failures.push(format!(
+                "error: expected '{}', got {}",
+                want,
+                if is_error {
+                    format!("'{}'", result_text)
+                } else {
+                    "no error".to_string()
+                }
+            ));
+        }
+        if self.no_error && is_error {
+            failures.push(format!("no_error: '{}'", result_text));
+        }
+        if let Some(want) = &self.screen
+            && !screen.iter().any(|l| l.contains(want.as_str()))
+        {
+            failures.push(format!("screen: '{}' not rendered", want));
+        }
+        if let Some(want) = self.quit
+            && want != app.quit
+        {
+            failures.push(format!("quit: expected {}, got {}", want, app.quit));
+        }
+        failures
+    }
+}
+
+pub(super) fn mode_name(app: &App) -> String {
+    format!("{:?}", app.mode()).to_lowercase()
+}
@@ -0,0 +1,205 @@
+use std::io::Write;
+
+use anyhow::{Context, Result};
+use ratatui::Terminal;
+use ratatui::backend::TestBackend;
+use serde_json::{Value, json};
+
+use super::event_loop::{press_key, press_mouse};
+use super::*;
+
+mod expect;
+mod steps;
+
+use self::expect::mode_name;
+use self::steps::{Step, parse_script};
+
+/// Runs `opts.script` against a `TestBackend` of the requested size. Each step is reported as a
+/// JSON line on `out`, followed by `expect` results and (on request) the rendered screen.
+pub(in crate::tui_shell) fn run_script(
+    opts: crate::tui::TuiScriptOptions,
+    out: &mut dyn Write,
+) -> Result<crate::tui::TuiScriptSummary> {
+    let text = std::fs::read_to_string(&opts.script)
+        .with_context(|| format!("read script {}", opts.script.display()))?;
+    let steps =
+        parse_script(&text).with_context(|| format!("parse script {}", opts.script.display()))?;
+
+    // Built-in theme and keymap, so a script behaves the same whatever `tui.toml` files exist.
+    let config = TuiConfig::default();
+
+    let mut terminal =
+        Terminal::new(TestBackend::new(opts.width, opts.height)).context("create terminal")?;
+    let mut app = App::load(
+        crate::tui::TuiRunOptions {
+            agent_trace: opts.agent_trace,
+        },
+        config,
+    );
+    let mut summary = crate::tui::TuiScriptSummary::default();
+    let mut screen = draw(&mut terminal, &mut app)?;
+
+    for (line, step) in steps {
+        if app.quit {
+            break;
+        }
+        summary.steps += 1;
+        let mut show_frame = opts.frames;
+        let mut failures = None;
+        match step {
+            Step::Keys(keys) => {
+                for key in keys {
+                    press_key(&mut app, key);
+                    run_pending_external(&mut app, out)?;
+                    if app.quit {
+                        break;
+                    }
+                }
+            }
+            Step::Mouse(events) => {
+                for mouse in events {
+                    press_mouse(&mut app, mouse);
+                }
+            }
+            Step::Type(text) => type_text(&mut app, &text),
+            Step::SetInput(text) => match app.modal.as_mut() {
+                Some(modal) => modal.input.set(text),
+                None => {
+                    app.input.set(text);
+                    app.recompute_suggestions();
+                }
+            },
+            Step::Command(cmd) => {
+                if let Some(m) = &app.modal {
+                    anyhow::bail!(
+                        "line {}: command '{}' with modal '{}' open",
+                        line,
+                        cmd,
+                        m.title
+                    );
+                }
+                app.input.set(cmd);
+                app.run_current_input();
+                run_pending_external(&mut app, out)?;
+            }
+            Step::Expect(expect) => failures = Some(expect.check(&app, &screen)),
+            Step::Frame => show_frame = true,
+        }
+        screen = draw(&mut terminal, &mut app)?;
+
+        emit(
+            out,
+            "step",
+            json!({ "step": summary.steps, "line": line, "state": state(&app) }),
+        )?;
+        if let Some(failures) = failures {
+            if !failures.is_empty() {
+                summary.failed += 1;
+            }
+            emit(
+                out,
+                "expect",
+                json!({ "line": line, "ok": failures.is_empty(), "failures": failures }),
+            )?;
+        }
+        if show_frame {
+            emit(out, "frame", json!({ "line": line, "lines": screen }))?;
+        }
+    }
+
+    summary.quit = app.quit;
+    app.trace_session_end(if app.quit { "quit" } else { "script_end" });
+    emit(
+        out,
+        "summary",
+        json!({ "steps": summary.steps, "failed": summary.failed, "quit": summary.quit }),
+    )?;
+    Ok(summary)
+}
+
+/// Renders the app and returns the screen as text, one string per row.
+fn draw(terminal: &mut Terminal<TestBackend>, app: &mut App) -> Result<Vec<String>> {
+    app.trace_screen_view_if_changed();
+    terminal
+        .draw(|f| super::render::draw(f, app))
+        .context("draw")?;
+    let buf = terminal.backend().buffer();
+    let width = buf.area.width as usize;
+    Ok(buf
+        .content
+        .chunks(width.max(1))
+        .map(|row| {
+            row.iter()
+                .map(|c| c.symbol())
+                .collect::<String>()
+                .trim_end()
+                .to_string()
+        })
+        .collect())
+}
+
+/// Typed text goes to the open modal's input, as keys would, or else to the command input.
+fn type_text(app: &mut App, text: &str) {
+    if let Some(modal) = app.modal.as_mut() {
+        for c in text.chars() {
+            modal.input.insert_char(c);
+        }
+        return;
+    }
+    for c in text.chars() {
+        app.input.insert_char(c);
+    }
+    app.recompute_suggestions();
+}
+
+/// There is no terminal to hand over: pager contents are reported as an event and an edited
+/// merge result is taken as saved unchanged.
+fn run_pending_external(app: &mut App, out: &mut dyn Write) -> Result<()> {
+    match app.pending_external.take() {
+        Some(ExternalProgram::Pager(path)) => {
+            let text = std::fs::read_to_string(&path).unwrap_or_default();
+            let _ = std::fs::remove_file(&path);
+            emit(
+                out,
+                "external",
+                json!({ "program": "pager", "lines": text.lines().collect::<Vec<_>>() }),
+            )?;
+        }
+        Some(ExternalProgram::Editor(path)) => {
+            emit(
+                out,
+                "external",
+                json!({ "program": "editor", "path": path.display().to_string() }),
+            )?;
+            app.finish_merge_edit(&path, Ok(()));
+        }
+        None => {}
+    }
+    Ok(())
+}
+
+fn state(app: &App) -> Value {
+    let result = app.last_result.as_ref();
+    json!({
+        "mode": mode_name(app),
+        "root_context": app.root_ctx.label(),
+        "title": app.view().title(),
+        "input": app.input.buf,
+        "modal": app.modal.as_ref().map(|m| m.title.clone()),
+        "modal_input": app.modal.as_ref().map(|m| m.input.buf.clone()),
+        "last_command": app.last_command,
+        "output": result.map(|r| r.lines.clone()).unwrap_or_default(),
+        "error": result.is_some_and(|r| r.kind == EntryKind::Error),
+    })
+}
+
+fn emit(out: &mut dyn Write, event: &str, body: Value) -> Result<()> {
+    let mut line = serde_json::Map::new();
+    line.insert("event".to_string(), Value::String(event.to_string()));
+    if let Value::Object(fields) = body {
+        line.extend(fields);
+    }
+    serde_json::to_writer(&mut *out, &line).context("write script event")?;
+    out.write_all(b"\n").context("write script event")?;
+    Ok(())
+}
@@ -0,0 +1,168 @@
+use anyhow::{Context, Result, bail};
+use crossterm::event::{KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
+use serde::Deserialize;
+use serde_json::Value;
+
+use super::super::super::config::KeySpec;
+use super::expect::Expect;
+
+/// One thing a script does to the app.
+#[derive(Clone, Debug)]
+pub(super) enum Step {
+    /// Key presses, dispatched as in the interactive loop.
+    Keys(Vec<KeyEvent>),
+    /// Mouse events, dispatched as in the interactive loop.
+    Mouse(Vec<MouseEvent>),
+    /// Text typed at the cursor of the open modal's input, or the command input.
+    Type(String),
+    /// Replaces the whole focused input, for prompts that come prefilled.
+    SetInput(String),
+    /// A command line, run as if typed and submitted.
+    Command(String),
+    Expect(Box<Expect>),
+    /// Emit the rendered screen.
+    Frame,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RawStep {
+    key: Option<String>,
+    keys: Option<Vec<String>>,
+    mouse: Option<String>,
+    #[serde(rename = "type")]
+    text: Option<String>,
+    input: Option<String>,
+    command: Option<String>,
+    expect: Option<Expect>,
+    #[serde(default)]
+    frame: bool,
+}
+
+/// Steps with their 1-based line numbers. Blank lines and `#` comments are skipped, and agent
+/// trace lines (`--agent-trace` output) replay their key presses, so a recorded session can be
+/// used as a script.
+pub(super) fn parse_script(text: &str) -> Result<Vec<(usize, Step)>> {
+    let mut out = Vec::new();
+    for (i, line) in text.lines().enumerate() {
+        let line_no = i + 1;
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let value: Value = serde_json::from_str(line)
+            .with_context(|| format!("line {}: invalid JSON", line_no))?;
+        let step = if value.get("event").is_some() {
+            trace_step(&value)
+        } else {
+            Some(script_step(value).with_context(|| format!("line {}", line_no))?)
+        };
+        if let Some(step) = step {
+            out.push((line_no, step));
+        }
+    }
+    Ok(out)
+}
+
+fn script_step(value: Value) -> Result<Step> {
+    let raw: RawStep = serde_json::from_value(value)?;
+    let mut steps = Vec::new();
+    if let Some(k) = raw.key {
+        steps.push(Step::Keys(vec![parse_key(&k)?]));
+    }
+    if let Some(ks) = raw.keys {
+        steps.push(Step::Keys(
+            ks.iter().map(|k| parse_key(k)).collect::<Result<_>>()?,
+        ));
+    }
+    if let Some(m) = raw.mouse {
+        steps.push(Step::Mouse(parse_mouse(&m)?));
+    }
+    if let Some(t) = raw.text {
+        steps.push(Step::Type(t));
+    }
+    if let Some(t) = raw.input {
+        steps.push(Step::SetInput(t));
+    }
+    if let Some(c) = raw.command {
+        steps.push(Step::Command(c));
+    }
+    if let Some(e) = raw.expect {
+        steps.push(Step::Expect(Box::new(e)));
+    }
+    if raw.frame {
+        steps.push(Step::Frame);
+    }
+    match steps.len() {
+        1 => Ok(steps.remove(0)),
+        0 => bail!("expected one of key, keys, mouse, type, input, command, expect, frame"),
+        _ => bail!(
+            "a step does exactly one of key, keys, mouse, type, input, command, expect, frame"
+        ),
+    }
+}
+
+/// Key presses from the trace are replayed; everything else in it follows from them.
+fn trace_step(value: &Value) -> Option<Step> {
+    let payload = value.get("payload")?;
+    if value.get("event")?.as_str()? != "user_action"
+        || payload.get("action")?.as_str()? != "key_press"
+    {
+        return None;
+    }
+    // The trace writes a space as the character itself.
+    let key = match payload.get("key")?.as_str()? {
+        " " => "space",
+        k => k,
+    };
+    let key = parse_key(key).ok()?;
+    Some(Step::Keys(vec![key]))
+}
+
+fn parse_key(s: &str) -> Result<KeyEvent> {
+    KeySpec::parse(s)
+        .map(KeySpec::to_event)
+        .map_err(anyhow::Error::msg)
+}
+
+/// Parses `<kind> <column> <row>`, with kind one of `click` (press and release), `down`,
+/// `drag`, `up`, `scroll-up` or `scroll-down`; positions are 0-based screen cells.
+fn parse_mouse(s: &str) -> Result<Vec<MouseEvent>> {
+    let parts: Vec<&str> = s.split_whitespace().collect();
+    let [kind, column, row] = parts[..] else {
+        bail!("mouse '{}': expected '<kind> <column> <row>'", s);
+    };
+    let column: u16 = column
+        .parse()
+        .with_context(|| format!("mouse '{}': bad column", s))?;
+    let row: u16 = row
+        .parse()
+        .with_context(|| format!("mouse '{}': bad row", s))?;
+    let event = |kind| MouseEvent {
+        kind,
+        column,
+        row,
+        modifiers: KeyModifiers::NONE,
+    };
+    let left = MouseButton::Left;
+    Ok(match kind {
+        "click" => vec![
+            event(MouseEventKind::Down(left)),
+            event(MouseEventKind::Up(left)),
+        ],
+        "down" => vec![event(MouseEventKind::Down(left))],
+        "drag" => vec![event(MouseEventKind::Drag(left))],
+        "up" => vec![event(MouseEventKind::Up(left))],
+        "scroll-up" => vec![event(MouseEventKind::ScrollUp)],
+        "scroll-down" => vec![event(MouseEventKind::ScrollDown)],
+        _ => bail!(
+            "mouse '{}': unknown kind '{}' (expected click, down, drag, up, scroll-up, scroll-down)",
+            s,
+            kind
+        ),
+    })
+}
+
+#[cfg(test)]
+#[path = "../../../tests/tui_shell/app/script/steps_tests.rs"]
+mod tests;
@@ -0,0 +1,141 @@
+use super::*;
+
+mod sources;
+
+use self::sources::{
+    bundle_candidates, lane_candidates, publication_candidates, release_candidates,
+    snap_candidates, user_candidates,
+};
+use super::super::views::{SearchTarget, SearchView};
+
+impl App {
+    pub(super) fn cmd_search(&mut self, args: &[String]) {
+        if args.is_empty() {
+            self.push_error("usage: search <query...>".to_string());
+            return;
+        }
+        let query = args.join(" ");
+        let Some(ws) = self.require_workspace() else {
+            return;
+        };
+
+        let mut candidates = Vec::new();
+        let mut notes = Vec::new();
+        match ws.list_snaps() {
+            Ok(snaps) => candidates.extend(snap_candidates(&snaps)),
+            Err(err) => notes.push(format!("snaps: {:#}", err)),
+        }
+
+        let client = if self.remote_config().is_some() {
+            self.remote_client()
+        } else {
+            None
+        };
+        match client {
+            Some(client) => {
+                match client.list_publications() {
+                    Ok(p) => candidates.extend(publication_candidates(&p)),
+                    Err(err) => notes.push(format!("publications: {:#}", err)),
+                }
+                match client.list_bundles() {
+                    Ok(b) => candidates.extend(bundle_candidates(&b)),
+                    Err(err) => notes.push(format!("bundles: {:#}", err)),
+                }
+                match client.list_releases() {
+                    Ok(r) => candidates.extend(release_candidates(&r)),
+                    Err(err) => notes.push(format!("releases: {:#}", err)),
+                }
+                let lanes = match client.list_lanes() {
+                    Ok(l) => l,
+                    Err(err) => {
+                        notes.push(format!("lanes: {:#}", err));
+                        Vec::new()
+                    }
+                };
+                candidates.extend(lane_candidates(&lanes));
+                let members = client.list_repo_members();
+                if let Err(err) = &members {
+                    notes.push(format!("users: {:#}", err));
+                }
+                candidates.extend(user_candidates(members.ok().as_ref(), &lanes));
+            }
+            None => notes.push("remote not searched (no remote configured)".to_string()),
+        }
+
+        let view = SearchView::new(&query, candidates, notes);
+        let count = view.hits.len();
+        self.push_view(view);
+        self.push_output(vec![format!("search \"{}\": {} matches", query, count)]);
+    }
+
+    /// Opens the view holding the selected match and selects it there.
+    pub(super) fn cmd_search_open(&mut self, args: &[String]) {
+        if !args.is_empty() {
+            self.push_error("usage: open".to_string());
+            return;
+        }
+        let Some(target) = self
+            .current_view::<SearchView>()
+            .and_then(|v| v.selected_hit())
+            .map(|h| h.candidate.target.clone())
+        else {
+            self.push_error("no match selected".to_string());
+            return;
+        };
+
+        let found = match target {
+            SearchTarget::Snap { id } => {
+                self.cmd_snaps(&[]);
+                self.current_view_mut::<SnapsView>()
+                    .is_some_and(|v| v.select_snap(&id))
+            }
+            SearchTarget::Publication { scope, gate, id } => {
+                self.open_inbox_view(scope, gate, None, None);
+                self.current_view_mut::<InboxView>()
+                    .is_some_and(|v| select_where(&v.items, &mut v.selected, |p| p.id == id))
+            }
+            SearchTarget::Bundle { scope, gate, id } => {
+                self.open_bundles_view(scope, gate, None, None);
+                self.current_view_mut::<BundlesView>()
+                    .is_some_and(|v| select_where(&v.items, &mut v.selected, |b| b.id == id))
+            }
+            SearchTarget::Release { channel } => {
+                self.cmd_releases(&[]);
+                self.current_view_mut::<ReleasesView>().is_some_and(|v| {
+                    select_where(&v.items, &mut v.selected, |r| r.channel == channel)
+                })
+            }
+            SearchTarget::Lane { id } => {
+                self.cmd_lanes(&[]);
+                self.current_view_mut::<LanesView>()
+                    .is_some_and(|v| select_where(&v.items, &mut v.selected, |l| l.lane_id == id))
+            }
+            SearchTarget::User { handle } => {
+                self.cmd_lanes(&[]);
+                let found = self
+                    .current_view_mut::<LanesView>()
+                    .is_some_and(|v| select_where(&v.items, &mut v.selected, |l| l.user == handle));
+                if !found && self.mode() == UiMode::Lanes {
+                    // Not in any lane; membership is all there is to show.
+                    self.pop_mode();
+                    self.cmd_members(&[]);
+                    return;
+                }
+                found
+            }
+        };
+        if !found && self.mode() != UiMode::Search {
+            self.push_output(vec!["match no longer listed in this view".to_string()]);
+        }
+    }
+}
+
+fn select_where<T>(items: &[T], selected: &mut usize, pred: impl Fn(&T) -> bool) -> bool {
+    match items.iter().position(pred) {
+        Some(i) => {
+            *selected = i;
+            true
+        }
+        None => false,
+    }
+}
@@ -0,0 +1,165 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::model::SnapRecord;
+use crate::remote::{Bundle, Lane, Publication, Release, RepoMembers};
+use crate::tui_shell::views::{SearchCandidate, SearchKind, SearchTarget};
+
+fn short(id: &str) -> String {
+    id.chars().take(8).collect()
+}
+
+fn joined(parts: &[&str]) -> String {
+    parts
+        .iter()
+        .filter(|p| !p.is_empty())
+        .copied()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+pub(super) fn snap_candidates(snaps: &[SnapRecord]) -> Vec<SearchCandidate> {
+    snaps
+        .iter()
+        .map(|s| SearchCandidate {
+            kind: SearchKind::Snap,
+            id: s.id.clone(),
+            text: joined(&[&short(&s.id), s.message.as_deref().unwrap_or("")]),
+            detail: match &s.author {
+                Some(a) => format!("{} by {}", s.created_at, a),
+                None => s.created_at.clone(),
+            },
+            target: SearchTarget::Snap { id: s.id.clone() },
+        })
+        .collect()
+}
+
+pub(super) fn publication_candidates(pubs: &[Publication]) -> Vec<SearchCandidate> {
+    pubs.iter()
+        .map(|p| SearchCandidate {
+            kind: SearchKind::Publication,
+            id: p.id.clone(),
+            text: format!("{} {} {}/{}", short(&p.id), p.publisher, p.scope, p.gate),
+            detail: format!("snap {} at {}", short(&p.snap_id), p.created_at),
+            target: SearchTarget::Publication {
+                scope: p.scope.clone(),
+                gate: p.gate.clone(),
+                id: p.id.clone(),
+            },
+        })
+        .collect()
+}
+
+pub(super) fn bundle_candidates(bundles: &[Bundle]) -> Vec<SearchCandidate> {
+    bundles
+        .iter()
+        .map(|b| SearchCandidate {
+            kind: SearchKind::Bundle,
+            id: b.id.clone(),
+            text: format!("{} {}/{} {}", short(&b.id), b.scope, b.gate, b.created_by),
+            detail: format!(
+                "{} at {}",
+                if b.promotable {
+                    "promotable"
+                } else {
+                    "blocked"
+                },
+                b.created_at
+            ),
+            target: SearchTarget::Bundle {
+                scope: b.scope.clone(),
+                gate: b.gate.clone(),
+                id: b.id.clone(),
+            },
+        })
+        .collect()
+}
+
+pub(super) fn release_candidates(releases: &[Release]) -> Vec<SearchCandidate> {
+    releases
+        .iter()
+        .map(|r| SearchCandidate {
+            kind: SearchKind::Release,
+            id: r.id.clone(),
+            text: joined(&[&r.channel, r.notes.as_deref().unwrap_or("")]),
+            detail: format!(
+                "bundle {} released by {} at {}",
+                short(&r.bundle_id),
+                r.released_by,
+                r.released_at
+            ),
+            target: SearchTarget::Release {
+                channel: r.channel.clone(),
+            },
+        })
+        .collect()
+}
+
+pub(super) fn lane_candidates(lanes: &[Lane]) -> Vec<SearchCandidate> {
+    lanes
+        .iter()
+        .map(|l| {
+            let members = l.members.iter().cloned().collect::<BTreeSet<_>>();
+            SearchCandidate {
+                kind: SearchKind::Lane,
+                id: l.id.clone(),
+                text: joined(&[&l.id, l.description.as_deref().unwrap_or("")]),
+                detail: format!(
+                    "members: {}",
+                    members.into_iter().collect::<Vec<_>>().join(", ")
+                ),
+                target: SearchTarget::Lane { id: l.id.clone() },
+            }
+        })
+        .collect()
+}
+
+/// Repo members and lane members, one candidate per handle.
+pub(super) fn user_candidates(
+    members: Option<&RepoMembers>,
+    lanes: &[Lane],
+) -> Vec<SearchCandidate> {
+    let mut roles: BTreeMap<String, BTreeSet<String>> = BTreeMap::new();
+    if let Some(m) = members {
+        roles
+            .entry(m.owner.clone())
+            .or_default()
+            .insert("owner".to_string());
+        for h in &m.readers {
+            roles
+                .entry(h.clone())
+                .or_default()
+                .insert("read".to_string());
+        }
+        for h in &m.publishers {
+            roles
+                .entry(h.clone())
+                .or_default()
+                .insert("publish".to_string());
+        }
+        for g in m.grants.iter().filter(|g| !g.handle.starts_with('@')) {
+            roles
+                .entry(g.handle.clone())
+                .or_default()
+                .insert(g.role.clone());
+        }
+    }
+    for l in lanes {
+        for h in &l.members {
+            roles
+                .entry(h.clone())
+                .or_default()
+                .insert(format!("lane {}", l.id));
+        }
+    }
+
+    roles
+        .into_iter()
+        .map(|(handle, roles)| SearchCandidate {
+            kind: SearchKind::User,
+            id: handle.clone(),
+            text: handle.clone(),
+            detail: roles.into_iter().collect::<Vec<_>>().join(", "),
+            target: SearchTarget::User { handle },
+        })
+        .collect()
+}
@@ -43,6 +43,7 @@ pub(in crate::tui_shell) struct App {
     pub(in crate::tui_shell) promote_wizard: Option<PromoteWizard>,
     pub(in crate::tui_shell) member_wizard: Option<MemberWizard>,
     pub(in crate::tui_shell) lane_member_wizard: Option<LaneMemberWizard>,
+    pub(in crate::tui_shell) lane_wizard: Option<LaneWizard>,
     pub(in crate::tui_shell) browse_wizard: Option<BrowseWizard>,
     pub(in crate::tui_shell) move_wizard: Option<MoveWizard>,
     pub(in crate::tui_shell) bootstrap_wizard: Option<BootstrapWizard>,
@@ -55,10 +56,19 @@ pub(in crate::tui_shell) struct App {
     pub(in crate::tui_shell) suggestions: Vec<CommandDef>,
     pub(in crate::tui_shell) suggestion_selected: usize,
 
-    pub(in crate::tui_shell) hint_rotation: [usize; 10],
+    pub(in crate::tui_shell) hint_rotation: [usize; 14],
 
     pub(in crate::tui_shell) frames: Vec<ViewFrame>,
 
+    // File waiting to be opened in $EDITOR or $PAGER once the terminal is released.
+    pub(in crate::tui_shell) pending_external: Option<ExternalProgram>,
+
+    pub(in crate::tui_shell) theme: Theme,
+    pub(in crate::tui_shell) keymap: Keymap,
+
+    // Split sizes per view and the pane regions mouse events hit.
+    pub(in crate::tui_shell) panes: Panes,
+
     pub(in crate::tui_shell) quit: bool,
 }
 
@@ -94,6 +104,7 @@ impl Default for App {
             promote_wizard: None,
             member_wizard: None,
             lane_member_wizard: None,
+            lane_wizard: None,
             browse_wizard: None,
             move_wizard: None,
             bootstrap_wizard: None,
@@ -104,10 +115,14 @@ impl Default for App {
             suggestions: Vec::new(),
             suggestion_selected: 0,
 
-            hint_rotation: [0; 10],
+            hint_rotation: [0; 14],
             frames: vec![ViewFrame {
                 view: Box::new(RootView::new(RootContext::Local)),
             }],
+            pending_external: None,
+            theme: Theme::default(),
+            keymap: Keymap::default(),
+            panes: Panes::default(),
             quit: false,
         }
     }
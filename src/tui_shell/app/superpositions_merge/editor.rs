@@ -0,0 +1,55 @@
+use std::path::Path;
+
+use anyhow::Context;
+
+use super::*;
+
+impl App {
+    /// Writes the current result to a temp file; the event loop opens it in `$EDITOR`.
+    pub(in crate::tui_shell) fn cmd_merge_edit(&mut self, args: &[String]) {
+        if !args.is_empty() {
+            self.push_error("usage: edit".to_string());
+            return;
+        }
+        let Some(view) = self.current_view::<MergeView>() else {
+            self.push_error("not in merge mode".to_string());
+            return;
+        };
+        let name = view.path.rsplit('/').next().unwrap_or("merge").to_string();
+        let text = view.result();
+
+        let path =
+            std::env::temp_dir().join(format!("converge-merge-{}-{}", std::process::id(), name));
+        if let Err(err) = std::fs::write(&path, text) {
+            self.push_error(format!("write {}: {}", path.display(), err));
+            return;
+        }
+        self.pending_external = Some(ExternalProgram::Editor(path));
+    }
+
+    /// Loads the edited file back into the merge view once the editor exits.
+    pub(in crate::tui_shell) fn finish_merge_edit(&mut self, path: &Path, res: Result<()>) {
+        let text = res.and_then(|()| {
+            std::fs::read_to_string(path).with_context(|| format!("read {}", path.display()))
+        });
+        let _ = std::fs::remove_file(path);
+        let text = match text {
+            Ok(t) => t,
+            Err(err) => {
+                self.push_error(format!("edit: {:#}", err));
+                return;
+            }
+        };
+
+        let Some(view) = self.current_view_mut::<MergeView>() else {
+            self.push_error("not in merge mode".to_string());
+            return;
+        };
+        if view.set_edited(text) {
+            let open = view.unresolved();
+            self.push_output(vec![format!("result edited ({} conflicts open)", open)]);
+        } else {
+            self.push_output(vec!["result unchanged".to_string()]);
+        }
+    }
+}
@@ -0,0 +1,123 @@
+use crate::model::{SuperpositionVariant, SuperpositionVariantKind};
+use crate::store::LocalStore;
+
+use super::*;
+
+mod editor;
+mod save;
+mod session;
+
+impl App {
+    /// Opens the merge editor on two variants (1-based; default `1 2`) of the selected path.
+    pub(in crate::tui_shell) fn cmd_superpositions_merge_mode(&mut self, args: &[String]) {
+        let (a, b) = match args {
+            [] => (1, 2),
+            [a, b] => match (a.parse::<usize>(), b.parse::<usize>()) {
+                (Ok(a), Ok(b)) if a > 0 && b > 0 && a != b => (a, b),
+                _ => {
+                    self.push_error("usage: merge [<a> <b>] (two distinct variant numbers)".into());
+                    return;
+                }
+            },
+            _ => {
+                self.push_error("usage: merge [<a> <b>]".to_string());
+                return;
+            }
+        };
+
+        let Some(ws) = self.require_workspace() else {
+            return;
+        };
+
+        let opened = match self.current_view::<SuperpositionsView>() {
+            Some(view) => {
+                if view.items.is_empty() {
+                    self.push_error("no selected superposition".to_string());
+                    return;
+                }
+                let idx = view.selected.min(view.items.len().saturating_sub(1));
+                let path = view.items[idx].0.clone();
+                let Some(variants) = view.variants.get(&path) else {
+                    self.push_error("variants not loaded".to_string());
+                    return;
+                };
+                let (Some(left), Some(right)) = (variants.get(a - 1), variants.get(b - 1)) else {
+                    self.push_error(format!(
+                        "variant out of range (variants: {})",
+                        variants.len()
+                    ));
+                    return;
+                };
+                open_variants(&ws.store, left, right).map(|(l, r, mode)| {
+                    MergeView::new(
+                        view.bundle_id.clone(),
+                        view.root_manifest.clone(),
+                        path.clone(),
+                        (variant_label(a, left), variant_label(b, right)),
+                        mode,
+                        &l,
+                        &r,
+                    )
+                })
+            }
+            None => {
+                self.push_error("not in superpositions mode".to_string());
+                return;
+            }
+        };
+
+        match opened {
+            Ok(view) => {
+                let line = format!(
+                    "opened merge editor for {} ({} conflicts)",
+                    view.path,
+                    view.conflicts()
+                );
+                self.push_view(view);
+                self.push_output(vec![line]);
+            }
+            Err(err) => self.push_error(format!("merge: {:#}", err)),
+        }
+    }
+}
+
+/// Text of both variants plus the file mode to keep (taken from the first).
+fn open_variants(
+    store: &LocalStore,
+    left: &SuperpositionVariant,
+    right: &SuperpositionVariant,
+) -> Result<(String, String, u32)> {
+    let (l, mode) = variant_text(store, left)?;
+    let (r, _) = variant_text(store, right)?;
+    Ok((l, r, mode))
+}
+
+fn variant_text(store: &LocalStore, variant: &SuperpositionVariant) -> Result<(String, u32)> {
+    let (bytes, mode) = match &variant.kind {
+        SuperpositionVariantKind::File { blob, mode, .. } => (store.get_blob(blob)?, *mode),
+        SuperpositionVariantKind::FileChunks { recipe, mode, .. } => {
+            let recipe = store.get_recipe(recipe)?;
+            let mut out = Vec::with_capacity(recipe.size as usize);
+            for c in recipe.chunks {
+                out.extend_from_slice(&store.get_blob(&c.blob)?);
+            }
+            (out, *mode)
+        }
+        _ => anyhow::bail!("only file variants can be merged; use pick instead"),
+    };
+    if bytes.contains(&0) {
+        anyhow::bail!("binary content cannot be merged; use pick instead");
+    }
+    let text = String::from_utf8(bytes)
+        .map_err(|_| anyhow::anyhow!("non-UTF-8 content cannot be merged; use pick instead"))?;
+    Ok((text, mode))
+}
+
+fn variant_label(n: usize, variant: &SuperpositionVariant) -> String {
+    let who = variant
+        .metadata
+        .as_ref()
+        .and_then(|m| m.publisher.clone())
+        .unwrap_or_else(|| variant.source.chars().take(12).collect());
+    format!("#{} {}", n, who)
+}
@@ -0,0 +1,73 @@
+use crate::model::VariantKey;
+
+use super::*;
+
+impl App {
+    /// Saves the result as a manual-merge decision; prompts for a rationale when none is given.
+    pub(in crate::tui_shell) fn cmd_merge_save(&mut self, args: &[String]) {
+        let Some(view) = self.current_view::<MergeView>() else {
+            self.push_error("not in merge mode".to_string());
+            return;
+        };
+        let open = view.unresolved();
+        if open > 0 {
+            self.push_error(format!("{} conflicts still open", open));
+            return;
+        }
+        if !args.is_empty() {
+            self.finish_merge_save(args.join(" "));
+            return;
+        }
+
+        let path = view.path.clone();
+        self.open_text_input_modal(
+            "Save Merge",
+            "rationale> ",
+            TextInputAction::MergeRationale,
+            None,
+            vec![format!("Why this merge of {}?", path)],
+        );
+    }
+
+    pub(in crate::tui_shell) fn finish_merge_save(&mut self, rationale: String) {
+        let rationale = rationale.trim().to_string();
+        if rationale.is_empty() {
+            self.push_error("rationale is required".to_string());
+            return;
+        }
+        let Some(ws) = self.require_workspace() else {
+            return;
+        };
+        let Some(view) = self.current_view::<MergeView>() else {
+            self.push_error("not in merge mode".to_string());
+            return;
+        };
+        let open = view.unresolved();
+        if open > 0 {
+            self.push_error(format!("{} conflicts still open", open));
+            return;
+        }
+
+        let text = view.result();
+        let (bundle_id, root_manifest) = (view.bundle_id.clone(), view.root_manifest.clone());
+        let (path, mode) = (view.path.clone(), view.mode);
+        let blob = match ws.store.put_blob(text.as_bytes()) {
+            Ok(b) => b,
+            Err(err) => {
+                self.push_error(format!("write merged blob: {:#}", err));
+                return;
+            }
+        };
+        let key = VariantKey::manual(blob, mode, text.len() as u64);
+
+        self.pop_mode();
+        super::superpositions_nav::superpositions_record_manual_merge(
+            self,
+            bundle_id,
+            root_manifest,
+            path,
+            key,
+            rationale,
+        );
+    }
+}
@@ -0,0 +1,80 @@
+use super::*;
+
+impl App {
+    pub(in crate::tui_shell) fn cmd_merge_choose(
+        &mut self,
+        choice: ConflictChoice,
+        args: &[String],
+    ) {
+        if !args.is_empty() {
+            self.push_error("usage: left | right | both".to_string());
+            return;
+        }
+        let Some(view) = self.current_view_mut::<MergeView>() else {
+            self.push_error("not in merge mode".to_string());
+            return;
+        };
+        let idx = view.conflict;
+        let res = view.choose(choice).map(|()| (idx, view.unresolved()));
+        match res {
+            Ok((idx, open)) => {
+                let what = match choice {
+                    ConflictChoice::Left => "A",
+                    ConflictChoice::Right => "B",
+                    ConflictChoice::Both => "A+B",
+                };
+                self.push_output(vec![format!(
+                    "conflict {}: took {} ({} open)",
+                    idx + 1,
+                    what,
+                    open
+                )]);
+            }
+            Err(err) => self.push_error(err),
+        }
+    }
+
+    pub(in crate::tui_shell) fn cmd_merge_jump(&mut self, forward: bool, args: &[String]) {
+        if !args.is_empty() {
+            self.push_error("usage: next | prev".to_string());
+            return;
+        }
+        let Some(view) = self.current_view_mut::<MergeView>() else {
+            self.push_error("not in merge mode".to_string());
+            return;
+        };
+        if !view.jump_conflict(forward) {
+            self.push_error("no conflicts".to_string());
+        }
+    }
+
+    pub(in crate::tui_shell) fn cmd_merge_undo(&mut self, args: &[String]) {
+        self.merge_history_step(args, "undo", MergeView::undo);
+    }
+
+    pub(in crate::tui_shell) fn cmd_merge_redo(&mut self, args: &[String]) {
+        self.merge_history_step(args, "redo", MergeView::redo);
+    }
+
+    fn merge_history_step(
+        &mut self,
+        args: &[String],
+        name: &str,
+        step: fn(&mut MergeView) -> bool,
+    ) {
+        if !args.is_empty() {
+            self.push_error(format!("usage: {}", name));
+            return;
+        }
+        let Some(view) = self.current_view_mut::<MergeView>() else {
+            self.push_error("not in merge mode".to_string());
+            return;
+        };
+        if step(view) {
+            let open = view.unresolved();
+            self.push_output(vec![format!("{} ({} open)", name, open)]);
+        } else {
+            self.push_error(format!("nothing to {}", name));
+        }
+    }
+}
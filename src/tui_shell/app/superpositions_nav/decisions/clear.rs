@@ -24,6 +24,8 @@ pub(super) fn superpositions_clear_decision(app: &mut App) {
     };
 
     resolution.decisions.remove(&path);
+    resolution.rules.remove(&path);
+    resolution.rationales.remove(&path);
     if let Err(err) = ws.store.put_resolution(&resolution) {
         app.push_error(format!("write resolution: {:#}", err));
         return;
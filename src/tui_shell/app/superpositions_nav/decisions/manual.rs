@@ -0,0 +1,63 @@
+use super::*;
+
+/// Records `key` (a manual merge) as the decision for `path` in the bundle's resolution.
+pub(super) fn superpositions_record_manual_merge(
+    app: &mut App,
+    bundle_id: String,
+    root_manifest: crate::model::ObjectId,
+    path: String,
+    key: crate::model::VariantKey,
+    rationale: String,
+) {
+    let Some(ws) = app.require_workspace() else {
+        return;
+    };
+
+    let Some(mut resolution) = resolution::load_or_init_resolution(app, &bundle_id, &root_manifest)
+    else {
+        return;
+    };
+
+    // Record on the server too; a failure there keeps the local merge but is surfaced.
+    let records = app.remote_client().and_then(|client| {
+        match client.record_bundle_resolutions(
+            &bundle_id,
+            &[crate::remote::RecordResolutionDecision {
+                path: path.clone(),
+                decision: key.clone(),
+                method: Some(crate::remote::ResolutionMethod::Manual),
+                rule: None,
+                rationale: Some(rationale.clone()),
+            }],
+        ) {
+            Ok(r) => Some(r.records),
+            Err(err) => {
+                app.push_error(format!("record resolution: {:#}", err));
+                None
+            }
+        }
+    });
+
+    let decision = ResolutionDecision::Key(key);
+    resolution.decisions.insert(path.clone(), decision.clone());
+    resolution.rules.remove(&path);
+    resolution.rationales.insert(path.clone(), rationale);
+    if let Err(err) = ws.store.put_resolution(&resolution) {
+        app.push_error(format!("write resolution: {:#}", err));
+        return;
+    }
+
+    if let Some(view) = app
+        .current_view_mut::<SuperpositionsView>()
+        .filter(|v| v.bundle_id == bundle_id)
+    {
+        view.decisions.insert(path.clone(), decision);
+        if let Some(records) = records {
+            view.records = records;
+        }
+        view.validation = validate_resolution(&ws.store, &view.root_manifest, &view.decisions).ok();
+        view.updated_at = now_ts();
+    }
+
+    app.push_output(vec![format!("saved manual merge for {}", path)]);
+}
@@ -1,13 +1,33 @@
 use super::*;
 
 mod clear;
+mod manual;
 mod pick;
+mod reopen;
 mod resolution;
 
 pub(in crate::tui_shell::app) fn superpositions_clear_decision(app: &mut App) {
     clear::superpositions_clear_decision(app);
 }
 
+pub(in crate::tui_shell::app) fn superpositions_record_manual_merge(
+    app: &mut App,
+    bundle_id: String,
+    root_manifest: crate::model::ObjectId,
+    path: String,
+    key: crate::model::VariantKey,
+    rationale: String,
+) {
+    manual::superpositions_record_manual_merge(app, bundle_id, root_manifest, path, key, rationale);
+}
+
 pub(in crate::tui_shell::app) fn superpositions_pick_variant(app: &mut App, variant_index: usize) {
     pick::superpositions_pick_variant(app, variant_index);
 }
+
+pub(in crate::tui_shell::app) fn superpositions_reopen_decision(
+    app: &mut App,
+    rationale: Option<String>,
+) {
+    reopen::superpositions_reopen_decision(app, rationale);
+}
@@ -39,8 +39,30 @@ pub(super) fn superpositions_pick_variant(app: &mut App, variant_index: usize) {
         return;
     };
 
+    // Record on the server too; a failure there keeps the local pick but is surfaced.
+    let records = app.remote_client().and_then(|client| {
+        match client.record_bundle_resolutions(
+            &bundle_id,
+            &[crate::remote::RecordResolutionDecision {
+                path: path.clone(),
+                decision: key.clone(),
+                method: Some(crate::remote::ResolutionMethod::Pick),
+                rule: None,
+                rationale: None,
+            }],
+        ) {
+            Ok(r) => Some(r.records),
+            Err(err) => {
+                app.push_error(format!("record resolution: {:#}", err));
+                None
+            }
+        }
+    });
+
     let decision = ResolutionDecision::Key(key);
     resolution.decisions.insert(path.clone(), decision.clone());
+    resolution.rules.remove(&path);
+    resolution.rationales.remove(&path);
     if let Err(err) = ws.store.put_resolution(&resolution) {
         app.push_error(format!("write resolution: {:#}", err));
         return;
@@ -48,6 +70,9 @@ pub(super) fn superpositions_pick_variant(app: &mut App, variant_index: usize) {
 
     if let Some(view) = app.current_view_mut::<SuperpositionsView>() {
         view.decisions.insert(path.clone(), decision);
+        if let Some(records) = records {
+            view.records = records;
+        }
         view.validation = validate_resolution(&ws.store, &view.root_manifest, &view.decisions).ok();
         view.updated_at = now_ts();
     }
@@ -0,0 +1,54 @@
+use super::*;
+
+pub(super) fn superpositions_reopen_decision(app: &mut App, rationale: Option<String>) {
+    let Some(ws) = app.require_workspace() else {
+        return;
+    };
+
+    let (bundle_id, path) = match app.current_view::<SuperpositionsView>() {
+        Some(view) => {
+            if view.items.is_empty() {
+                app.push_error("no selected superposition".to_string());
+                return;
+            }
+            let idx = view.selected.min(view.items.len().saturating_sub(1));
+            (view.bundle_id.clone(), view.items[idx].0.clone())
+        }
+        None => return,
+    };
+
+    let Some(client) = app.remote_client() else {
+        return;
+    };
+    let records = match client.reopen_bundle_resolution(&bundle_id, &path, rationale) {
+        Ok(r) => r.records,
+        Err(err) => {
+            app.push_error(format!("reopen: {:#}", err));
+            return;
+        }
+    };
+
+    if ws.store.has_resolution(&bundle_id) {
+        match ws.store.get_resolution(&bundle_id) {
+            Ok(mut resolution) => {
+                resolution.rules.remove(&path);
+                resolution.rationales.remove(&path);
+                if resolution.decisions.remove(&path).is_some()
+                    && let Err(err) = ws.store.put_resolution(&resolution)
+                {
+                    app.push_error(format!("write resolution: {:#}", err));
+                }
+            }
+            Err(err) => app.push_error(format!("load resolution: {:#}", err)),
+        }
+    }
+
+    if let Some(view) = app.current_view_mut::<SuperpositionsView>() {
+        view.decisions.remove(&path);
+        view.records = records;
+        view.validation = validate_resolution(&ws.store, &view.root_manifest, &view.decisions).ok();
+        view.updated_at = now_ts();
+    }
+
+    app.push_output(vec![format!("reopened {}", path)]);
+}
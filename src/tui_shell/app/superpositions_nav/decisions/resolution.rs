@@ -22,6 +22,8 @@ pub(super) fn load_or_init_resolution(
             root_manifest: root_manifest.clone(),
             created_at: now_ts(),
             decisions: std::collections::BTreeMap::new(),
+            rules: std::collections::BTreeMap::new(),
+            rationales: std::collections::BTreeMap::new(),
         }
     };
 
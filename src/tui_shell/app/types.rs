@@ -9,6 +9,19 @@ pub(in crate::tui_shell) enum UiMode {
     Superpositions,
     GateGraph,
     Settings,
+    Diff,
+    Merge,
+    Files,
+    Search,
+}
+
+/// A program the event loop runs on a temp file with the TUI suspended.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(in crate::tui_shell) enum ExternalProgram {
+    /// Merge result to edit in `$VISUAL`/`$EDITOR`.
+    Editor(std::path::PathBuf),
+    /// File contents to view in `$PAGER`; removed afterwards.
+    Pager(std::path::PathBuf),
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -38,6 +51,10 @@ impl UiMode {
             UiMode::Superpositions => "supers>",
             UiMode::GateGraph => "gates>",
             UiMode::Settings => "settings>",
+            UiMode::Diff => "diff>",
+            UiMode::Merge => "merge>",
+            UiMode::Files => "files>",
+            UiMode::Search => "search>",
         }
     }
 }
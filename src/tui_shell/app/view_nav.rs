@@ -40,6 +40,7 @@ impl App {
         self.frames.push(ViewFrame {
             view: Box::new(view),
         });
+        self.panes.detail_scroll = 0;
     }
 
     pub(super) fn pop_mode(&mut self) {
@@ -47,6 +48,7 @@ impl App {
         if self.frames.len() > 1 {
             self.frames.pop();
         }
+        self.panes.detail_scroll = 0;
 
         if self.mode() == UiMode::Root {
             self.refresh_root_view();
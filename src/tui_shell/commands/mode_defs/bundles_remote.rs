@@ -44,6 +44,18 @@ pub(in crate::tui_shell) fn bundles_command_defs() -> Vec<CommandDef> {
             usage: "superpositions",
             help: "Open superpositions for selected bundle",
         },
+        CommandDef {
+            name: "diff",
+            aliases: &[],
+            usage: "diff",
+            help: "Diff selected bundle against the previous bundle for its scope/gate",
+        },
+        CommandDef {
+            name: "files",
+            aliases: &["ls"],
+            usage: "files",
+            help: "Browse the selected bundle's files",
+        },
     ]
 }
 
@@ -61,6 +73,18 @@ pub(in crate::tui_shell) fn releases_command_defs() -> Vec<CommandDef> {
             usage: "fetch [restore] [into <dir>] [force]",
             help: "Fetch selected release (optional restore)",
         },
+        CommandDef {
+            name: "diff",
+            aliases: &[],
+            usage: "diff",
+            help: "Diff selected release against the previous release in its channel",
+        },
+        CommandDef {
+            name: "files",
+            aliases: &["ls"],
+            usage: "files",
+            help: "Browse the selected release's files",
+        },
     ]
 }
 
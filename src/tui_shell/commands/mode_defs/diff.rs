@@ -0,0 +1,30 @@
+use super::*;
+
+pub(in crate::tui_shell) fn diff_command_defs() -> Vec<CommandDef> {
+    vec![
+        CommandDef {
+            name: "back",
+            aliases: &[],
+            usage: "back",
+            help: "Return to the previous view",
+        },
+        CommandDef {
+            name: "next",
+            aliases: &["n"],
+            usage: "next",
+            help: "Jump to the next hunk (Alt+n)",
+        },
+        CommandDef {
+            name: "prev",
+            aliases: &["p"],
+            usage: "prev",
+            help: "Jump to the previous hunk (Alt+p)",
+        },
+        CommandDef {
+            name: "layout",
+            aliases: &[],
+            usage: "layout [unified|side]",
+            help: "Toggle unified / side-by-side layout (Alt+s)",
+        },
+    ]
+}
@@ -0,0 +1,24 @@
+use super::*;
+
+pub(in crate::tui_shell) fn files_command_defs() -> Vec<CommandDef> {
+    vec![
+        CommandDef {
+            name: "back",
+            aliases: &[],
+            usage: "back",
+            help: "Return to the previous view",
+        },
+        CommandDef {
+            name: "open",
+            aliases: &["o"],
+            usage: "open [<variant#>]",
+            help: "Enter the selected directory or view the file in $PAGER",
+        },
+        CommandDef {
+            name: "up",
+            aliases: &[".."],
+            usage: "up",
+            help: "Go to the parent directory",
+        },
+    ]
+}
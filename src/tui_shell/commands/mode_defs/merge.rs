@@ -0,0 +1,66 @@
+use super::*;
+
+pub(in crate::tui_shell) fn merge_command_defs() -> Vec<CommandDef> {
+    vec![
+        CommandDef {
+            name: "back",
+            aliases: &[],
+            usage: "back",
+            help: "Return to superpositions (discards the session)",
+        },
+        CommandDef {
+            name: "left",
+            aliases: &["a"],
+            usage: "left",
+            help: "Take variant A for the selected conflict (Alt+1)",
+        },
+        CommandDef {
+            name: "right",
+            aliases: &["b"],
+            usage: "right",
+            help: "Take variant B for the selected conflict (Alt+2)",
+        },
+        CommandDef {
+            name: "both",
+            aliases: &[],
+            usage: "both",
+            help: "Take A then B for the selected conflict (Alt+3)",
+        },
+        CommandDef {
+            name: "next",
+            aliases: &[],
+            usage: "next",
+            help: "Jump to the next conflict (Alt+n)",
+        },
+        CommandDef {
+            name: "prev",
+            aliases: &[],
+            usage: "prev",
+            help: "Jump to the previous conflict (Alt+p)",
+        },
+        CommandDef {
+            name: "undo",
+            aliases: &[],
+            usage: "undo",
+            help: "Undo the last change (Alt+u)",
+        },
+        CommandDef {
+            name: "redo",
+            aliases: &[],
+            usage: "redo",
+            help: "Redo the last undone change (Alt+y)",
+        },
+        CommandDef {
+            name: "edit",
+            aliases: &[],
+            usage: "edit",
+            help: "Edit the result in $EDITOR (Alt+e)",
+        },
+        CommandDef {
+            name: "save",
+            aliases: &[],
+            usage: "save [rationale...]",
+            help: "Save the result as a manual-merge resolution",
+        },
+    ]
+}
@@ -0,0 +1,18 @@
+use super::*;
+
+pub(in crate::tui_shell) fn search_command_defs() -> Vec<CommandDef> {
+    vec![
+        CommandDef {
+            name: "back",
+            aliases: &[],
+            usage: "back",
+            help: "Return to the previous view",
+        },
+        CommandDef {
+            name: "open",
+            aliases: &["o"],
+            usage: "open",
+            help: "Open the selected match in its view",
+        },
+    ]
+}
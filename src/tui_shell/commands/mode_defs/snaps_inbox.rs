@@ -50,6 +50,30 @@ pub(in crate::tui_shell) fn snaps_command_defs() -> Vec<CommandDef> {
             usage: "restore [<snap>] [force]",
             help: "Restore selected snap",
         },
+        CommandDef {
+            name: "diff",
+            aliases: &[],
+            usage: "diff",
+            help: "Diff selected snap against its parent (pending row: workspace vs head)",
+        },
+        CommandDef {
+            name: "files",
+            aliases: &["ls"],
+            usage: "files",
+            help: "Browse the selected snap's files",
+        },
+        CommandDef {
+            name: "history",
+            aliases: &[],
+            usage: "history <path>",
+            help: "List snaps, bundles and releases that changed a path",
+        },
+        CommandDef {
+            name: "blame",
+            aliases: &[],
+            usage: "blame <path>",
+            help: "Show which snap introduced each line of a file (selected snap) in $PAGER",
+        },
     ]
 }
 
@@ -79,5 +103,17 @@ pub(in crate::tui_shell) fn inbox_command_defs() -> Vec<CommandDef> {
             usage: "fetch [<snap_id>]",
             help: "Fetch selected snap",
         },
+        CommandDef {
+            name: "diff",
+            aliases: &[],
+            usage: "diff",
+            help: "Diff selected publication against the latest bundle for its scope/gate",
+        },
+        CommandDef {
+            name: "files",
+            aliases: &["ls"],
+            usage: "files",
+            help: "Browse the selected publication's files",
+        },
     ]
 }
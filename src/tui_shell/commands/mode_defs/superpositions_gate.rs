@@ -14,6 +14,18 @@ pub(in crate::tui_shell) fn gate_graph_command_defs() -> Vec<CommandDef> {
             usage: "refresh",
             help: "Reload gate graph from server",
         },
+        CommandDef {
+            name: "bundles",
+            aliases: &[],
+            usage: "bundles [--scope <id>] [--filter <q>] [--limit <n>]",
+            help: "Open bundles for the selected gate",
+        },
+        CommandDef {
+            name: "inbox",
+            aliases: &[],
+            usage: "inbox [--scope <id>] [--filter <q>] [--limit <n>]",
+            help: "Open inbox for the selected gate",
+        },
         CommandDef {
             name: "add-gate",
             aliases: &[],
@@ -56,6 +68,12 @@ pub(in crate::tui_shell) fn gate_graph_command_defs() -> Vec<CommandDef> {
             usage: "toggle-metadata-only",
             help: "Toggle allow_metadata_only_publications",
         },
+        CommandDef {
+            name: "toggle-signed",
+            aliases: &[],
+            usage: "toggle-signed",
+            help: "Toggle require_signed",
+        },
     ]
 }
 
@@ -73,12 +91,24 @@ pub(in crate::tui_shell) fn superpositions_command_defs() -> Vec<CommandDef> {
             usage: "pick <n>",
             help: "Pick variant for selected path",
         },
+        CommandDef {
+            name: "merge",
+            aliases: &[],
+            usage: "merge [<a> <b>]",
+            help: "Merge two text variants of selected path (default 1 2; Alt+m)",
+        },
         CommandDef {
             name: "clear",
             aliases: &[],
             usage: "clear",
             help: "Clear decision for selected path",
         },
+        CommandDef {
+            name: "reopen",
+            aliases: &[],
+            usage: "reopen [rationale...]",
+            help: "Reopen the server-side resolution for selected path",
+        },
         CommandDef {
             name: "next-missing",
             aliases: &[],
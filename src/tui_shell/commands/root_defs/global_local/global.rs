@@ -8,6 +8,12 @@ pub(in crate::tui_shell) fn global_command_defs() -> Vec<CommandDef> {
             usage: "help [command]",
             help: "Show help",
         },
+        CommandDef {
+            name: "search",
+            aliases: &["find"],
+            usage: "search <query...>",
+            help: "Search snaps, publications, bundles, releases, lanes and users",
+        },
         CommandDef {
             name: "settings",
             aliases: &[],
@@ -32,12 +32,30 @@ pub(super) fn browse_command_defs() -> Vec<CommandDef> {
             usage: "member",
             help: "Manage repo membership (guided prompt)",
         },
+        CommandDef {
+            name: "lane",
+            aliases: &[],
+            usage: "lane [create|archive|unarchive|delete] [id]",
+            help: "Create (guided prompt), archive, or delete lanes",
+        },
         CommandDef {
             name: "lane-member",
             aliases: &[],
             usage: "lane-member",
             help: "Manage lane membership (guided prompt)",
         },
+        CommandDef {
+            name: "groups",
+            aliases: &[],
+            usage: "groups [show|create|delete|add|remove ...]",
+            help: "List or manage groups (@<group> works wherever a member handle does)",
+        },
+        CommandDef {
+            name: "audit",
+            aliases: &[],
+            usage: "audit [limit]",
+            help: "Show the identity and membership audit log (admin)",
+        },
         CommandDef {
             name: "inbox",
             aliases: &[],
@@ -0,0 +1,322 @@
+use std::collections::BTreeMap;
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+/// Something a bound key does.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(in crate::tui_shell) enum KeyAction {
+    Quit,
+    Back,
+    Complete,
+    Submit,
+    Up,
+    Down,
+    Left,
+    Right,
+    ShrinkPane,
+    GrowPane,
+    ResetPane,
+
+    PickVariant(usize),
+    ClearDecision,
+    NextMissing,
+    NextInvalid,
+    OpenMerge,
+
+    NextHunk,
+    PrevHunk,
+    ToggleLayout,
+
+    TakeLeft,
+    TakeRight,
+    TakeBoth,
+    NextConflict,
+    PrevConflict,
+    Undo,
+    Redo,
+    EditResult,
+
+    Close,
+    Confirm,
+    ScrollUp,
+    ScrollDown,
+    PageUp,
+    PageDown,
+}
+
+/// Where a binding applies; mode scopes take precedence over `global`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub(in crate::tui_shell) enum KeyScope {
+    Global,
+    Superpositions,
+    Diff,
+    Merge,
+    Modal,
+}
+
+pub(super) const SCOPES: &[(&str, KeyScope)] = &[
+    ("global", KeyScope::Global),
+    ("superpositions", KeyScope::Superpositions),
+    ("diff", KeyScope::Diff),
+    ("merge", KeyScope::Merge),
+    ("modal", KeyScope::Modal),
+];
+
+/// `(action name, action, default keys)` per scope.
+type ActionDefs = &'static [(&'static str, KeyAction, &'static [&'static str])];
+
+fn action_defs(scope: KeyScope) -> ActionDefs {
+    use KeyAction::*;
+    match scope {
+        KeyScope::Global => &[
+            ("quit", Quit, &["q"]),
+            ("back", Back, &["esc"]),
+            ("complete", Complete, &["tab"]),
+            ("submit", Submit, &["enter"]),
+            ("up", Up, &["up"]),
+            ("down", Down, &["down"]),
+            ("left", Left, &["left"]),
+            ("right", Right, &["right"]),
+            ("shrink-pane", ShrinkPane, &["alt+up", "alt+left"]),
+            ("grow-pane", GrowPane, &["alt+down", "alt+right"]),
+            ("reset-pane", ResetPane, &["alt+="]),
+        ],
+        KeyScope::Superpositions => &[
+            ("pick-1", PickVariant(1), &["alt+1"]),
+            ("pick-2", PickVariant(2), &["alt+2"]),
+            ("pick-3", PickVariant(3), &["alt+3"]),
+            ("pick-4", PickVariant(4), &["alt+4"]),
+            ("pick-5", PickVariant(5), &["alt+5"]),
+            ("pick-6", PickVariant(6), &["alt+6"]),
+            ("pick-7", PickVariant(7), &["alt+7"]),
+            ("pick-8", PickVariant(8), &["alt+8"]),
+            ("pick-9", PickVariant(9), &["alt+9"]),
+            ("clear", ClearDecision, &["alt+0"]),
+            ("next-missing", NextMissing, &["alt+n"]),
+            ("next-invalid", NextInvalid, &["alt+f"]),
+            ("merge", OpenMerge, &["alt+m"]),
+        ],
+        KeyScope::Diff => &[
+            ("next-hunk", NextHunk, &["alt+n"]),
+            ("prev-hunk", PrevHunk, &["alt+p"]),
+            ("layout", ToggleLayout, &["alt+s"]),
+        ],
+        KeyScope::Merge => &[
+            ("take-left", TakeLeft, &["alt+1"]),
+            ("take-right", TakeRight, &["alt+2"]),
+            ("take-both", TakeBoth, &["alt+3"]),
+            ("next-conflict", NextConflict, &["alt+n"]),
+            ("prev-conflict", PrevConflict, &["alt+p"]),
+            ("undo", Undo, &["alt+u"]),
+            ("redo", Redo, &["alt+y"]),
+            ("edit", EditResult, &["alt+e"]),
+        ],
+        KeyScope::Modal => &[
+            ("close", Close, &["esc"]),
+            ("confirm", Confirm, &["enter"]),
+            ("scroll-up", ScrollUp, &["up"]),
+            ("scroll-down", ScrollDown, &["down"]),
+            ("page-up", PageUp, &["pageup"]),
+            ("page-down", PageDown, &["pagedown"]),
+        ],
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(in crate::tui_shell) struct KeySpec {
+    code: KeyCode,
+    mods: KeyModifiers,
+}
+
+impl KeySpec {
+    /// Parses `[ctrl+][alt+][shift+]<key>`: a single character, `f1`-`f12`, or a named key.
+    pub(in crate::tui_shell) fn parse(s: &str) -> Result<Self, String> {
+        let mut mods = KeyModifiers::NONE;
+        let mut rest = s.trim();
+        loop {
+            let lower = rest.to_ascii_lowercase();
+            let (m, len) = if lower.starts_with("ctrl+") {
+                (KeyModifiers::CONTROL, 5)
+            } else if lower.starts_with("alt+") {
+                (KeyModifiers::ALT, 4)
+            } else if lower.starts_with("shift+") {
+                (KeyModifiers::SHIFT, 6)
+            } else {
+                break;
+            };
+            mods |= m;
+            rest = &rest[len..];
+        }
+
+        let mut chars = rest.chars();
+        let code = match (chars.next(), chars.next()) {
+            (Some(c), None) => KeyCode::Char(c),
+            _ => match rest.to_ascii_lowercase().as_str() {
+                "esc" | "escape" => KeyCode::Esc,
+                "enter" | "return" => KeyCode::Enter,
+                "tab" => KeyCode::Tab,
+                "backtab" => KeyCode::BackTab,
+                "space" => KeyCode::Char(' '),
+                "backspace" => KeyCode::Backspace,
+                "delete" | "del" => KeyCode::Delete,
+                "insert" => KeyCode::Insert,
+                "up" => KeyCode::Up,
+                "down" => KeyCode::Down,
+                "left" => KeyCode::Left,
+                "right" => KeyCode::Right,
+                "home" => KeyCode::Home,
+                "end" => KeyCode::End,
+                "pageup" => KeyCode::PageUp,
+                "pagedown" => KeyCode::PageDown,
+                f if f.starts_with('f')
+                    && f[1..].parse::<u8>().is_ok_and(|n| (1..=12).contains(&n)) =>
+                {
+                    KeyCode::F(f[1..].parse().unwrap_or(1))
+                }
+                _ => return Err(format!("unknown key '{}'", s)),
+            },
+        };
+        Ok(Self::normalized(code, mods))
+    }
+
+    /// The key press this spec describes.
+    pub(in crate::tui_shell) fn to_event(self) -> KeyEvent {
+        KeyEvent::new(self.code, self.mods)
+    }
+
+    fn from_event(key: KeyEvent) -> Self {
+        Self::normalized(key.code, key.modifiers)
+    }
+
+    /// Shift is part of the character itself (`Q`, `?`), so it only counts for other keys.
+    fn normalized(code: KeyCode, mods: KeyModifiers) -> Self {
+        let mut mods = mods & (KeyModifiers::CONTROL | KeyModifiers::ALT | KeyModifiers::SHIFT);
+        if matches!(code, KeyCode::Char(_)) {
+            mods.remove(KeyModifiers::SHIFT);
+        }
+        Self { code, mods }
+    }
+
+    /// Plain and Alt characters would otherwise be typed, so they only fire with an empty input.
+    fn is_typed(&self) -> bool {
+        matches!(self.code, KeyCode::Char(_)) && !self.mods.contains(KeyModifiers::CONTROL)
+    }
+}
+
+#[derive(Debug)]
+pub(super) struct KeymapError {
+    pub(super) scope: KeyScope,
+    pub(super) actions: Vec<String>,
+    pub(super) message: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(in crate::tui_shell) struct Keymap {
+    bindings: BTreeMap<KeyScope, Vec<(KeySpec, KeyAction)>>,
+}
+
+impl Default for Keymap {
+    fn default() -> Self {
+        Self::build(&BTreeMap::new()).expect("default key bindings are valid")
+    }
+}
+
+impl Keymap {
+    /// Default bindings with `overrides` (scope -> action -> keys) replacing whole actions.
+    /// Errors carry the scope and the actions involved so callers can name their source.
+    pub(super) fn build(
+        overrides: &BTreeMap<KeyScope, BTreeMap<String, Vec<String>>>,
+    ) -> Result<Self, Vec<KeymapError>> {
+        let mut errors = Vec::new();
+        let mut bindings = BTreeMap::new();
+        for &(scope_name, scope) in SCOPES {
+            let defs = action_defs(scope);
+            let over = overrides.get(&scope);
+            if let Some(over) = over {
+                for name in over.keys() {
+                    if !defs.iter().any(|(n, _, _)| n == name) {
+                        errors.push(KeymapError {
+                            scope,
+                            actions: vec![name.clone()],
+                            message: format!(
+                                "keys.{}: unknown action '{}' (expected one of: {})",
+                                scope_name,
+                                name,
+                                defs.iter()
+                                    .map(|(n, _, _)| *n)
+                                    .collect::<Vec<_>>()
+                                    .join(", ")
+                            ),
+                        });
+                    }
+                }
+            }
+
+            let mut list: Vec<(KeySpec, KeyAction)> = Vec::new();
+            let mut names: Vec<(KeySpec, &str)> = Vec::new();
+            for &(name, action, default) in defs {
+                let keys: Vec<&str> = match over.and_then(|o| o.get(name)) {
+                    Some(keys) => keys.iter().map(|k| k.as_str()).collect(),
+                    None => default.to_vec(),
+                };
+                for k in keys {
+                    let spec = match KeySpec::parse(k) {
+                        Ok(s) => s,
+                        Err(e) => {
+                            errors.push(KeymapError {
+                                scope,
+                                actions: vec![name.to_string()],
+                                message: format!("keys.{}.{}: {}", scope_name, name, e),
+                            });
+                            continue;
+                        }
+                    };
+                    if let Some((_, other)) = names.iter().find(|(s, _)| *s == spec) {
+                        errors.push(KeymapError {
+                            scope,
+                            actions: vec![name.to_string(), other.to_string()],
+                            message: format!(
+                                "keys.{}.{}: '{}' is already bound to {}",
+                                scope_name, name, k, other
+                            ),
+                        });
+                        continue;
+                    }
+                    names.push((spec, name));
+                    list.push((spec, action));
+                }
+            }
+            bindings.insert(scope, list);
+        }
+        if errors.is_empty() {
+            Ok(Self { bindings })
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// The action bound to `key` in `scope`, falling back to `global` for mode scopes.
+    /// Typed characters only match when `typing` is false.
+    pub(in crate::tui_shell) fn lookup(
+        &self,
+        scope: KeyScope,
+        key: KeyEvent,
+        typing: bool,
+    ) -> Option<KeyAction> {
+        let spec = KeySpec::from_event(key);
+        if typing && spec.is_typed() {
+            return None;
+        }
+        let find = |s: KeyScope| {
+            self.bindings
+                .get(&s)?
+                .iter()
+                .find(|(k, _)| *k == spec)
+                .map(|(_, a)| *a)
+        };
+        match scope {
+            KeyScope::Global | KeyScope::Modal => find(scope),
+            _ => find(scope).or_else(|| find(KeyScope::Global)),
+        }
+    }
+}
@@ -0,0 +1,162 @@
+//! User configuration for the TUI: key bindings per mode and a color theme.
+//!
+//! Read from `$XDG_CONFIG_HOME/converge/tui.toml` (default `~/.config/converge/tui.toml`),
+//! then `.converge/tui.toml` in the workspace, whose settings win.
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+
+mod keymap;
+mod theme;
+
+pub(in crate::tui_shell) use self::keymap::{KeyAction, KeyScope, KeySpec, Keymap};
+pub(in crate::tui_shell) use self::theme::Theme;
+
+use self::keymap::SCOPES;
+use self::theme::PRESETS;
+
+#[derive(Clone, Debug, Default)]
+pub(in crate::tui_shell) struct TuiConfig {
+    pub(in crate::tui_shell) theme: Theme,
+    pub(in crate::tui_shell) keymap: Keymap,
+}
+
+#[derive(Debug, Default, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RawConfig {
+    #[serde(default)]
+    theme: BTreeMap<String, String>,
+    #[serde(default)]
+    keys: BTreeMap<String, BTreeMap<String, RawKeys>>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+#[serde(untagged)]
+enum RawKeys {
+    One(String),
+    Many(Vec<String>),
+}
+
+impl TuiConfig {
+    /// Loads the user config and the workspace override (either may be missing).
+    pub(in crate::tui_shell) fn load(workspace_root: Option<&Path>) -> Result<Self> {
+        let paths = [
+            user_config_path(),
+            workspace_root.map(|r| r.join(".converge").join("tui.toml")),
+        ];
+        let mut layers = Vec::new();
+        for path in paths.into_iter().flatten() {
+            if !path.is_file() {
+                continue;
+            }
+            let text = std::fs::read_to_string(&path)
+                .with_context(|| format!("read {}", path.display()))?;
+            layers.push((path, text));
+        }
+        Self::from_layers(&layers)
+    }
+
+    /// Applies each `(path, toml)` layer in order; every problem is reported with its file.
+    pub(in crate::tui_shell) fn from_layers(layers: &[(PathBuf, String)]) -> Result<Self> {
+        let mut errors = Vec::new();
+        let mut preset = None;
+        let mut roles: Vec<(String, String, &Path)> = Vec::new();
+        let mut keys: BTreeMap<KeyScope, BTreeMap<String, Vec<String>>> = BTreeMap::new();
+        let mut key_origin: BTreeMap<(KeyScope, String), &Path> = BTreeMap::new();
+
+        for (path, text) in layers {
+            let raw: RawConfig = match toml::from_str(text) {
+                Ok(r) => r,
+                Err(err) => {
+                    errors.push(format!("{}: {}", path.display(), err.message()));
+                    continue;
+                }
+            };
+            for (role, value) in raw.theme {
+                if role == "preset" {
+                    if PRESETS.contains(&value.as_str()) {
+                        preset = Some(value);
+                    } else {
+                        errors.push(format!(
+                            "{}: theme.preset: unknown preset '{}' (expected one of: {})",
+                            path.display(),
+                            value,
+                            PRESETS.join(", ")
+                        ));
+                    }
+                } else {
+                    roles.push((role, value, path));
+                }
+            }
+            for (scope_name, actions) in raw.keys {
+                let Some(&(_, scope)) = SCOPES.iter().find(|(n, _)| *n == scope_name) else {
+                    errors.push(format!(
+                        "{}: keys.{}: unknown mode (expected one of: {})",
+                        path.display(),
+                        scope_name,
+                        SCOPES
+                            .iter()
+                            .map(|(n, _)| *n)
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    ));
+                    continue;
+                };
+                let scope_keys = keys.entry(scope).or_default();
+                for (action, raw) in actions {
+                    let list = match raw {
+                        RawKeys::One(k) => vec![k],
+                        RawKeys::Many(ks) => ks,
+                    };
+                    key_origin.insert((scope, action.clone()), path);
+                    scope_keys.insert(action, list);
+                }
+            }
+        }
+
+        let mut theme = preset
+            .as_deref()
+            .and_then(Theme::preset)
+            .unwrap_or_default();
+        for (role, value, path) in roles {
+            if let Err(e) = theme.set(&role, &value) {
+                errors.push(format!("{}: theme.{}: {}", path.display(), role, e));
+            }
+        }
+        let keymap = match Keymap::build(&keys) {
+            Ok(k) => k,
+            Err(errs) => {
+                for e in errs {
+                    let origin = e
+                        .actions
+                        .iter()
+                        .find_map(|a| key_origin.get(&(e.scope, a.clone())));
+                    match origin {
+                        Some(path) => errors.push(format!("{}: {}", path.display(), e.message)),
+                        None => errors.push(e.message),
+                    }
+                }
+                Keymap::default()
+            }
+        };
+
+        if !errors.is_empty() {
+            anyhow::bail!("invalid TUI config:\n  {}", errors.join("\n  "));
+        }
+        Ok(Self { theme, keymap })
+    }
+}
+
+fn user_config_path() -> Option<PathBuf> {
+    let base = std::env::var_os("XDG_CONFIG_HOME")
+        .filter(|v| !v.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|h| PathBuf::from(h).join(".config")))?;
+    Some(base.join("converge").join("tui.toml"))
+}
+
+#[cfg(test)]
+#[path = "../../tests/tui_shell/config_tests.rs"]
+mod tests;
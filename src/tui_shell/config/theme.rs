@@ -0,0 +1,148 @@
+use std::str::FromStr;
+
+use ratatui::style::Color;
+
+/// Colors by role; views never use literal colors.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(in crate::tui_shell) struct Theme {
+    /// Primary text that needs emphasis (labels, values).
+    pub(in crate::tui_shell) text: Color,
+    /// Secondary text: timestamps, hints, context lines.
+    pub(in crate::tui_shell) muted: Color,
+    /// De-emphasized text: empty states, help footers.
+    pub(in crate::tui_shell) dim: Color,
+    /// Headers, ids and focused borders.
+    pub(in crate::tui_shell) accent: Color,
+    /// View titles and highlighted commands.
+    pub(in crate::tui_shell) heading: Color,
+    pub(in crate::tui_shell) added: Color,
+    pub(in crate::tui_shell) removed: Color,
+    pub(in crate::tui_shell) modified: Color,
+    pub(in crate::tui_shell) superposition: Color,
+    pub(in crate::tui_shell) warning: Color,
+    /// Background of the selected row.
+    pub(in crate::tui_shell) selected: Color,
+    pub(in crate::tui_shell) ok: Color,
+    pub(in crate::tui_shell) error: Color,
+    /// Prompt and badges for the local context.
+    pub(in crate::tui_shell) local: Color,
+    /// Prompt and badges for the remote context.
+    pub(in crate::tui_shell) remote: Color,
+}
+
+pub(super) const PRESETS: &[&str] = &["default", "light", "high-contrast"];
+
+pub(super) const ROLES: &[&str] = &[
+    "text",
+    "muted",
+    "dim",
+    "accent",
+    "heading",
+    "added",
+    "removed",
+    "modified",
+    "superposition",
+    "warning",
+    "selected",
+    "ok",
+    "error",
+    "local",
+    "remote",
+];
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self {
+            text: Color::White,
+            muted: Color::Gray,
+            dim: Color::DarkGray,
+            accent: Color::Cyan,
+            heading: Color::Yellow,
+            added: Color::Green,
+            removed: Color::Red,
+            modified: Color::Yellow,
+            superposition: Color::Magenta,
+            warning: Color::Yellow,
+            selected: Color::DarkGray,
+            ok: Color::Green,
+            error: Color::Red,
+            local: Color::Yellow,
+            remote: Color::Blue,
+        }
+    }
+}
+
+impl Theme {
+    pub(super) fn preset(name: &str) -> Option<Self> {
+        match name {
+            "default" => Some(Self::default()),
+            // For terminals with a light background.
+            "light" => Some(Self {
+                text: Color::Black,
+                muted: Color::DarkGray,
+                dim: Color::Gray,
+                accent: Color::Blue,
+                heading: Color::Magenta,
+                added: Color::Green,
+                removed: Color::Red,
+                modified: Color::Rgb(0x9a, 0x60, 0x00),
+                superposition: Color::Magenta,
+                warning: Color::Rgb(0x9a, 0x60, 0x00),
+                selected: Color::Rgb(0xd0, 0xd7, 0xe5),
+                ok: Color::Green,
+                error: Color::Red,
+                local: Color::Rgb(0x9a, 0x60, 0x00),
+                remote: Color::Blue,
+            }),
+            "high-contrast" => Some(Self {
+                text: Color::White,
+                muted: Color::White,
+                dim: Color::Gray,
+                accent: Color::LightCyan,
+                heading: Color::LightYellow,
+                added: Color::LightGreen,
+                removed: Color::LightRed,
+                modified: Color::LightYellow,
+                superposition: Color::LightMagenta,
+                warning: Color::LightYellow,
+                selected: Color::Blue,
+                ok: Color::LightGreen,
+                error: Color::LightRed,
+                local: Color::LightYellow,
+                remote: Color::LightBlue,
+            }),
+            _ => None,
+        }
+    }
+
+    /// Sets `role` from a color name (`red`, `light-blue`, `dark-gray`), `#rrggbb`, or a 0-255 index.
+    pub(super) fn set(&mut self, role: &str, value: &str) -> Result<(), String> {
+        let slot = match role {
+            "text" => &mut self.text,
+            "muted" => &mut self.muted,
+            "dim" => &mut self.dim,
+            "accent" => &mut self.accent,
+            "heading" => &mut self.heading,
+            "added" => &mut self.added,
+            "removed" => &mut self.removed,
+            "modified" => &mut self.modified,
+            "superposition" => &mut self.superposition,
+            "warning" => &mut self.warning,
+            "selected" => &mut self.selected,
+            "ok" => &mut self.ok,
+            "error" => &mut self.error,
+            "local" => &mut self.local,
+            "remote" => &mut self.remote,
+            _ => {
+                return Err(format!(
+                    "unknown theme role '{}' (expected preset or one of: {})",
+                    role,
+                    ROLES.join(", ")
+                ));
+            }
+        };
+        *slot = Color::from_str(value)
+            .map_err(|_| format!("invalid color '{}' (use a name, #rrggbb or 0-255)", value))?;
+        Ok(())
+    }
+}
@@ -3,8 +3,10 @@ use anyhow::Result;
 mod app;
 
 mod commands;
+mod config;
 mod input;
 mod modal;
+mod panes;
 mod status;
 mod suggest;
 mod view;
@@ -16,6 +18,7 @@ use app::{
     App, CommandDef, Modal, ModalKind, RootContext, TextInputAction, TimestampMode, UiMode,
     fmt_ts_list, fmt_ts_ui, latest_releases_by_channel,
 };
+use config::Theme;
 use view::{RenderCtx, View, render_view_chrome};
 
 pub fn run() -> Result<()> {
@@ -25,3 +28,10 @@ pub fn run() -> Result<()> {
 pub fn run_with_options(opts: crate::tui::TuiRunOptions) -> Result<()> {
     app::run(opts)
 }
+
+pub fn run_script(
+    opts: crate::tui::TuiScriptOptions,
+    out: &mut dyn std::io::Write,
+) -> Result<crate::tui::TuiScriptSummary> {
+    app::run_script(opts, out)
+}
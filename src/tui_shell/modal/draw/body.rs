@@ -1,5 +1,5 @@
 use ratatui::layout::{Constraint, Direction, Layout};
-use ratatui::style::{Color, Style};
+use ratatui::style::Style;
 use ratatui::text::{Line, Span};
 use ratatui::widgets::{Block, Borders, Paragraph, Wrap};
 
@@ -7,6 +7,7 @@ pub(super) fn render_modal_body(
     frame: &mut ratatui::Frame,
     modal: &super::super::super::Modal,
     inner: ratatui::layout::Rect,
+    theme: &super::super::super::Theme,
 ) {
     match &modal.kind {
         super::super::super::ModalKind::Viewer
@@ -38,7 +39,7 @@ pub(super) fn render_modal_body(
             render_lines(frame, modal, parts[0]);
 
             let input_line = Line::from(vec![
-                Span::styled(prompt.as_str(), Style::default().fg(Color::Yellow)),
+                Span::styled(prompt.as_str(), Style::default().fg(theme.heading)),
                 Span::raw(modal.input.buf.as_str()),
             ]);
             frame.render_widget(
@@ -3,7 +3,11 @@ use ratatui::widgets::{Block, Borders};
 mod body;
 mod title;
 
-pub(in crate::tui_shell) fn draw_modal(frame: &mut ratatui::Frame, modal: &super::super::Modal) {
+pub(in crate::tui_shell) fn draw_modal(
+    frame: &mut ratatui::Frame,
+    modal: &super::super::Modal,
+    theme: &super::super::Theme,
+) {
     let area = frame.area();
     let w = area.width.saturating_sub(6).clamp(20, 90);
     let h = area.height.saturating_sub(6).clamp(8, 22);
@@ -20,9 +24,9 @@ pub(in crate::tui_shell) fn draw_modal(frame: &mut ratatui::Frame, modal: &super
 
     let block = Block::default()
         .borders(Borders::ALL)
-        .title(title::modal_title(modal));
+        .title(title::modal_title(modal, theme));
     frame.render_widget(block.clone(), box_area);
     let inner = block.inner(box_area);
 
-    body::render_modal_body(frame, modal, inner);
+    body::render_modal_body(frame, modal, inner, theme);
 }
@@ -1,14 +1,17 @@
-use ratatui::style::{Color, Style};
+use ratatui::style::Style;
 use ratatui::text::{Line, Span};
 
-pub(super) fn modal_title(modal: &super::super::super::Modal) -> Line<'static> {
+pub(super) fn modal_title(
+    modal: &super::super::super::Modal,
+    theme: &super::super::super::Theme,
+) -> Line<'static> {
     let mut spans = vec![
         Span::styled(
             modal.title.as_str().to_string(),
-            Style::default().fg(Color::Yellow),
+            Style::default().fg(theme.heading),
         ),
         Span::raw("  ".to_string()),
-        Span::styled("Esc".to_string(), Style::default().fg(Color::Gray)),
+        Span::styled("Esc".to_string(), Style::default().fg(theme.muted)),
     ];
     if matches!(
         &modal.kind,
@@ -19,7 +22,7 @@ pub(super) fn modal_title(modal: &super::super::super::Modal) -> Line<'static> {
         spans.push(Span::raw("  ".to_string()));
         spans.push(Span::styled(
             "Enter".to_string(),
-            Style::default().fg(Color::Gray),
+            Style::default().fg(theme.muted),
         ));
     }
     Line::from(spans)
@@ -6,12 +6,11 @@ pub(super) fn apply_input_edit_key(modal: &mut super::super::super::Modal, key:
         KeyCode::Delete => modal.input.delete(),
         KeyCode::Left => modal.input.move_left(),
         KeyCode::Right => modal.input.move_right(),
-        KeyCode::Char(c) => {
+        KeyCode::Char(c)
             if !key.modifiers.contains(KeyModifiers::CONTROL)
-                && !key.modifiers.contains(KeyModifiers::ALT)
-            {
-                modal.input.insert_char(c);
-            }
+                && !key.modifiers.contains(KeyModifiers::ALT) =>
+        {
+            modal.input.insert_char(c);
         }
         _ => {}
     }
@@ -1,8 +1,9 @@
-use crossterm::event::{KeyCode, KeyEvent};
+use crossterm::event::KeyEvent;
 
 use self::errors::append_modal_error;
 use self::input::apply_input_edit_key;
 use self::viewer::handle_viewer_like_key;
+use super::super::config::{KeyAction, KeyScope, Keymap};
 use super::text_input_validate::{allow_empty_text_input, validate_text_input};
 
 mod errors;
@@ -23,13 +24,23 @@ pub(super) enum ModalAction {
     },
 }
 
-pub(super) fn map_modal_key(modal: &mut super::super::Modal, key: KeyEvent) -> ModalAction {
+pub(super) fn map_modal_key(
+    modal: &mut super::super::Modal,
+    key: KeyEvent,
+    keymap: &Keymap,
+) -> ModalAction {
+    // Text entry modals keep typed characters for the input.
+    let typing = matches!(
+        modal.kind,
+        super::super::ModalKind::SnapMessage { .. } | super::super::ModalKind::TextInput { .. }
+    );
+    let bound = keymap.lookup(KeyScope::Modal, key, typing);
     match &mut modal.kind {
-        super::super::ModalKind::Viewer => handle_viewer_like_key(modal, key),
+        super::super::ModalKind::Viewer => handle_viewer_like_key(modal, bound),
 
-        super::super::ModalKind::SnapMessage { snap_id } => match key.code {
-            KeyCode::Esc => ModalAction::Close,
-            KeyCode::Enter => ModalAction::SubmitSnapMessage {
+        super::super::ModalKind::SnapMessage { snap_id } => match bound {
+            Some(KeyAction::Close) => ModalAction::Close,
+            Some(KeyAction::Confirm) => ModalAction::SubmitSnapMessage {
                 snap_id: snap_id.clone(),
                 msg: modal.input.buf.clone(),
             },
@@ -39,9 +50,9 @@ pub(super) fn map_modal_key(modal: &mut super::super::Modal, key: KeyEvent) -> M
             }
         },
 
-        super::super::ModalKind::TextInput { action, .. } => match key.code {
-            KeyCode::Esc => ModalAction::Close,
-            KeyCode::Enter => {
+        super::super::ModalKind::TextInput { action, .. } => match bound {
+            Some(KeyAction::Close) => ModalAction::Close,
+            Some(KeyAction::Confirm) => {
                 let raw = modal.input.buf.trim().to_string();
                 if raw.is_empty() && !allow_empty_text_input(action) {
                     append_modal_error(modal, "value required".to_string());
@@ -65,10 +76,10 @@ pub(super) fn map_modal_key(modal: &mut super::super::Modal, key: KeyEvent) -> M
             }
         },
 
-        super::super::ModalKind::ConfirmAction { action } => match key.code {
-            KeyCode::Esc => ModalAction::Close,
-            KeyCode::Enter => ModalAction::Confirm(action.clone()),
-            _ => handle_viewer_like_key(modal, key),
+        super::super::ModalKind::ConfirmAction { action } => match bound {
+            Some(KeyAction::Close) => ModalAction::Close,
+            Some(KeyAction::Confirm) => ModalAction::Confirm(action.clone()),
+            _ => handle_viewer_like_key(modal, bound),
         },
     }
 }
@@ -1,28 +1,27 @@
-use crossterm::event::{KeyCode, KeyEvent};
-
+use super::super::super::config::KeyAction;
 use super::ModalAction;
 
 pub(super) fn handle_viewer_like_key(
     modal: &mut super::super::super::Modal,
-    key: KeyEvent,
+    action: Option<KeyAction>,
 ) -> ModalAction {
-    match key.code {
-        KeyCode::Esc | KeyCode::Enter => ModalAction::Close,
-        KeyCode::Up => {
+    match action {
+        Some(KeyAction::Close | KeyAction::Confirm) => ModalAction::Close,
+        Some(KeyAction::ScrollUp) => {
             modal.scroll = modal.scroll.saturating_sub(1);
             ModalAction::None
         }
-        KeyCode::Down => {
+        Some(KeyAction::ScrollDown) => {
             if modal.scroll < modal.lines.len().saturating_sub(1) {
                 modal.scroll += 1;
             }
             ModalAction::None
         }
-        KeyCode::PageUp => {
+        Some(KeyAction::PageUp) => {
             modal.scroll = modal.scroll.saturating_sub(10);
             ModalAction::None
         }
-        KeyCode::PageDown => {
+        Some(KeyAction::PageDown) => {
             modal.scroll = (modal.scroll + 10).min(modal.lines.len().saturating_sub(1));
             ModalAction::None
         }
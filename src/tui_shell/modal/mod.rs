@@ -10,15 +10,16 @@ pub(super) use self::draw::draw_modal;
 
 pub(super) fn handle_modal_key(app: &mut super::App, key: KeyEvent) {
     let action = {
-        let Some(m) = app.modal_mut() else {
+        let Some(m) = app.modal.as_mut() else {
             return;
         };
-        map_modal_key(m, key)
+        map_modal_key(m, key, &app.keymap)
     };
 
     match action {
         ModalAction::None => {}
         ModalAction::Close => {
+            app.trace_modal_cancelled();
             app.close_modal();
             app.cancel_wizards();
         }
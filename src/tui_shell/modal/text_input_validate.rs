@@ -20,6 +20,10 @@ pub(super) fn allow_empty_text_input(action: &super::super::TextInputAction) ->
             | super::super::TextInputAction::ReleaseNotes
             | super::super::TextInputAction::PinAction
             | super::super::TextInputAction::MemberRole
+            | super::super::TextInputAction::MemberTarget
+            | super::super::TextInputAction::LaneCreateDescription
+            | super::super::TextInputAction::LaneCreateGates
+            | super::super::TextInputAction::LaneCreateScope
             | super::super::TextInputAction::BrowseQuery
             | super::super::TextInputAction::GateGraphAddGateUpstream
             | super::super::TextInputAction::GateGraphEditUpstream
@@ -0,0 +1,258 @@
+//! Resizable two-pane layouts (a list and its details) and the screen regions the last
+//! draw left behind, which mouse events are matched against.
+
+use std::cell::Cell;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+
+use anyhow::{Context, Result};
+use ratatui::layout::{Constraint, Direction, Layout, Position, Rect};
+use ratatui::text::Line;
+use ratatui::widgets::{Block, List, ListState, Paragraph, Wrap};
+
+use super::RenderCtx;
+
+/// Bounds for the first pane's share of a split, in percent.
+pub(super) const MIN_PCT: u16 = 10;
+pub(super) const MAX_PCT: u16 = 90;
+/// How far one resize key press moves a divider, in percent.
+pub(super) const STEP_PCT: u16 = 5;
+
+/// A split between two panes, as last drawn.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(super) struct SplitHit {
+    /// The whole area that was split.
+    pub(super) area: Rect,
+    pub(super) direction: Direction,
+    /// The first pane's share, in percent.
+    pub(super) pct: u16,
+    /// First row (vertical) or column (horizontal) of the second pane.
+    pub(super) divider: u16,
+}
+
+impl SplitHit {
+    /// The divider is the first pane's last line and the second pane's first.
+    pub(super) fn on_divider(&self, column: u16, row: u16) -> bool {
+        if !contains(self.area, column, row) {
+            return false;
+        }
+        let at = match self.direction {
+            Direction::Vertical => row,
+            Direction::Horizontal => column,
+        };
+        at + 1 == self.divider || at == self.divider
+    }
+
+    /// The first pane's share if the divider were dragged to `(column, row)`.
+    pub(super) fn pct_at(&self, column: u16, row: u16) -> u16 {
+        let (at, start, len) = match self.direction {
+            Direction::Vertical => (row, self.area.y, self.area.height),
+            Direction::Horizontal => (column, self.area.x, self.area.width),
+        };
+        if len == 0 {
+            return self.pct;
+        }
+        let offset = at.saturating_sub(start) as u32 + 1;
+        ((offset * 100 / len as u32) as u16).clamp(MIN_PCT, MAX_PCT)
+    }
+}
+
+/// The rows of a list, as last drawn.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(super) struct ListHit {
+    pub(super) area: Rect,
+    /// Index of the item on the first row.
+    pub(super) offset: usize,
+    pub(super) selected: Option<usize>,
+    pub(super) len: usize,
+}
+
+impl ListHit {
+    /// The item drawn at `row`, if any.
+    pub(super) fn item_at(&self, column: u16, row: u16) -> Option<usize> {
+        if !contains(self.area, column, row) {
+            return None;
+        }
+        let index = self.offset + (row - self.area.y) as usize;
+        (index < self.len).then_some(index)
+    }
+}
+
+/// A scrollable details pane, as last drawn.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(super) struct ScrollHit {
+    pub(super) area: Rect,
+    /// Largest useful scroll offset.
+    pub(super) max: u16,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub(super) struct PaneHits {
+    pub(super) split: Option<SplitHit>,
+    pub(super) list: Option<ListHit>,
+    pub(super) details: Option<ScrollHit>,
+}
+
+/// What a view needs to lay out its panes; empty outside of a screen draw.
+#[derive(Clone, Copy, Debug, Default)]
+pub(super) struct PaneCtx<'a> {
+    /// The user's split for the view being drawn, overriding its default.
+    pub(super) split: Option<u16>,
+    pub(super) detail_scroll: u16,
+    pub(super) hits: Option<&'a Cell<PaneHits>>,
+}
+
+impl PaneCtx<'_> {
+    fn record(&self, f: impl FnOnce(&mut PaneHits)) {
+        if let Some(hits) = self.hits {
+            let mut h = hits.get();
+            f(&mut h);
+            hits.set(h);
+        }
+    }
+}
+
+impl RenderCtx<'_> {
+    /// Splits `area` in two, giving the first pane the user's share for this view or else
+    /// `default_pct`.
+    pub(super) fn split_panes(
+        &self,
+        area: Rect,
+        direction: Direction,
+        default_pct: u16,
+    ) -> Rc<[Rect]> {
+        let pct = self
+            .panes
+            .split
+            .unwrap_or(default_pct)
+            .clamp(MIN_PCT, MAX_PCT);
+        let parts = Layout::default()
+            .direction(direction)
+            .constraints([
+                Constraint::Percentage(pct),
+                Constraint::Percentage(100 - pct),
+            ])
+            .split(area);
+        let divider = match direction {
+            Direction::Vertical => parts[1].y,
+            Direction::Horizontal => parts[1].x,
+        };
+        self.panes.record(|h| {
+            h.split = Some(SplitHit {
+                area,
+                direction,
+                pct,
+                divider,
+            })
+        });
+        parts
+    }
+
+    /// Draws `block` over `area` and `list` inside it, keeping `selected` in view.
+    pub(super) fn render_list(
+        &self,
+        frame: &mut ratatui::Frame,
+        list: List,
+        block: Block,
+        area: Rect,
+        selected: Option<usize>,
+    ) {
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+        let len = list.len();
+        let mut state = ListState::default();
+        state.select(selected.filter(|_| len > 0).map(|i| i.min(len - 1)));
+        frame.render_stateful_widget(list, inner, &mut state);
+        self.panes.record(|h| {
+            h.list = Some(ListHit {
+                area: inner,
+                offset: state.offset(),
+                selected: state.selected(),
+                len,
+            })
+        });
+    }
+
+    /// Draws wrapped details text in `area`, scrolled as far as the user scrolled it.
+    pub(super) fn render_details(&self, frame: &mut ratatui::Frame, lines: Vec<Line>, area: Rect) {
+        let width = area.width.max(1) as usize;
+        let height: usize = lines.iter().map(|l| l.width().div_ceil(width).max(1)).sum();
+        let scroll = self.details_scroll(area, height);
+        frame.render_widget(
+            Paragraph::new(lines)
+                .wrap(Wrap { trim: false })
+                .scroll((scroll, 0)),
+            area,
+        );
+    }
+
+    /// Registers `area` as the scrollable details pane holding `height` rows of content and
+    /// returns how far to scroll it.
+    pub(super) fn details_scroll(&self, area: Rect, height: usize) -> u16 {
+        let max = height
+            .saturating_sub(area.height as usize)
+            .min(u16::MAX as usize) as u16;
+        self.panes
+            .record(|h| h.details = Some(ScrollHit { area, max }));
+        self.panes.detail_scroll.min(max)
+    }
+}
+
+fn contains(area: Rect, column: u16, row: u16) -> bool {
+    area.contains(Position::new(column, row))
+}
+
+/// Pane state kept by the app: split sizes per view, details scroll, and the regions from the
+/// last draw.
+#[derive(Debug, Default)]
+pub(in crate::tui_shell) struct Panes {
+    /// First pane's share per view (`snaps`, `diff`, ...), in percent.
+    pub(super) sizes: BTreeMap<String, u16>,
+    pub(super) detail_scroll: u16,
+    /// A divider is being dragged.
+    pub(super) dragging: bool,
+    pub(super) hits: Cell<PaneHits>,
+    /// Where sizes are saved; none for scripted sessions.
+    pub(super) path: Option<PathBuf>,
+}
+
+impl Panes {
+    /// Reads saved sizes from `path`; a missing or unreadable file starts from the defaults.
+    pub(in crate::tui_shell) fn load(path: Option<PathBuf>) -> Self {
+        let sizes = path
+            .as_deref()
+            .and_then(|p| std::fs::read_to_string(p).ok())
+            .and_then(|text| serde_json::from_str(&text).ok())
+            .unwrap_or_default();
+        Self {
+            sizes,
+            path,
+            ..Self::default()
+        }
+    }
+
+    pub(super) fn save(&self) -> Result<()> {
+        let Some(path) = &self.path else {
+            return Ok(());
+        };
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir).with_context(|| format!("create {}", dir.display()))?;
+        }
+        let text = serde_json::to_string_pretty(&self.sizes).context("serialize pane sizes")?;
+        std::fs::write(path, text).with_context(|| format!("write {}", path.display()))
+    }
+}
+
+/// `$XDG_STATE_HOME/converge/tui-panes.json` (default `~/.local/state/converge/tui-panes.json`).
+pub(in crate::tui_shell) fn state_path() -> Option<PathBuf> {
+    let base = std::env::var_os("XDG_STATE_HOME")
+        .filter(|v| !v.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|h| Path::new(&h).join(".local").join("state")))?;
+    Some(base.join("converge").join("tui-panes.json"))
+}
+
+#[cfg(test)]
+#[path = "../tests/tui_shell/panes_tests.rs"]
+mod tests;
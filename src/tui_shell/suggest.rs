@@ -1,18 +1,139 @@
+use ratatui::style::Style;
+use ratatui::text::Span;
+
 use super::CommandDef;
 
+const MATCH: i32 = 16;
+const CONSECUTIVE: i32 = 12;
+const WORD_START: i32 = 8;
+const FIRST_CHAR: i32 = 10;
+const GAP: i32 = 2;
+const LEADING_GAP: i32 = 1;
+
 pub(super) fn score_match(q: &str, candidate: &str) -> i32 {
-    let q = q.to_lowercase();
-    let c = candidate.to_lowercase();
-    if c == q {
-        return 100;
+    let Some((score, _)) = fuzzy_match(q, candidate) else {
+        return 0;
+    };
+    if candidate.eq_ignore_ascii_case(q) {
+        score + 100
+    } else {
+        score
+    }
+}
+
+/// Case-insensitive subsequence match of `q` in `candidate`: the best score and the char indices
+/// of `candidate` it matched. Consecutive runs and word starts score higher; gaps cost.
+pub(in crate::tui_shell) fn fuzzy_match(q: &str, candidate: &str) -> Option<(i32, Vec<usize>)> {
+    let qs = q.chars().flat_map(char::to_lowercase).collect::<Vec<_>>();
+    let orig = candidate.chars().collect::<Vec<_>>();
+    let cs = orig
+        .iter()
+        .map(|c| c.to_lowercase().next().unwrap_or(*c))
+        .collect::<Vec<_>>();
+    let (m, n) = (qs.len(), cs.len());
+    if m == 0 {
+        return Some((0, Vec::new()));
+    }
+    if m > n {
+        return None;
+    }
+
+    let bonus = |j: usize| {
+        if j == 0 {
+            FIRST_CHAR
+        } else if !orig[j - 1].is_alphanumeric()
+            || (orig[j].is_uppercase() && orig[j - 1].is_lowercase())
+        {
+            WORD_START
+        } else {
+            0
+        }
+    };
+
+    // score[i][j]: best alignment of qs[..=i] with qs[i] on cs[j]; back[i][j]: where qs[i - 1] sat.
+    let mut score = vec![vec![None::<i32>; n]; m];
+    let mut back = vec![vec![0usize; n]; m];
+    for j in 0..n {
+        if cs[j] == qs[0] {
+            score[0][j] = Some(MATCH + bonus(j) - LEADING_GAP * j as i32);
+        }
+    }
+    for i in 1..m {
+        // Best of score[i - 1][k] + GAP * k over k <= j - 2, so a gap costs GAP per skipped char.
+        let mut gapped: Option<(i32, usize)> = None;
+        for j in i..n {
+            if j >= 2
+                && let Some(s) = score[i - 1][j - 2]
+            {
+                let v = s + GAP * (j - 2) as i32;
+                if gapped.is_none_or(|(g, _)| v > g) {
+                    gapped = Some((v, j - 2));
+                }
+            }
+            if cs[j] != qs[i] {
+                continue;
+            }
+            let run = score[i - 1][j - 1].map(|s| (s + CONSECUTIVE, j - 1));
+            let gap = gapped.map(|(g, k)| (g - GAP * (j - 1) as i32, k));
+            let best = match (run, gap) {
+                (Some(a), Some(b)) => Some(if b.0 > a.0 { b } else { a }),
+                (a, b) => a.or(b),
+            };
+            if let Some((s, k)) = best {
+                score[i][j] = Some(s + MATCH + bonus(j));
+                back[i][j] = k;
+            }
+        }
+    }
+
+    let (mut j, best) = (0..n)
+        .filter_map(|j| score[m - 1][j].map(|s| (j, s)))
+        .fold(None, |acc: Option<(usize, i32)>, (j, s)| match acc {
+            Some((_, b)) if b >= s => acc,
+            _ => Some((j, s)),
+        })?;
+    let mut positions = vec![0; m];
+    for i in (0..m).rev() {
+        positions[i] = j;
+        j = back[i][j];
     }
-    if c.starts_with(&q) {
-        return 50 - (c.len() as i32 - q.len() as i32);
+    Some((best.max(1), positions))
+}
+
+/// `text` as spans, with the chars `query` fuzzy-matches drawn in `hit`.
+pub(in crate::tui_shell) fn highlight_matches(
+    text: &str,
+    query: &str,
+    base: Style,
+    hit: Style,
+) -> Vec<Span<'static>> {
+    let positions = fuzzy_match(query, text).map(|(_, p)| p).unwrap_or_default();
+    highlight_positions(text, &positions, base, hit)
+}
+
+/// `text` as spans, with the chars at `positions` (sorted char indices) drawn in `hit`.
+pub(in crate::tui_shell) fn highlight_positions(
+    text: &str,
+    positions: &[usize],
+    base: Style,
+    hit: Style,
+) -> Vec<Span<'static>> {
+    let mut spans = Vec::new();
+    let mut run = String::new();
+    let mut run_hit = false;
+    for (i, ch) in text.chars().enumerate() {
+        let is_hit = positions.binary_search(&i).is_ok();
+        if is_hit != run_hit && !run.is_empty() {
+            let style = if run_hit { hit } else { base };
+            spans.push(Span::styled(std::mem::take(&mut run), style));
+        }
+        run_hit = is_hit;
+        run.push(ch);
     }
-    if c.contains(&q) {
-        return 10;
+    if !run.is_empty() {
+        spans.push(Span::styled(run, if run_hit { hit } else { base }));
     }
-    0
+    spans
 }
 
 pub(super) fn sort_scored_suggestions(scored: &mut [(i32, CommandDef)], hint_order: &[String]) {
@@ -1,16 +1,18 @@
 use std::any::Any;
 
 use ratatui::layout::Rect;
-use ratatui::style::{Color, Style};
+use ratatui::style::Style;
 use ratatui::text::{Line, Span};
 use ratatui::widgets::{Block, Borders};
 
 use time::OffsetDateTime;
 
 #[derive(Clone, Copy, Debug)]
-pub(super) struct RenderCtx {
+pub(super) struct RenderCtx<'a> {
     pub(super) now: OffsetDateTime,
     pub(super) ts_mode: super::TimestampMode,
+    pub(super) theme: super::Theme,
+    pub(super) panes: super::panes::PaneCtx<'a>,
 }
 
 pub(super) trait View: Any {
@@ -29,16 +31,17 @@ pub(super) trait View: Any {
 
 pub(super) fn render_view_chrome(
     frame: &mut ratatui::Frame,
+    ctx: &RenderCtx,
     title: &str,
     updated_at: &str,
     area: Rect,
 ) -> Rect {
     let header = Line::from(vec![
-        Span::styled(title.to_string(), Style::default().fg(Color::Yellow)),
+        Span::styled(title.to_string(), Style::default().fg(ctx.theme.heading)),
         Span::raw("  "),
         Span::styled(
             super::fmt_ts_ui(updated_at),
-            Style::default().fg(Color::Gray),
+            Style::default().fg(ctx.theme.muted),
         ),
     ]);
 
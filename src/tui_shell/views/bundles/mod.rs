@@ -51,7 +51,7 @@ impl View for BundlesView {
         self.selected = (self.selected + 1).min(max);
     }
 
-    fn render(&self, frame: &mut ratatui::Frame, area: ratatui::layout::Rect, _ctx: &RenderCtx) {
-        render::render(self, frame, area);
+    fn render(&self, frame: &mut ratatui::Frame, area: ratatui::layout::Rect, ctx: &RenderCtx) {
+        render::render(self, frame, area, ctx);
     }
 }
@@ -0,0 +1,116 @@
+use std::any::Any;
+
+use ratatui::layout::Direction;
+use ratatui::style::Style;
+use ratatui::widgets::{Block, Borders, List, Paragraph};
+
+use crate::diff::FileDiff;
+
+use super::super::{RenderCtx, UiMode, View, render_view_chrome};
+
+mod nav;
+mod render;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(in crate::tui_shell) enum DiffLayout {
+    Unified,
+    SideBySide,
+}
+
+impl DiffLayout {
+    pub(in crate::tui_shell) fn toggle(self) -> Self {
+        match self {
+            DiffLayout::Unified => DiffLayout::SideBySide,
+            DiffLayout::SideBySide => DiffLayout::Unified,
+        }
+    }
+
+    pub(in crate::tui_shell) fn label(self) -> &'static str {
+        match self {
+            DiffLayout::Unified => "unified",
+            DiffLayout::SideBySide => "side-by-side",
+        }
+    }
+}
+
+#[derive(Debug)]
+pub(in crate::tui_shell) struct DiffView {
+    pub(in crate::tui_shell) updated_at: String,
+    pub(in crate::tui_shell) title: String,
+    pub(in crate::tui_shell) files: Vec<FileDiff>,
+    pub(in crate::tui_shell) selected: usize,
+    pub(in crate::tui_shell) hunk: usize,
+    pub(in crate::tui_shell) layout: DiffLayout,
+}
+
+impl DiffView {
+    pub(in crate::tui_shell) fn new(title: String, files: Vec<FileDiff>) -> Self {
+        Self {
+            updated_at: super::super::app::now_ts(),
+            title,
+            files,
+            selected: 0,
+            hunk: 0,
+            layout: DiffLayout::Unified,
+        }
+    }
+}
+
+impl View for DiffView {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn mode(&self) -> UiMode {
+        UiMode::Diff
+    }
+
+    fn title(&self) -> &str {
+        &self.title
+    }
+
+    fn updated_at(&self) -> &str {
+        &self.updated_at
+    }
+
+    fn move_up(&mut self) {
+        self.select_file(self.selected.saturating_sub(1));
+    }
+
+    fn move_down(&mut self) {
+        self.select_file(self.selected + 1);
+    }
+
+    fn render(&self, frame: &mut ratatui::Frame, area: ratatui::layout::Rect, ctx: &RenderCtx) {
+        let inner = render_view_chrome(frame, ctx, self.title(), self.updated_at(), area);
+        let parts = ctx.split_panes(inner, Direction::Horizontal, 30);
+
+        let list = List::new(render::file_rows(&self.files, &ctx.theme))
+            .highlight_style(Style::default().bg(ctx.theme.selected));
+        let block = Block::default()
+            .borders(Borders::RIGHT)
+            .title(format!("files ({})", self.files.len()));
+        let selected = (!self.files.is_empty()).then(|| self.selected_index());
+        ctx.render_list(frame, list, block, parts[0], selected);
+
+        let pane = Block::default().title(format!(
+            " {} [{}] (Alt+n/Alt+p: hunks; Alt+s: layout)",
+            self.selected_file().map(|f| f.path()).unwrap_or("-"),
+            self.layout.label()
+        ));
+        let body = pane.inner(parts[1]);
+        frame.render_widget(pane, parts[1]);
+
+        // The wheel scrolls on from where the selected hunk puts the body.
+        let (lines, scroll) = render::body_lines(self, body.width as usize, &ctx.theme);
+        let extra = ctx.details_scroll(body, lines.len().saturating_sub(scroll)) as usize;
+        frame.render_widget(
+            Paragraph::new(lines).scroll(((scroll + extra).min(u16::MAX as usize) as u16, 0)),
+            body,
+        );
+    }
+}
@@ -0,0 +1,66 @@
+use crate::diff::FileDiff;
+
+use super::DiffView;
+
+impl DiffView {
+    pub(in crate::tui_shell) fn selected_index(&self) -> usize {
+        self.selected.min(self.files.len().saturating_sub(1))
+    }
+
+    pub(in crate::tui_shell) fn selected_file(&self) -> Option<&FileDiff> {
+        self.files.get(self.selected_index())
+    }
+
+    pub(super) fn select_file(&mut self, idx: usize) {
+        let idx = idx.min(self.files.len().saturating_sub(1));
+        if idx != self.selected {
+            self.selected = idx;
+            self.hunk = 0;
+        }
+    }
+
+    /// Moves to the next hunk, continuing into later files (wrapping); false if there are none.
+    pub(in crate::tui_shell) fn next_hunk(&mut self) -> bool {
+        let cur = self.selected_index();
+        if let Some(f) = self.files.get(cur)
+            && self.hunk + 1 < f.hunks().len()
+        {
+            self.hunk += 1;
+            return true;
+        }
+        let n = self.files.len();
+        for step in 1..=n {
+            let idx = (cur + step) % n;
+            if !self.files[idx].hunks().is_empty() {
+                self.selected = idx;
+                self.hunk = 0;
+                return true;
+            }
+        }
+        false
+    }
+
+    /// Moves to the previous hunk, continuing into earlier files (wrapping); false if there are none.
+    pub(in crate::tui_shell) fn prev_hunk(&mut self) -> bool {
+        let cur = self.selected_index();
+        if self.hunk > 0 && self.files.get(cur).is_some_and(|f| !f.hunks().is_empty()) {
+            self.hunk = self.hunk.min(self.files[cur].hunks().len()) - 1;
+            return true;
+        }
+        let n = self.files.len();
+        for step in 1..=n {
+            let idx = (cur + n - step) % n;
+            let count = self.files[idx].hunks().len();
+            if count > 0 {
+                self.selected = idx;
+                self.hunk = count - 1;
+                return true;
+            }
+        }
+        false
+    }
+}
+
+#[cfg(test)]
+#[path = "../../../tests/tui_shell/views/diff/nav_tests.rs"]
+mod tests;
@@ -0,0 +1,178 @@
+use ratatui::style::{Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::ListItem;
+
+use crate::diff::{FileDiff, Hunk, HunkLine};
+
+use super::{DiffLayout, DiffView};
+use crate::tui_shell::Theme;
+
+pub(super) fn file_rows(files: &[FileDiff], theme: &Theme) -> Vec<ListItem<'static>> {
+    if files.is_empty() {
+        return vec![ListItem::new("(no changes)")];
+    }
+    files
+        .iter()
+        .map(|f| {
+            let status = match f.status_char() {
+                'A' => Style::default().fg(theme.added),
+                'D' => Style::default().fg(theme.removed),
+                _ => Style::default().fg(theme.modified),
+            };
+            let (added, removed) = f.line_counts();
+            let mut spans = vec![
+                Span::styled(format!("{} ", f.status_char()), status),
+                Span::raw(f.path().to_string()),
+            ];
+            if added > 0 || removed > 0 {
+                spans.push(Span::styled(
+                    format!(" +{}", added),
+                    Style::default().fg(theme.added),
+                ));
+                spans.push(Span::styled(
+                    format!(" -{}", removed),
+                    Style::default().fg(theme.removed),
+                ));
+            }
+            ListItem::new(Line::from(spans))
+        })
+        .collect()
+}
+
+/// Lines for the selected file and the row to scroll to (the selected hunk's header).
+pub(super) fn body_lines(v: &DiffView, width: usize, theme: &Theme) -> (Vec<Line<'static>>, usize) {
+    let Some(file) = v.selected_file() else {
+        return (vec![Line::from("(no changes)")], 0);
+    };
+    if let Some(summary) = file.body.summary() {
+        return (
+            vec![Line::from(Span::styled(
+                summary,
+                Style::default().fg(theme.warning),
+            ))],
+            0,
+        );
+    }
+    if file.hunks().is_empty() {
+        return (vec![Line::from("(no content changes)")], 0);
+    }
+
+    let selected = v.hunk.min(file.hunks().len() - 1);
+    let mut out = Vec::new();
+    let mut scroll = 0;
+    for (i, hunk) in file.hunks().iter().enumerate() {
+        if i == selected {
+            scroll = out.len();
+        }
+        let mut header = Style::default().fg(theme.accent);
+        if i == selected {
+            header = header.add_modifier(Modifier::REVERSED);
+        }
+        out.push(Line::from(Span::styled(hunk.header(), header)));
+        match v.layout {
+            DiffLayout::Unified => unified_lines(hunk, theme, &mut out),
+            DiffLayout::SideBySide => side_by_side_lines(hunk, width, theme, &mut out),
+        }
+    }
+    (out, scroll)
+}
+
+fn removed_style(theme: &Theme) -> Style {
+    Style::default().fg(theme.removed)
+}
+
+fn added_style(theme: &Theme) -> Style {
+    Style::default().fg(theme.added)
+}
+
+fn unified_lines(hunk: &Hunk, theme: &Theme, out: &mut Vec<Line<'static>>) {
+    for l in &hunk.lines {
+        out.push(match l {
+            HunkLine::Context(t) => Line::from(format!(" {}", t)),
+            HunkLine::Removed(t) => {
+                Line::from(Span::styled(format!("-{}", t), removed_style(theme)))
+            }
+            HunkLine::Added(t) => Line::from(Span::styled(format!("+{}", t), added_style(theme))),
+        });
+    }
+}
+
+type Cell = Option<(usize, String)>;
+
+/// Pairs each run of removed lines with the added lines that follow it.
+fn side_by_side_rows(hunk: &Hunk) -> Vec<(Cell, Cell)> {
+    let mut rows = Vec::new();
+    let (mut old_no, mut new_no) = (hunk.old_start.max(1), hunk.new_start.max(1));
+    let mut removed: Vec<(usize, String)> = Vec::new();
+    let mut added: Vec<(usize, String)> = Vec::new();
+
+    for l in &hunk.lines {
+        match l {
+            HunkLine::Context(t) => {
+                flush(&mut removed, &mut added, &mut rows);
+                rows.push((Some((old_no, t.clone())), Some((new_no, t.clone()))));
+                old_no += 1;
+                new_no += 1;
+            }
+            HunkLine::Removed(t) => {
+                if !added.is_empty() {
+                    flush(&mut removed, &mut added, &mut rows);
+                }
+                removed.push((old_no, t.clone()));
+                old_no += 1;
+            }
+            HunkLine::Added(t) => {
+                added.push((new_no, t.clone()));
+                new_no += 1;
+            }
+        }
+    }
+    flush(&mut removed, &mut added, &mut rows);
+    rows
+}
+
+fn flush(
+    removed: &mut Vec<(usize, String)>,
+    added: &mut Vec<(usize, String)>,
+    rows: &mut Vec<(Cell, Cell)>,
+) {
+    let n = removed.len().max(added.len());
+    let mut r = removed.drain(..);
+    let mut a = added.drain(..);
+    for _ in 0..n {
+        rows.push((r.next(), a.next()));
+    }
+}
+
+fn side_by_side_lines(hunk: &Hunk, width: usize, theme: &Theme, out: &mut Vec<Line<'static>>) {
+    let half = width.saturating_sub(3) / 2;
+    for (left, right) in side_by_side_rows(hunk) {
+        let changed = left.as_ref().map(|c| &c.1) != right.as_ref().map(|c| &c.1);
+        let l_style = if changed {
+            removed_style(theme)
+        } else {
+            Style::default()
+        };
+        let r_style = if changed {
+            added_style(theme)
+        } else {
+            Style::default()
+        };
+        out.push(Line::from(vec![
+            Span::styled(cell(left, half), l_style),
+            Span::styled(" | ".to_string(), Style::default().fg(theme.muted)),
+            Span::styled(cell(right, half), r_style),
+        ]));
+    }
+}
+
+fn cell(c: Cell, width: usize) -> String {
+    let text = match c {
+        Some((no, t)) => format!("{:>4} {}", no, t),
+        None => String::new(),
+    };
+    let mut s: String = text.chars().take(width).collect();
+    let pad = width.saturating_sub(s.chars().count());
+    s.extend(std::iter::repeat_n(' ', pad));
+    s
+}
@@ -0,0 +1,122 @@
+use std::any::Any;
+
+use ratatui::layout::Direction;
+use ratatui::style::Style;
+use ratatui::widgets::{Block, Borders, List};
+
+use crate::browse::{TreeEntry, TreeRef};
+
+use super::super::{RenderCtx, UiMode, View, render_view_chrome};
+
+mod render;
+
+/// Browses one snap, bundle or release tree a directory at a time.
+#[derive(Debug)]
+pub(in crate::tui_shell) struct FilesView {
+    pub(in crate::tui_shell) updated_at: String,
+    pub(in crate::tui_shell) title: String,
+    pub(in crate::tui_shell) tree: TreeRef,
+    /// Whether missing objects may be fetched from the remote.
+    pub(in crate::tui_shell) remote: bool,
+    /// Directory being listed ("" for the root).
+    pub(in crate::tui_shell) dir: String,
+    pub(in crate::tui_shell) entries: Vec<TreeEntry>,
+    pub(in crate::tui_shell) selected: usize,
+    /// Selection in each enclosing directory, restored when going up.
+    pub(in crate::tui_shell) parents: Vec<usize>,
+}
+
+impl FilesView {
+    pub(in crate::tui_shell) fn new(tree: TreeRef, remote: bool, entries: Vec<TreeEntry>) -> Self {
+        Self {
+            updated_at: super::super::app::now_ts(),
+            title: format!("Files {}", tree.label()),
+            tree,
+            remote,
+            dir: String::new(),
+            entries,
+            selected: 0,
+            parents: Vec::new(),
+        }
+    }
+
+    pub(in crate::tui_shell) fn selected_entry(&self) -> Option<&TreeEntry> {
+        self.entries
+            .get(self.selected.min(self.entries.len().saturating_sub(1)))
+    }
+
+    /// Shows the listing of `dir`, a child of the current directory.
+    pub(in crate::tui_shell) fn enter(&mut self, dir: String, entries: Vec<TreeEntry>) {
+        self.parents.push(self.selected);
+        self.dir = dir;
+        self.entries = entries;
+        self.selected = 0;
+        self.updated_at = super::super::app::now_ts();
+    }
+
+    /// Shows the listing of the parent directory, reselecting the directory just left.
+    pub(in crate::tui_shell) fn leave(&mut self, entries: Vec<TreeEntry>) {
+        self.dir = match self.dir.rsplit_once('/') {
+            Some((parent, _)) => parent.to_string(),
+            None => String::new(),
+        };
+        self.entries = entries;
+        self.selected = self.parents.pop().unwrap_or(0);
+        self.updated_at = super::super::app::now_ts();
+    }
+}
+
+impl View for FilesView {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn mode(&self) -> UiMode {
+        UiMode::Files
+    }
+
+    fn title(&self) -> &str {
+        &self.title
+    }
+
+    fn updated_at(&self) -> &str {
+        &self.updated_at
+    }
+
+    fn move_up(&mut self) {
+        self.selected = self.selected.saturating_sub(1);
+    }
+
+    fn move_down(&mut self) {
+        let max = self.entries.len().saturating_sub(1);
+        self.selected = (self.selected + 1).min(max);
+    }
+
+    fn render(&self, frame: &mut ratatui::Frame, area: ratatui::layout::Rect, ctx: &RenderCtx) {
+        let inner = render_view_chrome(frame, ctx, self.title(), self.updated_at(), area);
+        let parts = ctx.split_panes(inner, Direction::Horizontal, 60);
+
+        let list = List::new(render::entry_rows(&self.entries, &ctx.theme))
+            .highlight_style(Style::default().bg(ctx.theme.selected));
+        let block = Block::default().borders(Borders::RIGHT).title(format!(
+            "/{} ({} entries)",
+            self.dir,
+            self.entries.len()
+        ));
+        let selected = (!self.entries.is_empty()).then_some(self.selected);
+        ctx.render_list(frame, list, block, parts[0], selected);
+
+        let block = Block::default().title(" entry (Enter: open; up: parent)");
+        let details = block.inner(parts[1]);
+        frame.render_widget(block, parts[1]);
+        ctx.render_details(frame, render::detail_lines(self.selected_entry()), details);
+    }
+}
+
+#[cfg(test)]
+#[path = "../../../tests/tui_shell/views/files/nav_tests.rs"]
+mod tests;
@@ -0,0 +1,110 @@
+use ratatui::style::Style;
+use ratatui::text::Line;
+use ratatui::widgets::ListItem;
+
+use crate::browse::TreeEntry;
+use crate::model::{ManifestEntryKind, SuperpositionVariantKind};
+use crate::tui_shell::Theme;
+
+/// `<mode> <size> <name>` rows; directories end in `/`, superpositions are marked `!`.
+pub(super) fn entry_rows(entries: &[TreeEntry], theme: &Theme) -> Vec<ListItem<'static>> {
+    if entries.is_empty() {
+        return vec![ListItem::new("(empty)")];
+    }
+    entries
+        .iter()
+        .map(|e| {
+            let name = &e.entry.name;
+            let (mode, size, label, color) = match &e.entry.kind {
+                ManifestEntryKind::File { mode, size, .. } => {
+                    (format!("{:o}", mode), size.to_string(), name.clone(), None)
+                }
+                ManifestEntryKind::FileChunks { mode, size, .. } => (
+                    format!("{:o}", mode),
+                    size.to_string(),
+                    format!("{} [chunked]", name),
+                    None,
+                ),
+                ManifestEntryKind::Dir { .. } => (
+                    String::new(),
+                    String::new(),
+                    format!("{}/", name),
+                    Some(theme.accent),
+                ),
+                ManifestEntryKind::Symlink { target } => (
+                    String::new(),
+                    String::new(),
+                    format!("{} -> {}", name, target),
+                    Some(theme.muted),
+                ),
+                ManifestEntryKind::Superposition { variants } => (
+                    String::new(),
+                    String::new(),
+                    format!("! {} ({} variants)", name, variants.len()),
+                    Some(theme.superposition),
+                ),
+            };
+            let row = format!("{:>6} {:>10}  {}", mode, size, label);
+            match color {
+                Some(c) => ListItem::new(row).style(Style::default().fg(c)),
+                None => ListItem::new(row),
+            }
+        })
+        .collect()
+}
+
+pub(super) fn detail_lines(entry: Option<&TreeEntry>) -> Vec<Line<'static>> {
+    let Some(e) = entry else {
+        return vec![Line::from("(no selection)")];
+    };
+    let mut out = vec![Line::from(format!("path: {}", e.path))];
+    match &e.entry.kind {
+        ManifestEntryKind::File { blob, mode, size } => {
+            out.push(Line::from("kind: file"));
+            out.push(Line::from(format!("mode: {:o}", mode)));
+            out.push(Line::from(format!("size: {} bytes", size)));
+            out.push(Line::from(format!("blob: {}", blob.as_str())));
+        }
+        ManifestEntryKind::FileChunks { recipe, mode, size } => {
+            out.push(Line::from("kind: file (chunked)"));
+            out.push(Line::from(format!("mode: {:o}", mode)));
+            out.push(Line::from(format!("size: {} bytes", size)));
+            out.push(Line::from(format!("recipe: {}", recipe.as_str())));
+        }
+        ManifestEntryKind::Dir { manifest } => {
+            out.push(Line::from("kind: directory"));
+            out.push(Line::from(format!("manifest: {}", manifest.as_str())));
+        }
+        ManifestEntryKind::Symlink { target } => {
+            out.push(Line::from("kind: symlink"));
+            out.push(Line::from(format!("target: {}", target)));
+        }
+        ManifestEntryKind::Superposition { variants } => {
+            out.push(Line::from(format!(
+                "kind: superposition ({} variants; open <n> views one)",
+                variants.len()
+            )));
+            out.push(Line::from(""));
+            for (i, v) in variants.iter().enumerate() {
+                let what = match &v.kind {
+                    SuperpositionVariantKind::File { size, .. } => format!("file {} bytes", size),
+                    SuperpositionVariantKind::FileChunks { size, .. } => {
+                        format!("file {} bytes (chunked)", size)
+                    }
+                    SuperpositionVariantKind::Dir { .. } => "directory".to_string(),
+                    SuperpositionVariantKind::Symlink { target } => {
+                        format!("symlink -> {}", target)
+                    }
+                    SuperpositionVariantKind::Tombstone => "deleted".to_string(),
+                };
+                let who = v
+                    .metadata
+                    .as_ref()
+                    .and_then(|m| m.publisher.clone())
+                    .unwrap_or_else(|| v.source.clone());
+                out.push(Line::from(format!("#{} {} ({})", i + 1, what, who)));
+            }
+        }
+    }
+    out
+}
@@ -0,0 +1,359 @@
+use std::collections::BTreeMap;
+
+use ratatui::style::{Modifier, Style};
+use ratatui::text::{Line, Span};
+
+use super::layout::{Slot, gate_depths, predecessors};
+use super::*;
+use crate::tui_shell::Theme;
+
+const UP: u8 = 1;
+const DOWN: u8 = 2;
+const LEFT: u8 = 4;
+const RIGHT: u8 = 8;
+
+const GAP: usize = 3;
+const MIN_INNER: usize = 10;
+const MAX_INNER: usize = 28;
+const MAX_HEADS: usize = 2;
+
+/// The laid-out DAG as styled lines, plus where the selected gate's box sits.
+pub(super) struct DagRender {
+    pub(super) lines: Vec<Line<'static>>,
+    /// Top-left corner (x, y) of the selected gate's box.
+    pub(super) selected_at: Option<(usize, usize)>,
+    pub(super) box_size: (usize, usize),
+}
+
+pub(super) fn dag_render(view: &GateGraphView, theme: &Theme) -> DagRender {
+    let gates = &view.graph.gates;
+    let selected = gates
+        .get(view.selected.min(gates.len().saturating_sub(1)))
+        .map(|g| g.id.as_str());
+
+    let texts: BTreeMap<&str, Vec<(String, Style)>> = gates
+        .iter()
+        .map(|g| (g.id.as_str(), node_text(g, view.status.get(&g.id), theme)))
+        .collect();
+    let inner = texts
+        .values()
+        .flatten()
+        .map(|(t, _)| t.chars().count())
+        .max()
+        .unwrap_or(0)
+        .clamp(MIN_INNER, MAX_INNER);
+    let box_w = inner + 4;
+    let box_h = texts.values().map(|t| t.len()).max().unwrap_or(1) + 2;
+
+    let slot_w = |s: &Slot| match s {
+        Slot::Gate(_) => box_w,
+        Slot::Via { .. } => 1,
+    };
+    let row_w = |layer: &Vec<Slot>| {
+        layer.iter().map(slot_w).sum::<usize>() + GAP * layer.len().saturating_sub(1)
+    };
+    let width = view.layers.iter().map(row_w).max().unwrap_or(0);
+
+    // Left edge of every slot; layers are centred on the widest.
+    let lefts: Vec<Vec<usize>> = view
+        .layers
+        .iter()
+        .map(|layer| {
+            let mut x = (width - row_w(layer)) / 2;
+            layer
+                .iter()
+                .map(|s| {
+                    let left = x;
+                    x += slot_w(s) + GAP;
+                    left
+                })
+                .collect()
+        })
+        .collect();
+
+    // Edges into each layer as (source x, target x). Every edge gets its own port on both
+    // boxes, so fan-in and fan-out never share a line segment.
+    let depth = gate_depths(gates);
+    let bands: Vec<Vec<(usize, usize)>> = (1..view.layers.len())
+        .map(|l| {
+            let mut edges = Vec::new();
+            for (ti, s) in view.layers[l].iter().enumerate() {
+                for p in predecessors(s, gates, &depth, l) {
+                    if let Some(si) = view.layers[l - 1].iter().position(|x| x == &p) {
+                        edges.push((si, ti));
+                    }
+                }
+            }
+            let port = |layer: usize, idx: usize, nth: usize, of: usize| {
+                let w = slot_w(&view.layers[layer][idx]);
+                let left = lefts[layer][idx];
+                if w == 1 {
+                    left
+                } else {
+                    left + 1 + (nth + 1) * (w - 2) / (of + 1)
+                }
+            };
+            let mut out = Vec::new();
+            for &(si, ti) in &edges {
+                let outs = edges.iter().filter(|e| e.0 == si).map(|e| e.1);
+                let ins = edges.iter().filter(|e| e.1 == ti).map(|e| e.0);
+                let nth_out = outs.clone().filter(|&t| t < ti).count();
+                let nth_in = ins.clone().filter(|&s| s < si).count();
+                out.push((
+                    port(l - 1, si, nth_out, outs.count()),
+                    port(l, ti, nth_in, ins.count()),
+                ));
+            }
+            out.sort_by_key(|&(sx, tx)| (tx, sx));
+            out
+        })
+        .collect();
+
+    // Each band has a routing row per bent edge, then a row for arrows.
+    let mut tops = Vec::new();
+    let mut y = 0;
+    for l in 0..view.layers.len() {
+        tops.push(y);
+        y += box_h;
+        if let Some(band) = bands.get(l) {
+            y += band.iter().filter(|(sx, tx)| sx != tx).count() + 1;
+        }
+    }
+
+    let mut canvas = Canvas::new(width, y);
+    let mut selected_at = None;
+    for (l, layer) in view.layers.iter().enumerate() {
+        let top = tops[l];
+        for (i, s) in layer.iter().enumerate() {
+            let left = lefts[l][i];
+            match s {
+                Slot::Gate(id) => {
+                    let is_sel = selected == Some(id.as_str());
+                    let border = if is_sel {
+                        Style::default()
+                            .fg(theme.accent)
+                            .add_modifier(Modifier::BOLD)
+                    } else {
+                        Style::default()
+                    };
+                    if is_sel {
+                        selected_at = Some((left, top));
+                    }
+                    canvas.rect(left, top, box_w, box_h, border);
+                    for (row, (text, style)) in texts[id.as_str()].iter().enumerate() {
+                        let text = truncate(text, inner);
+                        let style = if row == 0 && is_sel {
+                            style.patch(border)
+                        } else {
+                            *style
+                        };
+                        canvas.text(left + 2, top + 1 + row, &text, style);
+                    }
+                }
+                Slot::Via { .. } => canvas.vline(left, top, top + box_h - 1, Style::default()),
+            }
+        }
+
+        if l == 0 {
+            continue;
+        }
+        let band_top = tops[l - 1] + box_h;
+        let mut route = band_top;
+        for &(sx, tx) in &bands[l - 1] {
+            if sx == tx {
+                canvas.vline(sx, band_top - 1, top, Style::default());
+            } else {
+                canvas.vline(sx, band_top - 1, route, Style::default());
+                canvas.hline(route, sx.min(tx), sx.max(tx), Style::default());
+                canvas.vline(tx, route, top, Style::default());
+                route += 1;
+            }
+            if layer.iter().enumerate().any(|(i, s)| {
+                matches!(s, Slot::Gate(_)) && (lefts[l][i]..lefts[l][i] + box_w).contains(&tx)
+            }) {
+                canvas.arrow(tx, top - 1);
+            }
+        }
+    }
+
+    DagRender {
+        lines: canvas.into_lines(),
+        selected_at,
+        box_size: (box_w, box_h),
+    }
+}
+
+/// Lines inside a gate's box: id, current bundle per scope, then pending inbox count.
+fn node_text(
+    g: &crate::remote::GateDef,
+    status: Option<&GateStatus>,
+    theme: &Theme,
+) -> Vec<(String, Style)> {
+    let mut out = vec![(g.id.clone(), Style::default().add_modifier(Modifier::BOLD))];
+    let dim = Style::default().fg(theme.dim);
+    let Some(status) = status else {
+        out.push(("(status unavailable)".to_string(), dim));
+        return out;
+    };
+
+    if status.heads.is_empty() {
+        out.push(("no bundle".to_string(), dim));
+    }
+    for h in status.heads.iter().take(MAX_HEADS) {
+        let (label, color) = match h.promotable {
+            Some(true) => ("ok", theme.ok),
+            Some(false) => ("blocked", theme.error),
+            None => ("?", theme.dim),
+        };
+        out.push((
+            format!(
+                "{} {} {}",
+                h.scope,
+                h.bundle_id.chars().take(8).collect::<String>(),
+                label
+            ),
+            Style::default().fg(color),
+        ));
+    }
+    if status.heads.len() > MAX_HEADS {
+        out.push((format!("+{} scopes", status.heads.len() - MAX_HEADS), dim));
+    }
+    if status.pending > 0 {
+        out.push((
+            format!("inbox: {} pending", status.pending),
+            Style::default().fg(theme.warning),
+        ));
+    } else {
+        out.push(("inbox: clear".to_string(), dim));
+    }
+    out
+}
+
+fn truncate(s: &str, max: usize) -> String {
+    if s.chars().count() <= max {
+        return s.to_string();
+    }
+    let mut out = s.chars().take(max.saturating_sub(1)).collect::<String>();
+    out.push('…');
+    out
+}
+
+#[derive(Clone, Copy)]
+enum Cell {
+    Blank,
+    Lines(u8),
+    Char(char),
+}
+
+/// Character grid where line segments merge into box-drawing junctions.
+struct Canvas {
+    cells: Vec<Vec<(Cell, Style)>>,
+}
+
+impl Canvas {
+    fn new(width: usize, height: usize) -> Self {
+        Self {
+            cells: vec![vec![(Cell::Blank, Style::default()); width]; height],
+        }
+    }
+
+    fn add(&mut self, x: usize, y: usize, bits: u8, style: Style) {
+        let Some(cell) = self.cells.get_mut(y).and_then(|r| r.get_mut(x)) else {
+            return;
+        };
+        cell.0 = match cell.0 {
+            Cell::Lines(b) => Cell::Lines(b | bits),
+            Cell::Blank => Cell::Lines(bits),
+            c @ Cell::Char(_) => c,
+        };
+        cell.1 = cell.1.patch(style);
+    }
+
+    fn hline(&mut self, y: usize, x0: usize, x1: usize, style: Style) {
+        if x0 == x1 {
+            return;
+        }
+        self.add(x0, y, RIGHT, style);
+        for x in x0 + 1..x1 {
+            self.add(x, y, LEFT | RIGHT, style);
+        }
+        self.add(x1, y, LEFT, style);
+    }
+
+    fn vline(&mut self, x: usize, y0: usize, y1: usize, style: Style) {
+        if y0 == y1 {
+            return;
+        }
+        self.add(x, y0, DOWN, style);
+        for y in y0 + 1..y1 {
+            self.add(x, y, UP | DOWN, style);
+        }
+        self.add(x, y1, UP, style);
+    }
+
+    fn rect(&mut self, x: usize, y: usize, w: usize, h: usize, style: Style) {
+        self.hline(y, x, x + w - 1, style);
+        self.hline(y + h - 1, x, x + w - 1, style);
+        self.vline(x, y, y + h - 1, style);
+        self.vline(x + w - 1, y, y + h - 1, style);
+    }
+
+    fn text(&mut self, x: usize, y: usize, text: &str, style: Style) {
+        for (i, ch) in text.chars().enumerate() {
+            if let Some(cell) = self.cells.get_mut(y).and_then(|r| r.get_mut(x + i)) {
+                *cell = (Cell::Char(ch), style);
+            }
+        }
+    }
+
+    fn arrow(&mut self, x: usize, y: usize) {
+        if let Some(cell) = self.cells.get_mut(y).and_then(|r| r.get_mut(x))
+            && matches!(cell.0, Cell::Lines(b) if b == UP | DOWN)
+        {
+            cell.0 = Cell::Char('▼');
+        }
+    }
+
+    fn into_lines(self) -> Vec<Line<'static>> {
+        self.cells
+            .into_iter()
+            .map(|row| {
+                let mut spans: Vec<Span<'static>> = Vec::new();
+                let mut run = String::new();
+                let mut run_style = Style::default();
+                for (cell, style) in row {
+                    if style != run_style && !run.is_empty() {
+                        spans.push(Span::styled(std::mem::take(&mut run), run_style));
+                    }
+                    run_style = style;
+                    run.push(match cell {
+                        Cell::Blank => ' ',
+                        Cell::Char(c) => c,
+                        Cell::Lines(b) => junction(b),
+                    });
+                }
+                let run = run.trim_end().to_string();
+                if !run.is_empty() {
+                    spans.push(Span::styled(run, run_style));
+                }
+                Line::from(spans)
+            })
+            .collect()
+    }
+}
+
+fn junction(bits: u8) -> char {
+    match bits {
+        b if b == UP | DOWN | LEFT | RIGHT => '┼',
+        b if b == DOWN | LEFT | RIGHT => '┬',
+        b if b == UP | LEFT | RIGHT => '┴',
+        b if b == UP | DOWN | RIGHT => '├',
+        b if b == UP | DOWN | LEFT => '┤',
+        b if b == DOWN | RIGHT => '┌',
+        b if b == DOWN | LEFT => '┐',
+        b if b == UP | RIGHT => '└',
+        b if b == UP | LEFT => '┘',
+        b if b & (LEFT | RIGHT) != 0 => '─',
+        _ => '│',
+    }
+}
@@ -17,6 +17,38 @@ pub(super) fn details_lines(view: &GateGraphView) -> Vec<Line<'static>> {
     } else {
         out.push(Line::from(format!("upstream: {}", g.upstream.join(", "))));
     }
+    let downstream = view
+        .graph
+        .gates
+        .iter()
+        .filter(|d| d.upstream.contains(&g.id))
+        .map(|d| d.id.as_str())
+        .collect::<Vec<_>>();
+    if !downstream.is_empty() {
+        out.push(Line::from(format!("downstream: {}", downstream.join(", "))));
+    }
+    out.push(Line::from(format!(
+        "owner lane: {}",
+        g.lane.as_deref().unwrap_or("(none)")
+    )));
+    if let Some(status) = view.status.get(&g.id) {
+        out.push(Line::from(""));
+        if status.heads.is_empty() {
+            out.push(Line::from("bundles: (none promoted)"));
+        }
+        for h in &status.heads {
+            let state = match h.promotable {
+                Some(true) => "promotable",
+                Some(false) => "blocked",
+                None => "unknown",
+            };
+            out.push(Line::from(format!(
+                "bundle {}: {} ({})",
+                h.scope, h.bundle_id, state
+            )));
+        }
+        out.push(Line::from(format!("inbox pending: {}", status.pending)));
+    }
     out.push(Line::from(""));
     out.push(Line::from("policy:"));
     out.push(Line::from(format!("allow_releases: {}", g.allow_releases)));
@@ -32,5 +64,9 @@ pub(super) fn details_lines(view: &GateGraphView) -> Vec<Line<'static>> {
         "required_approvals: {}",
         g.required_approvals
     )));
+    out.push(Line::from(format!("require_signed: {}", g.require_signed)));
+    if !g.roles.is_empty() {
+        out.push(Line::from(format!("roles: {}", g.roles.summary())));
+    }
     out
 }
@@ -0,0 +1,157 @@
+use std::collections::{BTreeMap, HashMap};
+
+use crate::remote::GateDef;
+
+/// One position in a layer of the DAG.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub(in crate::tui_shell) enum Slot {
+    Gate(String),
+    /// Edge `from -> to` passing through a layer it skips.
+    Via {
+        from: String,
+        to: String,
+    },
+}
+
+/// Layers top to bottom (upstream first); gates sit one layer below their deepest upstream.
+pub(in crate::tui_shell) fn gate_layers(gates: &[GateDef]) -> Vec<Vec<Slot>> {
+    let depth = gate_depths(gates);
+    let Some(max) = depth.values().copied().max() else {
+        return Vec::new();
+    };
+
+    let mut layers: Vec<Vec<Slot>> = vec![Vec::new(); max + 1];
+    for g in gates {
+        layers[depth[g.id.as_str()]].push(Slot::Gate(g.id.clone()));
+        for u in known_upstream(g, &depth) {
+            for layer in layers
+                .iter_mut()
+                .take(depth[g.id.as_str()])
+                .skip(depth[u] + 1)
+            {
+                layer.push(Slot::Via {
+                    from: u.to_string(),
+                    to: g.id.clone(),
+                });
+            }
+        }
+    }
+
+    // Order each layer by the mean position of its predecessors to keep edges short.
+    layers[0].sort_by(|a, b| slot_key(a).cmp(&slot_key(b)));
+    for l in 1..layers.len() {
+        let above: HashMap<&Slot, usize> = layers[l - 1]
+            .iter()
+            .enumerate()
+            .map(|(i, s)| (s, i))
+            .collect();
+        let mut keyed = layers[l]
+            .iter()
+            .map(|s| {
+                let preds = predecessors(s, gates, &depth, l)
+                    .iter()
+                    .filter_map(|p| above.get(p).copied())
+                    .collect::<Vec<_>>();
+                let bary = if preds.is_empty() {
+                    0.0
+                } else {
+                    preds.iter().sum::<usize>() as f64 / preds.len() as f64
+                };
+                (bary, s.clone())
+            })
+            .collect::<Vec<_>>();
+        keyed.sort_by(|(ka, a), (kb, b)| {
+            ka.total_cmp(kb).then_with(|| slot_key(a).cmp(&slot_key(b)))
+        });
+        layers[l] = keyed.into_iter().map(|(_, s)| s).collect();
+    }
+    layers
+}
+
+/// Gate ids in reading order of the layout (layer by layer, left to right).
+pub(in crate::tui_shell) fn gate_order(layers: &[Vec<Slot>]) -> Vec<String> {
+    layers
+        .iter()
+        .flatten()
+        .filter_map(|s| match s {
+            Slot::Gate(id) => Some(id.clone()),
+            Slot::Via { .. } => None,
+        })
+        .collect()
+}
+
+/// Slots in layer `l - 1` with an edge into `slot` (which sits in layer `l`).
+pub(in crate::tui_shell) fn predecessors(
+    slot: &Slot,
+    gates: &[GateDef],
+    depth: &BTreeMap<&str, usize>,
+    l: usize,
+) -> Vec<Slot> {
+    let via_or_gate = |from: &str, to: &str| {
+        if depth.get(from) == Some(&(l - 1)) {
+            Slot::Gate(from.to_string())
+        } else {
+            Slot::Via {
+                from: from.to_string(),
+                to: to.to_string(),
+            }
+        }
+    };
+    match slot {
+        Slot::Gate(id) => gates
+            .iter()
+            .find(|g| &g.id == id)
+            .map(|g| {
+                known_upstream(g, depth)
+                    .map(|u| via_or_gate(u, id))
+                    .collect()
+            })
+            .unwrap_or_default(),
+        Slot::Via { from, to } => vec![via_or_gate(from, to)],
+    }
+}
+
+/// Longest-path depth of each gate; unknown upstream ids are ignored and cycles are cut off.
+pub(in crate::tui_shell) fn gate_depths(gates: &[GateDef]) -> BTreeMap<&str, usize> {
+    let mut depth: BTreeMap<&str, usize> = gates.iter().map(|g| (g.id.as_str(), 0)).collect();
+    for _ in 0..gates.len() {
+        let mut changed = false;
+        for g in gates {
+            let d = g
+                .upstream
+                .iter()
+                .filter(|u| *u != &g.id)
+                .filter_map(|u| depth.get(u.as_str()))
+                .map(|d| d + 1)
+                .max()
+                .unwrap_or(0)
+                .min(gates.len());
+            if depth[g.id.as_str()] != d {
+                depth.insert(g.id.as_str(), d);
+                changed = true;
+            }
+        }
+        if !changed {
+            break;
+        }
+    }
+    depth
+}
+
+fn known_upstream<'a>(
+    g: &'a GateDef,
+    depth: &'a BTreeMap<&str, usize>,
+) -> impl Iterator<Item = &'a str> + 'a {
+    let own = depth.get(g.id.as_str()).copied().unwrap_or(0);
+    g.upstream
+        .iter()
+        .map(|u| u.as_str())
+        .filter(move |u| depth.get(u).is_some_and(|d| *d < own))
+}
+
+fn slot_key(s: &Slot) -> (&str, u8, &str) {
+    match s {
+        Slot::Gate(id) => (id, 0, ""),
+        Slot::Via { from, to } => (from, 1, to),
+    }
+}
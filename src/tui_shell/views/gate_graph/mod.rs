@@ -1,23 +1,71 @@
+use std::collections::BTreeMap;
+
 use super::super::{RenderCtx, UiMode, View, fmt_ts_ui, render_view_chrome};
 
+mod dag;
 mod details;
+mod layout;
 mod render;
-mod rows;
+
+pub(in crate::tui_shell) use self::layout::Slot;
+
+/// Current bundle for a gate in one scope, from the server's promotion state.
+#[derive(Clone, Debug)]
+pub(in crate::tui_shell) struct GateHead {
+    pub(in crate::tui_shell) scope: String,
+    pub(in crate::tui_shell) bundle_id: String,
+    /// `None` when the bundle is not in the bundle list (e.g. pruned).
+    pub(in crate::tui_shell) promotable: Option<bool>,
+}
+
+/// What the DAG annotates a gate with.
+#[derive(Clone, Debug, Default)]
+pub(in crate::tui_shell) struct GateStatus {
+    pub(in crate::tui_shell) heads: Vec<GateHead>,
+    /// Publications to this gate (any scope) not yet resolved into a bundle.
+    pub(in crate::tui_shell) pending: usize,
+}
 
 #[derive(Debug)]
 pub(in crate::tui_shell) struct GateGraphView {
     pub(in crate::tui_shell) updated_at: String,
     pub(in crate::tui_shell) graph: crate::remote::GateGraph,
+    pub(in crate::tui_shell) layers: Vec<Vec<Slot>>,
+    pub(in crate::tui_shell) status: BTreeMap<String, GateStatus>,
     pub(in crate::tui_shell) selected: usize,
 }
 
 impl GateGraphView {
-    pub(in crate::tui_shell) fn new(mut graph: crate::remote::GateGraph) -> Self {
-        graph.gates.sort_by(|a, b| a.id.cmp(&b.id));
+    pub(in crate::tui_shell) fn new(
+        graph: crate::remote::GateGraph,
+        status: BTreeMap<String, GateStatus>,
+    ) -> Self {
+        let (graph, layers) = lay_out(graph);
         Self {
             updated_at: super::super::app::now_ts(),
             graph,
+            layers,
+            status,
             selected: 0,
         }
     }
+
+    pub(in crate::tui_shell) fn set_graph(&mut self, graph: crate::remote::GateGraph) {
+        (self.graph, self.layers) = lay_out(graph);
+    }
 }
+
+/// Orders gates as the DAG reads (layer by layer, left to right) so Up/Down follow the layout.
+fn lay_out(mut graph: crate::remote::GateGraph) -> (crate::remote::GateGraph, Vec<Vec<Slot>>) {
+    graph.gates.sort_by(|a, b| a.id.cmp(&b.id));
+    let layers = layout::gate_layers(&graph.gates);
+    let order = layout::gate_order(&layers);
+    graph
+        .gates
+        .sort_by_key(|g| order.iter().position(|id| id == &g.id));
+    (graph, layers)
+}
+
+#[cfg(test)]
+#[path = "../../../tests/tui_shell/views/gate_graph/layout_tests.rs"]
+mod tests;
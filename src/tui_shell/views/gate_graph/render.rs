@@ -1,11 +1,10 @@
 use std::any::Any;
 
-use ratatui::layout::{Constraint, Direction, Layout};
-use ratatui::style::{Color, Style};
-use ratatui::widgets::{Block, Borders, List, ListState, Paragraph, Wrap};
+use ratatui::layout::Direction;
+use ratatui::widgets::{Block, Borders, Paragraph};
 
+use super::dag::dag_render;
 use super::details::details_lines;
-use super::rows::{list_rows, list_title};
 use super::*;
 
 impl View for GateGraphView {
@@ -42,38 +41,49 @@ impl View for GateGraphView {
         self.selected = (self.selected + 1).min(max);
     }
 
-    fn render(&self, frame: &mut ratatui::Frame, area: ratatui::layout::Rect, _ctx: &RenderCtx) {
-        let inner = render_view_chrome(frame, self.title(), self.updated_at(), area);
-        let parts = Layout::default()
-            .direction(Direction::Vertical)
-            .constraints([Constraint::Percentage(65), Constraint::Percentage(35)])
-            .split(inner);
+    fn render(&self, frame: &mut ratatui::Frame, area: ratatui::layout::Rect, ctx: &RenderCtx) {
+        let inner = render_view_chrome(frame, ctx, self.title(), self.updated_at(), area);
+        let parts = ctx.split_panes(inner, Direction::Vertical, 65);
 
-        let mut state = ListState::default();
-        if !self.graph.gates.is_empty() {
-            state.select(Some(
-                self.selected.min(self.graph.gates.len().saturating_sub(1)),
-            ));
-        }
+        let block = Block::default()
+            .borders(Borders::BOTTOM)
+            .title(graph_title(self));
+        let pane = block.inner(parts[0]);
+        frame.render_widget(block, parts[0]);
 
-        let list = List::new(list_rows(self))
-            .block(
-                Block::default()
-                    .borders(Borders::BOTTOM)
-                    .title(list_title(self)),
-            )
-            .highlight_style(Style::default().bg(Color::DarkGray));
-        frame.render_stateful_widget(list, parts[0], &mut state);
+        if self.graph.gates.is_empty() {
+            frame.render_widget(Paragraph::new("(empty)"), pane);
+        } else {
+            // Scroll just enough to keep the selected box in view.
+            let dag = dag_render(self, &ctx.theme);
+            let (x, y) = dag.selected_at.unwrap_or((0, 0));
+            let (w, h) = dag.box_size;
+            let scroll_x = (x + w).saturating_sub(pane.width as usize);
+            let scroll_y = (y + h).saturating_sub(pane.height as usize);
+            frame.render_widget(
+                Paragraph::new(dag.lines).scroll((
+                    scroll_y.min(u16::MAX as usize) as u16,
+                    scroll_x.min(u16::MAX as usize) as u16,
+                )),
+                pane,
+            );
+        }
 
-        frame.render_widget(
-            Paragraph::new(details_lines(self))
-                .wrap(Wrap { trim: false })
-                .block(
-                    Block::default()
-                        .borders(Borders::ALL)
-                        .title(format!("updated {}", fmt_ts_ui(self.updated_at()))),
-                ),
-            parts[1],
-        );
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(format!("updated {}", fmt_ts_ui(self.updated_at())));
+        let details = block.inner(parts[1]);
+        frame.render_widget(block, parts[1]);
+        ctx.render_details(frame, details_lines(self), details);
     }
 }
+
+fn graph_title(view: &GateGraphView) -> String {
+    let releases_enabled = view.graph.gates.iter().filter(|g| g.allow_releases).count();
+    format!(
+        "gates={} layers={} releases_enabled={} (/ for commands)",
+        view.graph.gates.len(),
+        view.layers.len(),
+        releases_enabled
+    )
+}
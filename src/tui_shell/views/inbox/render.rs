@@ -1,9 +1,9 @@
 use std::any::Any;
 
-use ratatui::layout::{Constraint, Direction, Layout};
-use ratatui::style::{Color, Style};
+use ratatui::layout::Direction;
+use ratatui::style::Style;
 use ratatui::text::{Line, Span};
-use ratatui::widgets::{Block, Borders, List, ListState, Paragraph, Wrap};
+use ratatui::widgets::{Block, Borders, List};
 
 use super::super::super::view::render_view_chrome_with_header;
 use super::details::details_lines;
@@ -44,41 +44,37 @@ impl View for InboxView {
         self.selected = (self.selected + 1).min(max);
     }
 
-    fn render(&self, frame: &mut ratatui::Frame, area: ratatui::layout::Rect, _ctx: &RenderCtx) {
+    fn render(&self, frame: &mut ratatui::Frame, area: ratatui::layout::Rect, ctx: &RenderCtx) {
         let header = Line::from(vec![
-            Span::styled(self.title().to_string(), Style::default().fg(Color::Yellow)),
+            Span::styled(
+                self.title().to_string(),
+                Style::default().fg(ctx.theme.heading),
+            ),
             Span::raw("  "),
             Span::styled(
                 format!(
                     "{} total  {} pending  {} resolved  {} missing",
                     self.total, self.pending, self.resolved, self.missing_local
                 ),
-                Style::default().fg(Color::Gray),
+                Style::default().fg(ctx.theme.muted),
             ),
         ]);
         let inner = render_view_chrome_with_header(frame, header, area);
-        let parts = Layout::default()
-            .direction(Direction::Vertical)
-            .constraints([Constraint::Percentage(65), Constraint::Percentage(35)])
-            .split(inner);
-
-        let mut state = ListState::default();
-        if !self.items.is_empty() {
-            state.select(Some(self.selected.min(self.items.len().saturating_sub(1))));
-        }
-
-        let list = List::new(list_rows(self))
-            .block(
-                Block::default()
-                    .borders(Borders::BOTTOM)
-                    .title(format!("{} (Enter: bundle; /: commands)", subtitle(self))),
-            )
-            .highlight_style(Style::default().bg(Color::DarkGray));
-        frame.render_stateful_widget(list, parts[0], &mut state);
+        let parts = ctx.split_panes(inner, Direction::Vertical, 65);
 
-        frame.render_widget(
-            Paragraph::new(details_lines(self)).wrap(Wrap { trim: false }),
-            parts[1],
+        let list =
+            List::new(list_rows(self)).highlight_style(Style::default().bg(ctx.theme.selected));
+        let block = Block::default()
+            .borders(Borders::BOTTOM)
+            .title(format!("{} (Enter: bundle; /: commands)", subtitle(self)));
+        ctx.render_list(
+            frame,
+            list,
+            block,
+            parts[0],
+            (!self.items.is_empty()).then_some(self.selected),
         );
+
+        ctx.render_details(frame, details_lines(self), parts[1]);
     }
 }
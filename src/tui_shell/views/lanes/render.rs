@@ -1,8 +1,8 @@
 use std::any::Any;
 
-use ratatui::layout::{Constraint, Direction, Layout};
-use ratatui::style::{Color, Style};
-use ratatui::widgets::{Block, Borders, List, ListState, Paragraph, Wrap};
+use ratatui::layout::Direction;
+use ratatui::style::Style;
+use ratatui::widgets::{Block, Borders, List};
 
 use super::details::details_lines;
 use super::rows::list_rows;
@@ -43,29 +43,22 @@ impl View for LanesView {
     }
 
     fn render(&self, frame: &mut ratatui::Frame, area: ratatui::layout::Rect, ctx: &RenderCtx) {
-        let inner = render_view_chrome(frame, self.title(), self.updated_at(), area);
-        let parts = Layout::default()
-            .direction(Direction::Vertical)
-            .constraints([Constraint::Percentage(65), Constraint::Percentage(35)])
-            .split(inner);
-
-        let mut state = ListState::default();
-        if !self.items.is_empty() {
-            state.select(Some(self.selected.min(self.items.len().saturating_sub(1))));
-        }
+        let inner = render_view_chrome(frame, ctx, self.title(), self.updated_at(), area);
+        let parts = ctx.split_panes(inner, Direction::Vertical, 65);
 
         let list = List::new(list_rows(self, ctx))
-            .block(
-                Block::default()
-                    .borders(Borders::BOTTOM)
-                    .title("(Enter: fetch; /: commands)".to_string()),
-            )
-            .highlight_style(Style::default().bg(Color::DarkGray));
-        frame.render_stateful_widget(list, parts[0], &mut state);
-
-        frame.render_widget(
-            Paragraph::new(details_lines(self)).wrap(Wrap { trim: false }),
-            parts[1],
+            .highlight_style(Style::default().bg(ctx.theme.selected));
+        let block = Block::default()
+            .borders(Borders::BOTTOM)
+            .title("(Enter: fetch; /: commands)".to_string());
+        ctx.render_list(
+            frame,
+            list,
+            block,
+            parts[0],
+            (!self.items.is_empty()).then_some(self.selected),
         );
+
+        ctx.render_details(frame, details_lines(self), parts[1]);
     }
 }
@@ -0,0 +1,109 @@
+use std::any::Any;
+
+use ratatui::layout::{Constraint, Direction, Layout};
+use ratatui::widgets::{Block, Borders, Paragraph};
+
+use crate::merge::{ConflictChoice, LineSegment};
+use crate::model::ObjectId;
+
+use super::super::{RenderCtx, UiMode, View, render_view_chrome};
+
+mod render;
+mod state;
+
+/// Undoable part of a merge session.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub(in crate::tui_shell) struct MergeState {
+    /// One entry per conflict, in document order.
+    pub(in crate::tui_shell) choices: Vec<Option<ConflictChoice>>,
+    /// Result text after a round trip through `$EDITOR`; overrides `choices`.
+    pub(in crate::tui_shell) edited: Option<String>,
+}
+
+/// Two-variant text merge for one superposition path.
+#[derive(Debug)]
+pub(in crate::tui_shell) struct MergeView {
+    pub(in crate::tui_shell) updated_at: String,
+    pub(in crate::tui_shell) title: String,
+    pub(in crate::tui_shell) bundle_id: String,
+    pub(in crate::tui_shell) root_manifest: ObjectId,
+    pub(in crate::tui_shell) path: String,
+    pub(in crate::tui_shell) labels: (String, String),
+    pub(in crate::tui_shell) mode: u32,
+    pub(in crate::tui_shell) segments: Vec<LineSegment>,
+    pub(in crate::tui_shell) trailing_newline: bool,
+
+    pub(in crate::tui_shell) state: MergeState,
+    pub(in crate::tui_shell) undo: Vec<MergeState>,
+    pub(in crate::tui_shell) redo: Vec<MergeState>,
+    pub(in crate::tui_shell) conflict: usize,
+}
+
+impl View for MergeView {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn mode(&self) -> UiMode {
+        UiMode::Merge
+    }
+
+    fn title(&self) -> &str {
+        &self.title
+    }
+
+    fn updated_at(&self) -> &str {
+        &self.updated_at
+    }
+
+    fn move_up(&mut self) {
+        self.conflict = self.conflict.saturating_sub(1);
+    }
+
+    fn move_down(&mut self) {
+        self.conflict = (self.conflict + 1).min(self.conflicts().saturating_sub(1));
+    }
+
+    fn render(&self, frame: &mut ratatui::Frame, area: ratatui::layout::Rect, ctx: &RenderCtx) {
+        let inner = render_view_chrome(frame, ctx, self.title(), self.updated_at(), area);
+        let parts = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([
+                Constraint::Percentage(33),
+                Constraint::Percentage(33),
+                Constraint::Percentage(34),
+            ])
+            .split(inner);
+
+        let (left, right, variant_row) = render::variant_panes(self, &ctx.theme);
+        let unresolved = self.unresolved();
+        let result_title = format!(
+            "result{} ({} unresolved)",
+            if self.state.edited.is_some() {
+                ", edited"
+            } else {
+                ""
+            },
+            unresolved
+        );
+        let (result, result_row) = render::result_pane(self, &ctx.theme);
+        let panes = [
+            (format!("A: {}", self.labels.0), left, variant_row),
+            (format!("B: {}", self.labels.1), right, variant_row),
+            (result_title, result, result_row),
+        ];
+        for (i, (title, lines, row)) in panes.into_iter().enumerate() {
+            let borders = if i < 2 { Borders::RIGHT } else { Borders::NONE };
+            let block = Block::default().borders(borders).title(title);
+            let scroll = row.saturating_sub(2).min(u16::MAX as usize) as u16;
+            frame.render_widget(
+                Paragraph::new(lines).block(block).scroll((scroll, 0)),
+                parts[i],
+            );
+        }
+    }
+}
@@ -0,0 +1,155 @@
+use ratatui::style::{Modifier, Style};
+use ratatui::text::{Line, Span};
+
+use crate::merge::{
+    CONFLICT_END_MARKER, CONFLICT_SEPARATOR, CONFLICT_START_MARKER, ConflictChoice, LineSegment,
+};
+
+use super::MergeView;
+use crate::tui_shell::Theme;
+
+/// Side-by-side variant lines, padded so each conflict lines up in both panes, plus the row
+/// where the selected conflict starts.
+pub(super) fn variant_panes(
+    v: &MergeView,
+    theme: &Theme,
+) -> (Vec<Line<'static>>, Vec<Line<'static>>, usize) {
+    let mut left = Vec::new();
+    let mut right = Vec::new();
+    let mut row = 0;
+    let mut conflict = 0;
+    for segment in &v.segments {
+        match segment {
+            LineSegment::Common(lines) => {
+                for l in lines {
+                    left.push(Line::from(l.clone()));
+                    right.push(Line::from(l.clone()));
+                }
+            }
+            LineSegment::Conflict { left: a, right: b } => {
+                let selected = conflict == v.conflict;
+                if selected {
+                    row = left.len();
+                }
+                let choice = v.state.choices.get(conflict).copied().flatten();
+                let (a_style, b_style) =
+                    side_styles(choice, v.state.edited.is_some(), selected, theme);
+                let n = a.len().max(b.len()).max(1);
+                for i in 0..n {
+                    left.push(conflict_line(a.get(i), a_style));
+                    right.push(conflict_line(b.get(i), b_style));
+                }
+                conflict += 1;
+            }
+        }
+    }
+    (left, right, row)
+}
+
+fn side_styles(
+    choice: Option<ConflictChoice>,
+    edited: bool,
+    selected: bool,
+    theme: &Theme,
+) -> (Style, Style) {
+    let open = Style::default().fg(theme.warning);
+    let taken = Style::default().fg(theme.added);
+    let dropped = Style::default().fg(theme.dim);
+    let (a, b) = match (edited, choice) {
+        (true, _) | (false, None) => (open, open),
+        (false, Some(ConflictChoice::Left)) => (taken, dropped),
+        (false, Some(ConflictChoice::Right)) => (dropped, taken),
+        (false, Some(ConflictChoice::Both)) => (taken, taken),
+    };
+    if selected {
+        (a.bg(theme.selected), b.bg(theme.selected))
+    } else {
+        (a, b)
+    }
+}
+
+fn conflict_line(text: Option<&String>, style: Style) -> Line<'static> {
+    match text {
+        Some(t) => Line::from(Span::styled(t.clone(), style)),
+        None => Line::from(Span::styled(
+            "~".to_string(),
+            style.add_modifier(Modifier::DIM),
+        )),
+    }
+}
+
+/// Result lines with conflict markers highlighted, plus the row of the selected conflict.
+pub(super) fn result_pane(v: &MergeView, theme: &Theme) -> (Vec<Line<'static>>, usize) {
+    let marker = Style::default().fg(theme.superposition);
+    if let Some(text) = &v.state.edited {
+        let lines = text
+            .lines()
+            .map(|l| {
+                if l.starts_with(CONFLICT_START_MARKER)
+                    || l.starts_with(CONFLICT_END_MARKER)
+                    || l == CONFLICT_SEPARATOR
+                {
+                    Line::from(Span::styled(l.to_string(), marker))
+                } else {
+                    Line::from(l.to_string())
+                }
+            })
+            .collect();
+        return (lines, 0);
+    }
+
+    let mut out = Vec::new();
+    let mut row = 0;
+    let mut conflict = 0;
+    for segment in &v.segments {
+        match segment {
+            LineSegment::Common(lines) => out.extend(lines.iter().map(|l| Line::from(l.clone()))),
+            LineSegment::Conflict { left, right } => {
+                let selected = conflict == v.conflict;
+                if selected {
+                    row = out.len();
+                }
+                let mut style = Style::default().fg(theme.added);
+                if selected {
+                    style = style.bg(theme.selected);
+                }
+                let styled = |l: &String| Line::from(Span::styled(l.clone(), style));
+                match v.state.choices.get(conflict).copied().flatten() {
+                    Some(ConflictChoice::Left) => out.extend(left.iter().map(styled)),
+                    Some(ConflictChoice::Right) => out.extend(right.iter().map(styled)),
+                    Some(ConflictChoice::Both) => {
+                        out.extend(left.iter().chain(right.iter()).map(styled))
+                    }
+                    None => {
+                        let open = Style::default().fg(theme.warning);
+                        let marker = if selected {
+                            marker.bg(theme.selected)
+                        } else {
+                            marker
+                        };
+                        out.push(Line::from(Span::styled(
+                            format!("{} {}", CONFLICT_START_MARKER, v.labels.0),
+                            marker,
+                        )));
+                        out.extend(
+                            left.iter()
+                                .map(|l| Line::from(Span::styled(l.clone(), open))),
+                        );
+                        out.push(Line::from(Span::styled(CONFLICT_SEPARATOR, marker)));
+                        out.extend(
+                            right
+                                .iter()
+                                .map(|l| Line::from(Span::styled(l.clone(), open))),
+                        );
+                        out.push(Line::from(Span::styled(
+                            format!("{} {}", CONFLICT_END_MARKER, v.labels.1),
+                            marker,
+                        )));
+                    }
+                }
+                conflict += 1;
+            }
+        }
+    }
+    (out, row)
+}
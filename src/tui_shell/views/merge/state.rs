@@ -0,0 +1,145 @@
+use crate::merge::{ConflictChoice, LineSegment, has_conflict_markers, merged_text};
+use crate::model::ObjectId;
+
+use super::{MergeState, MergeView};
+
+impl MergeView {
+    pub(in crate::tui_shell) fn new(
+        bundle_id: String,
+        root_manifest: ObjectId,
+        path: String,
+        labels: (String, String),
+        mode: u32,
+        left: &str,
+        right: &str,
+    ) -> Self {
+        let segments = crate::merge::line_segments(left, right);
+        let conflicts = segments
+            .iter()
+            .filter(|s| matches!(s, LineSegment::Conflict { .. }))
+            .count();
+        Self {
+            updated_at: super::super::super::app::now_ts(),
+            title: format!("Merge {}", path),
+            bundle_id,
+            root_manifest,
+            path,
+            labels,
+            mode,
+            segments,
+            trailing_newline: left.ends_with('\n') || right.ends_with('\n'),
+            state: MergeState {
+                choices: vec![None; conflicts],
+                edited: None,
+            },
+            undo: Vec::new(),
+            redo: Vec::new(),
+            conflict: 0,
+        }
+    }
+
+    pub(in crate::tui_shell) fn conflicts(&self) -> usize {
+        self.state.choices.len()
+    }
+
+    /// Conflicts still open: unchosen ones, or marker blocks left in an edited result.
+    pub(in crate::tui_shell) fn unresolved(&self) -> usize {
+        match &self.state.edited {
+            Some(text) if has_conflict_markers(text) => text
+                .lines()
+                .filter(|l| l.starts_with(crate::merge::CONFLICT_START_MARKER))
+                .count(),
+            Some(_) => 0,
+            None => self.state.choices.iter().filter(|c| c.is_none()).count(),
+        }
+    }
+
+    pub(in crate::tui_shell) fn result(&self) -> String {
+        if let Some(text) = &self.state.edited {
+            return text.clone();
+        }
+        merged_text(
+            &self.segments,
+            &self.state.choices,
+            (&self.labels.0, &self.labels.1),
+            self.trailing_newline,
+        )
+    }
+
+    /// Settles the selected conflict and moves to the next open one.
+    pub(in crate::tui_shell) fn choose(&mut self, choice: ConflictChoice) -> Result<(), String> {
+        if self.state.edited.is_some() {
+            return Err("result was edited in $EDITOR; undo to return to hunk choices".to_string());
+        }
+        if self.conflicts() == 0 {
+            return Err("no conflicts".to_string());
+        }
+        let idx = self.conflict.min(self.conflicts() - 1);
+        let mut next = self.state.clone();
+        next.choices[idx] = Some(choice);
+        self.commit(next);
+        if let Some(open) = (idx + 1..self.conflicts())
+            .chain(0..idx)
+            .find(|&i| self.state.choices[i].is_none())
+        {
+            self.conflict = open;
+        }
+        Ok(())
+    }
+
+    /// Replaces the result with edited text; false when nothing changed.
+    pub(in crate::tui_shell) fn set_edited(&mut self, text: String) -> bool {
+        if text == self.result() {
+            return false;
+        }
+        let next = MergeState {
+            choices: self.state.choices.clone(),
+            edited: Some(text),
+        };
+        self.commit(next);
+        true
+    }
+
+    fn commit(&mut self, next: MergeState) {
+        let prev = std::mem::replace(&mut self.state, next);
+        self.undo.push(prev);
+        self.redo.clear();
+    }
+
+    pub(in crate::tui_shell) fn undo(&mut self) -> bool {
+        let Some(prev) = self.undo.pop() else {
+            return false;
+        };
+        let cur = std::mem::replace(&mut self.state, prev);
+        self.redo.push(cur);
+        true
+    }
+
+    pub(in crate::tui_shell) fn redo(&mut self) -> bool {
+        let Some(next) = self.redo.pop() else {
+            return false;
+        };
+        let cur = std::mem::replace(&mut self.state, next);
+        self.undo.push(cur);
+        true
+    }
+
+    /// Moves to the next (or previous) conflict, wrapping; false if there are none.
+    pub(in crate::tui_shell) fn jump_conflict(&mut self, forward: bool) -> bool {
+        let n = self.conflicts();
+        if n == 0 {
+            return false;
+        }
+        let cur = self.conflict.min(n - 1);
+        self.conflict = if forward {
+            (cur + 1) % n
+        } else {
+            (cur + n - 1) % n
+        };
+        true
+    }
+}
+
+#[cfg(test)]
+#[path = "../../../tests/tui_shell/views/merge/state_tests.rs"]
+mod tests;
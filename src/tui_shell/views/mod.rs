@@ -1,19 +1,27 @@
 pub(super) mod bundles;
+pub(super) mod diff;
+pub(super) mod files;
 pub(super) mod gate_graph;
 pub(super) mod inbox;
 pub(super) mod lanes;
+pub(super) mod merge;
 pub(super) mod releases;
 pub(super) mod root;
+pub(super) mod search;
 pub(super) mod settings;
 pub(super) mod snaps;
 pub(super) mod superpositions;
 
 pub(in crate::tui_shell) use bundles::BundlesView;
-pub(in crate::tui_shell) use gate_graph::GateGraphView;
+pub(in crate::tui_shell) use diff::{DiffLayout, DiffView};
+pub(in crate::tui_shell) use files::FilesView;
+pub(in crate::tui_shell) use gate_graph::{GateGraphView, GateHead, GateStatus};
 pub(in crate::tui_shell) use inbox::InboxView;
 pub(in crate::tui_shell) use lanes::{LaneHeadItem, LanesView};
+pub(in crate::tui_shell) use merge::MergeView;
 pub(in crate::tui_shell) use releases::ReleasesView;
 pub(in crate::tui_shell) use root::RootView;
+pub(in crate::tui_shell) use search::{SearchCandidate, SearchKind, SearchTarget, SearchView};
 pub(in crate::tui_shell) use settings::{SettingsItemKind, SettingsSnapshot, SettingsView};
 pub(in crate::tui_shell) use snaps::SnapsView;
 pub(in crate::tui_shell) use superpositions::SuperpositionsView;
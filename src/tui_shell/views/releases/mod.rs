@@ -1,9 +1,9 @@
 use std::any::Any;
 
-use ratatui::layout::{Constraint, Direction, Layout};
-use ratatui::style::{Color, Style};
+use ratatui::layout::Direction;
+use ratatui::style::Style;
 use ratatui::text::Line;
-use ratatui::widgets::{Block, Borders, List, ListItem, ListState, Paragraph, Wrap};
+use ratatui::widgets::{Block, Borders, List, ListItem};
 
 use super::super::{RenderCtx, UiMode, View, render_view_chrome};
 
@@ -51,16 +51,8 @@ impl View for ReleasesView {
     }
 
     fn render(&self, frame: &mut ratatui::Frame, area: ratatui::layout::Rect, ctx: &RenderCtx) {
-        let inner = render_view_chrome(frame, self.title(), self.updated_at(), area);
-        let parts = Layout::default()
-            .direction(Direction::Vertical)
-            .constraints([Constraint::Percentage(65), Constraint::Percentage(35)])
-            .split(inner);
-
-        let mut state = ListState::default();
-        if !self.items.is_empty() {
-            state.select(Some(self.selected.min(self.items.len().saturating_sub(1))));
-        }
+        let inner = render_view_chrome(frame, ctx, self.title(), self.updated_at(), area);
+        let parts = ctx.split_panes(inner, Direction::Vertical, 65);
 
         let mut rows: Vec<ListItem> = render::release_rows(&self.items, ctx)
             .into_iter()
@@ -70,19 +62,17 @@ impl View for ReleasesView {
             rows.push(ListItem::new("(empty)"));
         }
 
-        let list = List::new(rows)
-            .block(
-                Block::default()
-                    .borders(Borders::BOTTOM)
-                    .title("channels (Enter: fetch; /: commands)"),
-            )
-            .highlight_style(Style::default().bg(Color::DarkGray));
-        frame.render_stateful_widget(list, parts[0], &mut state);
+        let list = List::new(rows).highlight_style(Style::default().bg(ctx.theme.selected));
+        let block = Block::default()
+            .borders(Borders::BOTTOM)
+            .title("channels (Enter: fetch; /: commands)");
+        let selected = (!self.items.is_empty()).then_some(self.selected);
+        ctx.render_list(frame, list, block, parts[0], selected);
 
         let details: Vec<Line> = render::release_details(&self.items, self.selected)
             .into_iter()
             .map(Line::from)
             .collect();
-        frame.render_widget(Paragraph::new(details).wrap(Wrap { trim: false }), parts[1]);
+        ctx.render_details(frame, details, parts[1]);
     }
 }
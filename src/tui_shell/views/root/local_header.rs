@@ -1,11 +1,13 @@
-use ratatui::style::{Color, Style};
+use ratatui::style::Style;
 use ratatui::text::{Line, Span};
 
 use super::RootView;
+use crate::tui_shell::Theme;
 
 pub(super) fn local_header_and_baseline_line(
     view: &RootView,
     area_width: u16,
+    theme: &Theme,
 ) -> (Line<'static>, bool) {
     let title = "Status";
     let baseline = view.baseline_compact.as_deref().unwrap_or("");
@@ -20,15 +22,15 @@ pub(super) fn local_header_and_baseline_line(
         && (area_width as usize) >= (base_len + baseline_prefix.len() + baseline.len());
 
     let header = Line::from(vec![
-        Span::styled(title.to_string(), Style::default().fg(Color::Yellow)),
+        Span::styled(title.to_string(), Style::default().fg(theme.heading)),
         Span::raw("  "),
-        Span::styled(a, Style::default().fg(Color::Green)),
+        Span::styled(a, Style::default().fg(theme.added)),
         Span::raw(" "),
-        Span::styled(m, Style::default().fg(Color::Yellow)),
+        Span::styled(m, Style::default().fg(theme.modified)),
         Span::raw(" "),
-        Span::styled(d, Style::default().fg(Color::Red)),
+        Span::styled(d, Style::default().fg(theme.removed)),
         Span::raw(" "),
-        Span::styled(r, Style::default().fg(Color::Cyan)),
+        Span::styled(r, Style::default().fg(theme.accent)),
         Span::raw(if include_baseline {
             baseline_prefix
         } else {
@@ -40,7 +42,7 @@ pub(super) fn local_header_and_baseline_line(
             } else {
                 String::new()
             },
-            Style::default().fg(Color::White),
+            Style::default().fg(theme.text),
         ),
     ]);
 
@@ -34,10 +34,11 @@ impl View for RootView {
         }
     }
 
-    fn render(&self, frame: &mut ratatui::Frame, area: ratatui::layout::Rect, _ctx: &RenderCtx) {
+    fn render(&self, frame: &mut ratatui::Frame, area: ratatui::layout::Rect, ctx: &RenderCtx) {
         let (inner, include_baseline_line) = match self.ctx {
             RootContext::Local => {
-                let (header, keep_baseline_line) = local_header_and_baseline_line(self, area.width);
+                let (header, keep_baseline_line) =
+                    local_header_and_baseline_line(self, area.width, &ctx.theme);
                 (
                     render_view_chrome_with_header(frame, header, area),
                     keep_baseline_line,
@@ -47,12 +48,12 @@ impl View for RootView {
                 let header = Line::from(vec![
                     Span::styled(
                         self.title().to_string(),
-                        Style::default().fg(root_ctx_color(RootContext::Remote)),
+                        Style::default().fg(root_ctx_color(&ctx.theme, RootContext::Remote)),
                     ),
                     Span::raw("  "),
                     Span::styled(
                         fmt_ts_ui(self.updated_at()),
-                        Style::default().fg(Color::Gray),
+                        Style::default().fg(ctx.theme.muted),
                     ),
                 ]);
                 let inner = render_view_chrome_with_header(frame, header, area);
@@ -90,7 +91,7 @@ impl View for RootView {
             if !include_baseline_line && s.trim_start().starts_with("baseline:") {
                 continue;
             }
-            lines.push(style_root_line(s));
+            lines.push(style_root_line(s, &ctx.theme));
         }
         if lines.is_empty() {
             lines.push(Line::from(""));
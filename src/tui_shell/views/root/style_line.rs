@@ -1,7 +1,9 @@
-use ratatui::style::{Color, Style};
+use ratatui::style::Style;
 use ratatui::text::{Line, Span};
 
-pub(super) fn style_root_line(s: &str) -> Line<'static> {
+use crate::tui_shell::Theme;
+
+pub(super) fn style_root_line(s: &str, theme: &Theme) -> Line<'static> {
     // Style change lines like: "A path (+3 -1)", "R* old -> new (+1 -2)".
     let (main, delta) = if let Some((left, right)) = s.rsplit_once(" (")
         && right.ends_with(')')
@@ -28,10 +30,10 @@ pub(super) fn style_root_line(s: &str) -> Line<'static> {
 
     if !prefix.is_empty() {
         let style = match prefix {
-            "A" => Style::default().fg(Color::Green),
-            "D" => Style::default().fg(Color::Red),
-            "M" => Style::default().fg(Color::Yellow),
-            "R" | "R*" => Style::default().fg(Color::Cyan),
+            "A" => Style::default().fg(theme.added),
+            "D" => Style::default().fg(theme.removed),
+            "M" => Style::default().fg(theme.modified),
+            "R" | "R*" => Style::default().fg(theme.accent),
             _ => Style::default(),
         };
         spans.push(Span::styled(prefix.to_string(), style));
@@ -48,11 +50,11 @@ pub(super) fn style_root_line(s: &str) -> Line<'static> {
             }
             first = false;
             let style = if tok.starts_with('+') {
-                Style::default().fg(Color::Green)
+                Style::default().fg(theme.added)
             } else if tok.starts_with('-') {
-                Style::default().fg(Color::Red)
+                Style::default().fg(theme.removed)
             } else {
-                Style::default().fg(Color::Gray)
+                Style::default().fg(theme.muted)
             };
             spans.push(Span::styled(tok.to_string(), style));
         }
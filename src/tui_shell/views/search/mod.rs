@@ -0,0 +1,198 @@
+use std::any::Any;
+
+use ratatui::layout::Direction;
+use ratatui::style::{Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, List, ListItem};
+
+use super::super::suggest::highlight_positions;
+use super::super::{RenderCtx, UiMode, View, render_view_chrome};
+
+mod rank;
+
+pub(in crate::tui_shell) use rank::rank_hits;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub(in crate::tui_shell) enum SearchKind {
+    Snap,
+    Publication,
+    Bundle,
+    Release,
+    Lane,
+    User,
+}
+
+impl SearchKind {
+    pub(in crate::tui_shell) fn label(self) -> &'static str {
+        match self {
+            SearchKind::Snap => "snap",
+            SearchKind::Publication => "publication",
+            SearchKind::Bundle => "bundle",
+            SearchKind::Release => "release",
+            SearchKind::Lane => "lane",
+            SearchKind::User => "user",
+        }
+    }
+}
+
+/// Where opening a hit goes: the view to open and the item to select in it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(in crate::tui_shell) enum SearchTarget {
+    Snap {
+        id: String,
+    },
+    Publication {
+        scope: String,
+        gate: String,
+        id: String,
+    },
+    Bundle {
+        scope: String,
+        gate: String,
+        id: String,
+    },
+    Release {
+        channel: String,
+    },
+    Lane {
+        id: String,
+    },
+    User {
+        handle: String,
+    },
+}
+
+/// Something searchable, before ranking.
+#[derive(Clone, Debug)]
+pub(in crate::tui_shell) struct SearchCandidate {
+    pub(in crate::tui_shell) kind: SearchKind,
+    /// Full id, matched by prefix (hex ids would otherwise fuzzy-match almost anything).
+    pub(in crate::tui_shell) id: String,
+    /// The line shown in the results; matched fuzzily.
+    pub(in crate::tui_shell) text: String,
+    pub(in crate::tui_shell) detail: String,
+    pub(in crate::tui_shell) target: SearchTarget,
+}
+
+#[derive(Clone, Debug)]
+pub(in crate::tui_shell) struct SearchHit {
+    pub(in crate::tui_shell) candidate: SearchCandidate,
+    pub(in crate::tui_shell) score: i32,
+    /// Char indices of `candidate.text` that matched.
+    pub(in crate::tui_shell) positions: Vec<usize>,
+}
+
+#[derive(Debug)]
+pub(in crate::tui_shell) struct SearchView {
+    pub(in crate::tui_shell) updated_at: String,
+    pub(in crate::tui_shell) title: String,
+    pub(in crate::tui_shell) hits: Vec<SearchHit>,
+    pub(in crate::tui_shell) selected: usize,
+    /// Sources that could not be searched (no remote, request failed).
+    pub(in crate::tui_shell) notes: Vec<String>,
+}
+
+impl SearchView {
+    pub(in crate::tui_shell) fn new(
+        query: &str,
+        candidates: Vec<SearchCandidate>,
+        notes: Vec<String>,
+    ) -> Self {
+        Self {
+            updated_at: super::super::app::now_ts(),
+            title: format!("Search \"{}\"", query),
+            hits: rank_hits(query, candidates),
+            selected: 0,
+            notes,
+        }
+    }
+
+    pub(in crate::tui_shell) fn selected_hit(&self) -> Option<&SearchHit> {
+        self.hits
+            .get(self.selected.min(self.hits.len().saturating_sub(1)))
+    }
+}
+
+impl View for SearchView {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn mode(&self) -> UiMode {
+        UiMode::Search
+    }
+
+    fn title(&self) -> &str {
+        &self.title
+    }
+
+    fn updated_at(&self) -> &str {
+        &self.updated_at
+    }
+
+    fn move_up(&mut self) {
+        self.selected = self.selected.saturating_sub(1);
+    }
+
+    fn move_down(&mut self) {
+        let max = self.hits.len().saturating_sub(1);
+        self.selected = (self.selected + 1).min(max);
+    }
+
+    fn render(&self, frame: &mut ratatui::Frame, area: ratatui::layout::Rect, ctx: &RenderCtx) {
+        let inner = render_view_chrome(frame, ctx, self.title(), self.updated_at(), area);
+        let parts = ctx.split_panes(inner, Direction::Vertical, 75);
+
+        let rows = if self.hits.is_empty() {
+            vec![ListItem::new("(no matches)")]
+        } else {
+            self.hits
+                .iter()
+                .map(|h| {
+                    let mut spans = vec![Span::styled(
+                        format!("{:<12}", h.candidate.kind.label()),
+                        Style::default().fg(ctx.theme.muted),
+                    )];
+                    spans.extend(highlight_positions(
+                        &h.candidate.text,
+                        &h.positions,
+                        Style::default(),
+                        Style::default()
+                            .fg(ctx.theme.accent)
+                            .add_modifier(Modifier::BOLD),
+                    ));
+                    ListItem::new(Line::from(spans))
+                })
+                .collect()
+        };
+        let list = List::new(rows).highlight_style(Style::default().bg(ctx.theme.selected));
+        let block = Block::default()
+            .borders(Borders::BOTTOM)
+            .title(format!("{} matches (Enter: open)", self.hits.len()));
+        let selected = (!self.hits.is_empty()).then_some(self.selected);
+        ctx.render_list(frame, list, block, parts[0], selected);
+
+        let mut details = Vec::new();
+        if let Some(h) = self.selected_hit() {
+            details.push(Line::from(format!(
+                "{} {}",
+                h.candidate.kind.label(),
+                h.candidate.id
+            )));
+            if !h.candidate.detail.is_empty() {
+                details.push(Line::from(h.candidate.detail.clone()));
+            }
+        }
+        for n in &self.notes {
+            details.push(Line::from(Span::styled(
+                n.clone(),
+                Style::default().fg(ctx.theme.warning),
+            )));
+        }
+        ctx.render_details(frame, details, parts[1]);
+    }
+}
@@ -0,0 +1,75 @@
+use super::super::super::suggest::fuzzy_match;
+use super::{SearchCandidate, SearchHit};
+
+/// Ids need at least this many chars before a prefix match counts.
+const MIN_ID_PREFIX: usize = 4;
+const ID_PREFIX: i32 = 200;
+const MAX_HITS: usize = 200;
+
+/// Hits for `query`, best first. Every whitespace-separated term must fuzzy-match the text; a
+/// single term may instead be a prefix of the id.
+pub(in crate::tui_shell) fn rank_hits(
+    query: &str,
+    candidates: Vec<SearchCandidate>,
+) -> Vec<SearchHit> {
+    let terms = query.split_whitespace().collect::<Vec<_>>();
+    if terms.is_empty() {
+        return Vec::new();
+    }
+
+    let mut hits = candidates
+        .into_iter()
+        .filter_map(|c| {
+            let (score, positions) =
+                id_prefix_match(&terms, &c).or_else(|| text_match(&terms, &c))?;
+            Some(SearchHit {
+                candidate: c,
+                score,
+                positions,
+            })
+        })
+        .collect::<Vec<_>>();
+    hits.sort_by(|a, b| {
+        b.score
+            .cmp(&a.score)
+            .then_with(|| a.candidate.kind.cmp(&b.candidate.kind))
+            .then_with(|| a.candidate.text.cmp(&b.candidate.text))
+    });
+    hits.truncate(MAX_HITS);
+    hits
+}
+
+fn id_prefix_match(terms: &[&str], c: &SearchCandidate) -> Option<(i32, Vec<usize>)> {
+    let [term] = terms else {
+        return None;
+    };
+    let term = term.to_lowercase();
+    if term.chars().count() < MIN_ID_PREFIX || !c.id.to_lowercase().starts_with(&term) {
+        return None;
+    }
+    let shown = c
+        .text
+        .to_lowercase()
+        .chars()
+        .zip(term.chars())
+        .take_while(|(a, b)| a == b)
+        .count();
+    Some((ID_PREFIX + term.len() as i32, (0..shown).collect()))
+}
+
+fn text_match(terms: &[&str], c: &SearchCandidate) -> Option<(i32, Vec<usize>)> {
+    let mut score = 0;
+    let mut positions = Vec::new();
+    for t in terms {
+        let (s, p) = fuzzy_match(t, &c.text)?;
+        score += s;
+        positions.extend(p);
+    }
+    positions.sort_unstable();
+    positions.dedup();
+    Some((score, positions))
+}
+
+#[cfg(test)]
+#[path = "../../../tests/tui_shell/views/search/rank_tests.rs"]
+mod tests;
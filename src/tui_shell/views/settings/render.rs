@@ -1,6 +1,6 @@
-use ratatui::layout::{Constraint, Direction, Layout};
-use ratatui::style::{Color, Style};
-use ratatui::widgets::{Block, Borders, List, ListState, Paragraph, Wrap};
+use ratatui::layout::Direction;
+use ratatui::style::Style;
+use ratatui::widgets::{Block, Borders, List};
 
 use super::details::detail_lines;
 use super::list_rows::list_rows;
@@ -14,28 +14,21 @@ pub(super) fn render(
     area: ratatui::layout::Rect,
     ctx: &RenderCtx,
 ) {
-    let inner = render_view_chrome(frame, "Settings", &view.updated_at, area);
-    let parts = Layout::default()
-        .direction(Direction::Vertical)
-        .constraints([Constraint::Percentage(65), Constraint::Percentage(35)])
-        .split(inner);
+    let inner = render_view_chrome(frame, ctx, "Settings", &view.updated_at, area);
+    let parts = ctx.split_panes(inner, Direction::Vertical, 65);
 
-    let mut state = ListState::default();
-    if !view.items.is_empty() {
-        state.select(Some(view.selected.min(view.items.len().saturating_sub(1))));
-    }
-
-    let list = List::new(list_rows(view, ctx))
-        .block(
-            Block::default()
-                .borders(Borders::BOTTOM)
-                .title("(Enter: do it; /: commands)"),
-        )
-        .highlight_style(Style::default().bg(Color::DarkGray));
-    frame.render_stateful_widget(list, parts[0], &mut state);
-
-    frame.render_widget(
-        Paragraph::new(detail_lines(view, ctx)).wrap(Wrap { trim: false }),
-        parts[1],
+    let list =
+        List::new(list_rows(view, ctx)).highlight_style(Style::default().bg(ctx.theme.selected));
+    let block = Block::default()
+        .borders(Borders::BOTTOM)
+        .title("(Enter: do it; /: commands)");
+    ctx.render_list(
+        frame,
+        list,
+        block,
+        parts[0],
+        (!view.items.is_empty()).then_some(view.selected),
     );
+
+    ctx.render_details(frame, detail_lines(view, ctx), parts[1]);
 }
@@ -1,8 +1,8 @@
 use std::any::Any;
 
-use ratatui::layout::{Constraint, Direction, Layout};
-use ratatui::style::{Color, Modifier, Style};
-use ratatui::widgets::{Block, Borders, List, ListState, Paragraph, Wrap};
+use ratatui::layout::Direction;
+use ratatui::style::{Modifier, Style};
+use ratatui::widgets::{Block, Borders, List};
 
 use super::super::super::{RenderCtx, UiMode, View, render_view_chrome};
 use super::SnapsView;
@@ -45,38 +45,32 @@ impl View for SnapsView {
     }
 
     fn render(&self, frame: &mut ratatui::Frame, area: ratatui::layout::Rect, ctx: &RenderCtx) {
-        let inner = render_view_chrome(frame, self.title(), self.updated_at(), area);
-        let parts = Layout::default()
-            .direction(Direction::Vertical)
-            .constraints([Constraint::Percentage(65), Constraint::Percentage(35)])
-            .split(inner);
-
-        let mut state = ListState::default();
-        let n_rows = self.rows_len();
-        if n_rows > 0 {
-            state.select(Some(self.selected_row.min(n_rows - 1)));
-        }
+        let inner = render_view_chrome(frame, ctx, self.title(), self.updated_at(), area);
+        let parts = ctx.split_panes(inner, Direction::Vertical, 65);
 
         let list = List::new(list_rows(self, ctx))
-            .block(Block::default().borders(Borders::BOTTOM).title(format!(
-                "snaps{} (/: commands)",
-                self.filter
-                    .as_ref()
-                    .map(|f| format!(" filter={}", f))
-                    .unwrap_or_default()
-            )))
-            .highlight_style(Style::default().bg(Color::DarkGray));
-        frame.render_stateful_widget(list, parts[0], &mut state);
-
-        frame.render_widget(
-            Paragraph::new(details_lines(self, ctx)).wrap(Wrap { trim: false }),
-            parts[1],
+            .highlight_style(Style::default().bg(ctx.theme.selected));
+        let block = Block::default().borders(Borders::BOTTOM).title(format!(
+            "snaps{} (/: commands)",
+            self.filter
+                .as_ref()
+                .map(|f| format!(" filter={}", f))
+                .unwrap_or_default()
+        ));
+        ctx.render_list(
+            frame,
+            list,
+            block,
+            parts[0],
+            (self.rows_len() > 0).then_some(self.selected_row),
         );
+
+        ctx.render_details(frame, details_lines(self, ctx), parts[1]);
     }
 }
 
-pub(super) fn head_style() -> Style {
+pub(super) fn head_style(ctx: &RenderCtx) -> Style {
     Style::default()
-        .fg(Color::Green)
+        .fg(ctx.theme.ok)
         .add_modifier(Modifier::BOLD)
 }
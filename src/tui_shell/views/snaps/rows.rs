@@ -13,9 +13,9 @@ pub(super) fn list_rows(view: &SnapsView, ctx: &RenderCtx) -> Vec<ListItem<'stat
         let sum = view.pending_changes.unwrap_or_default();
         let total = sum.total();
         let label = if total == 1 { "change" } else { "changes" };
-        rows.push(ListItem::new(format!("> {} {}", total, label)).style(head_style()));
+        rows.push(ListItem::new(format!("> {} {}", total, label)).style(head_style(ctx)));
     } else if view.has_clean_row() {
-        rows.push(ListItem::new("> clean").style(head_style()));
+        rows.push(ListItem::new("> clean").style(head_style(ctx)));
     }
 
     for snap in &view.items {
@@ -24,7 +24,7 @@ pub(super) fn list_rows(view: &SnapsView, ctx: &RenderCtx) -> Vec<ListItem<'stat
         let msg = snap.message.clone().unwrap_or_default();
         let marker = if is_head { "*" } else { " " };
         let id_style = if is_head && !has_pending {
-            head_style()
+            head_style(ctx)
         } else {
             Style::default()
         };
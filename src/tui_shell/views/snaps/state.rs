@@ -51,4 +51,13 @@ impl SnapsView {
             None
         }
     }
+
+    /// Selects the row of snap `id`; false if it is not listed.
+    pub(in crate::tui_shell) fn select_snap(&mut self, id: &str) -> bool {
+        let Some(idx) = self.items.iter().position(|s| s.id == id) else {
+            return false;
+        };
+        self.selected_row = if self.has_header_row() { idx + 1 } else { idx };
+        true
+    }
 }
@@ -55,12 +55,37 @@ pub(super) fn detail_lines(view: &SuperpositionsView) -> Vec<Line<'static>> {
         Some(ResolutionDecision::Index(i)) => {
             out.push(Line::from(format!("decision: index {}", i)))
         }
+        Some(ResolutionDecision::Key(key)) if key.is_manual() => {
+            if let crate::model::VariantKeyKind::File { blob, size, .. } = &key.kind {
+                out.push(Line::from(format!(
+                    "decision: manual merge blob={} ({} bytes)",
+                    blob.as_str().chars().take(8).collect::<String>(),
+                    size
+                )));
+            }
+        }
         Some(ResolutionDecision::Key(key)) => {
             let key_json = serde_json::to_string(key).unwrap_or_else(|_| "<key>".to_string());
             out.push(Line::from(format!("decision: key {}", key_json)));
         }
     }
 
+    if let Some(record) = view.records.get(path) {
+        let mut line = format!("server: {}", record.status.as_str());
+        if let Some(resolver) = &record.resolver {
+            line.push_str(&format!(" by {}", resolver));
+        }
+        match (record.method, &record.rule) {
+            (_, Some(rule)) => line.push_str(&format!(" (rule {})", rule)),
+            (Some(method), None) => line.push_str(&format!(" ({})", method.as_str())),
+            (None, None) => {}
+        }
+        out.push(Line::from(line));
+        if let Some(rationale) = &record.rationale {
+            out.push(Line::from(format!("rationale: {}", rationale)));
+        }
+    }
+
     if let Some(variants) = view.variants.get(path) {
         out.push(Line::from(""));
         out.push(Line::from("variants:"));
@@ -73,6 +98,33 @@ pub(super) fn detail_lines(view: &SuperpositionsView) -> Vec<Line<'static>> {
                 variant.source
             )));
             out.push(Line::from(format!("    key={}", key_json)));
+            if let Some(meta) = &variant.metadata {
+                let mut by = Vec::new();
+                if let Some(publisher) = &meta.publisher {
+                    by.push(format!("by {}", publisher));
+                }
+                if let Some(lane) = &meta.lane {
+                    by.push(format!("lane {}", lane));
+                }
+                if let Some(at) = &meta.published_at {
+                    by.push(format!("at {}", at));
+                }
+                if !by.is_empty() {
+                    out.push(Line::from(format!("    {}", by.join(" "))));
+                }
+                if let Some(message) = &meta.snap_message {
+                    out.push(Line::from(format!("    message: {}", message)));
+                }
+            }
+            if let Some(meta) = &variant.metadata
+                && let Some(driver) = &meta.merge_driver
+            {
+                out.push(Line::from(format!(
+                    "    {} keys: {}",
+                    driver,
+                    meta.conflicting_keys.join(", ")
+                )));
+            }
             match &variant.kind {
                 SuperpositionVariantKind::File { blob, mode, size } => {
                     out.push(Line::from(format!(
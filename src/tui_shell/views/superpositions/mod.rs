@@ -1,8 +1,8 @@
 use std::any::Any;
 
-use ratatui::layout::{Constraint, Direction, Layout};
-use ratatui::style::{Color, Style};
-use ratatui::widgets::{Block, Borders, List, ListState, Paragraph, Wrap};
+use ratatui::layout::Direction;
+use ratatui::style::Style;
+use ratatui::widgets::{Block, Borders, List};
 
 use crate::model::{ObjectId, ResolutionDecision};
 use crate::resolve::ResolutionValidation;
@@ -24,6 +24,8 @@ pub(in crate::tui_shell) struct SuperpositionsView {
     pub(in crate::tui_shell) variants:
         std::collections::BTreeMap<String, Vec<crate::model::SuperpositionVariant>>,
     pub(in crate::tui_shell) decisions: std::collections::BTreeMap<String, ResolutionDecision>,
+    pub(in crate::tui_shell) records:
+        std::collections::BTreeMap<String, crate::remote::ResolutionRecord>,
     pub(in crate::tui_shell) validation: Option<ResolutionValidation>,
     pub(in crate::tui_shell) items: Vec<(String, usize)>,
     pub(in crate::tui_shell) selected: usize,
@@ -63,43 +65,33 @@ impl View for SuperpositionsView {
         self.selected = (self.selected + 1).min(max);
     }
 
-    fn render(&self, frame: &mut ratatui::Frame, area: ratatui::layout::Rect, _ctx: &RenderCtx) {
-        let inner = render_view_chrome(frame, self.title(), self.updated_at(), area);
-        let parts = Layout::default()
-            .direction(Direction::Vertical)
-            .constraints([Constraint::Percentage(65), Constraint::Percentage(35)])
-            .split(inner);
-
-        let mut state = ListState::default();
-        if !self.items.is_empty() {
-            state.select(Some(self.selected.min(self.items.len().saturating_sub(1))));
-        }
-
-        let list = List::new(list_rows(self))
-            .block(Block::default().borders(Borders::BOTTOM).title(format!(
-                "bundle={}{}{} (pick; Alt+1..9, Alt+0; / for commands)",
-                self.bundle_id.chars().take(8).collect::<String>(),
-                self.filter
-                    .as_ref()
-                    .map(|f| format!(" filter={}", f))
-                    .unwrap_or_default(),
-                self.validation
-                    .as_ref()
-                    .map(|v| {
-                        format!(
-                            " missing={} invalid={}",
-                            v.missing.len(),
-                            v.invalid_keys.len() + v.out_of_range.len()
-                        )
-                    })
-                    .unwrap_or_default()
-            )))
-            .highlight_style(Style::default().bg(Color::DarkGray));
-        frame.render_stateful_widget(list, parts[0], &mut state);
-
-        frame.render_widget(
-            Paragraph::new(detail_lines(self)).wrap(Wrap { trim: false }),
-            parts[1],
-        );
+    fn render(&self, frame: &mut ratatui::Frame, area: ratatui::layout::Rect, ctx: &RenderCtx) {
+        let inner = render_view_chrome(frame, ctx, self.title(), self.updated_at(), area);
+        let parts = ctx.split_panes(inner, Direction::Vertical, 65);
+
+        let list =
+            List::new(list_rows(self)).highlight_style(Style::default().bg(ctx.theme.selected));
+        let block = Block::default().borders(Borders::BOTTOM).title(format!(
+            "bundle={}{}{} (pick; Alt+1..9, Alt+0; / for commands)",
+            self.bundle_id.chars().take(8).collect::<String>(),
+            self.filter
+                .as_ref()
+                .map(|f| format!(" filter={}", f))
+                .unwrap_or_default(),
+            self.validation
+                .as_ref()
+                .map(|v| {
+                    format!(
+                        " missing={} invalid={}",
+                        v.missing.len(),
+                        v.invalid_keys.len() + v.out_of_range.len()
+                    )
+                })
+                .unwrap_or_default()
+        ));
+        let selected = (!self.items.is_empty()).then_some(self.selected);
+        ctx.render_list(frame, list, block, parts[0], selected);
+
+        ctx.render_details(frame, detail_lines(self), parts[1]);
     }
 }
@@ -17,6 +17,7 @@ pub(super) fn list_rows(view: &SuperpositionsView) -> Vec<ListItem<'static>> {
                     "*".to_string()
                 }
             }
+            Some(ResolutionDecision::Key(key)) if key.is_manual() => "m".to_string(),
             Some(ResolutionDecision::Key(key)) => {
                 let idx = view
                     .variants
@@ -29,9 +30,16 @@ pub(super) fn list_rows(view: &SuperpositionsView) -> Vec<ListItem<'static>> {
                 }
             }
         };
+        let reopened = view
+            .records
+            .get(path)
+            .is_some_and(|r| r.status == crate::remote::ResolutionStatus::Reopened);
         rows.push(ListItem::new(format!(
-            "[{}] {} ({})",
-            mark, path, variants_count
+            "[{}] {} ({}){}",
+            mark,
+            path,
+            variants_count,
+            if reopened { " reopened" } else { "" }
         )));
     }
     if rows.is_empty() {
@@ -13,9 +13,9 @@ mod publish_sync_flow;
 mod release_ops_flow;
 mod types;
 pub(in crate::tui_shell) use self::types::{
-    BootstrapWizard, BrowseTarget, BrowseWizard, FetchWizard, LaneMemberWizard, LoginWizard,
-    MemberAction, MemberWizard, MoveWizard, PinWizard, PromoteWizard, PublishWizard, ReleaseWizard,
-    SyncWizard,
+    BootstrapWizard, BrowseTarget, BrowseWizard, FetchWizard, LaneMemberWizard, LaneWizard,
+    LoginWizard, MemberAction, MemberTarget, MemberWizard, MoveWizard, PinWizard, PromoteWizard,
+    PublishWizard, ReleaseWizard, SyncWizard,
 };
 
 impl super::App {
@@ -30,6 +30,7 @@ impl super::App {
         self.promote_wizard = None;
         self.member_wizard = None;
         self.lane_member_wizard = None;
+        self.lane_wizard = None;
         self.browse_wizard = None;
         self.move_wizard = None;
     }
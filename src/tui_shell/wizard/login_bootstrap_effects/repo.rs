@@ -5,7 +5,7 @@ pub(super) fn ensure_repo_exists(app: &mut super::super::super::App, repo_id: &s
             Ok(_) => {
                 app.push_output(vec![format!("repo {} exists", repo_id)]);
             }
-            Err(err) if err.to_string().contains("remote repo not found") => {
+            Err(err) if err.downcast_ref::<crate::remote::NotFound>().is_some() => {
                 match client.create_repo(repo_id) {
                     Ok(_) => app.push_output(vec![format!("created repo {}", repo_id)]),
                     Err(err) => app.push_error(format!("create repo: {:#}", err)),
@@ -0,0 +1,30 @@
+use crate::tui_shell::App;
+
+pub(super) fn finish_lane_wizard(app: &mut App, default_scope: Option<String>) {
+    let Some(w) = app.lane_wizard.take() else {
+        app.push_error("lane wizard not active".to_string());
+        return;
+    };
+
+    let client = match app.remote_client() {
+        Some(c) => c,
+        None => return,
+    };
+    let Some(id) = w.id else {
+        app.push_error("lane: missing id".to_string());
+        return;
+    };
+
+    match client.create_lane(
+        &id,
+        w.description.as_deref(),
+        &w.gates,
+        default_scope.as_deref(),
+    ) {
+        Ok(lane) => {
+            app.push_output(vec![format!("created lane {}", lane.id)]);
+            app.refresh_root_view();
+        }
+        Err(err) => app.push_error(format!("lane create: {:#}", err)),
+    }
+}
@@ -0,0 +1,47 @@
+use super::*;
+use crate::tui_shell::App;
+
+mod finish;
+mod prompts;
+mod transitions;
+
+impl App {
+    pub(in crate::tui_shell) fn start_lane_wizard(&mut self, id: Option<String>) {
+        if self.remote_client().is_none() {
+            self.start_login_wizard();
+            return;
+        }
+
+        let have_id = id.is_some();
+        self.lane_wizard = Some(LaneWizard {
+            id,
+            description: None,
+            gates: Vec::new(),
+        });
+
+        if have_id {
+            prompts::open_lane_description_prompt(self);
+        } else {
+            prompts::open_lane_id_prompt(self, None);
+        }
+    }
+
+    pub(in crate::tui_shell) fn continue_lane_wizard(
+        &mut self,
+        action: TextInputAction,
+        value: String,
+    ) {
+        if self.lane_wizard.is_none() {
+            self.push_error("lane wizard not active".to_string());
+            return;
+        }
+
+        match action {
+            TextInputAction::LaneCreateId => transitions::on_lane_id(self, value),
+            TextInputAction::LaneCreateDescription => transitions::on_lane_description(self, value),
+            TextInputAction::LaneCreateGates => transitions::on_lane_gates(self, value),
+            TextInputAction::LaneCreateScope => transitions::on_lane_scope(self, value),
+            _ => self.push_error("unexpected lane wizard input".to_string()),
+        }
+    }
+}
@@ -0,0 +1,47 @@
+use crate::tui_shell::{App, TextInputAction};
+
+pub(super) fn open_lane_id_prompt(app: &mut App, error: Option<String>) {
+    let mut lines = Vec::new();
+    if let Some(e) = error {
+        lines.push(e);
+    } else {
+        lines.push("New lane id".to_string());
+    }
+    app.open_text_input_modal(
+        "Create Lane",
+        "lane> ",
+        TextInputAction::LaneCreateId,
+        None,
+        lines,
+    );
+}
+
+pub(super) fn open_lane_description_prompt(app: &mut App) {
+    app.open_text_input_modal(
+        "Create Lane",
+        "description> ",
+        TextInputAction::LaneCreateDescription,
+        None,
+        vec!["What the lane is for (optional)".to_string()],
+    );
+}
+
+pub(super) fn open_lane_gates_prompt(app: &mut App) {
+    app.open_text_input_modal(
+        "Create Lane",
+        "gates> ",
+        TextInputAction::LaneCreateGates,
+        None,
+        vec!["Gates this lane feeds, comma separated (optional)".to_string()],
+    );
+}
+
+pub(super) fn open_lane_scope_prompt(app: &mut App) {
+    app.open_text_input_modal(
+        "Create Lane",
+        "default scope> ",
+        TextInputAction::LaneCreateScope,
+        None,
+        vec!["Scope new work defaults to (optional)".to_string()],
+    );
+}
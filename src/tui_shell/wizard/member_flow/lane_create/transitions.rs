@@ -0,0 +1,38 @@
+use super::prompts;
+use crate::tui_shell::App;
+
+pub(super) fn on_lane_id(app: &mut App, value: String) {
+    let id = value.trim().to_string();
+    if id.is_empty() {
+        prompts::open_lane_id_prompt(app, Some("error: value required".to_string()));
+        return;
+    }
+    if let Some(w) = app.lane_wizard.as_mut() {
+        w.id = Some(id);
+    }
+    prompts::open_lane_description_prompt(app);
+}
+
+pub(super) fn on_lane_description(app: &mut App, value: String) {
+    let description = value.trim().to_string();
+    if let Some(w) = app.lane_wizard.as_mut() {
+        w.description = (!description.is_empty()).then_some(description);
+    }
+    prompts::open_lane_gates_prompt(app);
+}
+
+pub(super) fn on_lane_gates(app: &mut App, value: String) {
+    if let Some(w) = app.lane_wizard.as_mut() {
+        w.gates = value
+            .split(',')
+            .map(|g| g.trim().to_string())
+            .filter(|g| !g.is_empty())
+            .collect();
+    }
+    prompts::open_lane_scope_prompt(app);
+}
+
+pub(super) fn on_lane_scope(app: &mut App, value: String) {
+    let scope = value.trim().to_string();
+    super::finish::finish_lane_wizard(app, (!scope.is_empty()).then_some(scope));
+}
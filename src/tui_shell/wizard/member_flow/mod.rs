@@ -1,5 +1,6 @@
 use super::super::TextInputAction;
-use super::types::{LaneMemberWizard, MemberAction, MemberWizard};
+use super::types::{LaneMemberWizard, LaneWizard, MemberAction, MemberWizard};
 
+mod lane_create;
 mod lane_member;
 mod repo_member;
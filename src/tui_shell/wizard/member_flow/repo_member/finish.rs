@@ -1,4 +1,5 @@
 use crate::tui_shell::App;
+use crate::tui_shell::wizard::MemberTarget;
 
 use super::MemberAction;
 
@@ -23,13 +24,24 @@ pub(super) fn finish_member_wizard(app: &mut App) {
     };
 
     match action {
-        MemberAction::Add => match client.add_repo_member(&handle, &w.role) {
-            Ok(()) => {
-                app.push_output(vec![format!("added {} ({})", handle, w.role)]);
-                app.refresh_root_view();
+        MemberAction::Add => {
+            match client.add_repo_member(&handle, &w.role, w.gate.as_deref(), w.lane.as_deref()) {
+                Ok(()) => {
+                    let target = MemberTarget {
+                        gate: w.gate.clone(),
+                        lane: w.lane.clone(),
+                    };
+                    app.push_output(vec![format!(
+                        "added {} ({}{})",
+                        handle,
+                        w.role,
+                        target.describe()
+                    )]);
+                    app.refresh_root_view();
+                }
+                Err(err) => app.push_error(format!("member add: {:#}", err)),
             }
-            Err(err) => app.push_error(format!("member add: {:#}", err)),
-        },
+        }
         MemberAction::Remove => match client.remove_repo_member(&handle) {
             Ok(()) => {
                 app.push_output(vec![format!("removed {}", handle)]);
@@ -16,6 +16,8 @@ impl App {
             action,
             handle: None,
             role: "read".to_string(),
+            gate: None,
+            lane: None,
         });
 
         match action {
@@ -38,6 +40,7 @@ impl App {
             TextInputAction::MemberAction => transitions::on_member_action(self, value),
             TextInputAction::MemberHandle => transitions::on_member_handle(self, value),
             TextInputAction::MemberRole => transitions::on_member_role(self, value),
+            TextInputAction::MemberTarget => transitions::on_member_target(self, value),
             _ => self.push_error("unexpected member wizard input".to_string()),
         }
     }
@@ -42,12 +42,34 @@ pub(super) fn open_member_role_prompt(
         lines.push(e);
     } else {
         lines.push("Default: read".to_string());
+        lines.push(format!(
+            "legacy: {}",
+            crate::remote::LEGACY_REPO_ROLES.join(" | ")
+        ));
+        lines.push(format!("roles: {}", crate::remote::REPO_ROLES.join(" | ")));
     }
     app.open_text_input_modal(
         "Member",
-        "role (read/publish)> ",
+        "role> ",
         TextInputAction::MemberRole,
         initial.or_else(|| Some("read".to_string())),
         lines,
     );
 }
+
+pub(super) fn open_member_target_prompt(app: &mut App, error: Option<String>) {
+    let mut lines = Vec::new();
+    if let Some(e) = error {
+        lines.push(e);
+    } else {
+        lines.push("Blank for the whole repo".to_string());
+        lines.push("gate:<id> | lane:<id> to narrow the role".to_string());
+    }
+    app.open_text_input_modal(
+        "Member",
+        "where> ",
+        TextInputAction::MemberTarget,
+        None,
+        lines,
+    );
+}
@@ -1,6 +1,7 @@
 use super::MemberAction;
 use super::prompts;
 use crate::tui_shell::App;
+use crate::tui_shell::wizard::MemberTarget;
 
 pub(super) fn on_member_action(app: &mut App, value: String) {
     let Some(act) = parse_member_action(&value) else {
@@ -37,18 +38,35 @@ pub(super) fn on_member_handle(app: &mut App, value: String) {
 
 pub(super) fn on_member_role(app: &mut App, value: String) {
     let role = normalize_role(&value);
-    if role != "read" && role != "publish" {
-        prompts::open_member_role_prompt(
-            app,
-            Some(role),
-            Some("error: role must be read or publish".to_string()),
-        );
+    let legacy = crate::remote::LEGACY_REPO_ROLES.contains(&role.as_str());
+    if !legacy && !crate::remote::REPO_ROLES.contains(&role.as_str()) {
+        prompts::open_member_role_prompt(app, Some(role), Some("error: unknown role".to_string()));
         return;
     }
 
     if let Some(w) = app.member_wizard.as_mut() {
         w.role = role;
     }
+    if legacy {
+        app.finish_member_wizard();
+    } else {
+        prompts::open_member_target_prompt(app, None);
+    }
+}
+
+pub(super) fn on_member_target(app: &mut App, value: String) {
+    let Some(target) = MemberTarget::parse(&value) else {
+        prompts::open_member_target_prompt(
+            app,
+            Some("error: use gate:<id>, lane:<id>, or leave blank".to_string()),
+        );
+        return;
+    };
+
+    if let Some(w) = app.member_wizard.as_mut() {
+        w.gate = target.gate;
+        w.lane = target.lane;
+    }
     app.finish_member_wizard();
 }
 
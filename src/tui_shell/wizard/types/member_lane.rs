@@ -9,6 +9,56 @@ pub(in crate::tui_shell) struct MemberWizard {
     pub(in crate::tui_shell) action: Option<MemberAction>,
     pub(in crate::tui_shell) handle: Option<String>,
     pub(in crate::tui_shell) role: String,
+    pub(in crate::tui_shell) gate: Option<String>,
+    pub(in crate::tui_shell) lane: Option<String>,
+}
+
+/// A role grant target: repo-wide, or narrowed to one gate or lane.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub(in crate::tui_shell) struct MemberTarget {
+    pub(in crate::tui_shell) gate: Option<String>,
+    pub(in crate::tui_shell) lane: Option<String>,
+}
+
+impl MemberTarget {
+    /// Parses `gate:<id>` or `lane:<id>`; blank means repo-wide.
+    pub(in crate::tui_shell) fn parse(value: &str) -> Option<Self> {
+        let value = value.trim();
+        if value.is_empty() || value == "repo" {
+            return Some(Self::default());
+        }
+        let (kind, id) = value.split_once(':')?;
+        let id = id.trim();
+        if id.is_empty() {
+            return None;
+        }
+        match kind.trim() {
+            "gate" => Some(Self {
+                gate: Some(id.to_string()),
+                lane: None,
+            }),
+            "lane" => Some(Self {
+                gate: None,
+                lane: Some(id.to_string()),
+            }),
+            _ => None,
+        }
+    }
+
+    pub(in crate::tui_shell) fn describe(&self) -> String {
+        match (&self.gate, &self.lane) {
+            (Some(g), _) => format!(" at gate {}", g),
+            (None, Some(l)) => format!(" in lane {}", l),
+            (None, None) => String::new(),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub(in crate::tui_shell) struct LaneWizard {
+    pub(in crate::tui_shell) id: Option<String>,
+    pub(in crate::tui_shell) description: Option<String>,
+    pub(in crate::tui_shell) gates: Vec<String>,
 }
 
 #[derive(Clone, Debug)]
@@ -7,7 +7,9 @@ pub(in crate::tui_shell) use self::admin::{BootstrapWizard, LoginWizard};
 pub(in crate::tui_shell) use self::fetch_release::{
     FetchKind, FetchWizard, PinWizard, PromoteWizard, ReleaseWizard,
 };
-pub(in crate::tui_shell) use self::member_lane::{LaneMemberWizard, MemberAction, MemberWizard};
+pub(in crate::tui_shell) use self::member_lane::{
+    LaneMemberWizard, LaneWizard, MemberAction, MemberTarget, MemberWizard,
+};
 pub(in crate::tui_shell) use self::publish_browse_move::{
     BrowseTarget, BrowseWizard, MoveWizard, PublishWizard, SyncWizard,
 };
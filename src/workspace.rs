@@ -7,9 +7,12 @@ use anyhow::{Context, Result, anyhow};
 use crate::model::{ObjectId, SnapStats};
 use crate::store::LocalStore;
 
+mod attachments;
 mod chunk_io;
 mod chunking;
 mod gc;
+mod git_export;
+mod git_import;
 mod manifest_query;
 mod manifest_scan;
 mod materialize_fs;
@@ -17,6 +20,11 @@ mod path_ops;
 mod restore_materialize;
 mod root_lifecycle;
 mod snap_ops;
+mod tree_files;
+
+pub use self::attachments::StoredFile;
+pub use self::git_export::{GitExport, GitExportCommit};
+pub use self::git_import::{GitImport, GitImportOptions, ImportedCommit};
 
 #[derive(Clone)]
 pub struct Workspace {
@@ -0,0 +1,80 @@
+//! Standalone files (release attachments) stored outside any manifest.
+
+use std::fs;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+
+use anyhow::{Context, Result};
+
+use crate::model::ObjectId;
+
+use super::Workspace;
+use super::chunk_io::chunk_file_to_recipe_store;
+use super::chunking::chunking_policy_from_config;
+
+/// Where a stored file's content lives: one blob, or a chunk recipe for large files.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StoredFile {
+    pub size: u64,
+    pub blob: Option<ObjectId>,
+    pub recipe: Option<ObjectId>,
+}
+
+impl Workspace {
+    /// Copies a file into the object store, chunked by the same policy as snaps.
+    pub fn store_file(&self, path: &Path) -> Result<StoredFile> {
+        let cfg = self.store.read_config()?;
+        let policy = chunking_policy_from_config(cfg.chunking.as_ref())?;
+        let size = fs::metadata(path)
+            .with_context(|| format!("stat {}", path.display()))?
+            .len();
+
+        if size >= policy.threshold {
+            let recipe = chunk_file_to_recipe_store(&self.store, path, size, policy.chunk_size)?;
+            return Ok(StoredFile {
+                size,
+                blob: None,
+                recipe: Some(recipe),
+            });
+        }
+        let bytes = fs::read(path).with_context(|| format!("read {}", path.display()))?;
+        Ok(StoredFile {
+            size,
+            blob: Some(self.store.put_blob(&bytes)?),
+            recipe: None,
+        })
+    }
+
+    /// Writes stored content to `dest`; objects must already be in the local store.
+    pub fn write_stored_file(&self, file: &StoredFile, dest: &Path) -> Result<()> {
+        let f = fs::File::create(dest).with_context(|| format!("create {}", dest.display()))?;
+        let mut w = BufWriter::new(f);
+        let mut written: u64 = 0;
+        match (&file.blob, &file.recipe) {
+            (Some(blob), _) => {
+                let bytes = self.store.get_blob(blob)?;
+                written += bytes.len() as u64;
+                w.write_all(&bytes)?;
+            }
+            (None, Some(recipe)) => {
+                for chunk in self.store.get_recipe(recipe)?.chunks {
+                    let bytes = self.store.get_blob(&chunk.blob)?;
+                    written += bytes.len() as u64;
+                    w.write_all(&bytes)?;
+                }
+            }
+            (None, None) => anyhow::bail!("stored file has no content reference"),
+        }
+        w.flush()
+            .with_context(|| format!("write {}", dest.display()))?;
+        if written != file.size {
+            anyhow::bail!(
+                "size mismatch writing {} (expected {}, got {})",
+                dest.display(),
+                file.size,
+                written
+            );
+        }
+        Ok(())
+    }
+}
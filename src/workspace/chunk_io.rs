@@ -95,3 +95,25 @@ pub(super) fn chunk_file_to_recipe_id(
     let bytes = serde_json::to_vec(&recipe).context("serialize recipe")?;
     Ok(hash_bytes(&bytes))
 }
+
+pub(super) fn chunk_bytes_to_recipe_store(
+    store: &LocalStore,
+    bytes: &[u8],
+    chunk_size: usize,
+) -> Result<ObjectId> {
+    let mut chunks = Vec::new();
+    for chunk in bytes.chunks(chunk_size) {
+        let blob = store.put_blob(chunk)?;
+        chunks.push(FileRecipeChunk {
+            blob,
+            size: chunk.len() as u32,
+        });
+    }
+
+    let recipe = FileRecipe {
+        version: 1,
+        size: bytes.len() as u64,
+        chunks,
+    };
+    store.put_recipe(&recipe)
+}
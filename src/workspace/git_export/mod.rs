@@ -0,0 +1,168 @@
+//! Export a manifest tree as a Git commit by feeding `git fast-import`.
+
+use std::io::Write;
+use std::path::Path;
+use std::process::{Command as Process, Stdio};
+
+use anyhow::{Context, Result};
+
+use crate::model::ObjectId;
+
+use super::Workspace;
+use super::git_import::git_output as git;
+use super::tree_files::{TreeFile, walk_tree_files};
+
+#[derive(Clone, Debug)]
+pub struct GitExportCommit {
+    /// Branch to commit to (without `refs/heads/`); an existing tip becomes the parent.
+    pub branch: String,
+    /// Committer/author name; the email is left empty.
+    pub committer: String,
+    pub unix_time: i64,
+    pub message: String,
+}
+
+#[derive(Clone, Debug, serde::Serialize)]
+pub struct GitExport {
+    pub branch: String,
+    pub commit: String,
+    pub parent: Option<String>,
+    /// False when the branch tip already was this export (same message), so nothing was written.
+    pub created: bool,
+}
+
+impl Workspace {
+    /// Writes the tree at `root` as a commit on `commit.branch` in the Git repository `into`.
+    ///
+    /// The repository is created when missing; its working tree is not touched.
+    pub fn export_git(
+        &self,
+        root: &ObjectId,
+        into: &Path,
+        commit: &GitExportCommit,
+    ) -> Result<GitExport> {
+        let unresolved = crate::resolve::superposition_variants(&self.store, root)?;
+        if !unresolved.is_empty() {
+            anyhow::bail!(
+                "refusing to export a tree with {} unresolved superposition(s)",
+                unresolved.len()
+            );
+        }
+
+        let is_repo = into.join(".git").exists()
+            || (into.join("HEAD").is_file() && into.join("objects").is_dir());
+        if !is_repo {
+            std::fs::create_dir_all(into).with_context(|| format!("create {}", into.display()))?;
+            git(into, &["init", "-q"])?;
+        }
+
+        let branch_ref = format!("refs/heads/{}", commit.branch);
+        let parent = git(
+            into,
+            &[
+                "rev-parse",
+                "--verify",
+                "--quiet",
+                &format!("{}^{{commit}}", branch_ref),
+            ],
+        )
+        .ok();
+        if let Some(parent) = &parent {
+            let tip_message = git(into, &["log", "-1", "--format=%B", parent])?;
+            if tip_message.trim_end() == commit.message.trim_end() {
+                return Ok(GitExport {
+                    branch: commit.branch.clone(),
+                    commit: parent.clone(),
+                    parent: None,
+                    created: false,
+                });
+            }
+        }
+
+        let mut child = Process::new("git")
+            .arg("-C")
+            .arg(into)
+            .args(["fast-import", "--quiet", "--date-format=raw"])
+            .stdin(Stdio::piped())
+            .stdout(Stdio::null())
+            .stderr(Stdio::piped())
+            .spawn()
+            .context("run git fast-import")?;
+        let mut stdin = std::io::BufWriter::new(child.stdin.take().context("open fast-import")?);
+
+        let ident = format!("{} <> {} +0000", commit.committer, commit.unix_time);
+        writeln!(stdin, "commit {}", branch_ref)?;
+        writeln!(stdin, "author {}", ident)?;
+        writeln!(stdin, "committer {}", ident)?;
+        write_data(&mut stdin, commit.message.as_bytes())?;
+        if let Some(parent) = &parent {
+            writeln!(stdin, "from {}", parent)?;
+        }
+        stdin.write_all(b"deleteall\n")?;
+        walk_tree_files(&self.store, root, &mut |path, file| {
+            let path = quote_path(path);
+            match file {
+                TreeFile::File { mode, content } => {
+                    let mode = if mode & 0o111 != 0 {
+                        "100755"
+                    } else {
+                        "100644"
+                    };
+                    writeln!(stdin, "M {} inline {}", mode, path)?;
+                    write_data(&mut stdin, &content)?;
+                }
+                TreeFile::Symlink { target } => {
+                    writeln!(stdin, "M 120000 inline {}", path)?;
+                    write_data(&mut stdin, target.as_bytes())?;
+                }
+            }
+            Ok(())
+        })?;
+        stdin.write_all(b"\n")?;
+        stdin.flush().context("write fast-import stream")?;
+        drop(stdin);
+
+        let output = child
+            .wait_with_output()
+            .context("wait for git fast-import")?;
+        if !output.status.success() {
+            anyhow::bail!(
+                "git fast-import failed: {}",
+                String::from_utf8_lossy(&output.stderr).trim()
+            );
+        }
+
+        let head = git(into, &["rev-parse", "--verify", &branch_ref])?;
+        Ok(GitExport {
+            branch: commit.branch.clone(),
+            commit: head,
+            parent,
+            created: true,
+        })
+    }
+}
+
+fn write_data(w: &mut impl Write, bytes: &[u8]) -> Result<()> {
+    writeln!(w, "data {}", bytes.len())?;
+    w.write_all(bytes)?;
+    w.write_all(b"\n")?;
+    Ok(())
+}
+
+/// C-style quoting for paths fast-import would otherwise misread.
+fn quote_path(path: &str) -> String {
+    if !path.starts_with('"') && !path.contains(['\n', '\\', '"']) {
+        return path.to_string();
+    }
+    let mut out = String::from("\"");
+    for c in path.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
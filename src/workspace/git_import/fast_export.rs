@@ -0,0 +1,284 @@
+//! Minimal reader for the `git fast-export` stream format.
+//!
+//! Only the commands `git fast-export` emits are understood: `blob`, `commit`, `reset`, `tag`,
+//! plus `feature`/`progress`/`done` lines which are skipped.
+
+use std::io::BufRead;
+
+use anyhow::{Context, Result, anyhow};
+
+#[derive(Debug, PartialEq, Eq)]
+pub(super) enum Command {
+    Blob {
+        mark: Option<String>,
+        data: Vec<u8>,
+    },
+    Commit(Commit),
+    Reset {
+        refname: String,
+        from: Option<String>,
+    },
+    Tag {
+        name: String,
+        from: String,
+    },
+}
+
+#[derive(Debug, Default, PartialEq, Eq)]
+pub(super) struct Commit {
+    pub(super) refname: String,
+    pub(super) mark: Option<String>,
+    pub(super) original_oid: Option<String>,
+    pub(super) author: Option<Ident>,
+    pub(super) committer: Option<Ident>,
+    pub(super) message: String,
+    pub(super) from: Option<String>,
+    pub(super) merges: Vec<String>,
+    pub(super) ops: Vec<FileOp>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub(super) struct Ident {
+    /// `Name <email>`.
+    pub(super) who: String,
+    pub(super) unix_time: i64,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub(super) enum FileOp {
+    Modify {
+        mode: u32,
+        dataref: String,
+        path: String,
+    },
+    Delete {
+        path: String,
+    },
+    DeleteAll,
+}
+
+pub(super) struct Reader<R> {
+    inner: R,
+    pending: Option<String>,
+}
+
+impl<R: BufRead> Reader<R> {
+    pub(super) fn new(inner: R) -> Self {
+        Self {
+            inner,
+            pending: None,
+        }
+    }
+
+    pub(super) fn next_command(&mut self) -> Result<Option<Command>> {
+        loop {
+            let Some(line) = self.next_line()? else {
+                return Ok(None);
+            };
+            if line.is_empty()
+                || line.starts_with("feature ")
+                || line.starts_with("progress ")
+                || line == "done"
+            {
+                continue;
+            }
+
+            if line == "blob" {
+                return self.read_blob().map(Some);
+            }
+            if let Some(refname) = line.strip_prefix("commit ") {
+                return self.read_commit(refname.to_string()).map(Some);
+            }
+            if let Some(refname) = line.strip_prefix("reset ") {
+                let from = self.optional_field("from")?;
+                return Ok(Some(Command::Reset {
+                    refname: refname.to_string(),
+                    from,
+                }));
+            }
+            if let Some(name) = line.strip_prefix("tag ") {
+                return self.read_tag(name.to_string()).map(Some);
+            }
+            return Err(anyhow!("unexpected fast-export line: {}", line));
+        }
+    }
+
+    fn read_blob(&mut self) -> Result<Command> {
+        let mark = self.optional_field("mark")?;
+        let _ = self.optional_field("original-oid")?;
+        let data = self.read_data()?;
+        Ok(Command::Blob { mark, data })
+    }
+
+    fn read_commit(&mut self, refname: String) -> Result<Command> {
+        let mut commit = Commit {
+            refname,
+            ..Default::default()
+        };
+        commit.mark = self.optional_field("mark")?;
+        commit.original_oid = self.optional_field("original-oid")?;
+        commit.author = self
+            .optional_field("author")?
+            .map(|s| parse_ident(&s))
+            .transpose()?;
+        commit.committer = self
+            .optional_field("committer")?
+            .map(|s| parse_ident(&s))
+            .transpose()?;
+        let _ = self.optional_field("encoding")?;
+        commit.message = String::from_utf8_lossy(&self.read_data()?).into_owned();
+        commit.from = self.optional_field("from")?;
+        while let Some(merge) = self.optional_field("merge")? {
+            commit.merges.push(merge);
+        }
+
+        while let Some(line) = self.next_line()? {
+            if let Some(rest) = line.strip_prefix("M ") {
+                let mut parts = rest.splitn(3, ' ');
+                let mode = parts.next().context("file op mode")?;
+                let dataref = parts.next().context("file op dataref")?;
+                let path = parts.next().context("file op path")?;
+                commit.ops.push(FileOp::Modify {
+                    mode: u32::from_str_radix(mode, 8)
+                        .with_context(|| format!("invalid mode {}", mode))?,
+                    dataref: dataref.to_string(),
+                    path: unquote_path(path)?,
+                });
+            } else if let Some(path) = line.strip_prefix("D ") {
+                commit.ops.push(FileOp::Delete {
+                    path: unquote_path(path)?,
+                });
+            } else if line == "deleteall" {
+                commit.ops.push(FileOp::DeleteAll);
+            } else if line.is_empty() {
+                break;
+            } else {
+                self.pending = Some(line);
+                break;
+            }
+        }
+        Ok(Command::Commit(commit))
+    }
+
+    fn read_tag(&mut self, name: String) -> Result<Command> {
+        let _ = self.optional_field("mark")?;
+        let from = self
+            .optional_field("from")?
+            .with_context(|| format!("tag {} has no target", name))?;
+        let _ = self.optional_field("original-oid")?;
+        let _ = self.optional_field("tagger")?;
+        let _ = self.read_data()?;
+        Ok(Command::Tag { name, from })
+    }
+
+    /// Consumes `<name> <value>` when it is the next line; otherwise leaves the line pending.
+    fn optional_field(&mut self, name: &str) -> Result<Option<String>> {
+        let Some(line) = self.next_line()? else {
+            return Ok(None);
+        };
+        if let Some(value) = line
+            .strip_prefix(name)
+            .and_then(|rest| rest.strip_prefix(' '))
+        {
+            return Ok(Some(value.to_string()));
+        }
+        self.pending = Some(line);
+        Ok(None)
+    }
+
+    fn read_data(&mut self) -> Result<Vec<u8>> {
+        let line = self.next_line()?.context("expected data command")?;
+        let len = line
+            .strip_prefix("data ")
+            .with_context(|| format!("expected data command, got {}", line))?;
+        if len.starts_with("<<") {
+            anyhow::bail!("delimited data is not supported");
+        }
+        let len: usize = len
+            .parse()
+            .with_context(|| format!("invalid data length {}", len))?;
+        let mut buf = vec![0u8; len];
+        self.inner
+            .read_exact(&mut buf)
+            .context("read fast-export data")?;
+
+        // An LF may follow the payload; skip it so it is not mistaken for a terminator.
+        let next = self.inner.fill_buf().context("read fast-export stream")?;
+        if next.first() == Some(&b'\n') {
+            self.inner.consume(1);
+        }
+        Ok(buf)
+    }
+
+    fn next_line(&mut self) -> Result<Option<String>> {
+        if let Some(line) = self.pending.take() {
+            return Ok(Some(line));
+        }
+        let mut buf = Vec::new();
+        let n = self
+            .inner
+            .read_until(b'\n', &mut buf)
+            .context("read fast-export stream")?;
+        if n == 0 {
+            return Ok(None);
+        }
+        if buf.last() == Some(&b'\n') {
+            buf.pop();
+        }
+        String::from_utf8(buf)
+            .map(Some)
+            .map_err(|_| anyhow!("non-utf8 fast-export command line"))
+    }
+}
+
+/// Parses `Name <email> <unix-seconds> <tz>`.
+fn parse_ident(s: &str) -> Result<Ident> {
+    let end = s
+        .rfind('>')
+        .with_context(|| format!("invalid identity {}", s))?;
+    let who = s[..=end].to_string();
+    let time = s[end + 1..]
+        .split_whitespace()
+        .next()
+        .with_context(|| format!("identity without time {}", s))?;
+    let unix_time = time
+        .parse()
+        .with_context(|| format!("invalid identity time {}", time))?;
+    Ok(Ident { who, unix_time })
+}
+
+/// Paths with special characters are C-style quoted.
+fn unquote_path(path: &str) -> Result<String> {
+    let Some(inner) = path.strip_prefix('"').and_then(|p| p.strip_suffix('"')) else {
+        return Ok(path.to_string());
+    };
+
+    let mut out = Vec::new();
+    let mut bytes = inner.bytes();
+    while let Some(b) = bytes.next() {
+        if b != b'\\' {
+            out.push(b);
+            continue;
+        }
+        let esc = bytes.next().context("dangling escape in quoted path")?;
+        match esc {
+            b'n' => out.push(b'\n'),
+            b't' => out.push(b'\t'),
+            b'"' => out.push(b'"'),
+            b'\\' => out.push(b'\\'),
+            b'0'..=b'7' => {
+                let d2 = bytes.next().context("short octal escape")?;
+                let d3 = bytes.next().context("short octal escape")?;
+                let digits = [esc, d2, d3];
+                let s = std::str::from_utf8(&digits).context("octal escape")?;
+                out.push(u8::from_str_radix(s, 8).context("octal escape")?);
+            }
+            other => anyhow::bail!("unsupported escape \\{} in quoted path", other as char),
+        }
+    }
+    String::from_utf8(out).map_err(|_| anyhow!("non-utf8 path in fast-export stream"))
+}
+
+#[cfg(test)]
+#[path = "../../tests/workspace/git_import/fast_export_tests.rs"]
+mod tests;
@@ -0,0 +1,452 @@
+//! Import Git history into snaps by reading `git fast-export` output.
+
+use std::collections::{BTreeMap, HashMap};
+use std::io::{BufReader, Read};
+use std::path::Path;
+use std::process::{Command as Process, Stdio};
+
+use anyhow::{Context, Result, anyhow};
+use time::format_description::well_known::Rfc3339;
+
+use crate::model::{
+    Manifest, ManifestEntry, ManifestEntryKind, ObjectId, SnapRecord, SnapStats,
+    compute_lineage_snap_id,
+};
+
+use super::Workspace;
+use super::chunk_io::chunk_bytes_to_recipe_store;
+use super::chunking::{ChunkingPolicy, chunking_policy_from_config};
+
+mod fast_export;
+
+use self::fast_export::{Command, Commit, FileOp};
+
+const MODE_SYMLINK: u32 = 0o120000;
+const MODE_GITLINK: u32 = 0o160000;
+
+#[derive(Clone, Debug, Default)]
+pub struct GitImportOptions {
+    /// Branch to import (defaults to the repository's current branch).
+    pub branch: Option<String>,
+}
+
+#[derive(Clone, Debug, serde::Serialize)]
+pub struct ImportedCommit {
+    pub git_commit: Option<String>,
+    pub snap_id: String,
+}
+
+#[derive(Clone, Debug, Default, serde::Serialize)]
+pub struct GitImport {
+    pub branch: String,
+    /// Commits in import order (parents before children).
+    pub commits: Vec<ImportedCommit>,
+    /// Tag name -> snap id, for tags reachable from the branch.
+    pub tags: BTreeMap<String, String>,
+    /// Snap for the branch tip.
+    pub tip: Option<String>,
+}
+
+/// Content of a tracked path, as stored in the local object store.
+#[derive(Clone, Debug)]
+enum StoredFile {
+    File { kind: ManifestEntryKind, size: u64 },
+    Symlink { target: String },
+}
+
+struct ImportState<'a> {
+    workspace: &'a Workspace,
+    policy: ChunkingPolicy,
+    /// Stored id, size and whether the id is a chunk recipe, per blob mark.
+    blobs: HashMap<String, (ObjectId, u64, bool)>,
+    /// Paths per commit mark, kept only for the latest commit on each ref; other parents are
+    /// read back from their stored manifests.
+    trees: HashMap<String, BTreeMap<String, StoredFile>>,
+    /// Ref name -> mark of the latest commit imported on it.
+    tips: HashMap<String, String>,
+    snaps: HashMap<String, String>,
+}
+
+impl Workspace {
+    /// Converts the history of one branch of a local Git repository into snaps.
+    ///
+    /// Snaps keep the commit's author, author time, message and parent lineage. HEAD and the
+    /// working tree are left untouched.
+    pub fn import_git(&self, repo: &Path, opts: &GitImportOptions) -> Result<GitImport> {
+        let cfg = self.store.read_config()?;
+        let policy = chunking_policy_from_config(cfg.chunking.as_ref())?;
+
+        let branch = match &opts.branch {
+            Some(b) => b.clone(),
+            None => git_output(repo, &["symbolic-ref", "--short", "HEAD"])
+                .context("determine current branch (pass --branch)")?,
+        };
+        let branch_ref = format!("refs/heads/{}", branch);
+        git_output(repo, &["rev-parse", "--verify", "--quiet", &branch_ref])
+            .with_context(|| format!("unknown branch {}", branch))?;
+        let tag_refs = git_output(
+            repo,
+            &[
+                "for-each-ref",
+                "--format=%(refname)",
+                "--merged",
+                &branch_ref,
+                "refs/tags",
+            ],
+        )?;
+
+        let mut child = Process::new("git")
+            .arg("-C")
+            .arg(repo)
+            .args([
+                "fast-export",
+                "--show-original-ids",
+                "--signed-tags=strip",
+                "--tag-of-filtered-object=drop",
+                "--reencode=yes",
+                &branch_ref,
+            ])
+            .args(tag_refs.lines().filter(|l| !l.is_empty()))
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .context("run git fast-export")?;
+        let stdout = child
+            .stdout
+            .take()
+            .context("capture git fast-export output")?;
+        // Drained on its own thread so a chatty git cannot fill the pipe and stall the export.
+        let mut stderr = child
+            .stderr
+            .take()
+            .context("capture git fast-export errors")?;
+        let stderr = std::thread::spawn(move || {
+            let mut buf = Vec::new();
+            let _ = stderr.read_to_end(&mut buf);
+            buf
+        });
+
+        let mut state = ImportState {
+            workspace: self,
+            policy,
+            blobs: HashMap::new(),
+            trees: HashMap::new(),
+            tips: HashMap::new(),
+            snaps: HashMap::new(),
+        };
+        let mut out = GitImport {
+            branch: branch.clone(),
+            ..Default::default()
+        };
+        let mut refs: BTreeMap<String, String> = BTreeMap::new();
+        let mut annotated: BTreeMap<String, String> = BTreeMap::new();
+        let mut last_snap = None;
+
+        let read_stream = || -> Result<()> {
+            let mut reader = fast_export::Reader::new(BufReader::new(stdout));
+            while let Some(cmd) = reader.next_command()? {
+                match cmd {
+                    Command::Blob { mark, data } => state.store_blob(mark, &data)?,
+                    Command::Commit(commit) => {
+                        let refname = commit.refname.clone();
+                        let mark = commit.mark.clone();
+                        let git_commit = commit.original_oid.clone();
+                        let snap_id = state.import_commit(commit)?;
+                        if let Some(mark) = mark {
+                            refs.insert(refname, mark);
+                        }
+                        out.commits.push(ImportedCommit {
+                            git_commit,
+                            snap_id: snap_id.clone(),
+                        });
+                        last_snap = Some(snap_id);
+                    }
+                    Command::Reset { refname, from } => match from {
+                        Some(from) => {
+                            refs.insert(refname, from);
+                        }
+                        None => {
+                            refs.remove(&refname);
+                        }
+                    },
+                    Command::Tag { name, from } => {
+                        annotated.insert(name, from);
+                    }
+                }
+            }
+            Ok(())
+        };
+        if let Err(err) = read_stream() {
+            let _ = child.kill();
+            let _ = child.wait();
+            let _ = stderr.join();
+            return Err(err);
+        }
+
+        let status = child.wait().context("wait for git fast-export")?;
+        let stderr = stderr.join().unwrap_or_default();
+        if !status.success() {
+            anyhow::bail!(
+                "git fast-export failed: {}",
+                String::from_utf8_lossy(&stderr).trim()
+            );
+        }
+
+        for (refname, mark) in &refs {
+            if let Some(tag) = refname.strip_prefix("refs/tags/")
+                && let Some(snap) = state.snaps.get(mark)
+            {
+                out.tags.insert(tag.to_string(), snap.clone());
+            }
+        }
+        for (tag, mark) in &annotated {
+            if let Some(snap) = state.snaps.get(mark) {
+                out.tags.insert(tag.clone(), snap.clone());
+            }
+        }
+        out.tip = refs
+            .get(&branch_ref)
+            .and_then(|mark| state.snaps.get(mark).cloned())
+            .or(last_snap);
+        Ok(out)
+    }
+}
+
+impl ImportState<'_> {
+    fn store_blob(&mut self, mark: Option<String>, data: &[u8]) -> Result<()> {
+        let store = &self.workspace.store;
+        let size = data.len() as u64;
+        let (id, chunked) = if size >= self.policy.threshold {
+            (
+                chunk_bytes_to_recipe_store(store, data, self.policy.chunk_size)?,
+                true,
+            )
+        } else {
+            (store.put_blob(data)?, false)
+        };
+        if let Some(mark) = mark {
+            self.blobs.insert(mark, (id, size, chunked));
+        }
+        Ok(())
+    }
+
+    fn import_commit(&mut self, commit: Commit) -> Result<String> {
+        let mut parents = Vec::new();
+        let mut tree = BTreeMap::new();
+        for (i, parent) in commit.from.iter().chain(commit.merges.iter()).enumerate() {
+            let snap = self
+                .snaps
+                .get(parent)
+                .with_context(|| format!("commit parent {} was not imported", parent))?;
+            parents.push(snap.clone());
+            if i == 0 {
+                tree = self.parent_tree(parent, snap)?;
+            }
+        }
+
+        for op in commit.ops {
+            match op {
+                FileOp::DeleteAll => tree.clear(),
+                FileOp::Delete { path } => {
+                    let prefix = format!("{}/", path);
+                    tree.retain(|p, _| p != &path && !p.starts_with(&prefix));
+                }
+                FileOp::Modify {
+                    mode,
+                    dataref,
+                    path,
+                } => {
+                    if mode == MODE_GITLINK {
+                        continue;
+                    }
+                    let stored = self.resolve_file(mode, &dataref)?;
+                    tree.insert(path, stored);
+                }
+            }
+        }
+
+        let mut stats = SnapStats::default();
+        let root_manifest = self.build_tree(&tree, "", &mut stats)?;
+
+        let ident = commit.author.as_ref().or(commit.committer.as_ref());
+        let created_at = match ident {
+            Some(ident) => time::OffsetDateTime::from_unix_timestamp(ident.unix_time)
+                .context("commit time out of range")?,
+            None => time::OffsetDateTime::UNIX_EPOCH,
+        }
+        .format(&Rfc3339)
+        .context("format created_at")?;
+        let message = commit.message.trim_end().to_string();
+        let message = (!message.is_empty()).then_some(message);
+        let author = ident.map(|i| i.who.clone());
+
+        let snap = SnapRecord {
+            version: 1,
+            id: compute_lineage_snap_id(
+                &created_at,
+                &root_manifest,
+                &parents,
+                message.as_deref(),
+                author.as_deref(),
+            ),
+            created_at,
+            root_manifest,
+            message,
+            stats,
+            parents,
+            author,
+        };
+        self.workspace.store.put_snap(&snap)?;
+
+        if let Some(mark) = commit.mark {
+            if let Some(old) = self.tips.insert(commit.refname, mark.clone())
+                && !self.tips.values().any(|m| m == &old)
+            {
+                self.trees.remove(&old);
+            }
+            self.trees.insert(mark.clone(), tree);
+            self.snaps.insert(mark, snap.id.clone());
+        }
+        Ok(snap.id)
+    }
+
+    fn parent_tree(&self, mark: &str, snap_id: &str) -> Result<BTreeMap<String, StoredFile>> {
+        if let Some(tree) = self.trees.get(mark) {
+            return Ok(tree.clone());
+        }
+        let snap = self.workspace.store.get_snap(snap_id)?;
+        let mut tree = BTreeMap::new();
+        self.read_tree(&snap.root_manifest, "", &mut tree)?;
+        Ok(tree)
+    }
+
+    /// Inverse of `build_tree`: collects the paths under a stored manifest.
+    fn read_tree(
+        &self,
+        manifest: &ObjectId,
+        prefix: &str,
+        tree: &mut BTreeMap<String, StoredFile>,
+    ) -> Result<()> {
+        for entry in self.workspace.store.get_manifest(manifest)?.entries {
+            let path = format!("{}{}", prefix, entry.name);
+            match entry.kind {
+                ManifestEntryKind::Dir { manifest } => {
+                    self.read_tree(&manifest, &format!("{}/", path), tree)?;
+                }
+                ManifestEntryKind::Symlink { target } => {
+                    tree.insert(path, StoredFile::Symlink { target });
+                }
+                kind @ (ManifestEntryKind::File { size, .. }
+                | ManifestEntryKind::FileChunks { size, .. }) => {
+                    tree.insert(path, StoredFile::File { kind, size });
+                }
+                ManifestEntryKind::Superposition { .. } => {
+                    anyhow::bail!("imported snap has a superposition at {}", path);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn resolve_file(&self, mode: u32, dataref: &str) -> Result<StoredFile> {
+        let (id, size, chunked) = self
+            .blobs
+            .get(dataref)
+            .with_context(|| format!("unknown blob reference {}", dataref))?;
+
+        if mode == MODE_SYMLINK {
+            let target = self.workspace.store.get_blob(id)?;
+            let target = String::from_utf8(target).map_err(|_| anyhow!("non-utf8 symlink"))?;
+            return Ok(StoredFile::Symlink { target });
+        }
+
+        let kind = if *chunked {
+            ManifestEntryKind::FileChunks {
+                recipe: id.clone(),
+                mode,
+                size: *size,
+            }
+        } else {
+            ManifestEntryKind::File {
+                blob: id.clone(),
+                mode,
+                size: *size,
+            }
+        };
+        Ok(StoredFile::File { kind, size: *size })
+    }
+
+    /// Writes manifests for the paths under `prefix` and returns the manifest id.
+    fn build_tree(
+        &self,
+        tree: &BTreeMap<String, StoredFile>,
+        prefix: &str,
+        stats: &mut SnapStats,
+    ) -> Result<ObjectId> {
+        let mut entries = Vec::new();
+        let mut dirs: BTreeMap<&str, ()> = BTreeMap::new();
+
+        let range = tree.range(prefix.to_string()..);
+        for (path, file) in range {
+            let Some(rest) = path.strip_prefix(prefix) else {
+                break;
+            };
+            match rest.split_once('/') {
+                Some((dir, _)) => {
+                    dirs.insert(dir, ());
+                }
+                None => {
+                    let kind = match file {
+                        StoredFile::File { kind, size } => {
+                            stats.files += 1;
+                            stats.bytes += size;
+                            kind.clone()
+                        }
+                        StoredFile::Symlink { target } => {
+                            stats.symlinks += 1;
+                            ManifestEntryKind::Symlink {
+                                target: target.clone(),
+                            }
+                        }
+                    };
+                    entries.push(ManifestEntry {
+                        name: rest.to_string(),
+                        kind,
+                    });
+                }
+            }
+        }
+
+        for dir in dirs.keys() {
+            stats.dirs += 1;
+            let manifest = self.build_tree(tree, &format!("{}{}/", prefix, dir), stats)?;
+            entries.push(ManifestEntry {
+                name: dir.to_string(),
+                kind: ManifestEntryKind::Dir { manifest },
+            });
+        }
+
+        entries.sort_by(|a, b| a.name.cmp(&b.name));
+        self.workspace.store.put_manifest(&Manifest {
+            version: 1,
+            entries,
+        })
+    }
+}
+
+pub(super) fn git_output(repo: &Path, args: &[&str]) -> Result<String> {
+    let out = Process::new("git")
+        .arg("-C")
+        .arg(repo)
+        .args(args)
+        .output()
+        .context("run git")?;
+    if !out.status.success() {
+        anyhow::bail!(
+            "git {} failed: {}",
+            args.join(" "),
+            String::from_utf8_lossy(&out.stderr).trim()
+        );
+    }
+    Ok(String::from_utf8_lossy(&out.stdout).trim().to_string())
+}
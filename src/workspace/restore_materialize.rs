@@ -41,6 +41,22 @@ impl Workspace {
         Ok(())
     }
 
+    /// Replace the working tree with a manifest tree and snap the result as the new HEAD.
+    pub fn checkout_manifest(
+        &self,
+        root_manifest: &ObjectId,
+        message: Option<String>,
+    ) -> Result<crate::model::SnapRecord> {
+        self.write_manifest(root_manifest)?;
+        self.create_snap(message)
+    }
+
+    /// Replace the working tree with a manifest tree, leaving it unsnapped.
+    pub fn write_manifest(&self, root_manifest: &ObjectId) -> Result<()> {
+        materialize_fs::clear_workspace_except_converge_and_git(&self.root)?;
+        materialize_fs::materialize_manifest(&self.store, root_manifest, &self.root)
+    }
+
     /// Materialize a snap into a separate directory (does not create a workspace).
     pub fn materialize_snap_to(&self, snap_id: &str, out_dir: &Path, force: bool) -> Result<()> {
         let snap = self.store.get_snap(snap_id)?;
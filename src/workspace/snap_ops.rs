@@ -24,6 +24,8 @@ impl Workspace {
             root_manifest,
             message,
             stats,
+            parents: Vec::new(),
+            author: None,
         };
         self.store.put_snap(&snap)?;
         self.store.set_head(Some(&snap.id))?;
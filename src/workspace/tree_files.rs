@@ -0,0 +1,65 @@
+//! Walk a manifest tree as a flat list of files, for exporters.
+
+use anyhow::{Context, Result, anyhow};
+
+use crate::model::{ManifestEntryKind, ObjectId};
+use crate::store::LocalStore;
+
+/// One exported path; directories are implied by the paths.
+pub(crate) enum TreeFile {
+    File { mode: u32, content: Vec<u8> },
+    Symlink { target: String },
+}
+
+/// Calls `visit` for every file and symlink under `root`, in path order.
+///
+/// Fails on superpositions: exports only make sense for fully resolved trees.
+pub(crate) fn walk_tree_files(
+    store: &LocalStore,
+    root: &ObjectId,
+    visit: &mut dyn FnMut(&str, TreeFile) -> Result<()>,
+) -> Result<()> {
+    walk(store, root, "", visit)
+}
+
+fn walk(
+    store: &LocalStore,
+    manifest_id: &ObjectId,
+    prefix: &str,
+    visit: &mut dyn FnMut(&str, TreeFile) -> Result<()>,
+) -> Result<()> {
+    let manifest = store.get_manifest(manifest_id)?;
+    for entry in manifest.entries {
+        let path = if prefix.is_empty() {
+            entry.name
+        } else {
+            format!("{}/{}", prefix, entry.name)
+        };
+        match entry.kind {
+            ManifestEntryKind::Dir { manifest } => walk(store, &manifest, &path, visit)?,
+            ManifestEntryKind::File { blob, mode, .. } => {
+                let content = store.get_blob(&blob)?;
+                visit(&path, TreeFile::File { mode, content })?;
+            }
+            ManifestEntryKind::FileChunks { recipe, mode, size } => {
+                let recipe = store.get_recipe(&recipe)?;
+                let mut content = Vec::with_capacity(size as usize);
+                for chunk in recipe.chunks {
+                    content.extend_from_slice(&store.get_blob(&chunk.blob)?);
+                }
+                if content.len() as u64 != size {
+                    return Err(anyhow!("recipe size mismatch for {}", path));
+                }
+                visit(&path, TreeFile::File { mode, content })?;
+            }
+            ManifestEntryKind::Symlink { target } => {
+                visit(&path, TreeFile::Symlink { target })?;
+            }
+            ManifestEntryKind::Superposition { .. } => {
+                return Err(anyhow!("unresolved superposition at {}", path))
+                    .context("tree has unresolved superpositions");
+            }
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,279 @@
+mod common;
+
+use std::fs;
+use std::io::Read;
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use converge::model::ChunkingConfig;
+use converge::workspace::Workspace;
+
+fn run_converge(cwd: &Path, args: &[&str]) -> Result<String> {
+    let out = std::process::Command::new(env!("CARGO_BIN_EXE_converge"))
+        .current_dir(cwd)
+        .args(args)
+        .output()
+        .with_context(|| format!("run converge {:?} in {}", args, cwd.display()))?;
+    if !out.status.success() {
+        anyhow::bail!(
+            "converge {:?} failed (status {:?})\nstdout:\n{}\nstderr:\n{}",
+            args,
+            out.status,
+            String::from_utf8_lossy(&out.stdout),
+            String::from_utf8_lossy(&out.stderr)
+        );
+    }
+
+    Ok(String::from_utf8_lossy(&out.stdout).trim().to_string())
+}
+
+fn archive(ws: &Path, source: &[&str], format: &str, out: &Path) -> Result<Vec<u8>> {
+    let out_str = out.display().to_string();
+    let mut args = vec!["archive"];
+    args.extend_from_slice(source);
+    args.extend_from_slice(&["--format", format, "-o", &out_str]);
+    run_converge(ws, &args)?;
+    fs::read(out).with_context(|| format!("read {}", out.display()))
+}
+
+#[test]
+fn archives_are_reproducible_across_cli_and_server() -> Result<()> {
+    let server = common::spawn_server()?;
+    let ws = tempfile::tempdir().context("create ws")?;
+    run_converge(ws.path(), &["init"])?;
+    let workspace = Workspace::discover(ws.path())?;
+    let mut cfg = workspace.store.read_config()?;
+    cfg.chunking = Some(ChunkingConfig {
+        chunk_size: 64 * 1024,
+        threshold: 100 * 1024,
+    });
+    workspace.store.write_config(&cfg)?;
+    run_converge(
+        ws.path(),
+        &[
+            "remote",
+            "set",
+            "--url",
+            &server.base_url,
+            "--token",
+            &server.token,
+            "--repo",
+            "test",
+            "--scope",
+            "main",
+            "--gate",
+            "dev-intake",
+        ],
+    )?;
+    run_converge(ws.path(), &["remote", "create-repo"])?;
+
+    let big = (0..300 * 1024u32)
+        .map(|i| (i.wrapping_mul(2654435761) >> 24) as u8)
+        .collect::<Vec<_>>();
+    fs::create_dir_all(ws.path().join("bin")).context("create bin")?;
+    fs::write(ws.path().join("app.txt"), b"v1\n").context("write app")?;
+    fs::write(ws.path().join("big.bin"), &big).context("write big")?;
+    fs::write(ws.path().join("bin/run.sh"), b"#!/bin/sh\n").context("write script")?;
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        fs::set_permissions(
+            ws.path().join("bin/run.sh"),
+            fs::Permissions::from_mode(0o755),
+        )
+        .context("chmod script")?;
+        std::os::unix::fs::symlink("app.txt", ws.path().join("current"))
+            .context("create symlink")?;
+    }
+
+    let snap = run_converge(ws.path(), &["snap"])?;
+    let out = tempfile::tempdir().context("create out dir")?;
+    let tar1 = archive(
+        ws.path(),
+        &["--snap", &snap],
+        "tar",
+        &out.path().join("1.tar"),
+    )?;
+    let tar2 = archive(
+        ws.path(),
+        &["--snap", &snap],
+        "tar",
+        &out.path().join("2.tar"),
+    )?;
+    assert_eq!(tar1, tar2, "archives of the same snap are identical");
+
+    let mut tar = tar::Archive::new(tar1.as_slice());
+    let mut names = Vec::new();
+    for entry in tar.entries().context("tar entries")? {
+        let mut entry = entry.context("tar entry")?;
+        let name = entry.path()?.to_string_lossy().into_owned();
+        match name.as_str() {
+            "big.bin" => {
+                let mut body = Vec::new();
+                entry.read_to_end(&mut body)?;
+                assert!(body == big, "chunked file content round-trips");
+            }
+            "bin/run.sh" => assert_eq!(entry.header().mode()?, 0o755),
+            "app.txt" => assert_eq!(entry.header().mode()?, 0o644),
+            "current" => {
+                assert_eq!(entry.header().entry_type(), tar::EntryType::Symlink);
+                assert_eq!(
+                    entry.link_name()?.map(|p| p.to_string_lossy().into_owned()),
+                    Some("app.txt".to_string())
+                );
+            }
+            _ => {}
+        }
+        names.push(name);
+    }
+    #[cfg(unix)]
+    assert_eq!(
+        names,
+        vec!["app.txt", "big.bin", "bin/", "bin/run.sh", "current"]
+    );
+
+    // Publish, bundle and release the same tree; the release archive matches the snap's.
+    let publication: serde_json::Value = serde_json::from_str(&run_converge(
+        ws.path(),
+        &["publish", "--snap-id", &snap, "--json"],
+    )?)
+    .context("parse publish json")?;
+    let pub_id = publication["id"].as_str().context("publication id")?;
+    let bundle: serde_json::Value = serde_json::from_str(&run_converge(
+        ws.path(),
+        &["bundle", "--publication", pub_id, "--json"],
+    )?)
+    .context("parse bundle json")?;
+    let bundle_id = bundle["id"].as_str().context("bundle id")?;
+    run_converge(
+        ws.path(),
+        &[
+            "release",
+            "create",
+            "--channel",
+            "stable",
+            "--bundle-id",
+            bundle_id,
+        ],
+    )?;
+
+    let release_tar = archive(
+        ws.path(),
+        &["--release", "stable"],
+        "tar",
+        &out.path().join("rel.tar"),
+    )?;
+    assert_eq!(release_tar, tar1);
+
+    let client = reqwest::blocking::Client::new();
+    let auth = common::auth_header(&server.token);
+    for format in ["tar", "tar.zst", "zip"] {
+        let local = archive(
+            ws.path(),
+            &["--bundle", bundle_id],
+            format,
+            &out.path().join(format!("bundle.{}", format)),
+        )?;
+        let resp = client
+            .get(format!(
+                "{}/repos/test/releases/stable/archive?format={}",
+                server.base_url, format
+            ))
+            .header(reqwest::header::AUTHORIZATION, &auth)
+            .send()
+            .context("GET release archive")?;
+        assert!(resp.status().is_success(), "{}", resp.status());
+        let served = resp.bytes().context("read archive body")?;
+        assert!(
+            served.as_ref() == local.as_slice(),
+            "server {} archive matches the CLI",
+            format
+        );
+    }
+
+    let resp = client
+        .get(format!(
+            "{}/repos/test/releases/stable/archive?format=rar",
+            server.base_url
+        ))
+        .header(reqwest::header::AUTHORIZATION, &auth)
+        .send()
+        .context("GET release archive")?;
+    assert_eq!(resp.status(), reqwest::StatusCode::BAD_REQUEST);
+
+    Ok(())
+}
+
+#[test]
+fn release_archive_aborts_when_an_object_fails_mid_stream() -> Result<()> {
+    let data = tempfile::tempdir().context("create server data dir")?;
+    let addr_file = data.path().join("addr.txt");
+    let (mut child, base_url) =
+        common::spawn_server_process(data.path(), &addr_file, &["--dev-token", "dev"])?;
+    common::wait_for_healthz(&base_url)?;
+
+    let result = (|| -> Result<()> {
+        let ws = tempfile::tempdir().context("create ws")?;
+        run_converge(ws.path(), &["init"])?;
+        run_converge(
+            ws.path(),
+            &[
+                "remote",
+                "set",
+                "--url",
+                &base_url,
+                "--token",
+                "dev",
+                "--repo",
+                "test",
+                "--scope",
+                "main",
+                "--gate",
+                "dev-intake",
+            ],
+        )?;
+        run_converge(ws.path(), &["remote", "create-repo"])?;
+        fs::write(ws.path().join("app.txt"), b"v1\n").context("write app")?;
+        let snap = run_converge(ws.path(), &["snap"])?;
+        run_converge(ws.path(), &["publish", "--snap-id", &snap])?;
+        let bundle: serde_json::Value =
+            serde_json::from_str(&run_converge(ws.path(), &["bundle", "--json"])?)
+                .context("parse bundle json")?;
+        let bundle_id = bundle["id"].as_str().context("bundle id")?;
+        run_converge(
+            ws.path(),
+            &[
+                "release",
+                "create",
+                "--channel",
+                "stable",
+                "--bundle-id",
+                bundle_id,
+            ],
+        )?;
+
+        // Blobs still exist (so the up-front checks pass) but no longer match their ids.
+        let blobs = data.path().join("test/objects/blobs");
+        for entry in fs::read_dir(&blobs).context("list blobs")? {
+            fs::write(entry?.path(), b"corrupt").context("corrupt blob")?;
+        }
+
+        // Depending on when it fails the connection closes before or after the headers; either
+        // way the client never sees a complete response.
+        let outcome = reqwest::blocking::Client::new()
+            .get(format!("{}/repos/test/releases/stable/archive", base_url))
+            .header(reqwest::header::AUTHORIZATION, common::auth_header("dev"))
+            .send()
+            .and_then(|resp| resp.bytes());
+        assert!(outcome.is_err(), "truncated archive served as complete");
+
+        let log = fs::read_to_string(addr_file.with_extension("stderr.log"))
+            .context("read server log")?;
+        assert!(log.contains("archive test stable"), "{}", log);
+        Ok(())
+    })();
+
+    let _ = child.kill();
+    let _ = child.wait();
+    result
+}
@@ -105,3 +105,39 @@ fn bundle_is_not_promotable_when_superpositions_present() -> Result<()> {
     assert!(bundle.reasons.iter().any(|r| r == "superpositions_present"));
     Ok(())
 }
+
+#[test]
+fn malformed_rules_file_blocks_promotion_instead_of_failing_the_bundle() -> Result<()> {
+    let server = common::spawn_server()?;
+    let ws = tempfile::tempdir().context("create ws")?;
+    run_converge(ws.path(), &["init"])?;
+    run_converge(
+        ws.path(),
+        &[
+            "remote",
+            "set",
+            "--url",
+            &server.base_url,
+            "--token",
+            &server.token,
+            "--repo",
+            "test",
+            "--scope",
+            "main",
+            "--gate",
+            "dev-intake",
+        ],
+    )?;
+    run_converge(ws.path(), &["remote", "create-repo"])?;
+
+    fs::write(ws.path().join("a.txt"), b"one\n").context("write a.txt")?;
+    fs::write(ws.path().join("converge-rules.json"), b"{ not json").context("write rules")?;
+    let snap = run_converge(ws.path(), &["snap", "-m", "bad rules"])?;
+    run_converge(ws.path(), &["publish", "--snap-id", &snap])?;
+
+    let bundle: Bundle = serde_json::from_str(&run_converge(ws.path(), &["bundle", "--json"])?)
+        .context("parse bundle")?;
+    assert!(!bundle.promotable);
+    assert_eq!(bundle.reasons, vec!["rules_invalid".to_string()]);
+    Ok(())
+}
@@ -0,0 +1,181 @@
+use std::fs;
+use std::path::Path;
+use std::process::{Command, Output};
+
+use anyhow::{Context, Result};
+
+mod common;
+
+fn converge(cwd: &Path, args: &[&str]) -> Result<Output> {
+    Command::new(env!("CARGO_BIN_EXE_converge"))
+        .current_dir(cwd)
+        .args(args)
+        .output()
+        .with_context(|| format!("run converge {:?} in {}", args, cwd.display()))
+}
+
+fn run_converge(cwd: &Path, args: &[&str]) -> Result<String> {
+    let out = converge(cwd, args)?;
+    if !out.status.success() {
+        anyhow::bail!(
+            "converge {:?} failed (status {:?})\nstdout:\n{}\nstderr:\n{}",
+            args,
+            out.status,
+            String::from_utf8_lossy(&out.stdout),
+            String::from_utf8_lossy(&out.stderr)
+        );
+    }
+    Ok(String::from_utf8_lossy(&out.stdout).trim().to_string())
+}
+
+fn login(cwd: &Path, server: &common::ServerGuard) -> Result<()> {
+    run_converge(
+        cwd,
+        &[
+            "login",
+            "--url",
+            &server.base_url,
+            "--token",
+            &server.token,
+            "--repo",
+            "test",
+            "--scope",
+            "main",
+            "--gate",
+            "dev-intake",
+        ],
+    )?;
+    Ok(())
+}
+
+#[test]
+fn ls_and_cat_read_local_snaps() -> Result<()> {
+    let ws = tempfile::tempdir().context("create ws")?;
+    run_converge(ws.path(), &["init"])?;
+    fs::create_dir_all(ws.path().join("sub")).context("create sub")?;
+    fs::write(ws.path().join("a.txt"), b"hello\n").context("write a.txt")?;
+    fs::write(ws.path().join("sub/b.txt"), b"world\n").context("write b.txt")?;
+    let snap_id = run_converge(ws.path(), &["snap", "-m", "browse"])?;
+
+    // Later edits must not leak into the snap's view.
+    fs::write(ws.path().join("a.txt"), b"changed\n").context("rewrite a.txt")?;
+
+    let ls = run_converge(ws.path(), &["ls", &snap_id])?;
+    let lines: Vec<&str> = ls.lines().collect();
+    assert_eq!(lines.len(), 2, "{}", ls);
+    assert!(lines[0].ends_with("file    a.txt"), "{}", ls);
+    assert!(lines[0].contains("100644"), "{}", ls);
+    assert!(lines[1].ends_with("dir     sub/"), "{}", ls);
+
+    let rec = run_converge(ws.path(), &["ls", &snap_id, "-r"])?;
+    assert!(rec.lines().any(|l| l.ends_with("sub/b.txt")), "{}", rec);
+
+    let json: serde_json::Value = serde_json::from_str(&run_converge(
+        ws.path(),
+        &["ls", &snap_id, "sub", "--json"],
+    )?)
+    .context("parse ls json")?;
+    assert_eq!(json["tree"]["kind"], "snap");
+    assert_eq!(json["entries"][0]["path"], "sub/b.txt");
+    assert_eq!(json["entries"][0]["size"], 6);
+
+    assert_eq!(
+        run_converge(ws.path(), &["cat", &format!("{}:a.txt", snap_id)])?,
+        "hello"
+    );
+    assert_eq!(
+        run_converge(ws.path(), &["cat", &format!("{}:/sub/b.txt", snap_id)])?,
+        "world"
+    );
+
+    let out = converge(ws.path(), &["cat", &format!("{}:sub", snap_id)])?;
+    assert!(!out.status.success());
+    assert!(String::from_utf8_lossy(&out.stderr).contains("is a directory"));
+
+    let out = converge(ws.path(), &["ls", "nope"])?;
+    assert!(!out.status.success());
+    assert!(String::from_utf8_lossy(&out.stderr).contains("no local snap"));
+    Ok(())
+}
+
+#[test]
+fn ls_and_cat_fetch_bundles_and_releases_lazily() -> Result<()> {
+    let server = common::spawn_server()?;
+    let ws1 = tempfile::tempdir().context("create ws1")?;
+    let ws2 = tempfile::tempdir().context("create ws2")?;
+
+    run_converge(ws1.path(), &["init"])?;
+    login(ws1.path(), &server)?;
+    run_converge(ws1.path(), &["remote", "create-repo"])?;
+    fs::create_dir_all(ws1.path().join("sub")).context("create sub")?;
+    fs::write(ws1.path().join("a.txt"), b"hello\n").context("write a.txt")?;
+    fs::write(ws1.path().join("sub/b.txt"), b"world\n").context("write b.txt")?;
+    let snap_id = run_converge(ws1.path(), &["snap", "-m", "browse"])?;
+    run_converge(ws1.path(), &["publish", "--snap-id", &snap_id])?;
+    let bundle_id = run_converge(ws1.path(), &["bundle"])?;
+    run_converge(
+        ws1.path(),
+        &[
+            "release",
+            "create",
+            "--channel",
+            "stable",
+            "--bundle-id",
+            &bundle_id,
+        ],
+    )?;
+
+    // A fresh workspace has none of the objects yet.
+    run_converge(ws2.path(), &["init"])?;
+    login(ws2.path(), &server)?;
+
+    let ls = run_converge(ws2.path(), &["ls", &bundle_id, "sub"])?;
+    assert!(ls.ends_with("file    b.txt"), "{}", ls);
+    assert_eq!(
+        run_converge(ws2.path(), &["cat", &format!("{}:sub/b.txt", bundle_id)])?,
+        "world"
+    );
+
+    let json: serde_json::Value =
+        serde_json::from_str(&run_converge(ws2.path(), &["ls", "stable", "--json"])?)
+            .context("parse ls json")?;
+    assert_eq!(json["tree"]["kind"], "release");
+    assert_eq!(json["entries"].as_array().map(|a| a.len()), Some(2));
+    assert_eq!(run_converge(ws2.path(), &["cat", "stable:a.txt"])?, "hello");
+
+    // Published snaps resolve too, before they are fetched.
+    assert_eq!(
+        run_converge(ws2.path(), &["cat", &format!("{}:a.txt", snap_id)])?,
+        "hello"
+    );
+
+    // Only "not found" falls through to the next kind; other remote errors surface.
+    let out = converge(ws2.path(), &["ls", "nightly"])?;
+    let stderr = String::from_utf8_lossy(&out.stderr);
+    assert!(
+        stderr.contains("no snap, bundle or release channel named nightly"),
+        "{}",
+        stderr
+    );
+    run_converge(
+        ws2.path(),
+        &[
+            "remote",
+            "set",
+            "--url",
+            &server.base_url,
+            "--token",
+            "not-a-token",
+            "--repo",
+            "test",
+            "--scope",
+            "main",
+            "--gate",
+            "dev-intake",
+        ],
+    )?;
+    let out = converge(ws2.path(), &["ls", &bundle_id])?;
+    let stderr = String::from_utf8_lossy(&out.stderr);
+    assert!(stderr.contains("unauthorized"), "{}", stderr);
+    Ok(())
+}
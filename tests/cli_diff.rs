@@ -62,3 +62,45 @@ fn diff_workspace_vs_head_and_snap_vs_snap() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn diff_patch_shows_hunks_and_binary_summaries() -> Result<()> {
+    let ws = tempfile::tempdir().context("create ws")?;
+    run_converge(ws.path(), &["init"])?;
+
+    fs::write(ws.path().join("a.txt"), b"one\ntwo\nthree\n").context("write a.txt")?;
+    fs::write(ws.path().join("img.bin"), [0u8, 1, 2]).context("write img.bin")?;
+    let snap1 = run_converge(ws.path(), &["snap", "-m", "s1"])?;
+
+    fs::write(ws.path().join("a.txt"), b"one\n2\nthree\n").context("rewrite a.txt")?;
+    fs::write(ws.path().join("img.bin"), [0u8, 1, 2, 3]).context("rewrite img.bin")?;
+
+    let out = run_converge(ws.path(), &["diff", "--patch"])?;
+    assert!(out.contains("--- a/a.txt\n+++ b/a.txt\n@@ -1,3 +1,3 @@\n one\n-two\n+2\n three\n"));
+    assert!(out.contains("Binary files differ (3 bytes -> 4 bytes)"));
+
+    let snap2 = run_converge(ws.path(), &["snap", "-m", "s2"])?;
+    let diff_json = run_converge(
+        ws.path(),
+        &[
+            "diff", "--from", &snap1, "--to", &snap2, "--patch", "--json",
+        ],
+    )?;
+    let v: serde_json::Value = serde_json::from_str(&diff_json).context("parse diff json")?;
+    let arr = v.as_array().context("diff json not array")?;
+    let text = arr
+        .iter()
+        .find(|x| x["path"] == "a.txt")
+        .context("a.txt entry")?;
+    assert_eq!(text["status"], "Modified");
+    assert_eq!(text["body"]["kind"], "text");
+    assert_eq!(text["body"]["hunks"][0]["lines"][1]["op"], "removed");
+    let bin = arr
+        .iter()
+        .find(|x| x["path"] == "img.bin")
+        .context("img.bin entry")?;
+    assert_eq!(bin["body"]["kind"], "binary");
+    assert_eq!(bin["body"]["new_size"], 4);
+
+    Ok(())
+}
@@ -0,0 +1,163 @@
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+use anyhow::{Context, Result};
+
+mod common;
+
+fn run_converge(cwd: &Path, args: &[&str]) -> Result<String> {
+    let out = Command::new(env!("CARGO_BIN_EXE_converge"))
+        .current_dir(cwd)
+        .args(args)
+        .output()
+        .with_context(|| format!("run converge {:?} in {}", args, cwd.display()))?;
+
+    if !out.status.success() {
+        anyhow::bail!(
+            "converge {:?} failed (status {:?})\nstdout:\n{}\nstderr:\n{}",
+            args,
+            out.status,
+            String::from_utf8_lossy(&out.stdout),
+            String::from_utf8_lossy(&out.stderr)
+        );
+    }
+    Ok(String::from_utf8_lossy(&out.stdout).trim().to_string())
+}
+
+#[test]
+fn history_and_blame_follow_local_snaps() -> Result<()> {
+    let ws = tempfile::tempdir().context("create ws")?;
+    run_converge(ws.path(), &["init"])?;
+
+    fs::write(ws.path().join("a.txt"), "one\ntwo\n").context("write a.txt")?;
+    let s1 = run_converge(ws.path(), &["snap", "-m", "first"])?;
+    fs::write(ws.path().join("b.txt"), "b\n").context("write b.txt")?;
+    run_converge(ws.path(), &["snap", "-m", "unrelated"])?;
+    fs::write(ws.path().join("a.txt"), "one\n2\nthree\n").context("rewrite a.txt")?;
+    let s3 = run_converge(ws.path(), &["snap", "-m", "third"])?;
+
+    let history: serde_json::Value =
+        serde_json::from_str(&run_converge(ws.path(), &["history", "a.txt", "--json"])?)
+            .context("parse history json")?;
+    let entries = history.as_array().context("history array")?;
+    assert_eq!(entries.len(), 2, "{}", history);
+    assert_eq!(entries[0]["id"], s3.as_str());
+    assert_eq!(entries[0]["change"], "modified");
+    assert_eq!(entries[0]["detail"], "third");
+    assert_eq!(entries[1]["id"], s1.as_str());
+    assert_eq!(entries[1]["change"], "added");
+
+    let text = run_converge(ws.path(), &["history", "a.txt", "--limit", "1"])?;
+    assert_eq!(text.lines().count(), 1, "{}", text);
+    assert!(text.contains(" M snap "), "{}", text);
+
+    let blame = run_converge(ws.path(), &["blame", "a.txt"])?;
+    let lines: Vec<&str> = blame.lines().collect();
+    assert_eq!(lines.len(), 3, "{}", blame);
+    assert!(lines[0].starts_with(&s1[..8]) && lines[0].ends_with("1) one"));
+    assert!(lines[1].starts_with(&s3[..8]) && lines[1].ends_with("2) 2"));
+    assert!(lines[2].starts_with(&s3[..8]) && lines[2].ends_with("3) three"));
+
+    let at_first = run_converge(ws.path(), &["blame", "a.txt", "--snap", &s1])?;
+    assert_eq!(at_first.lines().count(), 2, "{}", at_first);
+    Ok(())
+}
+
+#[test]
+fn history_includes_bundles_and_releases_with_publishers() -> Result<()> {
+    let server = common::spawn_server()?;
+    let ws = tempfile::tempdir().context("create ws")?;
+    run_converge(ws.path(), &["init"])?;
+    run_converge(
+        ws.path(),
+        &[
+            "login",
+            "--url",
+            &server.base_url,
+            "--token",
+            &server.token,
+            "--repo",
+            "test",
+            "--scope",
+            "main",
+            "--gate",
+            "dev-intake",
+        ],
+    )?;
+    run_converge(ws.path(), &["remote", "create-repo"])?;
+
+    fs::write(ws.path().join("a.txt"), "hello\n").context("write a.txt")?;
+    let snap_id = run_converge(ws.path(), &["snap", "-m", "publish me"])?;
+    run_converge(ws.path(), &["publish", "--snap-id", &snap_id])?;
+    let bundle_id = run_converge(ws.path(), &["bundle"])?;
+    run_converge(
+        ws.path(),
+        &[
+            "release",
+            "create",
+            "--channel",
+            "stable",
+            "--bundle-id",
+            &bundle_id,
+        ],
+    )?;
+
+    let history: serde_json::Value =
+        serde_json::from_str(&run_converge(ws.path(), &["history", "a.txt", "--json"])?)
+            .context("parse history json")?;
+    let kinds: Vec<(&str, &str)> = history
+        .as_array()
+        .context("history array")?
+        .iter()
+        .map(|e| (e["kind"].as_str().unwrap(), e["id"].as_str().unwrap()))
+        .collect();
+    assert!(kinds.contains(&("snap", snap_id.as_str())), "{}", history);
+    assert!(
+        kinds.contains(&("bundle", bundle_id.as_str())),
+        "{}",
+        history
+    );
+    assert!(kinds.contains(&("release", "stable")), "{}", history);
+
+    let snap_entry = history
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|e| e["kind"] == "snap")
+        .unwrap();
+    assert!(snap_entry["by"].is_string(), "{}", history);
+
+    let blame: serde_json::Value =
+        serde_json::from_str(&run_converge(ws.path(), &["blame", "a.txt", "--json"])?)
+            .context("parse blame json")?;
+    assert_eq!(blame["origins"][0]["snap_id"], snap_id.as_str());
+    assert!(
+        blame["origins"][0]["publication_id"].is_string(),
+        "{}",
+        blame
+    );
+
+    // With the remote gone, history and blame fall back to local snaps and warn.
+    drop(server);
+    for args in [["history", "a.txt"], ["blame", "a.txt"]] {
+        let out = Command::new(env!("CARGO_BIN_EXE_converge"))
+            .current_dir(ws.path())
+            .args(args)
+            .output()
+            .with_context(|| format!("run converge {:?}", args))?;
+        let stderr = String::from_utf8_lossy(&out.stderr);
+        assert!(out.status.success(), "{:?}: {}", args, stderr);
+        assert!(
+            stderr.contains("warning: remote unavailable, using local snaps only"),
+            "{}",
+            stderr
+        );
+        assert!(
+            String::from_utf8_lossy(&out.stdout).contains(&snap_id[..8]),
+            "{:?}",
+            args
+        );
+    }
+    Ok(())
+}
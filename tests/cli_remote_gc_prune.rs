@@ -109,6 +109,8 @@ fn cli_remote_purge_can_prune_release_history() -> Result<()> {
             root_manifest: root,
             message: None,
             stats: converge::model::SnapStats::default(),
+            parents: Vec::new(),
+            author: None,
         };
         client
             .put(format!("{}/repos/test/objects/snaps/{}", base_url, snap_id))
@@ -0,0 +1,124 @@
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+use anyhow::{Context, Result};
+
+fn run_converge(cwd: &Path, args: &[&str]) -> Result<String> {
+    let out = Command::new(env!("CARGO_BIN_EXE_converge"))
+        .current_dir(cwd)
+        .args(args)
+        .output()
+        .with_context(|| format!("run converge {:?} in {}", args, cwd.display()))?;
+
+    if !out.status.success() {
+        anyhow::bail!(
+            "converge {:?} failed (status {:?})\nstdout:\n{}\nstderr:\n{}",
+            args,
+            out.status,
+            String::from_utf8_lossy(&out.stdout),
+            String::from_utf8_lossy(&out.stderr)
+        );
+    }
+    Ok(String::from_utf8_lossy(&out.stdout).trim().to_string())
+}
+
+#[test]
+fn trace_report_summarizes_a_recorded_session() -> Result<()> {
+    let ws = tempfile::tempdir().context("create ws")?;
+    run_converge(ws.path(), &["init"])?;
+    let trace = ws.path().join("trace.jsonl");
+    let script = ws.path().join("session.jsonl");
+    let steps = [
+        serde_json::json!({"command": "bogus"}),
+        serde_json::json!({"command": "bogus"}),
+        serde_json::json!({"command": "bogus"}),
+        serde_json::json!({"key": "tab"}),
+        serde_json::json!({"command": "bootstrap"}),
+        serde_json::json!({"expect": {"modal": "Bootstrap"}}),
+        serde_json::json!({"key": "esc"}),
+        serde_json::json!({"expect": {"no_modal": true}}),
+    ];
+    fs::write(
+        &script,
+        steps
+            .iter()
+            .map(|s| s.to_string())
+            .collect::<Vec<_>>()
+            .join("\n"),
+    )
+    .context("write script")?;
+    run_converge(
+        ws.path(),
+        &[
+            "--agent-trace",
+            trace.to_str().unwrap(),
+            "tui",
+            "--script",
+            script.to_str().unwrap(),
+        ],
+    )?;
+
+    let out = run_converge(
+        ws.path(),
+        &["trace", "report", trace.to_str().unwrap(), "--json"],
+    )?;
+    let report: serde_json::Value = serde_json::from_str(&out).context("parse report json")?;
+    assert_eq!(report["schema_versions"], serde_json::json!([1]));
+    assert_eq!(report["sessions"], 1);
+    assert_eq!(report["skipped_lines"], 0);
+
+    let loops = report["command_loops"]
+        .as_array()
+        .context("command_loops")?;
+    assert_eq!(loops.len(), 1);
+    assert_eq!(loops[0]["command"], "bogus");
+    assert_eq!(loops[0]["count"], 3);
+    assert_eq!(loops[0]["errors"], 3);
+
+    let errors = report["error_loops"].as_array().context("error_loops")?;
+    assert_eq!(errors[0]["count"], 3);
+
+    let abandoned = report["abandoned_wizards"]
+        .as_array()
+        .context("abandoned_wizards")?;
+    assert_eq!(abandoned.len(), 1);
+    assert!(
+        abandoned[0]["title"]
+            .as_str()
+            .unwrap()
+            .contains("Bootstrap")
+    );
+
+    let root = report["modes"]
+        .as_array()
+        .context("modes")?
+        .iter()
+        .find(|m| m["mode"] == "root")
+        .context("root mode stats")?;
+    assert_eq!(root["commands"], 4);
+    assert!(root["error_rate"].as_f64().unwrap() > 0.0);
+
+    let report_path = ws.path().join("report.md");
+    run_converge(
+        ws.path(),
+        &[
+            "trace",
+            "report",
+            trace.to_str().unwrap(),
+            "--out",
+            report_path.to_str().unwrap(),
+        ],
+    )?;
+    let md = fs::read_to_string(&report_path).context("read report")?;
+    for heading in [
+        "## Modes",
+        "## Abandoned wizards",
+        "## Repeated command loops",
+        "## Repeated errors",
+    ] {
+        assert!(md.contains(heading), "missing {heading}:\n{md}");
+    }
+    assert!(md.contains("3x `bogus`"), "{md}");
+    Ok(())
+}
@@ -0,0 +1,312 @@
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+use anyhow::{Context, Result};
+
+#[allow(dead_code)]
+mod common;
+
+fn run_converge(cwd: &Path, args: &[&str]) -> Result<String> {
+    let out = Command::new(env!("CARGO_BIN_EXE_converge"))
+        .current_dir(cwd)
+        .args(args)
+        .output()
+        .with_context(|| format!("run converge {:?} in {}", args, cwd.display()))?;
+
+    if !out.status.success() {
+        anyhow::bail!(
+            "converge {:?} failed (status {:?})\nstdout:\n{}\nstderr:\n{}",
+            args,
+            out.status,
+            String::from_utf8_lossy(&out.stdout),
+            String::from_utf8_lossy(&out.stderr)
+        );
+    }
+    Ok(String::from_utf8_lossy(&out.stdout).trim().to_string())
+}
+
+/// Writes `steps` as a script, runs it, and returns whether it passed plus its events.
+fn run_script(
+    cwd: &Path,
+    name: &str,
+    steps: &[serde_json::Value],
+    extra: &[&str],
+) -> Result<(bool, Vec<serde_json::Value>)> {
+    let path = cwd.join(name);
+    let text = steps
+        .iter()
+        .map(|s| s.to_string())
+        .collect::<Vec<_>>()
+        .join("\n");
+    fs::write(&path, text).context("write script")?;
+
+    let mut args = vec!["tui", "--script", path.to_str().unwrap()];
+    args.extend_from_slice(extra);
+    let out = Command::new(env!("CARGO_BIN_EXE_converge"))
+        .current_dir(cwd)
+        .args(&args)
+        .output()
+        .context("run converge tui --script")?;
+    let events = String::from_utf8_lossy(&out.stdout)
+        .lines()
+        .map(|l| serde_json::from_str(l).with_context(|| format!("parse event {}", l)))
+        .collect::<Result<Vec<serde_json::Value>>>()?;
+    Ok((out.status.success(), events))
+}
+
+fn failures(events: &[serde_json::Value]) -> Vec<&serde_json::Value> {
+    events
+        .iter()
+        .filter(|e| e["event"] == "expect" && e["ok"] == false)
+        .collect()
+}
+
+#[test]
+fn tui_script_drives_views_and_reports_failed_expectations() -> Result<()> {
+    let ws = tempfile::tempdir().context("create ws")?;
+    run_converge(ws.path(), &["init"])?;
+    fs::write(ws.path().join("a.txt"), "hello\n").context("write a.txt")?;
+    run_converge(ws.path(), &["snap", "-m", "first snap"])?;
+    // Scripts run with the built-in config; this would stop the interactive TUI from starting.
+    fs::write(
+        ws.path().join(".converge/tui.toml"),
+        "[keys.nowhere]\nq = \"quit\"\n",
+    )
+    .context("write tui.toml")?;
+
+    let (ok, events) = run_script(
+        ws.path(),
+        "ok.jsonl",
+        &[
+            serde_json::json!({"expect": {"mode": "root", "root_context": "local", "no_modal": true}}),
+            serde_json::json!({"command": "history"}),
+            serde_json::json!({"expect": {"mode": "snaps", "output": "opened snaps", "screen": "first snap"}}),
+            serde_json::json!({"key": "esc"}),
+            serde_json::json!({"type": "bogus"}),
+            serde_json::json!({"expect": {"mode": "root", "input": "bogus"}}),
+            serde_json::json!({"key": "enter"}),
+            serde_json::json!({"expect": {"error": "unknown command"}}),
+            serde_json::json!({"frame": true}),
+            serde_json::json!({"key": "q"}),
+            serde_json::json!({"expect": {"mode": "snaps"}}),
+        ],
+        &["--width", "80", "--height", "24"],
+    )?;
+    assert!(ok, "{:?}", failures(&events));
+
+    let frame = events
+        .iter()
+        .find(|e| e["event"] == "frame")
+        .context("frame event")?;
+    let lines = frame["lines"].as_array().context("frame lines")?;
+    assert_eq!(lines.len(), 24);
+    assert!(lines.iter().any(|l| l.as_str().unwrap().contains("bogus")));
+
+    // `q` quit the TUI, so the trailing expectation never ran.
+    let summary = events.last().context("summary")?;
+    assert_eq!(summary["event"], "summary");
+    assert_eq!(summary["quit"], true);
+    assert_eq!(summary["failed"], 0);
+    assert_eq!(summary["steps"], 10);
+
+    let (ok, events) = run_script(
+        ws.path(),
+        "fail.jsonl",
+        &[
+            serde_json::json!({"command": "history"}),
+            serde_json::json!({"expect": {"mode": "root", "screen": "no such text"}}),
+        ],
+        &[],
+    )?;
+    assert!(!ok);
+    let failed = failures(&events);
+    assert_eq!(failed.len(), 1);
+    assert_eq!(failed[0]["line"], 2);
+    assert_eq!(failed[0]["failures"].as_array().unwrap().len(), 2);
+    Ok(())
+}
+
+#[test]
+fn tui_script_replays_agent_traces() -> Result<()> {
+    let ws = tempfile::tempdir().context("create ws")?;
+    run_converge(ws.path(), &["init"])?;
+    let trace = ws.path().join("trace.jsonl");
+
+    let record = ws.path().join("record.jsonl");
+    fs::write(
+        &record,
+        serde_json::json!({"keys": ["h", "i", "s", "t", "o", "r", "y", "enter"]}).to_string(),
+    )
+    .context("write record script")?;
+    run_converge(
+        ws.path(),
+        &[
+            "--agent-trace",
+            trace.to_str().unwrap(),
+            "tui",
+            "--script",
+            record.to_str().unwrap(),
+        ],
+    )?;
+
+    let recorded = fs::read_to_string(&trace).context("read trace")?;
+    let mut replay: Vec<serde_json::Value> = recorded
+        .lines()
+        .map(serde_json::from_str)
+        .collect::<Result<_, _>>()
+        .context("parse trace")?;
+    replay.push(serde_json::json!({"expect": {"mode": "snaps", "input": ""}}));
+    let (ok, events) = run_script(ws.path(), "replay.jsonl", &replay, &[])?;
+    assert!(ok, "{:?}", failures(&events));
+    Ok(())
+}
+
+#[test]
+fn tui_script_selects_scrolls_and_resizes_panes_with_mouse_and_keys() -> Result<()> {
+    let ws = tempfile::tempdir().context("create ws")?;
+    run_converge(ws.path(), &["init"])?;
+    for i in 1..=4 {
+        fs::write(ws.path().join(format!("f{i}.txt")), format!("{i}\n")).context("write file")?;
+        run_converge(ws.path(), &["snap", "-m", &format!("snap number {i}")])?;
+    }
+
+    // At 80x24 the snaps list starts on row 4 (after the header row) and its bottom border,
+    // the divider, is row 11.
+    let (ok, events) = run_script(
+        ws.path(),
+        "panes.jsonl",
+        &[
+            serde_json::json!({"command": "history"}),
+            serde_json::json!({"mouse": "click 10 7"}),
+            serde_json::json!({"expect": {"screen": "message: snap number 2"}}),
+            serde_json::json!({"mouse": "scroll-down 10 6"}),
+            serde_json::json!({"expect": {"screen": "message: snap number 1"}}),
+            serde_json::json!({"mouse": "down 10 11"}),
+            serde_json::json!({"mouse": "drag 10 14"}),
+            serde_json::json!({"mouse": "up 10 14"}),
+            serde_json::json!({"frame": true}),
+            serde_json::json!({"mouse": "scroll-down 10 16"}),
+            serde_json::json!({"frame": true}),
+            serde_json::json!({"key": "alt+up"}),
+            serde_json::json!({"key": "alt+up"}),
+            serde_json::json!({"frame": true}),
+            serde_json::json!({"key": "alt+="}),
+            serde_json::json!({"frame": true}),
+        ],
+        &["--width", "80", "--height", "24"],
+    )?;
+    assert!(ok, "{:?}", failures(&events));
+
+    let frames: Vec<Vec<String>> = events
+        .iter()
+        .filter(|e| e["event"] == "frame")
+        .map(|e| {
+            e["lines"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .map(|l| l.as_str().unwrap().to_string())
+                .collect()
+        })
+        .collect();
+    let divider = |frame: &[String]| {
+        frame
+            .iter()
+            .skip(4)
+            .position(|l| l.starts_with("│───"))
+            .map(|i| i + 4)
+    };
+    let shows = |frame: &[String], text: &str| frame.iter().any(|l| l.contains(text));
+
+    // Dragged down: the details pane keeps only its first lines.
+    assert_eq!(divider(&frames[0]), Some(14));
+    assert!(shows(&frames[0], "id: "));
+    // The wheel scrolls the details under the pointer.
+    assert!(!shows(&frames[1], "id: "));
+    assert!(shows(&frames[1], "root_manifest"));
+    // Two resize steps up, then back to the default split.
+    assert!(divider(&frames[2]).unwrap() < 14);
+    assert_eq!(divider(&frames[3]), Some(11));
+    Ok(())
+}
+
+#[test]
+fn tui_script_bootstraps_publishes_bundles_and_promotes() -> Result<()> {
+    let data_dir = tempfile::tempdir().context("create server tempdir")?;
+    let addr_file = data_dir.path().join("addr.txt");
+    let (mut child, base_url) =
+        common::spawn_server_process(data_dir.path(), &addr_file, &["--bootstrap-token", "boot"])?;
+    let res = (|| -> Result<()> {
+        common::wait_for_healthz(&base_url)?;
+
+        let ws = tempfile::tempdir().context("create ws")?;
+        run_converge(ws.path(), &["init"])?;
+        fs::write(ws.path().join("a.txt"), "hello\n").context("write a.txt")?;
+        run_converge(ws.path(), &["snap", "-m", "ship it"])?;
+
+        // The bootstrap wizard asks for url, repo, scope, gate, token, handle, display name.
+        let (ok, events) = run_script(
+            ws.path(),
+            "bootstrap.jsonl",
+            &[
+                serde_json::json!({"key": "tab"}),
+                serde_json::json!({"command": "bootstrap"}),
+                serde_json::json!({"expect": {"modal": "Bootstrap"}}),
+                serde_json::json!({"input": base_url}),
+                serde_json::json!({"keys": ["enter", "enter", "enter", "enter"]}),
+                serde_json::json!({"type": "boot"}),
+                serde_json::json!({"keys": ["enter", "enter", "enter"]}),
+                serde_json::json!({"expect": {"no_modal": true, "output": "bootstrapped admin"}}),
+                serde_json::json!({"key": "tab"}),
+                serde_json::json!({"command": "publish"}),
+                serde_json::json!({"expect": {"modal": "Publish"}}),
+                serde_json::json!({"key": "enter"}),
+                serde_json::json!({"expect": {"no_modal": true, "no_error": true, "output": "published"}}),
+                serde_json::json!({"key": "tab"}),
+                serde_json::json!({"command": "bundle"}),
+                serde_json::json!({"expect": {"mode": "inbox", "root_context": "remote"}}),
+                serde_json::json!({"command": "bundle"}),
+                serde_json::json!({"expect": {"no_error": true, "output": "bundle "}}),
+            ],
+            &[],
+        )?;
+        assert!(ok, "{:?}", failures(&events));
+
+        let graph = ws.path().join("graph.json");
+        fs::write(
+            &graph,
+            serde_json::json!({
+                "version": 1,
+                "gates": [
+                    {"id": "dev-intake", "name": "Dev Intake", "upstream": [], "allow_superpositions": false},
+                    {"id": "team", "name": "Team", "upstream": ["dev-intake"], "allow_superpositions": false}
+                ]
+            })
+            .to_string(),
+        )
+        .context("write gate graph")?;
+        run_converge(
+            ws.path(),
+            &["gates", "set", "--file", graph.to_str().unwrap()],
+        )?;
+
+        let (ok, events) = run_script(
+            ws.path(),
+            "promote.jsonl",
+            &[
+                serde_json::json!({"key": "tab"}),
+                serde_json::json!({"command": "bundles"}),
+                serde_json::json!({"expect": {"mode": "bundles", "screen": "promotable"}}),
+                serde_json::json!({"command": "promote"}),
+                serde_json::json!({"expect": {"no_error": true, "output": "team"}}),
+            ],
+            &[],
+        )?;
+        assert!(ok, "{:?}", failures(&events));
+        Ok(())
+    })();
+    let _ = child.kill();
+    let _ = child.wait();
+    res
+}
@@ -135,3 +135,93 @@ fn read_stderr(stderr_file: &std::path::Path) -> String {
 pub fn auth_header(token: &str) -> String {
     format!("Bearer {}", token)
 }
+
+fn converge_ok(cwd: &std::path::Path, args: &[&str]) -> Result<String> {
+    let out = Command::new(env!("CARGO_BIN_EXE_converge"))
+        .current_dir(cwd)
+        .args(args)
+        .output()
+        .with_context(|| format!("run converge {:?} in {}", args, cwd.display()))?;
+    if !out.status.success() {
+        anyhow::bail!(
+            "converge {:?} failed (status {:?})\nstdout:\n{}\nstderr:\n{}",
+            args,
+            out.status,
+            String::from_utf8_lossy(&out.stdout),
+            String::from_utf8_lossy(&out.stderr)
+        );
+    }
+    Ok(String::from_utf8_lossy(&out.stdout).trim().to_string())
+}
+
+/// Creates a user as the server admin and mints a token for it.
+#[allow(dead_code)]
+pub fn create_user_token(
+    client: &reqwest::blocking::Client,
+    server: &ServerGuard,
+    handle: &str,
+) -> Result<String> {
+    let admin_auth = auth_header(&server.token);
+    let user: serde_json::Value = client
+        .post(format!("{}/users", server.base_url))
+        .header(reqwest::header::AUTHORIZATION, &admin_auth)
+        .json(&serde_json::json!({"handle": handle}))
+        .send()
+        .context("create user")?
+        .error_for_status()
+        .context("create user status")?
+        .json()
+        .context("parse user")?;
+    let user_id = user["id"].as_str().context("user id")?;
+    let token: serde_json::Value = client
+        .post(format!("{}/users/{}/tokens", server.base_url, user_id))
+        .header(reqwest::header::AUTHORIZATION, &admin_auth)
+        .json(&serde_json::json!({"label": handle}))
+        .send()
+        .context("mint token")?
+        .error_for_status()
+        .context("mint token status")?
+        .json()
+        .context("parse token")?;
+    Ok(token["token"].as_str().context("token")?.to_string())
+}
+
+/// Initializes a workspace at `ws` pointing at repo `test`, scope `main`, gate `dev-intake`.
+#[allow(dead_code)]
+pub fn remote_set(ws: &std::path::Path, server: &ServerGuard, token: &str) -> Result<()> {
+    converge_ok(ws, &["init"])?;
+    converge_ok(
+        ws,
+        &[
+            "remote",
+            "set",
+            "--url",
+            &server.base_url,
+            "--token",
+            token,
+            "--repo",
+            "test",
+            "--scope",
+            "main",
+            "--gate",
+            "dev-intake",
+        ],
+    )?;
+    Ok(())
+}
+
+/// Publishes a fresh snap from the owner workspace and bundles it at dev-intake.
+#[allow(dead_code)]
+pub fn owner_bundle(ws: &std::path::Path, content: &str) -> Result<String> {
+    std::fs::write(ws.join("a.txt"), content).context("write a.txt")?;
+    converge_ok(ws, &["snap"])?;
+    let publication: serde_json::Value =
+        serde_json::from_str(&converge_ok(ws, &["publish", "--json"])?).context("parse publish")?;
+    let pub_id = publication["id"].as_str().context("publication id")?;
+    let bundle: serde_json::Value = serde_json::from_str(&converge_ok(
+        ws,
+        &["bundle", "--publication", pub_id, "--json"],
+    )?)
+    .context("parse bundle")?;
+    Ok(bundle["id"].as_str().context("bundle id")?.to_string())
+}
@@ -0,0 +1,243 @@
+mod common;
+
+use std::fs;
+use std::path::Path;
+
+use anyhow::{Context, Result};
+
+fn run_converge_raw(cwd: &Path, args: &[&str]) -> Result<std::process::Output> {
+    std::process::Command::new(env!("CARGO_BIN_EXE_converge"))
+        .current_dir(cwd)
+        .args(args)
+        .output()
+        .with_context(|| format!("run converge {:?} in {}", args, cwd.display()))
+}
+
+fn run_converge(cwd: &Path, args: &[&str]) -> Result<String> {
+    let out = run_converge_raw(cwd, args)?;
+    if !out.status.success() {
+        anyhow::bail!(
+            "converge {:?} failed (status {:?})\nstdout:\n{}\nstderr:\n{}",
+            args,
+            out.status,
+            String::from_utf8_lossy(&out.stdout),
+            String::from_utf8_lossy(&out.stderr)
+        );
+    }
+
+    Ok(String::from_utf8_lossy(&out.stdout).trim().to_string())
+}
+
+fn git(repo: &Path, args: &[&str]) -> Result<String> {
+    let out = std::process::Command::new("git")
+        .current_dir(repo)
+        .args(args)
+        .output()
+        .with_context(|| format!("run git {:?}", args))?;
+    if !out.status.success() {
+        anyhow::bail!(
+            "git {:?} failed: {}",
+            args,
+            String::from_utf8_lossy(&out.stderr)
+        );
+    }
+    Ok(String::from_utf8_lossy(&out.stdout).trim().to_string())
+}
+
+fn publish_and_release(ws: &Path, channel: &str, notes: &str) -> Result<String> {
+    let snap = run_converge(ws, &["snap"])?;
+    let publication: serde_json::Value = serde_json::from_str(&run_converge(
+        ws,
+        &["publish", "--snap-id", &snap, "--json"],
+    )?)
+    .context("parse publish json")?;
+    let pub_id = publication["id"].as_str().context("publication id")?;
+    let bundle: serde_json::Value = serde_json::from_str(&run_converge(
+        ws,
+        &["bundle", "--publication", pub_id, "--json"],
+    )?)
+    .context("parse bundle json")?;
+    let bundle_id = bundle["id"].as_str().context("bundle id")?.to_string();
+    run_converge(
+        ws,
+        &[
+            "release",
+            "create",
+            "--channel",
+            channel,
+            "--bundle-id",
+            &bundle_id,
+            "--notes",
+            notes,
+        ],
+    )?;
+    Ok(bundle_id)
+}
+
+#[test]
+fn git_export_writes_release_commits_with_chained_history() -> Result<()> {
+    let server = common::spawn_server()?;
+    let ws = tempfile::tempdir().context("create ws")?;
+    run_converge(ws.path(), &["init"])?;
+    run_converge(
+        ws.path(),
+        &[
+            "remote",
+            "set",
+            "--url",
+            &server.base_url,
+            "--token",
+            &server.token,
+            "--repo",
+            "test",
+            "--scope",
+            "main",
+            "--gate",
+            "dev-intake",
+        ],
+    )?;
+    run_converge(ws.path(), &["remote", "create-repo"])?;
+
+    fs::create_dir_all(ws.path().join("bin")).context("create bin")?;
+    fs::write(ws.path().join("app.txt"), b"v1\n").context("write app")?;
+    fs::write(ws.path().join("bin/run.sh"), b"#!/bin/sh\n").context("write script")?;
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        fs::set_permissions(
+            ws.path().join("bin/run.sh"),
+            fs::Permissions::from_mode(0o755),
+        )
+        .context("chmod script")?;
+    }
+    let bundle1 = publish_and_release(ws.path(), "stable", "first cut")?;
+
+    let git_dir = tempfile::tempdir().context("create git dir")?;
+    let into = git_dir.path().join("mirror");
+    let into_str = into.display().to_string();
+    let first: serde_json::Value = serde_json::from_str(&run_converge(
+        ws.path(),
+        &[
+            "export",
+            "git",
+            "--release",
+            "stable",
+            "--into",
+            &into_str,
+            "--json",
+        ],
+    )?)
+    .context("parse export json")?;
+    assert_eq!(first["branch"], "converge/stable");
+    assert_eq!(first["created"], true);
+    assert!(first["parent"].is_null());
+
+    let message = git(&into, &["log", "-1", "--format=%B", "converge/stable"])?;
+    assert!(
+        message.starts_with("Release stable\n\nfirst cut\n"),
+        "{}",
+        message
+    );
+    assert!(
+        message.contains(&format!("Converge-Bundle: {}", bundle1)),
+        "{}",
+        message
+    );
+    assert!(message.contains("Converge-Channel: stable"), "{}", message);
+    assert_eq!(git(&into, &["show", "converge/stable:app.txt"])?, "v1");
+    let tree = git(&into, &["ls-tree", "-r", "converge/stable"])?;
+    assert!(tree.contains("100755 blob"), "{}", tree);
+
+    // Re-exporting the same release is a no-op.
+    let again: serde_json::Value = serde_json::from_str(&run_converge(
+        ws.path(),
+        &[
+            "export",
+            "git",
+            "--release",
+            "stable",
+            "--into",
+            &into_str,
+            "--json",
+        ],
+    )?)
+    .context("parse export json")?;
+    assert_eq!(again["created"], false);
+    assert_eq!(again["commit"], first["commit"]);
+
+    fs::write(ws.path().join("app.txt"), b"v2\n").context("rewrite app")?;
+    publish_and_release(ws.path(), "stable", "second cut")?;
+    let second: serde_json::Value = serde_json::from_str(&run_converge(
+        ws.path(),
+        &[
+            "export",
+            "git",
+            "--release",
+            "stable",
+            "--into",
+            &into_str,
+            "--json",
+        ],
+    )?)
+    .context("parse export json")?;
+    assert_eq!(second["parent"], first["commit"]);
+    assert_eq!(
+        git(&into, &["rev-list", "--count", "converge/stable"])?,
+        "2"
+    );
+    assert_eq!(git(&into, &["show", "converge/stable:app.txt"])?, "v2");
+
+    Ok(())
+}
+
+#[test]
+fn git_export_refuses_unresolved_superpositions() -> Result<()> {
+    let server = common::spawn_server()?;
+    let ws1 = tempfile::tempdir().context("create ws1")?;
+    let ws2 = tempfile::tempdir().context("create ws2")?;
+    for ws in [&ws1, &ws2] {
+        run_converge(ws.path(), &["init"])?;
+        run_converge(
+            ws.path(),
+            &[
+                "remote",
+                "set",
+                "--url",
+                &server.base_url,
+                "--token",
+                &server.token,
+                "--repo",
+                "test",
+                "--scope",
+                "main",
+                "--gate",
+                "dev-intake",
+            ],
+        )?;
+    }
+    run_converge(ws1.path(), &["remote", "create-repo"])?;
+    for (ws, content) in [(&ws1, "one\n"), (&ws2, "two\n")] {
+        fs::write(ws.path().join("a.txt"), content).context("write a.txt")?;
+        let snap = run_converge(ws.path(), &["snap"])?;
+        run_converge(ws.path(), &["publish", "--snap-id", &snap])?;
+    }
+    let bundle: serde_json::Value =
+        serde_json::from_str(&run_converge(ws1.path(), &["bundle", "--json"])?)
+            .context("parse bundle json")?;
+    let bundle_id = bundle["id"].as_str().context("bundle id")?;
+
+    let git_dir = tempfile::tempdir().context("create git dir")?;
+    let into = git_dir.path().display().to_string();
+    let out = run_converge_raw(
+        ws1.path(),
+        &["export", "git", "--bundle-id", bundle_id, "--into", &into],
+    )?;
+    assert!(!out.status.success());
+    assert!(
+        String::from_utf8_lossy(&out.stderr).contains("unresolved superpositions"),
+        "{}",
+        String::from_utf8_lossy(&out.stderr)
+    );
+
+    Ok(())
+}
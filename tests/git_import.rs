@@ -0,0 +1,249 @@
+mod common;
+
+use std::fs;
+use std::path::Path;
+
+use anyhow::{Context, Result};
+
+use converge::model::{ChunkingConfig, ManifestEntryKind};
+use converge::workspace::{GitImportOptions, Workspace};
+
+fn git(repo: &Path, args: &[&str], when: &str) -> Result<String> {
+    let out = std::process::Command::new("git")
+        .current_dir(repo)
+        .args(args)
+        .env("GIT_AUTHOR_NAME", "Ada")
+        .env("GIT_AUTHOR_EMAIL", "ada@example.com")
+        .env("GIT_COMMITTER_NAME", "Ada")
+        .env("GIT_COMMITTER_EMAIL", "ada@example.com")
+        .env("GIT_AUTHOR_DATE", when)
+        .env("GIT_COMMITTER_DATE", when)
+        .output()
+        .with_context(|| format!("run git {:?}", args))?;
+    if !out.status.success() {
+        anyhow::bail!(
+            "git {:?} failed: {}",
+            args,
+            String::from_utf8_lossy(&out.stderr)
+        );
+    }
+    Ok(String::from_utf8_lossy(&out.stdout).trim().to_string())
+}
+
+fn run_converge(cwd: &Path, args: &[&str]) -> Result<String> {
+    let out = std::process::Command::new(env!("CARGO_BIN_EXE_converge"))
+        .current_dir(cwd)
+        .args(args)
+        .output()
+        .with_context(|| format!("run converge {:?} in {}", args, cwd.display()))?;
+
+    if !out.status.success() {
+        anyhow::bail!(
+            "converge {:?} failed (status {:?})\nstdout:\n{}\nstderr:\n{}",
+            args,
+            out.status,
+            String::from_utf8_lossy(&out.stdout),
+            String::from_utf8_lossy(&out.stderr)
+        );
+    }
+
+    Ok(String::from_utf8_lossy(&out.stdout).trim().to_string())
+}
+
+/// main: c1 (tag v1) -> c2 -> merge(c2, side) (tag v2); side: c1 -> s1.
+fn fixture_repo(repo: &Path) -> Result<()> {
+    git(repo, &["init", "-q", "-b", "main"], "1700000000 +0000")?;
+    fs::create_dir_all(repo.join("src")).context("create src")?;
+    fs::write(repo.join("README.md"), b"hello\n").context("write readme")?;
+    fs::write(repo.join("src/lib.rs"), b"fn a() {}\n").context("write lib")?;
+    fs::write(repo.join("big.bin"), vec![7u8; 200 * 1024]).context("write big")?;
+    git(repo, &["add", "-A"], "1700000000 +0000")?;
+    git(
+        repo,
+        &["commit", "-q", "-m", "initial import"],
+        "1700000000 +0000",
+    )?;
+    git(repo, &["tag", "v1"], "1700000000 +0000")?;
+
+    git(repo, &["checkout", "-q", "-b", "side"], "1700000100 +0000")?;
+    fs::write(repo.join("side.txt"), b"side\n").context("write side")?;
+    git(repo, &["add", "-A"], "1700000100 +0000")?;
+    git(
+        repo,
+        &["commit", "-q", "-m", "side work"],
+        "1700000100 +0000",
+    )?;
+
+    git(repo, &["checkout", "-q", "main"], "1700000200 +0000")?;
+    fs::remove_file(repo.join("README.md")).context("remove readme")?;
+    fs::write(repo.join("src/lib.rs"), b"fn b() {}\n").context("rewrite lib")?;
+    git(repo, &["add", "-A"], "1700000200 +0000")?;
+    git(
+        repo,
+        &["commit", "-q", "-m", "drop readme"],
+        "1700000200 +0000",
+    )?;
+    git(
+        repo,
+        &["merge", "-q", "--no-ff", "-m", "merge side", "side"],
+        "1700000300 +0000",
+    )?;
+    git(
+        repo,
+        &["tag", "-a", "v2", "-m", "second release"],
+        "1700000300 +0000",
+    )?;
+    Ok(())
+}
+
+#[test]
+fn git_import_preserves_history_and_chunks_large_blobs() -> Result<()> {
+    let repo = tempfile::tempdir().context("create repo dir")?;
+    fixture_repo(repo.path())?;
+
+    let ws_dir = tempfile::tempdir().context("create workspace dir")?;
+    let ws = Workspace::init(ws_dir.path(), false)?;
+    let mut cfg = ws.store.read_config()?;
+    cfg.chunking = Some(ChunkingConfig {
+        chunk_size: 64 * 1024,
+        threshold: 100 * 1024,
+    });
+    ws.store.write_config(&cfg)?;
+
+    let imported = ws.import_git(repo.path(), &GitImportOptions::default())?;
+    assert_eq!(imported.branch, "main");
+    assert_eq!(imported.commits.len(), 4);
+    assert!(imported.commits.iter().all(|c| c.git_commit.is_some()));
+    assert_eq!(ws.store.get_head()?, None, "import leaves HEAD alone");
+
+    let tip_id = imported.tip.clone().context("tip snap")?;
+    let tip = ws.store.get_snap(&tip_id)?;
+    assert_eq!(tip.message.as_deref(), Some("merge side"));
+    assert_eq!(tip.author.as_deref(), Some("Ada <ada@example.com>"));
+    assert_eq!(tip.created_at, "2023-11-14T22:18:20Z");
+    assert_eq!(tip.parents.len(), 2);
+    assert_eq!(imported.tags.get("v2"), Some(&tip_id));
+
+    let v1 = ws
+        .store
+        .get_snap(imported.tags.get("v1").context("v1 tag")?)?;
+    assert_eq!(v1.message.as_deref(), Some("initial import"));
+    assert!(v1.parents.is_empty());
+    assert!(tip.parents.iter().all(|p| ws.store.has_snap(p)));
+
+    let root = ws.store.get_manifest(&v1.root_manifest)?;
+    let big = root
+        .entries
+        .iter()
+        .find(|e| e.name == "big.bin")
+        .context("big.bin entry")?;
+    assert!(
+        matches!(big.kind, ManifestEntryKind::FileChunks { .. }),
+        "{:?}",
+        big.kind
+    );
+
+    ws.restore_snap(&tip_id, true)?;
+    assert!(!ws_dir.path().join("README.md").exists());
+    assert_eq!(fs::read(ws_dir.path().join("src/lib.rs"))?, b"fn b() {}\n");
+    assert_eq!(fs::read(ws_dir.path().join("side.txt"))?, b"side\n");
+    assert_eq!(fs::read(ws_dir.path().join("big.bin"))?.len(), 200 * 1024);
+
+    Ok(())
+}
+
+#[test]
+fn git_import_can_release_tags() -> Result<()> {
+    let server = common::spawn_server()?;
+    let repo = tempfile::tempdir().context("create repo dir")?;
+    fixture_repo(repo.path())?;
+    // Tags that are not valid channel names, sharing the v2 commit.
+    git(repo.path(), &["tag", "V1.2.0"], "1700000300 +0000")?;
+    git(repo.path(), &["tag", "release/1.0"], "1700000300 +0000")?;
+
+    let ws = tempfile::tempdir().context("create workspace dir")?;
+    run_converge(ws.path(), &["init"])?;
+    run_converge(
+        ws.path(),
+        &[
+            "remote",
+            "set",
+            "--url",
+            &server.base_url,
+            "--token",
+            &server.token,
+            "--repo",
+            "test",
+            "--scope",
+            "main",
+            "--gate",
+            "dev-intake",
+        ],
+    )?;
+    run_converge(ws.path(), &["remote", "create-repo"])?;
+
+    let repo_path = repo.path().display().to_string();
+    let import = || -> Result<Vec<String>> {
+        let out: serde_json::Value = serde_json::from_str(&run_converge(
+            ws.path(),
+            &[
+                "import",
+                "git",
+                &repo_path,
+                "--branch",
+                "main",
+                "--release-tags",
+                "--json",
+            ],
+        )?)
+        .context("parse import json")?;
+        Ok(out["releases"]
+            .as_array()
+            .context("releases array")?
+            .iter()
+            .filter_map(|r| r["channel"].as_str().map(str::to_string))
+            .collect())
+    };
+    let expected = vec!["v1", "v1-2-0", "release-1-0", "v2"];
+    assert_eq!(import()?, expected);
+    // Re-importing reuses the publications from the first run.
+    assert_eq!(import()?, expected);
+
+    let show: serde_json::Value = serde_json::from_str(&run_converge(
+        ws.path(),
+        &["release", "show", "--channel", "v2", "--json"],
+    )?)
+    .context("parse release show")?;
+    assert_eq!(show["notes"], "imported from git tag v2");
+
+    Ok(())
+}
+
+#[test]
+fn git_import_keeps_commits_with_the_same_tree_and_time_apart() -> Result<()> {
+    let repo = tempfile::tempdir().context("create repo dir")?;
+    let when = "1700000000 +0000";
+    git(repo.path(), &["init", "-q", "-b", "main"], when)?;
+    fs::write(repo.path().join("a.txt"), b"a\n").context("write a")?;
+    git(repo.path(), &["add", "-A"], when)?;
+    git(repo.path(), &["commit", "-q", "-m", "one"], when)?;
+    git(
+        repo.path(),
+        &["commit", "-q", "--allow-empty", "-m", "two"],
+        when,
+    )?;
+
+    let ws_dir = tempfile::tempdir().context("create workspace dir")?;
+    let ws = Workspace::init(ws_dir.path(), false)?;
+    let imported = ws.import_git(repo.path(), &GitImportOptions::default())?;
+    assert_eq!(imported.commits.len(), 2);
+    let one = ws.store.get_snap(&imported.commits[0].snap_id)?;
+    let two = ws.store.get_snap(&imported.commits[1].snap_id)?;
+    assert_ne!(one.id, two.id);
+    assert_eq!(one.root_manifest, two.root_manifest);
+    assert_eq!(one.message.as_deref(), Some("one"));
+    assert_eq!(two.message.as_deref(), Some("two"));
+    assert_eq!(two.parents, vec![one.id.clone()]);
+    assert!(one.id_matches_content() && two.id_matches_content());
+    Ok(())
+}
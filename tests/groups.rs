@@ -0,0 +1,250 @@
+mod common;
+
+use std::fs;
+use std::path::Path;
+
+use anyhow::{Context, Result};
+
+fn run_converge(cwd: &Path, args: &[&str]) -> Result<String> {
+    let out = std::process::Command::new(env!("CARGO_BIN_EXE_converge"))
+        .current_dir(cwd)
+        .args(args)
+        .output()
+        .with_context(|| format!("run converge {:?} in {}", args, cwd.display()))?;
+    if !out.status.success() {
+        anyhow::bail!(
+            "converge {:?} failed (status {:?})\nstdout:\n{}\nstderr:\n{}",
+            args,
+            out.status,
+            String::from_utf8_lossy(&out.stdout),
+            String::from_utf8_lossy(&out.stderr)
+        );
+    }
+    Ok(String::from_utf8_lossy(&out.stdout).trim().to_string())
+}
+
+#[test]
+fn group_roles_resolve_through_nested_groups() -> Result<()> {
+    let server = common::spawn_server()?;
+    let client = reqwest::blocking::Client::new();
+
+    let ws = tempfile::tempdir().context("create ws")?;
+    run_converge(ws.path(), &["init"])?;
+    run_converge(
+        ws.path(),
+        &[
+            "remote",
+            "set",
+            "--url",
+            &server.base_url,
+            "--token",
+            &server.token,
+            "--repo",
+            "test",
+            "--scope",
+            "main",
+            "--gate",
+            "dev-intake",
+        ],
+    )?;
+    run_converge(ws.path(), &["remote", "create-repo"])?;
+
+    let alice = common::auth_header(&common::create_user_token(&client, &server, "alice")?);
+    common::create_user_token(&client, &server, "bob")?;
+
+    run_converge(ws.path(), &["group", "create", "platform"])?;
+    run_converge(ws.path(), &["group", "create", "sre"])?;
+    run_converge(ws.path(), &["group", "add", "sre", "alice"])?;
+    run_converge(ws.path(), &["group", "add", "platform", "@sre"])?;
+    run_converge(ws.path(), &["group", "add", "platform", "bob"])?;
+    let show = run_converge(ws.path(), &["group", "show", "platform"])?;
+    assert!(show.contains("members: bob"), "{}", show);
+    assert!(show.contains("groups: @sre"), "{}", show);
+
+    // Nesting may not loop back on itself.
+    let err = run_converge(ws.path(), &["group", "add", "sre", "@platform"])
+        .expect_err("cycle should be rejected");
+    assert!(err.to_string().contains("400 Bad Request"), "{:#}", err);
+
+    run_converge(
+        ws.path(),
+        &[
+            "members",
+            "add",
+            "@platform",
+            "--role",
+            "approver",
+            "--gate",
+            "dev-intake",
+        ],
+    )?;
+    let list = run_converge(ws.path(), &["members", "list"])?;
+    assert!(
+        list.contains("@platform approver gate=dev-intake"),
+        "{}",
+        list
+    );
+
+    let bundle_id = common::owner_bundle(ws.path(), "one\n")?;
+    let approve = |auth: &str| -> Result<reqwest::StatusCode> {
+        Ok(client
+            .post(format!(
+                "{}/repos/test/bundles/{}/approve",
+                server.base_url, bundle_id
+            ))
+            .header(reqwest::header::AUTHORIZATION, auth)
+            .json(&serde_json::json!({}))
+            .send()
+            .context("approve")?
+            .status())
+    };
+
+    // alice is in @sre, which is in @platform.
+    assert!(approve(&alice)?.is_success());
+
+    run_converge(ws.path(), &["group", "remove", "sre", "alice"])?;
+    assert_eq!(approve(&alice)?, reqwest::StatusCode::FORBIDDEN);
+
+    let audit: serde_json::Value =
+        serde_json::from_str(&run_converge(ws.path(), &["audit", "--json"])?)
+            .context("parse audit")?;
+    let actions: Vec<&str> = audit
+        .as_array()
+        .context("audit array")?
+        .iter()
+        .filter_map(|e| e["action"].as_str())
+        .collect();
+    for action in [
+        "group.create",
+        "group.member_add",
+        "group.member_remove",
+        "repo.member_add",
+    ] {
+        assert!(actions.contains(&action), "{:?}", actions);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn group_lane_membership_and_delete_cleanup() -> Result<()> {
+    let server = common::spawn_server()?;
+    let client = reqwest::blocking::Client::new();
+
+    let ws = tempfile::tempdir().context("create ws")?;
+    run_converge(ws.path(), &["init"])?;
+    run_converge(
+        ws.path(),
+        &[
+            "remote",
+            "set",
+            "--url",
+            &server.base_url,
+            "--token",
+            &server.token,
+            "--repo",
+            "test",
+            "--scope",
+            "main",
+            "--gate",
+            "dev-intake",
+        ],
+    )?;
+    run_converge(ws.path(), &["remote", "create-repo"])?;
+
+    let carol = common::auth_header(&common::create_user_token(&client, &server, "carol")?);
+    run_converge(ws.path(), &["group", "create", "writers"])?;
+    run_converge(ws.path(), &["group", "add", "writers", "carol"])?;
+    run_converge(
+        ws.path(),
+        &["members", "add", "@writers", "--role", "publisher"],
+    )?;
+    run_converge(
+        ws.path(),
+        &["lane", "members", "default", "add", "@writers"],
+    )?;
+    let lane = run_converge(ws.path(), &["lane", "members", "default", "list"])?;
+    assert!(lane.contains("@writers"), "{}", lane);
+
+    fs::write(ws.path().join("a.txt"), "one\n").context("write a.txt")?;
+    run_converge(ws.path(), &["snap"])?;
+    let publication: serde_json::Value =
+        serde_json::from_str(&run_converge(ws.path(), &["publish", "--json"])?)
+            .context("parse publish")?;
+    let snap_id = publication["snap_id"].as_str().context("snap id")?;
+
+    let update_head = || -> Result<reqwest::StatusCode> {
+        Ok(client
+            .post(format!(
+                "{}/repos/test/lanes/default/heads/me",
+                server.base_url
+            ))
+            .header(reqwest::header::AUTHORIZATION, &carol)
+            .json(&serde_json::json!({"snap_id": snap_id}))
+            .send()
+            .context("update lane head")?
+            .status())
+    };
+    assert!(update_head()?.is_success());
+
+    // Deleting the group drops its repo role and lane membership.
+    run_converge(ws.path(), &["group", "delete", "writers"])?;
+    assert_eq!(update_head()?, reqwest::StatusCode::FORBIDDEN);
+    let list = run_converge(ws.path(), &["members", "list"])?;
+    assert!(!list.contains("@writers"), "{}", list);
+    let lane = run_converge(ws.path(), &["lane", "members", "default", "list"])?;
+    assert!(!lane.contains("@writers"), "{}", lane);
+
+    Ok(())
+}
+
+#[test]
+fn group_lane_members_publish_into_their_lane() -> Result<()> {
+    let server = common::spawn_server()?;
+    let client = reqwest::blocking::Client::new();
+
+    let owner = tempfile::tempdir().context("create owner ws")?;
+    common::remote_set(owner.path(), &server, &server.token)?;
+    run_converge(owner.path(), &["remote", "create-repo"])?;
+
+    let carol_token = common::create_user_token(&client, &server, "carol")?;
+    let bob_token = common::create_user_token(&client, &server, "bob")?;
+    run_converge(owner.path(), &["group", "create", "writers"])?;
+    run_converge(owner.path(), &["group", "add", "writers", "carol"])?;
+    run_converge(
+        owner.path(),
+        &["members", "add", "@writers", "--role", "publisher"],
+    )?;
+    run_converge(owner.path(), &["members", "add", "bob", "--role", "read"])?;
+    run_converge(owner.path(), &["lane", "create", "team"])?;
+    run_converge(
+        owner.path(),
+        &["lane", "members", "team", "add", "@writers"],
+    )?;
+
+    // carol is a lane member only through @writers.
+    let carol = tempfile::tempdir().context("create carol ws")?;
+    common::remote_set(carol.path(), &server, &carol_token)?;
+    fs::write(carol.path().join("a.txt"), "one\n").context("write a.txt")?;
+    run_converge(carol.path(), &["snap"])?;
+    run_converge(carol.path(), &["publish"])?;
+
+    let publications = |token: &str| -> Result<serde_json::Value> {
+        client
+            .get(format!("{}/repos/test/publications", server.base_url))
+            .header(reqwest::header::AUTHORIZATION, common::auth_header(token))
+            .send()
+            .context("list publications")?
+            .error_for_status()
+            .context("list publications status")?
+            .json()
+            .context("parse publications")
+    };
+    let own = publications(&carol_token)?;
+    assert_eq!(own[0]["lanes"], serde_json::json!(["team"]), "{}", own);
+    assert_eq!(own.as_array().map(Vec::len), Some(1));
+    let other = publications(&bob_token)?;
+    assert_eq!(other.as_array().map(Vec::len), Some(0), "{}", other);
+
+    Ok(())
+}
@@ -0,0 +1,224 @@
+mod common;
+
+use std::fs;
+use std::path::Path;
+
+use anyhow::{Context, Result};
+
+fn run_converge(cwd: &Path, args: &[&str]) -> Result<String> {
+    let out = std::process::Command::new(env!("CARGO_BIN_EXE_converge"))
+        .current_dir(cwd)
+        .args(args)
+        .output()
+        .with_context(|| format!("run converge {:?} in {}", args, cwd.display()))?;
+    if !out.status.success() {
+        anyhow::bail!(
+            "converge {:?} failed (status {:?})\nstdout:\n{}\nstderr:\n{}",
+            args,
+            out.status,
+            String::from_utf8_lossy(&out.stdout),
+            String::from_utf8_lossy(&out.stderr)
+        );
+    }
+    Ok(String::from_utf8_lossy(&out.stdout).trim().to_string())
+}
+
+#[test]
+fn lane_lifecycle_create_archive_rename_delete() -> Result<()> {
+    let server = common::spawn_server()?;
+    let client = reqwest::blocking::Client::new();
+
+    let owner = tempfile::tempdir().context("create owner ws")?;
+    common::remote_set(owner.path(), &server, &server.token)?;
+    run_converge(owner.path(), &["remote", "create-repo"])?;
+
+    let bob_token = common::create_user_token(&client, &server, "bob")?;
+    run_converge(owner.path(), &["members", "add", "bob", "--role", "read"])?;
+
+    run_converge(
+        owner.path(),
+        &[
+            "lane",
+            "create",
+            "feature",
+            "--description",
+            "feature work",
+            "--gate",
+            "dev-intake",
+            "--default-scope",
+            "main",
+        ],
+    )?;
+    let err = run_converge(owner.path(), &["lane", "create", "feature"])
+        .expect_err("duplicate lane should be rejected");
+    assert!(err.to_string().contains("already exists"), "{:#}", err);
+    let err = run_converge(
+        owner.path(),
+        &["lane", "create", "other", "--gate", "no-such-gate"],
+    )
+    .expect_err("unknown gate should be rejected");
+    assert!(err.to_string().contains("400 Bad Request"), "{:#}", err);
+
+    // Lane lifecycle is reserved for owners and maintainers.
+    let bob = tempfile::tempdir().context("create bob ws")?;
+    common::remote_set(bob.path(), &server, &bob_token)?;
+    let err = run_converge(bob.path(), &["lane", "create", "mine"])
+        .expect_err("readers cannot create lanes");
+    assert!(err.to_string().contains("forbidden"), "{:#}", err);
+
+    run_converge(owner.path(), &["lane", "members", "feature", "add", "dev"])?;
+    fs::write(owner.path().join("a.txt"), "one\n").context("write a.txt")?;
+    run_converge(owner.path(), &["snap"])?;
+    run_converge(owner.path(), &["sync", "--lane", "feature"])?;
+
+    let lanes = run_converge(owner.path(), &["lanes"])?;
+    assert!(lanes.contains("description: feature work"), "{}", lanes);
+    assert!(lanes.contains("gates: dev-intake"), "{}", lanes);
+    assert!(lanes.contains("default scope: main"), "{}", lanes);
+
+    let err = run_converge(owner.path(), &["lane", "delete", "feature"])
+        .expect_err("lanes with heads cannot be deleted");
+    assert!(err.to_string().contains("has heads"), "{:#}", err);
+
+    run_converge(owner.path(), &["lane", "archive", "feature"])?;
+    let err = run_converge(owner.path(), &["sync", "--lane", "feature"])
+        .expect_err("archived lanes reject head updates");
+    assert!(err.to_string().contains("409 Conflict"), "{:#}", err);
+    run_converge(owner.path(), &["lane", "unarchive", "feature"])?;
+    run_converge(owner.path(), &["sync", "--lane", "feature"])?;
+
+    run_converge(
+        owner.path(),
+        &[
+            "lane",
+            "edit",
+            "feature",
+            "--rename",
+            "feature-2",
+            "--description",
+            "",
+        ],
+    )?;
+    let lanes = run_converge(owner.path(), &["lanes", "--json"])?;
+    let lanes: serde_json::Value = serde_json::from_str(&lanes).context("parse lanes")?;
+    let renamed = lanes
+        .as_array()
+        .context("lanes array")?
+        .iter()
+        .find(|l| l["id"] == "feature-2")
+        .context("renamed lane")?;
+    assert!(renamed["description"].is_null(), "{}", renamed);
+    assert_eq!(renamed["members"].as_array().map(|m| m.len()), Some(1));
+
+    run_converge(owner.path(), &["lane", "create", "scratch"])?;
+    run_converge(owner.path(), &["lane", "delete", "scratch"])?;
+
+    let audit = run_converge(owner.path(), &["audit"])?;
+    for action in [
+        "lane.create test/feature",
+        "lane.archive test/feature",
+        "lane.unarchive test/feature",
+        "lane.update test/feature",
+        "lane.delete test/scratch",
+    ] {
+        assert!(audit.contains(action), "{}\n{}", action, audit);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn gates_owned_by_a_lane_are_operated_by_its_members() -> Result<()> {
+    let server = common::spawn_server()?;
+    let client = reqwest::blocking::Client::new();
+
+    let owner = tempfile::tempdir().context("create owner ws")?;
+    common::remote_set(owner.path(), &server, &server.token)?;
+    run_converge(owner.path(), &["remote", "create-repo"])?;
+
+    let bob = common::auth_header(&common::create_user_token(&client, &server, "bob")?);
+    run_converge(
+        owner.path(),
+        &["members", "add", "bob", "--role", "converger"],
+    )?;
+    run_converge(
+        owner.path(),
+        &["lane", "create", "intake", "--gate", "dev-intake"],
+    )?;
+
+    let bundle = || -> Result<reqwest::StatusCode> {
+        Ok(client
+            .post(format!("{}/repos/test/bundles", server.base_url))
+            .header(reqwest::header::AUTHORIZATION, &bob)
+            .json(&serde_json::json!({
+                "scope": "main",
+                "gate": "dev-intake",
+                "input_publications": ["0".repeat(64)],
+            }))
+            .send()
+            .context("create bundle")?
+            .status())
+    };
+    assert_eq!(bundle()?, reqwest::StatusCode::FORBIDDEN);
+
+    run_converge(owner.path(), &["lane", "members", "intake", "add", "bob"])?;
+    assert_eq!(bundle()?, reqwest::StatusCode::BAD_REQUEST);
+
+    let perms: serde_json::Value = client
+        .get(format!("{}/repos/test/permissions", server.base_url))
+        .header(reqwest::header::AUTHORIZATION, &bob)
+        .send()
+        .context("get permissions")?
+        .json()
+        .context("parse permissions")?;
+    assert_eq!(perms["lanes"], serde_json::json!(["intake"]));
+    assert_eq!(perms["owned_gates"], serde_json::json!(["dev-intake"]));
+
+    // Ownership lives in the gate graph and follows lane renames.
+    let err = run_converge(owner.path(), &["lane", "delete", "intake"])
+        .expect_err("lanes owning gates cannot be deleted");
+    assert!(err.to_string().contains("lane owns gates"), "{:#}", err);
+    run_converge(
+        owner.path(),
+        &["lane", "edit", "intake", "--rename", "platform"],
+    )?;
+    let admin = common::auth_header(&server.token);
+    let mut graph: serde_json::Value = client
+        .get(format!("{}/repos/test/gate-graph", server.base_url))
+        .header(reqwest::header::AUTHORIZATION, &admin)
+        .send()
+        .context("get gate graph")?
+        .json()
+        .context("parse gate graph")?;
+    assert_eq!(graph["gates"][0]["lane"], "platform");
+
+    graph["gates"][0]["lane"] = serde_json::json!("nope");
+    let resp = client
+        .put(format!("{}/repos/test/gate-graph", server.base_url))
+        .header(reqwest::header::AUTHORIZATION, &admin)
+        .json(&graph)
+        .send()
+        .context("put gate graph")?;
+    assert_eq!(resp.status(), reqwest::StatusCode::BAD_REQUEST);
+    let body = resp.text().context("read body")?;
+    assert!(body.contains("unknown_lane"), "{}", body);
+
+    // Clearing ownership in the gate graph is reflected on the lane.
+    graph["gates"][0]
+        .as_object_mut()
+        .context("gate object")?
+        .remove("lane");
+    client
+        .put(format!("{}/repos/test/gate-graph", server.base_url))
+        .header(reqwest::header::AUTHORIZATION, &admin)
+        .json(&graph)
+        .send()
+        .context("put gate graph")?
+        .error_for_status()
+        .context("put gate graph status")?;
+    let lanes = run_converge(owner.path(), &["lanes", "--json"])?;
+    assert!(!lanes.contains("dev-intake"), "{}", lanes);
+    run_converge(owner.path(), &["lane", "delete", "platform"])?;
+
+    Ok(())
+}
@@ -0,0 +1,148 @@
+mod common;
+
+use std::fs;
+use std::path::Path;
+
+use anyhow::{Context, Result};
+
+fn run_converge(cwd: &Path, args: &[&str]) -> Result<String> {
+    let out = std::process::Command::new(env!("CARGO_BIN_EXE_converge"))
+        .current_dir(cwd)
+        .args(args)
+        .output()
+        .with_context(|| format!("run converge {:?} in {}", args, cwd.display()))?;
+    if !out.status.success() {
+        anyhow::bail!(
+            "converge {:?} failed (status {:?})\nstdout:\n{}\nstderr:\n{}",
+            args,
+            out.status,
+            String::from_utf8_lossy(&out.stdout),
+            String::from_utf8_lossy(&out.stderr)
+        );
+    }
+    Ok(String::from_utf8_lossy(&out.stdout).trim().to_string())
+}
+
+fn count(json: &str) -> Result<usize> {
+    let v: serde_json::Value = serde_json::from_str(json).context("parse json list")?;
+    Ok(v.as_array().context("json array")?.len())
+}
+
+#[test]
+fn lane_visibility_requires_membership_or_subscription() -> Result<()> {
+    let server = common::spawn_server()?;
+    let client = reqwest::blocking::Client::new();
+
+    let owner = tempfile::tempdir().context("create owner ws")?;
+    common::remote_set(owner.path(), &server, &server.token)?;
+    run_converge(owner.path(), &["remote", "create-repo"])?;
+
+    let alice_token = common::create_user_token(&client, &server, "alice")?;
+    common::create_user_token(&client, &server, "bob")?;
+    run_converge(owner.path(), &["members", "add", "alice", "--role", "read"])?;
+    run_converge(owner.path(), &["members", "add", "bob", "--role", "read"])?;
+
+    // The owner is the only member of the default lane.
+    fs::write(owner.path().join("a.txt"), "one\n").context("write a.txt")?;
+    run_converge(owner.path(), &["snap"])?;
+    run_converge(owner.path(), &["publish"])?;
+    run_converge(owner.path(), &["sync"])?;
+
+    let alice = tempfile::tempdir().context("create alice ws")?;
+    common::remote_set(alice.path(), &server, &alice_token)?;
+    let publications = |token: &str| -> Result<usize> {
+        let text = client
+            .get(format!("{}/repos/test/publications", server.base_url))
+            .header(reqwest::header::AUTHORIZATION, common::auth_header(token))
+            .send()
+            .context("list publications")?
+            .error_for_status()
+            .context("list publications status")?
+            .text()
+            .context("read publications")?;
+        count(&text)
+    };
+    let lanes = |ws: &Path| -> Result<usize> { count(&run_converge(ws, &["lanes", "--json"])?) };
+
+    assert_eq!(publications(&server.token)?, 1);
+    assert_eq!(publications(&alice_token)?, 0);
+    assert_eq!(lanes(alice.path())?, 0);
+
+    // A scope-limited subscription shows publications in that scope, but not lane heads.
+    run_converge(
+        alice.path(),
+        &["lane", "subscribe", "default", "--scope", "main"],
+    )?;
+    assert_eq!(publications(&alice_token)?, 1);
+    assert_eq!(lanes(alice.path())?, 0);
+
+    // Self-subscriptions default to an expiry and cannot be made open-ended.
+    let err = run_converge(
+        alice.path(),
+        &["lane", "subscribe", "default", "--until", "2999-01-01"],
+    )
+    .expect_err("far expiry should be rejected");
+    assert!(err.to_string().contains("400 Bad Request"), "{:#}", err);
+    let until = (time::OffsetDateTime::now_utc() + time::Duration::days(7)).date();
+    run_converge(
+        alice.path(),
+        &[
+            "lane",
+            "subscribe",
+            "default",
+            "--until",
+            &until.to_string(),
+        ],
+    )?;
+    assert_eq!(lanes(alice.path())?, 1);
+    let subs = run_converge(alice.path(), &["lane", "subscriptions"])?;
+    assert!(subs.contains("default alice scope=main until="), "{}", subs);
+    let expected = format!("until={}T00:00:00Z", until.next_day().context("next day")?);
+    assert!(subs.contains(&expected), "{}", subs);
+
+    let err = run_converge(
+        alice.path(),
+        &["lane", "subscribe", "default", "--until", "2000-01-01"],
+    )
+    .expect_err("past expiry should be rejected");
+    assert!(err.to_string().contains("400 Bad Request"), "{:#}", err);
+
+    // Only owners and maintainers may subscribe someone else.
+    let err = run_converge(
+        alice.path(),
+        &["lane", "subscribe", "default", "--user", "bob"],
+    )
+    .expect_err("subscribing others should be forbidden");
+    assert!(err.to_string().contains("forbidden"), "{:#}", err);
+    run_converge(
+        owner.path(),
+        &["lane", "subscribe", "default", "--user", "bob"],
+    )?;
+
+    run_converge(
+        alice.path(),
+        &["lane", "unsubscribe", "default", "--scope", "main"],
+    )?;
+    run_converge(alice.path(), &["lane", "unsubscribe", "default"])?;
+    assert_eq!(publications(&alice_token)?, 0);
+    assert_eq!(lanes(alice.path())?, 0);
+
+    let audit = run_converge(owner.path(), &["audit"])?;
+    assert!(
+        audit.contains("lane.subscribe test/default alice scope=main"),
+        "{}",
+        audit
+    );
+    assert!(
+        audit.contains("dev lane.subscribe test/default bob"),
+        "{}",
+        audit
+    );
+    assert!(
+        audit.contains("lane.unsubscribe test/default alice"),
+        "{}",
+        audit
+    );
+
+    Ok(())
+}
@@ -57,6 +57,8 @@ fn metadata_only_publications_respect_gate_policy_and_object_availability() -> R
         root_manifest,
         message: None,
         stats: converge::model::SnapStats::default(),
+        parents: Vec::new(),
+        author: None,
     };
 
     client
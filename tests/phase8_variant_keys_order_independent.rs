@@ -24,6 +24,7 @@ fn phase8_variant_keys_are_order_independent() -> Result<()> {
             mode: 0o100644,
             size: 4,
         },
+        metadata: None,
     };
     let v2 = SuperpositionVariant {
         source: "pub-2".to_string(),
@@ -32,6 +33,7 @@ fn phase8_variant_keys_are_order_independent() -> Result<()> {
             mode: 0o100644,
             size: 4,
         },
+        metadata: None,
     };
 
     let m1 = Manifest {
@@ -0,0 +1,284 @@
+mod common;
+
+use std::fs;
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use converge::model::ChunkingConfig;
+use converge::workspace::Workspace;
+
+fn run_converge(cwd: &Path, args: &[&str]) -> Result<String> {
+    let out = std::process::Command::new(env!("CARGO_BIN_EXE_converge"))
+        .current_dir(cwd)
+        .args(args)
+        .output()
+        .with_context(|| format!("run converge {:?} in {}", args, cwd.display()))?;
+    if !out.status.success() {
+        anyhow::bail!(
+            "converge {:?} failed (status {:?})\nstdout:\n{}\nstderr:\n{}",
+            args,
+            out.status,
+            String::from_utf8_lossy(&out.stdout),
+            String::from_utf8_lossy(&out.stderr)
+        );
+    }
+
+    Ok(String::from_utf8_lossy(&out.stdout).trim().to_string())
+}
+
+fn init_workspace(dir: &Path, server: &common::ServerGuard) -> Result<()> {
+    run_converge(dir, &["init"])?;
+    run_converge(
+        dir,
+        &[
+            "remote",
+            "set",
+            "--url",
+            &server.base_url,
+            "--token",
+            &server.token,
+            "--repo",
+            "test",
+            "--scope",
+            "main",
+            "--gate",
+            "dev-intake",
+        ],
+    )?;
+    Ok(())
+}
+
+#[test]
+fn release_attachments_round_trip_and_survive_gc() -> Result<()> {
+    let server = common::spawn_server()?;
+    let ws = tempfile::tempdir().context("create ws")?;
+    init_workspace(ws.path(), &server)?;
+    run_converge(ws.path(), &["remote", "create-repo"])?;
+
+    // Small chunks so the artifact below is stored as a recipe.
+    let workspace = Workspace::discover(ws.path())?;
+    let mut cfg = workspace.store.read_config()?;
+    cfg.chunking = Some(ChunkingConfig {
+        chunk_size: 64 * 1024,
+        threshold: 100 * 1024,
+    });
+    workspace.store.write_config(&cfg)?;
+
+    fs::write(ws.path().join("app.txt"), b"v1\n").context("write app")?;
+    let snap = run_converge(ws.path(), &["snap"])?;
+    let publication: serde_json::Value = serde_json::from_str(&run_converge(
+        ws.path(),
+        &["publish", "--snap-id", &snap, "--json"],
+    )?)
+    .context("parse publish json")?;
+    let pub_id = publication["id"].as_str().context("publication id")?;
+    let bundle: serde_json::Value = serde_json::from_str(&run_converge(
+        ws.path(),
+        &["bundle", "--publication", pub_id, "--json"],
+    )?)
+    .context("parse bundle json")?;
+    let bundle_id = bundle["id"].as_str().context("bundle id")?;
+    run_converge(
+        ws.path(),
+        &[
+            "release",
+            "create",
+            "--channel",
+            "stable",
+            "--bundle-id",
+            bundle_id,
+        ],
+    )?;
+
+    // Attachments live outside the workspace tree.
+    let files = tempfile::tempdir().context("create files dir")?;
+    let build = (0..300 * 1024u32)
+        .map(|i| (i.wrapping_mul(2654435761) >> 24) as u8)
+        .collect::<Vec<_>>();
+    let sbom = br#"{"bomFormat":"CycloneDX","components":[]}"#.to_vec();
+    let build_path = files.path().join("build.zip");
+    let sbom_path = files.path().join("sbom.json");
+    fs::write(&build_path, &build).context("write build")?;
+    fs::write(&sbom_path, &sbom).context("write sbom")?;
+
+    let build_str = build_path.display().to_string();
+    let sbom_str = sbom_path.display().to_string();
+    run_converge(
+        ws.path(),
+        &[
+            "release",
+            "attach",
+            "--channel",
+            "stable",
+            "--file",
+            &build_str,
+        ],
+    )?;
+    run_converge(
+        ws.path(),
+        &[
+            "release",
+            "attach",
+            "--channel",
+            "stable",
+            "--file",
+            &sbom_str,
+            "--kind",
+            "sbom",
+        ],
+    )?;
+    // Re-attaching identical content is a no-op.
+    run_converge(
+        ws.path(),
+        &[
+            "release",
+            "attach",
+            "--channel",
+            "stable",
+            "--file",
+            &sbom_str,
+            "--kind",
+            "sbom",
+        ],
+    )?;
+
+    let err = run_converge(
+        ws.path(),
+        &[
+            "release",
+            "attach",
+            "--channel",
+            "stable",
+            "--file",
+            &sbom_str,
+            "--kind",
+            "binary",
+        ],
+    );
+    assert!(err.is_err(), "unknown kinds are rejected");
+
+    // Same name, different content conflicts.
+    fs::write(&sbom_path, b"{}").context("rewrite sbom")?;
+    let err = run_converge(
+        ws.path(),
+        &[
+            "release",
+            "attach",
+            "--channel",
+            "stable",
+            "--file",
+            &sbom_str,
+            "--kind",
+            "sbom",
+        ],
+    );
+    assert!(err.is_err(), "replacing an attachment is refused");
+
+    let shown: serde_json::Value = serde_json::from_str(&run_converge(
+        ws.path(),
+        &["release", "show", "--channel", "stable", "--json"],
+    )?)
+    .context("parse release show")?;
+    let attachments = shown["attachments"]
+        .as_array()
+        .context("attachments array")?;
+    let summary = attachments
+        .iter()
+        .map(|a| {
+            (
+                a["name"].as_str().unwrap_or_default().to_string(),
+                a["kind"].as_str().unwrap_or_default().to_string(),
+                a["size"].as_u64().unwrap_or_default(),
+                a["recipe"].is_string(),
+            )
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        summary,
+        vec![
+            (
+                "build.zip".to_string(),
+                "artifact".to_string(),
+                build.len() as u64,
+                true
+            ),
+            (
+                "sbom.json".to_string(),
+                "sbom".to_string(),
+                sbom.len() as u64,
+                false
+            ),
+        ]
+    );
+
+    // Nothing else references the attachment objects; GC must keep them as release roots.
+    let client = reqwest::blocking::Client::new();
+    client
+        .post(format!(
+            "{}/repos/test/gc?dry_run=false&prune_metadata=true",
+            server.base_url
+        ))
+        .header(
+            reqwest::header::AUTHORIZATION,
+            common::auth_header(&server.token),
+        )
+        .send()
+        .context("gc repo")?
+        .error_for_status()
+        .context("gc repo status")?;
+
+    // Fetch into a fresh workspace so every object comes from the server.
+    let other = tempfile::tempdir().context("create other ws")?;
+    init_workspace(other.path(), &server)?;
+    let out = tempfile::tempdir().context("create out dir")?;
+    let out_str = out.path().display().to_string();
+    run_converge(
+        other.path(),
+        &[
+            "fetch",
+            "--release",
+            "stable",
+            "--attachment",
+            "build.zip",
+            "--attachment",
+            "sbom.json",
+            "--into",
+            &out_str,
+        ],
+    )?;
+    assert!(fs::read(out.path().join("build.zip"))? == build);
+    assert_eq!(fs::read(out.path().join("sbom.json"))?, sbom);
+
+    let err = run_converge(
+        other.path(),
+        &[
+            "fetch",
+            "--release",
+            "stable",
+            "--attachment",
+            "sbom.json",
+            "--into",
+            &out_str,
+        ],
+    );
+    assert!(
+        err.is_err(),
+        "existing files are not overwritten without --force"
+    );
+
+    let err = run_converge(
+        other.path(),
+        &[
+            "fetch",
+            "--release",
+            "stable",
+            "--attachment",
+            "missing.txt",
+            "--into",
+            &out_str,
+        ],
+    );
+    assert!(err.is_err(), "unknown attachment names fail");
+
+    Ok(())
+}
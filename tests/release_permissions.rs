@@ -85,6 +85,8 @@ fn upload_metadata_only_snap(
         root_manifest,
         message: None,
         stats: converge::model::SnapStats::default(),
+        parents: Vec::new(),
+        author: None,
     };
 
     client
@@ -0,0 +1,252 @@
+mod common;
+
+use std::path::Path;
+
+use anyhow::{Context, Result};
+
+fn run_converge(cwd: &Path, args: &[&str]) -> Result<String> {
+    let out = std::process::Command::new(env!("CARGO_BIN_EXE_converge"))
+        .current_dir(cwd)
+        .args(args)
+        .output()
+        .with_context(|| format!("run converge {:?} in {}", args, cwd.display()))?;
+    if !out.status.success() {
+        anyhow::bail!(
+            "converge {:?} failed (status {:?})\nstdout:\n{}\nstderr:\n{}",
+            args,
+            out.status,
+            String::from_utf8_lossy(&out.stdout),
+            String::from_utf8_lossy(&out.stderr)
+        );
+    }
+    Ok(String::from_utf8_lossy(&out.stdout).trim().to_string())
+}
+
+#[test]
+fn roles_grant_distinct_rights_per_repo_and_gate() -> Result<()> {
+    let server = common::spawn_server()?;
+    let client = reqwest::blocking::Client::new();
+
+    let ws = tempfile::tempdir().context("create ws")?;
+    run_converge(ws.path(), &["init"])?;
+    run_converge(
+        ws.path(),
+        &[
+            "remote",
+            "set",
+            "--url",
+            &server.base_url,
+            "--token",
+            &server.token,
+            "--repo",
+            "test",
+            "--scope",
+            "main",
+            "--gate",
+            "dev-intake",
+        ],
+    )?;
+    run_converge(ws.path(), &["remote", "create-repo"])?;
+
+    let alice = common::auth_header(&common::create_user_token(&client, &server, "alice")?);
+    let bob = common::auth_header(&common::create_user_token(&client, &server, "bob")?);
+
+    run_converge(
+        ws.path(),
+        &[
+            "members",
+            "add",
+            "alice",
+            "--role",
+            "approver",
+            "--gate",
+            "dev-intake",
+        ],
+    )?;
+    run_converge(ws.path(), &["members", "add", "bob", "--role", "releaser"])?;
+    let list = run_converge(ws.path(), &["members", "list"])?;
+    assert!(list.contains("alice approver gate=dev-intake"), "{}", list);
+    assert!(list.contains("bob releaser"), "{}", list);
+
+    let bundle_id = common::owner_bundle(ws.path(), "one\n")?;
+    let post = |auth: &str, path: &str, body: serde_json::Value| -> Result<reqwest::StatusCode> {
+        Ok(client
+            .post(format!("{}{}", server.base_url, path))
+            .header(reqwest::header::AUTHORIZATION, auth)
+            .json(&body)
+            .send()
+            .with_context(|| format!("post {}", path))?
+            .status())
+    };
+    let approve_path = format!("/repos/test/bundles/{}/approve", bundle_id);
+    let release_body = serde_json::json!({"channel": "stable", "bundle_id": bundle_id});
+    let bundle_body = serde_json::json!({"scope": "main", "gate": "dev-intake", "input_publications": ["0".repeat(64)]});
+
+    // Each role only grants its own right.
+    assert!(post(&alice, &approve_path, serde_json::json!({}))?.is_success());
+    assert_eq!(
+        post(&alice, "/repos/test/releases", release_body.clone())?,
+        reqwest::StatusCode::FORBIDDEN
+    );
+    assert_eq!(
+        post(&alice, "/repos/test/bundles", bundle_body)?,
+        reqwest::StatusCode::FORBIDDEN
+    );
+    assert_eq!(
+        post(&bob, &approve_path, serde_json::json!({}))?,
+        reqwest::StatusCode::FORBIDDEN
+    );
+    assert!(post(&bob, "/repos/test/releases", release_body.clone())?.is_success());
+
+    let perms: serde_json::Value = client
+        .get(format!("{}/repos/test/permissions", server.base_url))
+        .header(reqwest::header::AUTHORIZATION, &bob)
+        .send()
+        .context("get permissions")?
+        .json()
+        .context("parse permissions")?;
+    assert_eq!(perms["read"], serde_json::json!(true));
+    assert_eq!(perms["release"], serde_json::json!(true));
+    assert_eq!(perms["publish"], serde_json::json!(false));
+    assert_eq!(perms["manage"], serde_json::json!(false));
+
+    // Gates can restrict who releases there.
+    let mut graph: serde_json::Value = client
+        .get(format!("{}/repos/test/gate-graph", server.base_url))
+        .header(
+            reqwest::header::AUTHORIZATION,
+            common::auth_header(&server.token),
+        )
+        .send()
+        .context("get gate graph")?
+        .json()
+        .context("parse gate graph")?;
+    graph["gates"][0]["roles"] = serde_json::json!({"release": ["maintainer"]});
+    client
+        .put(format!("{}/repos/test/gate-graph", server.base_url))
+        .header(
+            reqwest::header::AUTHORIZATION,
+            common::auth_header(&server.token),
+        )
+        .json(&graph)
+        .send()
+        .context("put gate graph")?
+        .error_for_status()
+        .context("put gate graph status")?;
+    let shown = run_converge(ws.path(), &["gates", "show"])?;
+    assert!(shown.contains("roles[release=maintainer]"), "{}", shown);
+    assert_eq!(
+        post(&bob, "/repos/test/releases", release_body.clone())?,
+        reqwest::StatusCode::FORBIDDEN
+    );
+    run_converge(
+        ws.path(),
+        &["members", "add", "bob", "--role", "maintainer"],
+    )?;
+    assert!(post(&bob, "/repos/test/releases", release_body)?.is_success());
+
+    // Removing one role leaves the member's other roles alone.
+    run_converge(
+        ws.path(),
+        &["members", "remove", "bob", "--role", "releaser"],
+    )?;
+    let list = run_converge(ws.path(), &["members", "list"])?;
+    assert!(!list.contains("bob releaser"), "{}", list);
+    assert!(list.contains("bob maintainer"), "{}", list);
+
+    run_converge(
+        ws.path(),
+        &[
+            "members",
+            "remove",
+            "alice",
+            "--role",
+            "approver",
+            "--gate",
+            "dev-intake",
+        ],
+    )?;
+    assert_eq!(
+        post(&alice, &approve_path, serde_json::json!({}))?,
+        reqwest::StatusCode::FORBIDDEN
+    );
+
+    Ok(())
+}
+
+#[test]
+fn gate_narrowed_roles_do_not_apply_elsewhere_and_bad_grants_are_rejected() -> Result<()> {
+    let server = common::spawn_server()?;
+    let client = reqwest::blocking::Client::new();
+    let admin_auth = common::auth_header(&server.token);
+
+    client
+        .post(format!("{}/repos", server.base_url))
+        .header(reqwest::header::AUTHORIZATION, &admin_auth)
+        .json(&serde_json::json!({"id": "test"}))
+        .send()
+        .context("create repo")?
+        .error_for_status()
+        .context("create repo status")?;
+    let carol = common::auth_header(&common::create_user_token(&client, &server, "carol")?);
+
+    let add = |body: serde_json::Value| -> Result<reqwest::StatusCode> {
+        Ok(client
+            .post(format!("{}/repos/test/members", server.base_url))
+            .header(reqwest::header::AUTHORIZATION, &admin_auth)
+            .json(&body)
+            .send()
+            .context("add member")?
+            .status())
+    };
+    for bad in [
+        serde_json::json!({"handle": "carol", "role": "overlord"}),
+        serde_json::json!({"handle": "carol", "role": "publish", "gate": "dev-intake"}),
+        serde_json::json!({"handle": "carol", "role": "converger", "gate": "nope"}),
+        serde_json::json!({"handle": "carol", "role": "converger", "lane": "nope"}),
+        serde_json::json!({"handle": "carol", "role": "converger", "gate": "dev-intake", "lane": "default"}),
+    ] {
+        assert_eq!(
+            add(bad.clone())?,
+            reqwest::StatusCode::BAD_REQUEST,
+            "{}",
+            bad
+        );
+    }
+
+    // A lane-narrowed publisher may update heads in that lane only and cannot publish to gates.
+    assert!(
+        add(serde_json::json!({"handle": "carol", "role": "publisher", "lane": "default"}))?
+            .is_success()
+    );
+    let resp = client
+        .post(format!("{}/repos/test/publications", server.base_url))
+        .header(reqwest::header::AUTHORIZATION, &carol)
+        .json(&serde_json::json!({
+            "snap_id": "0".repeat(64),
+            "scope": "main",
+            "gate": "dev-intake"
+        }))
+        .send()
+        .context("create publication")?;
+    assert_eq!(resp.status(), reqwest::StatusCode::FORBIDDEN);
+
+    let members: serde_json::Value = client
+        .get(format!("{}/repos/test/members", server.base_url))
+        .header(reqwest::header::AUTHORIZATION, &admin_auth)
+        .send()
+        .context("list members")?
+        .json()
+        .context("parse members")?;
+    assert_eq!(
+        members["grants"],
+        serde_json::json!([{
+            "user_id": members["grants"][0]["user_id"],
+            "handle": "carol",
+            "role": "publisher",
+            "lane": "default"
+        }])
+    );
+
+    Ok(())
+}
@@ -0,0 +1,200 @@
+mod common;
+
+use std::fs;
+
+use anyhow::{Context, Result};
+
+use converge::remote::{BundleResolutions, ResolutionMethod, ResolutionStatus};
+
+fn run_converge(cwd: &std::path::Path, args: &[&str]) -> Result<String> {
+    let out = std::process::Command::new(env!("CARGO_BIN_EXE_converge"))
+        .current_dir(cwd)
+        .args(args)
+        .output()
+        .with_context(|| format!("run converge {:?} in {}", args, cwd.display()))?;
+
+    if !out.status.success() {
+        anyhow::bail!(
+            "converge {:?} failed (status {:?})\nstdout:\n{}\nstderr:\n{}",
+            args,
+            out.status,
+            String::from_utf8_lossy(&out.stdout),
+            String::from_utf8_lossy(&out.stderr)
+        );
+    }
+
+    Ok(String::from_utf8_lossy(&out.stdout).trim().to_string())
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct Bundle {
+    id: String,
+    promotable: bool,
+    reasons: Vec<String>,
+}
+
+fn get_records(base_url: &str, token: &str, bundle_id: &str) -> Result<BundleResolutions> {
+    reqwest::blocking::Client::new()
+        .get(format!(
+            "{}/repos/test/bundles/{}/resolutions",
+            base_url, bundle_id
+        ))
+        .header(reqwest::header::AUTHORIZATION, common::auth_header(token))
+        .send()
+        .context("get resolutions")?
+        .error_for_status()
+        .context("get resolutions status")?
+        .json()
+        .context("parse resolutions")
+}
+
+#[test]
+fn resolution_records_track_resolver_and_reopen_blocks_gates() -> Result<()> {
+    let server = common::spawn_server()?;
+    let base_url = server.base_url.clone();
+    let token = server.token.clone();
+
+    let ws1 = tempfile::tempdir().context("create ws1")?;
+    let ws2 = tempfile::tempdir().context("create ws2")?;
+    for ws in [&ws1, &ws2] {
+        run_converge(ws.path(), &["init"])?;
+        run_converge(
+            ws.path(),
+            &[
+                "remote",
+                "set",
+                "--url",
+                &base_url,
+                "--token",
+                &token,
+                "--repo",
+                "test",
+                "--scope",
+                "main",
+                "--gate",
+                "dev-intake",
+            ],
+        )?;
+    }
+    run_converge(ws1.path(), &["remote", "create-repo"])?;
+
+    fs::write(ws1.path().join("a.txt"), b"one\n").context("write a.txt ws1")?;
+    let snap1 = run_converge(ws1.path(), &["snap"])?;
+    run_converge(ws1.path(), &["publish", "--snap-id", &snap1])?;
+
+    fs::write(ws2.path().join("a.txt"), b"two\n").context("write a.txt ws2")?;
+    let snap2 = run_converge(ws2.path(), &["snap"])?;
+    run_converge(ws2.path(), &["publish", "--snap-id", &snap2])?;
+
+    let bundle: Bundle = serde_json::from_str(&run_converge(ws1.path(), &["bundle", "--json"])?)
+        .context("parse bundle")?;
+
+    // Before any decision the server reports the conflicted path as unresolved.
+    let records = get_records(&base_url, &token, &bundle.id)?;
+    let rec = records
+        .records
+        .get("a.txt")
+        .context("a.txt record missing")?;
+    assert_eq!(rec.status, ResolutionStatus::Unresolved);
+
+    run_converge(ws1.path(), &["resolve", "init", "--bundle-id", &bundle.id])?;
+    run_converge(
+        ws1.path(),
+        &[
+            "resolve",
+            "pick",
+            "--bundle-id",
+            &bundle.id,
+            "--path",
+            "a.txt",
+            "--variant",
+            "1",
+            "--rationale",
+            "first publication wins",
+        ],
+    )?;
+
+    let records = get_records(&base_url, &token, &bundle.id)?;
+    let rec = records
+        .records
+        .get("a.txt")
+        .context("a.txt record missing")?;
+    assert_eq!(rec.status, ResolutionStatus::Resolved);
+    assert_eq!(rec.method, Some(ResolutionMethod::Pick));
+    assert_eq!(rec.rationale.as_deref(), Some("first publication wins"));
+    assert!(rec.resolver.is_some());
+    assert!(rec.decision.is_some());
+
+    let out = run_converge(
+        ws1.path(),
+        &[
+            "resolve",
+            "apply",
+            "--bundle-id",
+            &bundle.id,
+            "--publish",
+            "--json",
+        ],
+    )?;
+    let v: serde_json::Value = serde_json::from_str(&out).context("parse resolve apply json")?;
+    let pub_id = v
+        .get("published_publication_id")
+        .and_then(|v| v.as_str())
+        .context("published_publication_id missing")?
+        .to_string();
+
+    let resolved: Bundle = serde_json::from_str(&run_converge(
+        ws1.path(),
+        &["bundle", "--publication", &pub_id, "--json"],
+    )?)
+    .context("parse resolved bundle")?;
+    assert!(resolved.promotable, "reasons: {:?}", resolved.reasons);
+
+    // Reopening the decision makes bundles built from the resolution unpromotable again.
+    run_converge(
+        ws1.path(),
+        &[
+            "resolve",
+            "reopen",
+            "--bundle-id",
+            &bundle.id,
+            "--path",
+            "a.txt",
+            "--rationale",
+            "wrong side",
+        ],
+    )?;
+    let records = get_records(&base_url, &token, &bundle.id)?;
+    let rec = records
+        .records
+        .get("a.txt")
+        .context("a.txt record missing")?;
+    assert_eq!(rec.status, ResolutionStatus::Reopened);
+    assert!(rec.decision.is_none());
+    assert_eq!(rec.history.len(), 2);
+
+    let reopened: Bundle = serde_json::from_str(&run_converge(
+        ws1.path(),
+        &["bundle", "--publication", &pub_id, "--json"],
+    )?)
+    .context("parse reopened bundle")?;
+    assert!(!reopened.promotable);
+    assert!(reopened.reasons.iter().any(|r| r == "resolutions_open"));
+
+    let show = run_converge(
+        ws1.path(),
+        &["resolve", "show", "--bundle-id", &bundle.id, "--json"],
+    )?;
+    let show: serde_json::Value = serde_json::from_str(&show).context("parse resolve show")?;
+    assert_eq!(
+        show.pointer("/records/a.txt/status")
+            .and_then(|v| v.as_str()),
+        Some("reopened")
+    );
+    assert!(
+        show.pointer("/resolution/decisions/a.txt").is_none(),
+        "reopen should drop the local decision"
+    );
+
+    Ok(())
+}
@@ -0,0 +1,256 @@
+mod common;
+
+use std::fs;
+
+use anyhow::{Context, Result};
+
+use converge::remote::{BundleResolutions, ResolutionMethod, ResolutionStatus};
+
+fn run_converge(cwd: &std::path::Path, args: &[&str]) -> Result<String> {
+    let out = std::process::Command::new(env!("CARGO_BIN_EXE_converge"))
+        .current_dir(cwd)
+        .args(args)
+        .output()
+        .with_context(|| format!("run converge {:?} in {}", args, cwd.display()))?;
+
+    if !out.status.success() {
+        anyhow::bail!(
+            "converge {:?} failed (status {:?})\nstdout:\n{}\nstderr:\n{}",
+            args,
+            out.status,
+            String::from_utf8_lossy(&out.stdout),
+            String::from_utf8_lossy(&out.stderr)
+        );
+    }
+
+    Ok(String::from_utf8_lossy(&out.stdout).trim().to_string())
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct Bundle {
+    id: String,
+    root_manifest: String,
+    reasons: Vec<String>,
+}
+
+fn get_json<T: serde::de::DeserializeOwned>(base_url: &str, token: &str, path: &str) -> Result<T> {
+    reqwest::blocking::Client::new()
+        .get(format!("{}{}", base_url, path))
+        .header(reqwest::header::AUTHORIZATION, common::auth_header(token))
+        .send()
+        .with_context(|| format!("get {}", path))?
+        .error_for_status()
+        .with_context(|| format!("get {} status", path))?
+        .json()
+        .with_context(|| format!("parse {}", path))
+}
+
+fn get_records(base_url: &str, token: &str, bundle_id: &str) -> Result<BundleResolutions> {
+    get_json(
+        base_url,
+        token,
+        &format!("/repos/test/bundles/{}/resolutions", bundle_id),
+    )
+}
+
+fn put_gate_rules(base_url: &str, token: &str, rules: serde_json::Value) -> Result<()> {
+    let client = reqwest::blocking::Client::new();
+    let mut graph: serde_json::Value = get_json(base_url, token, "/repos/test/gate-graph")?;
+    let gates = graph
+        .get_mut("gates")
+        .and_then(|v| v.as_array_mut())
+        .context("gate graph gates missing")?;
+    for g in gates.iter_mut() {
+        if g.get("id") == Some(&serde_json::Value::String("dev-intake".to_string())) {
+            g["resolution_rules"] = rules.clone();
+        }
+    }
+    client
+        .put(format!("{}/repos/test/gate-graph", base_url))
+        .header(reqwest::header::AUTHORIZATION, common::auth_header(token))
+        .json(&graph)
+        .send()
+        .context("put gate graph")?
+        .error_for_status()
+        .context("put gate graph status")?;
+    Ok(())
+}
+
+#[test]
+fn resolution_rules_decide_superpositions_and_record_the_rule() -> Result<()> {
+    let server = common::spawn_server()?;
+    let base_url = server.base_url.clone();
+    let token = server.token.clone();
+
+    let tree_rules = serde_json::json!({
+        "version": 1,
+        "rules": [
+            { "name": "assets", "path": "*.png", "strategy": "prefer-newest" }
+        ]
+    });
+
+    let ws1 = tempfile::tempdir().context("create ws1")?;
+    let ws2 = tempfile::tempdir().context("create ws2")?;
+    for ws in [&ws1, &ws2] {
+        run_converge(ws.path(), &["init"])?;
+        run_converge(
+            ws.path(),
+            &[
+                "remote",
+                "set",
+                "--url",
+                &base_url,
+                "--token",
+                &token,
+                "--repo",
+                "test",
+                "--scope",
+                "main",
+                "--gate",
+                "dev-intake",
+            ],
+        )?;
+        fs::write(
+            ws.path().join("converge-rules.json"),
+            serde_json::to_vec_pretty(&tree_rules)?,
+        )
+        .context("write rules file")?;
+    }
+    run_converge(ws1.path(), &["remote", "create-repo"])?;
+
+    fs::write(ws1.path().join("Cargo.lock"), b"v1\n").context("write lock ws1")?;
+    fs::write(ws1.path().join("notes.txt"), b"a\nshared\n").context("write notes ws1")?;
+    fs::write(ws1.path().join("art.png"), b"art1").context("write art ws1")?;
+    let snap1 = run_converge(ws1.path(), &["snap"])?;
+    run_converge(ws1.path(), &["publish", "--snap-id", &snap1])?;
+
+    // Publication times are second-granular; keep "newest" unambiguous.
+    std::thread::sleep(std::time::Duration::from_millis(1100));
+
+    fs::write(ws2.path().join("Cargo.lock"), b"v2\n").context("write lock ws2")?;
+    fs::write(ws2.path().join("notes.txt"), b"shared\nb\n").context("write notes ws2")?;
+    fs::write(ws2.path().join("art.png"), b"art2").context("write art ws2")?;
+    let snap2 = run_converge(ws2.path(), &["snap"])?;
+    let pub2: serde_json::Value = serde_json::from_str(&run_converge(
+        ws2.path(),
+        &["publish", "--snap-id", &snap2, "--json"],
+    )?)
+    .context("parse publish json")?;
+    let pub2_id = pub2
+        .get("id")
+        .and_then(|v| v.as_str())
+        .context("publication id missing")?
+        .to_string();
+
+    // The in-tree rules file resolves art.png while bundling; the rest stays conflicted.
+    let bundle1: Bundle = serde_json::from_str(&run_converge(ws1.path(), &["bundle", "--json"])?)
+        .context("parse bundle")?;
+    let records = get_records(&base_url, &token, &bundle1.id)?;
+    let art = records.records.get("art.png").context("art.png record")?;
+    assert_eq!(art.status, ResolutionStatus::Resolved);
+    assert_eq!(art.method, Some(ResolutionMethod::Rule));
+    assert_eq!(art.rule.as_deref(), Some("assets"));
+    assert_eq!(
+        art.decision.as_ref().map(|k| k.source.as_str()),
+        Some(pub2_id.as_str())
+    );
+    let lock = records.records.get("Cargo.lock").context("lock record")?;
+    assert_eq!(lock.status, ResolutionStatus::Unresolved);
+
+    // Clients rank variants by the same attribution the server used while bundling.
+    let origins: std::collections::BTreeMap<String, converge::resolve::VariantOrigin> = get_json(
+        &base_url,
+        &token,
+        &format!("/repos/test/bundles/{}/origins", bundle1.id),
+    )?;
+    assert_eq!(origins.len(), 2);
+    assert!(origins.contains_key(&pub2_id));
+    assert!(origins.values().all(|o| !o.created_at.is_empty()));
+
+    put_gate_rules(
+        &base_url,
+        &token,
+        serde_json::json!([
+            { "name": "lockfiles", "path": "*.lock", "strategy": "prefer-newest" },
+            { "name": "notes", "path": "notes.txt", "strategy": "union-lines" }
+        ]),
+    )?;
+
+    // `resolve init --auto` applies the gate rules to the existing bundle.
+    let init: serde_json::Value = serde_json::from_str(&run_converge(
+        ws1.path(),
+        &[
+            "resolve",
+            "init",
+            "--bundle-id",
+            &bundle1.id,
+            "--auto",
+            "--json",
+        ],
+    )?)
+    .context("parse resolve init json")?;
+    assert_eq!(
+        init.pointer("/resolution/rules/Cargo.lock")
+            .and_then(|v| v.as_str()),
+        Some("lockfiles")
+    );
+    assert!(init.pointer("/resolution/decisions/Cargo.lock").is_some());
+    assert!(
+        init.pointer("/resolution/decisions/notes.txt").is_none(),
+        "union-lines only applies while bundling"
+    );
+
+    let records = get_records(&base_url, &token, &bundle1.id)?;
+    let lock = records.records.get("Cargo.lock").context("lock record")?;
+    assert_eq!(lock.status, ResolutionStatus::Resolved);
+    assert_eq!(lock.method, Some(ResolutionMethod::Rule));
+    assert_eq!(lock.rule.as_deref(), Some("lockfiles"));
+
+    // A fresh bundle gets every path decided by rules during coalescing.
+    let bundle2: Bundle = serde_json::from_str(&run_converge(ws1.path(), &["bundle", "--json"])?)
+        .context("parse bundle")?;
+    assert!(
+        !bundle2
+            .reasons
+            .iter()
+            .any(|r| r == "superpositions_present"),
+        "reasons: {:?}",
+        bundle2.reasons
+    );
+    let records = get_records(&base_url, &token, &bundle2.id)?;
+    let notes = records.records.get("notes.txt").context("notes record")?;
+    assert_eq!(notes.rule.as_deref(), Some("notes"));
+    assert!(notes.decision.is_none());
+
+    let root: converge::model::Manifest = get_json(
+        &base_url,
+        &token,
+        &format!("/repos/test/objects/manifests/{}", bundle2.root_manifest),
+    )?;
+    let entry = root
+        .entries
+        .iter()
+        .find(|e| e.name == "notes.txt")
+        .context("notes.txt entry")?;
+    let converge::model::ManifestEntryKind::File { blob, .. } = &entry.kind else {
+        anyhow::bail!("notes.txt should be a file, got {:?}", entry.kind);
+    };
+    let merged = reqwest::blocking::Client::new()
+        .get(format!(
+            "{}/repos/test/objects/blobs/{}",
+            base_url,
+            blob.as_str()
+        ))
+        .header(reqwest::header::AUTHORIZATION, common::auth_header(&token))
+        .send()
+        .context("get merged blob")?
+        .error_for_status()
+        .context("get merged blob status")?
+        .text()
+        .context("read merged blob")?;
+    let mut lines = merged.lines().collect::<Vec<_>>();
+    lines.sort();
+    assert_eq!(lines, vec!["a", "b", "shared"]);
+
+    Ok(())
+}
@@ -24,6 +24,7 @@ fn validate_resolution_reports_missing_and_invalid() -> Result<()> {
             mode: 0o100644,
             size: 4,
         },
+        metadata: None,
     };
     let v2 = SuperpositionVariant {
         source: "pub-2".to_string(),
@@ -32,6 +33,7 @@ fn validate_resolution_reports_missing_and_invalid() -> Result<()> {
             mode: 0o100644,
             size: 4,
         },
+        metadata: None,
     };
 
     let root = store.put_manifest(&Manifest {
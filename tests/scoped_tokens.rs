@@ -0,0 +1,298 @@
+mod common;
+
+use std::fs;
+use std::path::Path;
+
+use anyhow::{Context, Result};
+
+fn converge(cwd: &Path, args: &[&str]) -> Result<std::process::Output> {
+    std::process::Command::new(env!("CARGO_BIN_EXE_converge"))
+        .current_dir(cwd)
+        .args(args)
+        .output()
+        .with_context(|| format!("run converge {:?} in {}", args, cwd.display()))
+}
+
+fn run_converge(cwd: &Path, args: &[&str]) -> Result<String> {
+    let out = converge(cwd, args)?;
+    if !out.status.success() {
+        anyhow::bail!(
+            "converge {:?} failed (status {:?})\nstdout:\n{}\nstderr:\n{}",
+            args,
+            out.status,
+            String::from_utf8_lossy(&out.stdout),
+            String::from_utf8_lossy(&out.stderr)
+        );
+    }
+    Ok(String::from_utf8_lossy(&out.stdout).trim().to_string())
+}
+
+fn init_workspace(dir: &Path, base_url: &str, token: &str) -> Result<()> {
+    run_converge(dir, &["init"])?;
+    run_converge(
+        dir,
+        &[
+            "remote",
+            "set",
+            "--url",
+            base_url,
+            "--token",
+            token,
+            "--repo",
+            "test",
+            "--scope",
+            "main",
+            "--gate",
+            "dev-intake",
+        ],
+    )?;
+    Ok(())
+}
+
+fn status_of(req: reqwest::blocking::RequestBuilder) -> Result<reqwest::StatusCode> {
+    Ok(req.send().context("send request")?.status())
+}
+
+#[test]
+fn scoped_token_is_limited_to_its_repos_and_capabilities() -> Result<()> {
+    let server = common::spawn_server()?;
+    let client = reqwest::blocking::Client::new();
+    let admin_auth = common::auth_header(&server.token);
+
+    let admin_ws = tempfile::tempdir().context("create admin ws")?;
+    init_workspace(admin_ws.path(), &server.base_url, &server.token)?;
+    run_converge(admin_ws.path(), &["remote", "create-repo"])?;
+    client
+        .post(format!("{}/repos", server.base_url))
+        .header(reqwest::header::AUTHORIZATION, &admin_auth)
+        .json(&serde_json::json!({"id": "other"}))
+        .send()
+        .context("create other repo")?
+        .error_for_status()
+        .context("create other repo status")?;
+
+    let created: serde_json::Value = serde_json::from_str(&run_converge(
+        admin_ws.path(),
+        &[
+            "token",
+            "create",
+            "--label",
+            "ci",
+            "--repo",
+            "test",
+            "--allow",
+            "read,publish",
+            "--expires",
+            "30d",
+            "--json",
+        ],
+    )?)
+    .context("parse token create")?;
+    let scoped = created["token"].as_str().context("token")?.to_string();
+    let scoped_id = created["id"].as_str().context("token id")?.to_string();
+
+    let list = run_converge(admin_ws.path(), &["token", "list"])?;
+    let line = list
+        .lines()
+        .find(|l| l.starts_with(&scoped_id))
+        .context("scoped token listed")?;
+    assert!(
+        line.contains("repos=test allow=read,publish expires="),
+        "{}",
+        line
+    );
+    let tokens: serde_json::Value = serde_json::from_str(&run_converge(
+        admin_ws.path(),
+        &["token", "list", "--json"],
+    )?)
+    .context("parse token list")?;
+    let listed = tokens
+        .as_array()
+        .context("token list")?
+        .iter()
+        .find(|t| t["id"].as_str() == Some(scoped_id.as_str()))
+        .context("scoped token in json")?;
+    assert_eq!(
+        listed["capabilities"],
+        serde_json::json!(["read", "publish"])
+    );
+    assert!(listed["expires_at"].is_string());
+
+    let auth = common::auth_header(&scoped);
+    let get = |path: &str| {
+        client
+            .get(format!("{}{}", server.base_url, path))
+            .header(reqwest::header::AUTHORIZATION, &auth)
+    };
+    let post = |path: &str| {
+        client
+            .post(format!("{}{}", server.base_url, path))
+            .header(reqwest::header::AUTHORIZATION, &auth)
+    };
+
+    assert!(status_of(get("/repos/test"))?.is_success());
+    assert_eq!(
+        status_of(get("/repos/other"))?,
+        reqwest::StatusCode::FORBIDDEN
+    );
+    let repos: serde_json::Value = get("/repos")
+        .send()
+        .context("list repos")?
+        .json()
+        .context("parse repos")?;
+    assert_eq!(repos.as_array().map(|r| r.len()), Some(1));
+
+    // The user is an admin, but a scoped token does not carry admin rights
+    // and cannot mint broader tokens or create repos.
+    assert_eq!(status_of(get("/users"))?, reqwest::StatusCode::FORBIDDEN);
+    assert_eq!(
+        status_of(post("/tokens").json(&serde_json::json!({})))?,
+        reqwest::StatusCode::FORBIDDEN
+    );
+    assert_eq!(
+        status_of(post("/repos").json(&serde_json::json!({"id": "x"})))?,
+        reqwest::StatusCode::FORBIDDEN
+    );
+
+    let perms: serde_json::Value = get("/repos/test/permissions")
+        .send()
+        .context("get permissions")?
+        .json()
+        .context("parse permissions")?;
+    assert_eq!(perms["read"], serde_json::json!(true));
+    assert_eq!(perms["publish"], serde_json::json!(true));
+    assert_eq!(perms["approve"], serde_json::json!(false));
+    assert_eq!(perms["report_checks"], serde_json::json!(false));
+    assert_eq!(perms["release"], serde_json::json!(false));
+    assert_eq!(perms["manage"], serde_json::json!(false));
+
+    // A CI workspace using the scoped token can publish and bundle, but not release.
+    let ci_ws = tempfile::tempdir().context("create ci ws")?;
+    init_workspace(ci_ws.path(), &server.base_url, &scoped)?;
+    fs::write(ci_ws.path().join("a.txt"), b"ci\n").context("write a.txt")?;
+    run_converge(ci_ws.path(), &["snap"])?;
+    let publication: serde_json::Value =
+        serde_json::from_str(&run_converge(ci_ws.path(), &["publish", "--json"])?)
+            .context("parse publish")?;
+    let pub_id = publication["id"].as_str().context("publication id")?;
+    let bundle: serde_json::Value = serde_json::from_str(&run_converge(
+        ci_ws.path(),
+        &["bundle", "--publication", pub_id, "--json"],
+    )?)
+    .context("parse bundle")?;
+    let bundle_id = bundle["id"].as_str().context("bundle id")?;
+
+    let out = converge(
+        ci_ws.path(),
+        &[
+            "release",
+            "create",
+            "--channel",
+            "stable",
+            "--bundle-id",
+            bundle_id,
+        ],
+    )?;
+    assert!(!out.status.success());
+    assert!(String::from_utf8_lossy(&out.stderr).contains("forbidden"));
+
+    // Reporting checks needs its own capability; `publish` does not imply it.
+    let report = |ws: &Path| {
+        converge(
+            ws,
+            &[
+                "report-check",
+                "--bundle-id",
+                bundle_id,
+                "--name",
+                "ci/build",
+                "--status",
+                "success",
+                "--url",
+                "https://ci.example/1",
+                "--json",
+            ],
+        )
+    };
+    let out = report(ci_ws.path())?;
+    assert!(!out.status.success());
+    assert!(String::from_utf8_lossy(&out.stderr).contains("forbidden"));
+
+    let checker: serde_json::Value = serde_json::from_str(&run_converge(
+        admin_ws.path(),
+        &[
+            "token",
+            "create",
+            "--repo",
+            "test",
+            "--allow",
+            "report-checks",
+            "--json",
+        ],
+    )?)
+    .context("parse checker token")?;
+    let checker_ws = tempfile::tempdir().context("create checker ws")?;
+    init_workspace(
+        checker_ws.path(),
+        &server.base_url,
+        checker["token"].as_str().context("checker token")?,
+    )?;
+    let out = report(checker_ws.path())?;
+    assert!(
+        out.status.success(),
+        "{}",
+        String::from_utf8_lossy(&out.stderr)
+    );
+    let reported: serde_json::Value =
+        serde_json::from_slice(&out.stdout).context("parse report-check")?;
+    assert_eq!(reported["checks"][0]["name"], "ci/build");
+    assert_eq!(reported["checks"][0]["status"], "success");
+    let out = converge(checker_ws.path(), &["approve", "--bundle-id", bundle_id])?;
+    assert!(String::from_utf8_lossy(&out.stderr).contains("forbidden"));
+
+    run_converge(
+        admin_ws.path(),
+        &[
+            "release",
+            "create",
+            "--channel",
+            "stable",
+            "--bundle-id",
+            bundle_id,
+        ],
+    )?;
+
+    Ok(())
+}
+
+#[test]
+fn token_create_rejects_invalid_scopes() -> Result<()> {
+    let server = common::spawn_server()?;
+    let ws = tempfile::tempdir().context("create ws")?;
+    init_workspace(ws.path(), &server.base_url, &server.token)?;
+    run_converge(ws.path(), &["remote", "create-repo"])?;
+
+    for args in [
+        &["token", "create", "--allow", "read,deploy"][..],
+        &["token", "create", "--expires", "30x"][..],
+        &["token", "create", "--repo", "missing"][..],
+    ] {
+        let out = converge(ws.path(), args)?;
+        assert!(!out.status.success(), "{:?} should fail", args);
+    }
+
+    // Unscoped tokens keep full access.
+    let created: serde_json::Value =
+        serde_json::from_str(&run_converge(ws.path(), &["token", "create", "--json"])?)
+            .context("parse token create")?;
+    let token = created["token"].as_str().context("token")?;
+    let status = reqwest::blocking::Client::new()
+        .get(format!("{}/users", server.base_url))
+        .header(reqwest::header::AUTHORIZATION, common::auth_header(token))
+        .send()
+        .context("list users")?
+        .status();
+    assert!(status.is_success());
+
+    Ok(())
+}
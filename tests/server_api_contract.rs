@@ -325,6 +325,8 @@ fn server_api_contract_happy_path_and_auth_failures() -> Result<()> {
         root_manifest,
         message: None,
         stats: converge::model::SnapStats::default(),
+        parents: Vec::new(),
+        author: None,
     };
     client
         .put(format!(
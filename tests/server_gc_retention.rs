@@ -115,6 +115,8 @@ fn server_gc_prunes_unpinned_objects_and_metadata() -> Result<()> {
         root_manifest: root1,
         message: None,
         stats: converge::model::SnapStats::default(),
+        parents: Vec::new(),
+        author: None,
     };
     let snap2 = converge::model::SnapRecord {
         version: 1,
@@ -123,6 +125,8 @@ fn server_gc_prunes_unpinned_objects_and_metadata() -> Result<()> {
         root_manifest: root2,
         message: None,
         stats: converge::model::SnapStats::default(),
+        parents: Vec::new(),
+        author: None,
     };
 
     client
@@ -48,6 +48,8 @@ fn lane_heads_can_be_updated_and_survive_gc() -> Result<()> {
         root_manifest,
         message: None,
         stats: converge::model::SnapStats::default(),
+        parents: Vec::new(),
+        author: None,
     };
 
     client
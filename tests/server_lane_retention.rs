@@ -53,6 +53,8 @@ fn lane_head_history_retains_recent_snaps_and_allows_gc_of_older() -> Result<()>
             root_manifest,
             message: None,
             stats: converge::model::SnapStats::default(),
+            parents: Vec::new(),
+            author: None,
         };
 
         client
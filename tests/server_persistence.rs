@@ -68,6 +68,8 @@ fn server_persists_repo_across_restart() -> Result<()> {
         root_manifest,
         message: None,
         stats: converge::model::SnapStats::default(),
+        parents: Vec::new(),
+        author: None,
     };
 
     client
@@ -86,6 +86,8 @@ fn release_endpoints_create_list_and_show() -> Result<()> {
         root_manifest,
         message: None,
         stats: converge::model::SnapStats::default(),
+        parents: Vec::new(),
+        author: None,
     };
 
     client
@@ -0,0 +1,274 @@
+mod common;
+
+use std::fs;
+use std::path::Path;
+
+use anyhow::{Context, Result};
+
+fn converge(cwd: &Path, args: &[&str]) -> Result<std::process::Output> {
+    std::process::Command::new(env!("CARGO_BIN_EXE_converge"))
+        .current_dir(cwd)
+        .args(args)
+        .output()
+        .with_context(|| format!("run converge {:?} in {}", args, cwd.display()))
+}
+
+fn run_converge(cwd: &Path, args: &[&str]) -> Result<String> {
+    let out = converge(cwd, args)?;
+    if !out.status.success() {
+        anyhow::bail!(
+            "converge {:?} failed (status {:?})\nstdout:\n{}\nstderr:\n{}",
+            args,
+            out.status,
+            String::from_utf8_lossy(&out.stdout),
+            String::from_utf8_lossy(&out.stderr)
+        );
+    }
+    Ok(String::from_utf8_lossy(&out.stdout).trim().to_string())
+}
+
+/// Runs a command that must fail and returns its stderr.
+fn run_converge_err(cwd: &Path, args: &[&str]) -> Result<String> {
+    let out = converge(cwd, args)?;
+    if out.status.success() {
+        anyhow::bail!(
+            "converge {:?} unexpectedly succeeded\nstdout:\n{}",
+            args,
+            String::from_utf8_lossy(&out.stdout)
+        );
+    }
+    Ok(String::from_utf8_lossy(&out.stderr).to_string())
+}
+
+fn init_workspace(dir: &Path, server: &common::ServerGuard) -> Result<()> {
+    run_converge(dir, &["init"])?;
+    run_converge(
+        dir,
+        &[
+            "remote",
+            "set",
+            "--url",
+            &server.base_url,
+            "--token",
+            &server.token,
+            "--repo",
+            "test",
+            "--scope",
+            "main",
+            "--gate",
+            "dev-intake",
+        ],
+    )?;
+    Ok(())
+}
+
+#[test]
+fn signed_gate_requires_and_verifies_the_signature_chain() -> Result<()> {
+    let server = common::spawn_server()?;
+    let ws = tempfile::tempdir().context("create ws")?;
+    init_workspace(ws.path(), &server)?;
+    run_converge(ws.path(), &["remote", "create-repo"])?;
+
+    // A second workspace for the same user, without a signing key.
+    let unsigned = tempfile::tempdir().context("create unsigned ws")?;
+    init_workspace(unsigned.path(), &server)?;
+
+    let generated: serde_json::Value = serde_json::from_str(&run_converge(
+        ws.path(),
+        &[
+            "key",
+            "generate",
+            "--register",
+            "--label",
+            "laptop",
+            "--json",
+        ],
+    )?)
+    .context("parse key generate")?;
+    let key_id = generated["key_id"].as_str().context("key id")?.to_string();
+    assert!(Path::new(generated["path"].as_str().context("key path")?).exists());
+
+    let keys: serde_json::Value =
+        serde_json::from_str(&run_converge(ws.path(), &["key", "list", "--json"])?)
+            .context("parse key list")?;
+    assert_eq!(keys[0]["id"].as_str(), Some(key_id.as_str()));
+    assert_eq!(keys[0]["label"].as_str(), Some("laptop"));
+
+    let client = reqwest::blocking::Client::new();
+    client
+        .put(format!("{}/repos/test/gate-graph", server.base_url))
+        .header(
+            reqwest::header::AUTHORIZATION,
+            common::auth_header(&server.token),
+        )
+        .json(&serde_json::json!({
+            "version": 1,
+            "gates": [
+                {"id": "dev-intake", "name": "Dev Intake", "upstream": [], "required_approvals": 1, "require_signed": true}
+            ]
+        }))
+        .send()
+        .context("put gate graph")?
+        .error_for_status()
+        .context("put gate graph status")?;
+
+    fs::write(unsigned.path().join("a.txt"), b"unsigned\n").context("write a.txt")?;
+    run_converge(unsigned.path(), &["snap"])?;
+    let err = run_converge_err(unsigned.path(), &["publish"])?;
+    assert!(err.contains("400 Bad Request"), "{}", err);
+
+    fs::write(ws.path().join("a.txt"), b"signed\n").context("write a.txt")?;
+    run_converge(ws.path(), &["snap"])?;
+    let publication: serde_json::Value =
+        serde_json::from_str(&run_converge(ws.path(), &["publish", "--json"])?)
+            .context("parse publish")?;
+    assert_eq!(
+        publication["signature"]["key_id"].as_str(),
+        Some(key_id.as_str())
+    );
+    assert_eq!(
+        publication["snap_signature"]["signer"].as_str(),
+        Some("dev")
+    );
+    let pub_id = publication["id"].as_str().context("publication id")?;
+
+    let bundle: serde_json::Value = serde_json::from_str(&run_converge(
+        ws.path(),
+        &["bundle", "--publication", pub_id, "--json"],
+    )?)
+    .context("parse bundle")?;
+    let bundle_id = bundle["id"].as_str().context("bundle id")?;
+    assert_eq!(bundle["reasons"], serde_json::json!(["approvals_missing"]));
+
+    // Unsigned approvals do not count at a signed gate.
+    let err = run_converge_err(unsigned.path(), &["approve", "--bundle-id", bundle_id])?;
+    assert!(err.contains("400 Bad Request"), "{}", err);
+    let approved: serde_json::Value = serde_json::from_str(&run_converge(
+        ws.path(),
+        &["approve", "--bundle-id", bundle_id, "--json"],
+    )?)
+    .context("parse approve")?;
+    assert_eq!(approved["promotable"], serde_json::json!(true));
+
+    let err = run_converge_err(
+        unsigned.path(),
+        &[
+            "release",
+            "create",
+            "--channel",
+            "stable",
+            "--bundle-id",
+            bundle_id,
+        ],
+    )?;
+    assert!(err.contains("400 Bad Request"), "{}", err);
+    run_converge(
+        ws.path(),
+        &[
+            "release",
+            "create",
+            "--channel",
+            "stable",
+            "--bundle-id",
+            bundle_id,
+        ],
+    )?;
+
+    // Any workspace can verify; only public keys are needed.
+    let report: serde_json::Value = serde_json::from_str(&run_converge(
+        unsigned.path(),
+        &["verify", "--release", "stable", "--json"],
+    )?)
+    .context("parse verify")?;
+    assert_eq!(report["verified"], serde_json::json!(true));
+    let objects = report["checks"]
+        .as_array()
+        .context("checks")?
+        .iter()
+        .map(|c| c["object"].as_str().unwrap_or_default().to_string())
+        .collect::<Vec<_>>();
+    assert_eq!(objects, vec!["release", "approval", "publication", "snap"]);
+    run_converge(unsigned.path(), &["verify", "--bundle", bundle_id])?;
+
+    // Revoking the key stops new signatures; ones made before the revocation stay valid.
+    run_converge(ws.path(), &["key", "revoke", "--id", &key_id])?;
+    run_converge(ws.path(), &["verify", "--release", "stable"])?;
+    let err = run_converge_err(ws.path(), &["approve", "--bundle-id", bundle_id])?;
+    assert!(err.contains("400 Bad Request"), "{}", err);
+
+    Ok(())
+}
+
+#[test]
+fn signatures_are_optional_on_unsigned_gates_and_checked_when_present() -> Result<()> {
+    let server = common::spawn_server()?;
+    let ws = tempfile::tempdir().context("create ws")?;
+    init_workspace(ws.path(), &server)?;
+    run_converge(ws.path(), &["remote", "create-repo"])?;
+
+    fs::write(ws.path().join("a.txt"), b"one\n").context("write a.txt")?;
+    run_converge(ws.path(), &["snap"])?;
+    let publication: serde_json::Value =
+        serde_json::from_str(&run_converge(ws.path(), &["publish", "--json"])?)
+            .context("parse publish")?;
+    assert!(publication.get("signature").is_none());
+    let pub_id = publication["id"].as_str().context("publication id")?;
+    let bundle: serde_json::Value = serde_json::from_str(&run_converge(
+        ws.path(),
+        &["bundle", "--publication", pub_id, "--json"],
+    )?)
+    .context("parse bundle")?;
+    let bundle_id = bundle["id"].as_str().context("bundle id")?;
+    let err = run_converge_err(ws.path(), &["verify", "--bundle", bundle_id])?;
+    assert!(err.contains("2 of 2 checks failed"), "{}", err);
+
+    // A key that was never registered is rejected rather than silently dropped.
+    run_converge(ws.path(), &["key", "generate"])?;
+    fs::write(ws.path().join("a.txt"), b"two\n").context("write a.txt")?;
+    run_converge(ws.path(), &["snap"])?;
+    let err = run_converge_err(ws.path(), &["publish"])?;
+    assert!(err.contains("400 Bad Request"), "{}", err);
+
+    run_converge(ws.path(), &["key", "register"])?;
+    run_converge(ws.path(), &["publish"])?;
+
+    Ok(())
+}
+
+#[test]
+fn key_registration_requires_proof_of_possession() -> Result<()> {
+    let server = common::spawn_server()?;
+    let client = reqwest::blocking::Client::new();
+    let key = converge::signing::SigningKey::generate()?;
+    let register = |proof: String| {
+        client
+            .post(format!("{}/keys", server.base_url))
+            .header(
+                reqwest::header::AUTHORIZATION,
+                common::auth_header(&server.token),
+            )
+            .json(&serde_json::json!({
+                "public_key": key.public_key_hex(),
+                "proof": proof,
+            }))
+            .send()
+    };
+
+    // A proof bound to another user does not register the key.
+    let foreign = key.sign(&converge::signing::key_registration_payload(
+        "someone-else",
+        &key.public_key_hex(),
+    ));
+    let resp = register(foreign.signature).context("register with foreign proof")?;
+    assert_eq!(resp.status(), reqwest::StatusCode::BAD_REQUEST);
+    let resp = register("00".repeat(64)).context("register with bogus proof")?;
+    assert_eq!(resp.status(), reqwest::StatusCode::BAD_REQUEST);
+
+    let ws = tempfile::tempdir().context("create ws")?;
+    init_workspace(ws.path(), &server)?;
+    let keys: serde_json::Value =
+        serde_json::from_str(&run_converge(ws.path(), &["key", "list", "--json"])?)
+            .context("parse key list")?;
+    assert_eq!(keys, serde_json::json!([]));
+    Ok(())
+}
@@ -0,0 +1,245 @@
+mod common;
+
+use std::fs;
+
+use anyhow::{Context, Result};
+
+use converge::model::{Manifest, ManifestEntryKind};
+
+fn run_converge(cwd: &std::path::Path, args: &[&str]) -> Result<String> {
+    let out = std::process::Command::new(env!("CARGO_BIN_EXE_converge"))
+        .current_dir(cwd)
+        .args(args)
+        .output()
+        .with_context(|| format!("run converge {:?} in {}", args, cwd.display()))?;
+
+    if !out.status.success() {
+        anyhow::bail!(
+            "converge {:?} failed (status {:?})\nstdout:\n{}\nstderr:\n{}",
+            args,
+            out.status,
+            String::from_utf8_lossy(&out.stdout),
+            String::from_utf8_lossy(&out.stderr)
+        );
+    }
+
+    Ok(String::from_utf8_lossy(&out.stdout).trim().to_string())
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct Bundle {
+    id: String,
+    root_manifest: String,
+}
+
+fn get_root(base_url: &str, token: &str, root: &str) -> Result<Manifest> {
+    reqwest::blocking::Client::new()
+        .get(format!(
+            "{}/repos/test/objects/manifests/{}",
+            base_url, root
+        ))
+        .header(reqwest::header::AUTHORIZATION, common::auth_header(token))
+        .send()
+        .context("get root manifest")?
+        .error_for_status()
+        .context("get root manifest status")?
+        .json()
+        .context("parse root manifest")
+}
+
+fn publish(ws: &std::path::Path) -> Result<String> {
+    let snap = run_converge(ws, &["snap"])?;
+    let publication: serde_json::Value = serde_json::from_str(&run_converge(
+        ws,
+        &["publish", "--snap-id", &snap, "--json"],
+    )?)
+    .context("parse publish json")?;
+    publication
+        .get("id")
+        .and_then(|v| v.as_str())
+        .map(|s| s.to_string())
+        .context("publication id missing")
+}
+
+#[test]
+fn structured_drivers_merge_keys_and_annotate_conflicts() -> Result<()> {
+    let server = common::spawn_server()?;
+    let base_url = server.base_url.clone();
+    let token = server.token.clone();
+
+    let ws1 = tempfile::tempdir().context("create ws1")?;
+    let ws2 = tempfile::tempdir().context("create ws2")?;
+    for ws in [&ws1, &ws2] {
+        run_converge(ws.path(), &["init"])?;
+        run_converge(
+            ws.path(),
+            &[
+                "remote",
+                "set",
+                "--url",
+                &base_url,
+                "--token",
+                &token,
+                "--repo",
+                "test",
+                "--scope",
+                "main",
+                "--gate",
+                "dev-intake",
+            ],
+        )?;
+    }
+    run_converge(ws1.path(), &["remote", "create-repo"])?;
+
+    fs::write(
+        ws1.path().join("config.json"),
+        br#"{"name": "app", "port": 8080}"#,
+    )
+    .context("write config ws1")?;
+    fs::write(ws1.path().join("deploy.yaml"), b"replicas: 2\nimage: app\n")
+        .context("write deploy ws1")?;
+    let pub1 = publish(ws1.path())?;
+
+    fs::write(
+        ws2.path().join("config.json"),
+        br#"{"name": "app", "features": ["beta"]}"#,
+    )
+    .context("write config ws2")?;
+    fs::write(ws2.path().join("deploy.yaml"), b"replicas: 3\nimage: app\n")
+        .context("write deploy ws2")?;
+    let pub2 = publish(ws2.path())?;
+
+    let bundle: Bundle = serde_json::from_str(&run_converge(ws1.path(), &["bundle", "--json"])?)
+        .context("parse bundle")?;
+    let root = get_root(&base_url, &token, &bundle.root_manifest)?;
+
+    // Different keys of config.json merge into a plain file.
+    let config = root
+        .entries
+        .iter()
+        .find(|e| e.name == "config.json")
+        .context("config.json entry")?;
+    assert!(
+        matches!(config.kind, ManifestEntryKind::File { .. }),
+        "config.json should merge cleanly, got {:?}",
+        config.kind
+    );
+
+    // Both sides changed `replicas`; the superposition names the driver and key.
+    let deploy = root
+        .entries
+        .iter()
+        .find(|e| e.name == "deploy.yaml")
+        .context("deploy.yaml entry")?;
+    let ManifestEntryKind::Superposition { variants } = &deploy.kind else {
+        anyhow::bail!(
+            "deploy.yaml should be a superposition, got {:?}",
+            deploy.kind
+        );
+    };
+    assert_eq!(variants.len(), 2);
+    for v in variants {
+        let meta = v.metadata.as_ref().context("variant metadata")?;
+        assert_eq!(meta.merge_driver.as_deref(), Some("yaml"));
+        assert_eq!(meta.conflicting_keys, vec!["replicas".to_string()]);
+    }
+
+    let show = run_converge(ws1.path(), &["resolve", "init", "--bundle-id", &bundle.id])
+        .and_then(|_| run_converge(ws1.path(), &["resolve", "show", "--bundle-id", &bundle.id]))?;
+    assert!(
+        show.contains("driver=yaml conflicting_keys: replicas"),
+        "{}",
+        show
+    );
+
+    // ws2 agrees on replicas; a bundle of ws1 + the new publication is clean.
+    fs::write(ws2.path().join("deploy.yaml"), b"replicas: 2\nimage: app\n")
+        .context("rewrite deploy ws2")?;
+    let pub3 = publish(ws2.path())?;
+    let clean: Bundle = serde_json::from_str(&run_converge(
+        ws1.path(),
+        &[
+            "bundle",
+            "--publication",
+            &pub1,
+            "--publication",
+            &pub3,
+            "--json",
+        ],
+    )?)
+    .context("parse clean bundle")?;
+    assert_ne!(pub2, pub3);
+
+    // `update` folds the bundle into ws1 while keeping a local-only key.
+    fs::write(
+        ws1.path().join("config.json"),
+        br#"{"name": "app", "port": 8080, "local": true}"#,
+    )
+    .context("edit config ws1")?;
+    run_converge(ws1.path(), &["snap"])?;
+    let update: serde_json::Value = serde_json::from_str(&run_converge(
+        ws1.path(),
+        &["update", "--bundle-id", &clean.id, "--json"],
+    )?)
+    .context("parse update json")?;
+    assert_eq!(
+        update
+            .get("conflicts")
+            .and_then(|v| v.as_array())
+            .map(|a| a.len()),
+        Some(0),
+        "{}",
+        update
+    );
+    assert!(
+        update
+            .get("merged")
+            .and_then(|v| v.as_array())
+            .is_some_and(|a| a.iter().any(|p| p == "config.json")),
+        "{}",
+        update
+    );
+
+    let config: serde_json::Value = serde_json::from_slice(
+        &fs::read(ws1.path().join("config.json")).context("read merged config")?,
+    )
+    .context("parse merged config")?;
+    assert_eq!(
+        config,
+        serde_json::json!({"name": "app", "port": 8080, "local": true, "features": ["beta"]})
+    );
+
+    // A conflicting update merges into the working tree but leaves it unsnapped.
+    fs::write(ws2.path().join("deploy.yaml"), b"replicas: 5\nimage: app\n")
+        .context("rewrite deploy ws2")?;
+    fs::write(ws2.path().join("notes.txt"), b"upstream\n").context("write notes ws2")?;
+    let pub4 = publish(ws2.path())?;
+    let upstream: Bundle = serde_json::from_str(&run_converge(
+        ws1.path(),
+        &["bundle", "--publication", &pub4, "--json"],
+    )?)
+    .context("parse upstream bundle")?;
+    fs::write(ws1.path().join("deploy.yaml"), b"replicas: 4\nimage: app\n")
+        .context("edit deploy ws1")?;
+    run_converge(ws1.path(), &["snap"])?;
+    let update: serde_json::Value = serde_json::from_str(&run_converge(
+        ws1.path(),
+        &["update", "--bundle-id", &upstream.id, "--json"],
+    )?)
+    .context("parse conflicting update json")?;
+    assert!(update["snap_id"].is_null(), "{}", update);
+    assert_eq!(update["conflicts"][0]["path"], "deploy.yaml", "{}", update);
+    assert_eq!(
+        fs::read_to_string(ws1.path().join("deploy.yaml")).context("read deploy")?,
+        "replicas: 4\nimage: app\n"
+    );
+    assert_eq!(
+        fs::read_to_string(ws1.path().join("notes.txt")).context("read notes")?,
+        "upstream\n"
+    );
+    let err = run_converge(ws1.path(), &["update", "--bundle-id", &upstream.id])
+        .expect_err("update over an unsnapped merge");
+    assert!(err.to_string().contains("uncommitted changes"), "{:#}", err);
+
+    Ok(())
+}
@@ -94,6 +94,8 @@ fn upload_integrity_hash_mismatch_rejected() -> Result<()> {
         root_manifest,
         message: None,
         stats: converge::model::SnapStats::default(),
+        parents: Vec::new(),
+        author: None,
     };
 
     let resp = client
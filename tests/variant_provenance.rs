@@ -0,0 +1,138 @@
+mod common;
+
+use std::fs;
+
+use anyhow::{Context, Result};
+
+use converge::model::{Manifest, ManifestEntryKind};
+
+fn run_converge(cwd: &std::path::Path, args: &[&str]) -> Result<String> {
+    let out = std::process::Command::new(env!("CARGO_BIN_EXE_converge"))
+        .current_dir(cwd)
+        .args(args)
+        .output()
+        .with_context(|| format!("run converge {:?} in {}", args, cwd.display()))?;
+
+    if !out.status.success() {
+        anyhow::bail!(
+            "converge {:?} failed (status {:?})\nstdout:\n{}\nstderr:\n{}",
+            args,
+            out.status,
+            String::from_utf8_lossy(&out.stdout),
+            String::from_utf8_lossy(&out.stderr)
+        );
+    }
+
+    Ok(String::from_utf8_lossy(&out.stdout).trim().to_string())
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct Bundle {
+    id: String,
+    root_manifest: String,
+}
+
+#[test]
+fn superposition_variants_carry_publication_provenance() -> Result<()> {
+    let server = common::spawn_server()?;
+    let base_url = server.base_url.clone();
+    let token = server.token.clone();
+
+    let ws1 = tempfile::tempdir().context("create ws1")?;
+    let ws2 = tempfile::tempdir().context("create ws2")?;
+    for ws in [&ws1, &ws2] {
+        run_converge(ws.path(), &["init"])?;
+        run_converge(
+            ws.path(),
+            &[
+                "remote",
+                "set",
+                "--url",
+                &base_url,
+                "--token",
+                &token,
+                "--repo",
+                "test",
+                "--scope",
+                "main",
+                "--gate",
+                "dev-intake",
+            ],
+        )?;
+    }
+    run_converge(ws1.path(), &["remote", "create-repo"])?;
+
+    let mut pubs = Vec::new();
+    for (ws, content, message) in [
+        (&ws1, "one\n", "first take"),
+        (&ws2, "two\n", "second take"),
+    ] {
+        fs::write(ws.path().join("a.txt"), content).context("write a.txt")?;
+        let snap = run_converge(ws.path(), &["snap", "-m", message])?;
+        let publication: serde_json::Value = serde_json::from_str(&run_converge(
+            ws.path(),
+            &["publish", "--snap-id", &snap, "--json"],
+        )?)
+        .context("parse publish json")?;
+        pubs.push(publication);
+    }
+
+    let bundle: Bundle = serde_json::from_str(&run_converge(ws1.path(), &["bundle", "--json"])?)
+        .context("parse bundle")?;
+    let root: Manifest = reqwest::blocking::Client::new()
+        .get(format!(
+            "{}/repos/test/objects/manifests/{}",
+            base_url, bundle.root_manifest
+        ))
+        .header(reqwest::header::AUTHORIZATION, common::auth_header(&token))
+        .send()
+        .context("get root manifest")?
+        .error_for_status()
+        .context("get root manifest status")?
+        .json()
+        .context("parse root manifest")?;
+    let entry = root
+        .entries
+        .iter()
+        .find(|e| e.name == "a.txt")
+        .context("a.txt entry")?;
+    let ManifestEntryKind::Superposition { variants } = &entry.kind else {
+        anyhow::bail!("a.txt should be a superposition, got {:?}", entry.kind);
+    };
+
+    for publication in &pubs {
+        let id = publication["id"].as_str().context("publication id")?;
+        let variant = variants
+            .iter()
+            .find(|v| v.source == id)
+            .context("variant for publication")?;
+        let meta = variant.metadata.as_ref().context("variant metadata")?;
+        assert_eq!(meta.publisher.as_deref(), publication["publisher"].as_str());
+        assert_eq!(
+            meta.published_at.as_deref(),
+            publication["created_at"].as_str()
+        );
+        assert!(meta.publisher_user_id.is_some());
+        assert_eq!(meta.lane.as_deref(), Some("default"));
+
+        // Provenance is context only; the variant key is unchanged without it.
+        let mut bare = variant.clone();
+        bare.metadata = None;
+        assert_eq!(bare.key(), variant.key());
+    }
+    let messages = variants
+        .iter()
+        .filter_map(|v| v.metadata.as_ref()?.snap_message.clone())
+        .collect::<Vec<_>>();
+    assert_eq!(messages.len(), 2);
+    assert!(messages.contains(&"first take".to_string()));
+    assert!(messages.contains(&"second take".to_string()));
+
+    run_converge(ws1.path(), &["resolve", "init", "--bundle-id", &bundle.id])?;
+    let show = run_converge(ws1.path(), &["resolve", "show", "--bundle-id", &bundle.id])?;
+    assert!(show.contains("lane=default"), "{}", show);
+    assert!(show.contains("message: first take"), "{}", show);
+    assert!(show.contains("message: second take"), "{}", show);
+
+    Ok(())
+}